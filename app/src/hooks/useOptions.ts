@@ -2,6 +2,7 @@ import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
 import { PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
 import { BN } from '@coral-xyz/anchor';
 import { useOptionsProgram } from './useAnchor';
+import { defaultSeriesConfig, vanillaOptionType } from '@/lib/seriesConfig';
 import {useConnection, useWallet} from '@solana/wallet-adapter-react';
 import {
     TOKEN_PROGRAM_ID,
@@ -99,8 +100,16 @@ export function useCreateOption() {
             }
             try {
                 const tx = await program.methods
-                    .createOption(baseMintPk, quoteMintPk, strikeBN, expirationBN, isPut)
-                    .accountsStrict({
+                    .createOption(
+                        baseMintPk,
+                        quoteMintPk,
+                        strikeBN,
+                        expirationBN,
+                        vanillaOptionType(isPut),
+                        defaultSeriesConfig()
+                    )
+                    // Config, stats, tick and approval PDAs resolve from their seeds
+                    .accountsPartial({
                         user: publicKey,
                         optionContext: optionContextPda,
                         collateralMint: baseMintPk,
@@ -111,7 +120,10 @@ export function useCreateOption() {
                         considerationVault: considerationVault,
                         systemProgram: SystemProgram.programId,
                         tokenProgram: TOKEN_PROGRAM_ID,
-                        rent: SYSVAR_RENT_PUBKEY
+                        rent: SYSVAR_RENT_PUBKEY,
+                        tokenMetadataProgram: null,
+                        optionMetadata: null,
+                        redemptionMetadata: null,
                     })
                     .rpc();
 
@@ -5,7 +5,7 @@
  * IDL can be found at `target/idl/sol_option_protocol.json`.
  */
 export type SolOptionProtocol = {
-  "address": "2xQGkb3maNPAA3Kaj7xd5RgJzddppLiPjxoupfruhXnF",
+  "address": "7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP",
   "metadata": {
     "name": "solOptionProtocol",
     "version": "0.1.0",
@@ -14,285 +14,349 @@ export type SolOptionProtocol = {
   },
   "instructions": [
     {
-      "name": "burn",
+      "name": "acceptConfigAdmin",
       "docs": [
-        "Burn: burn both legs to reclaim 1:1 collateral anytime"
+        "AcceptConfigAdmin: nominated successor signs to take over (step 2 of 2; a",
+        "governance accepts by executing it from a proposal)"
       ],
       "discriminator": [
-        116,
-        110,
-        29,
-        56,
-        107,
-        219,
-        42,
-        93
+        166,
+        251,
+        87,
+        11,
+        190,
+        105,
+        44,
+        30
       ],
       "accounts": [
         {
-          "name": "user",
-          "writable": true,
+          "name": "pendingAdmin",
           "signer": true
         },
         {
-          "name": "optionContext",
-          "docs": [
-            "The OptionContext PDA (client calculates and sends this)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "collateralMint",
-          "docs": [
-            "Collateral mint (validated against stored value in option_context)"
-          ]
-        },
-        {
-          "name": "considerationMint",
-          "docs": [
-            "Consideration mint (validated against stored value in option_context)"
-          ]
-        },
-        {
-          "name": "optionMint",
-          "docs": [
-            "Option mint (validated against stored value in option_context)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "redemptionMint",
-          "docs": [
-            "Redemption mint (validated against stored value in option_context)"
-          ],
-          "writable": true
-        },
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptSeriesAuthority",
+      "docs": [
+        "AcceptSeriesAuthority: nominated successor signs to take over (step 2 of 2)"
+      ],
+      "discriminator": [
+        101,
+        104,
+        151,
+        4,
+        159,
+        163,
+        66,
+        250
+      ],
+      "accounts": [
         {
-          "name": "collateralVault",
-          "docs": [
-            "Collateral vault (validated against stored value in option_context)"
-          ],
-          "writable": true
+          "name": "pendingAuthority",
+          "signer": true
         },
         {
-          "name": "considerationVault",
-          "docs": [
-            "Consideration vault (validated against stored value in option_context)"
-          ],
+          "name": "optionContext",
           "writable": true
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "accrueFunding",
+      "docs": [
+        "AccrueFunding: permissionless crank bringing a perpetual series' funding",
+        "index up to date"
+      ],
+      "discriminator": [
+        152,
+        118,
+        41,
+        215,
+        85,
+        208,
+        160,
+        108
+      ],
+      "accounts": [
         {
-          "name": "userCollateralAccount",
-          "docs": [
-            "User's collateral token account"
-          ],
+          "name": "optionContext",
           "writable": true
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "announceVersion",
+      "docs": [
+        "AnnounceVersion: permissionless, emits version/capabilities/config hash once per upgrade"
+      ],
+      "discriminator": [
+        41,
+        42,
+        196,
+        233,
+        235,
+        145,
+        185,
+        137
+      ],
+      "accounts": [
         {
-          "name": "userConsiderationAccount",
-          "docs": [
-            "User's consideration token account"
-          ],
-          "writable": true
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "userOptionAccount",
-          "docs": [
-            "User's option token account"
-          ],
-          "writable": true
+          "name": "programVersion",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  118,
+                  101,
+                  114,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "userRedemptionAccount",
+          "name": "protocolConfig",
           "docs": [
-            "User's redemption token account"
+            "Omitted before the config is initialized (config hash is then all zeros)"
           ],
-          "writable": true
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createOption",
+      "name": "applyChange",
       "docs": [
-        "CreateOption: Initializes OptionContext + vaults + mints"
+        "ApplyChange: admin applies a scheduled change whose timelock has passed"
       ],
       "discriminator": [
-        226,
-        92,
-        124,
-        94,
-        113,
-        96,
-        60,
-        172
+        248,
+        177,
+        9,
+        41,
+        50,
+        98,
+        255,
+        50
       ],
       "accounts": [
         {
-          "name": "user",
+          "name": "admin",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "optionContext",
-          "docs": [
-            "The OptionContext PDA - INITIALIZE it (create new account)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "collateralMint",
-          "docs": [
-            "Collateral mint (provided by client)"
-          ]
-        },
-        {
-          "name": "considerationMint",
-          "docs": [
-            "Consideration/strike currency mint (provided by client)"
+          "signer": true,
+          "relations": [
+            "protocolConfig"
           ]
         },
         {
-          "name": "optionMint",
-          "docs": [
-            "Option token mint PDA - INITIALIZE it"
-          ],
+          "name": "protocolConfig",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  111,
                   112,
+                  114,
+                  111,
                   116,
-                  105,
                   111,
-                  110,
+                  99,
+                  111,
+                  108,
                   95,
-                  109,
-                  105,
+                  99,
+                  111,
                   110,
-                  116
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "optionContext"
               }
             ]
           }
         },
         {
-          "name": "redemptionMint",
-          "docs": [
-            "Redemption token mint PDA - INITIALIZE it"
-          ],
+          "name": "pendingChange",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  114,
+                  112,
                   101,
+                  110,
                   100,
-                  101,
-                  109,
-                  112,
-                  116,
                   105,
-                  111,
                   110,
+                  103,
                   95,
-                  109,
-                  105,
+                  99,
+                  104,
+                  97,
                   110,
-                  116
+                  103,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "optionContext"
+                "path": "pending_change.id",
+                "account": "pendingChange"
               }
             ]
           }
         },
         {
-          "name": "collateralVault",
+          "name": "approvedMint",
           "docs": [
-            "Collateral vault PDA - INITIALIZE it"
+            "only (created if governance never touched the mint); checked in handler"
           ],
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  108,
-                  108,
-                  97,
-                  116,
-                  101,
-                  114,
-                  97,
-                  108,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "optionContext"
-              }
-            ]
-          }
+          "optional": true
         },
         {
-          "name": "considerationVault",
-          "docs": [
-            "Consideration vault PDA - INITIALIZE it"
-          ],
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "attestSettlement",
+      "docs": [
+        "AttestSettlement: anyone records a settled series' result for",
+        "cross-chain consumers, optionally posting it through Wormhole"
+      ],
+      "discriminator": [
+        139,
+        194,
+        42,
+        227,
+        36,
+        121,
+        240,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "attestation",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  111,
-                  110,
                   115,
-                  105,
-                  100,
                   101,
-                  114,
-                  97,
                   116,
-                  105,
-                  111,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
                   110,
+                  116,
                   95,
-                  118,
                   97,
-                  117,
-                  108,
-                  116
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
@@ -307,51 +371,60 @@ export type SolOptionProtocol = {
           "address": "11111111111111111111111111111111"
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "wormholeProgram",
+          "optional": true
         },
         {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "wormholeBridge",
+          "writable": true,
+          "optional": true
+        },
         {
-          "name": "collateralMint",
-          "type": "pubkey"
+          "name": "wormholeMessage",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "considerationMint",
-          "type": "pubkey"
+          "name": "wormholeEmitter",
+          "optional": true
         },
         {
-          "name": "strikePrice",
-          "type": "u64"
+          "name": "wormholeSequence",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "expiration",
-          "type": "i64"
+          "name": "wormholeFeeCollector",
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "isPut",
-          "type": "bool"
+          "name": "clock",
+          "optional": true,
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "optional": true,
+          "address": "SysvarRent111111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "exercise",
+      "name": "burn",
       "docs": [
-        "Exercise: burn options, pay strike → receive collateral"
+        "Burn: burn both legs to reclaim 1:1 collateral anytime"
       ],
       "discriminator": [
-        144,
-        79,
-        103,
-        64,
-        241,
-        78,
-        80,
-        174
+        116,
+        110,
+        29,
+        56,
+        107,
+        219,
+        42,
+        93
       ],
       "accounts": [
         {
@@ -366,6 +439,36 @@ export type SolOptionProtocol = {
           ],
           "writable": true
         },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "collateralMint",
           "docs": [
@@ -406,145 +509,527 @@ export type SolOptionProtocol = {
           ],
           "writable": true
         },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
         {
           "name": "userCollateralAccount",
           "docs": [
-            "User's collateral token account"
+            "User's collateral token account (associated; created if missing)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
           "name": "userConsiderationAccount",
           "docs": [
-            "User's consideration token account"
+            "User's consideration token account (associated; created if missing)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
           "name": "userOptionAccount",
           "docs": [
-            "User's option token account"
+            "Option token account of `option_owner` (associated; created if missing)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
           "name": "userRedemptionAccount",
           "docs": [
-            "User's redemption token account"
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": [
+          "name": "tokenProgram"
+        },
         {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "mint",
-      "docs": [
-        "Mint: deposit collateral → mint option + redemption tokens 1:1"
-      ],
-      "discriminator": [
-        51,
-        57,
-        225,
-        47,
-        182,
-        146,
-        137,
-        166
-      ],
-      "accounts": [
-        {
-          "name": "user",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "optionContext",
-          "docs": [
-            "The OptionContext PDA (client calculates and sends this)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "collateralMint",
-          "docs": [
-            "Collateral mint (validated against stored value in option_context)"
-          ]
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
-          "name": "considerationMint",
-          "docs": [
-            "Consideration mint (validated against stored value in option_context)"
-          ]
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
-          "name": "optionMint",
+          "name": "writerPosition",
           "docs": [
-            "Option mint (validated against stored value in option_context)"
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
         },
         {
-          "name": "redemptionMint",
+          "name": "feeVault",
           "docs": [
-            "Redemption mint (validated against stored value in option_context)"
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "collateralVault",
+          "name": "feeStats",
           "docs": [
-            "Collateral vault (validated against stored value in option_context)"
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
         },
         {
-          "name": "considerationVault",
+          "name": "referrerAccount",
           "docs": [
-            "Consideration vault (validated against stored value in option_context)"
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "userCollateralAccount",
+          "name": "referralStats",
           "docs": [
-            "User's collateral token account"
+            "Referrer's earnings in the charged mint"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "userConsiderationAccount",
+          "name": "insuranceFund",
           "docs": [
-            "User's consideration token account"
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "userOptionAccount",
+          "name": "insuranceVault",
           "docs": [
-            "User's option token account"
+            "Token account of the insurance fund in the charged mint"
           ],
-          "writable": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "userRedemptionAccount",
+          "name": "credentialAccount",
           "docs": [
-            "User's redemption token account"
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
           ],
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "optional": true
         }
       ],
       "args": [
@@ -555,104 +1040,109 @@ export type SolOptionProtocol = {
       ]
     },
     {
-      "name": "redeem",
+      "name": "burnCompact",
       "docs": [
-        "Redeem: post-expiry pro-rata of collateral + consideration by burning redemption tokens"
+        "BurnCompact: `burn` over `BurnAccounts` (no protocol config or fee accounts)"
       ],
       "discriminator": [
-        184,
-        12,
-        86,
-        149,
-        70,
-        196,
-        97,
-        225
+        200,
+        123,
+        95,
+        111,
+        182,
+        158,
+        192,
+        241
       ],
       "accounts": [
         {
           "name": "user",
-          "writable": true,
           "signer": true
         },
         {
           "name": "optionContext",
-          "docs": [
-            "The OptionContext PDA (client calculates and sends this)"
-          ],
           "writable": true
         },
         {
-          "name": "collateralMint",
-          "docs": [
-            "Collateral mint (validated against stored value in option_context)"
-          ]
+          "name": "collateralMint"
         },
         {
-          "name": "considerationMint",
-          "docs": [
-            "Consideration mint (validated against stored value in option_context)"
-          ]
+          "name": "considerationMint"
         },
         {
           "name": "optionMint",
-          "docs": [
-            "Option mint (validated against stored value in option_context)"
-          ],
           "writable": true
         },
         {
           "name": "redemptionMint",
-          "docs": [
-            "Redemption mint (validated against stored value in option_context)"
-          ],
           "writable": true
         },
         {
           "name": "collateralVault",
-          "docs": [
-            "Collateral vault (validated against stored value in option_context)"
-          ],
           "writable": true
         },
         {
           "name": "considerationVault",
-          "docs": [
-            "Consideration vault (validated against stored value in option_context)"
-          ],
           "writable": true
         },
         {
           "name": "userCollateralAccount",
-          "docs": [
-            "User's collateral token account"
-          ],
           "writable": true
         },
         {
           "name": "userConsiderationAccount",
-          "docs": [
-            "User's consideration token account"
-          ],
           "writable": true
         },
         {
           "name": "userOptionAccount",
-          "docs": [
-            "User's option token account"
-          ],
           "writable": true
         },
         {
           "name": "userRedemptionAccount",
-          "docs": [
-            "User's redemption token account"
-          ],
           "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; recorded into when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
         }
       ],
       "args": [
@@ -663,20 +1153,19 @@ export type SolOptionProtocol = {
       ]
     },
     {
-      "name": "redeemConsideration",
+      "name": "burnNative",
       "docs": [
-        "Allows SHORT token holders to claim pro-rata consideration before expiry",
-        "Greek.fi compliance: Key capital efficiency feature"
+        "BurnNative: `burn` for WSOL series, the escrow returned as lamports"
       ],
       "discriminator": [
-        54,
-        82,
-        154,
-        89,
-        31,
+        109,
+        22,
+        224,
+        88,
         206,
-        139,
-        53
+        67,
+        143,
+        114
       ],
       "accounts": [
         {
@@ -691,6 +1180,36 @@ export type SolOptionProtocol = {
           ],
           "writable": true
         },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "collateralMint",
           "docs": [
@@ -732,224 +1251,28720 @@ export type SolOptionProtocol = {
           "writable": true
         },
         {
-          "name": "userCollateralAccount",
+          "name": "optionOwner",
           "docs": [
-            "User's collateral token account"
-          ],
-          "writable": true
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
         },
         {
-          "name": "userConsiderationAccount",
+          "name": "redemptionOwner",
           "docs": [
-            "User's consideration token account"
-          ],
-          "writable": true
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
         },
         {
-          "name": "userOptionAccount",
+          "name": "userCollateralAccount",
           "docs": [
-            "User's option token account"
+            "User's collateral token account (associated; created if missing)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
         },
         {
-          "name": "userRedemptionAccount",
+          "name": "userConsiderationAccount",
           "docs": [
-            "User's redemption token account"
+            "User's consideration token account (associated; created if missing)"
           ],
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "burnVerticalSpread",
+      "docs": [
+        "BurnVerticalSpread: writer burns spread tokens before settlement for",
+        "their longs and backing"
+      ],
+      "discriminator": [
+        3,
+        219,
+        175,
+        219,
+        73,
+        31,
+        193,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (mint, burn, redeem), holder (claim) or any cranker (settle)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shortOptionContext",
+          "docs": [
+            "Series whose intrinsic value spread tokens pay (only read)"
+          ]
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose options writers lock (pays their netted payoff at settlement)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verticalSpread",
+          "docs": [
+            "Spread PDA (created by the first `mint_vertical_spread` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shortOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "longOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadMint",
+          "docs": [
+            "Spread token mint (same decimals as the options; the spread is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "backingMint",
+          "docs": [
+            "Mint of the asset the short series' writers escrow"
+          ]
+        },
+        {
+          "name": "longEscrow",
+          "docs": [
+            "Locked long options, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadVault",
+          "docs": [
+            "Backing and settlement payoff, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the backing asset (pays the locked longs' payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userBackingAccount",
+          "docs": [
+            "User's account of the backing asset (deposits from, payouts to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userSpreadAccount",
+          "docs": [
+            "User's spread token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "spreadMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "position",
+          "docs": [
+            "User's writer position in the spread (mint, burn and redeem only;",
+            "created by the first mint)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelEmergencyWithdrawal",
+      "docs": [
+        "CancelEmergencyWithdrawal: guardian (or admin) cancels a queued withdrawal"
+      ],
+      "discriminator": [
+        52,
+        163,
+        39,
+        45,
+        54,
+        174,
+        105,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "canceller",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true
+        },
+        {
+          "name": "withdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  109,
+                  101,
+                  114,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "withdrawal.vault",
+                "account": "emergencyWithdrawal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelPending",
+      "docs": [
+        "CancelPending: admin or guardian drops a scheduled change before it applies"
+      ],
+      "discriminator": [
+        74,
+        87,
+        109,
+        242,
+        64,
+        192,
+        151,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "canceller",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "writable": true
+        },
+        {
+          "name": "pendingChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pending_change.id",
+                "account": "pendingChange"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAll",
+      "docs": [
+        "ClaimAll: after settlement, burn every redemption token held for both the",
+        "collateral and consideration legs"
+      ],
+      "discriminator": [
+        194,
+        194,
+        80,
+        194,
+        234,
+        210,
+        217,
+        90
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimNetPayoff",
+      "docs": [
+        "ClaimNetPayoff: post-settlement, burn option tokens for their netted ITM payoff"
+      ],
+      "discriminator": [
+        83,
+        160,
+        149,
+        202,
+        183,
+        106,
+        216,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimVerticalSpread",
+      "docs": [
+        "ClaimVerticalSpread: holder burns settled spread tokens for their payoff"
+      ],
+      "discriminator": [
+        123,
+        218,
+        242,
+        6,
+        103,
+        67,
+        34,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (mint, burn, redeem), holder (claim) or any cranker (settle)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shortOptionContext",
+          "docs": [
+            "Series whose intrinsic value spread tokens pay (only read)"
+          ]
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose options writers lock (pays their netted payoff at settlement)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verticalSpread",
+          "docs": [
+            "Spread PDA (created by the first `mint_vertical_spread` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shortOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "longOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadMint",
+          "docs": [
+            "Spread token mint (same decimals as the options; the spread is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "backingMint",
+          "docs": [
+            "Mint of the asset the short series' writers escrow"
+          ]
+        },
+        {
+          "name": "longEscrow",
+          "docs": [
+            "Locked long options, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadVault",
+          "docs": [
+            "Backing and settlement payoff, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the backing asset (pays the locked longs' payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userBackingAccount",
+          "docs": [
+            "User's account of the backing asset (deposits from, payouts to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userSpreadAccount",
+          "docs": [
+            "User's spread token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "spreadMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "position",
+          "docs": [
+            "User's writer position in the spread (mint, burn and redeem only;",
+            "created by the first mint)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closePosition",
+      "docs": [
+        "PsyOptions-compatible `burn`"
+      ],
+      "discriminator": [
+        123,
+        134,
+        81,
+        0,
+        49,
+        68,
+        98,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "userAuthority",
+          "signer": true
+        },
+        {
+          "name": "optionMarket",
+          "writable": true
+        },
+        {
+          "name": "optionMint",
+          "writable": true
+        },
+        {
+          "name": "optionTokenSrc",
+          "writable": true
+        },
+        {
+          "name": "writerTokenMint",
+          "writable": true
+        },
+        {
+          "name": "writerTokenSrc",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetPool",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetDest",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "underlyingAssetMint"
+        },
+        {
+          "name": "quoteAssetMint"
+        },
+        {
+          "name": "quoteAssetPool",
+          "writable": true
+        },
+        {
+          "name": "quoteAssetDest",
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; recorded into when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMarket"
+              },
+              {
+                "kind": "account",
+                "path": "userAuthority"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closePostExpiration",
+      "docs": [
+        "PsyOptions-compatible `redeem`"
+      ],
+      "discriminator": [
+        115,
+        203,
+        223,
+        108,
+        122,
+        150,
+        229,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "userAuthority",
+          "signer": true
+        },
+        {
+          "name": "optionMarket",
+          "writable": true
+        },
+        {
+          "name": "writerTokenMint",
+          "writable": true
+        },
+        {
+          "name": "writerTokenSrc",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetPool",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetDest",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "underlyingAssetMint"
+        },
+        {
+          "name": "quoteAssetMint"
+        },
+        {
+          "name": "quoteAssetPool",
+          "writable": true
+        },
+        {
+          "name": "quoteAssetDest",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeSeries",
+      "docs": [
+        "CloseSeries: permissionless; once a settled series has no tokens or margin",
+        "left, closes its vaults and OptionContext and refunds the rent to the authority"
+      ],
+      "discriminator": [
+        141,
+        153,
+        5,
+        139,
+        18,
+        10,
+        236,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "(validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "(validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSpread",
+      "docs": [
+        "CloseSpread: unlock `amount` spread longs, staying above the initial requirement"
+      ],
+      "discriminator": [
+        165,
+        32,
+        218,
+        147,
+        121,
+        46,
+        230,
+        29
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (create, close) or any cranker (settle); pays for the escrow"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "Series the position is short on margin"
+          ],
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "writer",
+          "writable": true
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose long options hedge the short (fixed by the first spread)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "spreadEscrow",
+          "docs": [
+            "Locked long options, owned by the position"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Short series' vault of the backing asset (holds the position's margin)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the same asset (pays the long leg's netted payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (receives released margin)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeVaultListing",
+      "docs": [
+        "CloseVaultListing: cancels the rest of a put vault's listing (the",
+        "manager, or anyone once it sold out)"
+      ],
+      "discriminator": [
+        186,
+        164,
+        252,
+        0,
+        7,
+        154,
+        99,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "signer": true
+        },
+        {
+          "name": "optionVault",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "underlyingMint",
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "vaultUnderlyingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "underlyingMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "listing",
+          "writable": true
+        },
+        {
+          "name": "listingEscrow",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createCombo",
+      "docs": [
+        "CreateCombo: lock `amount` call and put options (a straddle or strangle)",
+        "behind `amount` combo tokens"
+      ],
+      "discriminator": [
+        193,
+        61,
+        105,
+        35,
+        0,
+        162,
+        104,
+        101
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Holder locking legs (create) or burning combo tokens (decompose)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "callOptionContext",
+          "docs": [
+            "Call leg's series"
+          ]
+        },
+        {
+          "name": "putOptionContext",
+          "docs": [
+            "Put leg's series"
+          ]
+        },
+        {
+          "name": "combo",
+          "docs": [
+            "Combo PDA (created by the first `create_combo` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "callOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "putOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "comboMint",
+          "docs": [
+            "Combo token mint (same decimals as the legs; the combo is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combo"
+              }
+            ]
+          }
+        },
+        {
+          "name": "callOptionMint",
+          "docs": [
+            "Call leg's option mint (validated against stored value in call_option_context)"
+          ]
+        },
+        {
+          "name": "putOptionMint",
+          "docs": [
+            "Put leg's option mint (validated against stored value in put_option_context)"
+          ]
+        },
+        {
+          "name": "callEscrow",
+          "docs": [
+            "Locked call options, owned by the combo"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combo"
+              },
+              {
+                "kind": "account",
+                "path": "callOptionMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "putEscrow",
+          "docs": [
+            "Locked put options, owned by the combo"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combo"
+              },
+              {
+                "kind": "account",
+                "path": "putOptionMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCallOptionAccount",
+          "docs": [
+            "User's call option account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "callOptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userPutOptionAccount",
+          "docs": [
+            "User's put option account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "putOptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userComboAccount",
+          "docs": [
+            "User's combo token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "comboMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createOption",
+      "docs": [
+        "CreateOption: Initializes OptionContext + vaults + mints"
+      ],
+      "discriminator": [
+        226,
+        92,
+        124,
+        94,
+        113,
+        96,
+        60,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA - INITIALIZE it (create new account)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (creation rate limits)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorStats",
+          "docs": [
+            "Creator's rate-limit tracking account (created on first series)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (provided by client; must match the seed argument)",
+            "",
+            "Owned by `token_program` (SPL Token or Token-2022), like the consideration",
+            "mint; the series' own mints and vaults are created under it too."
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration/strike currency mint (provided by client; must match the seed argument)"
+          ]
+        },
+        {
+          "name": "strikeTick",
+          "docs": [
+            "strike allowed), only deserialized when governance has created it"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  114,
+                  105,
+                  107,
+                  101,
+                  95,
+                  116,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralApproval",
+          "docs": [
+            "only read while the mint allowlist is enabled"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  114,
+                  111,
+                  118,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "considerationApproval",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  114,
+                  111,
+                  118,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "(sized for the transfer hook extension when the series has one)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redemptionMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  100,
+                  101,
+                  109,
+                  112,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault PDA - INITIALIZE it"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault PDA - INITIALIZE it"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  115,
+                  105,
+                  100,
+                  101,
+                  114,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "accounts below (omitted, the series gets no token metadata until",
+            "`update_series_metadata`)"
+          ],
+          "optional": true,
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "optionMetadata",
+          "docs": [
+            "Token Metadata program CPI)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "redemptionMetadata",
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "considerationMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "strikePrice",
+          "type": "u64"
+        },
+        {
+          "name": "expiration",
+          "type": "i64"
+        },
+        {
+          "name": "optionType",
+          "type": {
+            "defined": {
+              "name": "optionType"
+            }
+          }
+        },
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "seriesConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createOptionVault",
+      "docs": [
+        "CreateOptionVault: manager opens a pooled vault writing `option_type`",
+        "series of an underlying against a quote mint, with its share mint"
+      ],
+      "discriminator": [
+        218,
+        224,
+        40,
+        50,
+        177,
+        159,
+        7,
+        112
+      ],
+      "accounts": [
+        {
+          "name": "manager",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "underlyingMint"
+        },
+        {
+          "name": "quoteMint"
+        },
+        {
+          "name": "optionVault",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "shareMint",
+          "docs": [
+            "Depositors' shares, in the escrow mint's decimals"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultUnderlyingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "underlyingMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultQuoteAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "optionType",
+          "type": {
+            "defined": {
+              "name": "optionType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSeriesBatch",
+      "docs": [
+        "CreateSeriesBatch: creates a strike ladder (one series per strike, shared",
+        "mints, expiry, type and config); per-strike PDAs in remaining_accounts"
+      ],
+      "discriminator": [
+        110,
+        126,
+        244,
+        221,
+        159,
+        26,
+        42,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (creation rate limits)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorStats",
+          "docs": [
+            "Creator's rate-limit tracking account (created on first series)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (same checks as `create_option`)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration/strike currency mint (same checks as `create_option`)"
+          ]
+        },
+        {
+          "name": "strikeTick",
+          "docs": [
+            "strike allowed), only deserialized when governance has created it"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  114,
+                  105,
+                  107,
+                  101,
+                  95,
+                  116,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralApproval",
+          "docs": [
+            "only read while the mint allowlist is enabled"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  114,
+                  111,
+                  118,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "considerationApproval",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  114,
+                  111,
+                  118,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "collateralMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "considerationMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "expiration",
+          "type": "i64"
+        },
+        {
+          "name": "optionType",
+          "type": {
+            "defined": {
+              "name": "optionType"
+            }
+          }
+        },
+        {
+          "name": "strikes",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "config",
+          "type": {
+            "defined": {
+              "name": "seriesConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSpread",
+      "docs": [
+        "CreateSpread: lock `amount` long options of a further-OTM series (same mints",
+        "and expiry) against the signer's margin short, releasing the excess margin"
+      ],
+      "discriminator": [
+        195,
+        61,
+        175,
+        46,
+        173,
+        173,
+        56,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (create, close) or any cranker (settle); pays for the escrow"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "Series the position is short on margin"
+          ],
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "writer",
+          "writable": true
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose long options hedge the short (fixed by the first spread)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "spreadEscrow",
+          "docs": [
+            "Locked long options, owned by the position"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Short series' vault of the backing asset (holds the position's margin)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the same asset (pays the long leg's netted payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (receives released margin)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "decomposeCombo",
+      "docs": [
+        "DecomposeCombo: burn `amount` combo tokens for their call and put legs"
+      ],
+      "discriminator": [
+        161,
+        188,
+        25,
+        243,
+        69,
+        33,
+        60,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Holder locking legs (create) or burning combo tokens (decompose)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "callOptionContext",
+          "docs": [
+            "Call leg's series"
+          ]
+        },
+        {
+          "name": "putOptionContext",
+          "docs": [
+            "Put leg's series"
+          ]
+        },
+        {
+          "name": "combo",
+          "docs": [
+            "Combo PDA (created by the first `create_combo` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "callOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "putOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "comboMint",
+          "docs": [
+            "Combo token mint (same decimals as the legs; the combo is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combo"
+              }
+            ]
+          }
+        },
+        {
+          "name": "callOptionMint",
+          "docs": [
+            "Call leg's option mint (validated against stored value in call_option_context)"
+          ]
+        },
+        {
+          "name": "putOptionMint",
+          "docs": [
+            "Put leg's option mint (validated against stored value in put_option_context)"
+          ]
+        },
+        {
+          "name": "callEscrow",
+          "docs": [
+            "Locked call options, owned by the combo"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combo"
+              },
+              {
+                "kind": "account",
+                "path": "callOptionMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "putEscrow",
+          "docs": [
+            "Locked put options, owned by the combo"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  109,
+                  98,
+                  111,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "combo"
+              },
+              {
+                "kind": "account",
+                "path": "putOptionMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userCallOptionAccount",
+          "docs": [
+            "User's call option account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "callOptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userPutOptionAccount",
+          "docs": [
+            "User's put option account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "putOptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userComboAccount",
+          "docs": [
+            "User's combo token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "comboMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositMargin",
+      "docs": [
+        "DepositMargin: top up any writer's margin position"
+      ],
+      "discriminator": [
+        240,
+        96,
+        57,
+        37,
+        173,
+        174,
+        158,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer, depositor or liquidator, depending on the instruction"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Margin position being changed (owner-only instructions check `writer`)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Vault of the backing asset: collateral vault for calls, consideration vault for puts"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (funds deposits, receives releases)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated (mint_on_margin only)"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositToVault",
+      "docs": [
+        "DepositToVault: between rounds, deposit the vault's escrow mint (and",
+        "the matching part of its other balance) for shares"
+      ],
+      "discriminator": [
+        18,
+        62,
+        110,
+        8,
+        26,
+        106,
+        248,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionVault"
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "underlyingMint",
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "quoteMint",
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "shareMint",
+          "writable": true,
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "vaultUnderlyingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "underlyingMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultQuoteAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userUnderlyingAccount",
+          "writable": true
+        },
+        {
+          "name": "userQuoteAccount",
+          "writable": true
+        },
+        {
+          "name": "userShareAccount",
+          "docs": [
+            "User's share account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "describeSeries",
+      "docs": [
+        "DescribeSeries: view instruction returning the series' canonical symbol",
+        "(e.g. \"BONK-28FEB25-0.04-C\"), formatted by the same code as its token metadata"
+      ],
+      "discriminator": [
+        22,
+        228,
+        76,
+        8,
+        97,
+        71,
+        7,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "optionContext"
+        }
+      ],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "enableCollateralYield",
+      "docs": [
+        "EnableCollateralYield: series authority opts a series with nothing",
+        "escrowed yet into lending through its escrow mint's venue"
+      ],
+      "discriminator": [
+        180,
+        135,
+        3,
+        6,
+        181,
+        86,
+        28,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Pays for the share vault"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "yieldVenue",
+          "docs": [
+            "Approved venue of the series' escrow mint"
+          ]
+        },
+        {
+          "name": "shareMint"
+        },
+        {
+          "name": "shareVault",
+          "docs": [
+            "Series-owned account holding the venue's shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  121,
+                  105,
+                  101,
+                  108,
+                  100,
+                  95,
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeEmergencyWithdrawal",
+      "docs": [
+        "ExecuteEmergencyWithdrawal: admin executes a queued withdrawal once the timelock elapsed"
+      ],
+      "discriminator": [
+        6,
+        138,
+        50,
+        154,
+        178,
+        103,
+        180,
+        192
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "withdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  109,
+                  101,
+                  114,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext",
+          "writable": true,
+          "relations": [
+            "withdrawal"
+          ]
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "relations": [
+            "withdrawal"
+          ]
+        },
+        {
+          "name": "destination",
+          "writable": true,
+          "relations": [
+            "withdrawal"
+          ]
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "exercise",
+      "docs": [
+        "Exercise: burn options, pay strike → receive collateral"
+      ],
+      "discriminator": [
+        144,
+        79,
+        103,
+        64,
+        241,
+        78,
+        80,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exerciseAndSell",
+      "docs": [
+        "ExerciseAndSell: flash `exercise` of calls, selling `sell_amount` of the",
+        "collateral paid out into a marketplace bid before the strike is collected"
+      ],
+      "discriminator": [
+        83,
+        241,
+        188,
+        18,
+        83,
+        103,
+        201,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "option",
+          "accounts": [
+            {
+              "name": "user",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "optionContext",
+              "docs": [
+                "The OptionContext PDA (client calculates and sends this)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "protocolConfig",
+              "docs": [
+                "Protocol config (mint and exercise fees)"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      111,
+                      116,
+                      111,
+                      99,
+                      111,
+                      108,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "collateralMint",
+              "docs": [
+                "Collateral mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "considerationMint",
+              "docs": [
+                "Consideration mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "optionMint",
+              "docs": [
+                "Option mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "redemptionMint",
+              "docs": [
+                "Redemption mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "collateralVault",
+              "docs": [
+                "Collateral vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "considerationVault",
+              "docs": [
+                "Consideration vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "optionOwner",
+              "docs": [
+                "may send option tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "redemptionOwner",
+              "docs": [
+                "may send redemption tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "userCollateralAccount",
+              "docs": [
+                "User's collateral token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "collateralMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userConsiderationAccount",
+              "docs": [
+                "User's consideration token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userOptionAccount",
+              "docs": [
+                "Option token account of `option_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "optionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userRedemptionAccount",
+              "docs": [
+                "Redemption token account of `redemption_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "redemptionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "redemptionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "writerPosition",
+              "docs": [
+                "User's writer position; mint and burn record into it when passed",
+                "(trailing and optional, so clients that don't track positions omit it)"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      114,
+                      105,
+                      116,
+                      101,
+                      114,
+                      95,
+                      112,
+                      111,
+                      115,
+                      105,
+                      116,
+                      105,
+                      111,
+                      110
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionContext"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "feeVault",
+              "docs": [
+                "Protocol fee account of the charged mint (escrow mint on mint,",
+                "consideration on exercise); only needed while that fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeStats",
+              "docs": [
+                "User's volume in the consideration mint; mint and exercise record into",
+                "it and discount the fee by the tier it reaches when passed"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      101,
+                      101,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "referrerAccount",
+              "docs": [
+                "Referrer's token account of the charged mint; with their stats, takes",
+                "the referral share of the fee"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "referralStats",
+              "docs": [
+                "Referrer's earnings in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceFund",
+              "docs": [
+                "Insurance fund of the charged mint; only needed while the insurance",
+                "share of the fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceVault",
+              "docs": [
+                "Token account of the insurance fund in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "credentialAccount",
+              "docs": [
+                "User's token account of the series' gate mint; mint and exercise of a",
+                "gated series require it"
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "market",
+          "docs": [
+            "the marketplace checks the bid belongs to it"
+          ]
+        },
+        {
+          "name": "bid",
+          "writable": true
+        },
+        {
+          "name": "bidEscrow",
+          "writable": true
+        },
+        {
+          "name": "bidderCollateralAccount",
+          "docs": [
+            "program checks its mint"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "sellAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exerciseCompact",
+      "docs": [
+        "ExerciseCompact: `exercise` over `ExerciseAccounts` (no short leg)"
+      ],
+      "discriminator": [
+        161,
+        169,
+        110,
+        101,
+        253,
+        152,
+        164,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "considerationMint"
+        },
+        {
+          "name": "optionMint",
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "writable": true
+        },
+        {
+          "name": "userCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "userConsiderationAccount",
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Same optional fee and credential accounts as `OptionContext`"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exerciseNative",
+      "docs": [
+        "ExerciseNative: `exercise` for WSOL series, delivering up to `lamports`",
+        "wrapped and receiving the payout and the rest as lamports"
+      ],
+      "discriminator": [
+        137,
+        146,
+        227,
+        48,
+        184,
+        176,
+        60,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exerciseOption",
+      "docs": [
+        "PsyOptions-compatible `exercise`"
+      ],
+      "discriminator": [
+        231,
+        98,
+        131,
+        183,
+        245,
+        93,
+        122,
+        48
+      ],
+      "accounts": [
+        {
+          "name": "userAuthority",
+          "signer": true
+        },
+        {
+          "name": "optionAuthority",
+          "signer": true
+        },
+        {
+          "name": "optionMarket",
+          "writable": true
+        },
+        {
+          "name": "optionMint",
+          "writable": true
+        },
+        {
+          "name": "exerciserOptionTokenSrc",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetPool",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetDest",
+          "writable": true
+        },
+        {
+          "name": "quoteAssetPool",
+          "writable": true
+        },
+        {
+          "name": "quoteAssetSrc",
+          "writable": true
+        },
+        {
+          "name": "feeOwner"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "underlyingAssetMint"
+        },
+        {
+          "name": "quoteAssetMint"
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Same optional fee and credential accounts as `OptionContext`"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "quoteAssetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "exitPosition",
+      "docs": [
+        "ExitPosition: unwind the user's option and redemption tokens in one transaction",
+        "(paired burn and early claim before settlement, payoff claim and redeem after)"
+      ],
+      "discriminator": [
+        130,
+        193,
+        80,
+        25,
+        78,
+        132,
+        189,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeSeries",
+      "docs": [
+        "FreezeSeries: admin or guardian permanently halts mint and exercise in a",
+        "compromised series; it then settles with every option worthless and",
+        "redeems pro rata right away, regardless of expiry"
+      ],
+      "discriminator": [
+        18,
+        56,
+        150,
+        165,
+        254,
+        12,
+        244,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConfig",
+      "docs": [
+        "InitializeConfig: one-time ProtocolConfig creation by the upgrade authority"
+      ],
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "lendEscrow",
+      "docs": [
+        "LendEscrow: series authority lends idle escrow to the series' venue"
+      ],
+      "discriminator": [
+        174,
+        160,
+        96,
+        81,
+        141,
+        151,
+        219,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Series authority when lending; anyone when unwinding"
+          ],
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "yieldVenue"
+        },
+        {
+          "name": "escrowVault",
+          "docs": [
+            "Vault of the asset writers escrow (collateral for calls, consideration",
+            "for puts)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "shareVault",
+          "docs": [
+            "Series-owned account holding the venue's shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  121,
+                  105,
+                  101,
+                  108,
+                  100,
+                  95,
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lendingProgram"
+        },
+        {
+          "name": "reserve",
+          "writable": true
+        },
+        {
+          "name": "reserveLiquiditySupply",
+          "writable": true
+        },
+        {
+          "name": "shareMint",
+          "writable": true
+        },
+        {
+          "name": "lendingMarket"
+        },
+        {
+          "name": "lendingMarketAuthority"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "liquidate",
+      "docs": [
+        "Liquidate: keepers burn `amount` options against a position below maintenance",
+        "margin and seize its pro-rata margin"
+      ],
+      "discriminator": [
+        223,
+        179,
+        226,
+        125,
+        48,
+        46,
+        39,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer, depositor or liquidator, depending on the instruction"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Margin position being changed (owner-only instructions check `writer`)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Vault of the backing asset: collateral vault for calls, consideration vault for puts"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (funds deposits, receives releases)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated (mint_on_margin only)"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "listVaultCollateral",
+      "docs": [
+        "ListVaultCollateral: a put vault's manager lists assigned underlying",
+        "on the marketplace between rounds"
+      ],
+      "discriminator": [
+        212,
+        212,
+        86,
+        125,
+        100,
+        135,
+        18,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "manager",
+          "signer": true,
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "optionVault",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "underlyingMint",
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "vaultUnderlyingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "underlyingMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "market",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migratePsyPosition",
+      "docs": [
+        "MigratePsyPosition: close a paired PsyOptions position into this series' vault",
+        "and mint the equivalent option + redemption pair, without unwinding"
+      ],
+      "discriminator": [
+        149,
+        75,
+        47,
+        85,
+        46,
+        98,
+        23,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "Destination series (must describe the same contract as the PsyOptions market)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault: receives the underlying released by PsyOptions"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account (receives the new LONG leg)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "User's redemption token account (receives the new SHORT leg)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "psyOptionMarket"
+        },
+        {
+          "name": "psyOptionMint",
+          "writable": true
+        },
+        {
+          "name": "psyWriterMint",
+          "writable": true
+        },
+        {
+          "name": "psyUnderlyingPool",
+          "writable": true
+        },
+        {
+          "name": "userPsyOptionAccount",
+          "docs": [
+            "User's PsyOptions option token account (burned by close_position)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userPsyWriterAccount",
+          "docs": [
+            "User's PsyOptions writer token account (burned by close_position)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "psyProgram",
+          "address": "R2y9ip6mxmWUj4pt54jP2hz2dgvMozy9VTSwMWE7evs"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated"
+          ],
+          "optional": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (protocol-wide pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "contracts",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateSeries",
+      "docs": [
+        "MigrateSeries: permissionless, grows a series to the current account layout",
+        "and steps its version up"
+      ],
+      "discriminator": [
+        6,
+        62,
+        130,
+        156,
+        97,
+        251,
+        198,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "checked here, discriminator in the handler"
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Legacy series only: the mints and vaults the series stores, which its",
+            "strike units and vault ledger are backfilled from"
+          ],
+          "optional": true
+        },
+        {
+          "name": "considerationMint",
+          "optional": true
+        },
+        {
+          "name": "collateralVault",
+          "optional": true
+        },
+        {
+          "name": "considerationVault",
+          "optional": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Legacy series only: names the protocol admin, who becomes the series",
+            "authority"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mint",
+      "docs": [
+        "Mint: deposit collateral → mint option + redemption tokens 1:1"
+      ],
+      "discriminator": [
+        51,
+        57,
+        225,
+        47,
+        182,
+        146,
+        137,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintAndList",
+      "docs": [
+        "MintAndList: `mint`, then list the minted options on a marketplace market",
+        "at `price` through `place_order` (an ask, in one transaction)"
+      ],
+      "discriminator": [
+        200,
+        161,
+        243,
+        36,
+        250,
+        45,
+        242,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "option",
+          "accounts": [
+            {
+              "name": "user",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "optionContext",
+              "docs": [
+                "The OptionContext PDA (client calculates and sends this)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "protocolConfig",
+              "docs": [
+                "Protocol config (mint and exercise fees)"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      111,
+                      116,
+                      111,
+                      99,
+                      111,
+                      108,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "collateralMint",
+              "docs": [
+                "Collateral mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "considerationMint",
+              "docs": [
+                "Consideration mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "optionMint",
+              "docs": [
+                "Option mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "redemptionMint",
+              "docs": [
+                "Redemption mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "collateralVault",
+              "docs": [
+                "Collateral vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "considerationVault",
+              "docs": [
+                "Consideration vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "optionOwner",
+              "docs": [
+                "may send option tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "redemptionOwner",
+              "docs": [
+                "may send redemption tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "userCollateralAccount",
+              "docs": [
+                "User's collateral token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "collateralMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userConsiderationAccount",
+              "docs": [
+                "User's consideration token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userOptionAccount",
+              "docs": [
+                "Option token account of `option_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "optionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userRedemptionAccount",
+              "docs": [
+                "Redemption token account of `redemption_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "redemptionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "redemptionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "writerPosition",
+              "docs": [
+                "User's writer position; mint and burn record into it when passed",
+                "(trailing and optional, so clients that don't track positions omit it)"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      114,
+                      105,
+                      116,
+                      101,
+                      114,
+                      95,
+                      112,
+                      111,
+                      115,
+                      105,
+                      116,
+                      105,
+                      111,
+                      110
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionContext"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "feeVault",
+              "docs": [
+                "Protocol fee account of the charged mint (escrow mint on mint,",
+                "consideration on exercise); only needed while that fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeStats",
+              "docs": [
+                "User's volume in the consideration mint; mint and exercise record into",
+                "it and discount the fee by the tier it reaches when passed"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      101,
+                      101,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "referrerAccount",
+              "docs": [
+                "Referrer's token account of the charged mint; with their stats, takes",
+                "the referral share of the fee"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "referralStats",
+              "docs": [
+                "Referrer's earnings in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceFund",
+              "docs": [
+                "Insurance fund of the charged mint; only needed while the insurance",
+                "share of the fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceVault",
+              "docs": [
+                "Token account of the insurance fund in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "credentialAccount",
+              "docs": [
+                "User's token account of the series' gate mint; mint and exercise of a",
+                "gated series require it"
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "market",
+          "docs": [
+            "its base mint is the series' option mint"
+          ],
+          "writable": true
+        },
+        {
+          "name": "order",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintCompact",
+      "docs": [
+        "MintCompact: `mint` over `MintAccounts` (existing token accounts only,",
+        "no leg owners)"
+      ],
+      "discriminator": [
+        107,
+        56,
+        144,
+        211,
+        170,
+        16,
+        242,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "considerationMint"
+        },
+        {
+          "name": "optionMint",
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "writable": true
+        },
+        {
+          "name": "userCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "userConsiderationAccount",
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Receives the long leg (any owner)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Receives the short leg (any owner)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Same optional trailing accounts as `OptionContext`"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintNative",
+      "docs": [
+        "MintNative: `mint` for WSOL series paying in lamports (up to",
+        "`lamports` wrapped, the rest returned unwrapped)"
+      ],
+      "discriminator": [
+        197,
+        115,
+        187,
+        9,
+        178,
+        255,
+        94,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintOnMargin",
+      "docs": [
+        "MintOnMargin: write `amount` options (long leg only) against the signer's",
+        "writer position, adding `deposit` margin; must meet the initial requirement"
+      ],
+      "discriminator": [
+        141,
+        35,
+        234,
+        210,
+        13,
+        183,
+        224,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer, depositor or liquidator, depending on the instruction"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Margin position being changed (owner-only instructions check `writer`)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Vault of the backing asset: collateral vault for calls, consideration vault for puts"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (funds deposits, receives releases)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated (mint_on_margin only)"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintOption",
+      "docs": [
+        "PsyOptions-compatible `mint`"
+      ],
+      "discriminator": [
+        76,
+        112,
+        32,
+        89,
+        147,
+        85,
+        222,
+        43
+      ],
+      "accounts": [
+        {
+          "name": "userAuthority",
+          "signer": true
+        },
+        {
+          "name": "underlyingAssetMint"
+        },
+        {
+          "name": "underlyingAssetPool",
+          "writable": true
+        },
+        {
+          "name": "underlyingAssetSrc",
+          "writable": true
+        },
+        {
+          "name": "optionMint",
+          "writable": true
+        },
+        {
+          "name": "mintedOptionDest",
+          "docs": [
+            "Receives the long leg (any owner)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "writerTokenMint",
+          "writable": true
+        },
+        {
+          "name": "mintedWriterTokenDest",
+          "docs": [
+            "Receives the short leg (any owner)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionMarket",
+          "writable": true
+        },
+        {
+          "name": "feeOwner"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "quoteAssetMint"
+        },
+        {
+          "name": "quoteAssetPool",
+          "writable": true
+        },
+        {
+          "name": "quoteAssetSrc",
+          "docs": [
+            "Pays the escrow of series that lock consideration (puts, binaries, forwards)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Same optional trailing accounts as `OptionContext`"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMarket"
+              },
+              {
+                "kind": "account",
+                "path": "userAuthority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "quoteAssetMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintVerticalSpread",
+      "docs": [
+        "MintVerticalSpread: lock `amount` further-OTM longs plus the strike",
+        "difference and mint `amount` spread tokens paying the short series' intrinsic value"
+      ],
+      "discriminator": [
+        77,
+        224,
+        224,
+        102,
+        20,
+        21,
+        50,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (mint, burn, redeem), holder (claim) or any cranker (settle)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shortOptionContext",
+          "docs": [
+            "Series whose intrinsic value spread tokens pay (only read)"
+          ]
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose options writers lock (pays their netted payoff at settlement)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verticalSpread",
+          "docs": [
+            "Spread PDA (created by the first `mint_vertical_spread` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shortOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "longOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadMint",
+          "docs": [
+            "Spread token mint (same decimals as the options; the spread is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "backingMint",
+          "docs": [
+            "Mint of the asset the short series' writers escrow"
+          ]
+        },
+        {
+          "name": "longEscrow",
+          "docs": [
+            "Locked long options, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadVault",
+          "docs": [
+            "Backing and settlement payoff, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the backing asset (pays the locked longs' payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userBackingAccount",
+          "docs": [
+            "User's account of the backing asset (deposits from, payouts to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userSpreadAccount",
+          "docs": [
+            "User's spread token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "spreadMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "position",
+          "docs": [
+            "User's writer position in the spread (mint, burn and redeem only;",
+            "created by the first mint)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintWithSubstitute",
+      "docs": [
+        "MintWithSubstitute: mint against a governance-approved substitute for the",
+        "escrow mint, held in the series' vault for that substitute"
+      ],
+      "discriminator": [
+        152,
+        68,
+        205,
+        160,
+        137,
+        30,
+        62,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "escrowMint",
+          "docs": [
+            "Mint writers normally escrow (collateral for calls, consideration otherwise)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context;",
+            "fee tiers count volume in it)"
+          ]
+        },
+        {
+          "name": "collateralBasket",
+          "docs": [
+            "Substitutes governance accepts for the escrow mint"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  98,
+                  97,
+                  115,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "escrowMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (feature switches)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "substituteMint",
+          "docs": [
+            "Substitute deposited at par (same decimals as the escrow mint)"
+          ]
+        },
+        {
+          "name": "basketVault",
+          "docs": [
+            "The series' vault for this substitute (created on first deposit)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  97,
+                  115,
+                  107,
+                  101,
+                  116,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "substituteMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userSubstituteAccount",
+          "writable": true
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "User's redemption token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position, recorded into when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the substitute mint; only needed while the",
+            "mint fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the series' consideration mint, recorded into when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "observePrice",
+      "docs": [
+        "ObservePrice: permissionless crank recording a guarded oracle reading",
+        "The stored reading is the deviation reference for later oracle consumers"
+      ],
+      "discriminator": [
+        147,
+        178,
+        4,
+        21,
+        14,
+        213,
+        81,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openFeeStats",
+      "docs": [
+        "OpenFeeStats: opt in to recording this signer's mint and exercise volume in",
+        "a consideration mint, which earns fee tier discounts"
+      ],
+      "discriminator": [
+        173,
+        83,
+        76,
+        163,
+        109,
+        160,
+        147,
+        220
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "considerationMint"
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openInsuranceFund",
+      "docs": [
+        "OpenInsuranceFund: admin creates the insurance fund of a mint, which fees",
+        "in that mint then pay their insurance share into"
+      ],
+      "discriminator": [
+        106,
+        154,
+        155,
+        22,
+        165,
+        21,
+        203,
+        138
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "insuranceFund",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openOpenbookMarket",
+      "docs": [
+        "OpenOpenbookMarket: the series authority creates an OpenBook v2 market",
+        "for the series' option token"
+      ],
+      "discriminator": [
+        42,
+        119,
+        228,
+        191,
+        35,
+        99,
+        27,
+        226
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Pays for the market and becomes its fee and close admin"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "optionMint",
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "openbookProgram",
+          "address": "opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb"
+        },
+        {
+          "name": "market",
+          "docs": [
+            "New market account (a fresh keypair); created by OpenBook"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "marketAuthority"
+        },
+        {
+          "name": "bids",
+          "writable": true
+        },
+        {
+          "name": "asks",
+          "writable": true
+        },
+        {
+          "name": "eventHeap",
+          "writable": true
+        },
+        {
+          "name": "marketBaseVault",
+          "writable": true
+        },
+        {
+          "name": "marketQuoteVault",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "openbookMarketParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "openReferralStats",
+      "docs": [
+        "OpenReferralStats: a referrer opts in to earning the referral share of fees",
+        "paid in a mint, recorded in their stats"
+      ],
+      "discriminator": [
+        154,
+        170,
+        17,
+        154,
+        186,
+        186,
+        211,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "referrer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "referralStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  102,
+                  101,
+                  114,
+                  114,
+                  97,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "referrer"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openSeriesMarket",
+      "docs": [
+        "OpenSeriesMarket: series authority creates (or adopts) the marketplace",
+        "market of the option token against the consideration and records it"
+      ],
+      "discriminator": [
+        184,
+        199,
+        94,
+        62,
+        29,
+        72,
+        237,
+        124
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Pays for the market and becomes its authority"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "optionMint"
+        },
+        {
+          "name": "considerationMint"
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "market",
+          "docs": [
+            "created by the marketplace unless someone already opened it"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  114,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "marketplaceProgram"
+            }
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openWriterPosition",
+      "docs": [
+        "OpenWriterPosition: opt in to recording this signer's written amount in a series",
+        "(mint and burn update it when the position account is passed)"
+      ],
+      "discriminator": [
+        236,
+        221,
+        104,
+        102,
+        83,
+        178,
+        224,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "writer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "writerPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "payInsurance",
+      "docs": [
+        "PayInsurance: admin (or the governance treasury holding the role) covers a",
+        "series vault's shortfall against its accounting from the insurance fund"
+      ],
+      "discriminator": [
+        144,
+        170,
+        105,
+        32,
+        236,
+        86,
+        72,
+        139
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "insuranceFund",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  115,
+                  117,
+                  114,
+                  97,
+                  110,
+                  99,
+                  101,
+                  95,
+                  102,
+                  117,
+                  110,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the fund (any token account it owns)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Collateral or consideration vault of the series"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposeConfigAdmin",
+      "docs": [
+        "ProposeConfigAdmin: current admin nominates a successor (step 1 of 2)"
+      ],
+      "discriminator": [
+        149,
+        46,
+        216,
+        17,
+        58,
+        232,
+        159,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "proposeGovernanceAdmin",
+      "docs": [
+        "ProposeGovernanceAdmin: current admin nominates a Realms DAO (its governance's",
+        "native treasury) as successor (step 1 of 2)"
+      ],
+      "discriminator": [
+        61,
+        149,
+        51,
+        77,
+        42,
+        88,
+        22,
+        106
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "governance",
+          "docs": [
+            "its owner is the governance program the treasury is derived under"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeSeriesAuthority",
+      "docs": [
+        "ProposeSeriesAuthority: current authority nominates a successor (step 1 of 2)"
+      ],
+      "discriminator": [
+        130,
+        4,
+        17,
+        154,
+        125,
+        93,
+        143,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "queueEmergencyWithdrawal",
+      "docs": [
+        "QueueEmergencyWithdrawal: admin queues a timelocked vault withdrawal (justification hash)"
+      ],
+      "discriminator": [
+        114,
+        234,
+        215,
+        152,
+        105,
+        0,
+        90,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "vault"
+        },
+        {
+          "name": "destination"
+        },
+        {
+          "name": "withdrawal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  109,
+                  101,
+                  114,
+                  103,
+                  101,
+                  110,
+                  99,
+                  121,
+                  95,
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "justificationHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordKnockout",
+      "docs": [
+        "RecordKnockout: permissionless crank killing a knock-out series whose",
+        "barrier the oracle shows breached; it then settles with every option worthless"
+      ],
+      "discriminator": [
+        49,
+        10,
+        44,
+        243,
+        245,
+        197,
+        224,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recoverUnclaimed",
+      "docs": [
+        "RecoverUnclaimed: permissionless; once the unclaimed timeout after expiry has",
+        "passed, moves a series' remaining vault funds to the treasury and closes the vaults"
+      ],
+      "discriminator": [
+        145,
+        250,
+        67,
+        46,
+        166,
+        179,
+        123,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasuryCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryConsiderationAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeem",
+      "docs": [
+        "Redeem: post-expiry pro-rata of collateral + consideration by burning redemption tokens"
+      ],
+      "discriminator": [
+        184,
+        12,
+        86,
+        149,
+        70,
+        196,
+        97,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemCompact",
+      "docs": [
+        "RedeemCompact: `redeem` over `RedeemAccounts` (no long leg or fee accounts)"
+      ],
+      "discriminator": [
+        43,
+        28,
+        218,
+        98,
+        55,
+        151,
+        80,
+        143
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "considerationMint"
+        },
+        {
+          "name": "redemptionMint",
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "writable": true
+        },
+        {
+          "name": "userCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "userConsiderationAccount",
+          "writable": true
+        },
+        {
+          "name": "userRedemptionAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemConsideration",
+      "docs": [
+        "Allows SHORT token holders to burn redemption tokens for exercise proceeds before expiry",
+        "Greek.fi compliance: Key capital efficiency feature"
+      ],
+      "discriminator": [
+        54,
+        82,
+        154,
+        89,
+        31,
+        206,
+        139,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemExercisedShare",
+      "docs": [
+        "RedeemExercisedShare: burn only the exercised fraction of `amount` redemption tokens",
+        "for its proceeds, keeping the collateral-backed remainder"
+      ],
+      "discriminator": [
+        186,
+        181,
+        102,
+        129,
+        5,
+        248,
+        108,
+        134
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemNative",
+      "docs": [
+        "RedeemNative: `redeem` for WSOL series, the WSOL payout as lamports"
+      ],
+      "discriminator": [
+        229,
+        5,
+        196,
+        226,
+        95,
+        109,
+        96,
+        54
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "The OptionContext PDA (client calculates and sends this)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (mint and exercise fees)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "optionOwner",
+          "docs": [
+            "may send option tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "redemptionOwner",
+          "docs": [
+            "may send redemption tokens to anyone (the token program checks burns)"
+          ]
+        },
+        {
+          "name": "userCollateralAccount",
+          "docs": [
+            "User's collateral token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userConsiderationAccount",
+          "docs": [
+            "User's consideration token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "Option token account of `option_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "optionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userRedemptionAccount",
+          "docs": [
+            "Redemption token account of `redemption_owner` (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "redemptionOwner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "redemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "User's writer position; mint and burn record into it when passed",
+            "(trailing and optional, so clients that don't track positions omit it)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Protocol fee account of the charged mint (escrow mint on mint,",
+            "consideration on exercise); only needed while that fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "docs": [
+            "User's volume in the consideration mint; mint and exercise record into",
+            "it and discount the fee by the tier it reaches when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrerAccount",
+          "docs": [
+            "Referrer's token account of the charged mint; with their stats, takes",
+            "the referral share of the fee"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "referralStats",
+          "docs": [
+            "Referrer's earnings in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceFund",
+          "docs": [
+            "Insurance fund of the charged mint; only needed while the insurance",
+            "share of the fee is non-zero"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "insuranceVault",
+          "docs": [
+            "Token account of the insurance fund in the charged mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; mint and exercise of a",
+            "gated series require it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemVerticalSpread",
+      "docs": [
+        "RedeemVerticalSpread: writer takes their share of the settled spread's remainder"
+      ],
+      "discriminator": [
+        43,
+        61,
+        38,
+        106,
+        240,
+        201,
+        218,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (mint, burn, redeem), holder (claim) or any cranker (settle)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shortOptionContext",
+          "docs": [
+            "Series whose intrinsic value spread tokens pay (only read)"
+          ]
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose options writers lock (pays their netted payoff at settlement)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verticalSpread",
+          "docs": [
+            "Spread PDA (created by the first `mint_vertical_spread` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shortOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "longOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadMint",
+          "docs": [
+            "Spread token mint (same decimals as the options; the spread is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "backingMint",
+          "docs": [
+            "Mint of the asset the short series' writers escrow"
+          ]
+        },
+        {
+          "name": "longEscrow",
+          "docs": [
+            "Locked long options, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadVault",
+          "docs": [
+            "Backing and settlement payoff, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the backing asset (pays the locked longs' payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userBackingAccount",
+          "docs": [
+            "User's account of the backing asset (deposits from, payouts to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userSpreadAccount",
+          "docs": [
+            "User's spread token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "spreadMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "position",
+          "docs": [
+            "User's writer position in the spread (mint, burn and redeem only;",
+            "created by the first mint)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resetOracleReference",
+      "docs": [
+        "ResetOracleReference: admin clears a series' deviation reference after a",
+        "genuine move past the threshold, so guarded reads resume at once"
+      ],
+      "discriminator": [
+        66,
+        13,
+        223,
+        147,
+        60,
+        70,
+        185,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rollPosition",
+      "docs": [
+        "RollPosition: burn `amount` pairs and re-mint them at the same strike",
+        "with a later expiry, moving the escrow between vaults"
+      ],
+      "discriminator": [
+        42,
+        161,
+        126,
+        203,
+        120,
+        83,
+        208,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "fromOptionContext",
+          "docs": [
+            "Series the pairs are burned in"
+          ],
+          "writable": true
+        },
+        {
+          "name": "fromOptionMint",
+          "docs": [
+            "Option mint (validated against stored value in from_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "fromRedemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in from_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "fromVault",
+          "docs": [
+            "Vault holding from_option_context's escrow (collateral for calls,",
+            "consideration otherwise)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "toOptionContext",
+          "docs": [
+            "Series the pairs are minted in (same mints, strike and type; later expiry)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "toOptionMint",
+          "docs": [
+            "Option mint (validated against stored value in to_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "toRedemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in to_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "toVault",
+          "docs": [
+            "Vault holding to_option_context's escrow"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrowMint",
+          "docs": [
+            "Mint of the escrowed asset (shared by both series)"
+          ]
+        },
+        {
+          "name": "userEscrowAccount",
+          "docs": [
+            "User's account of the escrowed asset (covers rounding differences)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userFromOptionAccount",
+          "docs": [
+            "User's option tokens in the expiring series (burned)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userFromRedemptionAccount",
+          "docs": [
+            "User's redemption tokens in the expiring series (burned)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userToOptionAccount",
+          "docs": [
+            "User's option token account in the target series (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "toOptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userToRedemptionAccount",
+          "docs": [
+            "User's redemption token account in the target series (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "toRedemptionMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "fromWriterPosition",
+          "docs": [
+            "User's writer position in the expiring series (records the burn when passed)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "fromOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "toWriterPosition",
+          "docs": [
+            "User's writer position in the target series (records the mint when passed)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "toOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the target series' gate mint; required",
+            "when that series is gated"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "scheduleChange",
+      "docs": [
+        "ScheduleChange: admin queues a fee, allowlist, creation bond or rate limit",
+        "change under `id`; it can be applied once the timelock delay has passed"
+      ],
+      "discriminator": [
+        58,
+        73,
+        61,
+        73,
+        6,
+        51,
+        136,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingChange",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  104,
+                  97,
+                  110,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "id",
+          "type": "u64"
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": {
+              "name": "parameterChange"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "seedOpenbookMarket",
+      "docs": [
+        "SeedOpenbookMarket: `mint` plus an OpenBook v2 ask of the minted options"
+      ],
+      "discriminator": [
+        97,
+        21,
+        154,
+        8,
+        42,
+        119,
+        141,
+        194
+      ],
+      "accounts": [
+        {
+          "name": "option",
+          "accounts": [
+            {
+              "name": "user",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "optionContext",
+              "docs": [
+                "The OptionContext PDA (client calculates and sends this)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "protocolConfig",
+              "docs": [
+                "Protocol config (mint and exercise fees)"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      111,
+                      116,
+                      111,
+                      99,
+                      111,
+                      108,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "collateralMint",
+              "docs": [
+                "Collateral mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "considerationMint",
+              "docs": [
+                "Consideration mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "optionMint",
+              "docs": [
+                "Option mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "redemptionMint",
+              "docs": [
+                "Redemption mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "collateralVault",
+              "docs": [
+                "Collateral vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "considerationVault",
+              "docs": [
+                "Consideration vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "optionOwner",
+              "docs": [
+                "may send option tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "redemptionOwner",
+              "docs": [
+                "may send redemption tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "userCollateralAccount",
+              "docs": [
+                "User's collateral token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "collateralMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userConsiderationAccount",
+              "docs": [
+                "User's consideration token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userOptionAccount",
+              "docs": [
+                "Option token account of `option_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "optionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userRedemptionAccount",
+              "docs": [
+                "Redemption token account of `redemption_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "redemptionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "redemptionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "writerPosition",
+              "docs": [
+                "User's writer position; mint and burn record into it when passed",
+                "(trailing and optional, so clients that don't track positions omit it)"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      114,
+                      105,
+                      116,
+                      101,
+                      114,
+                      95,
+                      112,
+                      111,
+                      115,
+                      105,
+                      116,
+                      105,
+                      111,
+                      110
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionContext"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "feeVault",
+              "docs": [
+                "Protocol fee account of the charged mint (escrow mint on mint,",
+                "consideration on exercise); only needed while that fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeStats",
+              "docs": [
+                "User's volume in the consideration mint; mint and exercise record into",
+                "it and discount the fee by the tier it reaches when passed"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      101,
+                      101,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "referrerAccount",
+              "docs": [
+                "Referrer's token account of the charged mint; with their stats, takes",
+                "the referral share of the fee"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "referralStats",
+              "docs": [
+                "Referrer's earnings in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceFund",
+              "docs": [
+                "Insurance fund of the charged mint; only needed while the insurance",
+                "share of the fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceVault",
+              "docs": [
+                "Token account of the insurance fund in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "credentialAccount",
+              "docs": [
+                "User's token account of the series' gate mint; mint and exercise of a",
+                "gated series require it"
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "openbookProgram",
+          "address": "opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb"
+        },
+        {
+          "name": "openOrdersAccount",
+          "writable": true
+        },
+        {
+          "name": "market",
+          "docs": [
+            "vault, so a market of another token refuses the ask"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bids",
+          "writable": true
+        },
+        {
+          "name": "asks",
+          "writable": true
+        },
+        {
+          "name": "eventHeap",
+          "writable": true
+        },
+        {
+          "name": "marketBaseVault",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "priceLots",
+          "type": "i64"
+        },
+        {
+          "name": "baseLots",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setApprovedMint",
+      "docs": [
+        "SetApprovedMint: admin approves or revokes a mint for new series' collateral",
+        "and consideration (checked while the mint allowlist is enabled)"
+      ],
+      "discriminator": [
+        211,
+        158,
+        35,
+        31,
+        185,
+        67,
+        75,
+        141
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "approvedMint",
+          "docs": [
+            "Approval of the mint (created on first update)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  112,
+                  112,
+                  114,
+                  111,
+                  118,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "approved",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setCollateralBasket",
+      "docs": [
+        "SetCollateralBasket: admin sets the substitutes basket series accept in",
+        "place of an escrow mint (an empty list stops new substitute deposits)"
+      ],
+      "discriminator": [
+        76,
+        238,
+        162,
+        37,
+        122,
+        28,
+        160,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseMint"
+        },
+        {
+          "name": "collateralBasket",
+          "docs": [
+            "Basket of the mint (created on first update)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  98,
+                  97,
+                  115,
+                  107,
+                  101,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "baseMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "mints",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCreationBond",
+      "docs": [
+        "SetCreationBond: admin sets the lamports locked in each new series until it",
+        "is closed (0 disables)"
+      ],
+      "discriminator": [
+        113,
+        17,
+        170,
+        60,
+        31,
+        168,
+        20,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "creationBond",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setExpiryGrid",
+      "docs": [
+        "SetExpiryGrid: admin restricts new expirations to `offset + k * interval`",
+        "(0 interval disables) and sets the creators exempt from it"
+      ],
+      "discriminator": [
+        250,
+        113,
+        159,
+        182,
+        36,
+        248,
+        170,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "interval",
+          "type": "i64"
+        },
+        {
+          "name": "offset",
+          "type": "i64"
+        },
+        {
+          "name": "exempt",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setExpiryLimits",
+      "docs": [
+        "SetExpiryLimits: admin bounds the time to expiration of new series",
+        "(0 disables either limit)"
+      ],
+      "discriminator": [
+        195,
+        129,
+        12,
+        128,
+        205,
+        152,
+        150,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "minTimeToExpiry",
+          "type": "i64"
+        },
+        {
+          "name": "maxTimeToExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setFeatures",
+      "docs": [
+        "SetFeatures: admin switches off protocol features (`ProtocolConfig::FEATURE_*`",
+        "bits); new series and substitute deposits using them are refused"
+      ],
+      "discriminator": [
+        156,
+        239,
+        90,
+        236,
+        2,
+        45,
+        41,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "disabledFeatures",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setFeeExempt",
+      "docs": [
+        "SetFeeExempt: admin replaces the accounts (e.g. market makers) that pay",
+        "no mint or exercise fee"
+      ],
+      "discriminator": [
+        224,
+        228,
+        88,
+        87,
+        69,
+        164,
+        109,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "accounts",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeeTiers",
+      "docs": [
+        "SetFeeTiers: admin replaces the volume tiers that discount mint and",
+        "exercise fees (empty removes every discount)"
+      ],
+      "discriminator": [
+        162,
+        35,
+        72,
+        250,
+        39,
+        183,
+        30,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "tiers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "feeTier"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFees",
+      "docs": [
+        "SetFees: admin sets the mint and exercise fees in basis points (capped at",
+        "`ProtocolConfig::MAX_FEE_BPS`; 0 disables)"
+      ],
+      "discriminator": [
+        137,
+        178,
+        49,
+        58,
+        0,
+        245,
+        242,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "mintFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "exerciseFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setGuardian",
+      "docs": [
+        "SetGuardian: admin sets the key allowed to cancel emergency withdrawals and pause"
+      ],
+      "discriminator": [
+        147,
+        243,
+        50,
+        121,
+        154,
+        164,
+        50,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setInsuranceShare",
+      "docs": [
+        "SetInsuranceShare: admin sets the share of the protocol's part of each fee,",
+        "in basis points, paid into the mint's insurance fund (0 = none)"
+      ],
+      "discriminator": [
+        172,
+        243,
+        185,
+        104,
+        97,
+        46,
+        98,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "insuranceShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setMintAllowlist",
+      "docs": [
+        "SetMintAllowlist: admin requires approved mints for new series (true) or",
+        "lets any SPL Token or Token-2022 mint back one (false)"
+      ],
+      "discriminator": [
+        56,
+        167,
+        221,
+        74,
+        219,
+        8,
+        201,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setMintExtensions",
+      "docs": [
+        "SetMintExtensions: admin allows risky Token-2022 mint extensions",
+        "(`ProtocolConfig::MINT_EXTENSION_*` bits) on new series' collateral and",
+        "consideration mints; mints carrying any other one are refused"
+      ],
+      "discriminator": [
+        206,
+        180,
+        203,
+        123,
+        203,
+        207,
+        14,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "allowedMintExtensions",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setProtocolPaused",
+      "docs": [
+        "SetProtocolPaused: admin or guardian halts/resumes mint and exercise in every",
+        "series (burn and redeem stay open)"
+      ],
+      "discriminator": [
+        47,
+        62,
+        75,
+        69,
+        166,
+        0,
+        147,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setReferralShare",
+      "docs": [
+        "SetReferralShare: admin sets the share of each fee, in basis points, paid",
+        "to the referrer passed with it (0 keeps every fee for the protocol)"
+      ],
+      "discriminator": [
+        230,
+        159,
+        74,
+        188,
+        192,
+        81,
+        25,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "referralShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setSeriesEmergencyPaused",
+      "docs": [
+        "SetSeriesEmergencyPaused: admin or guardian halts/resumes a series on a flag",
+        "the series authority can't clear (burn and redeem stay open)"
+      ],
+      "discriminator": [
+        104,
+        155,
+        84,
+        78,
+        210,
+        203,
+        193,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setSeriesPaused",
+      "docs": [
+        "SetSeriesPaused: series authority halts/resumes mint, exercise and linked-market trading"
+      ],
+      "discriminator": [
+        20,
+        115,
+        203,
+        74,
+        115,
+        125,
+        227,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setStrikeTick",
+      "docs": [
+        "SetStrikeTick: admin sets the strike increment new series quoted in a",
+        "consideration mint must respect (0 allows any strike)"
+      ],
+      "discriminator": [
+        154,
+        87,
+        107,
+        5,
+        174,
+        50,
+        240,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "considerationMint"
+        },
+        {
+          "name": "strikeTick",
+          "docs": [
+            "Tick config of the mint (created on first update)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  116,
+                  114,
+                  105,
+                  107,
+                  101,
+                  95,
+                  116,
+                  105,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "considerationMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tickSize",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setTimelockDelay",
+      "docs": [
+        "SetTimelockDelay: admin lengthens the delay on fee and allowlist changes",
+        "(shortening it is scheduled like any other change)"
+      ],
+      "discriminator": [
+        47,
+        45,
+        87,
+        12,
+        19,
+        247,
+        83,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "timelockDelay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setTokenAccountFrozen",
+      "docs": [
+        "SetTokenAccountFrozen: compliance key of a freezable series freezes or",
+        "thaws a holder's option or redemption token account"
+      ],
+      "discriminator": [
+        99,
+        170,
+        46,
+        143,
+        36,
+        175,
+        184,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "compliance",
+          "docs": [
+            "The series' compliance authority (its series authority if none was set)"
+          ],
+          "signer": true
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The series' option or redemption mint"
+          ]
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTreasury",
+      "docs": [
+        "SetTreasury: admin sets the owner of the accounts receiving swept series funds"
+      ],
+      "discriminator": [
+        57,
+        97,
+        196,
+        95,
+        195,
+        206,
+        106,
+        136
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setUnclaimedTimeout",
+      "docs": [
+        "SetUnclaimedTimeout: admin sets how long after expiry abandoned series funds",
+        "become recoverable (0 disables recovery)"
+      ],
+      "discriminator": [
+        150,
+        46,
+        36,
+        138,
+        188,
+        35,
+        255,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "unclaimedTimeout",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setYieldVenue",
+      "docs": [
+        "SetYieldVenue: admin approves the lending reserve idle escrow of a mint",
+        "may be lent to, or switches an approved one on and off"
+      ],
+      "discriminator": [
+        74,
+        184,
+        172,
+        156,
+        160,
+        99,
+        145,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowMint"
+        },
+        {
+          "name": "yieldVenue",
+          "docs": [
+            "Venue of the mint (created on first approval)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  121,
+                  105,
+                  101,
+                  108,
+                  100,
+                  95,
+                  118,
+                  101,
+                  110,
+                  117,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "escrowMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "yieldVenueParams"
+            }
+          }
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "settleExpiry",
+      "docs": [
+        "SettleExpiry: permissionless post-expiry crank recording the settlement price",
+        "Walks the primary → secondary oracle hierarchy and records which feed was used",
+        "Snapshots vault balances and freezes the series; redeem pays from the snapshot"
+      ],
+      "discriminator": [
+        75,
+        119,
+        150,
+        43,
+        240,
+        9,
+        203,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleForward",
+      "docs": [
+        "SettleForward: permissionless post-expiry crank delivering a forward at its",
+        "strike; option holders claim the collateral, redemption holders the strike"
+      ],
+      "discriminator": [
+        57,
+        250,
+        108,
+        51,
+        191,
+        126,
+        121,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleMarginPosition",
+      "docs": [
+        "SettleMarginPosition: after settlement, return what the writer's margin",
+        "doesn't owe option holders"
+      ],
+      "discriminator": [
+        66,
+        43,
+        46,
+        77,
+        141,
+        252,
+        171,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer, depositor or liquidator, depending on the instruction"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Margin position being changed (owner-only instructions check `writer`)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Vault of the backing asset: collateral vault for calls, consideration vault for puts"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (funds deposits, receives releases)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated (mint_on_margin only)"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleSpread",
+      "docs": [
+        "SettleSpread: after the long series settles, pay the locked longs' netted",
+        "payoff into the position's margin (required before the short series settles)"
+      ],
+      "discriminator": [
+        103,
+        119,
+        174,
+        213,
+        211,
+        242,
+        38,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (create, close) or any cranker (settle); pays for the escrow"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "docs": [
+            "Series the position is short on margin"
+          ],
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "writer",
+          "writable": true
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose long options hedge the short (fixed by the first spread)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "spreadEscrow",
+          "docs": [
+            "Locked long options, owned by the position"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Short series' vault of the backing asset (holds the position's margin)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the same asset (pays the long leg's netted payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (receives released margin)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleVaultRound",
+      "docs": [
+        "SettleVaultRound: anyone cancels the rest of a round's ask and redeems",
+        "the vault's short side once the series redeems"
+      ],
+      "discriminator": [
+        41,
+        156,
+        162,
+        225,
+        246,
+        160,
+        146,
+        224
+      ],
+      "accounts": [
+        {
+          "name": "optionVault",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "series",
+          "accounts": [
+            {
+              "name": "optionContext",
+              "writable": true
+            },
+            {
+              "name": "protocolConfig"
+            },
+            {
+              "name": "collateralMint"
+            },
+            {
+              "name": "considerationMint"
+            },
+            {
+              "name": "optionMint",
+              "writable": true
+            },
+            {
+              "name": "redemptionMint",
+              "writable": true
+            },
+            {
+              "name": "collateralVault",
+              "writable": true
+            },
+            {
+              "name": "considerationVault",
+              "writable": true
+            },
+            {
+              "name": "vaultCollateralAccount",
+              "writable": true
+            },
+            {
+              "name": "vaultConsiderationAccount",
+              "writable": true
+            },
+            {
+              "name": "vaultOptionAccount",
+              "writable": true
+            },
+            {
+              "name": "vaultRedemptionAccount",
+              "writable": true
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "feeVault",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "optionsProgram",
+              "address": "7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP"
+            }
+          ]
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "ask",
+          "writable": true
+        },
+        {
+          "name": "askEscrow",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleVerticalSpread",
+      "docs": [
+        "SettleVerticalSpread: permissionless crank after the long series settles,",
+        "splitting the spread vault between holders and writers"
+      ],
+      "discriminator": [
+        210,
+        210,
+        13,
+        168,
+        208,
+        190,
+        54,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer (mint, burn, redeem), holder (claim) or any cranker (settle)"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "shortOptionContext",
+          "docs": [
+            "Series whose intrinsic value spread tokens pay (only read)"
+          ]
+        },
+        {
+          "name": "longOptionContext",
+          "docs": [
+            "Series whose options writers lock (pays their netted payoff at settlement)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "verticalSpread",
+          "docs": [
+            "Spread PDA (created by the first `mint_vertical_spread` for the pair)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "shortOptionContext"
+              },
+              {
+                "kind": "account",
+                "path": "longOptionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadMint",
+          "docs": [
+            "Spread token mint (same decimals as the options; the spread is authority)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longOptionMint",
+          "docs": [
+            "Long leg's option mint (validated against stored value in long_option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "backingMint",
+          "docs": [
+            "Mint of the asset the short series' writers escrow"
+          ]
+        },
+        {
+          "name": "longEscrow",
+          "docs": [
+            "Locked long options, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "spreadVault",
+          "docs": [
+            "Backing and settlement payoff, owned by the spread"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              }
+            ]
+          }
+        },
+        {
+          "name": "longVault",
+          "docs": [
+            "Long series' vault of the backing asset (pays the locked longs' payoff)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userLongOptionAccount",
+          "docs": [
+            "User's long-leg option account (locked from, unlocked to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userBackingAccount",
+          "docs": [
+            "User's account of the backing asset (deposits from, payouts to)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userSpreadAccount",
+          "docs": [
+            "User's spread token account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "spreadMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "position",
+          "docs": [
+            "User's writer position in the spread (mint, burn and redeem only;",
+            "created by the first mint)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  114,
+                  116,
+                  105,
+                  99,
+                  97,
+                  108,
+                  95,
+                  115,
+                  112,
+                  114,
+                  101,
+                  97,
+                  100,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "verticalSpread"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "simulateRedemption",
+      "docs": [
+        "SimulateRedemption: view instruction returning expiry payoffs at a hypothetical price",
+        "Lets UIs draw payoff diagrams from the same split settle_expiry pays out"
+      ],
+      "discriminator": [
+        155,
+        206,
+        58,
+        1,
+        227,
+        50,
+        133,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "settlementPrice",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "redemptionOutcome"
+        }
+      }
+    },
+    {
+      "name": "startVaultRound",
+      "docs": [
+        "StartVaultRound: manager writes `amount` options of a series from the",
+        "vault's escrow and lists them at `price` (`mint_and_list` as the vault)"
+      ],
+      "discriminator": [
+        100,
+        42,
+        119,
+        176,
+        154,
+        87,
+        78,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "manager",
+          "signer": true,
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "optionVault",
+          "writable": true
+        },
+        {
+          "name": "vaultAuthority",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "series",
+          "accounts": [
+            {
+              "name": "optionContext",
+              "writable": true
+            },
+            {
+              "name": "protocolConfig"
+            },
+            {
+              "name": "collateralMint"
+            },
+            {
+              "name": "considerationMint"
+            },
+            {
+              "name": "optionMint",
+              "writable": true
+            },
+            {
+              "name": "redemptionMint",
+              "writable": true
+            },
+            {
+              "name": "collateralVault",
+              "writable": true
+            },
+            {
+              "name": "considerationVault",
+              "writable": true
+            },
+            {
+              "name": "vaultCollateralAccount",
+              "writable": true
+            },
+            {
+              "name": "vaultConsiderationAccount",
+              "writable": true
+            },
+            {
+              "name": "vaultOptionAccount",
+              "writable": true
+            },
+            {
+              "name": "vaultRedemptionAccount",
+              "writable": true
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "feeVault",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "optionsProgram",
+              "address": "7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP"
+            }
+          ]
+        },
+        {
+          "name": "marketplaceProgram",
+          "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+        },
+        {
+          "name": "market",
+          "writable": true
+        },
+        {
+          "name": "order",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "price",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapAndExercise",
+      "docs": [
+        "SwapAndExercise: `exercise` paid for by a Jupiter route swapping any",
+        "token the user holds into the strike (calls) or the collateral (puts)"
+      ],
+      "discriminator": [
+        58,
+        67,
+        18,
+        252,
+        28,
+        215,
+        194,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "option",
+          "accounts": [
+            {
+              "name": "user",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "optionContext",
+              "docs": [
+                "The OptionContext PDA (client calculates and sends this)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "protocolConfig",
+              "docs": [
+                "Protocol config (mint and exercise fees)"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      112,
+                      114,
+                      111,
+                      116,
+                      111,
+                      99,
+                      111,
+                      108,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "collateralMint",
+              "docs": [
+                "Collateral mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "considerationMint",
+              "docs": [
+                "Consideration mint (validated against stored value in option_context)"
+              ]
+            },
+            {
+              "name": "optionMint",
+              "docs": [
+                "Option mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "redemptionMint",
+              "docs": [
+                "Redemption mint (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "collateralVault",
+              "docs": [
+                "Collateral vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "considerationVault",
+              "docs": [
+                "Consideration vault (validated against stored value in option_context)"
+              ],
+              "writable": true
+            },
+            {
+              "name": "optionOwner",
+              "docs": [
+                "may send option tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "redemptionOwner",
+              "docs": [
+                "may send redemption tokens to anyone (the token program checks burns)"
+              ]
+            },
+            {
+              "name": "userCollateralAccount",
+              "docs": [
+                "User's collateral token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "collateralMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userConsiderationAccount",
+              "docs": [
+                "User's consideration token account (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userOptionAccount",
+              "docs": [
+                "Option token account of `option_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "optionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "userRedemptionAccount",
+              "docs": [
+                "Redemption token account of `redemption_owner` (associated; created if missing)"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "redemptionOwner"
+                  },
+                  {
+                    "kind": "const",
+                    "value": [
+                      6,
+                      221,
+                      246,
+                      225,
+                      215,
+                      101,
+                      161,
+                      147,
+                      217,
+                      203,
+                      225,
+                      70,
+                      206,
+                      235,
+                      121,
+                      172,
+                      28,
+                      180,
+                      133,
+                      237,
+                      95,
+                      91,
+                      55,
+                      145,
+                      58,
+                      140,
+                      245,
+                      133,
+                      126,
+                      255,
+                      0,
+                      169
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "redemptionMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "writerPosition",
+              "docs": [
+                "User's writer position; mint and burn record into it when passed",
+                "(trailing and optional, so clients that don't track positions omit it)"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      114,
+                      105,
+                      116,
+                      101,
+                      114,
+                      95,
+                      112,
+                      111,
+                      115,
+                      105,
+                      116,
+                      105,
+                      111,
+                      110
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "optionContext"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "feeVault",
+              "docs": [
+                "Protocol fee account of the charged mint (escrow mint on mint,",
+                "consideration on exercise); only needed while that fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "feeStats",
+              "docs": [
+                "User's volume in the consideration mint; mint and exercise record into",
+                "it and discount the fee by the tier it reaches when passed"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      101,
+                      101,
+                      95,
+                      115,
+                      116,
+                      97,
+                      116,
+                      115
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "user"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "considerationMint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "referrerAccount",
+              "docs": [
+                "Referrer's token account of the charged mint; with their stats, takes",
+                "the referral share of the fee"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "referralStats",
+              "docs": [
+                "Referrer's earnings in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceFund",
+              "docs": [
+                "Insurance fund of the charged mint; only needed while the insurance",
+                "share of the fee is non-zero"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "insuranceVault",
+              "docs": [
+                "Token account of the insurance fund in the charged mint"
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "credentialAccount",
+              "docs": [
+                "User's token account of the series' gate mint; mint and exercise of a",
+                "gated series require it"
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "jupiterProgram",
+          "address": "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "routeData",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "sweepDust",
+      "docs": [
+        "SweepDust: permissionless; moves a fully redeemed series' leftover vault dust",
+        "to the treasury and closes the vaults"
+      ],
+      "discriminator": [
+        9,
+        49,
+        242,
+        88,
+        156,
+        84,
+        109,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "redemptionMint",
+          "docs": [
+            "Redemption mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "collateralVault",
+          "docs": [
+            "Collateral vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "considerationVault",
+          "docs": [
+            "Consideration vault (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "treasuryCollateralAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryConsiderationAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unwindEscrow",
+      "docs": [
+        "UnwindEscrow: anyone redeems venue shares back into the escrow vault",
+        "(ahead of a payout the vault can't cover)"
+      ],
+      "discriminator": [
+        141,
+        252,
+        165,
+        124,
+        66,
+        228,
+        32,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Series authority when lending; anyone when unwinding"
+          ],
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "yieldVenue"
+        },
+        {
+          "name": "escrowVault",
+          "docs": [
+            "Vault of the asset writers escrow (collateral for calls, consideration",
+            "for puts)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "shareVault",
+          "docs": [
+            "Series-owned account holding the venue's shares"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  121,
+                  105,
+                  101,
+                  108,
+                  100,
+                  95,
+                  115,
+                  104,
+                  97,
+                  114,
+                  101,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              }
+            ]
+          }
+        },
+        {
+          "name": "lendingProgram"
+        },
+        {
+          "name": "reserve",
+          "writable": true
+        },
+        {
+          "name": "reserveLiquiditySupply",
+          "writable": true
+        },
+        {
+          "name": "shareMint",
+          "writable": true
+        },
+        {
+          "name": "lendingMarket"
+        },
+        {
+          "name": "lendingMarketAuthority"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateRateLimits",
+      "docs": [
+        "UpdateRateLimits: admin sets per-creator series creation limits (0 disables)"
+      ],
+      "discriminator": [
+        247,
+        36,
+        121,
+        254,
+        22,
+        16,
+        226,
+        1
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "maxSeriesPerEpoch",
+          "type": "u16"
+        },
+        {
+          "name": "creationCooldown",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateSeriesMetadata",
+      "docs": [
+        "UpdateSeriesMetadata: series authority rewrites the option and",
+        "redemption mints' token metadata (created if missing) from the series'",
+        "stored terms and metadata URI"
+      ],
+      "discriminator": [
+        208,
+        254,
+        115,
+        23,
+        30,
+        225,
+        1,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Pays for the metadata accounts when the series has none yet"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "optionContext"
+          ]
+        },
+        {
+          "name": "optionContext"
+        },
+        {
+          "name": "optionMint"
+        },
+        {
+          "name": "redemptionMint"
+        },
+        {
+          "name": "optionMetadata",
+          "docs": [
+            "by the Token Metadata program)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "redemptionMetadata",
+          "writable": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
       "args": []
+    },
+    {
+      "name": "withdrawFees",
+      "docs": [
+        "WithdrawFees: admin moves collected protocol fees out of a fee account of",
+        "the ProtocolConfig PDA"
+      ],
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Fee account of the mint (any token account of the ProtocolConfig PDA)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFromVault",
+      "docs": [
+        "WithdrawFromVault: between rounds, burn shares for their part of both",
+        "of the vault's balances"
+      ],
+      "discriminator": [
+        180,
+        34,
+        37,
+        46,
+        156,
+        0,
+        211,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionVault"
+        },
+        {
+          "name": "vaultAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "underlyingMint",
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "quoteMint",
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "shareMint",
+          "writable": true,
+          "relations": [
+            "optionVault"
+          ]
+        },
+        {
+          "name": "vaultUnderlyingAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "underlyingMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultQuoteAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vaultAuthority"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userUnderlyingAccount",
+          "writable": true
+        },
+        {
+          "name": "userQuoteAccount",
+          "writable": true
+        },
+        {
+          "name": "userShareAccount",
+          "docs": [
+            "User's share account (associated; created if missing)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "user"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "shareMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "shares",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawMargin",
+      "docs": [
+        "WithdrawMargin: burn `burn` options against the signer's margin short and",
+        "withdraw `withdraw` margin, staying above the initial requirement"
+      ],
+      "discriminator": [
+        124,
+        222,
+        8,
+        141,
+        181,
+        108,
+        15,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "docs": [
+            "Writer, depositor or liquidator, depending on the instruction"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "optionContext",
+          "writable": true
+        },
+        {
+          "name": "writerPosition",
+          "docs": [
+            "Margin position being changed (owner-only instructions check `writer`)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  114,
+                  105,
+                  116,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "optionContext"
+              },
+              {
+                "kind": "account",
+                "path": "writer_position.writer",
+                "account": "writerPosition"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (global pause)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "Collateral mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "considerationMint",
+          "docs": [
+            "Consideration mint (validated against stored value in option_context)"
+          ]
+        },
+        {
+          "name": "optionMint",
+          "docs": [
+            "Option mint (validated against stored value in option_context)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "marginVault",
+          "docs": [
+            "Vault of the backing asset: collateral vault for calls, consideration vault for puts"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userMarginAccount",
+          "docs": [
+            "User's account of the backing asset (funds deposits, receives releases)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userOptionAccount",
+          "docs": [
+            "User's option token account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "primaryOracle",
+          "optional": true
+        },
+        {
+          "name": "secondaryOracle",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "credentialAccount",
+          "docs": [
+            "User's token account of the series' gate mint; required when the",
+            "series is gated (mint_on_margin only)"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "burn",
+          "type": "u64"
+        },
+        {
+          "name": "withdraw",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "approvedMint",
+      "discriminator": [
+        110,
+        224,
+        178,
+        194,
+        82,
+        202,
+        156,
+        181
+      ]
+    },
+    {
+      "name": "collateralBasket",
+      "discriminator": [
+        30,
+        74,
+        212,
+        250,
+        83,
+        153,
+        11,
+        167
+      ]
+    },
+    {
+      "name": "combo",
+      "discriminator": [
+        56,
+        70,
+        203,
+        192,
+        83,
+        186,
+        125,
+        24
+      ]
+    },
+    {
+      "name": "creatorStats",
+      "discriminator": [
+        239,
+        158,
+        112,
+        237,
+        227,
+        82,
+        97,
+        129
+      ]
+    },
+    {
+      "name": "emergencyWithdrawal",
+      "discriminator": [
+        102,
+        167,
+        163,
+        25,
+        93,
+        28,
+        134,
+        215
+      ]
+    },
+    {
+      "name": "feeStats",
+      "discriminator": [
+        178,
+        157,
+        11,
+        16,
+        173,
+        135,
+        166,
+        11
+      ]
+    },
+    {
+      "name": "insuranceFund",
+      "discriminator": [
+        43,
+        134,
+        170,
+        87,
+        102,
+        16,
+        142,
+        147
+      ]
+    },
+    {
+      "name": "optionData",
+      "discriminator": [
+        241,
+        2,
+        215,
+        22,
+        100,
+        177,
+        168,
+        108
+      ]
+    },
+    {
+      "name": "optionVault",
+      "discriminator": [
+        99,
+        120,
+        255,
+        154,
+        75,
+        85,
+        29,
+        36
+      ]
+    },
+    {
+      "name": "pendingChange",
+      "discriminator": [
+        232,
+        183,
+        176,
+        196,
+        15,
+        195,
+        115,
+        147
+      ]
+    },
+    {
+      "name": "programVersion",
+      "discriminator": [
+        138,
+        104,
+        244,
+        197,
+        206,
+        47,
+        159,
+        154
+      ]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [
+        207,
+        91,
+        250,
+        28,
+        152,
+        179,
+        215,
+        209
+      ]
+    },
+    {
+      "name": "referralStats",
+      "discriminator": [
+        1,
+        195,
+        51,
+        123,
+        240,
+        93,
+        99,
+        240
+      ]
+    },
+    {
+      "name": "settlementAttestation",
+      "discriminator": [
+        50,
+        238,
+        111,
+        45,
+        53,
+        190,
+        126,
+        147
+      ]
+    },
+    {
+      "name": "strikeTick",
+      "discriminator": [
+        18,
+        108,
+        177,
+        241,
+        156,
+        11,
+        75,
+        76
+      ]
+    },
+    {
+      "name": "verticalSpread",
+      "discriminator": [
+        12,
+        151,
+        87,
+        144,
+        176,
+        95,
+        243,
+        114
+      ]
+    },
+    {
+      "name": "verticalSpreadPosition",
+      "discriminator": [
+        85,
+        164,
+        24,
+        159,
+        188,
+        35,
+        125,
+        62
+      ]
+    },
+    {
+      "name": "writerPosition",
+      "discriminator": [
+        195,
+        252,
+        56,
+        77,
+        221,
+        13,
+        8,
+        69
+      ]
+    },
+    {
+      "name": "yieldVenue",
+      "discriminator": [
+        102,
+        6,
+        108,
+        179,
+        170,
+        114,
+        86,
+        13
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        62,
+        194,
+        237,
+        81,
+        208,
+        210,
+        68,
+        145
+      ],
+      "name": "approvedMintUpdated"
+    },
+    {
+      "discriminator": [
+        226,
+        1,
+        224,
+        100,
+        192,
+        110,
+        58,
+        24
+      ],
+      "name": "changeApplied"
+    },
+    {
+      "discriminator": [
+        139,
+        179,
+        97,
+        81,
+        214,
+        21,
+        119,
+        93
+      ],
+      "name": "changeCancelled"
+    },
+    {
+      "discriminator": [
+        204,
+        76,
+        7,
+        11,
+        15,
+        173,
+        202,
+        33
+      ],
+      "name": "changeScheduled"
+    },
+    {
+      "discriminator": [
+        75,
+        36,
+        250,
+        37,
+        29,
+        196,
+        230,
+        126
+      ],
+      "name": "collateralBasketUpdated"
+    },
+    {
+      "discriminator": [
+        106,
+        225,
+        70,
+        237,
+        68,
+        147,
+        136,
+        191
+      ],
+      "name": "collateralYieldEnabled"
+    },
+    {
+      "discriminator": [
+        8,
+        228,
+        244,
+        29,
+        148,
+        78,
+        141,
+        191
+      ],
+      "name": "configAdminProposed"
+    },
+    {
+      "discriminator": [
+        3,
+        74,
+        0,
+        47,
+        98,
+        129,
+        255,
+        232
+      ],
+      "name": "configAdminTransferred"
+    },
+    {
+      "discriminator": [
+        102,
+        203,
+        234,
+        121,
+        126,
+        82,
+        172,
+        33
+      ],
+      "name": "considerationClaimed"
+    },
+    {
+      "discriminator": [
+        94,
+        189,
+        213,
+        29,
+        167,
+        0,
+        22,
+        77
+      ],
+      "name": "creationBondUpdated"
+    },
+    {
+      "discriminator": [
+        131,
+        70,
+        179,
+        205,
+        208,
+        80,
+        13,
+        168
+      ],
+      "name": "dustSwept"
+    },
+    {
+      "discriminator": [
+        155,
+        192,
+        66,
+        172,
+        63,
+        20,
+        223,
+        19
+      ],
+      "name": "emergencyWithdrawalCancelled"
+    },
+    {
+      "discriminator": [
+        204,
+        198,
+        53,
+        187,
+        12,
+        224,
+        27,
+        77
+      ],
+      "name": "emergencyWithdrawalExecuted"
+    },
+    {
+      "discriminator": [
+        193,
+        13,
+        194,
+        147,
+        90,
+        20,
+        18,
+        124
+      ],
+      "name": "emergencyWithdrawalQueued"
+    },
+    {
+      "discriminator": [
+        2,
+        53,
+        173,
+        127,
+        46,
+        73,
+        73,
+        171
+      ],
+      "name": "escrowLent"
+    },
+    {
+      "discriminator": [
+        74,
+        219,
+        94,
+        17,
+        174,
+        122,
+        140,
+        248
+      ],
+      "name": "escrowUnwound"
+    },
+    {
+      "discriminator": [
+        178,
+        34,
+        207,
+        122,
+        14,
+        224,
+        69,
+        32
+      ],
+      "name": "expiryGridUpdated"
+    },
+    {
+      "discriminator": [
+        12,
+        6,
+        209,
+        134,
+        77,
+        196,
+        106,
+        88
+      ],
+      "name": "expiryLimitsUpdated"
+    },
+    {
+      "discriminator": [
+        103,
+        226,
+        0,
+        123,
+        140,
+        185,
+        255,
+        110
+      ],
+      "name": "featuresUpdated"
+    },
+    {
+      "discriminator": [
+        10,
+        15,
+        44,
+        253,
+        165,
+        0,
+        86,
+        248
+      ],
+      "name": "feeCharged"
+    },
+    {
+      "discriminator": [
+        21,
+        111,
+        209,
+        76,
+        104,
+        34,
+        3,
+        45
+      ],
+      "name": "feeExemptUpdated"
+    },
+    {
+      "discriminator": [
+        172,
+        186,
+        57,
+        25,
+        74,
+        242,
+        109,
+        42
+      ],
+      "name": "feeTiersUpdated"
+    },
+    {
+      "discriminator": [
+        65,
+        34,
+        234,
+        59,
+        248,
+        242,
+        101,
+        118
+      ],
+      "name": "feesUpdated"
+    },
+    {
+      "discriminator": [
+        234,
+        15,
+        0,
+        119,
+        148,
+        241,
+        40,
+        21
+      ],
+      "name": "feesWithdrawn"
+    },
+    {
+      "discriminator": [
+        245,
+        38,
+        158,
+        236,
+        129,
+        195,
+        123,
+        235
+      ],
+      "name": "fundingAccrued"
+    },
+    {
+      "discriminator": [
+        245,
+        32,
+        77,
+        255,
+        224,
+        173,
+        162,
+        2
+      ],
+      "name": "governanceAdminProposed"
+    },
+    {
+      "discriminator": [
+        31,
+        95,
+        81,
+        24,
+        90,
+        9,
+        246,
+        32
+      ],
+      "name": "guardianUpdated"
+    },
+    {
+      "discriminator": [
+        49,
+        91,
+        213,
+        176,
+        42,
+        167,
+        8,
+        70
+      ],
+      "name": "insuranceContributed"
+    },
+    {
+      "discriminator": [
+        79,
+        49,
+        59,
+        130,
+        129,
+        152,
+        15,
+        180
+      ],
+      "name": "insuranceFundOpened"
+    },
+    {
+      "discriminator": [
+        148,
+        7,
+        204,
+        203,
+        5,
+        130,
+        134,
+        89
+      ],
+      "name": "insurancePaidOut"
+    },
+    {
+      "discriminator": [
+        111,
+        161,
+        194,
+        58,
+        245,
+        7,
+        194,
+        169
+      ],
+      "name": "insuranceShareUpdated"
+    },
+    {
+      "discriminator": [
+        252,
+        44,
+        218,
+        101,
+        237,
+        49,
+        11,
+        129
+      ],
+      "name": "marginLiquidated"
+    },
+    {
+      "discriminator": [
+        116,
+        189,
+        207,
+        216,
+        216,
+        164,
+        15,
+        135
+      ],
+      "name": "mintAllowlistUpdated"
+    },
+    {
+      "discriminator": [
+        55,
+        52,
+        252,
+        94,
+        37,
+        126,
+        0,
+        251
+      ],
+      "name": "mintExtensionsUpdated"
+    },
+    {
+      "discriminator": [
+        138,
+        64,
+        36,
+        109,
+        47,
+        130,
+        120,
+        50
+      ],
+      "name": "netPayoffClaimed"
+    },
+    {
+      "discriminator": [
+        83,
+        121,
+        215,
+        37,
+        192,
+        186,
+        96,
+        137
+      ],
+      "name": "openbookMarketOpened"
+    },
+    {
+      "discriminator": [
+        168,
+        131,
+        200,
+        57,
+        141,
+        217,
+        249,
+        194
+      ],
+      "name": "optionVaultCreated"
+    },
+    {
+      "discriminator": [
+        134,
+        114,
+        18,
+        76,
+        232,
+        195,
+        254,
+        176
+      ],
+      "name": "optionsExercised"
+    },
+    {
+      "discriminator": [
+        116,
+        167,
+        203,
+        155,
+        71,
+        212,
+        52,
+        211
+      ],
+      "name": "optionsMinted"
+    },
+    {
+      "discriminator": [
+        85,
+        246,
+        78,
+        253,
+        45,
+        72,
+        133,
+        125
+      ],
+      "name": "oracleReferenceReset"
+    },
+    {
+      "discriminator": [
+        123,
+        129,
+        41,
+        59,
+        180,
+        163,
+        14,
+        57
+      ],
+      "name": "pairBurned"
+    },
+    {
+      "discriminator": [
+        72,
+        71,
+        119,
+        167,
+        178,
+        118,
+        40,
+        195
+      ],
+      "name": "programVersionAnnounced"
+    },
+    {
+      "discriminator": [
+        18,
+        112,
+        97,
+        19,
+        182,
+        70,
+        162,
+        226
+      ],
+      "name": "protocolPauseUpdated"
+    },
+    {
+      "discriminator": [
+        32,
+        232,
+        72,
+        132,
+        73,
+        25,
+        219,
+        10
+      ],
+      "name": "rateLimitsUpdated"
+    },
+    {
+      "discriminator": [
+        14,
+        29,
+        183,
+        71,
+        31,
+        165,
+        107,
+        38
+      ],
+      "name": "redeemed"
+    },
+    {
+      "discriminator": [
+        70,
+        190,
+        133,
+        42,
+        145,
+        213,
+        87,
+        197
+      ],
+      "name": "referralPaid"
+    },
+    {
+      "discriminator": [
+        18,
+        255,
+        201,
+        105,
+        100,
+        81,
+        44,
+        91
+      ],
+      "name": "referralShareUpdated"
+    },
+    {
+      "discriminator": [
+        46,
+        9,
+        170,
+        220,
+        96,
+        158,
+        87,
+        117
+      ],
+      "name": "seriesAuthorityProposed"
+    },
+    {
+      "discriminator": [
+        6,
+        186,
+        110,
+        85,
+        77,
+        6,
+        27,
+        172
+      ],
+      "name": "seriesAuthorityTransferred"
+    },
+    {
+      "discriminator": [
+        97,
+        146,
+        166,
+        119,
+        177,
+        104,
+        132,
+        53
+      ],
+      "name": "seriesClosed"
+    },
+    {
+      "discriminator": [
+        2,
+        164,
+        54,
+        38,
+        24,
+        181,
+        233,
+        180
+      ],
+      "name": "seriesCreated"
+    },
+    {
+      "discriminator": [
+        70,
+        214,
+        221,
+        144,
+        241,
+        25,
+        90,
+        155
+      ],
+      "name": "seriesEmergencyPauseUpdated"
+    },
+    {
+      "discriminator": [
+        190,
+        201,
+        198,
+        137,
+        165,
+        1,
+        200,
+        185
+      ],
+      "name": "seriesFrozen"
+    },
+    {
+      "discriminator": [
+        231,
+        48,
+        165,
+        235,
+        232,
+        16,
+        99,
+        89
+      ],
+      "name": "seriesKnockedOut"
+    },
+    {
+      "discriminator": [
+        111,
+        157,
+        77,
+        58,
+        113,
+        147,
+        201,
+        137
+      ],
+      "name": "seriesMarketOpened"
+    },
+    {
+      "discriminator": [
+        155,
+        153,
+        104,
+        119,
+        125,
+        102,
+        28,
+        51
+      ],
+      "name": "seriesMetadataUpdated"
+    },
+    {
+      "discriminator": [
+        15,
+        78,
+        231,
+        229,
+        202,
+        208,
+        211,
+        69
+      ],
+      "name": "seriesMigrated"
+    },
+    {
+      "discriminator": [
+        210,
+        105,
+        114,
+        37,
+        108,
+        13,
+        42,
+        5
+      ],
+      "name": "seriesPauseUpdated"
+    },
+    {
+      "discriminator": [
+        119,
+        185,
+        208,
+        158,
+        40,
+        59,
+        197,
+        235
+      ],
+      "name": "seriesSettled"
+    },
+    {
+      "discriminator": [
+        239,
+        162,
+        77,
+        172,
+        75,
+        14,
+        204,
+        5
+      ],
+      "name": "settlementAttested"
+    },
+    {
+      "discriminator": [
+        134,
+        49,
+        175,
+        8,
+        30,
+        97,
+        251,
+        10
+      ],
+      "name": "strikeTickUpdated"
+    },
+    {
+      "discriminator": [
+        84,
+        141,
+        204,
+        136,
+        91,
+        78,
+        94,
+        150
+      ],
+      "name": "substituteDeposited"
+    },
+    {
+      "discriminator": [
+        235,
+        86,
+        194,
+        104,
+        233,
+        216,
+        233,
+        15
+      ],
+      "name": "timelockDelayUpdated"
+    },
+    {
+      "discriminator": [
+        13,
+        77,
+        182,
+        187,
+        120,
+        24,
+        182,
+        109
+      ],
+      "name": "tokenAccountFrozen"
+    },
+    {
+      "discriminator": [
+        80,
+        239,
+        54,
+        168,
+        43,
+        38,
+        85,
+        145
+      ],
+      "name": "treasuryUpdated"
+    },
+    {
+      "discriminator": [
+        94,
+        98,
+        46,
+        197,
+        218,
+        69,
+        168,
+        85
+      ],
+      "name": "unclaimedRecovered"
+    },
+    {
+      "discriminator": [
+        139,
+        217,
+        126,
+        33,
+        170,
+        95,
+        250,
+        244
+      ],
+      "name": "unclaimedTimeoutUpdated"
+    },
+    {
+      "discriminator": [
+        121,
+        132,
+        143,
+        159,
+        156,
+        186,
+        108,
+        213
+      ],
+      "name": "vaultListingClosed"
+    },
+    {
+      "discriminator": [
+        135,
+        179,
+        216,
+        141,
+        241,
+        28,
+        182,
+        61
+      ],
+      "name": "vaultListingOpened"
+    },
+    {
+      "discriminator": [
+        159,
+        232,
+        92,
+        155,
+        222,
+        98,
+        252,
+        173
+      ],
+      "name": "vaultRoundSettled"
+    },
+    {
+      "discriminator": [
+        225,
+        117,
+        211,
+        15,
+        197,
+        230,
+        209,
+        32
+      ],
+      "name": "vaultRoundStarted"
+    },
+    {
+      "discriminator": [
+        83,
+        166,
+        223,
+        179,
+        194,
+        30,
+        112,
+        7
+      ],
+      "name": "vaultSharesChanged"
+    },
+    {
+      "discriminator": [
+        79,
+        86,
+        74,
+        73,
+        222,
+        197,
+        213,
+        138
+      ],
+      "name": "yieldVenueUpdated"
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "expirationInPast",
+      "msg": "Expiration must be in the future"
+    },
+    {
+      "code": 6001,
+      "name": "invalidStrikePrice",
+      "msg": "Strike price must be greater than zero"
+    },
+    {
+      "code": 6002,
+      "name": "invalidAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6003,
+      "name": "mathOverflow",
+      "msg": "Math operation overflow"
+    },
+    {
+      "code": 6004,
+      "name": "invalidUnderlyingMint",
+      "msg": "Invalid underlying mint"
+    },
+    {
+      "code": 6005,
+      "name": "invalidCollateralVault",
+      "msg": "Invalid collateral vault"
+    },
+    {
+      "code": 6006,
+      "name": "optionExpired",
+      "msg": "Option has expired"
+    },
+    {
+      "code": 6007,
+      "name": "invalidOptionMint",
+      "msg": "Invalid option mint"
+    },
+    {
+      "code": 6008,
+      "name": "invalidRedemptionMint",
+      "msg": "Invalid redemption mint"
+    },
+    {
+      "code": 6009,
+      "name": "invalidStrikeCurrency",
+      "msg": "Invalid strike currency"
+    },
+    {
+      "code": 6010,
+      "name": "invalidCashVault",
+      "msg": "Invalid cash vault"
+    },
+    {
+      "code": 6011,
+      "name": "insufficientCollateral",
+      "msg": "Insufficient collateral in vault"
+    },
+    {
+      "code": 6012,
+      "name": "optionNotExpired",
+      "msg": "Option has not expired yet"
+    },
+    {
+      "code": 6013,
+      "name": "noTokensIssued",
+      "msg": "No tokens have been issued"
+    },
+    {
+      "code": 6014,
+      "name": "noShortTokens",
+      "msg": "User has no SHORT (redemption) tokens"
+    },
+    {
+      "code": 6015,
+      "name": "noCashAvailable",
+      "msg": "Cash vault has no funds available"
+    },
+    {
+      "code": 6016,
+      "name": "noClaimableConsideration",
+      "msg": "No claimable consideration available for this user"
+    },
+    {
+      "code": 6017,
+      "name": "invalidOptionSeries",
+      "msg": "Invalid option series"
+    },
+    {
+      "code": 6018,
+      "name": "invalidUser",
+      "msg": "Invalid user"
+    },
+    {
+      "code": 6019,
+      "name": "invalidOracleConfig",
+      "msg": "Invalid oracle configuration"
+    },
+    {
+      "code": 6020,
+      "name": "invalidOracleAccount",
+      "msg": "Oracle account does not match the configured feed"
+    },
+    {
+      "code": 6021,
+      "name": "oracleNotConfigured",
+      "msg": "Oracle feed is not configured"
+    },
+    {
+      "code": 6022,
+      "name": "staleOraclePrice",
+      "msg": "Oracle price is stale"
+    },
+    {
+      "code": 6023,
+      "name": "invalidOraclePrice",
+      "msg": "Oracle price is invalid"
+    },
+    {
+      "code": 6024,
+      "name": "oracleUnavailable",
+      "msg": "No oracle in the hierarchy produced a usable price"
+    },
+    {
+      "code": 6025,
+      "name": "seriesAlreadySettled",
+      "msg": "Series has already been settled"
+    },
+    {
+      "code": 6026,
+      "name": "oracleConfidenceTooWide",
+      "msg": "Oracle confidence interval is too wide"
+    },
+    {
+      "code": 6027,
+      "name": "oraclePriceDeviation",
+      "msg": "Oracle price deviates too far from the previous reading"
+    },
+    {
+      "code": 6028,
+      "name": "metadataUriTooLong",
+      "msg": "Metadata URI exceeds the maximum length"
+    },
+    {
+      "code": 6029,
+      "name": "invalidUnderlyingSymbol",
+      "msg": "Underlying symbol must be 1-10 ASCII alphanumeric characters"
+    },
+    {
+      "code": 6030,
+      "name": "unauthorized",
+      "msg": "Signer is not the required authority"
+    },
+    {
+      "code": 6031,
+      "name": "noPendingAuthority",
+      "msg": "No authority transfer is pending"
+    },
+    {
+      "code": 6032,
+      "name": "invalidMigrationSource",
+      "msg": "Migration source account is not a recognized option market"
+    },
+    {
+      "code": 6033,
+      "name": "migrationParameterMismatch",
+      "msg": "Migration source does not match the destination series"
+    },
+    {
+      "code": 6034,
+      "name": "invalidConfigValue",
+      "msg": "Invalid protocol config value"
+    },
+    {
+      "code": 6035,
+      "name": "creationRateLimited",
+      "msg": "Creator has reached the series creation limit for this epoch"
+    },
+    {
+      "code": 6036,
+      "name": "creationCooldownActive",
+      "msg": "Creator must wait for the creation cooldown to elapse"
+    },
+    {
+      "code": 6037,
+      "name": "seriesPaused",
+      "msg": "Series is paused"
+    },
+    {
+      "code": 6038,
+      "name": "invalidWithdrawalVault",
+      "msg": "Withdrawal vault does not belong to the series"
+    },
+    {
+      "code": 6039,
+      "name": "timelockNotElapsed",
+      "msg": "Emergency withdrawal timelock has not elapsed"
+    },
+    {
+      "code": 6040,
+      "name": "versionAlreadyAnnounced",
+      "msg": "This program version has already been announced"
+    },
+    {
+      "code": 6041,
+      "name": "invalidExerciseWindow",
+      "msg": "Invalid exercise window for the exercise style"
+    },
+    {
+      "code": 6042,
+      "name": "outsideExerciseWindow",
+      "msg": "European options can only be exercised during the exercise window"
+    },
+    {
+      "code": 6043,
+      "name": "exerciseWindowOpen",
+      "msg": "Redemption opens after the exercise window closes"
+    },
+    {
+      "code": 6044,
+      "name": "unverifiedOraclePrice",
+      "msg": "Oracle price update is not fully verified"
+    },
+    {
+      "code": 6045,
+      "name": "seriesNotSettled",
+      "msg": "Series has not been settled yet"
+    },
+    {
+      "code": 6046,
+      "name": "exerciseCutoffPassed",
+      "msg": "Exercise cutoff before expiration has passed"
+    },
+    {
+      "code": 6047,
+      "name": "invalidSettlementDelay",
+      "msg": "Settlement delay exceeds the maximum"
+    },
+    {
+      "code": 6048,
+      "name": "redemptionNotOpen",
+      "msg": "Redemption opens after the settlement delay"
+    },
+    {
+      "code": 6049,
+      "name": "insufficientExercisedAmount",
+      "msg": "Amount exceeds the exercised units available to claim"
+    },
+    {
+      "code": 6050,
+      "name": "nothingToExit",
+      "msg": "Nothing in this position can be unwound yet"
+    },
+    {
+      "code": 6051,
+      "name": "treasuryNotSet",
+      "msg": "Protocol treasury is not set"
+    },
+    {
+      "code": 6052,
+      "name": "invalidTreasuryAccount",
+      "msg": "Token account is not owned by the protocol treasury"
+    },
+    {
+      "code": 6053,
+      "name": "seriesNotFullyRedeemed",
+      "msg": "Series still has outstanding claims on its vaults"
+    },
+    {
+      "code": 6054,
+      "name": "recoveryDisabled",
+      "msg": "Unclaimed-funds recovery is disabled"
+    },
+    {
+      "code": 6055,
+      "name": "unclaimedTimeoutNotElapsed",
+      "msg": "Unclaimed-funds timeout has not elapsed"
+    },
+    {
+      "code": 6056,
+      "name": "invalidMarginConfig",
+      "msg": "Margin buffers must be zero or maintenance below initial, with a primary oracle"
+    },
+    {
+      "code": 6057,
+      "name": "marginDisabled",
+      "msg": "Series does not allow margin writing"
+    },
+    {
+      "code": 6058,
+      "name": "insufficientMargin",
+      "msg": "Position would fall below the initial margin requirement"
+    },
+    {
+      "code": 6059,
+      "name": "positionHealthy",
+      "msg": "Position is above the maintenance margin requirement"
+    },
+    {
+      "code": 6060,
+      "name": "exceedsMarginWritten",
+      "msg": "Amount exceeds the options written on margin"
+    },
+    {
+      "code": 6061,
+      "name": "invalidSpread",
+      "msg": "Spread legs must share mints, strike units, contract size, expiry, exercise style and oracle, long leg further OTM"
+    },
+    {
+      "code": 6062,
+      "name": "spreadsOutstanding",
+      "msg": "Open spreads must be settled against their long leg first"
+    },
+    {
+      "code": 6063,
+      "name": "invalidLotConfig",
+      "msg": "Minimum mint amount must be a multiple of the lot size"
+    },
+    {
+      "code": 6064,
+      "name": "amountNotLotMultiple",
+      "msg": "Amount is not a multiple of the series lot size"
+    },
+    {
+      "code": 6065,
+      "name": "belowMinMintAmount",
+      "msg": "Amount is below the series minimum mint amount"
+    },
+    {
+      "code": 6066,
+      "name": "vaultNotEmpty",
+      "msg": "Vault still holds tokens; sweep it first"
+    },
+    {
+      "code": 6067,
+      "name": "invalidOptionType",
+      "msg": "Unknown option type"
+    },
+    {
+      "code": 6068,
+      "name": "invalidStrikeExponent",
+      "msg": "Strike exponent is out of range for the series' mints"
+    },
+    {
+      "code": 6069,
+      "name": "identicalMints",
+      "msg": "Collateral and consideration mints must differ"
+    },
+    {
+      "code": 6070,
+      "name": "invalidExpiryGrid",
+      "msg": "Invalid expiry grid configuration"
+    },
+    {
+      "code": 6071,
+      "name": "expirationOffGrid",
+      "msg": "Expiration is not on the protocol's expiry grid"
+    },
+    {
+      "code": 6072,
+      "name": "strikeOffTick",
+      "msg": "Strike price is not a multiple of the consideration mint's tick size"
+    },
+    {
+      "code": 6073,
+      "name": "expirationTooSoon",
+      "msg": "Expiration is sooner than the protocol's minimum time to expiry"
+    },
+    {
+      "code": 6074,
+      "name": "expirationTooFar",
+      "msg": "Expiration is later than the protocol's maximum time to expiry"
+    },
+    {
+      "code": 6075,
+      "name": "invalidBatch",
+      "msg": "Batch strikes must be increasing, at most the batch limit, with five accounts each"
+    },
+    {
+      "code": 6076,
+      "name": "invalidBinaryConfig",
+      "msg": "Binary series need a payout and a primary oracle, and can't write on margin"
+    },
+    {
+      "code": 6077,
+      "name": "cashSettledOnly",
+      "msg": "Binary series settle in cash at expiry and can't be exercised"
+    },
+    {
+      "code": 6078,
+      "name": "invalidBarrier",
+      "msg": "Knock-out barriers must lie beyond the strike and need a primary oracle"
+    },
+    {
+      "code": 6079,
+      "name": "barrierNotBreached",
+      "msg": "Oracle price has not reached the knock-out barrier"
+    },
+    {
+      "code": 6080,
+      "name": "seriesKnockedOut",
+      "msg": "Series has been knocked out"
+    },
+    {
+      "code": 6081,
+      "name": "invalidPerpetualConfig",
+      "msg": "Invalid perpetual series configuration"
+    },
+    {
+      "code": 6082,
+      "name": "invalidCombo",
+      "msg": "Combo legs must be a call and a put sharing mints, contract size and expiry"
+    },
+    {
+      "code": 6083,
+      "name": "spreadPositionRequired",
+      "msg": "Writers must pass their vertical spread position"
+    },
+    {
+      "code": 6084,
+      "name": "invalidRoll",
+      "msg": "Rolls need the same mints, strike, type and contract size at a later expiry"
+    },
+    {
+      "code": 6085,
+      "name": "forwardSettlementOnly",
+      "msg": "Forwards can't be exercised; they settle at the strike through settle_forward"
+    },
+    {
+      "code": 6086,
+      "name": "invalidForwardConfig",
+      "msg": "Invalid forward configuration"
+    },
+    {
+      "code": 6087,
+      "name": "invalidCollateralBasket",
+      "msg": "Invalid collateral basket"
+    },
+    {
+      "code": 6088,
+      "name": "substituteNotAccepted",
+      "msg": "This series doesn't accept the substitute mint"
+    },
+    {
+      "code": 6089,
+      "name": "invalidBasketAccounts",
+      "msg": "Pass the mint, basket vault and destination of every substitute the series holds"
+    },
+    {
+      "code": 6090,
+      "name": "feeTooHigh",
+      "msg": "Fee exceeds the protocol maximum"
+    },
+    {
+      "code": 6091,
+      "name": "invalidFeatures",
+      "msg": "Unknown feature switch"
+    },
+    {
+      "code": 6092,
+      "name": "featureDisabled",
+      "msg": "This feature is switched off by the protocol admin"
+    },
+    {
+      "code": 6093,
+      "name": "invalidFeeVault",
+      "msg": "Pass the protocol fee account for the charged mint"
+    },
+    {
+      "code": 6094,
+      "name": "invalidFeeTiers",
+      "msg": "Fee tiers need increasing volumes and non-decreasing discounts of at most 100%"
+    },
+    {
+      "code": 6095,
+      "name": "invalidFeeExempt",
+      "msg": "Invalid fee exemption list"
+    },
+    {
+      "code": 6096,
+      "name": "invalidReferral",
+      "msg": "Pass the referrer's token account and stats for the charged mint; no self-referrals"
+    },
+    {
+      "code": 6097,
+      "name": "invalidReferralShare",
+      "msg": "Referral share exceeds 100% of the fee"
+    },
+    {
+      "code": 6098,
+      "name": "protocolPaused",
+      "msg": "The protocol is paused"
+    },
+    {
+      "code": 6099,
+      "name": "mintNotApproved",
+      "msg": "Mint is not on the protocol's allowlist"
+    },
+    {
+      "code": 6100,
+      "name": "invalidGovernance",
+      "msg": "Not a governance account of an SPL Governance program"
+    },
+    {
+      "code": 6101,
+      "name": "timelockRequired",
+      "msg": "This parameter is timelocked; schedule the change instead"
+    },
+    {
+      "code": 6102,
+      "name": "invalidTimelockDelay",
+      "msg": "Timelock delay must be between 0 and 30 days"
+    },
+    {
+      "code": 6103,
+      "name": "invalidPendingChange",
+      "msg": "Pending change is invalid or its accounts don't match"
+    },
+    {
+      "code": 6104,
+      "name": "seriesFrozen",
+      "msg": "Series is frozen by the guardian"
+    },
+    {
+      "code": 6105,
+      "name": "invalidInsuranceShare",
+      "msg": "Insurance share must be at most 10000 bps"
+    },
+    {
+      "code": 6106,
+      "name": "invalidInsuranceFund",
+      "msg": "Insurance fund or its token account doesn't match the charged mint"
+    },
+    {
+      "code": 6107,
+      "name": "noVaultShortfall",
+      "msg": "Payout exceeds the vault's shortfall"
+    },
+    {
+      "code": 6108,
+      "name": "depositShortfall",
+      "msg": "Transfer fees leave too little of the deposit in the vault to mint"
+    },
+    {
+      "code": 6109,
+      "name": "invalidMetadataAccount",
+      "msg": "Token metadata program or account doesn't match the series mints"
+    },
+    {
+      "code": 6110,
+      "name": "transferHookRequiresToken2022",
+      "msg": "Transfer hooks need option and redemption mints under Token-2022"
+    },
+    {
+      "code": 6111,
+      "name": "invalidFreezeConfig",
+      "msg": "A compliance authority needs a freezable series"
+    },
+    {
+      "code": 6112,
+      "name": "seriesNotFreezable",
+      "msg": "Series was created without a freeze authority"
+    },
+    {
+      "code": 6113,
+      "name": "notNativeSeries",
+      "msg": "Neither of the series' mints is wrapped SOL"
+    },
+    {
+      "code": 6114,
+      "name": "credentialRequired",
+      "msg": "Series is gated: present a token account holding its credential"
+    },
+    {
+      "code": 6115,
+      "name": "invalidMintExtensions",
+      "msg": "Mint extensions must be ProtocolConfig::MINT_EXTENSION_* bits"
+    },
+    {
+      "code": 6116,
+      "name": "unsupportedMintExtension",
+      "msg": "Mint has a Token-2022 extension the protocol doesn't allow for series"
+    },
+    {
+      "code": 6117,
+      "name": "listingNotOwned",
+      "msg": "Options listed on mint must be minted to the user's own account"
+    },
+    {
+      "code": 6118,
+      "name": "flashExerciseNeedsCall",
+      "msg": "Only calls pay out collateral that can be sold for the strike"
+    },
+    {
+      "code": 6119,
+      "name": "marketAlreadyOpened",
+      "msg": "Series already records its marketplace market"
+    },
+    {
+      "code": 6120,
+      "name": "invalidSwapProgram",
+      "msg": "Swap program is not Jupiter"
+    },
+    {
+      "code": 6121,
+      "name": "invalidSwapRoute",
+      "msg": "Swap route data is malformed"
+    },
+    {
+      "code": 6122,
+      "name": "swapOutputMissing",
+      "msg": "Swap delivered nothing to the account exercise pays from"
+    },
+    {
+      "code": 6123,
+      "name": "invalidYieldVenue",
+      "msg": "Yield venue addresses don't match the approved reserve"
+    },
+    {
+      "code": 6124,
+      "name": "yieldVenueDisabled",
+      "msg": "Yield venue is not accepting new lending"
+    },
+    {
+      "code": 6125,
+      "name": "yieldNotEnabled",
+      "msg": "Series doesn't lend its escrow"
+    },
+    {
+      "code": 6126,
+      "name": "yieldOptInTooLate",
+      "msg": "Series can only opt into lending before anything is escrowed"
+    },
+    {
+      "code": 6127,
+      "name": "insufficientYieldShares",
+      "msg": "Series holds fewer yield shares than requested"
+    },
+    {
+      "code": 6128,
+      "name": "unsupportedVaultType",
+      "msg": "Vaults write calls or puts only"
+    },
+    {
+      "code": 6129,
+      "name": "vaultRoundActive",
+      "msg": "Vault round in progress"
+    },
+    {
+      "code": 6130,
+      "name": "vaultRoundNotActive",
+      "msg": "Vault has no open round"
+    },
+    {
+      "code": 6131,
+      "name": "vaultSeriesMismatch",
+      "msg": "Series doesn't match the vault's underlying, quote or option type"
+    },
+    {
+      "code": 6132,
+      "name": "vaultHasNoEscrow",
+      "msg": "Vault holds none of its escrow mint to price shares against"
+    },
+    {
+      "code": 6133,
+      "name": "insufficientVaultShares",
+      "msg": "Not enough vault shares"
+    },
+    {
+      "code": 6134,
+      "name": "notAPutVault",
+      "msg": "Only put vaults re-list assigned underlying"
+    },
+    {
+      "code": 6135,
+      "name": "vaultListingOpen",
+      "msg": "The vault's re-listing of assigned underlying is still open"
+    },
+    {
+      "code": 6136,
+      "name": "vaultListingNotOpen",
+      "msg": "The vault has no open re-listing"
+    },
+    {
+      "code": 6137,
+      "name": "invalidWormholeAccount",
+      "msg": "Invalid or incomplete Wormhole accounts"
+    },
+    {
+      "code": 6138,
+      "name": "invalidOpenbookProgram",
+      "msg": "Not the OpenBook v2 program"
+    },
+    {
+      "code": 6139,
+      "name": "invalidMarginAccount",
+      "msg": "Token account is not of the series' margin mint"
+    },
+    {
+      "code": 6140,
+      "name": "invariantViolated",
+      "msg": "Series accounting invariant violated"
+    },
+    {
+      "code": 6141,
+      "name": "seriesUpToDate",
+      "msg": "Series already uses the current account layout"
+    },
+    {
+      "code": 6142,
+      "name": "basketVaultsOpen",
+      "msg": "Basket vaults are still open; sweep the series before closing it"
+    },
+    {
+      "code": 6143,
+      "name": "yieldSharesOutstanding",
+      "msg": "Escrow is still lent to the yield venue; unwind its shares first"
+    },
+    {
+      "code": 6144,
+      "name": "legacySeriesAccounts",
+      "msg": "Migrating a legacy series takes the mints and vaults it stores and the protocol config"
+    },
+    {
+      "code": 6145,
+      "name": "legacyPutRestricted",
+      "msg": "Legacy puts can only be burned and redeemed"
+    },
+    {
+      "code": 6146,
+      "name": "primaryOracleRequired",
+      "msg": "The series' primary oracle account must be passed"
     }
   ],
-  "accounts": [
+  "types": [
+    {
+      "name": "approvedMint",
+      "docs": [
+        "Governance approval of a mint for use as collateral or consideration",
+        "(seeds = [\"approved_mint\", mint])",
+        "",
+        "Only consulted while the protocol config's `mint_allowlist_enabled` is set."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Mint the approval applies to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "Whether new series may use the mint (cleared to revoke)"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin approves or revokes a collateral/consideration mint"
+      ],
+      "name": "approvedMintUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "approved",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a scheduled parameter change takes effect"
+      ],
+      "name": "changeApplied",
+      "type": {
+        "fields": [
+          {
+            "name": "pendingChange",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin or guardian cancels a scheduled parameter change"
+      ],
+      "name": "changeCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "pendingChange",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "cancelledBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin schedules a timelocked parameter change"
+      ],
+      "name": "changeScheduled",
+      "type": {
+        "fields": [
+          {
+            "name": "pendingChange",
+            "type": "pubkey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "type": {
+              "defined": {
+                "name": "parameterChange"
+              }
+            }
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "collateralBasket",
+      "docs": [
+        "Governance-defined substitutes for one escrow mint (seeds =",
+        "[\"collateral_basket\", base_mint]), e.g. USDT for USDC-backed puts",
+        "Series that opt in accept the substitutes at par, each in its own vault"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseMint",
+            "docs": [
+              "Escrow mint the substitutes stand in for"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "mints",
+            "docs": [
+              "Accepted substitutes (Pubkey::default() marks unused slots)"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin replaces the substitutes accepted for an escrow mint"
+      ],
+      "name": "collateralBasketUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "baseMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "mints",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a series opts into lending its escrow"
+      ],
+      "name": "collateralYieldEnabled",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "yieldVenue",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "combo",
+      "docs": [
+        "Straddle/strangle basket: call and put options of one underlying and",
+        "expiry locked 1:1 behind a single combo token",
+        "(PDA, seeds = [\"combo\", call_series, put_series])",
+        "",
+        "Each combo token is a claim on one call and one put held in the combo's",
+        "escrows; `decompose_combo` burns it to get both legs back."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "callSeries",
+            "docs": [
+              "Call leg's series"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "putSeries",
+            "docs": [
+              "Put leg's series (strike at or below the call's)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "comboMint",
+            "docs": [
+              "Combo token mint (PDA, seeds = [\"combo_mint\", combo]; combo is authority)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "locked",
+            "docs": [
+              "Pairs locked in the escrows (equals the combo token supply)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the protocol admin nominates a successor"
+      ],
+      "name": "configAdminProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the nominated successor accepts the protocol admin role"
+      ],
+      "name": "configAdminTransferred",
+      "type": {
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when redemption tokens are converted into exercise proceeds before expiry"
+      ],
+      "name": "considerationClaimed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Exercised units converted (redemption tokens burned)"
+            ],
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Paid out of the vault the exercisers paid into"
+            ],
+            "name": "proceeds",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Funding share paid separately in consideration (puts; calls' is in `proceeds`)"
+            ],
+            "name": "funding",
+            "type": "u64"
+          },
+          {
+            "name": "exercisedClaimed",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Redemption mint supply after the burn"
+            ],
+            "name": "redemptionSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the series creation bond"
+      ],
+      "name": "creationBondUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "creationBond",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "creatorStats",
+      "docs": [
+        "Per-creator series creation tracking (seeds = [\"creator_stats\", creator])"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "Creator this account tracks"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "Epoch `series_this_epoch` counts for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "seriesThisEpoch",
+            "docs": [
+              "Series created in `epoch`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "lastCreatedAt",
+            "docs": [
+              "Timestamp of the creator's latest series"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalSeries",
+            "docs": [
+              "Lifetime series count"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a fully redeemed series' leftover vault dust goes to the treasury"
+      ],
+      "name": "dustSwept",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "consideration",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "emergencyWithdrawal",
+      "docs": [
+        "Queued last-resort withdrawal from a series vault",
+        "(PDA, seeds = [\"emergency_withdrawal\", vault]; at most one per vault)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "optionContext",
+            "docs": [
+              "Series whose vault is being drained"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "Vault of the series (see `WithdrawalVault`)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "docs": [
+              "Token account receiving the funds (fixed at queue time)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount to withdraw, in vault token units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "justificationHash",
+            "docs": [
+              "Hash of the published off-chain justification"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "queuedAt",
+            "docs": [
+              "Time the withdrawal was queued"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "docs": [
+              "Earliest time the withdrawal can be executed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the guardian (or admin) cancels a queued emergency withdrawal"
+      ],
+      "name": "emergencyWithdrawalCancelled",
+      "type": {
+        "fields": [
+          {
+            "name": "withdrawal",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a queued emergency withdrawal is executed after the timelock"
+      ],
+      "name": "emergencyWithdrawalExecuted",
+      "type": {
+        "fields": [
+          {
+            "name": "withdrawal",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin queues an emergency withdrawal"
+      ],
+      "name": "emergencyWithdrawalQueued",
+      "type": {
+        "fields": [
+          {
+            "name": "withdrawal",
+            "type": "pubkey"
+          },
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "justificationHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "executableAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a series lends idle escrow (`shares` minted by the venue)"
+      ],
+      "name": "escrowLent",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a series unwinds venue shares back into its escrow vault",
+        "(`received` above `principal` is yield, below it a loss)"
+      ],
+      "name": "escrowUnwound",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "principal",
+            "type": "u64"
+          },
+          {
+            "name": "received",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "exerciseStyle",
+      "docs": [
+        "When option holders may exercise"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "american"
+          },
+          {
+            "name": "european"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the expiry grid or its allowlist"
+      ],
+      "name": "expiryGridUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "interval",
+            "type": "i64"
+          },
+          {
+            "name": "offset",
+            "type": "i64"
+          },
+          {
+            "name": "exempt",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the time-to-expiry limits"
+      ],
+      "name": "expiryLimitsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "minTimeToExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "maxTimeToExpiry",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin switches protocol features on or off"
+      ],
+      "name": "featuresUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "disabledFeatures",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a mint or exercise pays the protocol fee"
+      ],
+      "name": "feeCharged",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin replaces the fee-exempt accounts"
+      ],
+      "name": "feeExemptUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "accounts",
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "feeStats",
+      "docs": [
+        "A user's trading volume in one quote mint",
+        "(PDA, seeds = [\"fee_stats\", user, consideration_mint]; opt-in)",
+        "",
+        "Mints and exercises that pass the account add their strike notional to it,",
+        "and the protocol's fee tiers discount the user's fees by the volume",
+        "recorded before each one."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "docs": [
+              "Account the volume belongs to (the signer of the recorded trades)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "considerationMint",
+            "docs": [
+              "Mint the volume is counted in (the consideration of the series traded)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "volume",
+            "docs": [
+              "Lifetime strike notional minted and exercised, in raw consideration units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "docs": [
+        "Fee discount earned by a user's recorded volume"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minVolume",
+            "docs": [
+              "Volume needed, in whole consideration tokens (e.g. 1_000_000 = $1M of USDC)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "discountBps",
+            "docs": [
+              "Share of the fee waived, in basis points (10_000 = no fee)"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin replaces the fee volume tiers"
+      ],
+      "name": "feeTiersUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "tiers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTier"
+                }
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the mint and exercise fees"
+      ],
+      "name": "feesUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "mintFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "exerciseFeeBps",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin withdraws collected fees"
+      ],
+      "name": "feesWithdrawn",
+      "type": {
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when `accrue_funding` brings a perpetual series' funding index up",
+        "to date (funding owed per unit of underlying, quoted like the strike)"
+      ],
+      "name": "fundingAccrued",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "fundingRateBps",
+            "type": "u32"
+          },
+          {
+            "name": "fundingIndex",
+            "type": "u64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin nominates a Realms governance's native treasury"
+      ],
+      "name": "governanceAdminProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "governanceProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "governance",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the guardian key"
+      ],
+      "name": "guardianUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "previousGuardian",
+            "type": "pubkey"
+          },
+          {
+            "name": "newGuardian",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a fee pays its insurance share into the fund"
+      ],
+      "name": "insuranceContributed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Lifetime contributions, including this one"
+            ],
+            "name": "contributed",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "insuranceFund",
+      "docs": [
+        "Protocol insurance fund in one mint",
+        "(PDA, seeds = [\"insurance_fund\", mint])",
+        "",
+        "Mints and exercises send `insurance_share_bps` of the protocol's part of",
+        "their fee to a token account of this PDA, and the admin pays it out to",
+        "series vaults that hold less than their accounting says."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Mint the fund holds"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "contributed",
+            "docs": [
+              "Lifetime fee shares paid in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paidOut",
+            "docs": [
+              "Lifetime payouts to series vaults"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin opens the insurance fund of a mint"
+      ],
+      "name": "insuranceFundOpened",
+      "type": {
+        "fields": [
+          {
+            "name": "insuranceFund",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin covers a series vault's shortfall from the fund"
+      ],
+      "name": "insurancePaidOut",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Shortfall before the payout"
+            ],
+            "name": "shortfall",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Lifetime payouts, including this one"
+            ],
+            "name": "paidOut",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the insurance fund's share of fees"
+      ],
+      "name": "insuranceShareUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "insuranceShareBps",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a keeper liquidates part of a margin position"
+      ],
+      "name": "marginLiquidated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "writerPosition",
+            "type": "pubkey"
+          },
+          {
+            "name": "writer",
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidator",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "seized",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Position health before the liquidation (bps of maintenance margin)"
+            ],
+            "name": "healthBps",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin switches the mint allowlist on or off"
+      ],
+      "name": "mintAllowlistUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the risky mint extensions series may use"
+      ],
+      "name": "mintExtensionsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "allowedMintExtensions",
+            "type": "u32"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when option tokens claim their netted payoff after settlement"
+      ],
+      "name": "netPayoffClaimed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "consideration",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Option mint supply after the burn"
+            ],
+            "name": "optionSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a series authority creates an OpenBook market for its option token"
+      ],
+      "name": "openbookMarketOpened",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "market",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteLotSize",
+            "type": "i64"
+          },
+          {
+            "name": "baseLotSize",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "openbookMarketParams",
+      "docs": [
+        "OpenBook market parameters the series authority chooses"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "quoteLotSize",
+            "docs": [
+              "Quote (consideration) units per price lot"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "baseLotSize",
+            "docs": [
+              "Base (option token) units per size lot"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "makerFee",
+            "docs": [
+              "Fees in units of 1e-6; a negative maker fee is a rebate"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "takerFee",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionData",
+      "docs": [
+        "Core data struct stored on-chain representing an option series",
+        "",
+        "PDA Seeds (used to derive the OptionContext address):",
+        "- \"option_context\"",
+        "- collateral_mint",
+        "- consideration_mint",
+        "- strike_price",
+        "- expiration",
+        "- option_type (as a u8)",
+        "",
+        "Stored Data (NOT used in PDA derivation, but stored in the account):",
+        "- Derived PDAs (option_mint, redemption_mint, vaults)",
+        "- Runtime tracking (total_supply, exercised_amount)",
+        "- Settlement (oracle hierarchy and the recorded settlement price)",
+        "- Off-chain metadata URI (set at creation; changeable only while empty or",
+        "before any options are minted)",
+        "",
+        "Layout: fixed-size fields come first so their byte offsets are stable for",
+        "getProgramAccounts memcmp filters (see the `*_OFFSET` constants below).",
+        "They end with the schema fields (`version` and the `reserved` space new",
+        "fields are carved from), so raw readers find the version at",
+        "`VERSION_OFFSET`. Variable-length fields (Strings) must always stay at the",
+        "end. Accounts older than versioning lack the schema fields and have their",
+        "Strings where they now sit, until `migrate_series` moves them up."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collateralMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "considerationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "strikePrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiration",
+            "type": "i64"
+          },
+          {
+            "name": "optionType",
+            "type": {
+              "defined": {
+                "name": "optionType"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "optionMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "redemptionMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "considerationVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "exercisedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "primaryOracle",
+            "type": {
+              "defined": {
+                "name": "oracleFeed"
+              }
+            }
+          },
+          {
+            "name": "secondaryOracle",
+            "type": {
+              "defined": {
+                "name": "oracleFeed"
+              }
+            }
+          },
+          {
+            "name": "settlementPrice",
+            "type": "u64"
+          },
+          {
+            "name": "settlementSource",
+            "type": {
+              "defined": {
+                "name": "settlementSource"
+              }
+            }
+          },
+          {
+            "name": "lastOraclePrice",
+            "type": "u64"
+          },
+          {
+            "name": "lastOracleUpdate",
+            "type": "i64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": "u8"
+          },
+          {
+            "name": "exerciseStyle",
+            "type": {
+              "defined": {
+                "name": "exerciseStyle"
+              }
+            }
+          },
+          {
+            "name": "exerciseWindow",
+            "type": "u32"
+          },
+          {
+            "name": "exerciseCutoff",
+            "type": "u32"
+          },
+          {
+            "name": "settlementDelay",
+            "type": "u32"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          },
+          {
+            "name": "settledCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "settledConsideration",
+            "type": "u64"
+          },
+          {
+            "name": "settledSupply",
+            "type": "u64"
+          },
+          {
+            "name": "settledOptionSupply",
+            "type": "u64"
+          },
+          {
+            "name": "netOptionCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "netOptionConsideration",
+            "type": "u64"
+          },
+          {
+            "name": "exercisedClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "collateralDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "collateralPaid",
+            "type": "u64"
+          },
+          {
+            "name": "considerationReceived",
+            "type": "u64"
+          },
+          {
+            "name": "considerationPaid",
+            "type": "u64"
+          },
+          {
+            "name": "considerationClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "collateralRedeemed",
+            "type": "u64"
+          },
+          {
+            "name": "initialMarginBps",
+            "type": "u16"
+          },
+          {
+            "name": "maintenanceMarginBps",
+            "type": "u16"
+          },
+          {
+            "name": "marginWritten",
+            "type": "u64"
+          },
+          {
+            "name": "marginCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "spreadHedged",
+            "type": "u64"
+          },
+          {
+            "name": "creationBond",
+            "type": "u64"
+          },
+          {
+            "name": "lotSize",
+            "type": "u64"
+          },
+          {
+            "name": "minMintAmount",
+            "type": "u64"
+          },
+          {
+            "name": "contractMultiplier",
+            "type": "u64"
+          },
+          {
+            "name": "strikeExponent",
+            "type": "u8"
+          },
+          {
+            "name": "strikeScale",
+            "type": "u8"
+          },
+          {
+            "name": "binaryPayout",
+            "type": "u64"
+          },
+          {
+            "name": "knockoutBarrier",
+            "type": "u64"
+          },
+          {
+            "name": "knockedOutAt",
+            "type": "i64"
+          },
+          {
+            "name": "frozenAt",
+            "type": "i64"
+          },
+          {
+            "name": "fundingRateBps",
+            "type": "u32"
+          },
+          {
+            "name": "fundingStartedAt",
+            "type": "i64"
+          },
+          {
+            "name": "fundingIndex",
+            "type": "u64"
+          },
+          {
+            "name": "fundingUpdatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "fundingCollected",
+            "type": "u64"
+          },
+          {
+            "name": "fundingClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "acceptsBasket",
+            "type": "bool"
+          },
+          {
+            "name": "basketMints",
+            "type": {
+              "array": [
+                "pubkey",
+                2
+              ]
+            }
+          },
+          {
+            "name": "basketEscrow",
+            "type": {
+              "array": [
+                "u64",
+                2
+              ]
+            }
+          },
+          {
+            "name": "transferHookProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "freezable",
+            "type": "bool"
+          },
+          {
+            "name": "complianceAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "gateMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "yieldVenue",
+            "type": "pubkey"
+          },
+          {
+            "name": "yieldPrincipal",
+            "type": "u64"
+          },
+          {
+            "name": "yieldShares",
+            "type": "u64"
+          },
+          {
+            "name": "market",
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                16
+              ]
+            }
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "underlyingSymbol",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionType",
+      "docs": [
+        "Kind of contract a series writes; stored (and used in the PDA seeds) as its",
+        "one-byte discriminant, so new kinds can be added without moving any address"
+      ],
+      "repr": {
+        "kind": "rust"
+      },
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "call"
+          },
+          {
+            "name": "put"
+          },
+          {
+            "name": "binaryCall"
+          },
+          {
+            "name": "binaryPut"
+          },
+          {
+            "name": "forward"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionVault",
+      "docs": [
+        "Pooled writing vault (DOV): depositors pool the escrow asset for shares and",
+        "the vault writes one series per round, listing the options it mints on the",
+        "marketplace (PDA, seeds = [\"option_vault\", manager, underlying_mint,",
+        "quote_mint, option_type])",
+        "",
+        "Covered-call vaults pool the underlying; cash-secured put vaults pool the",
+        "quote mint, and hold the underlying their puts are assigned until the",
+        "manager re-lists it.",
+        "",
+        "Tokens are held by the vault authority, a system-owned PDA (seeds =",
+        "[\"vault_authority\", option_vault]) that signs as the user of the round's",
+        "option and marketplace instructions and pays the rent of their accounts",
+        "(the manager keeps it funded). The vault's balances are its authority's",
+        "associated accounts of the underlying and the quote mint."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "manager",
+            "docs": [
+              "Picks each round's series and ask price"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "underlyingMint",
+            "docs": [
+              "Collateral mint of the series the vault writes"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteMint",
+            "docs": [
+              "Consideration mint of the series the vault writes (premiums are paid in it)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "optionType",
+            "docs": [
+              "Kind of series written each round"
+            ],
+            "type": {
+              "defined": {
+                "name": "optionType"
+              }
+            }
+          },
+          {
+            "name": "shareMint",
+            "docs": [
+              "Depositors' share token (seeds = [\"vault_shares\", option_vault])"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "series",
+            "docs": [
+              "Series of the open round; default() between rounds"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "ask",
+            "docs": [
+              "Marketplace ask of the open round's options"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "round",
+            "docs": [
+              "Rounds started so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "written",
+            "docs": [
+              "Options written in the open round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "listing",
+            "docs": [
+              "Marketplace ask of re-listed assigned underlying (put vaults);",
+              "default() when none is open"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bumps"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a manager opens a pooled writing vault"
+      ],
+      "name": "optionVaultCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "manager",
+            "type": "pubkey"
+          },
+          {
+            "name": "underlyingMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "optionType",
+            "type": {
+              "defined": {
+                "name": "optionType"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a holder exercises options"
+      ],
+      "name": "optionsExercised",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "strikePayment",
+            "type": "u64"
+          },
+          {
+            "name": "funding",
+            "type": "u64"
+          },
+          {
+            "name": "exercisedAmount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Option mint supply after the burn"
+            ],
+            "name": "optionSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a writer mints option and redemption pairs"
+      ],
+      "name": "optionsMinted",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Escrow the vaults received for them"
+            ],
+            "name": "escrow",
+            "type": "u64"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "oracleFeed",
+      "docs": [
+        "A single configured price feed (kind + account address + guard thresholds)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": {
+              "defined": {
+                "name": "oracleKind"
+              }
+            }
+          },
+          {
+            "name": "feed",
+            "type": "pubkey"
+          },
+          {
+            "name": "guard",
+            "type": {
+              "defined": {
+                "name": "oracleGuard"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleGuard",
+      "docs": [
+        "Per-feed thresholds enforced on every oracle read (settlement, observation,",
+        "and any later consumer), so a single bad print can never be trusted"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxStaleness",
+            "docs": [
+              "Max seconds between publish time and the reference time (0 = default)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxConfidenceBps",
+            "docs": [
+              "Max confidence interval as bps of price (0 = unchecked)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxDeviationBps",
+            "docs": [
+              "Max move vs the previous accepted reading, in bps (0 = unchecked)"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleKind",
+      "docs": [
+        "Oracle backend a series feed is read from"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "pyth"
+          },
+          {
+            "name": "switchboard"
+          },
+          {
+            "name": "stakePool"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin clears a series' deviation reference"
+      ],
+      "name": "oracleReferenceReset",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousPrice",
+            "type": "u64"
+          },
+          {
+            "name": "resetBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a user burns matched pairs for the locked obligation"
+      ],
+      "name": "pairBurned",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Escrow refunded, plus the strike refunded by forwards"
+            ],
+            "name": "refund",
+            "type": "u64"
+          },
+          {
+            "name": "strikeRefund",
+            "type": "u64"
+          },
+          {
+            "name": "totalSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "parameterChange",
+      "docs": [
+        "Timelocked admin parameter change, mirroring the setter it replaces"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "fees",
+            "fields": [
+              {
+                "name": "mintFeeBps",
+                "type": "u16"
+              },
+              {
+                "name": "exerciseFeeBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "feeTiers",
+            "fields": [
+              {
+                "name": "tiers",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "feeTier"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "feeExempt",
+            "fields": [
+              {
+                "name": "accounts",
+                "type": {
+                  "vec": "pubkey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "referralShare",
+            "fields": [
+              {
+                "name": "referralShareBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "approvedMint",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              },
+              {
+                "name": "approved",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "mintAllowlist",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "timelockDelay",
+            "fields": [
+              {
+                "name": "timelockDelay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "insuranceShare",
+            "fields": [
+              {
+                "name": "insuranceShareBps",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "creationBond",
+            "fields": [
+              {
+                "name": "creationBond",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "rateLimits",
+            "fields": [
+              {
+                "name": "maxSeriesPerEpoch",
+                "type": "u16"
+              },
+              {
+                "name": "creationCooldown",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingChange",
+      "docs": [
+        "Admin change waiting out the protocol's timelock",
+        "(PDA, seeds = [\"pending_change\", id (u64 LE)])"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "docs": [
+              "Admin-chosen identifier (lets proposals derive the address up front)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "change",
+            "docs": [
+              "Change applied by `apply_change`"
+            ],
+            "type": {
+              "defined": {
+                "name": "parameterChange"
+              }
+            }
+          },
+          {
+            "name": "scheduledAt",
+            "docs": [
+              "Time the change was scheduled"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "docs": [
+              "Earliest time the change can be applied"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programVersion",
+      "docs": [
+        "Last version announced by this deployment (singleton PDA, seeds = [\"program_version\"])"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "announcedSlot",
+            "docs": [
+              "Slot of the latest announcement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "configHash",
+            "docs": [
+              "Hash of the ProtocolConfig account data at announcement time"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Semantic version of the latest announcement (empty before the first)"
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted once per deployed version so indexers can detect supported capabilities"
+      ],
+      "name": "programVersionAnnounced",
+      "type": {
+        "fields": [
+          {
+            "name": "programId",
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "type": "string"
+          },
+          {
+            "name": "previousVersion",
+            "type": "string"
+          },
+          {
+            "name": "capabilities",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "configHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "docs": [
+        "Global protocol configuration (singleton PDA, seeds = [\"protocol_config\"])"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Admin allowed to update protocol parameters"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Proposed successor admin (Pubkey::default() when none; two-step transfer)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxSeriesPerEpoch",
+            "docs": [
+              "Max series a single creator may create per Solana epoch (0 = unlimited)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "creationCooldown",
+            "docs": [
+              "Minimum seconds between two series created by the same creator (0 = none)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Guardian allowed to cancel queued emergency withdrawals and pause the protocol",
+              "or a series (Pubkey::default() when unset)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Owner of the token accounts receiving swept series funds (Pubkey::default() when unset)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "unclaimedTimeout",
+            "docs": [
+              "Seconds after expiration before a series' unclaimed funds can be recovered (0 = disabled)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "creationBond",
+            "docs": [
+              "Lamports a creator locks in each new series, refunded when it is closed (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiryGridInterval",
+            "docs": [
+              "Spacing of the expiry grid in seconds; expirations must fall on",
+              "`expiry_grid_offset + k * expiry_grid_interval` (0 = any expiration)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "expiryGridOffset",
+            "docs": [
+              "First grid point, in seconds since the Unix epoch (e.g. a Friday 08:00 UTC)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "expiryGridExempt",
+            "docs": [
+              "Creators allowed to list off-grid expirations (Pubkey::default() marks unused slots)"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "minTimeToExpiry",
+            "docs": [
+              "Shortest time to expiration a new series may have, in seconds (0 = none)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxTimeToExpiry",
+            "docs": [
+              "Longest time to expiration a new series may have, in seconds (0 = none)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "mintFeeBps",
+            "docs": [
+              "Fee on minting, in basis points of the escrow locked (0 = none)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "exerciseFeeBps",
+            "docs": [
+              "Fee on exercise, in basis points of the strike paid (0 = none)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "disabledFeatures",
+            "docs": [
+              "Features the admin has switched off (`FEATURE_*` bits; 0 = everything on)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "feeExempt",
+            "docs": [
+              "Accounts (e.g. market makers) charged no mint or exercise fee",
+              "(Pubkey::default() marks unused slots)"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeTiers",
+            "docs": [
+              "Volume discounts, by increasing `min_volume` (zeroed tiers are unused)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "feeTier"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "referralShareBps",
+            "docs": [
+              "Share of each fee paid to the referrer passed with it, in basis points (0 = none)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Protocol-wide pause set by the admin or guardian: blocks mint and",
+              "exercise in every series, never burn or redeem"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "mintAllowlistEnabled",
+            "docs": [
+              "Whether new series need both mints approved by governance (an",
+              "`ApprovedMint` PDA); false keeps creation permissionless"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Seconds a scheduled fee or allowlist change waits before it can be",
+              "applied (0 = changes apply immediately through the setters)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "insuranceShareBps",
+            "docs": [
+              "Share of the protocol's part of each fee paid into the mint's",
+              "insurance fund, in basis points (0 = none)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "allowedMintExtensions",
+            "docs": [
+              "Risky Token-2022 mint extensions (`MINT_EXTENSION_*` bits) new series",
+              "may still use as collateral or consideration (0 = all refused)"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin or guardian pauses or resumes the whole protocol"
+      ],
+      "name": "protocolPauseUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "updatedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the per-creator series creation limits change"
+      ],
+      "name": "rateLimitsUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "maxSeriesPerEpoch",
+            "type": "u16"
+          },
+          {
+            "name": "creationCooldown",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when redemption tokens are redeemed after settlement"
+      ],
+      "name": "redeemed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "consideration",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Redemption mint supply after the burn"
+            ],
+            "name": "redemptionSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "redemptionOutcome",
+      "docs": [
+        "Returned to the caller via return data"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlementPrice",
+            "type": "u64"
+          },
+          {
+            "name": "inTheMoney",
+            "type": "bool"
+          },
+          {
+            "name": "optionCollateral",
+            "docs": [
+              "Option holder: netted payoff claim_net_payoff pays for `amount` options",
+              "(calls are paid in collateral, puts and binaries in consideration)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "optionConsideration",
+            "type": "u64"
+          },
+          {
+            "name": "redemptionCollateral",
+            "docs": [
+              "Redemption holder: pro-rata vault payout for `amount` redemption tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redemptionConsideration",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a referrer receives their share of a fee"
+      ],
+      "name": "referralPaid",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "referrer",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the admin changes the referrers' share of fees"
+      ],
+      "name": "referralShareUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "referralShareBps",
+            "type": "u16"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "referralStats",
+      "docs": [
+        "What a referrer (an integrator, say) has earned in one mint",
+        "(PDA, seeds = [\"referral_stats\", referrer, mint])",
+        "",
+        "Mints and exercises that pass a referrer's token account and stats send",
+        "the protocol's referral share of their fee to the referrer."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "referrer",
+            "docs": [
+              "Owner of the token accounts that receive the referral share"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint the share is paid in"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "earned",
+            "docs": [
+              "Lifetime referral share received"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referredFees",
+            "docs": [
+              "Fees the share was paid out of"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the current series authority nominates a successor"
+      ],
+      "name": "seriesAuthorityProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the nominated successor accepts the series authority"
+      ],
+      "name": "seriesAuthorityTransferred",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a dead series' accounts are closed and their rent refunded"
+      ],
+      "name": "seriesClosed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Lamports held by the OptionContext (rent plus any unrefunded bond)"
+            ],
+            "name": "lamports",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "seriesConfig",
+      "docs": [
+        "Series settings supplied at creation that are NOT part of the PDA seeds"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "primaryOracle",
+            "type": {
+              "defined": {
+                "name": "oracleFeed"
+              }
+            }
+          },
+          {
+            "name": "secondaryOracle",
+            "type": {
+              "defined": {
+                "name": "oracleFeed"
+              }
+            }
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "underlyingSymbol",
+            "type": "string"
+          },
+          {
+            "name": "exerciseStyle",
+            "type": {
+              "defined": {
+                "name": "exerciseStyle"
+              }
+            }
+          },
+          {
+            "name": "exerciseWindow",
+            "type": "u32"
+          },
+          {
+            "name": "exerciseCutoff",
+            "type": "u32"
+          },
+          {
+            "name": "settlementDelay",
+            "type": "u32"
+          },
+          {
+            "name": "initialMarginBps",
+            "type": "u16"
+          },
+          {
+            "name": "maintenanceMarginBps",
+            "type": "u16"
+          },
+          {
+            "name": "lotSize",
+            "type": "u64"
+          },
+          {
+            "name": "minMintAmount",
+            "type": "u64"
+          },
+          {
+            "name": "contractMultiplier",
+            "docs": [
+              "Collateral units one option token covers; 0 means 1"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "strikeExponent",
+            "docs": [
+              "Decimals of `strike_price`; None quotes it in raw consideration units",
+              "(the consideration mint's decimals)"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "binaryPayout",
+            "docs": [
+              "Binary series only: consideration paid per collateral unit if the",
+              "series finishes in the money, quoted like the strike"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "knockoutBarrier",
+            "docs": [
+              "Knock-out barrier in strike units (above the strike for calls, below",
+              "it for puts; needs a primary oracle); 0 means none"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fundingRateBps",
+            "docs": [
+              "Perpetual series only: annual funding longs pay shorts, in bps of the",
+              "strike"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "acceptsBasket",
+            "docs": [
+              "Lets writers escrow the governance basket's substitutes for the escrow",
+              "mint (not with margin, funding or forwards)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "transferHookProgram",
+            "docs": [
+              "Compliance program the option and redemption mints' Token-2022",
+              "transfer hook calls (Token-2022 series only); default() means none"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "freezable",
+            "docs": [
+              "Makes the series PDA freeze authority of the option and redemption",
+              "mints, so holders' accounts can be frozen through",
+              "`set_token_account_frozen` (regulated series)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "complianceAuthority",
+            "docs": [
+              "Key allowed to freeze and thaw a freezable series' token accounts;",
+              "default() leaves it to the series authority"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "gateMint",
+            "docs": [
+              "Credential mint (e.g. a KYC NFT or soulbound token) users must hold",
+              "to mint or exercise; default() leaves the series permissionless"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
-      "name": "optionData",
-      "discriminator": [
-        241,
-        2,
-        215,
-        22,
-        100,
-        177,
-        168,
-        108
-      ]
-    }
-  ],
-  "errors": [
+      "docs": [
+        "Emitted when a series is created (by `create_option` or a batch)"
+      ],
+      "name": "seriesCreated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "considerationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "optionMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "redemptionMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "strikePrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiration",
+            "type": "i64"
+          },
+          {
+            "name": "optionType",
+            "type": {
+              "defined": {
+                "name": "optionType"
+              }
+            }
+          }
+        ],
+        "kind": "struct"
+      }
+    },
     {
-      "code": 6000,
-      "name": "expirationInPast",
-      "msg": "Expiration must be in the future"
+      "docs": [
+        "Emitted when the admin or guardian pauses or resumes a series in an emergency"
+      ],
+      "name": "seriesEmergencyPauseUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "updatedBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the guardian freezes a compromised series"
+      ],
+      "name": "seriesFrozen",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "frozenAt",
+            "type": "i64"
+          },
+          {
+            "name": "frozenBy",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when `record_knockout` finds a series' barrier breached"
+      ],
+      "name": "seriesKnockedOut",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "barrier",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "knockedOutAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a series records the marketplace market its option token",
+        "trades on"
+      ],
+      "name": "seriesMarketOpened",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "market",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the series authority rewrites a series' token metadata",
+        "(`name` is the option token's)"
+      ],
+      "name": "seriesMetadataUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when migrate_series brings a series to the current account layout"
+      ],
+      "name": "seriesMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the series authority pauses or resumes a series"
+      ],
+      "name": "seriesPauseUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when settle_expiry freezes a series and snapshots its vaults"
+      ],
+      "name": "seriesSettled",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlementPrice",
+            "type": "u64"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "consideration",
+            "type": "u64"
+          },
+          {
+            "name": "redemptionSupply",
+            "type": "u64"
+          },
+          {
+            "name": "optionSupply",
+            "type": "u64"
+          },
+          {
+            "name": "netOptionCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "netOptionConsideration",
+            "type": "u64"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "settlementAttestation",
+      "docs": [
+        "Settlement result of a series, kept for relayers and cross-chain mirrors",
+        "(PDA, seeds = [\"settlement_attestation\", option_context])",
+        "",
+        "Written once by `attest_settlement` after the series settles. The payout",
+        "ratios are kept as their numerators and denominator: each redemption token",
+        "is owed `settled_collateral / settled_supply` collateral and",
+        "`settled_consideration / settled_supply` consideration, and each option",
+        "token `net_option_collateral / settled_option_supply` collateral and",
+        "`net_option_consideration / settled_option_supply` consideration."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "optionContext",
+            "docs": [
+              "Series attested"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMint",
+            "docs": [
+              "Series terms, so consumers needn't read the series itself"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "considerationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "strikePrice",
+            "type": "u64"
+          },
+          {
+            "name": "expiration",
+            "type": "i64"
+          },
+          {
+            "name": "optionType",
+            "type": {
+              "defined": {
+                "name": "optionType"
+              }
+            }
+          },
+          {
+            "name": "settlementPrice",
+            "docs": [
+              "Settlement price in strike units (0 when settled without oracles)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settledAt",
+            "type": "i64"
+          },
+          {
+            "name": "settledCollateral",
+            "docs": [
+              "Redemption token payouts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settledConsideration",
+            "type": "u64"
+          },
+          {
+            "name": "settledSupply",
+            "type": "u64"
+          },
+          {
+            "name": "settledOptionSupply",
+            "docs": [
+              "Option token payouts (ITM netting)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "netOptionCollateral",
+            "type": "u64"
+          },
+          {
+            "name": "netOptionConsideration",
+            "type": "u64"
+          },
+          {
+            "name": "posted",
+            "docs": [
+              "Whether the attestation was also posted as a Wormhole message"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "wormholeSequence",
+            "docs": [
+              "Wormhole sequence of that message (0 when not posted)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "invalidStrikePrice",
-      "msg": "Strike price must be greater than zero"
+      "docs": [
+        "Emitted when a settled series' result is attested (and posted to Wormhole)"
+      ],
+      "name": "settlementAttested",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "attestation",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlementPrice",
+            "type": "u64"
+          },
+          {
+            "name": "posted",
+            "type": "bool"
+          },
+          {
+            "name": "wormholeSequence",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidAmount",
-      "msg": "Amount must be greater than zero"
+      "name": "settlementSource",
+      "docs": [
+        "Which feed in the hierarchy produced the settlement price"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "unsettled"
+          },
+          {
+            "name": "primary"
+          },
+          {
+            "name": "secondary"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "mathOverflow",
-      "msg": "Math operation overflow"
+      "name": "strikeTick",
+      "docs": [
+        "Governance-set strike increment for one consideration mint",
+        "(seeds = [\"strike_tick\", consideration_mint])"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "considerationMint",
+            "docs": [
+              "Consideration mint the tick applies to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tickSize",
+            "docs": [
+              "Strikes must be multiples of this many raw consideration units per",
+              "whole collateral token (0 = any strike)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "invalidUnderlyingMint",
-      "msg": "Invalid underlying mint"
+      "docs": [
+        "Emitted when the admin changes a consideration mint's strike tick size"
+      ],
+      "name": "strikeTickUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "considerationMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tickSize",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6005,
-      "name": "invalidCollateralVault",
-      "msg": "Invalid collateral vault"
+      "docs": [
+        "Emitted when a writer escrows a substitute in a series' basket vault"
+      ],
+      "name": "substituteDeposited",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "substituteMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrow",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6006,
-      "name": "optionExpired",
-      "msg": "Option has expired"
+      "docs": [
+        "Emitted when the admin sets how long scheduled parameter changes wait"
+      ],
+      "name": "timelockDelayUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "timelockDelay",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6007,
-      "name": "invalidOptionMint",
-      "msg": "Invalid option mint"
+      "docs": [
+        "Emitted when a freezable series' compliance key freezes or thaws a",
+        "holder's option or redemption token account"
+      ],
+      "name": "tokenAccountFrozen",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "frozen",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidRedemptionMint",
-      "msg": "Invalid redemption mint"
+      "docs": [
+        "Emitted when the admin changes the treasury owner"
+      ],
+      "name": "treasuryUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "previousTreasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "newTreasury",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6009,
-      "name": "invalidStrikeCurrency",
-      "msg": "Invalid strike currency"
+      "docs": [
+        "Emitted when an abandoned series' remaining funds are recovered to the treasury"
+      ],
+      "name": "unclaimedRecovered",
+      "type": {
+        "fields": [
+          {
+            "name": "optionContext",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateral",
+            "type": "u64"
+          },
+          {
+            "name": "consideration",
+            "type": "u64"
+          },
+          {
+            "name": "redemptionSupply",
+            "type": "u64"
+          },
+          {
+            "name": "optionSupply",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6010,
-      "name": "invalidCashVault",
-      "msg": "Invalid cash vault"
+      "docs": [
+        "Emitted when the admin changes the unclaimed-funds recovery timeout"
+      ],
+      "name": "unclaimedTimeoutUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "unclaimedTimeout",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6011,
-      "name": "insufficientCollateral",
-      "msg": "Insufficient collateral in vault"
+      "docs": [
+        "Emitted when a put vault's re-listing is closed (unsold underlying returned)"
+      ],
+      "name": "vaultListingClosed",
+      "type": {
+        "fields": [
+          {
+            "name": "optionVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "unsold",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6012,
-      "name": "optionNotExpired",
-      "msg": "Option has not expired yet"
+      "docs": [
+        "Emitted when a put vault lists the underlying it was assigned"
+      ],
+      "name": "vaultListingOpened",
+      "type": {
+        "fields": [
+          {
+            "name": "optionVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "listing",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6013,
-      "name": "noTokensIssued",
-      "msg": "No tokens have been issued"
+      "docs": [
+        "Emitted when a vault redeems a finished round's short side"
+      ],
+      "name": "vaultRoundSettled",
+      "type": {
+        "fields": [
+          {
+            "name": "optionVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "round",
+            "type": "u64"
+          },
+          {
+            "name": "series",
+            "type": "pubkey"
+          },
+          {
+            "name": "redeemed",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6014,
-      "name": "noShortTokens",
-      "msg": "User has no SHORT (redemption) tokens"
+      "docs": [
+        "Emitted when a vault writes a round's options and lists them"
+      ],
+      "name": "vaultRoundStarted",
+      "type": {
+        "fields": [
+          {
+            "name": "optionVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "round",
+            "type": "u64"
+          },
+          {
+            "name": "series",
+            "type": "pubkey"
+          },
+          {
+            "name": "written",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6015,
-      "name": "noCashAvailable",
-      "msg": "Cash vault has no funds available"
+      "docs": [
+        "Emitted when a depositor buys into (`deposit`) or leaves a vault"
+      ],
+      "name": "vaultSharesChanged",
+      "type": {
+        "fields": [
+          {
+            "name": "optionVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "deposit",
+            "type": "bool"
+          },
+          {
+            "name": "shares",
+            "type": "u64"
+          },
+          {
+            "name": "underlying",
+            "type": "u64"
+          },
+          {
+            "name": "quote",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6016,
-      "name": "noClaimableConsideration",
-      "msg": "No claimable consideration available for this user"
+      "name": "verticalSpread",
+      "docs": [
+        "Tokenized vertical spread between two series of the same mints and expiry",
+        "(PDA, seeds = [\"vertical_spread\", short_series, long_series])",
+        "",
+        "Writers lock long options of the further-OTM `long_series` plus the",
+        "spread's max loss and receive spread tokens, each paying the intrinsic",
+        "value of one `short_series` option at expiry. The locked longs cover",
+        "everything beyond the strike difference, so writers never post the short",
+        "leg's full backing. All amounts are in the asset writers of the short",
+        "series escrow (collateral for calls, consideration for puts)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shortSeries",
+            "docs": [
+              "Series whose intrinsic value spread tokens pay"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "longSeries",
+            "docs": [
+              "Further out-of-the-money series whose options writers lock"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "spreadMint",
+            "docs": [
+              "Spread token mint (PDA, seeds = [\"vertical_spread_mint\", vertical_spread])"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "written",
+            "docs": [
+              "Spread tokens written and not burned back by writers; after",
+              "settlement, what writers that haven't redeemed wrote"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "backing",
+            "docs": [
+              "Max-loss backing deposited by writers (until settlement)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "settledAt",
+            "docs": [
+              "Settlement timestamp (0 until `settle_vertical_spread`)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "settlementPrice",
+            "docs": [
+              "Long series' settlement price, in strike units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "holderPayout",
+            "docs": [
+              "Still owed to spread token holders after settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "writerPayout",
+            "docs": [
+              "Still owed to writers after settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "invalidOptionSeries",
-      "msg": "Invalid option series"
+      "name": "verticalSpreadPosition",
+      "docs": [
+        "What one writer has written into a vertical spread",
+        "(PDA, seeds = [\"vertical_spread_position\", vertical_spread, writer])",
+        "",
+        "Spread tokens are fungible across writers, so the position is what entitles",
+        "a writer to burn tokens for their backing or to their share of what's left",
+        "after settlement."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verticalSpread",
+            "docs": [
+              "Spread the position belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "writer",
+            "docs": [
+              "Writer"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "written",
+            "docs": [
+              "Spread tokens written and not burned back"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "invalidUser",
-      "msg": "Invalid user"
-    }
-  ],
-  "types": [
-    {
-      "name": "optionData",
+      "name": "writerPosition",
       "docs": [
-        "Core data struct stored on-chain representing an option series",
+        "Amount one writer has outstanding in a series",
+        "(PDA, seeds = [\"writer_position\", option_context, writer]; opt-in)",
         "",
-        "PDA Seeds (used to derive the OptionContext address):",
-        "- \"option_context\"",
-        "- collateral_mint",
-        "- consideration_mint",
-        "- strike_price",
-        "- expiration",
-        "- is_put",
+        "Redemption tokens are fungible and can change hands, so they can't say who",
+        "wrote what. Writers that open a position get their mints and paired burns",
+        "recorded here when they pass it to `mint` / `burn`.",
         "",
-        "Stored Data (NOT used in PDA derivation, but stored in the account):",
-        "- Derived PDAs (option_mint, redemption_mint, vaults)",
-        "- Runtime tracking (total_supply, exercised_amount)"
+        "The position is also the writer's margin account: options written on",
+        "margin are backed only by `margin_collateral`, never by the redemption pool."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "collateralMint",
+            "name": "optionContext",
+            "docs": [
+              "Series the position belongs to"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "considerationMint",
+            "name": "writer",
+            "docs": [
+              "Writer (the signer of the recorded mints)"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "strikePrice",
+            "name": "outstanding",
+            "docs": [
+              "Options written and not yet burned back"
+            ],
             "type": "u64"
           },
           {
-            "name": "expiration",
-            "type": "i64"
+            "name": "totalWritten",
+            "docs": [
+              "Lifetime amount written through this position"
+            ],
+            "type": "u64"
           },
           {
-            "name": "isPut",
-            "type": "bool"
+            "name": "marginWritten",
+            "docs": [
+              "Options written on margin (long leg only) and not yet bought back"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "marginCollateral",
+            "docs": [
+              "Margin held for `margin_written`, in the asset that backs the series",
+              "(collateral for calls, consideration for puts)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "spreadSeries",
+            "docs": [
+              "Series whose long options hedge the margin short (default() until the",
+              "first `create_spread`; shares mints and expiry with this series)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "spreadStrike",
+            "docs": [
+              "Strike of `spread_series`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "spreadLocked",
+            "docs": [
+              "Long options of `spread_series` locked in the position's spread escrow"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "healthBps",
+            "docs": [
+              "Health factor in bps at the last margin operation",
+              "(margin over the maintenance requirement; below 10_000 is liquidatable)"
+            ],
+            "type": "u64"
           },
           {
             "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "yieldVenue",
+      "docs": [
+        "Governance-approved lending reserve for one escrow mint (seeds =",
+        "[\"yield_venue\", escrow_mint]), e.g. a Solend-style USDC reserve",
+        "Series that opt in lend idle escrow to it and hold its share tokens"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrowMint",
+            "docs": [
+              "Escrow mint the reserve lends out"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "optionMint",
+            "name": "lendingProgram",
+            "docs": [
+              "Token-lending program of the reserve"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "redemptionMint",
+            "name": "lendingMarket",
+            "docs": [
+              "Lending market the reserve belongs to, and its derived authority"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "collateralVault",
+            "name": "lendingMarketAuthority",
             "type": "pubkey"
           },
           {
-            "name": "considerationVault",
+            "name": "reserve",
+            "docs": [
+              "Reserve account, its liquidity supply and its collateral (share) mint"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "totalSupply",
-            "type": "u64"
+            "name": "reserveLiquiditySupply",
+            "type": "pubkey"
           },
           {
-            "name": "exercisedAmount",
-            "type": "u64"
+            "name": "shareMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether series may opt in and lend; unwinding is always open"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "yieldVenueParams",
+      "docs": [
+        "Lending reserve addresses of a venue (the program reads and writes them by",
+        "position, so they are fixed once approved)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lendingProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "lendingMarket",
+            "type": "pubkey"
+          },
+          {
+            "name": "lendingMarketAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserve",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserveLiquiditySupply",
+            "type": "pubkey"
+          },
+          {
+            "name": "shareMint",
+            "type": "pubkey"
           }
         ]
       }
+    },
+    {
+      "docs": [
+        "Emitted when the admin approves or updates the lending venue of an escrow mint"
+      ],
+      "name": "yieldVenueUpdated",
+      "type": {
+        "fields": [
+          {
+            "name": "escrowMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "lendingProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "reserve",
+            "type": "pubkey"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          }
+        ],
+        "kind": "struct"
+      }
     }
   ]
 };
@@ -5,7 +5,7 @@
  * IDL can be found at `target/idl/spl_marketplace.json`.
  */
 export type SplMarketplace = {
-  "address": "DwPtSt28GcsFLkyPozvD1shNU8AXa6NkSgbRJRHfpGkU",
+  "address": "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz",
   "metadata": {
     "name": "splMarketplace",
     "version": "0.1.0",
@@ -13,6 +13,83 @@ export type SplMarketplace = {
     "description": "Generic SPL token marketplace with CLOB"
   },
   "instructions": [
+    {
+      "name": "acceptMarketAuthority",
+      "discriminator": [
+        147,
+        221,
+        80,
+        152,
+        170,
+        143,
+        134,
+        149
+      ],
+      "accounts": [
+        {
+          "name": "pendingAuthority",
+          "signer": true
+        },
+        {
+          "name": "market",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "announceVersion",
+      "discriminator": [
+        41,
+        42,
+        196,
+        233,
+        235,
+        145,
+        185,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programVersion",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  118,
+                  101,
+                  114,
+                  115,
+                  105,
+                  111,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
     {
       "name": "cancelOrder",
       "discriminator": [
@@ -197,6 +274,13 @@ export type SplMarketplace = {
         },
         {
           "name": "tokenProgram"
+        },
+        {
+          "name": "linkedSeries",
+          "docs": [
+            "Last, after the accounts existing clients already pass"
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -206,6 +290,84 @@ export type SplMarketplace = {
         }
       ]
     },
+    {
+      "name": "linkSeries",
+      "discriminator": [
+        130,
+        27,
+        174,
+        238,
+        49,
+        94,
+        148,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "market"
+          ]
+        },
+        {
+          "name": "market",
+          "writable": true
+        },
+        {
+          "name": "series"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMarket",
+      "discriminator": [
+        201,
+        113,
+        181,
+        120,
+        217,
+        60,
+        109,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "market",
+          "docs": [
+            "checked here, discriminator in the handler"
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "admin",
+          "docs": [
+            "Legacy markets only: the options protocol admin, backfilled as the",
+            "market authority"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "address, owner and discriminator checked in `protocol_admin`"
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "placeOrder",
       "discriminator": [
@@ -297,6 +459,13 @@ export type SplMarketplace = {
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "linkedSeries",
+          "docs": [
+            "Last, after the accounts existing clients already pass"
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -313,6 +482,38 @@ export type SplMarketplace = {
           "type": "bool"
         }
       ]
+    },
+    {
+      "name": "proposeMarketAuthority",
+      "discriminator": [
+        13,
+        245,
+        242,
+        143,
+        57,
+        27,
+        163,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "market"
+          ]
+        },
+        {
+          "name": "market",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
     }
   ],
   "accounts": [
@@ -341,6 +542,86 @@ export type SplMarketplace = {
         28,
         51
       ]
+    },
+    {
+      "name": "programVersion",
+      "discriminator": [
+        138,
+        104,
+        244,
+        197,
+        206,
+        47,
+        159,
+        154
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        204,
+        60,
+        246,
+        191,
+        130,
+        246,
+        244,
+        123
+      ],
+      "name": "marketAuthorityProposed"
+    },
+    {
+      "discriminator": [
+        156,
+        254,
+        217,
+        151,
+        102,
+        5,
+        15,
+        193
+      ],
+      "name": "marketAuthorityTransferred"
+    },
+    {
+      "discriminator": [
+        196,
+        0,
+        143,
+        107,
+        73,
+        246,
+        52,
+        158
+      ],
+      "name": "marketMigrated"
+    },
+    {
+      "discriminator": [
+        29,
+        89,
+        63,
+        65,
+        140,
+        88,
+        33,
+        163
+      ],
+      "name": "marketSeriesLinked"
+    },
+    {
+      "discriminator": [
+        72,
+        71,
+        119,
+        167,
+        178,
+        118,
+        40,
+        195
+      ],
+      "name": "programVersionAnnounced"
     }
   ],
   "errors": [
@@ -383,13 +664,56 @@ export type SplMarketplace = {
       "code": 6007,
       "name": "invalidMarket",
       "msg": "Invalid market"
+    },
+    {
+      "code": 6008,
+      "name": "noPendingAuthority",
+      "msg": "No authority transfer is pending"
+    },
+    {
+      "code": 6009,
+      "name": "invalidLinkedSeries",
+      "msg": "Linked series account is missing or does not match the market"
+    },
+    {
+      "code": 6010,
+      "name": "tradingHalted",
+      "msg": "Trading is halted: linked series is paused, frozen or expired"
+    },
+    {
+      "code": 6011,
+      "name": "versionAlreadyAnnounced",
+      "msg": "This program version has already been announced"
+    },
+    {
+      "code": 6012,
+      "name": "marketUpToDate",
+      "msg": "Market already uses the current account layout"
+    },
+    {
+      "code": 6013,
+      "name": "unsupportedSeriesVersion",
+      "msg": "Linked series uses an account layout this program can't read; migrate it"
+    },
+    {
+      "code": 6014,
+      "name": "legacyMarketAuthority",
+      "msg": "Migrating a legacy market takes the options protocol admin's signature"
+    },
+    {
+      "code": 6015,
+      "name": "invalidProtocolConfig",
+      "msg": "Account is not the options protocol's config"
     }
   ],
   "types": [
     {
       "name": "market",
       "docs": [
-        "Represents a trading market for a pair of SPL tokens"
+        "Represents a trading market for a pair of SPL tokens",
+        "",
+        "Layout: new fields are appended so the mint offsets stay stable for",
+        "memcmp filters (see the `*_OFFSET` constants)."
       ],
       "type": {
         "kind": "struct",
@@ -440,14 +764,148 @@ export type SplMarketplace = {
           {
             "name": "totalQuoteVolume",
             "type": "u64"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Market authority (creator by default; two-step transfer)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "docs": [
+              "Proposed successor authority (Pubkey::default() when none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "linkedSeries",
+            "docs": [
+              "Option series this market trades (Pubkey::default() when unlinked);",
+              "placement and fills halt while the series is paused, frozen or expired"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version (0 for markets created before versioning)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Zeroed space new fields take their bytes from"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
           }
         ]
       }
     },
+    {
+      "docs": [
+        "Emitted when the current market authority nominates a successor"
+      ],
+      "name": "marketAuthorityProposed",
+      "type": {
+        "fields": [
+          {
+            "name": "market",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the nominated successor accepts the market authority"
+      ],
+      "name": "marketAuthorityTransferred",
+      "type": {
+        "fields": [
+          {
+            "name": "market",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when migrate_market brings a market to the current account layout"
+      ],
+      "name": "marketMigrated",
+      "type": {
+        "fields": [
+          {
+            "name": "market",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the market authority links the market to an option series"
+      ],
+      "name": "marketSeriesLinked",
+      "type": {
+        "fields": [
+          {
+            "name": "market",
+            "type": "pubkey"
+          },
+          {
+            "name": "linkedSeries",
+            "type": "pubkey"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
     {
       "name": "order",
       "docs": [
-        "Represents a single limit order in the market"
+        "Represents a single limit order in the market",
+        "",
+        "Layout: every field is fixed-size, so \"orders in market X\", \"orders of",
+        "owner Y\" and \"bids/asks\" are memcmp filters at stable offsets (see the",
+        "`*_OFFSET` constants). Keep the field order and append new fields so",
+        "existing orders still deserialize."
       ],
       "type": {
         "kind": "struct",
@@ -517,6 +975,80 @@ export type SplMarketplace = {
           }
         ]
       }
+    },
+    {
+      "name": "programVersion",
+      "docs": [
+        "Last version announced by this deployment (singleton PDA)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "announcedSlot",
+            "docs": [
+              "Slot of the latest announcement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Semantic version of the latest announcement (empty before the first)"
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted once per deployed version so indexers can detect supported capabilities"
+      ],
+      "name": "programVersionAnnounced",
+      "type": {
+        "fields": [
+          {
+            "name": "programId",
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "type": "string"
+          },
+          {
+            "name": "previousVersion",
+            "type": "string"
+          },
+          {
+            "name": "capabilities",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "configHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ],
+        "kind": "struct"
+      }
     }
   ]
 };
@@ -0,0 +1,47 @@
+import { PublicKey } from '@solana/web3.js';
+import { BN, IdlTypes } from '@coral-xyz/anchor';
+import type { SolOptionProtocol } from '@/lib/idls/sol_option_protocol';
+
+export type SeriesConfig = IdlTypes<SolOptionProtocol>['seriesConfig'];
+export type OptionType = IdlTypes<SolOptionProtocol>['optionType'];
+
+// Oracle slot with no feed configured
+const NO_ORACLE = {
+    kind: { none: {} },
+    feed: PublicKey.default,
+    guard: { maxStaleness: 0, maxConfidenceBps: 0, maxDeviationBps: 0 },
+};
+
+// Plain American series: no oracle, margin, lots, barrier, hook or gate
+export function defaultSeriesConfig(overrides: Partial<SeriesConfig> = {}): SeriesConfig {
+    return {
+        primaryOracle: NO_ORACLE,
+        secondaryOracle: NO_ORACLE,
+        metadataUri: '',
+        underlyingSymbol: '',
+        exerciseStyle: { american: {} },
+        exerciseWindow: 0,
+        exerciseCutoff: 0,
+        settlementDelay: 0,
+        initialMarginBps: 0,
+        maintenanceMarginBps: 0,
+        lotSize: new BN(0),
+        minMintAmount: new BN(0),
+        contractMultiplier: new BN(0),
+        strikeExponent: null,
+        binaryPayout: new BN(0),
+        knockoutBarrier: new BN(0),
+        fundingRateBps: 0,
+        acceptsBasket: false,
+        transferHookProgram: PublicKey.default,
+        freezable: false,
+        complianceAuthority: PublicKey.default,
+        gateMint: PublicKey.default,
+        ...overrides,
+    };
+}
+
+// `option_type` argument (and PDA seed byte) of a vanilla call or put
+export function vanillaOptionType(isPut: boolean): OptionType {
+    return isPut ? { put: {} } : { call: {} };
+}
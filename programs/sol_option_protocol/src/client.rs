@@ -0,0 +1,435 @@
+//! Instruction builders for clients and for programs calling the protocol.
+//!
+//! Depend on the crate with the `cpi` feature (it implies `no-entrypoint`).
+//! On-chain callers then invoke `cpi::mint`, `cpi::exercise`, `cpi::redeem`
+//! and the rest with a `CpiContext` over `cpi::accounts::OptionContext`.
+//! Anchor generates both from the program. Callers that sign with their own
+//! PDA, or off-chain clients, can instead build the `Instruction` here and
+//! pass it to `invoke_signed`.
+//!
+//! Indexers list series with getProgramAccounts and the [`Memcmp`] filters
+//! built here: the account discriminator plus a field at its `*_OFFSET`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{Discriminator, InstructionData};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+
+pub use crate::accounts;
+#[cfg(feature = "cpi")]
+pub use crate::cpi;
+pub use crate::instruction;
+
+use crate::instructions::{OptionData, OptionType};
+use crate::state::ProtocolConfig;
+use crate::utils::pda::{
+    derive_option_context, derive_option_mint, derive_redemption_mint, derive_vaults,
+};
+
+/// Every address of a series the option instructions take
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeriesAddresses {
+    pub option_context: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub consideration_mint: Pubkey,
+    pub option_mint: Pubkey,
+    pub redemption_mint: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub consideration_vault: Pubkey,
+    /// SPL Token or Token-2022 (the owner of the series' mints)
+    pub token_program: Pubkey,
+}
+
+impl SeriesAddresses {
+    /// Addresses recorded in the series' account at `option_context`
+    pub fn of(option_context: Pubkey, data: &OptionData, token_program: Pubkey) -> Self {
+        Self {
+            option_context,
+            collateral_mint: data.collateral_mint,
+            consideration_mint: data.consideration_mint,
+            option_mint: data.option_mint,
+            redemption_mint: data.redemption_mint,
+            collateral_vault: data.collateral_vault,
+            consideration_vault: data.consideration_vault,
+            token_program,
+        }
+    }
+
+    /// Addresses of the series with the given terms, derived without reading
+    /// its account
+    pub fn derive(
+        collateral_mint: Pubkey,
+        consideration_mint: Pubkey,
+        strike_price: u64,
+        expiration: i64,
+        option_type: OptionType,
+        token_program: Pubkey,
+    ) -> Self {
+        let (option_context, _) = derive_option_context(
+            &collateral_mint,
+            &consideration_mint,
+            strike_price,
+            expiration,
+            option_type,
+        );
+        let (collateral_vault, consideration_vault) = derive_vaults(&option_context);
+        Self {
+            option_context,
+            collateral_mint,
+            consideration_mint,
+            option_mint: derive_option_mint(&option_context).0,
+            redemption_mint: derive_redemption_mint(&option_context).0,
+            collateral_vault,
+            consideration_vault,
+            token_program,
+        }
+    }
+
+    /// `owner`'s associated token account of `mint`, under the series' token program
+    pub fn associated(&self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(owner, mint, &self.token_program)
+    }
+}
+
+/// getProgramAccounts memcmp filter: `bytes` must sit at `offset` of the
+/// account data
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Memcmp {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+impl Memcmp {
+    fn new(offset: usize, bytes: &[u8]) -> Self {
+        Self {
+            offset,
+            bytes: bytes.to_vec(),
+        }
+    }
+}
+
+/// Filters matching every series (`OptionData`) account
+pub fn series_filter() -> Vec<Memcmp> {
+    vec![Memcmp::new(0, OptionData::DISCRIMINATOR)]
+}
+
+/// Filters matching one more field of a series at `offset`
+fn series_field_filter(offset: usize, bytes: &[u8]) -> Vec<Memcmp> {
+    let mut filters = series_filter();
+    filters.push(Memcmp::new(offset, bytes));
+    filters
+}
+
+/// Filters matching the series escrowing `collateral_mint`
+pub fn series_by_collateral_mint_filter(collateral_mint: &Pubkey) -> Vec<Memcmp> {
+    series_field_filter(OptionData::COLLATERAL_MINT_OFFSET, collateral_mint.as_ref())
+}
+
+/// Filters matching the series struck in `consideration_mint`
+pub fn series_by_consideration_mint_filter(consideration_mint: &Pubkey) -> Vec<Memcmp> {
+    series_field_filter(
+        OptionData::CONSIDERATION_MINT_OFFSET,
+        consideration_mint.as_ref(),
+    )
+}
+
+/// Filters matching the series expiring at `expiration`
+pub fn series_by_expiration_filter(expiration: i64) -> Vec<Memcmp> {
+    series_field_filter(OptionData::EXPIRATION_OFFSET, &expiration.to_le_bytes())
+}
+
+/// Filters matching the series whose option token is `option_mint`
+pub fn series_by_option_mint_filter(option_mint: &Pubkey) -> Vec<Memcmp> {
+    series_field_filter(OptionData::OPTION_MINT_OFFSET, option_mint.as_ref())
+}
+
+/// Filters matching the series `authority` administers
+pub fn series_by_authority_filter(authority: &Pubkey) -> Vec<Memcmp> {
+    series_field_filter(OptionData::AUTHORITY_OFFSET, authority.as_ref())
+}
+
+/// ProtocolConfig singleton
+pub fn protocol_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[ProtocolConfig::SEED], &crate::ID).0
+}
+
+/// `OptionContext` accounts of `user` trading with their associated token
+/// accounts, holding both legs and passing none of the optional accounts.
+/// Set `fee_vault`, `writer_position` and the like on the result when the
+/// series or the protocol needs them.
+pub fn option_context_accounts(series: &SeriesAddresses, user: &Pubkey) -> accounts::OptionContext {
+    accounts::OptionContext {
+        user: *user,
+        option_context: series.option_context,
+        protocol_config: protocol_config_address(),
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        option_owner: *user,
+        redemption_owner: *user,
+        user_collateral_account: series.associated(user, &series.collateral_mint),
+        user_consideration_account: series.associated(user, &series.consideration_mint),
+        user_option_account: series.associated(user, &series.option_mint),
+        user_redemption_account: series.associated(user, &series.redemption_mint),
+        token_program: series.token_program,
+        associated_token_program: AssociatedToken::id(),
+        system_program: System::id(),
+        writer_position: None,
+        fee_vault: None,
+        fee_stats: None,
+        referrer_account: None,
+        referral_stats: None,
+        insurance_fund: None,
+        insurance_vault: None,
+        credential_account: None,
+    }
+}
+
+/// Any instruction taking `OptionContext` accounts, e.g. `instruction::Burn`
+pub fn option_ix(accounts: accounts::OptionContext, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+/// `mint` of `amount` option and redemption tokens against `user`'s collateral
+pub fn mint_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    option_ix(
+        option_context_accounts(series, user),
+        instruction::Mint { amount },
+    )
+}
+
+/// `exercise` of `amount` of `user`'s options
+pub fn exercise_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    option_ix(
+        option_context_accounts(series, user),
+        instruction::Exercise { amount },
+    )
+}
+
+/// `redeem` of `amount` of `user`'s redemption tokens after settlement
+pub fn redeem_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    option_ix(
+        option_context_accounts(series, user),
+        instruction::Redeem { amount },
+    )
+}
+
+/// `mint_compact` of `amount` pairs to `user`'s existing associated accounts
+pub fn mint_compact_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    let accounts = accounts::MintAccounts {
+        user: *user,
+        option_context: series.option_context,
+        protocol_config: protocol_config_address(),
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        user_collateral_account: series.associated(user, &series.collateral_mint),
+        user_consideration_account: series.associated(user, &series.consideration_mint),
+        user_option_account: series.associated(user, &series.option_mint),
+        user_redemption_account: series.associated(user, &series.redemption_mint),
+        token_program: series.token_program,
+        writer_position: None,
+        fee_vault: None,
+        fee_stats: None,
+        referrer_account: None,
+        referral_stats: None,
+        insurance_fund: None,
+        insurance_vault: None,
+        credential_account: None,
+    };
+    compact_ix(accounts, instruction::MintCompact { amount })
+}
+
+/// `exercise_compact` of `amount` of `user`'s options
+pub fn exercise_compact_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    let accounts = accounts::ExerciseAccounts {
+        user: *user,
+        option_context: series.option_context,
+        protocol_config: protocol_config_address(),
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        user_collateral_account: series.associated(user, &series.collateral_mint),
+        user_consideration_account: series.associated(user, &series.consideration_mint),
+        user_option_account: series.associated(user, &series.option_mint),
+        token_program: series.token_program,
+        fee_vault: None,
+        fee_stats: None,
+        referrer_account: None,
+        referral_stats: None,
+        insurance_fund: None,
+        insurance_vault: None,
+        credential_account: None,
+    };
+    compact_ix(accounts, instruction::ExerciseCompact { amount })
+}
+
+/// `redeem_compact` of `amount` of `user`'s redemption tokens after settlement
+pub fn redeem_compact_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    let accounts = accounts::RedeemAccounts {
+        user: *user,
+        option_context: series.option_context,
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        user_collateral_account: series.associated(user, &series.collateral_mint),
+        user_consideration_account: series.associated(user, &series.consideration_mint),
+        user_redemption_account: series.associated(user, &series.redemption_mint),
+        token_program: series.token_program,
+    };
+    compact_ix(accounts, instruction::RedeemCompact { amount })
+}
+
+/// `burn_compact` of `amount` of `user`'s pairs
+pub fn burn_compact_ix(series: &SeriesAddresses, user: &Pubkey, amount: u64) -> Instruction {
+    let accounts = accounts::BurnAccounts {
+        user: *user,
+        option_context: series.option_context,
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        user_collateral_account: series.associated(user, &series.collateral_mint),
+        user_consideration_account: series.associated(user, &series.consideration_mint),
+        user_option_account: series.associated(user, &series.option_mint),
+        user_redemption_account: series.associated(user, &series.redemption_mint),
+        token_program: series.token_program,
+        writer_position: None,
+    };
+    compact_ix(accounts, instruction::BurnCompact { amount })
+}
+
+/// PsyOptions-compatible `mint_option` of `size` options by `user`. Its
+/// `fee_owner` slot carries the protocol config.
+pub fn psy_mint_option_ix(series: &SeriesAddresses, user: &Pubkey, size: u64) -> Instruction {
+    let accounts = accounts::PsyMintOption {
+        user_authority: *user,
+        underlying_asset_mint: series.collateral_mint,
+        underlying_asset_pool: series.collateral_vault,
+        underlying_asset_src: series.associated(user, &series.collateral_mint),
+        option_mint: series.option_mint,
+        minted_option_dest: series.associated(user, &series.option_mint),
+        writer_token_mint: series.redemption_mint,
+        minted_writer_token_dest: series.associated(user, &series.redemption_mint),
+        option_market: series.option_context,
+        fee_owner: protocol_config_address(),
+        token_program: series.token_program,
+        associated_token_program: AssociatedToken::id(),
+        clock: sysvar::clock::ID,
+        rent: sysvar::rent::ID,
+        system_program: System::id(),
+        protocol_config: protocol_config_address(),
+        quote_asset_mint: series.consideration_mint,
+        quote_asset_pool: series.consideration_vault,
+        quote_asset_src: series.associated(user, &series.consideration_mint),
+        writer_position: None,
+        fee_vault: None,
+        fee_stats: None,
+        referrer_account: None,
+        referral_stats: None,
+        insurance_fund: None,
+        insurance_vault: None,
+        credential_account: None,
+    };
+    compact_ix(accounts, instruction::MintOption { size })
+}
+
+/// PsyOptions-compatible `exercise_option` of `size` of `user`'s options
+pub fn psy_exercise_option_ix(series: &SeriesAddresses, user: &Pubkey, size: u64) -> Instruction {
+    let accounts = accounts::PsyExerciseOption {
+        user_authority: *user,
+        option_authority: *user,
+        option_market: series.option_context,
+        option_mint: series.option_mint,
+        exerciser_option_token_src: series.associated(user, &series.option_mint),
+        underlying_asset_pool: series.collateral_vault,
+        underlying_asset_dest: series.associated(user, &series.collateral_mint),
+        quote_asset_pool: series.consideration_vault,
+        quote_asset_src: series.associated(user, &series.consideration_mint),
+        fee_owner: protocol_config_address(),
+        token_program: series.token_program,
+        system_program: System::id(),
+        clock: sysvar::clock::ID,
+        protocol_config: protocol_config_address(),
+        underlying_asset_mint: series.collateral_mint,
+        quote_asset_mint: series.consideration_mint,
+        fee_vault: None,
+        fee_stats: None,
+        referrer_account: None,
+        referral_stats: None,
+        insurance_fund: None,
+        insurance_vault: None,
+        credential_account: None,
+    };
+    compact_ix(accounts, instruction::ExerciseOption { size })
+}
+
+/// PsyOptions-compatible `close_position` of `size` of `user`'s pairs
+pub fn psy_close_position_ix(series: &SeriesAddresses, user: &Pubkey, size: u64) -> Instruction {
+    let accounts = accounts::PsyClosePosition {
+        user_authority: *user,
+        option_market: series.option_context,
+        option_mint: series.option_mint,
+        option_token_src: series.associated(user, &series.option_mint),
+        writer_token_mint: series.redemption_mint,
+        writer_token_src: series.associated(user, &series.redemption_mint),
+        underlying_asset_pool: series.collateral_vault,
+        underlying_asset_dest: series.associated(user, &series.collateral_mint),
+        token_program: series.token_program,
+        underlying_asset_mint: series.collateral_mint,
+        quote_asset_mint: series.consideration_mint,
+        quote_asset_pool: series.consideration_vault,
+        quote_asset_dest: series.associated(user, &series.consideration_mint),
+        writer_position: None,
+    };
+    compact_ix(accounts, instruction::ClosePosition { size })
+}
+
+/// PsyOptions-compatible `close_post_expiration` of `size` of `user`'s
+/// redemption tokens after settlement
+pub fn psy_close_post_expiration_ix(
+    series: &SeriesAddresses,
+    user: &Pubkey,
+    size: u64,
+) -> Instruction {
+    let accounts = accounts::PsyClosePostExpiration {
+        user_authority: *user,
+        option_market: series.option_context,
+        writer_token_mint: series.redemption_mint,
+        writer_token_src: series.associated(user, &series.redemption_mint),
+        underlying_asset_pool: series.collateral_vault,
+        underlying_asset_dest: series.associated(user, &series.collateral_mint),
+        token_program: series.token_program,
+        clock: sysvar::clock::ID,
+        underlying_asset_mint: series.collateral_mint,
+        quote_asset_mint: series.consideration_mint,
+        quote_asset_pool: series.consideration_vault,
+        quote_asset_dest: series.associated(user, &series.consideration_mint),
+    };
+    compact_ix(accounts, instruction::ClosePostExpiration { size })
+}
+
+fn compact_ix(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
@@ -62,4 +62,396 @@ pub enum ErrorCode {
 
     #[msg("Invalid user")]
     InvalidUser,
+
+    // Oracle / settlement error codes
+    #[msg("Invalid oracle configuration")]
+    InvalidOracleConfig,
+
+    #[msg("Oracle account does not match the configured feed")]
+    InvalidOracleAccount,
+
+    #[msg("Oracle feed is not configured")]
+    OracleNotConfigured,
+
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+
+    #[msg("Oracle price is invalid")]
+    InvalidOraclePrice,
+
+    #[msg("No oracle in the hierarchy produced a usable price")]
+    OracleUnavailable,
+
+    #[msg("Series has already been settled")]
+    SeriesAlreadySettled,
+
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+
+    #[msg("Oracle price deviates too far from the previous reading")]
+    OraclePriceDeviation,
+
+    #[msg("Metadata URI exceeds the maximum length")]
+    MetadataUriTooLong,
+
+    #[msg("Underlying symbol must be 1-10 ASCII alphanumeric characters")]
+    InvalidUnderlyingSymbol,
+
+    // Authority error codes
+    #[msg("Signer is not the required authority")]
+    Unauthorized,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    // Migration error codes
+    #[msg("Migration source account is not a recognized option market")]
+    InvalidMigrationSource,
+
+    #[msg("Migration source does not match the destination series")]
+    MigrationParameterMismatch,
+
+    // Protocol config error codes
+    #[msg("Invalid protocol config value")]
+    InvalidConfigValue,
+
+    #[msg("Creator has reached the series creation limit for this epoch")]
+    CreationRateLimited,
+
+    #[msg("Creator must wait for the creation cooldown to elapse")]
+    CreationCooldownActive,
+
+    #[msg("Series is paused")]
+    SeriesPaused,
+
+    // Emergency withdrawal errors
+    #[msg("Withdrawal vault does not belong to the series")]
+    InvalidWithdrawalVault,
+
+    #[msg("Emergency withdrawal timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("This program version has already been announced")]
+    VersionAlreadyAnnounced,
+
+    // Exercise style errors
+    #[msg("Invalid exercise window for the exercise style")]
+    InvalidExerciseWindow,
+
+    #[msg("European options can only be exercised during the exercise window")]
+    OutsideExerciseWindow,
+
+    #[msg("Redemption opens after the exercise window closes")]
+    ExerciseWindowOpen,
+
+    #[msg("Oracle price update is not fully verified")]
+    UnverifiedOraclePrice,
+
+    #[msg("Series has not been settled yet")]
+    SeriesNotSettled,
+
+    #[msg("Exercise cutoff before expiration has passed")]
+    ExerciseCutoffPassed,
+
+    #[msg("Settlement delay exceeds the maximum")]
+    InvalidSettlementDelay,
+
+    #[msg("Redemption opens after the settlement delay")]
+    RedemptionNotOpen,
+
+    #[msg("Amount exceeds the exercised units available to claim")]
+    InsufficientExercisedAmount,
+
+    #[msg("Nothing in this position can be unwound yet")]
+    NothingToExit,
+
+    #[msg("Protocol treasury is not set")]
+    TreasuryNotSet,
+
+    #[msg("Token account is not owned by the protocol treasury")]
+    InvalidTreasuryAccount,
+
+    #[msg("Series still has outstanding claims on its vaults")]
+    SeriesNotFullyRedeemed,
+
+    #[msg("Unclaimed-funds recovery is disabled")]
+    RecoveryDisabled,
+
+    #[msg("Unclaimed-funds timeout has not elapsed")]
+    UnclaimedTimeoutNotElapsed,
+
+    #[msg("Margin buffers must be zero or maintenance below initial, with a primary oracle")]
+    InvalidMarginConfig,
+
+    #[msg("Series does not allow margin writing")]
+    MarginDisabled,
+
+    #[msg("Position would fall below the initial margin requirement")]
+    InsufficientMargin,
+
+    #[msg("Position is above the maintenance margin requirement")]
+    PositionHealthy,
+
+    #[msg("Amount exceeds the options written on margin")]
+    ExceedsMarginWritten,
+
+    #[msg("Spread legs must share mints, strike units, contract size, expiry, exercise style and oracle, long leg further OTM")]
+    InvalidSpread,
+
+    #[msg("Open spreads must be settled against their long leg first")]
+    SpreadsOutstanding,
+
+    #[msg("Minimum mint amount must be a multiple of the lot size")]
+    InvalidLotConfig,
+
+    #[msg("Amount is not a multiple of the series lot size")]
+    AmountNotLotMultiple,
+
+    #[msg("Amount is below the series minimum mint amount")]
+    BelowMinMintAmount,
+
+    #[msg("Vault still holds tokens; sweep it first")]
+    VaultNotEmpty,
+
+    #[msg("Unknown option type")]
+    InvalidOptionType,
+
+    #[msg("Strike exponent is out of range for the series' mints")]
+    InvalidStrikeExponent,
+
+    #[msg("Collateral and consideration mints must differ")]
+    IdenticalMints,
+
+    #[msg("Invalid expiry grid configuration")]
+    InvalidExpiryGrid,
+
+    #[msg("Expiration is not on the protocol's expiry grid")]
+    ExpirationOffGrid,
+
+    #[msg("Strike price is not a multiple of the consideration mint's tick size")]
+    StrikeOffTick,
+
+    #[msg("Expiration is sooner than the protocol's minimum time to expiry")]
+    ExpirationTooSoon,
+
+    #[msg("Expiration is later than the protocol's maximum time to expiry")]
+    ExpirationTooFar,
+
+    #[msg("Batch strikes must be increasing, at most the batch limit, with five accounts each")]
+    InvalidBatch,
+
+    #[msg("Binary series need a payout and a primary oracle, and can't write on margin")]
+    InvalidBinaryConfig,
+
+    #[msg("Binary series settle in cash at expiry and can't be exercised")]
+    CashSettledOnly,
+
+    #[msg("Knock-out barriers must lie beyond the strike and need a primary oracle")]
+    InvalidBarrier,
+
+    #[msg("Oracle price has not reached the knock-out barrier")]
+    BarrierNotBreached,
+
+    #[msg("Series has been knocked out")]
+    SeriesKnockedOut,
+
+    #[msg("Invalid perpetual series configuration")]
+    InvalidPerpetualConfig,
+
+    #[msg("Combo legs must be a call and a put sharing mints, contract size and expiry")]
+    InvalidCombo,
+
+    #[msg("Writers must pass their vertical spread position")]
+    SpreadPositionRequired,
+
+    #[msg("Rolls need the same mints, strike, type and contract size at a later expiry")]
+    InvalidRoll,
+
+    #[msg("Forwards can't be exercised; they settle at the strike through settle_forward")]
+    ForwardSettlementOnly,
+
+    #[msg("Invalid forward configuration")]
+    InvalidForwardConfig,
+
+    #[msg("Invalid collateral basket")]
+    InvalidCollateralBasket,
+
+    #[msg("This series doesn't accept the substitute mint")]
+    SubstituteNotAccepted,
+
+    #[msg("Pass the mint, basket vault and destination of every substitute the series holds")]
+    InvalidBasketAccounts,
+
+    #[msg("Fee exceeds the protocol maximum")]
+    FeeTooHigh,
+
+    #[msg("Unknown feature switch")]
+    InvalidFeatures,
+
+    #[msg("This feature is switched off by the protocol admin")]
+    FeatureDisabled,
+
+    #[msg("Pass the protocol fee account for the charged mint")]
+    InvalidFeeVault,
+
+    #[msg("Fee tiers need increasing volumes and non-decreasing discounts of at most 100%")]
+    InvalidFeeTiers,
+
+    #[msg("Invalid fee exemption list")]
+    InvalidFeeExempt,
+
+    #[msg("Pass the referrer's token account and stats for the charged mint; no self-referrals")]
+    InvalidReferral,
+
+    #[msg("Referral share exceeds 100% of the fee")]
+    InvalidReferralShare,
+
+    #[msg("The protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Mint is not on the protocol's allowlist")]
+    MintNotApproved,
+
+    #[msg("Not a governance account of an SPL Governance program")]
+    InvalidGovernance,
+
+    #[msg("This parameter is timelocked; schedule the change instead")]
+    TimelockRequired,
+
+    #[msg("Timelock delay must be between 0 and 30 days")]
+    InvalidTimelockDelay,
+
+    #[msg("Pending change is invalid or its accounts don't match")]
+    InvalidPendingChange,
+
+    #[msg("Series is frozen by the guardian")]
+    SeriesFrozen,
+
+    #[msg("Insurance share must be at most 10000 bps")]
+    InvalidInsuranceShare,
+
+    #[msg("Insurance fund or its token account doesn't match the charged mint")]
+    InvalidInsuranceFund,
+
+    #[msg("Payout exceeds the vault's shortfall")]
+    NoVaultShortfall,
+
+    #[msg("Transfer fees leave too little of the deposit in the vault to mint")]
+    DepositShortfall,
+
+    #[msg("Token metadata program or account doesn't match the series mints")]
+    InvalidMetadataAccount,
+
+    #[msg("Transfer hooks need option and redemption mints under Token-2022")]
+    TransferHookRequiresToken2022,
+
+    #[msg("A compliance authority needs a freezable series")]
+    InvalidFreezeConfig,
+
+    #[msg("Series was created without a freeze authority")]
+    SeriesNotFreezable,
+
+    #[msg("Neither of the series' mints is wrapped SOL")]
+    NotNativeSeries,
+
+    #[msg("Series is gated: present a token account holding its credential")]
+    CredentialRequired,
+
+    #[msg("Mint extensions must be ProtocolConfig::MINT_EXTENSION_* bits")]
+    InvalidMintExtensions,
+
+    #[msg("Mint has a Token-2022 extension the protocol doesn't allow for series")]
+    UnsupportedMintExtension,
+
+    #[msg("Options listed on mint must be minted to the user's own account")]
+    ListingNotOwned,
+
+    #[msg("Only calls pay out collateral that can be sold for the strike")]
+    FlashExerciseNeedsCall,
+
+    #[msg("Series already records its marketplace market")]
+    MarketAlreadyOpened,
+
+    #[msg("Swap program is not Jupiter")]
+    InvalidSwapProgram,
+
+    #[msg("Swap route data is malformed")]
+    InvalidSwapRoute,
+
+    #[msg("Swap delivered nothing to the account exercise pays from")]
+    SwapOutputMissing,
+
+    #[msg("Yield venue addresses don't match the approved reserve")]
+    InvalidYieldVenue,
+
+    #[msg("Yield venue is not accepting new lending")]
+    YieldVenueDisabled,
+
+    #[msg("Series doesn't lend its escrow")]
+    YieldNotEnabled,
+
+    #[msg("Series can only opt into lending before anything is escrowed")]
+    YieldOptInTooLate,
+
+    #[msg("Series holds fewer yield shares than requested")]
+    InsufficientYieldShares,
+
+    #[msg("Vaults write calls or puts only")]
+    UnsupportedVaultType,
+
+    #[msg("Vault round in progress")]
+    VaultRoundActive,
+
+    #[msg("Vault has no open round")]
+    VaultRoundNotActive,
+
+    #[msg("Series doesn't match the vault's underlying, quote or option type")]
+    VaultSeriesMismatch,
+
+    #[msg("Vault holds none of its escrow mint to price shares against")]
+    VaultHasNoEscrow,
+
+    #[msg("Not enough vault shares")]
+    InsufficientVaultShares,
+
+    #[msg("Only put vaults re-list assigned underlying")]
+    NotAPutVault,
+
+    #[msg("The vault's re-listing of assigned underlying is still open")]
+    VaultListingOpen,
+
+    #[msg("The vault has no open re-listing")]
+    VaultListingNotOpen,
+
+    #[msg("Invalid or incomplete Wormhole accounts")]
+    InvalidWormholeAccount,
+
+    #[msg("Not the OpenBook v2 program")]
+    InvalidOpenbookProgram,
+
+    #[msg("Token account is not of the series' margin mint")]
+    InvalidMarginAccount,
+
+    #[msg("Series accounting invariant violated")]
+    InvariantViolated,
+
+    #[msg("Series already uses the current account layout")]
+    SeriesUpToDate,
+
+    #[msg("Basket vaults are still open; sweep the series before closing it")]
+    BasketVaultsOpen,
+
+    #[msg("Escrow is still lent to the yield venue; unwind its shares first")]
+    YieldSharesOutstanding,
+
+    #[msg(
+        "Migrating a legacy series takes the mints and vaults it stores and the protocol config"
+    )]
+    LegacySeriesAccounts,
+
+    #[msg("Legacy puts can only be burned and redeemed")]
+    LegacyPutRestricted,
+
+    #[msg("The series' primary oracle account must be passed")]
+    PrimaryOracleRequired,
 }
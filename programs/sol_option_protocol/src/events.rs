@@ -0,0 +1,642 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::OptionType;
+use crate::state::{FeeTier, ParameterChange};
+
+/// Emitted when the current series authority nominates a successor
+#[event]
+pub struct SeriesAuthorityProposed {
+    pub option_context: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the nominated successor accepts the series authority
+#[event]
+pub struct SeriesAuthorityTransferred {
+    pub option_context: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when the protocol admin nominates a successor
+#[event]
+pub struct ConfigAdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+/// Emitted when the admin nominates a Realms governance's native treasury
+#[event]
+pub struct GovernanceAdminProposed {
+    pub governance_program: Pubkey,
+    pub governance: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+/// Emitted when the nominated successor accepts the protocol admin role
+#[event]
+pub struct ConfigAdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when the per-creator series creation limits change
+#[event]
+pub struct RateLimitsUpdated {
+    pub max_series_per_epoch: u16,
+    pub creation_cooldown: i64,
+}
+
+/// Emitted when settle_expiry freezes a series and snapshots its vaults
+#[event]
+pub struct SeriesSettled {
+    pub option_context: Pubkey,
+    pub settlement_price: u64,
+    pub collateral: u64,
+    pub consideration: u64,
+    pub redemption_supply: u64,
+    pub option_supply: u64,
+    pub net_option_collateral: u64,
+    pub net_option_consideration: u64,
+    pub settled_at: i64,
+}
+
+/// Emitted when the series authority pauses or resumes a series
+#[event]
+pub struct SeriesPauseUpdated {
+    pub option_context: Pubkey,
+    pub paused: bool,
+}
+
+/// Emitted when the admin or guardian pauses or resumes a series in an emergency
+#[event]
+pub struct SeriesEmergencyPauseUpdated {
+    pub option_context: Pubkey,
+    pub paused: bool,
+    pub updated_by: Pubkey,
+}
+
+/// Emitted when the admin or guardian pauses or resumes the whole protocol
+#[event]
+pub struct ProtocolPauseUpdated {
+    pub paused: bool,
+    pub updated_by: Pubkey,
+}
+
+/// Emitted when the admin changes the guardian key
+#[event]
+pub struct GuardianUpdated {
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
+
+/// Emitted when the admin changes the treasury owner
+#[event]
+pub struct TreasuryUpdated {
+    pub previous_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+}
+
+/// Emitted when the admin changes the mint and exercise fees
+#[event]
+pub struct FeesUpdated {
+    pub mint_fee_bps: u16,
+    pub exercise_fee_bps: u16,
+}
+
+/// Emitted when a mint or exercise pays the protocol fee
+#[event]
+pub struct FeeCharged {
+    pub option_context: Pubkey,
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the admin replaces the fee volume tiers
+#[event]
+pub struct FeeTiersUpdated {
+    pub tiers: Vec<FeeTier>,
+}
+
+/// Emitted when the admin replaces the fee-exempt accounts
+#[event]
+pub struct FeeExemptUpdated {
+    pub accounts: Vec<Pubkey>,
+}
+
+/// Emitted when a referrer receives their share of a fee
+#[event]
+pub struct ReferralPaid {
+    pub option_context: Pubkey,
+    pub referrer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the admin changes the referrers' share of fees
+#[event]
+pub struct ReferralShareUpdated {
+    pub referral_share_bps: u16,
+}
+
+/// Emitted when the admin changes the insurance fund's share of fees
+#[event]
+pub struct InsuranceShareUpdated {
+    pub insurance_share_bps: u16,
+}
+
+/// Emitted when the admin opens the insurance fund of a mint
+#[event]
+pub struct InsuranceFundOpened {
+    pub insurance_fund: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when a fee pays its insurance share into the fund
+#[event]
+pub struct InsuranceContributed {
+    pub option_context: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// Lifetime contributions, including this one
+    pub contributed: u64,
+}
+
+/// Emitted when the admin covers a series vault's shortfall from the fund
+#[event]
+pub struct InsurancePaidOut {
+    pub option_context: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    /// Shortfall before the payout
+    pub shortfall: u64,
+    /// Lifetime payouts, including this one
+    pub paid_out: u64,
+}
+
+/// Emitted when the admin withdraws collected fees
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the admin switches protocol features on or off
+#[event]
+pub struct FeaturesUpdated {
+    pub disabled_features: u32,
+}
+
+/// Emitted when the admin changes the risky mint extensions series may use
+#[event]
+pub struct MintExtensionsUpdated {
+    pub allowed_mint_extensions: u32,
+}
+
+/// Emitted when the admin changes the unclaimed-funds recovery timeout
+#[event]
+pub struct UnclaimedTimeoutUpdated {
+    pub unclaimed_timeout: i64,
+}
+
+/// Emitted when the admin changes the series creation bond
+#[event]
+pub struct CreationBondUpdated {
+    pub creation_bond: u64,
+}
+
+/// Emitted when the admin changes the expiry grid or its allowlist
+#[event]
+pub struct ExpiryGridUpdated {
+    pub interval: i64,
+    pub offset: i64,
+    pub exempt: Vec<Pubkey>,
+}
+
+/// Emitted when the admin changes the time-to-expiry limits
+#[event]
+pub struct ExpiryLimitsUpdated {
+    pub min_time_to_expiry: i64,
+    pub max_time_to_expiry: i64,
+}
+
+/// Emitted when the admin changes a consideration mint's strike tick size
+#[event]
+pub struct StrikeTickUpdated {
+    pub consideration_mint: Pubkey,
+    pub tick_size: u64,
+}
+
+/// Emitted when the admin approves or revokes a collateral/consideration mint
+#[event]
+pub struct ApprovedMintUpdated {
+    pub mint: Pubkey,
+    pub approved: bool,
+}
+
+/// Emitted when the admin switches the mint allowlist on or off
+#[event]
+pub struct MintAllowlistUpdated {
+    pub enabled: bool,
+}
+
+/// Emitted when the admin sets how long scheduled parameter changes wait
+#[event]
+pub struct TimelockDelayUpdated {
+    pub timelock_delay: i64,
+}
+
+/// Emitted when the admin schedules a timelocked parameter change
+#[event]
+pub struct ChangeScheduled {
+    pub pending_change: Pubkey,
+    pub id: u64,
+    pub change: ParameterChange,
+    pub executable_at: i64,
+}
+
+/// Emitted when a scheduled parameter change takes effect
+#[event]
+pub struct ChangeApplied {
+    pub pending_change: Pubkey,
+    pub id: u64,
+}
+
+/// Emitted when the admin or guardian cancels a scheduled parameter change
+#[event]
+pub struct ChangeCancelled {
+    pub pending_change: Pubkey,
+    pub id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+/// Emitted when the admin queues an emergency withdrawal
+#[event]
+pub struct EmergencyWithdrawalQueued {
+    pub withdrawal: Pubkey,
+    pub option_context: Pubkey,
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub justification_hash: [u8; 32],
+    pub executable_at: i64,
+}
+
+/// Emitted when the guardian (or admin) cancels a queued emergency withdrawal
+#[event]
+pub struct EmergencyWithdrawalCancelled {
+    pub withdrawal: Pubkey,
+    pub cancelled_by: Pubkey,
+}
+
+/// Emitted when a queued emergency withdrawal is executed after the timelock
+#[event]
+pub struct EmergencyWithdrawalExecuted {
+    pub withdrawal: Pubkey,
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted once per deployed version so indexers can detect supported capabilities
+#[event]
+pub struct ProgramVersionAnnounced {
+    pub program_id: Pubkey,
+    pub version: String,
+    pub previous_version: String,
+    pub capabilities: Vec<String>,
+    pub config_hash: [u8; 32],
+    pub slot: u64,
+}
+
+/// Emitted when a fully redeemed series' leftover vault dust goes to the treasury
+#[event]
+pub struct DustSwept {
+    pub option_context: Pubkey,
+    pub treasury: Pubkey,
+    pub collateral: u64,
+    pub consideration: u64,
+}
+
+/// Emitted when an abandoned series' remaining funds are recovered to the treasury
+#[event]
+pub struct UnclaimedRecovered {
+    pub option_context: Pubkey,
+    pub treasury: Pubkey,
+    pub collateral: u64,
+    pub consideration: u64,
+    pub redemption_supply: u64,
+    pub option_supply: u64,
+}
+
+/// Emitted when a dead series' accounts are closed and their rent refunded
+#[event]
+pub struct SeriesClosed {
+    pub option_context: Pubkey,
+    pub authority: Pubkey,
+    /// Lamports held by the OptionContext (rent plus any unrefunded bond)
+    pub lamports: u64,
+}
+
+/// Emitted when a keeper liquidates part of a margin position
+#[event]
+pub struct MarginLiquidated {
+    pub option_context: Pubkey,
+    pub writer_position: Pubkey,
+    pub writer: Pubkey,
+    pub liquidator: Pubkey,
+    pub amount: u64,
+    pub seized: u64,
+    pub price: u64,
+    /// Position health before the liquidation (bps of maintenance margin)
+    pub health_bps: u64,
+}
+
+/// Emitted when `record_knockout` finds a series' barrier breached
+#[event]
+pub struct SeriesKnockedOut {
+    pub option_context: Pubkey,
+    pub barrier: u64,
+    pub price: u64,
+    pub knocked_out_at: i64,
+}
+
+/// Emitted when the series authority rewrites a series' token metadata
+/// (`name` is the option token's)
+#[event]
+pub struct SeriesMetadataUpdated {
+    pub option_context: Pubkey,
+    pub name: String,
+    pub uri: String,
+}
+
+/// Emitted when a freezable series' compliance key freezes or thaws a
+/// holder's option or redemption token account
+#[event]
+pub struct TokenAccountFrozen {
+    pub option_context: Pubkey,
+    pub token_account: Pubkey,
+    pub frozen: bool,
+}
+
+/// Emitted when the guardian freezes a compromised series
+#[event]
+pub struct SeriesFrozen {
+    pub option_context: Pubkey,
+    pub frozen_at: i64,
+    pub frozen_by: Pubkey,
+}
+
+/// Emitted when `accrue_funding` brings a perpetual series' funding index up
+/// to date (funding owed per unit of underlying, quoted like the strike)
+#[event]
+pub struct FundingAccrued {
+    pub option_context: Pubkey,
+    pub funding_rate_bps: u32,
+    pub funding_index: u64,
+    pub updated_at: i64,
+}
+
+/// Emitted when the admin replaces the substitutes accepted for an escrow mint
+#[event]
+pub struct CollateralBasketUpdated {
+    pub base_mint: Pubkey,
+    pub mints: Vec<Pubkey>,
+}
+
+/// Emitted when a writer escrows a substitute in a series' basket vault
+#[event]
+pub struct SubstituteDeposited {
+    pub option_context: Pubkey,
+    pub substitute_mint: Pubkey,
+    pub amount: u64,
+    pub escrow: u64,
+}
+
+/// Emitted when a series records the marketplace market its option token
+/// trades on
+#[event]
+pub struct SeriesMarketOpened {
+    pub option_context: Pubkey,
+    pub market: Pubkey,
+}
+
+/// Emitted when the admin approves or updates the lending venue of an escrow mint
+#[event]
+pub struct YieldVenueUpdated {
+    pub escrow_mint: Pubkey,
+    pub lending_program: Pubkey,
+    pub reserve: Pubkey,
+    pub enabled: bool,
+}
+
+/// Emitted when a series opts into lending its escrow
+#[event]
+pub struct CollateralYieldEnabled {
+    pub option_context: Pubkey,
+    pub yield_venue: Pubkey,
+}
+
+/// Emitted when a series lends idle escrow (`shares` minted by the venue)
+#[event]
+pub struct EscrowLent {
+    pub option_context: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+}
+
+/// Emitted when a series unwinds venue shares back into its escrow vault
+/// (`received` above `principal` is yield, below it a loss)
+#[event]
+pub struct EscrowUnwound {
+    pub option_context: Pubkey,
+    pub shares: u64,
+    pub principal: u64,
+    pub received: u64,
+}
+
+/// Emitted when a manager opens a pooled writing vault
+#[event]
+pub struct OptionVaultCreated {
+    pub option_vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub option_type: OptionType,
+}
+
+/// Emitted when a depositor buys into (`deposit`) or leaves a vault
+#[event]
+pub struct VaultSharesChanged {
+    pub option_vault: Pubkey,
+    pub user: Pubkey,
+    pub deposit: bool,
+    pub shares: u64,
+    pub underlying: u64,
+    pub quote: u64,
+}
+
+/// Emitted when a vault writes a round's options and lists them
+#[event]
+pub struct VaultRoundStarted {
+    pub option_vault: Pubkey,
+    pub round: u64,
+    pub series: Pubkey,
+    pub written: u64,
+    pub price: u64,
+}
+
+/// Emitted when a vault redeems a finished round's short side
+#[event]
+pub struct VaultRoundSettled {
+    pub option_vault: Pubkey,
+    pub round: u64,
+    pub series: Pubkey,
+    pub redeemed: u64,
+}
+
+/// Emitted when a put vault lists the underlying it was assigned
+#[event]
+pub struct VaultListingOpened {
+    pub option_vault: Pubkey,
+    pub listing: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+}
+
+/// Emitted when a put vault's re-listing is closed (unsold underlying returned)
+#[event]
+pub struct VaultListingClosed {
+    pub option_vault: Pubkey,
+    pub listing: Pubkey,
+    pub unsold: u64,
+}
+
+/// Emitted when a settled series' result is attested (and posted to Wormhole)
+#[event]
+pub struct SettlementAttested {
+    pub option_context: Pubkey,
+    pub attestation: Pubkey,
+    pub settlement_price: u64,
+    pub posted: bool,
+    pub wormhole_sequence: u64,
+}
+
+/// Emitted when a series authority creates an OpenBook market for its option token
+#[event]
+pub struct OpenbookMarketOpened {
+    pub option_context: Pubkey,
+    pub market: Pubkey,
+    pub quote_lot_size: i64,
+    pub base_lot_size: i64,
+}
+
+/// Emitted when a series is created (by `create_option` or a batch)
+#[event]
+pub struct SeriesCreated {
+    pub option_context: Pubkey,
+    pub authority: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub consideration_mint: Pubkey,
+    pub option_mint: Pubkey,
+    pub redemption_mint: Pubkey,
+    pub strike_price: u64,
+    pub expiration: i64,
+    pub option_type: OptionType,
+}
+
+/// Emitted when a writer mints option and redemption pairs
+#[event]
+pub struct OptionsMinted {
+    pub option_context: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    /// Escrow the vaults received for them
+    pub escrow: u64,
+    pub total_supply: u64,
+}
+
+/// Emitted when a holder exercises options
+#[event]
+pub struct OptionsExercised {
+    pub option_context: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub strike_payment: u64,
+    pub funding: u64,
+    pub exercised_amount: u64,
+    /// Option mint supply after the burn
+    pub option_supply: u64,
+}
+
+/// Emitted when redemption tokens are redeemed after settlement
+#[event]
+pub struct Redeemed {
+    pub option_context: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub collateral: u64,
+    pub consideration: u64,
+    /// Redemption mint supply after the burn
+    pub redemption_supply: u64,
+}
+
+/// Emitted when option tokens claim their netted payoff after settlement
+#[event]
+pub struct NetPayoffClaimed {
+    pub option_context: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub collateral: u64,
+    pub consideration: u64,
+    /// Option mint supply after the burn
+    pub option_supply: u64,
+}
+
+/// Emitted when a user burns matched pairs for the locked obligation
+#[event]
+pub struct PairBurned {
+    pub option_context: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    /// Escrow refunded, plus the strike refunded by forwards
+    pub refund: u64,
+    pub strike_refund: u64,
+    pub total_supply: u64,
+}
+
+/// Emitted when redemption tokens are converted into exercise proceeds before expiry
+#[event]
+pub struct ConsiderationClaimed {
+    pub option_context: Pubkey,
+    pub user: Pubkey,
+    /// Exercised units converted (redemption tokens burned)
+    pub amount: u64,
+    /// Paid out of the vault the exercisers paid into
+    pub proceeds: u64,
+    /// Funding share paid separately in consideration (puts; calls' is in `proceeds`)
+    pub funding: u64,
+    pub exercised_claimed: u64,
+    /// Redemption mint supply after the burn
+    pub redemption_supply: u64,
+}
+
+/// Emitted when migrate_series brings a series to the current account layout
+#[event]
+pub struct SeriesMigrated {
+    pub option_context: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+/// Emitted when the admin clears a series' deviation reference
+#[event]
+pub struct OracleReferenceReset {
+    pub option_context: Pubkey,
+    pub previous_price: u64,
+    pub reset_by: Pubkey,
+}
@@ -0,0 +1,47 @@
+//! SPL Governance (Realms) adapter.
+//!
+//! A Realms DAO executes proposal instructions by signing with its governance
+//! PDAs. The native treasury (seeds `["native-treasury", governance]` under the
+//! governance program) is the one meant to hold authority: it is a system
+//! account that can also pay rent. The protocol admin can be handed to it, so
+//! every admin instruction then runs as an approved proposal.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Mainnet SPL Governance program (DAOs may also run their own deployment)
+pub const SPL_GOVERNANCE_PROGRAM_ID: Pubkey =
+    pubkey!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+
+/// Seed prefix of a governance's native treasury
+pub const NATIVE_TREASURY_SEED: &[u8] = b"native-treasury";
+
+/// `GovernanceAccountType` tags of governance accounts (V1 and V2 of the
+/// generic, program, mint and token governances)
+pub const GOVERNANCE_ACCOUNT_TYPES: [u8; 8] = [3, 4, 9, 10, 18, 19, 20, 21];
+
+/// Native treasury of `governance` under `governance_program`
+pub fn native_treasury_address(governance_program: &Pubkey, governance: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[NATIVE_TREASURY_SEED, governance.as_ref()],
+        governance_program,
+    )
+    .0
+}
+
+/// Checks `account` is a governance account of some governance program and
+/// returns its native treasury
+pub fn governance_treasury(account: &AccountInfo) -> Result<Pubkey> {
+    require!(
+        *account.owner != crate::ID && *account.owner != System::id(),
+        ErrorCode::InvalidGovernance
+    );
+    let data = account.try_borrow_data()?;
+    require!(
+        data.first()
+            .is_some_and(|tag| GOVERNANCE_ACCOUNT_TYPES.contains(tag)),
+        ErrorCode::InvalidGovernance
+    );
+    Ok(native_treasury_address(account.owner, account.key))
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigAdminTransferred;
+use crate::state::ProtocolConfig;
+
+/// Nominated successor accepting the admin role (step 2 of 2)
+#[derive(Accounts)]
+pub struct AcceptConfigAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        constraint = protocol_config.pending_admin != Pubkey::default() @ ErrorCode::NoPendingAuthority,
+        constraint = protocol_config.pending_admin == pending_admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    let previous_admin = config.admin;
+
+    config.admin = ctx.accounts.pending_admin.key();
+    config.pending_admin = Pubkey::default();
+
+    emit!(ConfigAdminTransferred {
+        previous_admin,
+        new_admin: config.admin,
+    });
+
+    msg!(
+        "Protocol admin transferred: {} -> {}",
+        previous_admin,
+        config.admin
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesAuthorityTransferred;
+use crate::instructions::OptionData;
+
+/// Nominated successor accepting the series authority (step 2 of 2)
+#[derive(Accounts)]
+pub struct AcceptSeriesAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = option_context.pending_authority != Pubkey::default() @ ErrorCode::NoPendingAuthority,
+        constraint = option_context.pending_authority == pending_authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Completes the transfer: only a key that can sign ever becomes authority
+pub fn handler(ctx: Context<AcceptSeriesAuthority>) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+    let previous_authority = option_context.authority;
+
+    option_context.authority = ctx.accounts.pending_authority.key();
+    option_context.pending_authority = Pubkey::default();
+
+    emit!(SeriesAuthorityTransferred {
+        option_context: option_context.key(),
+        previous_authority,
+        new_authority: option_context.authority,
+    });
+
+    msg!(
+        "Series {} authority transferred: {} -> {}",
+        option_context.key(),
+        previous_authority,
+        option_context.authority
+    );
+
+    Ok(())
+}
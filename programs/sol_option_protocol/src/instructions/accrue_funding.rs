@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FundingAccrued;
+use crate::instructions::OptionData;
+use crate::utils::validation::validate_not_settled;
+
+/// Permissionless funding crank for perpetual series
+#[derive(Accounts)]
+pub struct AccrueFunding<'info> {
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Brings a perpetual series' funding index up to the current time so
+/// integrators can quote what exercising costs; exercise accrues on its own,
+/// so nothing depends on this being called
+pub fn handler(ctx: Context<AccrueFunding>) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+
+    // Validation
+    require!(
+        option_context.is_perpetual() && option_context.funding_rate_bps > 0,
+        ErrorCode::InvalidPerpetualConfig
+    );
+    validate_not_settled(option_context)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    option_context.accrue_funding(now)?;
+
+    emit!(FundingAccrued {
+        option_context: option_context.key(),
+        funding_rate_bps: option_context.funding_rate_bps,
+        funding_index: option_context.funding_index,
+        updated_at: now,
+    });
+
+    msg!(
+        "Funding index {} at {} ({} bps/year)",
+        option_context.funding_index,
+        now,
+        option_context.funding_rate_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+
+use crate::errors::ErrorCode;
+use crate::events::ProgramVersionAnnounced;
+use crate::state::{ProgramVersion, ProtocolConfig};
+use crate::version::{CAPABILITIES, VERSION};
+
+/// Permissionless: emits the deployment's version once per upgrade
+#[derive(Accounts)]
+pub struct AnnounceVersion<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProgramVersion::INIT_SPACE,
+        seeds = [ProgramVersion::SEED],
+        bump
+    )]
+    pub program_version: Account<'info, ProgramVersion>,
+
+    /// Omitted before the config is initialized (config hash is then all zeros)
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AnnounceVersion>) -> Result<()> {
+    let record = &mut ctx.accounts.program_version;
+    require!(
+        record.version != VERSION,
+        ErrorCode::VersionAlreadyAnnounced
+    );
+
+    let config_hash = match &ctx.accounts.protocol_config {
+        Some(config) => hash(&config.to_account_info().try_borrow_data()?).to_bytes(),
+        None => [0u8; 32],
+    };
+
+    let previous_version = std::mem::replace(&mut record.version, VERSION.to_string());
+    record.bump = ctx.bumps.program_version;
+    record.announced_slot = Clock::get()?.slot;
+    record.config_hash = config_hash;
+
+    emit!(ProgramVersionAnnounced {
+        program_id: crate::ID,
+        version: VERSION.to_string(),
+        previous_version,
+        capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+        config_hash,
+        slot: record.announced_slot,
+    });
+
+    msg!("Program version announced: {}", VERSION);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ChangeApplied;
+use crate::instructions::create_series_batch::create_pda_account;
+use crate::instructions::{
+    set_approved_mint, set_creation_bond, set_fee_exempt, set_fee_tiers, set_fees,
+    set_insurance_share, set_mint_allowlist, set_referral_share, set_timelock_delay,
+    update_rate_limits,
+};
+use crate::state::{ApprovedMint, ParameterChange, PendingChange, ProtocolConfig};
+
+/// Admin applying a scheduled parameter change after its timelock
+#[derive(Accounts)]
+pub struct ApplyChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingChange::SEED, pending_change.id.to_le_bytes().as_ref()],
+        bump = pending_change.bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    /// CHECK: ApprovedMint PDA of the change's mint, for `ApprovedMint` changes
+    /// only (created if governance never touched the mint); checked in handler
+    #[account(mut)]
+    pub approved_mint: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Applies the change exactly as its setter would, then closes the schedule
+pub fn handler(ctx: Context<ApplyChange>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.pending_change.executable_at,
+        ErrorCode::TimelockNotElapsed
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+    match ctx.accounts.pending_change.change.clone() {
+        ParameterChange::Fees {
+            mint_fee_bps,
+            exercise_fee_bps,
+        } => set_fees::apply(config, mint_fee_bps, exercise_fee_bps)?,
+        ParameterChange::FeeTiers { tiers } => set_fee_tiers::apply(config, tiers)?,
+        ParameterChange::FeeExempt { accounts } => set_fee_exempt::apply(config, accounts)?,
+        ParameterChange::ReferralShare { referral_share_bps } => {
+            set_referral_share::apply(config, referral_share_bps)?
+        }
+        ParameterChange::MintAllowlist { enabled } => set_mint_allowlist::apply(config, enabled),
+        ParameterChange::TimelockDelay { timelock_delay } => {
+            set_timelock_delay::apply(config, timelock_delay)?
+        }
+        ParameterChange::InsuranceShare {
+            insurance_share_bps,
+        } => set_insurance_share::apply(config, insurance_share_bps)?,
+        ParameterChange::ApprovedMint { mint, approved } => {
+            apply_mint_approval(&ctx, mint, approved)?
+        }
+        ParameterChange::CreationBond { creation_bond } => {
+            set_creation_bond::apply(config, creation_bond)
+        }
+        ParameterChange::RateLimits {
+            max_series_per_epoch,
+            creation_cooldown,
+        } => update_rate_limits::apply(config, max_series_per_epoch, creation_cooldown)?,
+    }
+
+    emit!(ChangeApplied {
+        pending_change: ctx.accounts.pending_change.key(),
+        id: ctx.accounts.pending_change.id,
+    });
+
+    msg!(
+        "Parameter change {} applied",
+        ctx.accounts.pending_change.id
+    );
+
+    Ok(())
+}
+
+/// Updates (creating it first if needed) the ApprovedMint PDA of `mint`
+fn apply_mint_approval(ctx: &Context<ApplyChange>, mint: Pubkey, approved: bool) -> Result<()> {
+    let account = ctx
+        .accounts
+        .approved_mint
+        .as_ref()
+        .ok_or(ErrorCode::InvalidPendingChange)?;
+    let (address, bump) =
+        Pubkey::find_program_address(&[ApprovedMint::SEED, mint.as_ref()], ctx.program_id);
+    require_keys_eq!(account.key(), address, ErrorCode::InvalidPendingChange);
+
+    let mut approved_mint = if account.owner == ctx.program_id {
+        ApprovedMint::try_deserialize(&mut &account.try_borrow_data()?[..])?
+    } else {
+        create_pda_account(
+            &ctx.accounts.admin,
+            account,
+            &ctx.accounts.system_program,
+            ctx.program_id,
+            8 + ApprovedMint::INIT_SPACE,
+            &[ApprovedMint::SEED, mint.as_ref(), &[bump]],
+        )?;
+        ApprovedMint {
+            mint,
+            bump,
+            approved: false,
+        }
+    };
+    set_approved_mint::apply(&mut approved_mint, mint, bump, approved);
+    approved_mint.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
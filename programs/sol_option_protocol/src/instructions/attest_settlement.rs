@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+use crate::errors::ErrorCode;
+use crate::events::SettlementAttested;
+use crate::instructions::OptionData;
+use crate::state::SettlementAttestation;
+use crate::wormhole::{post_settlement, WormholeAccounts};
+
+/// Permissionless record of a settled series' result, optionally posted
+/// through Wormhole (the Wormhole accounts are all passed or all omitted)
+#[derive(Accounts)]
+pub struct AttestSettlement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(constraint = option_context.is_settled() @ ErrorCode::SeriesNotSettled)]
+    pub option_context: Box<Account<'info, OptionData>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SettlementAttestation::INIT_SPACE,
+        seeds = [SettlementAttestation::SEED, option_context.key().as_ref()],
+        bump
+    )]
+    pub attestation: Box<Account<'info, SettlementAttestation>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Wormhole core bridge; checked by `post_settlement`
+    pub wormhole_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bridge config; checked by `post_settlement`
+    #[account(mut)]
+    pub wormhole_bridge: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Message PDA of the series; created by the bridge
+    #[account(mut)]
+    pub wormhole_message: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The protocol's emitter PDA; checked by `post_settlement`
+    pub wormhole_emitter: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The emitter's sequence tracker; checked by `post_settlement`
+    #[account(mut)]
+    pub wormhole_sequence: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bridge fee collector; checked by `post_settlement`
+    #[account(mut)]
+    pub wormhole_fee_collector: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Clock sysvar, which the bridge reads as an account
+    #[account(address = sysvar::clock::ID)]
+    pub clock: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rent sysvar, which the bridge reads as an account
+    #[account(address = sysvar::rent::ID)]
+    pub rent: Option<UncheckedAccount<'info>>,
+}
+
+/// Records the series' settlement price and payout ratios in its attestation
+/// PDA, once. With the Wormhole accounts, the attestation is also posted as a
+/// Wormhole message (the payer covers the bridge fee), so EVM-side wrappers
+/// can mirror settlement from the signed VAA.
+pub fn handler(ctx: Context<AttestSettlement>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let option_context = accounts.option_context.key();
+    let mut attestation = SettlementAttestation::of(
+        option_context,
+        &accounts.option_context,
+        ctx.bumps.attestation,
+    );
+
+    match (
+        &accounts.wormhole_program,
+        &accounts.wormhole_bridge,
+        &accounts.wormhole_message,
+        &accounts.wormhole_emitter,
+        &accounts.wormhole_sequence,
+        &accounts.wormhole_fee_collector,
+        &accounts.clock,
+        &accounts.rent,
+    ) {
+        (
+            Some(wormhole_program),
+            Some(bridge),
+            Some(message),
+            Some(emitter),
+            Some(sequence),
+            Some(fee_collector),
+            Some(clock),
+            Some(rent),
+        ) => {
+            attestation.wormhole_sequence = post_settlement(
+                WormholeAccounts {
+                    wormhole_program: &wormhole_program.to_account_info(),
+                    bridge: &bridge.to_account_info(),
+                    message: &message.to_account_info(),
+                    emitter: &emitter.to_account_info(),
+                    sequence: &sequence.to_account_info(),
+                    fee_collector: &fee_collector.to_account_info(),
+                    payer: &accounts.payer.to_account_info(),
+                    clock: &clock.to_account_info(),
+                    rent: &rent.to_account_info(),
+                    system_program: &accounts.system_program.to_account_info(),
+                },
+                &option_context,
+                attestation.payload(),
+            )?;
+            attestation.posted = true;
+        }
+        (None, None, None, None, None, None, None, None) => {}
+        _ => return err!(ErrorCode::InvalidWormholeAccount),
+    }
+
+    emit!(SettlementAttested {
+        option_context,
+        attestation: accounts.attestation.key(),
+        settlement_price: attestation.settlement_price,
+        posted: attestation.posted,
+        wormhole_sequence: attestation.wormhole_sequence,
+    });
+
+    msg!("Settlement of {} attested", option_context);
+
+    accounts.attestation.set_inner(attestation);
+
+    Ok(())
+}
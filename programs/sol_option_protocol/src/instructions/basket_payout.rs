@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::state::CollateralBasket;
+
+/// Accounts each substitute slot in use takes in `remaining_accounts`: its
+/// mint, the series' basket vault and the destination token account
+pub const BASKET_PAYOUT_ACCOUNTS: usize = 3;
+
+/// Pays `shares` (see `OptionData::split_escrow_payout`) out of the series'
+/// substitute vaults and debits their slots; `accounts` holds one triple per
+/// slot in use, in slot order (nothing is read when every share is zero)
+pub fn pay_basket_shares<'info>(
+    option_context: &mut Account<'info, OptionData>,
+    token_program: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    shares: &[u64; CollateralBasket::MAX_MINTS],
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if shares.iter().all(|share| *share == 0) {
+        return Ok(());
+    }
+
+    let series_key = option_context.key();
+    let used = option_context
+        .basket_mints
+        .iter()
+        .take_while(|mint| **mint != Pubkey::default())
+        .count();
+    require!(
+        accounts.len() >= used * BASKET_PAYOUT_ACCOUNTS,
+        ErrorCode::InvalidBasketAccounts
+    );
+
+    for (slot, triple) in accounts
+        .chunks(BASKET_PAYOUT_ACCOUNTS)
+        .take(used)
+        .enumerate()
+    {
+        let share = shares[slot];
+        if share == 0 {
+            continue;
+        }
+        let (mint, vault, destination) = (&triple[0], &triple[1], &triple[2]);
+        require_keys_eq!(
+            mint.key(),
+            option_context.basket_mints[slot],
+            ErrorCode::InvalidBasketAccounts
+        );
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[
+                CollateralBasket::VAULT_SEED,
+                series_key.as_ref(),
+                mint.key().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            vault.key(),
+            expected_vault,
+            ErrorCode::InvalidBasketAccounts
+        );
+
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token::TransferChecked {
+                    from: vault.clone(),
+                    mint: mint.clone(),
+                    to: destination.clone(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            share,
+            decimals,
+        )?;
+        option_context.basket_escrow[slot] -= share;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::instructions::burn_paired::{burn, BurnParts};
+use crate::instructions::OptionData;
+use crate::state::WriterPosition;
+
+/// `burn` without the protocol config, leg owners, fee accounts or associated
+/// account creation: the user's token accounts must already exist
+#[derive(Accounts)]
+pub struct BurnAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(constraint = collateral_mint.key() == option_context.collateral_mint)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(constraint = consideration_mint.key() == option_context.consideration_mint)]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = option_mint.key() == option_context.option_mint)]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = redemption_mint.key() == option_context.redemption_mint)]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = collateral_vault.key() == option_context.collateral_vault)]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = collateral_mint, token::authority = user)]
+    pub user_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = consideration_mint, token::authority = user)]
+    pub user_consideration_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = option_mint, token::authority = user)]
+    pub user_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = redemption_mint, token::authority = user)]
+    pub user_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// User's writer position; recorded into when passed
+    #[account(
+        mut,
+        seeds = [WriterPosition::SEED, option_context.key().as_ref(), user.key().as_ref()],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Option<Account<'info, WriterPosition>>,
+}
+
+impl<'info> BurnAccounts<'info> {
+    fn parts(&mut self) -> BurnParts<'_, 'info> {
+        BurnParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            option_mint: &self.option_mint,
+            redemption_mint: &self.redemption_mint,
+            collateral_vault: &self.collateral_vault,
+            consideration_vault: &self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_option_account: &self.user_option_account,
+            user_redemption_account: &self.user_redemption_account,
+            token_program: &self.token_program,
+            writer_position: &mut self.writer_position,
+        }
+    }
+}
+
+/// Same refund and bookkeeping as `burn`
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnAccounts<'info>>,
+    amount: u64,
+) -> Result<()> {
+    burn(ctx.accounts.parts(), ctx.remaining_accounts, amount)
+}
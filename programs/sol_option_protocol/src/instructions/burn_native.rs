@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::native_sol::NativeAccount;
+use crate::instructions::{burn_paired, OptionContext};
+
+/// `burn` returning the escrow as native SOL when the escrow mint is WSOL
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let native = NativeAccount::of(ctx.accounts)?;
+    burn_paired::handler(ctx, amount)?;
+    native.unwrap()
+}
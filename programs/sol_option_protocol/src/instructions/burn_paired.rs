@@ -1,24 +1,97 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount, TokenInterface};
 
-use crate::instructions::option::OptionContext;
-use crate::utils::validation::{validate_amount, validate_vault_balance};
+use crate::events::PairBurned;
+use crate::instructions::basket_payout::pay_basket_shares;
+use crate::instructions::option::{OptionContext, OptionData};
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::WriterPosition;
+use crate::utils::validation::{
+    validate_amount, validate_lot_multiple, validate_not_settled, validate_vault_balance,
+};
 
-/// Burns paired option + redemption tokens to reclaim 1:1 collateral anytime
-/// Anytime: User burns both tokens → receives 1:1 collateral refund
-pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+/// Burns paired option + redemption tokens to reclaim the locked obligation anytime
+/// Call: user burns both tokens → receives the collateral they lock back
+/// Put: user burns both tokens → receives the strike value in consideration
+/// Binary: user burns both tokens → receives the binary payout in consideration
+/// Forward: user burns both tokens → receives the collateral and the strike value
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    burn(ctx.accounts.burn_parts(), ctx.remaining_accounts, amount)
+}
+
+/// Accounts `burn` works on, borrowed from `OptionContext` or `BurnAccounts`
+pub struct BurnParts<'a, 'info> {
+    pub user: &'a Signer<'info>,
+    pub option_context: &'a mut Account<'info, OptionData>,
+    pub collateral_mint: &'a InterfaceAccount<'info, Mint>,
+    pub consideration_mint: &'a InterfaceAccount<'info, Mint>,
+    pub option_mint: &'a InterfaceAccount<'info, Mint>,
+    pub redemption_mint: &'a InterfaceAccount<'info, Mint>,
+    pub collateral_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub consideration_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_collateral_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_consideration_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_option_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_redemption_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub writer_position: &'a mut Option<Account<'info, WriterPosition>>,
+}
+
+impl<'info> OptionContext<'info> {
+    pub(crate) fn burn_parts(&mut self) -> BurnParts<'_, 'info> {
+        BurnParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            option_mint: &self.option_mint,
+            redemption_mint: &self.redemption_mint,
+            collateral_vault: &self.collateral_vault,
+            consideration_vault: &self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_option_account: &self.user_option_account,
+            user_redemption_account: &self.user_redemption_account,
+            token_program: &self.token_program,
+            writer_position: &mut self.writer_position,
+        }
+    }
+}
+
+/// `burn` over whichever accounts struct carried the call
+pub(crate) fn burn<'info>(
+    accounts: BurnParts<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
     // Validation
     validate_amount(amount)?;
-    validate_vault_balance(ctx.accounts.collateral_vault.amount, amount)?;
+    validate_lot_multiple(accounts.option_context, amount)?;
+    validate_not_settled(accounts.option_context)?;
+
+    let escrows_consideration = accounts.option_context.escrows_consideration();
+    let underlying = accounts.option_context.underlying_amount(amount)?;
+    let refund = accounts.option_context.escrow_payout(underlying)?;
+    validate_vault_balance(accounts.option_context.escrow_balance(), refund)?;
+    // Substitutes escrowed in the series' basket pay their pro-rata part
+    let (primary_refund, basket_shares) = accounts.option_context.split_escrow_payout(refund)?;
+    let strike_refund = accounts.option_context.strike_payout(underlying)?;
+    validate_vault_balance(
+        accounts.option_context.consideration_balance(),
+        strike_refund,
+    )?;
 
     // 1. Burn option tokens from user
     token::burn(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::Burn {
-                mint: ctx.accounts.option_mint.to_account_info(),
-                from: ctx.accounts.user_option_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+                mint: accounts.option_mint.to_account_info(),
+                from: accounts.user_option_account.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         ),
         amount,
@@ -27,23 +100,23 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
     // 2. Burn redemption tokens from user
     token::burn(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::Burn {
-                mint: ctx.accounts.redemption_mint.to_account_info(),
-                from: ctx.accounts.user_redemption_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+                mint: accounts.redemption_mint.to_account_info(),
+                from: accounts.user_redemption_account.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         ),
         amount,
     )?;
 
-    // 3. Transfer collateral 1:1 from vault to user (OptionContext PDA signs)
-    let option_context = &ctx.accounts.option_context;
+    // 3. Refund the locked obligation from its vault (OptionContext PDA signs)
+    let option_context = &accounts.option_context;
     let collateral_mint_key = option_context.collateral_mint;
     let consideration_mint_key = option_context.consideration_mint;
     let strike_price_bytes = option_context.strike_price.to_le_bytes();
     let expiration_bytes = option_context.expiration.to_le_bytes();
-    let is_put_byte = [option_context.is_put as u8];
+    let option_type_byte = [option_context.option_type as u8];
     let bump = option_context.bump;
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -52,38 +125,110 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
         consideration_mint_key.as_ref(),
         strike_price_bytes.as_ref(),
         expiration_bytes.as_ref(),
-        &is_put_byte,
+        &option_type_byte,
         &[bump],
     ]];
 
+    let (vault, mint, destination, decimals) = if escrows_consideration {
+        (
+            &accounts.consideration_vault,
+            &accounts.consideration_mint,
+            &accounts.user_consideration_account,
+            accounts.consideration_mint.decimals,
+        )
+    } else {
+        (
+            &accounts.collateral_vault,
+            &accounts.collateral_mint,
+            &accounts.user_collateral_account,
+            accounts.collateral_mint.decimals,
+        )
+    };
+
     token::transfer_checked(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::TransferChecked {
-                from: ctx.accounts.collateral_vault.to_account_info(),
-                mint: ctx.accounts.collateral_mint.to_account_info(),
-                to: ctx.accounts.user_collateral_account.to_account_info(),
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: destination.to_account_info(),
                 authority: option_context.to_account_info(),
             },
             signer_seeds,
         ),
-        amount,
-        ctx.accounts.collateral_mint.decimals,
+        primary_refund,
+        decimals,
+    )?;
+
+    // Forwards also hand back the strike locked for the long side
+    if strike_refund > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: accounts.consideration_vault.to_account_info(),
+                    mint: accounts.consideration_mint.to_account_info(),
+                    to: accounts.user_consideration_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            strike_refund,
+            accounts.consideration_mint.decimals,
+        )?;
+    }
+
+    pay_basket_shares(
+        accounts.option_context,
+        &accounts.token_program.to_account_info(),
+        remaining_accounts,
+        &basket_shares,
+        decimals,
+        signer_seeds,
     )?;
 
-    // 4. Update total supply (decrease by burned amount)
-    let option_context = &mut ctx.accounts.option_context;
+    // 4. Update total supply (decrease by burned amount) and vault accounting
+    let option_context = &mut *accounts.option_context;
+    option_context.debit_escrow(refund)?;
+    option_context.debit_consideration(strike_refund)?;
     option_context.total_supply = option_context
         .total_supply
         .checked_sub(amount)
         .ok_or_else(|| error!(crate::errors::ErrorCode::MathOverflow))?;
 
-    msg!(
-        "Burned {} paired tokens. Refunded: {} collateral. New total supply: {}",
+    // Writer position, when the user tracks one
+    if let Some(position) = accounts.writer_position.as_mut() {
+        position.record_burn(amount);
+    }
+
+    emit!(PairBurned {
+        option_context: option_context.key(),
+        user: accounts.user.key(),
         amount,
+        refund,
+        strike_refund,
+        total_supply: option_context.total_supply,
+    });
+
+    msg!(
+        "Burned {} paired tokens. Refunded: {} {}. New total supply: {}",
         amount,
+        refund,
+        if escrows_consideration {
+            "consideration"
+        } else {
+            "collateral"
+        },
         option_context.total_supply
     );
 
-    Ok(())
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(accounts.consideration_vault.to_account_info()),
+            option_mint: Some(accounts.option_mint.to_account_info()),
+            redemption_mint: Some(accounts.redemption_mint.to_account_info()),
+        },
+    )
 }
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::VerticalSpreadContext;
+use crate::utils::math::calculate_pro_rata_share_u128;
+use crate::utils::validation::validate_amount;
+
+/// Writer burns `amount` spread tokens they hold before settlement and gets
+/// back the locked long options and the matching share of the backing
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VerticalSpreadContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    require!(
+        !ctx.accounts.vertical_spread.is_settled(),
+        ErrorCode::SeriesAlreadySettled
+    );
+    require!(
+        amount <= ctx.accounts.position()?.written,
+        ErrorCode::InvalidAmount
+    );
+    let spread = &ctx.accounts.vertical_spread;
+    let release = calculate_pro_rata_share_u128(spread.backing, amount, spread.written)?;
+
+    // 1. Burn the spread tokens, then return the longs and the backing
+    ctx.accounts.burn_spread_tokens(amount)?;
+    ctx.accounts.unlock(amount, ctx.remaining_accounts)?;
+    ctx.accounts.pay_out(release)?;
+
+    // 2. Bookkeeping (spread and position)
+    let spread = &mut ctx.accounts.vertical_spread;
+    spread.written -= amount;
+    spread.backing -= release;
+    let spread_key = spread.key();
+    let position = ctx.accounts.position()?;
+    position.written -= amount;
+
+    msg!(
+        "Burned {} spread tokens of {}: released {} backing",
+        amount,
+        spread_key,
+        release
+    );
+
+    Ok(())
+}
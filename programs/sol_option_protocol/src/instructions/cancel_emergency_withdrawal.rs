@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawalCancelled;
+use crate::state::{EmergencyWithdrawal, ProtocolConfig};
+
+/// Guardian (or admin) cancelling a queued emergency withdrawal
+#[derive(Accounts)]
+pub struct CancelEmergencyWithdrawal<'info> {
+    #[account(
+        constraint = canceller.key() == protocol_config.guardian
+            || canceller.key() == protocol_config.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub canceller: Signer<'info>,
+
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Rent refund destination, must be the admin who paid for the queue
+    #[account(mut, address = protocol_config.admin @ ErrorCode::Unauthorized)]
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [EmergencyWithdrawal::SEED, withdrawal.vault.as_ref()],
+        bump = withdrawal.bump
+    )]
+    pub withdrawal: Account<'info, EmergencyWithdrawal>,
+}
+
+pub fn handler(ctx: Context<CancelEmergencyWithdrawal>) -> Result<()> {
+    emit!(EmergencyWithdrawalCancelled {
+        withdrawal: ctx.accounts.withdrawal.key(),
+        cancelled_by: ctx.accounts.canceller.key(),
+    });
+
+    msg!(
+        "Emergency withdrawal {} cancelled",
+        ctx.accounts.withdrawal.key()
+    );
+
+    Ok(())
+}
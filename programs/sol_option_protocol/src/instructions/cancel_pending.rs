@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ChangeCancelled;
+use crate::state::{PendingChange, ProtocolConfig};
+
+/// Admin or guardian cancelling a scheduled parameter change
+#[derive(Accounts)]
+pub struct CancelPending<'info> {
+    #[account(
+        constraint = canceller.key() == protocol_config.guardian
+            || canceller.key() == protocol_config.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub canceller: Signer<'info>,
+
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Rent refund destination, must be the admin who paid for the schedule
+    #[account(mut, address = protocol_config.admin @ ErrorCode::Unauthorized)]
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [PendingChange::SEED, pending_change.id.to_le_bytes().as_ref()],
+        bump = pending_change.bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+pub fn handler(ctx: Context<CancelPending>) -> Result<()> {
+    emit!(ChangeCancelled {
+        pending_change: ctx.accounts.pending_change.key(),
+        id: ctx.accounts.pending_change.id,
+        cancelled_by: ctx.accounts.canceller.key(),
+    });
+
+    msg!(
+        "Parameter change {} cancelled",
+        ctx.accounts.pending_change.id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::{redeem, OptionContext};
+
+/// Claims everything a short position is owed after settlement in one call
+/// Burns the user's whole redemption balance for its pro-rata share of both
+/// settled vaults: the collateral leg and the consideration leg, which already
+/// holds the unclaimed exercise proceeds (redeem_consideration closes at settlement)
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>) -> Result<()> {
+    let amount = ctx.accounts.user_redemption_account.amount;
+    require!(amount > 0, ErrorCode::NoShortTokens);
+
+    msg!("Claiming all {} redemption tokens", amount);
+    redeem::handler(ctx, amount)
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::events::NetPayoffClaimed;
+use crate::instructions::basket_payout::pay_basket_shares;
+use crate::instructions::OptionContext;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::utils::{
+    math::calculate_pro_rata_share_u128,
+    validation::{validate_amount, validate_settled},
+};
+
+/// Claims the netted expiry payoff of option tokens after settlement
+/// Post-expiry: User burns option tokens → receives pro-rata share of the amount
+/// settle_expiry reserved for ITM options (nothing if the series expired OTM)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    validate_settled(&ctx.accounts.option_context)?;
+
+    let option_context = &ctx.accounts.option_context;
+
+    // Get mint decimals
+    let collateral_decimals = ctx.accounts.collateral_mint.decimals;
+    let consideration_decimals = ctx.accounts.consideration_mint.decimals;
+
+    // Calculate pro-rata shares of the netted option payoff using utils
+    let collateral_payout = calculate_pro_rata_share_u128(
+        option_context.net_option_collateral,
+        amount,
+        option_context.settled_option_supply,
+    )?;
+
+    let consideration_payout = calculate_pro_rata_share_u128(
+        option_context.net_option_consideration,
+        amount,
+        option_context.settled_option_supply,
+    )?;
+
+    // Substitutes in the series' basket pay their pro-rata part of the escrow leg
+    let escrows_consideration = option_context.escrows_consideration();
+    let (escrow_paid, escrow_decimals) = if escrows_consideration {
+        (consideration_payout, consideration_decimals)
+    } else {
+        (collateral_payout, collateral_decimals)
+    };
+    let (primary_paid, basket_shares) = option_context.split_escrow_payout(escrow_paid)?;
+    let (collateral_from_vault, consideration_from_vault) = if escrows_consideration {
+        (collateral_payout, primary_paid)
+    } else {
+        (primary_paid, consideration_payout)
+    };
+
+    // 1. Burn option tokens from user (destroys their claim)
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.option_mint.to_account_info(),
+                from: ctx.accounts.user_option_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Prepare PDA signer seeds for vault transfers
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_mint_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_mint_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    // 2. Transfer collateral from vault to user (if any)
+    if collateral_from_vault > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: ctx.accounts.collateral_vault.to_account_info(),
+                    mint: ctx.accounts.collateral_mint.to_account_info(),
+                    to: ctx.accounts.user_collateral_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            collateral_from_vault,
+            collateral_decimals,
+        )?;
+    }
+
+    // 3. Transfer consideration from vault to user (if any)
+    if consideration_from_vault > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: ctx.accounts.consideration_vault.to_account_info(),
+                    mint: ctx.accounts.consideration_mint.to_account_info(),
+                    to: ctx.accounts.user_consideration_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            consideration_from_vault,
+            consideration_decimals,
+        )?;
+    }
+    pay_basket_shares(
+        &mut ctx.accounts.option_context,
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        &basket_shares,
+        escrow_decimals,
+        signer_seeds,
+    )?;
+
+    // Update vault accounting (OptionContext bookkeeping)
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.debit_collateral(collateral_payout)?;
+    option_context.debit_consideration(consideration_payout)?;
+
+    emit!(NetPayoffClaimed {
+        option_context: option_context.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+        collateral: collateral_payout,
+        consideration: consideration_payout,
+        option_supply: ctx
+            .accounts
+            .option_mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?,
+    });
+
+    msg!(
+        "Claimed payoff of {} options. Collateral: {}, Consideration: {}",
+        amount,
+        collateral_payout,
+        consideration_payout
+    );
+
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(ctx.accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(ctx.accounts.consideration_vault.to_account_info()),
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
+}
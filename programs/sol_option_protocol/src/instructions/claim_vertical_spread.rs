@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::VerticalSpreadContext;
+use crate::utils::math::calculate_pro_rata_share_u128;
+use crate::utils::validation::validate_amount;
+
+/// Holder burns `amount` spread tokens after settlement for their pro-rata
+/// share of what holders are owed (the short series' intrinsic value)
+pub fn handler(ctx: Context<VerticalSpreadContext>, amount: u64) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    let spread = &ctx.accounts.vertical_spread;
+    require!(spread.is_settled(), ErrorCode::SeriesNotSettled);
+    let share = calculate_pro_rata_share_u128(
+        spread.holder_payout,
+        amount,
+        ctx.accounts.spread_mint.supply,
+    )?;
+
+    // 1. Burn the spread tokens and pay the share
+    ctx.accounts.burn_spread_tokens(amount)?;
+    ctx.accounts.pay_out(share)?;
+
+    // 2. Bookkeeping
+    let spread = &mut ctx.accounts.vertical_spread;
+    spread.holder_payout -= share;
+
+    msg!(
+        "Claimed {} spread tokens of {} for {}",
+        amount,
+        spread.key(),
+        share
+    );
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesClosed;
+use crate::instructions::OptionData;
+use crate::utils::validation::validate_settled;
+
+/// Permissionless teardown of a dead series: closes any vault still open and
+/// the OptionContext itself, returning all rent (and the creation bond) to the
+/// series authority. The mints stay, as SPL Token mints can't be closed.
+#[derive(Accounts)]
+pub struct CloseSeries<'info> {
+    #[account(mut, close = authority)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// CHECK: Series authority, receives the rent (validated against option_context)
+    #[account(
+        mut,
+        constraint = authority.key() == option_context.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Collateral vault, possibly already closed by sweep_dust
+    /// (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == option_context.collateral_vault
+    )]
+    pub collateral_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Consideration vault, possibly already closed by sweep_dust
+    /// (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Requires what sweep_dust does (settled, no redemption tokens, no option
+/// tokens with a netted payoff, no margin, no escrow lent out); a vault that
+/// still holds tokens must be swept first, as must a basket series' substitute
+/// vaults
+pub fn handler(ctx: Context<CloseSeries>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+
+    // Validation
+    validate_settled(option_context)?;
+    require!(
+        ctx.accounts.redemption_mint.supply == 0,
+        ErrorCode::SeriesNotFullyRedeemed
+    );
+    let options_owed =
+        option_context.net_option_collateral > 0 || option_context.net_option_consideration > 0;
+    require!(
+        ctx.accounts.option_mint.supply == 0 || !options_owed,
+        ErrorCode::SeriesNotFullyRedeemed
+    );
+    require!(
+        option_context.margin_collateral == 0,
+        ErrorCode::SeriesNotFullyRedeemed
+    );
+    // Only sweep_dust / recover_unclaimed close basket vaults, and they close
+    // the collateral vault in the same instruction
+    let uses_basket = option_context.basket_mints[0] != Pubkey::default();
+    require!(
+        !uses_basket || ctx.accounts.collateral_vault.data_is_empty(),
+        ErrorCode::BasketVaultsOpen
+    );
+    require!(
+        option_context.yield_shares == 0,
+        ErrorCode::YieldSharesOutstanding
+    );
+
+    // OptionContext PDA signs the vault closures
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_mint_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_mint_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    for vault in [
+        &ctx.accounts.collateral_vault,
+        &ctx.accounts.consideration_vault,
+    ] {
+        // Already closed by sweep_dust / recover_unclaimed
+        if vault.data_is_empty() {
+            continue;
+        }
+        let balance = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?.amount;
+        require!(balance == 0, ErrorCode::VaultNotEmpty);
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: option_context.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    // The account's lamports (rent and any unrefunded bond) go to the authority
+    // through `close = authority`
+    let series_key = option_context.key();
+    let authority = ctx.accounts.authority.key();
+    emit!(SeriesClosed {
+        option_context: series_key,
+        authority,
+        lamports: option_context.to_account_info().lamports(),
+    });
+
+    msg!(
+        "Closed series {}; rent returned to {}",
+        series_key,
+        authority
+    );
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::SpreadContext;
+use crate::utils::validation::{validate_amount, validate_not_settled};
+
+/// Unlocks `amount` of the writer's spread longs before settlement; the
+/// position must still meet the initial requirement without them
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SpreadContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+    require_keys_eq!(
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.user.key(),
+        ErrorCode::Unauthorized
+    );
+    require!(
+        amount <= ctx.accounts.writer_position.spread_locked,
+        ErrorCode::InvalidAmount
+    );
+
+    // 1. Return the long options to the writer
+    ctx.accounts.unlock(amount, ctx.remaining_accounts)?;
+
+    // 2. Drop the hedge (position and short series bookkeeping)
+    ctx.accounts.writer_position.spread_locked -= amount;
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.spread_hedged = option_context.spread_hedged.saturating_sub(amount);
+
+    // 3. What stays written must be covered without the hedge
+    if ctx.accounts.writer_position.margin_written > 0 {
+        let price = ctx.accounts.live_price()?;
+        let required = ctx
+            .accounts
+            .requirement(price, ctx.accounts.option_context.initial_margin_bps)?;
+        require!(
+            ctx.accounts.writer_position.margin_collateral >= required,
+            ErrorCode::InsufficientMargin
+        );
+        ctx.accounts.record_health(price)?;
+    }
+
+    msg!(
+        "Unlocked {} spread longs from series {}",
+        amount,
+        ctx.accounts.option_context.key()
+    );
+
+    ctx.accounts.check_invariants()
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use spl_marketplace::cpi::accounts::CancelOrder;
+use spl_marketplace::program::SplMarketplace;
+use spl_marketplace::state::order::Order;
+
+use crate::errors::ErrorCode;
+use crate::events::VaultListingClosed;
+use crate::state::OptionVault;
+
+/// Closing a put vault's re-listing of assigned underlying
+#[derive(Accounts)]
+pub struct CloseVaultListing<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = underlying_mint,
+        constraint = option_vault.listing_open() @ ErrorCode::VaultListingNotOpen
+    )]
+    pub option_vault: Box<Account<'info, OptionVault>>,
+
+    /// CHECK: System-owned PDA owning the listing (gets its rent back)
+    #[account(
+        mut,
+        seeds = [OptionVault::AUTHORITY_SEED, option_vault.key().as_ref()],
+        bump = option_vault.authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_underlying_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: The vault's listing (closed when cancelled, so it may be empty)
+    #[account(mut, address = option_vault.listing @ ErrorCode::VaultListingNotOpen)]
+    pub listing: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA of the listing; checked by the marketplace
+    #[account(mut)]
+    pub listing_escrow: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Cancels what is left of the listing back into the vault's underlying
+/// account and reopens deposits and withdrawals. The manager can close it at
+/// any time; once it has sold out, anyone can.
+pub fn handler(ctx: Context<CloseVaultListing>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let unsold = if accounts.listing.data_is_empty() {
+        0
+    } else {
+        let data = accounts.listing.try_borrow_data()?;
+        Order::try_deserialize(&mut &data[..])?.remaining()
+    };
+    require!(
+        unsold == 0 || accounts.caller.key() == accounts.option_vault.manager,
+        ErrorCode::Unauthorized
+    );
+
+    if unsold > 0 {
+        let option_vault_key = accounts.option_vault.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            OptionVault::AUTHORITY_SEED,
+            option_vault_key.as_ref(),
+            &[accounts.option_vault.authority_bump],
+        ]];
+        spl_marketplace::cpi::cancel_order(CpiContext::new_with_signer(
+            accounts.marketplace_program.to_account_info(),
+            CancelOrder {
+                user: accounts.vault_authority.to_account_info(),
+                order: accounts.listing.to_account_info(),
+                return_mint: accounts.underlying_mint.to_account_info(),
+                user_return_account: accounts.vault_underlying_account.to_account_info(),
+                escrow: accounts.listing_escrow.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    let vault = &mut accounts.option_vault;
+    let listing = vault.listing;
+    vault.listing = Pubkey::default();
+
+    emit!(VaultListingClosed {
+        option_vault: vault.key(),
+        listing,
+        unsold,
+    });
+
+    msg!(
+        "Vault {} closed its listing: {} unsold",
+        vault.key(),
+        unsold
+    );
+
+    Ok(())
+}
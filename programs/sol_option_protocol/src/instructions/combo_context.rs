@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::series_transfer::{transfer_series_tokens, SeriesTransfer};
+use crate::instructions::OptionData;
+use crate::state::Combo;
+
+/// Shared accounts struct for combos (create, decompose)
+///
+/// A combo locks one call and one put option of the same underlying and
+/// expiry in escrows owned by the combo PDA and issues a combo token for each
+/// pair, so a straddle or strangle trades as a single SPL token.
+#[derive(Accounts)]
+pub struct ComboContext<'info> {
+    /// Holder locking legs (create) or burning combo tokens (decompose)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Call leg's series
+    pub call_option_context: Box<Account<'info, OptionData>>,
+
+    /// Put leg's series
+    pub put_option_context: Box<Account<'info, OptionData>>,
+
+    /// Combo PDA (created by the first `create_combo` for the pair)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Combo::INIT_SPACE,
+        seeds = [
+            Combo::SEED,
+            call_option_context.key().as_ref(),
+            put_option_context.key().as_ref(),
+        ],
+        bump
+    )]
+    pub combo: Box<Account<'info, Combo>>,
+
+    /// Combo token mint (same decimals as the legs; the combo is authority)
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [Combo::MINT_SEED, combo.key().as_ref()],
+        bump,
+        mint::decimals = call_option_mint.decimals,
+        mint::authority = combo,
+    )]
+    pub combo_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Call leg's option mint (validated against stored value in call_option_context)
+    #[account(
+        constraint = call_option_mint.key() == call_option_context.option_mint
+            @ ErrorCode::InvalidCombo
+    )]
+    pub call_option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Put leg's option mint (validated against stored value in put_option_context)
+    #[account(
+        constraint = put_option_mint.key() == put_option_context.option_mint
+            @ ErrorCode::InvalidCombo
+    )]
+    pub put_option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Locked call options, owned by the combo
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [Combo::ESCROW_SEED, combo.key().as_ref(), call_option_mint.key().as_ref()],
+        bump,
+        token::mint = call_option_mint,
+        token::authority = combo
+    )]
+    pub call_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Locked put options, owned by the combo
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [Combo::ESCROW_SEED, combo.key().as_ref(), put_option_mint.key().as_ref()],
+        bump,
+        token::mint = put_option_mint,
+        token::authority = combo
+    )]
+    pub put_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's call option account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = call_option_mint,
+        associated_token::authority = user,
+    )]
+    pub user_call_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's put option account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = put_option_mint,
+        associated_token::authority = user,
+    )]
+    pub user_put_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's combo token account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = combo_mint,
+        associated_token::authority = user,
+    )]
+    pub user_combo_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ComboContext<'info> {
+    /// Moves `amount` of each leg out of the escrows to the user (the combo
+    /// signs); `hook_accounts` serve the option mints' transfer hooks, if any
+    pub fn unlock(&self, amount: u64, hook_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let combo = &self.combo;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            Combo::SEED,
+            combo.call_series.as_ref(),
+            combo.put_series.as_ref(),
+            &[combo.bump],
+        ]];
+        for (escrow, mint, destination) in [
+            (
+                &self.call_escrow,
+                &self.call_option_mint,
+                &self.user_call_option_account,
+            ),
+            (
+                &self.put_escrow,
+                &self.put_option_mint,
+                &self.user_put_option_account,
+            ),
+        ] {
+            transfer_series_tokens(
+                SeriesTransfer {
+                    token_program: self.token_program.to_account_info(),
+                    from: escrow.to_account_info(),
+                    mint,
+                    to: destination.to_account_info(),
+                    authority: combo.to_account_info(),
+                },
+                hook_accounts,
+                amount,
+                signer_seeds,
+            )?;
+        }
+        Ok(())
+    }
+}
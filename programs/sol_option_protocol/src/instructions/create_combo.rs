@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::series_transfer::{transfer_series_tokens, SeriesTransfer};
+use crate::instructions::ComboContext;
+use crate::state::Combo;
+use crate::utils::validation::{
+    validate_amount, validate_combo_legs, validate_lot_multiple, validate_not_expired,
+    validate_not_settled,
+};
+
+/// Locks `amount` call and `amount` put options in the combo's escrows and
+/// mints `amount` combo tokens to the user (straddle when the strikes match,
+/// strangle when the put is struck lower)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ComboContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let call = &ctx.accounts.call_option_context;
+    let put = &ctx.accounts.put_option_context;
+
+    // Validation
+    validate_amount(amount)?;
+    validate_combo_legs(call, put)?;
+    validate_lot_multiple(call, amount)?;
+    validate_not_expired(call.expiration)?;
+    validate_not_settled(call)?;
+    validate_not_settled(put)?;
+
+    // 1. Lock one call and one put per combo token
+    for (from, mint, to) in [
+        (
+            &ctx.accounts.user_call_option_account,
+            &ctx.accounts.call_option_mint,
+            &ctx.accounts.call_escrow,
+        ),
+        (
+            &ctx.accounts.user_put_option_account,
+            &ctx.accounts.put_option_mint,
+            &ctx.accounts.put_escrow,
+        ),
+    ] {
+        transfer_series_tokens(
+            SeriesTransfer {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: from.to_account_info(),
+                mint,
+                to: to.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+            ctx.remaining_accounts,
+            amount,
+            &[],
+        )?;
+    }
+
+    // 2. Record the pair (first use fills in the combo)
+    let call_series = ctx.accounts.call_option_context.key();
+    let put_series = ctx.accounts.put_option_context.key();
+    let combo_mint = ctx.accounts.combo_mint.key();
+    let combo = &mut ctx.accounts.combo;
+    if combo.call_series == Pubkey::default() {
+        combo.call_series = call_series;
+        combo.put_series = put_series;
+        combo.combo_mint = combo_mint;
+        combo.bump = ctx.bumps.combo;
+    }
+    combo.locked = combo
+        .locked
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // 3. Mint the combo tokens (the combo signs)
+    let combo = &ctx.accounts.combo;
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.combo_mint.to_account_info(),
+                to: ctx.accounts.user_combo_account.to_account_info(),
+                authority: combo.to_account_info(),
+            },
+            &[&[
+                Combo::SEED,
+                call_series.as_ref(),
+                put_series.as_ref(),
+                &[combo.bump],
+            ]],
+        ),
+        amount,
+    )?;
+
+    msg!(
+        "Locked {} {} pairs ({} / {}) into combo {}: {} locked",
+        amount,
+        if Combo::is_straddle(
+            ctx.accounts.call_option_context.strike_price,
+            ctx.accounts.put_option_context.strike_price
+        ) {
+            "straddle"
+        } else {
+            "strangle"
+        },
+        call_series,
+        put_series,
+        combo.key(),
+        combo.locked
+    );
+
+    Ok(())
+}
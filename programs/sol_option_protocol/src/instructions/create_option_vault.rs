@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::OptionVaultCreated;
+use crate::instructions::OptionType;
+use crate::state::OptionVault;
+
+/// Manager opening a pooled writing vault
+#[derive(Accounts)]
+#[instruction(option_type: OptionType)]
+pub struct CreateOptionVault<'info> {
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + OptionVault::INIT_SPACE,
+        seeds = [
+            OptionVault::SEED,
+            manager.key().as_ref(),
+            underlying_mint.key().as_ref(),
+            quote_mint.key().as_ref(),
+            &[option_type as u8],
+        ],
+        bump
+    )]
+    pub option_vault: Box<Account<'info, OptionVault>>,
+
+    /// CHECK: System-owned PDA holding the vault's tokens
+    #[account(seeds = [OptionVault::AUTHORITY_SEED, option_vault.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// Depositors' shares, in the escrow mint's decimals
+    #[account(
+        init,
+        payer = manager,
+        seeds = [OptionVault::SHARE_MINT_SEED, option_vault.key().as_ref()],
+        bump,
+        mint::decimals = if option_type == OptionType::Put {
+            quote_mint.decimals
+        } else {
+            underlying_mint.decimals
+        },
+        mint::authority = vault_authority,
+        mint::token_program = token_program
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = manager,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_underlying_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = manager,
+        associated_token::mint = quote_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a vault writing `option_type` series of the underlying against the
+/// quote mint: covered calls (deposits in the underlying) or cash-secured puts
+/// (deposits in the quote mint). Deposits open right away; the first round
+/// starts once the manager picks a series.
+pub fn handler(ctx: Context<CreateOptionVault>, option_type: OptionType) -> Result<()> {
+    require!(
+        matches!(option_type, OptionType::Call | OptionType::Put),
+        ErrorCode::UnsupportedVaultType
+    );
+
+    let vault = &mut ctx.accounts.option_vault;
+    vault.manager = ctx.accounts.manager.key();
+    vault.underlying_mint = ctx.accounts.underlying_mint.key();
+    vault.quote_mint = ctx.accounts.quote_mint.key();
+    vault.option_type = option_type;
+    vault.share_mint = ctx.accounts.share_mint.key();
+    vault.series = Pubkey::default();
+    vault.ask = Pubkey::default();
+    vault.round = 0;
+    vault.written = 0;
+    vault.listing = Pubkey::default();
+    vault.bump = ctx.bumps.option_vault;
+    vault.authority_bump = ctx.bumps.vault_authority;
+
+    emit!(OptionVaultCreated {
+        option_vault: vault.key(),
+        manager: vault.manager,
+        underlying_mint: vault.underlying_mint,
+        quote_mint: vault.quote_mint,
+        option_type,
+    });
+
+    msg!("Option vault {} created", vault.key());
+
+    Ok(())
+}
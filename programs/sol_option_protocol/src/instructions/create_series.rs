@@ -1,8 +1,32 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{transfer_hook, ExtensionType};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self as token, TokenInterface};
 
-use crate::utils::validation::{validate_expiration, validate_strike_price};
+use crate::errors::ErrorCode;
+use crate::events::SeriesCreated;
+use crate::metadata::{write_series_metadata, SeriesMetadataAccounts};
+use crate::utils::symbol::series_symbol;
+use crate::utils::validation::{
+    risky_mint_extensions, validate_basket_config, validate_binary_config, validate_exercise_style,
+    validate_expiration, validate_expiry_grid, validate_forward_config, validate_freeze_config,
+    validate_knockout_barrier, validate_lot_config, validate_margin_config, validate_metadata_uri,
+    validate_mint_extensions, validate_mints_approved, validate_oracle_config,
+    validate_perpetual_config, validate_series_features, validate_settlement_delay,
+    validate_strike_exponent, validate_strike_price, validate_strike_tick, validate_transfer_hook,
+    validate_underlying_symbol,
+};
 
-use crate::instructions::OptionCreate;
+use crate::instructions::create_series_batch::create_pda_account;
+use crate::instructions::{
+    OptionCreate, OptionData, OptionType, SeriesConfig, PERPETUAL_EXPIRATION,
+};
+use crate::oracle::SettlementSource;
+use crate::state::{ApprovedMint, CollateralBasket, CreatorStats, ProtocolConfig, StrikeTick};
 
 pub fn handler(
     ctx: Context<OptionCreate>,
@@ -10,46 +34,500 @@ pub fn handler(
     consideration_mint_key: Pubkey,
     strike_price: u64,
     expiration: i64,
-    is_put: bool,
+    option_type: OptionType,
+    config: SeriesConfig,
 ) -> Result<()> {
     // Validations using utils
-    validate_expiration(expiration)?;
-    validate_strike_price(strike_price)?;
+    validate_series_mints(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.collateral_mint.to_account_info(),
+        &ctx.accounts.consideration_mint.to_account_info(),
+        &ctx.accounts.collateral_approval,
+        &ctx.accounts.consideration_approval,
+    )?;
+    let (strike_exponent, strike_scale) = validate_series_config(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.user.key(),
+        expiration,
+        option_type,
+        &config,
+        ctx.accounts.collateral_mint.decimals,
+        ctx.accounts.consideration_mint.decimals,
+    )?;
+    validate_transfer_hook(
+        &config.transfer_hook_program,
+        ctx.accounts.token_program.key,
+    )?;
+    validate_strike(
+        strike_price,
+        option_type,
+        &config,
+        strike_exponent,
+        ctx.accounts.consideration_mint.decimals,
+        StrikeTick::tick_size_of(&ctx.accounts.strike_tick)?,
+    )?;
+
+    // Per-creator rate limits (config-driven, disabled when zero)
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    if creator_stats.creator == Pubkey::default() {
+        creator_stats.creator = ctx.accounts.user.key();
+        creator_stats.bump = ctx.bumps.creator_stats;
+    }
+    let creation_bond = record_creation(
+        &ctx.accounts.protocol_config,
+        creator_stats,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.option_context.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // Option and redemption mints (OptionContext PDA is their authority)
+    let option_context_key = ctx.accounts.option_context.key();
+    let space = series_mint_space(&config.transfer_hook_program)?;
+    for (prefix, mint, bump) in [
+        (
+            &b"option_mint"[..],
+            &ctx.accounts.option_mint,
+            ctx.bumps.option_mint,
+        ),
+        (
+            &b"redemption_mint"[..],
+            &ctx.accounts.redemption_mint,
+            ctx.bumps.redemption_mint,
+        ),
+    ] {
+        create_pda_account(
+            &ctx.accounts.user,
+            mint,
+            &ctx.accounts.system_program,
+            ctx.accounts.token_program.key,
+            space,
+            &[prefix, option_context_key.as_ref(), &[bump]],
+        )?;
+        initialize_series_mint(
+            &ctx.accounts.token_program,
+            mint,
+            ctx.accounts.collateral_mint.decimals,
+            &option_context_key,
+            config.freezable,
+            &config.transfer_hook_program,
+        )?;
+    }
 
     // Store all values in OptionContext
-    let option_context = &mut ctx.accounts.option_context;
+    let series = NewSeries {
+        collateral_mint: collateral_mint_key,
+        consideration_mint: consideration_mint_key,
+        strike_price,
+        expiration,
+        option_type,
+        bump: ctx.bumps.option_context,
+        option_mint: ctx.accounts.option_mint.key(),
+        redemption_mint: ctx.accounts.redemption_mint.key(),
+        collateral_vault: ctx.accounts.collateral_vault.key(),
+        consideration_vault: ctx.accounts.consideration_vault.key(),
+        authority: ctx.accounts.user.key(),
+        creation_bond,
+        strike_exponent,
+        strike_scale,
+        created_at: Clock::get()?.unix_timestamp,
+    };
+    write_series(&mut ctx.accounts.option_context, &series, &config);
+
+    // Token metadata, when the creator passed the Token Metadata accounts
+    let accounts = &ctx.accounts;
+    match (
+        &accounts.token_metadata_program,
+        &accounts.option_metadata,
+        &accounts.redemption_metadata,
+    ) {
+        (Some(metadata_program), Some(option_metadata), Some(redemption_metadata)) => {
+            write_series_metadata(
+                &accounts.option_context,
+                SeriesMetadataAccounts {
+                    metadata_program: &metadata_program.to_account_info(),
+                    option_metadata: &option_metadata.to_account_info(),
+                    redemption_metadata: &redemption_metadata.to_account_info(),
+                    option_mint: &accounts.option_mint.to_account_info(),
+                    redemption_mint: &accounts.redemption_mint.to_account_info(),
+                    payer: &accounts.user.to_account_info(),
+                    system_program: &accounts.system_program.to_account_info(),
+                    rent: &accounts.rent.to_account_info(),
+                },
+            )?;
+        }
+        (None, None, None) => {}
+        _ => return err!(ErrorCode::InvalidMetadataAccount),
+    }
+
+    log_series(
+        ctx.accounts.option_context.key(),
+        &ctx.accounts.option_context,
+    );
+
+    Ok(())
+}
+
+/// Validates everything about a new series except its strike; returns the
+/// strike exponent and strike scale it will store
+pub(crate) fn validate_series_config(
+    protocol_config: &ProtocolConfig,
+    creator: &Pubkey,
+    expiration: i64,
+    option_type: OptionType,
+    config: &SeriesConfig,
+    collateral_decimals: u8,
+    consideration_decimals: u8,
+) -> Result<(u8, u8)> {
+    // Perpetual series have no expiry for the protocol's limits or grid to check
+    if expiration != PERPETUAL_EXPIRATION {
+        validate_expiration(protocol_config, expiration)?;
+        validate_expiry_grid(protocol_config, creator, expiration)?;
+    }
+    validate_series_features(
+        protocol_config,
+        option_type,
+        expiration,
+        config.initial_margin_bps,
+        config.knockout_barrier,
+        config.accepts_basket,
+    )?;
+    validate_perpetual_config(
+        option_type,
+        expiration,
+        config.funding_rate_bps,
+        config.exercise_style,
+        config.initial_margin_bps,
+    )?;
+    validate_forward_config(
+        option_type,
+        expiration,
+        config.exercise_style,
+        config.initial_margin_bps,
+        config.knockout_barrier,
+    )?;
+    validate_basket_config(
+        option_type,
+        config.accepts_basket,
+        config.funding_rate_bps,
+        config.initial_margin_bps,
+    )?;
+    validate_oracle_config(&config.primary_oracle, &config.secondary_oracle)?;
+    validate_metadata_uri(&config.metadata_uri)?;
+    validate_freeze_config(config.freezable, &config.compliance_authority)?;
+    validate_underlying_symbol(&config.underlying_symbol)?;
+    validate_exercise_style(
+        config.exercise_style,
+        config.exercise_window,
+        config.exercise_cutoff,
+    )?;
+    validate_settlement_delay(config.settlement_delay)?;
+    validate_margin_config(
+        config.initial_margin_bps,
+        config.maintenance_margin_bps,
+        &config.primary_oracle,
+    )?;
+    validate_lot_config(config.lot_size, config.min_mint_amount)?;
+    validate_binary_config(
+        option_type,
+        config.binary_payout,
+        &config.primary_oracle,
+        config.initial_margin_bps,
+    )?;
+    let strike_exponent = config.strike_exponent.unwrap_or(consideration_decimals);
+    let strike_scale =
+        validate_strike_exponent(strike_exponent, collateral_decimals, consideration_decimals)?;
+    Ok((strike_exponent, strike_scale))
+}
+
+/// Validates a new series' mints: no Token-2022 extension the protocol config
+/// doesn't allow, and approved by governance while the allowlist is enabled
+pub(crate) fn validate_series_mints<'info>(
+    protocol_config: &ProtocolConfig,
+    collateral_mint: &AccountInfo<'info>,
+    consideration_mint: &AccountInfo<'info>,
+    collateral_approval: &AccountInfo,
+    consideration_approval: &AccountInfo,
+) -> Result<()> {
+    for mint in [collateral_mint, consideration_mint] {
+        if *mint.owner == spl_token_2022::ID {
+            let extensions = risky_mint_extensions(&mint.try_borrow_data()?)?;
+            validate_mint_extensions(protocol_config, extensions)?;
+        }
+    }
+    if !protocol_config.mint_allowlist_enabled {
+        return Ok(());
+    }
+    validate_mints_approved(
+        protocol_config,
+        ApprovedMint::is_approved(collateral_approval)?,
+        ApprovedMint::is_approved(consideration_approval)?,
+    )
+}
 
+/// Validates a new series' strike (non-zero, on the consideration mint's tick,
+/// inside the knock-out barrier)
+pub(crate) fn validate_strike(
+    strike_price: u64,
+    option_type: OptionType,
+    config: &SeriesConfig,
+    strike_exponent: u8,
+    consideration_decimals: u8,
+    tick_size: u64,
+) -> Result<()> {
+    validate_strike_price(strike_price)?;
+    validate_strike_tick(
+        strike_price,
+        strike_exponent,
+        consideration_decimals,
+        tick_size,
+    )?;
+    validate_knockout_barrier(
+        option_type,
+        strike_price,
+        config.knockout_barrier,
+        &config.primary_oracle,
+    )
+}
+
+/// Counts one more series against the creator's rate limits and moves the
+/// creation bond (config-driven, held by the series until it is closed) from
+/// `creator` into `option_context`; returns the bond
+pub(crate) fn record_creation<'info>(
+    protocol_config: &ProtocolConfig,
+    creator_stats: &mut CreatorStats,
+    creator: &AccountInfo<'info>,
+    option_context: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let clock = Clock::get()?;
+    creator_stats.record_creation(protocol_config, clock.epoch, clock.unix_timestamp)?;
+
+    let creation_bond = protocol_config.creation_bond;
+    if creation_bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: creator.clone(),
+                    to: option_context.clone(),
+                },
+            ),
+            creation_bond,
+        )?;
+    }
+    Ok(creation_bond)
+}
+
+/// Size of a series' option or redemption mint: a plain mint, or one with
+/// room for the transfer hook extension when the series has a hook program
+pub(crate) fn series_mint_space(transfer_hook_program: &Pubkey) -> Result<usize> {
+    if *transfer_hook_program == Pubkey::default() {
+        return Ok(MintState::LEN);
+    }
+    ExtensionType::try_calculate_account_len::<MintState>(&[ExtensionType::TransferHook])
+        .map_err(Into::into)
+}
+
+/// Initializes a series' option or redemption mint (allocated with
+/// `series_mint_space`) with `authority` as mint authority, and as freeze
+/// authority too when the series is `freezable`. A hooked series gets its
+/// transfer hook first, with no authority, so the compliance program can
+/// never be swapped out.
+pub(crate) fn initialize_series_mint<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &AccountInfo<'info>,
+    decimals: u8,
+    authority: &Pubkey,
+    freezable: bool,
+    transfer_hook_program: &Pubkey,
+) -> Result<()> {
+    if *transfer_hook_program != Pubkey::default() {
+        invoke(
+            &transfer_hook::instruction::initialize(
+                token_program.key,
+                mint.key,
+                None,
+                Some(*transfer_hook_program),
+            )?,
+            std::slice::from_ref(mint),
+        )?;
+    }
+    token::initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token::InitializeMint2 { mint: mint.clone() },
+        ),
+        decimals,
+        authority,
+        freezable.then_some(authority),
+    )
+}
+
+/// Everything a new series stores besides its `SeriesConfig`
+pub(crate) struct NewSeries {
+    pub collateral_mint: Pubkey,
+    pub consideration_mint: Pubkey,
+    pub strike_price: u64,
+    pub expiration: i64,
+    pub option_type: OptionType,
+    pub bump: u8,
+    pub option_mint: Pubkey,
+    pub redemption_mint: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub consideration_vault: Pubkey,
+    pub authority: Pubkey,
+    pub creation_bond: u64,
+    pub strike_exponent: u8,
+    pub strike_scale: u8,
+    pub created_at: i64,
+}
+
+/// Initializes every field of a freshly created OptionContext
+pub(crate) fn write_series(
+    option_context: &mut OptionData,
+    series: &NewSeries,
+    config: &SeriesConfig,
+) {
     // Core parameters
-    option_context.collateral_mint = collateral_mint_key;
-    option_context.consideration_mint = consideration_mint_key;
-    option_context.strike_price = strike_price;
-    option_context.expiration = expiration;
-    option_context.is_put = is_put;
+    option_context.collateral_mint = series.collateral_mint;
+    option_context.consideration_mint = series.consideration_mint;
+    option_context.strike_price = series.strike_price;
+    option_context.expiration = series.expiration;
+    option_context.option_type = series.option_type;
 
-    // Store the mint keys (mints are already initialized by Anchor's init constraint)
-    option_context.option_mint = ctx.accounts.option_mint.key();
-    option_context.redemption_mint = ctx.accounts.redemption_mint.key();
+    // Store the mint keys (mints are already initialized)
+    option_context.option_mint = series.option_mint;
+    option_context.redemption_mint = series.redemption_mint;
 
     // Associated accounts (vaults) - custody only, logic in OptionSeries
-    option_context.collateral_vault = ctx.accounts.collateral_vault.key();
-    option_context.consideration_vault = ctx.accounts.consideration_vault.key();
+    option_context.collateral_vault = series.collateral_vault;
+    option_context.consideration_vault = series.consideration_vault;
 
     // State tracking
     option_context.total_supply = 0;
     option_context.exercised_amount = 0;
 
+    // Vault accounting (payouts use these, never the live vault balances)
+    option_context.collateral_deposited = 0;
+    option_context.collateral_paid = 0;
+    option_context.consideration_received = 0;
+    option_context.consideration_paid = 0;
+    option_context.consideration_claimed = 0;
+    option_context.collateral_redeemed = 0;
+
+    // Margin writing (disabled unless the creator set the buffers)
+    option_context.initial_margin_bps = config.initial_margin_bps;
+    option_context.maintenance_margin_bps = config.maintenance_margin_bps;
+    option_context.margin_written = 0;
+    option_context.margin_collateral = 0;
+    option_context.spread_hedged = 0;
+
+    option_context.creation_bond = series.creation_bond;
+
+    // Contract sizing (round lots instead of raw token dust)
+    option_context.lot_size = config.lot_size;
+    option_context.min_mint_amount = config.min_mint_amount;
+    option_context.contract_multiplier = config.contract_multiplier.max(1);
+
+    // Strike units (every strike and oracle conversion goes through these)
+    option_context.strike_exponent = series.strike_exponent;
+    option_context.strike_scale = series.strike_scale;
+
+    // Binary payout (cash-or-nothing series only)
+    option_context.binary_payout = config.binary_payout;
+
+    // Knock-out barrier (record_knockout kills the series once it is breached)
+    option_context.knockout_barrier = config.knockout_barrier;
+    option_context.knocked_out_at = 0;
+
+    // Emergency freeze (only freeze_series sets it)
+    option_context.frozen_at = 0;
+
+    // Funding (perpetual series only; accrues from creation)
+    option_context.funding_rate_bps = config.funding_rate_bps;
+    option_context.funding_started_at = series.created_at;
+    option_context.funding_index = 0;
+    option_context.funding_updated_at = series.created_at;
+    option_context.funding_collected = 0;
+    option_context.funding_claimed = 0;
+
+    // Collateral basket (substitute slots fill on first deposit)
+    option_context.accepts_basket = config.accepts_basket;
+    option_context.basket_mints = [Pubkey::default(); CollateralBasket::MAX_MINTS];
+    option_context.basket_escrow = [0; CollateralBasket::MAX_MINTS];
+
+    // Transfer hook (the mints were created with it; recorded for clients)
+    option_context.transfer_hook_program = config.transfer_hook_program;
+
+    // Freeze authority (the mints were created with the series PDA as it)
+    option_context.freezable = config.freezable;
+    option_context.compliance_authority = config.compliance_authority;
+
+    // Credential gate (mint and exercise check it)
+    option_context.gate_mint = config.gate_mint;
+
+    // Yield (only enable_collateral_yield opts in)
+    option_context.yield_venue = Pubkey::default();
+    option_context.yield_principal = 0;
+    option_context.yield_shares = 0;
+
+    // Marketplace (only open_series_market sets it)
+    option_context.market = Pubkey::default();
+
+    // Settlement oracles (price is recorded later by settle_expiry)
+    option_context.primary_oracle = config.primary_oracle;
+    option_context.secondary_oracle = config.secondary_oracle;
+    option_context.settlement_price = 0;
+    option_context.settlement_source = SettlementSource::Unsettled;
+    option_context.last_oracle_price = 0;
+    option_context.last_oracle_update = 0;
+
+    // Series authority starts as the creator
+    option_context.authority = series.authority;
+    option_context.pending_authority = Pubkey::default();
+    option_context.status = 0;
+
+    // Exercise style (European series exercise only in the window after expiry)
+    option_context.exercise_style = config.exercise_style;
+    option_context.exercise_window = config.exercise_window;
+    option_context.exercise_cutoff = config.exercise_cutoff;
+
+    // Grace period after exercise closes so settlement cranks run before redeem opens
+    option_context.settlement_delay = config.settlement_delay;
+
+    // Optional off-chain metadata (immutable once the series exists)
+    option_context.metadata_uri = config.metadata_uri.clone();
+    option_context.underlying_symbol = config.underlying_symbol.to_uppercase();
+
     // Store OptionContext PDA bump
-    option_context.bump = ctx.bumps.option_context;
+    option_context.bump = series.bump;
+
+    // Current layout, nothing reserved in use yet
+    option_context.version = OptionData::CURRENT_VERSION;
+    option_context.reserved = [0; 16];
+}
 
+pub(crate) fn log_series(key: Pubkey, option_context: &OptionData) {
+    emit!(SeriesCreated {
+        option_context: key,
+        authority: option_context.authority,
+        collateral_mint: option_context.collateral_mint,
+        consideration_mint: option_context.consideration_mint,
+        option_mint: option_context.option_mint,
+        redemption_mint: option_context.redemption_mint,
+        strike_price: option_context.strike_price,
+        expiration: option_context.expiration,
+        option_type: option_context.option_type,
+    });
     msg!(
         "Created option series: {} @ {} (strike currency: {}) expiring {}",
-        ctx.accounts.collateral_mint.key(),
-        strike_price,
-        ctx.accounts.consideration_mint.key(),
-        expiration
+        option_context.collateral_mint,
+        option_context.strike_price,
+        option_context.consideration_mint,
+        option_context.expiration
     );
-    msg!("Option mint: {}", ctx.accounts.option_mint.key());
-    msg!("Redemption mint: {}", ctx.accounts.redemption_mint.key());
-
-    Ok(())
+    msg!("Symbol: {}", series_symbol(option_context));
+    msg!("Option mint: {}", option_context.option_mint);
+    msg!("Redemption mint: {}", option_context.redemption_mint);
 }
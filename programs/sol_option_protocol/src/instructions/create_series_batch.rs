@@ -0,0 +1,366 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::state::Account as TokenState;
+use anchor_spl::token_interface::{self as token, Mint, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::create_series::{
+    initialize_series_mint, log_series, record_creation, series_mint_space, validate_series_config,
+    validate_series_mints, validate_strike, write_series, NewSeries,
+};
+use crate::instructions::{OptionData, OptionType, SeriesConfig};
+use crate::state::{ApprovedMint, CreatorStats, ProtocolConfig, StrikeTick};
+use crate::utils::validation::validate_transfer_hook;
+
+/// Most strikes a single batch can list (five accounts each)
+pub const MAX_BATCH_STRIKES: usize = 8;
+
+/// Accounts the series of a ladder share; each strike's own accounts follow
+/// in `remaining_accounts`
+#[derive(Accounts)]
+#[instruction(collateral_mint_key: Pubkey, consideration_mint_key: Pubkey)]
+pub struct CreateSeriesBatch<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Protocol config (creation rate limits)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Creator's rate-limit tracking account (created on first series)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + CreatorStats::INIT_SPACE,
+        seeds = [CreatorStats::SEED, user.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Box<Account<'info, CreatorStats>>,
+
+    /// Collateral mint (same checks as `create_option`)
+    #[account(
+        constraint = collateral_mint.key() == collateral_mint_key @ ErrorCode::InvalidUnderlyingMint,
+        constraint = collateral_mint_key != consideration_mint_key @ ErrorCode::IdenticalMints,
+        mint::token_program = token_program,
+    )]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Consideration/strike currency mint (same checks as `create_option`)
+    #[account(
+        constraint = consideration_mint.key() == consideration_mint_key @ ErrorCode::InvalidStrikeCurrency,
+        mint::token_program = token_program,
+    )]
+    pub consideration_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Strike tick PDA of the consideration mint; may not exist (any
+    /// strike allowed), only deserialized when governance has created it
+    #[account(
+        seeds = [StrikeTick::SEED, consideration_mint.key().as_ref()],
+        bump
+    )]
+    pub strike_tick: UncheckedAccount<'info>,
+
+    /// CHECK: Approval PDA of the collateral mint; may not exist (not approved),
+    /// only read while the mint allowlist is enabled
+    #[account(
+        seeds = [ApprovedMint::SEED, collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_approval: UncheckedAccount<'info>,
+
+    /// CHECK: Approval PDA of the consideration mint (same as above)
+    #[account(
+        seeds = [ApprovedMint::SEED, consideration_mint.key().as_ref()],
+        bump
+    )]
+    pub consideration_approval: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates one series per strike, all sharing mints, expiry, option type and
+/// config (a standard strike ladder)
+///
+/// `remaining_accounts` holds five writable accounts per strike, in the order
+/// of `strikes`: option_context, option_mint, redemption_mint,
+/// collateral_vault, consideration_vault (the PDAs `create_option` derives).
+/// Strikes must be strictly increasing.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateSeriesBatch<'info>>,
+    collateral_mint_key: Pubkey,
+    consideration_mint_key: Pubkey,
+    expiration: i64,
+    option_type: OptionType,
+    strikes: Vec<u64>,
+    config: SeriesConfig,
+) -> Result<()> {
+    // Validation
+    require!(
+        !strikes.is_empty() && strikes.len() <= MAX_BATCH_STRIKES,
+        ErrorCode::InvalidBatch
+    );
+    require!(
+        strikes.windows(2).all(|pair| pair[0] < pair[1]),
+        ErrorCode::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == strikes.len() * SERIES_ACCOUNTS,
+        ErrorCode::InvalidBatch
+    );
+    validate_series_mints(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.collateral_mint.to_account_info(),
+        &ctx.accounts.consideration_mint.to_account_info(),
+        &ctx.accounts.collateral_approval,
+        &ctx.accounts.consideration_approval,
+    )?;
+    let consideration_decimals = ctx.accounts.consideration_mint.decimals;
+    let (strike_exponent, strike_scale) = validate_series_config(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.user.key(),
+        expiration,
+        option_type,
+        &config,
+        ctx.accounts.collateral_mint.decimals,
+        consideration_decimals,
+    )?;
+    validate_transfer_hook(
+        &config.transfer_hook_program,
+        ctx.accounts.token_program.key,
+    )?;
+    let tick_size = StrikeTick::tick_size_of(&ctx.accounts.strike_tick)?;
+    for &strike_price in &strikes {
+        validate_strike(
+            strike_price,
+            option_type,
+            &config,
+            strike_exponent,
+            consideration_decimals,
+            tick_size,
+        )?;
+    }
+
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    if creator_stats.creator == Pubkey::default() {
+        creator_stats.creator = ctx.accounts.user.key();
+        creator_stats.bump = ctx.bumps.creator_stats;
+    }
+
+    for (strike_price, accounts) in strikes
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(SERIES_ACCOUNTS))
+    {
+        let [option_context, option_mint, redemption_mint, collateral_vault, consideration_vault] =
+            accounts
+        else {
+            return err!(ErrorCode::InvalidBatch);
+        };
+
+        // 1. The series PDA, exactly as create_option derives it
+        let strike_price_bytes = strike_price.to_le_bytes();
+        let expiration_bytes = expiration.to_le_bytes();
+        let option_type_byte = [option_type as u8];
+        let (expected, bump) = Pubkey::find_program_address(
+            &[
+                b"option_context",
+                collateral_mint_key.as_ref(),
+                consideration_mint_key.as_ref(),
+                &strike_price_bytes,
+                &expiration_bytes,
+                &option_type_byte,
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(option_context.key(), expected, ErrorCode::InvalidBatch);
+        let signer_seeds: &[&[u8]] = &[
+            b"option_context",
+            collateral_mint_key.as_ref(),
+            consideration_mint_key.as_ref(),
+            &strike_price_bytes,
+            &expiration_bytes,
+            &option_type_byte,
+            &[bump],
+        ];
+        create_pda_account(
+            &ctx.accounts.user,
+            option_context,
+            &ctx.accounts.system_program,
+            ctx.program_id,
+            8 + OptionData::INIT_SPACE,
+            signer_seeds,
+        )?;
+
+        // 2. Its mints and vaults (OptionContext PDA is their authority)
+        let decimals = ctx.accounts.collateral_mint.decimals;
+        let mint_space = series_mint_space(&config.transfer_hook_program)?;
+        for (prefix, mint) in [
+            (&b"option_mint"[..], option_mint),
+            (&b"redemption_mint"[..], redemption_mint),
+        ] {
+            create_series_pda(&ctx, prefix, option_context, mint, mint_space)?;
+            initialize_series_mint(
+                &ctx.accounts.token_program,
+                mint,
+                decimals,
+                &option_context.key(),
+                config.freezable,
+                &config.transfer_hook_program,
+            )?;
+        }
+        for (prefix, vault, mint) in [
+            (
+                &b"collateral_vault"[..],
+                collateral_vault,
+                ctx.accounts.collateral_mint.to_account_info(),
+            ),
+            (
+                &b"consideration_vault"[..],
+                consideration_vault,
+                ctx.accounts.consideration_mint.to_account_info(),
+            ),
+        ] {
+            create_series_pda(&ctx, prefix, option_context, vault, TokenState::LEN)?;
+            token::initialize_account3(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::InitializeAccount3 {
+                    account: vault.clone(),
+                    mint,
+                    authority: option_context.clone(),
+                },
+            ))?;
+        }
+
+        // 3. Rate limits and creation bond count every series
+        let creation_bond = record_creation(
+            &ctx.accounts.protocol_config,
+            &mut ctx.accounts.creator_stats,
+            &ctx.accounts.user.to_account_info(),
+            option_context,
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // 4. Store the series
+        let mut data = OptionData::default();
+        write_series(
+            &mut data,
+            &NewSeries {
+                collateral_mint: collateral_mint_key,
+                consideration_mint: consideration_mint_key,
+                strike_price: *strike_price,
+                expiration,
+                option_type,
+                bump,
+                option_mint: option_mint.key(),
+                redemption_mint: redemption_mint.key(),
+                collateral_vault: collateral_vault.key(),
+                consideration_vault: consideration_vault.key(),
+                authority: ctx.accounts.user.key(),
+                creation_bond,
+                strike_exponent,
+                strike_scale,
+                created_at: Clock::get()?.unix_timestamp,
+            },
+            &config,
+        );
+        data.try_serialize(&mut &mut option_context.try_borrow_mut_data()?[..])?;
+
+        log_series(option_context.key(), &data);
+    }
+
+    msg!("Created {} series in one batch", strikes.len());
+
+    Ok(())
+}
+
+/// Accounts per strike in `remaining_accounts`
+const SERIES_ACCOUNTS: usize = 5;
+
+/// Creates the `[prefix, option_context]` PDA owned by the token program
+fn create_series_pda<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, CreateSeriesBatch<'info>>,
+    prefix: &[u8],
+    option_context: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let option_context_key = option_context.key();
+    let (expected, bump) =
+        Pubkey::find_program_address(&[prefix, option_context_key.as_ref()], ctx.program_id);
+    require_keys_eq!(target.key(), expected, ErrorCode::InvalidBatch);
+    create_pda_account(
+        &ctx.accounts.user,
+        target,
+        &ctx.accounts.system_program,
+        ctx.accounts.token_program.key,
+        space,
+        &[prefix, option_context_key.as_ref(), &[bump]],
+    )
+}
+
+/// Allocates a rent-exempt PDA owned by `owner`, the way Anchor's `init` does
+/// (accounts someone pre-funded are topped up, allocated and assigned instead)
+pub(crate) fn create_pda_account<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    system: &Program<'info, System>,
+    owner: &Pubkey,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer: &[&[&[u8]]] = &[seeds];
+    if target.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(target.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system.to_account_info(),
+            system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            signer,
+        ),
+        owner,
+    )
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::series_transfer::{transfer_series_tokens, SeriesTransfer};
+use crate::instructions::SpreadContext;
+use crate::utils::validation::{
+    validate_amount, validate_not_expired, validate_not_settled, validate_spread_legs,
+};
+
+/// Locks `amount` long options of a further-OTM series against the writer's
+/// margin short and releases the margin the hedge makes unnecessary
+///
+/// Hedged shorts need at most the spread's max loss, so everything above the
+/// new initial requirement (at the live oracle price) returns to the writer.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SpreadContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    validate_not_expired(ctx.accounts.option_context.expiration)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+    require!(
+        ctx.accounts.option_context.margin_enabled(),
+        ErrorCode::MarginDisabled
+    );
+    require_keys_eq!(
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.user.key(),
+        ErrorCode::Unauthorized
+    );
+    validate_spread_legs(
+        &ctx.accounts.option_context,
+        &ctx.accounts.long_option_context,
+    )?;
+
+    // 1. Lock the long options in the position's escrow
+    transfer_series_tokens(
+        SeriesTransfer {
+            token_program: ctx.accounts.token_program.to_account_info(),
+            from: ctx.accounts.user_long_option_account.to_account_info(),
+            mint: &ctx.accounts.long_option_mint,
+            to: ctx.accounts.spread_escrow.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        amount,
+        &[],
+    )?;
+
+    // 2. Record the hedge (position and short series bookkeeping)
+    let long_series = ctx.accounts.long_option_context.key();
+    let long_strike = ctx.accounts.long_option_context.strike_price;
+    let position = &mut ctx.accounts.writer_position;
+    position.spread_series = long_series;
+    position.spread_strike = long_strike;
+    position.spread_locked = position
+        .spread_locked
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.spread_hedged = option_context
+        .spread_hedged
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // 3. Release margin above the new initial requirement
+    let price = ctx.accounts.live_price()?;
+    let required = ctx
+        .accounts
+        .requirement(price, ctx.accounts.option_context.initial_margin_bps)?;
+    let excess = ctx
+        .accounts
+        .writer_position
+        .margin_collateral
+        .saturating_sub(required);
+    if excess > 0 {
+        let option_context = &ctx.accounts.option_context;
+        let mint = ctx.accounts.margin_mint();
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: ctx.accounts.margin_vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: ctx.accounts.user_margin_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                &[&[
+                    b"option_context",
+                    option_context.collateral_mint.as_ref(),
+                    option_context.consideration_mint.as_ref(),
+                    &option_context.strike_price.to_le_bytes(),
+                    &option_context.expiration.to_le_bytes(),
+                    &[option_context.option_type as u8],
+                    &[option_context.bump],
+                ]],
+            ),
+            excess,
+            mint.decimals,
+        )?;
+        ctx.accounts.writer_position.margin_collateral -= excess;
+        ctx.accounts.option_context.margin_collateral -= excess;
+    }
+    let health = ctx.accounts.record_health(price)?;
+
+    msg!(
+        "Locked {} long options of {} against series {}: released {} margin (health {} bps)",
+        amount,
+        long_series,
+        ctx.accounts.option_context.key(),
+        excess,
+        health
+    );
+
+    ctx.accounts.check_invariants()
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::ComboContext;
+use crate::utils::validation::validate_amount;
+
+/// Burns `amount` combo tokens and returns `amount` call and `amount` put
+/// options to the user; works at any time, so holders can always get back to
+/// the legs to exercise or claim them
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ComboContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    require!(
+        amount <= ctx.accounts.combo.locked,
+        ErrorCode::InvalidAmount
+    );
+
+    // 1. Burn the combo tokens from the user
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.combo_mint.to_account_info(),
+                from: ctx.accounts.user_combo_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // 2. Return both legs
+    ctx.accounts.unlock(amount, ctx.remaining_accounts)?;
+
+    // 3. Release the pairs (combo bookkeeping)
+    let combo = &mut ctx.accounts.combo;
+    combo.locked -= amount;
+
+    msg!(
+        "Decomposed {} pairs from combo {}: {} locked",
+        amount,
+        combo.key(),
+        combo.locked
+    );
+
+    Ok(())
+}
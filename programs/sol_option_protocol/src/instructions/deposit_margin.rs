@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::MarginContext;
+use crate::invariants::check_series;
+use crate::utils::validation::{validate_amount, validate_not_settled};
+
+/// Tops up any writer's margin position; anyone may pay (e.g. a keeper
+/// rescuing a position close to liquidation)
+pub fn handler(ctx: Context<MarginContext>, amount: u64) -> Result<()> {
+    validate_amount(amount)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+
+    // 1. Move the deposit into the backing vault
+    ctx.accounts.deposit(amount)?;
+
+    // 2. Position and series bookkeeping
+    ctx.accounts.credit_margin(amount)?;
+
+    // Refresh the health factor when the oracle can be read; a top-up never
+    // waits on the feed
+    if let Ok(price) = ctx.accounts.live_price() {
+        ctx.accounts.record_health(price)?;
+    }
+
+    msg!(
+        "{} deposited {} margin for {} in series {}",
+        ctx.accounts.user.key(),
+        amount,
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.option_context.key()
+    );
+
+    check_series(&ctx.accounts.option_context, ctx.accounts.series_accounts())
+}
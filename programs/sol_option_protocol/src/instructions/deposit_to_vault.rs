@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::VaultSharesChanged;
+use crate::instructions::vault_deposit::deposit_received;
+use crate::instructions::VaultShares;
+use crate::state::OptionVault;
+use crate::utils::validation::validate_amount;
+
+/// Deposits `amount` of the vault's escrow mint between rounds for shares
+/// priced at the vault's balance of it. Once rounds have brought in the other
+/// mint (premiums, or assignments), the deposit also brings that mint in the
+/// vault's own ratio, so shares always claim both balances evenly.
+pub fn handler(ctx: Context<VaultShares>, amount: u64) -> Result<()> {
+    validate_amount(amount)?;
+
+    let accounts = ctx.accounts;
+    let underlying = accounts.escrows_underlying();
+    let (escrow_balance, other_balance) = if underlying {
+        (
+            accounts.vault_underlying_account.amount,
+            accounts.vault_quote_account.amount,
+        )
+    } else {
+        (
+            accounts.vault_quote_account.amount,
+            accounts.vault_underlying_account.amount,
+        )
+    };
+    let supply = accounts.share_mint.supply;
+
+    // What arrives (net of any transfer fee) buys the shares
+    let token_program = accounts.token_program.to_account_info();
+    let user = accounts.user.to_account_info();
+    let received = if underlying {
+        deposit_received(
+            &token_program,
+            &accounts.user_underlying_account.to_account_info(),
+            &accounts.underlying_mint,
+            &mut accounts.vault_underlying_account,
+            &user,
+            amount,
+        )?
+    } else {
+        deposit_received(
+            &token_program,
+            &accounts.user_quote_account.to_account_info(),
+            &accounts.quote_mint,
+            &mut accounts.vault_quote_account,
+            &user,
+            amount,
+        )?
+    };
+    let shares = OptionVault::shares_for_deposit(received, escrow_balance, supply)?;
+    require!(shares > 0, ErrorCode::InvalidAmount);
+
+    let matching = OptionVault::matching_deposit(received, escrow_balance, other_balance)?;
+    if matching > 0 {
+        if underlying {
+            deposit_received(
+                &token_program,
+                &accounts.user_quote_account.to_account_info(),
+                &accounts.quote_mint,
+                &mut accounts.vault_quote_account,
+                &user,
+                matching,
+            )?;
+        } else {
+            deposit_received(
+                &token_program,
+                &accounts.user_underlying_account.to_account_info(),
+                &accounts.underlying_mint,
+                &mut accounts.vault_underlying_account,
+                &user,
+                matching,
+            )?;
+        }
+    }
+
+    accounts.mint_shares(shares)?;
+
+    let (underlying_in, quote_in) = if underlying {
+        (amount, matching)
+    } else {
+        (matching, amount)
+    };
+    emit!(VaultSharesChanged {
+        option_vault: accounts.option_vault.key(),
+        user: accounts.user.key(),
+        deposit: true,
+        shares,
+        underlying: underlying_in,
+        quote: quote_in,
+    });
+
+    msg!(
+        "{} deposited into vault for {} shares",
+        accounts.user.key(),
+        shares
+    );
+
+    Ok(())
+}
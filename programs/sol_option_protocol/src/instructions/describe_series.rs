@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::OptionData;
+use crate::utils::symbol::series_symbol;
+
+/// The series to describe (read-only)
+#[derive(Accounts)]
+pub struct DescribeSeries<'info> {
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Returns the series' canonical descriptor (see `series_symbol`) through
+/// return data, the same string its token metadata is named after
+pub fn handler(ctx: Context<DescribeSeries>) -> Result<String> {
+    let symbol = series_symbol(&ctx.accounts.option_context);
+    msg!("Series {}: {}", ctx.accounts.option_context.key(), symbol);
+    Ok(symbol)
+}
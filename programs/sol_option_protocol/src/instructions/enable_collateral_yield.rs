@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::CollateralYieldEnabled;
+use crate::instructions::OptionData;
+use crate::state::YieldVenue;
+
+/// Series authority opting the series into lending its escrow
+#[derive(Accounts)]
+pub struct EnableCollateralYield<'info> {
+    /// Pays for the share vault
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Approved venue of the series' escrow mint
+    #[account(
+        constraint = yield_venue.escrow_mint == option_context.margin_mint()
+            @ ErrorCode::InvalidYieldVenue,
+        constraint = yield_venue.enabled @ ErrorCode::YieldVenueDisabled
+    )]
+    pub yield_venue: Account<'info, YieldVenue>,
+
+    #[account(
+        constraint = share_mint.key() == yield_venue.share_mint @ ErrorCode::InvalidYieldVenue
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    /// Series-owned account holding the venue's shares
+    #[account(
+        init,
+        payer = authority,
+        seeds = [YieldVenue::SHARE_VAULT_SEED, option_context.key().as_ref()],
+        bump,
+        token::mint = share_mint,
+        token::authority = option_context,
+        token::token_program = token_program
+    )]
+    pub share_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the venue on the series. Writers take on the reserve's risk, so
+/// a series opts in before anything is escrowed, and never opts out: its
+/// authority may simply stop lending, and anyone can unwind what was lent.
+pub fn handler(ctx: Context<EnableCollateralYield>) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+    require_keys_eq!(
+        option_context.yield_venue,
+        Pubkey::default(),
+        ErrorCode::YieldOptInTooLate
+    );
+    require!(
+        option_context.escrow_balance() == 0 && option_context.margin_collateral == 0,
+        ErrorCode::YieldOptInTooLate
+    );
+
+    option_context.yield_venue = ctx.accounts.yield_venue.key();
+
+    emit!(CollateralYieldEnabled {
+        option_context: option_context.key(),
+        yield_venue: option_context.yield_venue,
+    });
+
+    msg!(
+        "Series {} lends through {}",
+        option_context.key(),
+        option_context.yield_venue
+    );
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawalExecuted;
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::{split_withdrawal, EmergencyWithdrawal, ProtocolConfig, WithdrawalVault};
+
+/// Admin executing a queued emergency withdrawal after its timelock
+#[derive(Accounts)]
+pub struct ExecuteEmergencyWithdrawal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [EmergencyWithdrawal::SEED, vault.key().as_ref()],
+        bump = withdrawal.bump,
+        has_one = option_context @ ErrorCode::InvalidWithdrawalVault,
+        has_one = vault @ ErrorCode::InvalidWithdrawalVault,
+        has_one = destination @ ErrorCode::InvalidWithdrawalVault
+    )]
+    pub withdrawal: Account<'info, EmergencyWithdrawal>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(mut)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = mint.key() == vault.mint @ ErrorCode::InvalidWithdrawalVault)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Moves the queued amount out of the vault. Supply and exercise counters are
+/// left as-is (the withdrawal is an out-of-band recovery, recorded by its event);
+/// the vault's ledger bucket (see `WithdrawalVault`) drops by the amount
+/// withdrawn, donations first. Basket and yield share withdrawals also debit
+/// the escrow they carry, so the primary vault's expected balance is unchanged.
+pub fn handler(ctx: Context<ExecuteEmergencyWithdrawal>) -> Result<()> {
+    let withdrawal = &ctx.accounts.withdrawal;
+    require!(
+        Clock::get()?.unix_timestamp >= withdrawal.executable_at,
+        ErrorCode::TimelockNotElapsed
+    );
+
+    let option_context = &ctx.accounts.option_context;
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_mint_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_mint_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: option_context.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        withdrawal.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Untracked tokens (donations) leave first, then the tracked bucket, then
+    // margin held in the vault (`vault` isn't reloaded, so `amount` is still the
+    // pre-transfer balance)
+    let amount = withdrawal.amount;
+    let vault_key = ctx.accounts.vault.key();
+    let held = ctx.accounts.vault.amount;
+    let series_key = ctx.accounts.option_context.key();
+    let option_context = &mut ctx.accounts.option_context;
+    let kind = WithdrawalVault::of(
+        option_context,
+        &series_key,
+        &vault_key,
+        &ctx.accounts.vault.mint,
+    )
+    .ok_or(ErrorCode::InvalidWithdrawalVault)?;
+    match kind {
+        WithdrawalVault::Collateral | WithdrawalVault::Consideration => {
+            let is_collateral = kind == WithdrawalVault::Collateral;
+            let tracked = if is_collateral {
+                option_context.collateral_balance()
+            } else {
+                option_context.consideration_balance()
+            };
+            let margin = if vault_key == option_context.margin_vault() {
+                option_context.margin_collateral
+            } else {
+                0
+            };
+            let (from_tracked, from_margin) = split_withdrawal(amount, held, tracked, margin);
+            if is_collateral {
+                option_context.debit_collateral(from_tracked)?;
+            } else {
+                option_context.debit_consideration(from_tracked)?;
+            }
+            option_context.margin_collateral -= from_margin;
+        }
+        WithdrawalVault::Basket(slot) => {
+            let tracked = option_context.basket_escrow[slot];
+            let (from_tracked, _) = split_withdrawal(amount, held, tracked, 0);
+            option_context.basket_escrow[slot] -= from_tracked;
+            option_context.debit_escrow(from_tracked)?;
+        }
+        WithdrawalVault::YieldShares => {
+            // The shares withdrawn take their pro-rata part of the principal
+            // with them, as if unwound for nothing
+            let shares = option_context.yield_shares;
+            let (from_shares, _) = split_withdrawal(amount, held, shares, 0);
+            let principal = if from_shares == shares {
+                option_context.yield_principal
+            } else {
+                u64::try_from(
+                    option_context.yield_principal as u128 * from_shares as u128 / shares as u128,
+                )
+                .map_err(|_| error!(ErrorCode::MathOverflow))?
+            };
+            option_context.yield_shares -= from_shares;
+            option_context.yield_principal -= principal;
+            option_context.debit_escrow(principal)?;
+        }
+    }
+
+    let withdrawal = &ctx.accounts.withdrawal;
+    emit!(EmergencyWithdrawalExecuted {
+        withdrawal: withdrawal.key(),
+        vault: withdrawal.vault,
+        destination: withdrawal.destination,
+        amount: withdrawal.amount,
+    });
+
+    msg!(
+        "Emergency withdrawal of {} from {} executed",
+        withdrawal.amount,
+        withdrawal.vault
+    );
+
+    // Only a primary vault is checked: the withdrawal may knowingly leave
+    // outstanding options uncovered, which is what the mints would be checked
+    // against. Basket and share vaults aren't in the vault ledger the check
+    // reads, so those withdrawals check the counters alone
+    let vault = Some(ctx.accounts.vault.to_account_info());
+    let option_context = &ctx.accounts.option_context;
+    let accounts = match kind {
+        WithdrawalVault::Collateral => SeriesAccounts {
+            collateral_vault: vault,
+            ..SeriesAccounts::default()
+        },
+        WithdrawalVault::Consideration => SeriesAccounts {
+            consideration_vault: vault,
+            ..SeriesAccounts::default()
+        },
+        WithdrawalVault::Basket(_) | WithdrawalVault::YieldShares => SeriesAccounts::default(),
+    };
+    check_series(option_context, accounts)
+}
@@ -1,69 +1,178 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount, TokenInterface};
 
-use crate::instructions::OptionContext;
 use crate::errors::ErrorCode;
+use crate::events::OptionsExercised;
+use crate::instructions::basket_payout::pay_basket_shares;
+use crate::instructions::protocol_fee::{charge_fee, fee_due, FeeAccounts};
+use crate::instructions::vault_deposit::deposit_received;
+use crate::instructions::{OptionContext, OptionData};
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats};
 use crate::utils::{
     math::calculate_strike_payment,
-    validation::{validate_amount, validate_vault_balance},
+    validation::{
+        credential_state, validate_amount, validate_credential, validate_exercise_allowed,
+        validate_lot_multiple, validate_not_paused, validate_not_settled,
+        validate_protocol_not_paused, validate_vault_balance,
+    },
 };
 
-/// Exercises American call options by paying strike price to receive collateral
-/// User burns option tokens + pays strike → receives collateral
-pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+/// Exercises options (American: any time, European: only during the exercise window)
+/// Call: user burns option tokens + pays strike → receives collateral
+/// Put: user burns option tokens + delivers collateral → receives strike
+/// Perpetual series also charge the funding accrued so far (calls pay it on top
+/// of the strike, puts receive the strike less it); it goes to the writers
+/// The protocol's exercise fee is charged on the strike, in consideration
+/// (discounted by the user's volume tier, waived for exempt accounts)
+/// Writers are credited what the holder's payment actually left in the vault,
+/// net of any transfer fee the mint withholds
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    exercise(
+        ctx.accounts.exercise_parts(),
+        ctx.remaining_accounts,
+        amount,
+        |_| Ok(()),
+    )
+}
+
+/// Accounts `exercise` works on, borrowed from `OptionContext` or `ExerciseAccounts`
+pub struct ExerciseParts<'a, 'info> {
+    pub user: &'a Signer<'info>,
+    pub option_context: &'a mut Account<'info, OptionData>,
+    pub protocol_config: &'a Account<'info, ProtocolConfig>,
+    pub collateral_mint: &'a InterfaceAccount<'info, Mint>,
+    pub consideration_mint: &'a InterfaceAccount<'info, Mint>,
+    pub option_mint: &'a InterfaceAccount<'info, Mint>,
+    pub collateral_vault: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub consideration_vault: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub user_collateral_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_consideration_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_option_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub fee_vault: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub fee_stats: &'a mut Option<Account<'info, FeeStats>>,
+    pub referrer_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub referral_stats: &'a mut Option<Account<'info, ReferralStats>>,
+    pub insurance_fund: &'a mut Option<Account<'info, InsuranceFund>>,
+    pub insurance_vault: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub credential_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> OptionContext<'info> {
+    pub(crate) fn exercise_parts(&mut self) -> ExerciseParts<'_, 'info> {
+        ExerciseParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            protocol_config: &self.protocol_config,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            option_mint: &self.option_mint,
+            collateral_vault: &mut self.collateral_vault,
+            consideration_vault: &mut self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_option_account: &self.user_option_account,
+            token_program: &self.token_program,
+            fee_vault: &self.fee_vault,
+            fee_stats: &mut self.fee_stats,
+            referrer_account: &self.referrer_account,
+            referral_stats: &mut self.referral_stats,
+            insurance_fund: &mut self.insurance_fund,
+            insurance_vault: &self.insurance_vault,
+            credential_account: &self.credential_account,
+        }
+    }
+}
+
+/// `exercise`, running `before_strike` on calls once the collateral has been
+/// paid out and before the strike is collected (e.g. to sell the collateral
+/// for the strike)
+pub(crate) fn exercise<'info>(
+    accounts: ExerciseParts<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    before_strike: impl FnOnce(&ExerciseParts<'_, 'info>) -> Result<()>,
+) -> Result<()> {
     // Validation
     validate_amount(amount)?;
-    validate_vault_balance(ctx.accounts.collateral_vault.amount, amount)?;
+    validate_lot_multiple(accounts.option_context, amount)?;
+    validate_protocol_not_paused(accounts.protocol_config)?;
+    validate_not_paused(accounts.option_context)?;
+    validate_exercise_allowed(accounts.option_context)?;
+    validate_not_settled(accounts.option_context)?;
+    validate_credential(
+        accounts.option_context,
+        &accounts.user.key(),
+        credential_state(accounts.credential_account),
+    )?;
+
+    accounts
+        .option_context
+        .accrue_funding(Clock::get()?.unix_timestamp)?;
 
-    let option_context = &ctx.accounts.option_context;
+    let option_context = &accounts.option_context;
+    let underlying = option_context.underlying_amount(amount)?;
 
     // Get mint decimals
-    let collateral_decimals = ctx.accounts.collateral_mint.decimals;
-    let strike_decimals = ctx.accounts.consideration_mint.decimals;
+    let collateral_decimals = accounts.collateral_mint.decimals;
+    let consideration_decimals = accounts.consideration_mint.decimals;
 
-    // Calculate required strike payment
-    // Formula: (amount × strike_price) / 10^collateral_decimals
+    // Calculate strike value of the exercised options
+    // Formula: (underlying × strike_price) / 10^strike_scale
     // Example: 100 BONK × $0.04 = $4 USDC
-    let strike_payment = calculate_strike_payment(
-        amount,
-        option_context.strike_price,
-        collateral_decimals,
-    )?;
+    // Calls pay it in (rounded up), puts take it out of the escrow (rounded down)
+    let strike_payment = if option_context.is_put() {
+        option_context.escrow_payout(underlying)?
+    } else {
+        calculate_strike_payment(
+            underlying,
+            option_context.strike_price,
+            option_context.strike_scale,
+        )?
+    };
+
+    // Funding owed by the exercised options (a put can't owe more than its strike)
+    let funding = if option_context.is_put() {
+        option_context.funding_owed(underlying)?.min(strike_payment)
+    } else {
+        option_context.funding_owed(underlying)?
+    };
+
+    // Calls pay the collateral out of escrow, puts the strike payment
+    let escrow_payout = option_context.escrow_payout(underlying)?;
+    validate_vault_balance(option_context.escrow_balance(), escrow_payout)?;
+
+    // What leaves the escrow: substitutes in the series' basket pay their pro-rata part
+    let (escrow_paid, escrow_decimals) = if option_context.is_put() {
+        (strike_payment - funding, consideration_decimals)
+    } else {
+        (underlying, collateral_decimals)
+    };
+    let (primary_paid, basket_shares) = option_context.split_escrow_payout(escrow_paid)?;
 
     // 1. Burn option tokens from user (destroys the right to exercise)
     token::burn(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::Burn {
-                mint: ctx.accounts.option_mint.to_account_info(),
-                from: ctx.accounts.user_option_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+                mint: accounts.option_mint.to_account_info(),
+                from: accounts.user_option_account.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         ),
         amount,
     )?;
 
-    // 2. Transfer strike payment from user to consideration vault
-    token::transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::TransferChecked {
-                from: ctx.accounts.user_consideration_account.to_account_info(),
-                mint: ctx.accounts.consideration_mint.to_account_info(),
-                to: ctx.accounts.consideration_vault.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
-        strike_payment,
-        strike_decimals,
-    )?;
-
-    // 3. Transfer collateral from vault to user (OptionContext PDA signs)
+    // OptionContext PDA signs vault transfers
     let collateral_mint_key = option_context.collateral_mint;
     let consideration_mint_key = option_context.consideration_mint;
     let strike_price_bytes = option_context.strike_price.to_le_bytes();
     let expiration_bytes = option_context.expiration.to_le_bytes();
-    let is_put_byte = [option_context.is_put as u8];
+    let option_type_byte = [option_context.option_type as u8];
     let bump = option_context.bump;
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -72,38 +181,161 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
         consideration_mint_key.as_ref(),
         strike_price_bytes.as_ref(),
         expiration_bytes.as_ref(),
-        &is_put_byte,
+        &option_type_byte,
         &[bump],
     ]];
 
-    token::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::TransferChecked {
-                from: ctx.accounts.collateral_vault.to_account_info(),
-                mint: ctx.accounts.collateral_mint.to_account_info(),
-                to: ctx.accounts.user_collateral_account.to_account_info(),
-                authority: option_context.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        amount,
-        collateral_decimals,
+    // What the holder's delivery actually left in the vault (less than sent
+    // when the mint withholds a transfer fee)
+    let delivered = if option_context.is_put() {
+        // 2. Put: user delivers collateral to the collateral vault
+        let delivered = deposit_received(
+            &accounts.token_program.to_account_info(),
+            &accounts.user_collateral_account.to_account_info(),
+            accounts.collateral_mint,
+            accounts.collateral_vault,
+            &accounts.user.to_account_info(),
+            underlying,
+        )?;
+
+        // 3. Put: strike (less funding) paid out of the consideration vault writers funded
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: accounts.consideration_vault.to_account_info(),
+                    mint: accounts.consideration_mint.to_account_info(),
+                    to: accounts.user_consideration_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            primary_paid,
+            consideration_decimals,
+        )?;
+        delivered
+    } else {
+        // 2. Call: transfer collateral from vault to user
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: accounts.collateral_vault.to_account_info(),
+                    mint: accounts.collateral_mint.to_account_info(),
+                    to: accounts.user_collateral_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            primary_paid,
+            collateral_decimals,
+        )?;
+
+        before_strike(&accounts)?;
+
+        // 3. Call: transfer strike payment (plus funding) from user to consideration vault
+        deposit_received(
+            &accounts.token_program.to_account_info(),
+            &accounts.user_consideration_account.to_account_info(),
+            accounts.consideration_mint,
+            accounts.consideration_vault,
+            &accounts.user.to_account_info(),
+            strike_payment
+                .checked_add(funding)
+                .ok_or(ErrorCode::MathOverflow)?,
+        )?
+    };
+    pay_basket_shares(
+        accounts.option_context,
+        &accounts.token_program.to_account_info(),
+        remaining_accounts,
+        &basket_shares,
+        escrow_decimals,
+        signer_seeds,
     )?;
 
-    // 4. Update exercised amount (OptionContext bookkeeping)
-    let option_context = &mut ctx.accounts.option_context;
+    // Protocol fee on the strike, paid in consideration on top of it (puts pay
+    // it out of the strike they just received)
+    let fee = fee_due(
+        accounts.protocol_config,
+        &accounts.user.key(),
+        strike_payment,
+        accounts.protocol_config.exercise_fee_bps,
+        accounts.fee_stats.as_deref(),
+        consideration_decimals,
+    )?;
+    charge_fee(
+        accounts.option_context.key(),
+        FeeAccounts {
+            token_program: accounts.token_program.to_account_info(),
+            from: accounts.user_consideration_account.to_account_info(),
+            payer: accounts.user.to_account_info(),
+            mint: accounts.consideration_mint,
+            fee_vault: accounts.fee_vault.as_deref(),
+            referrer_account: accounts.referrer_account.as_deref(),
+            referral_stats: accounts.referral_stats.as_mut(),
+            insurance_fund: accounts.insurance_fund.as_mut(),
+            insurance_vault: accounts.insurance_vault.as_deref(),
+        },
+        fee,
+        accounts.protocol_config.referral_share_bps,
+        accounts.protocol_config.insurance_share_bps,
+    )?;
+
+    // 4. Update exercised amount and vault accounting (OptionContext bookkeeping);
+    // writers are credited what was delivered, and a transfer fee comes out of
+    // the strike before the funding
+    let option_context = &mut *accounts.option_context;
+    let funding = if option_context.is_put() {
+        option_context.credit_collateral(delivered)?;
+        option_context.debit_consideration(strike_payment - funding)?;
+        funding
+    } else {
+        option_context.credit_consideration(delivered)?;
+        option_context.debit_collateral(underlying)?;
+        funding.min(delivered)
+    };
+    option_context.funding_collected = option_context
+        .funding_collected
+        .checked_add(funding)
+        .ok_or(ErrorCode::MathOverflow)?;
     option_context.exercised_amount = option_context
         .exercised_amount
         .checked_add(amount)
         .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(stats) = accounts.fee_stats.as_mut() {
+        stats.record(strike_payment);
+    }
+
+    emit!(OptionsExercised {
+        option_context: option_context.key(),
+        user: accounts.user.key(),
+        amount,
+        strike_payment,
+        funding,
+        exercised_amount: option_context.exercised_amount,
+        option_supply: accounts
+            .option_mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?,
+    });
 
     msg!(
-        "Exercised {} options. Strike payment: {}. Total exercised: {}",
+        "Exercised {} options. Strike payment: {}. Funding: {}. Total exercised: {}",
         amount,
         strike_payment,
+        funding,
         option_context.exercised_amount
     );
 
-    Ok(())
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(accounts.consideration_vault.to_account_info()),
+            option_mint: Some(accounts.option_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
 }
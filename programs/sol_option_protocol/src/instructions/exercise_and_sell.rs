@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use spl_marketplace::cpi::accounts::FillOrder;
+use spl_marketplace::program::SplMarketplace;
+
+use crate::errors::ErrorCode;
+use crate::instructions::exercise::exercise;
+use crate::instructions::OptionType;
+// The generated client modules of OptionContext come along for the nesting
+use crate::instructions::option::*;
+
+/// `exercise` of calls that sells the collateral into a marketplace bid
+#[derive(Accounts)]
+pub struct ExerciseAndSell<'info> {
+    pub option: OptionContext<'info>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: Market of the bid (collateral as base, consideration as quote);
+    /// the marketplace checks the bid belongs to it
+    pub market: UncheckedAccount<'info>,
+
+    /// CHECK: Resting bid the collateral is sold into; validated by the marketplace
+    #[account(mut)]
+    pub bid: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA of the bid (the consideration it pays with)
+    #[account(mut)]
+    pub bid_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Bidder's collateral account receiving what is sold; the token
+    /// program checks its mint
+    #[account(mut)]
+    pub bidder_collateral_account: UncheckedAccount<'info>,
+}
+
+/// Flash exercise: burns `amount` call options, pays their collateral out to
+/// the user, sells `sell_amount` of it into `bid`, then collects the strike
+/// (plus funding and fee) from the user's consideration account. The sale's
+/// proceeds land there first, so holders capture the intrinsic value without
+/// funding the strike; the whole instruction fails when the bid doesn't cover
+/// what the user can't pay. Unsold collateral stays with the user.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExerciseAndSell<'info>>,
+    amount: u64,
+    sell_amount: u64,
+) -> Result<()> {
+    let ExerciseAndSell {
+        option,
+        marketplace_program,
+        market,
+        bid,
+        bid_escrow,
+        bidder_collateral_account,
+    } = ctx.accounts;
+    require!(
+        option.option_context.option_type == OptionType::Call,
+        ErrorCode::FlashExerciseNeedsCall
+    );
+
+    exercise(
+        option.exercise_parts(),
+        ctx.remaining_accounts,
+        amount,
+        |option| {
+            spl_marketplace::cpi::fill_order(
+                CpiContext::new(
+                    marketplace_program.to_account_info(),
+                    FillOrder {
+                        taker: option.user.to_account_info(),
+                        market: market.to_account_info(),
+                        maker_order: bid.to_account_info(),
+                        base_mint: option.collateral_mint.to_account_info(),
+                        quote_mint: option.consideration_mint.to_account_info(),
+                        maker_escrow: bid_escrow.to_account_info(),
+                        taker_base_account: option.user_collateral_account.to_account_info(),
+                        taker_quote_account: option.user_consideration_account.to_account_info(),
+                        maker_receive_account: bidder_collateral_account.to_account_info(),
+                        token_program: option.token_program.to_account_info(),
+                        linked_series: None,
+                    },
+                ),
+                sell_amount,
+            )
+        },
+    )?;
+
+    msg!(
+        "Sold {} collateral of {} exercised options",
+        sell_amount,
+        amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::exercise::{exercise, ExerciseParts};
+use crate::instructions::OptionData;
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats};
+
+/// `exercise` without the short leg, the leg owners, the writer position or
+/// associated account creation: the user's token accounts must already exist
+#[derive(Accounts)]
+pub struct ExerciseAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(constraint = collateral_mint.key() == option_context.collateral_mint)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(constraint = consideration_mint.key() == option_context.consideration_mint)]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = option_mint.key() == option_context.option_mint)]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = collateral_vault.key() == option_context.collateral_vault)]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = collateral_mint, token::authority = user)]
+    pub user_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = consideration_mint, token::authority = user)]
+    pub user_consideration_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = option_mint, token::authority = user)]
+    pub user_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Same optional fee and credential accounts as `OptionContext`
+    #[account(
+        mut,
+        constraint = fee_vault.owner == protocol_config.key() @ ErrorCode::InvalidFeeVault
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [FeeStats::SEED, user.key().as_ref(), consideration_mint.key().as_ref()],
+        bump = fee_stats.bump
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    #[account(mut)]
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+
+    #[account(mut)]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(mut)]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> ExerciseAccounts<'info> {
+    fn parts(&mut self) -> ExerciseParts<'_, 'info> {
+        ExerciseParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            protocol_config: &self.protocol_config,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            option_mint: &self.option_mint,
+            collateral_vault: &mut self.collateral_vault,
+            consideration_vault: &mut self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_option_account: &self.user_option_account,
+            token_program: &self.token_program,
+            fee_vault: &self.fee_vault,
+            fee_stats: &mut self.fee_stats,
+            referrer_account: &self.referrer_account,
+            referral_stats: &mut self.referral_stats,
+            insurance_fund: &mut self.insurance_fund,
+            insurance_vault: &self.insurance_vault,
+            credential_account: &self.credential_account,
+        }
+    }
+}
+
+/// Same payments, fee and bookkeeping as `exercise`
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExerciseAccounts<'info>>,
+    amount: u64,
+) -> Result<()> {
+    exercise(ctx.accounts.parts(), ctx.remaining_accounts, amount, |_| {
+        Ok(())
+    })
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::native_sol::NativeAccount;
+use crate::instructions::{exercise, OptionContext};
+
+/// `exercise` settled in native SOL: wraps up to `lamports` for what the
+/// holder delivers when that side is WSOL, exercises `amount`, and pays the
+/// unused lamports and any WSOL received back as lamports
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+    lamports: u64,
+) -> Result<()> {
+    let native = NativeAccount::wrap(ctx.accounts, lamports)?;
+    exercise::handler(ctx, amount)?;
+    native.unwrap()
+}
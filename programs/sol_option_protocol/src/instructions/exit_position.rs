@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::{
+    burn_paired, claim_net_payoff, redeem, redeem_consideration, OptionContext,
+};
+
+/// Unwinds everything the user holds in a series in one transaction
+/// Before settlement:
+/// 1. Burns matched option + redemption tokens for the locked obligation (burn_paired)
+/// 2. Converts leftover redemption tokens into unclaimed exercise proceeds
+///    (redeem_consideration), as far as exercised units allow
+///
+/// After settlement:
+/// 1. Claims the netted payoff of held option tokens (claim_net_payoff)
+/// 2. Redeems held redemption tokens once redemption is open (redeem)
+///
+/// Positions that can't be unwound yet (lone option tokens before expiry,
+/// unexercised redemption tokens) are left in the wallet
+pub fn handler<'info>(mut ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>) -> Result<()> {
+    let mut unwound = false;
+
+    if !ctx.accounts.option_context.is_settled() {
+        // 1. Paired burn of the matched part of both legs
+        let paired = ctx
+            .accounts
+            .user_option_account
+            .amount
+            .min(ctx.accounts.user_redemption_account.amount);
+        if paired > 0 {
+            burn_paired::handler(reborrow(&mut ctx), paired)?;
+            ctx.accounts.user_option_account.reload()?;
+            ctx.accounts.user_redemption_account.reload()?;
+            unwound = true;
+        }
+
+        // 2. Remaining shorts convert exercised units into proceeds
+        let option_context = &ctx.accounts.option_context;
+        let unclaimed = option_context
+            .exercised_amount
+            .checked_sub(option_context.exercised_claimed)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let convertible = ctx.accounts.user_redemption_account.amount.min(unclaimed);
+        if convertible > 0 {
+            redeem_consideration::handler(reborrow(&mut ctx), convertible)?;
+            unwound = true;
+        }
+    } else {
+        // 1. Option tokens collect their netted payoff (nothing if settled OTM)
+        let options = ctx.accounts.user_option_account.amount;
+        if options > 0 {
+            claim_net_payoff::handler(reborrow(&mut ctx), options)?;
+            unwound = true;
+        }
+
+        // 2. Redemption tokens take their share of the snapshot once redeem opens
+        let shorts = ctx.accounts.user_redemption_account.amount;
+        let redemption_open =
+            Clock::get()?.unix_timestamp >= ctx.accounts.option_context.redemption_opens_at()?;
+        if shorts > 0 && redemption_open {
+            redeem::handler(reborrow(&mut ctx), shorts)?;
+            unwound = true;
+        }
+    }
+
+    require!(unwound, ErrorCode::NothingToExit);
+    Ok(())
+}
+
+/// Hands the same accounts to another handler, keeping `ctx` usable afterwards
+/// (OptionContext derives no PDAs, so there are no bumps to carry over)
+fn reborrow<'a, 'c, 'info>(
+    ctx: &'a mut Context<'_, '_, 'c, 'info, OptionContext<'info>>,
+) -> Context<'a, 'a, 'c, 'info, OptionContext<'info>> {
+    Context::new(
+        ctx.program_id,
+        ctx.accounts,
+        ctx.remaining_accounts,
+        Default::default(),
+    )
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesFrozen;
+use crate::instructions::OptionData;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_not_settled;
+
+/// Admin or guardian freezing a compromised series (e.g. an exploited mint)
+#[derive(Accounts)]
+pub struct FreezeSeries<'info> {
+    #[account(
+        constraint = guardian.key() == protocol_config.guardian
+            || guardian.key() == protocol_config.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub guardian: Signer<'info>,
+
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Marks the series frozen for good: minting and exercise stop, and like a
+/// knock-out it can settle right away with every option worthless, after
+/// which redemption holders redeem the vaults pro rata without waiting for
+/// expiry or the settlement grace period
+/// Forwards can't be frozen, as they only settle by delivery
+pub fn handler(ctx: Context<FreezeSeries>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+
+    // Validation
+    require!(!option_context.is_frozen(), ErrorCode::SeriesFrozen);
+    require!(
+        !option_context.is_forward(),
+        ErrorCode::ForwardSettlementOnly
+    );
+    validate_not_settled(option_context)?;
+
+    // Freeze the series (OptionContext bookkeeping)
+    let now = Clock::get()?.unix_timestamp;
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.status |= OptionData::STATUS_FROZEN;
+    option_context.frozen_at = now;
+
+    emit!(SeriesFrozen {
+        option_context: option_context.key(),
+        frozen_at: now,
+        frozen_by: ctx.accounts.guardian.key(),
+    });
+
+    msg!("Series {} frozen at {}", option_context.key(), now);
+
+    Ok(())
+}
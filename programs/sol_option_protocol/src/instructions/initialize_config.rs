@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::program::SolOptionProtocol;
+use crate::state::{FeeTier, ProtocolConfig};
+
+/// One-time creation of the ProtocolConfig singleton.
+/// Only the program's upgrade authority may initialize it.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [ProtocolConfig::SEED],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::Unauthorized
+    )]
+    pub program: Program<'info, SolOptionProtocol>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = Pubkey::default();
+    config.bump = ctx.bumps.protocol_config;
+
+    // Rate limits start disabled
+    config.max_series_per_epoch = 0;
+    config.creation_cooldown = 0;
+
+    config.guardian = Pubkey::default();
+    config.treasury = Pubkey::default();
+
+    // Unclaimed-funds recovery starts disabled
+    config.unclaimed_timeout = 0;
+
+    // Series creation starts unbonded
+    config.creation_bond = 0;
+
+    // Expirations start unrestricted
+    config.expiry_grid_interval = 0;
+    config.expiry_grid_offset = 0;
+    config.expiry_grid_exempt = [Pubkey::default(); ProtocolConfig::MAX_EXPIRY_GRID_EXEMPT];
+    config.min_time_to_expiry = 0;
+    config.max_time_to_expiry = 0;
+
+    // No fees, every feature on
+    config.mint_fee_bps = 0;
+    config.exercise_fee_bps = 0;
+    config.disabled_features = 0;
+
+    // Everyone pays the full fee
+    config.fee_exempt = [Pubkey::default(); ProtocolConfig::MAX_FEE_EXEMPT];
+    config.fee_tiers = [FeeTier::default(); ProtocolConfig::MAX_FEE_TIERS];
+    config.referral_share_bps = 0;
+
+    config.paused = false;
+
+    // Any mint may back a series
+    config.mint_allowlist_enabled = false;
+
+    // Parameter changes start untimelocked
+    config.timelock_delay = 0;
+
+    // Fees go entirely to the fee accounts until the admin funds insurance
+    config.insurance_share_bps = 0;
+
+    msg!("Protocol config initialized. Admin: {}", config.admin);
+
+    Ok(())
+}
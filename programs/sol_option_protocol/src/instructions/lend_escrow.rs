@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::EscrowLent;
+use crate::instructions::YieldContext;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::utils::validation::{validate_amount, validate_not_settled, validate_vault_balance};
+
+/// Series authority lends `amount` of idle escrow to the series' venue. Only
+/// accounted escrow still in the vault can go (never margin); settled series
+/// are paying out and lend nothing.
+pub fn handler(ctx: Context<YieldContext>, amount: u64) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.user.key(),
+        ctx.accounts.option_context.authority,
+        ErrorCode::Unauthorized
+    );
+    require!(
+        ctx.accounts.yield_venue.enabled,
+        ErrorCode::YieldVenueDisabled
+    );
+    validate_amount(amount)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+
+    let option_context = &ctx.accounts.option_context;
+    let idle = option_context
+        .escrow_balance()
+        .saturating_sub(option_context.yield_principal)
+        .min(ctx.accounts.escrow_vault.amount);
+    validate_vault_balance(idle, amount)?;
+
+    let shares = ctx.accounts.lend(amount)?;
+
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.yield_principal = option_context
+        .yield_principal
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    option_context.yield_shares = option_context
+        .yield_shares
+        .checked_add(shares)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(EscrowLent {
+        option_context: option_context.key(),
+        amount,
+        shares,
+    });
+
+    msg!(
+        "Series {} lent {} escrow for {} shares",
+        option_context.key(),
+        amount,
+        shares
+    );
+
+    check_series(
+        option_context,
+        SeriesAccounts::escrow_vault(option_context, ctx.accounts.escrow_vault.to_account_info()),
+    )
+}
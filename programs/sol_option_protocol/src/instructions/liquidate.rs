@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::MarginLiquidated;
+use crate::instructions::MarginContext;
+use crate::invariants::check_series;
+use crate::oracle::guard::BPS_DENOMINATOR;
+use crate::utils::{
+    margin::liquidation_seizure,
+    validation::{validate_amount, validate_not_settled},
+};
+
+/// Keeper liquidation of a margin position below its maintenance requirement
+///
+/// The keeper burns `amount` option tokens against the position's short and
+/// seizes the matching pro-rata share of its margin. The rest of the position
+/// keeps its health, so liquidating never leaves debt behind; a keeper that
+/// would rather rescue the position tops it up with `deposit_margin` instead.
+pub fn handler(ctx: Context<MarginContext>, amount: u64) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+
+    // Only positions under maintenance margin at the live price
+    let price = ctx.accounts.live_price()?;
+    let health = ctx.accounts.record_health(price)?;
+    require!(health < BPS_DENOMINATOR, ErrorCode::PositionHealthy);
+
+    let position = &ctx.accounts.writer_position;
+    require!(
+        amount <= position.margin_written,
+        ErrorCode::ExceedsMarginWritten
+    );
+    let seized = liquidation_seizure(position.margin_collateral, amount, position.margin_written)?;
+
+    // 1. Burn the keeper's options against the position's short
+    ctx.accounts.record_margin_buyback(amount)?;
+    ctx.accounts.burn_options(amount)?;
+
+    // 2. Pay the keeper the seized margin
+    ctx.accounts.debit_margin(seized)?;
+    if seized > 0 {
+        ctx.accounts.release(seized)?;
+    }
+
+    let health_after = if ctx.accounts.writer_position.margin_written > 0 {
+        ctx.accounts.record_health(price)?
+    } else {
+        ctx.accounts.writer_position.health_bps = u64::MAX;
+        u64::MAX
+    };
+
+    emit!(MarginLiquidated {
+        option_context: ctx.accounts.option_context.key(),
+        writer_position: ctx.accounts.writer_position.key(),
+        writer: ctx.accounts.writer_position.writer,
+        liquidator: ctx.accounts.user.key(),
+        amount,
+        seized,
+        price,
+        health_bps: health,
+    });
+
+    msg!(
+        "Liquidated {} options of {} in series {}: seized {} (health {} -> {} bps)",
+        amount,
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.option_context.key(),
+        seized,
+        health,
+        health_after
+    );
+
+    check_series(&ctx.accounts.option_context, ctx.accounts.series_accounts())
+}
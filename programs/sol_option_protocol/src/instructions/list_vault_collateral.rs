@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use spl_marketplace::cpi::accounts::PlaceOrder;
+use spl_marketplace::program::SplMarketplace;
+
+use crate::errors::ErrorCode;
+use crate::events::VaultListingOpened;
+use crate::instructions::OptionType;
+use crate::state::OptionVault;
+
+/// Manager re-listing a put vault's assigned underlying between rounds
+#[derive(Accounts)]
+pub struct ListVaultCollateral<'info> {
+    pub manager: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = manager @ ErrorCode::Unauthorized,
+        has_one = underlying_mint,
+        constraint = option_vault.option_type == OptionType::Put @ ErrorCode::NotAPutVault,
+        constraint = option_vault.is_idle() @ ErrorCode::VaultRoundActive,
+        constraint = !option_vault.listing_open() @ ErrorCode::VaultListingOpen
+    )]
+    pub option_vault: Box<Account<'info, OptionVault>>,
+
+    /// CHECK: System-owned PDA owning the listing and paying its accounts' rent
+    #[account(
+        mut,
+        seeds = [OptionVault::AUTHORITY_SEED, option_vault.key().as_ref()],
+        bump = option_vault.authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_underlying_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: Market of the underlying against the quote mint; checked by the marketplace
+    #[account(mut)]
+    pub market: UncheckedAccount<'info>,
+
+    /// CHECK: Order PDA of the market's next order id; created by the marketplace
+    #[account(mut)]
+    pub order: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA of the order; created by the marketplace
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lists `amount` of the underlying the vault's puts were assigned at `price`
+/// (quote per underlying token). Fills pay into the vault's quote account;
+/// deposits and withdrawals wait until the listing is closed.
+pub fn handler(ctx: Context<ListVaultCollateral>, amount: u64, price: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    require!(
+        amount > 0 && amount <= accounts.vault_underlying_account.amount,
+        ErrorCode::InvalidAmount
+    );
+
+    let option_vault_key = accounts.option_vault.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        OptionVault::AUTHORITY_SEED,
+        option_vault_key.as_ref(),
+        &[accounts.option_vault.authority_bump],
+    ]];
+    spl_marketplace::cpi::place_order(
+        CpiContext::new_with_signer(
+            accounts.marketplace_program.to_account_info(),
+            PlaceOrder {
+                user: accounts.vault_authority.to_account_info(),
+                market: accounts.market.to_account_info(),
+                order: accounts.order.to_account_info(),
+                deposit_mint: accounts.underlying_mint.to_account_info(),
+                user_deposit_account: accounts.vault_underlying_account.to_account_info(),
+                escrow: accounts.escrow.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                linked_series: None,
+            },
+            signer_seeds,
+        ),
+        price,
+        amount,
+        false,
+    )?;
+
+    let vault = &mut accounts.option_vault;
+    vault.listing = accounts.order.key();
+
+    emit!(VaultListingOpened {
+        option_vault: vault.key(),
+        listing: vault.listing,
+        amount,
+        price,
+    });
+
+    msg!(
+        "Vault {} lists {} underlying at {}",
+        vault.key(),
+        amount,
+        price
+    );
+
+    Ok(())
+}
@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::invariants::SeriesAccounts;
+use crate::oracle::{resolve_price, PriceRequest};
+use crate::state::{ProtocolConfig, WriterPosition};
+use crate::utils::margin::{health_factor_bps, spread_requirement};
+
+/// Shared accounts struct for margin writing (mint, deposit, withdraw, liquidate, settle)
+///
+/// Margin lives in the vault of the asset that backs the series (collateral
+/// for calls, consideration for puts) but is tracked per position and in
+/// `OptionData::margin_collateral`, never in the redemption pool's accounting.
+#[derive(Accounts)]
+pub struct MarginContext<'info> {
+    /// Writer, depositor or liquidator, depending on the instruction
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Margin position being changed (owner-only instructions check `writer`)
+    #[account(
+        mut,
+        seeds = [
+            WriterPosition::SEED,
+            option_context.key().as_ref(),
+            writer_position.writer.as_ref(),
+        ],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Account<'info, WriterPosition>,
+
+    /// Protocol config (global pause)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Collateral mint (validated against stored value in option_context)
+    #[account(
+        constraint = collateral_mint.key() == option_context.collateral_mint
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    /// Consideration mint (validated against stored value in option_context)
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault of the backing asset: collateral vault for calls, consideration vault for puts
+    #[account(
+        mut,
+        constraint = margin_vault.key() == option_context.margin_vault()
+    )]
+    pub margin_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's account of the backing asset (funds deposits, receives releases)
+    #[account(
+        mut,
+        token::authority = user,
+        constraint = user_margin_account.mint == option_context.margin_mint()
+            @ ErrorCode::InvalidMarginAccount
+    )]
+    pub user_margin_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's option token account
+    #[account(mut, token::mint = option_mint, token::authority = user)]
+    pub user_option_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Validated against option_context.primary_oracle by the oracle module
+    pub primary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated against option_context.secondary_oracle by the oracle module
+    pub secondary_oracle: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// User's token account of the series' gate mint; required when the
+    /// series is gated (mint_on_margin only)
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> MarginContext<'info> {
+    /// Mint of the asset margin is held in
+    fn margin_mint(&self) -> &InterfaceAccount<'info, Mint> {
+        if self.option_context.is_put() {
+            &self.consideration_mint
+        } else {
+            &self.collateral_mint
+        }
+    }
+
+    /// Live accounts the series invariants are checked against: the margin
+    /// vault and the option mint
+    pub fn series_accounts(&self) -> SeriesAccounts<'info> {
+        SeriesAccounts {
+            option_mint: Some(self.option_mint.to_account_info()),
+            ..SeriesAccounts::escrow_vault(
+                &self.option_context,
+                self.margin_vault.to_account_info(),
+            )
+        }
+    }
+
+    /// Current guarded price from the series' oracle hierarchy
+    pub fn live_price(&self) -> Result<u64> {
+        live_price(
+            &self.option_context,
+            self.primary_oracle.as_ref(),
+            self.secondary_oracle.as_ref(),
+        )
+    }
+
+    /// Margin the position needs at `price` with a `margin_bps` buffer
+    pub fn requirement(&self, price: u64, margin_bps: u16) -> Result<u64> {
+        position_requirement(
+            &self.writer_position,
+            &self.option_context,
+            price,
+            margin_bps,
+        )
+    }
+
+    /// Records the position's health against the maintenance requirement at `price`
+    pub fn record_health(&mut self, price: u64) -> Result<u64> {
+        let requirement = self.requirement(price, self.option_context.maintenance_margin_bps)?;
+        let health = health_factor_bps(self.writer_position.margin_collateral, requirement)?;
+        self.writer_position.health_bps = health;
+        Ok(health)
+    }
+
+    /// Adds `amount` to the position's margin (and the series' margin total)
+    pub fn credit_margin(&mut self, amount: u64) -> Result<()> {
+        let position = &mut self.writer_position;
+        position.margin_collateral = position
+            .margin_collateral
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let option_context = &mut self.option_context;
+        option_context.margin_collateral = option_context
+            .margin_collateral
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Takes `amount` out of the position's margin; fails beyond what it holds
+    pub fn debit_margin(&mut self, amount: u64) -> Result<()> {
+        let position = &mut self.writer_position;
+        require!(
+            amount <= position.margin_collateral,
+            ErrorCode::InsufficientMargin
+        );
+        position.margin_collateral -= amount;
+        let option_context = &mut self.option_context;
+        option_context.margin_collateral = option_context.margin_collateral.saturating_sub(amount);
+        Ok(())
+    }
+
+    /// Records `amount` options written on margin through the position
+    pub fn record_margin_write(&mut self, amount: u64) -> Result<()> {
+        let position = &mut self.writer_position;
+        position.margin_written = position
+            .margin_written
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        position.total_written = position
+            .total_written
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let option_context = &mut self.option_context;
+        option_context.margin_written = option_context
+            .margin_written
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        option_context.total_supply = option_context
+            .total_supply
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Records `amount` of the position's margin short bought back (burned)
+    pub fn record_margin_buyback(&mut self, amount: u64) -> Result<()> {
+        let position = &mut self.writer_position;
+        require!(
+            amount <= position.margin_written,
+            ErrorCode::ExceedsMarginWritten
+        );
+        position.margin_written -= amount;
+        let option_context = &mut self.option_context;
+        option_context.margin_written = option_context.margin_written.saturating_sub(amount);
+        Ok(())
+    }
+
+    /// Moves `amount` of the backing asset from the user into the vault
+    pub fn deposit(&self, amount: u64) -> Result<()> {
+        let mint = self.margin_mint();
+        token::transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: self.user_margin_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: self.margin_vault.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    /// Moves `amount` of the backing asset from the vault to the user
+    pub fn release(&self, amount: u64) -> Result<()> {
+        let option_context = &self.option_context;
+        let mint = self.margin_mint();
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: self.margin_vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: self.user_margin_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                &[&[
+                    b"option_context",
+                    option_context.collateral_mint.as_ref(),
+                    option_context.consideration_mint.as_ref(),
+                    &option_context.strike_price.to_le_bytes(),
+                    &option_context.expiration.to_le_bytes(),
+                    &[option_context.option_type as u8],
+                    &[option_context.bump],
+                ]],
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    /// Mints `amount` option tokens to the user (OptionContext PDA signs)
+    pub fn mint_options(&self, amount: u64) -> Result<()> {
+        let option_context = &self.option_context;
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::MintTo {
+                    mint: self.option_mint.to_account_info(),
+                    to: self.user_option_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                &[&[
+                    b"option_context",
+                    option_context.collateral_mint.as_ref(),
+                    option_context.consideration_mint.as_ref(),
+                    &option_context.strike_price.to_le_bytes(),
+                    &option_context.expiration.to_le_bytes(),
+                    &[option_context.option_type as u8],
+                    &[option_context.bump],
+                ]],
+            ),
+            amount,
+        )
+    }
+
+    /// Burns `amount` of the user's option tokens
+    pub fn burn_options(&self, amount: u64) -> Result<()> {
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.option_mint.to_account_info(),
+                    from: self.user_option_account.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+/// Current guarded price from `option_context`'s oracle hierarchy
+pub(crate) fn live_price(
+    option_context: &OptionData,
+    primary_oracle: Option<&UncheckedAccount>,
+    secondary_oracle: Option<&UncheckedAccount>,
+) -> Result<u64> {
+    let primary_account = primary_oracle.map(|a| a.to_account_info());
+    let secondary_account = secondary_oracle.map(|a| a.to_account_info());
+
+    let request = PriceRequest {
+        reference_time: Clock::get()?.unix_timestamp,
+        late_window: 0,
+        previous_price: option_context.last_oracle_price,
+        previous_update: option_context.last_oracle_update,
+        strike_exponent: option_context.strike_exponent,
+    };
+
+    let (price, _, _) = resolve_price(
+        &option_context.primary_oracle,
+        primary_account.as_ref(),
+        &option_context.secondary_oracle,
+        secondary_account.as_ref(),
+        &request,
+    )?;
+    Ok(price)
+}
+
+/// Margin `position` needs at `price` with a `margin_bps` buffer
+/// (spread-hedged options need at most the spread's max loss)
+pub(crate) fn position_requirement(
+    position: &WriterPosition,
+    option_context: &OptionData,
+    price: u64,
+    margin_bps: u16,
+) -> Result<u64> {
+    spread_requirement(
+        option_context.underlying_amount(position.margin_written)?,
+        option_context.underlying_amount(position.spread_locked)?,
+        option_context.strike_price,
+        position.spread_strike,
+        price,
+        option_context.strike_scale,
+        option_context.is_put(),
+        margin_bps,
+    )
+}
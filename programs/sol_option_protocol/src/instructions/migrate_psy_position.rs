@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::OptionsMinted;
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::migration::psy_american::{
+    close_position, ClosePosition, PsyOptionMarket, PSY_AMERICAN_PROGRAM_ID,
+};
+use crate::state::ProtocolConfig;
+use crate::utils::validation::{
+    credential_state, validate_amount, validate_credential, validate_lot_multiple,
+    validate_min_mint_amount, validate_not_expired, validate_not_frozen, validate_not_knocked_out,
+    validate_not_legacy_put, validate_not_paused, validate_not_settled,
+    validate_protocol_not_paused,
+};
+
+/// Accounts for moving a paired PsyOptions position into an equivalent series
+#[derive(Accounts)]
+pub struct MigratePsyPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Destination series (must describe the same contract as the PsyOptions market)
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Collateral mint (validated against stored value in option_context)
+    #[account(
+        constraint = collateral_mint.key() == option_context.collateral_mint
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    /// Collateral vault: receives the underlying released by PsyOptions
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == option_context.collateral_vault
+    )]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's option token account (receives the new LONG leg)
+    #[account(mut, token::mint = option_mint, token::authority = user)]
+    pub user_option_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's redemption token account (receives the new SHORT leg)
+    #[account(mut, token::mint = redemption_mint, token::authority = user)]
+    pub user_redemption_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Parsed and owner-checked by PsyOptionMarket::load
+    pub psy_option_market: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the market's option mint (checked in handler)
+    #[account(mut)]
+    pub psy_option_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the market's writer token mint (checked in handler)
+    #[account(mut)]
+    pub psy_writer_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the market's underlying pool (checked in handler)
+    #[account(mut)]
+    pub psy_underlying_pool: UncheckedAccount<'info>,
+
+    /// User's PsyOptions option token account (burned by close_position)
+    #[account(mut, token::mint = psy_option_mint, token::authority = user)]
+    pub user_psy_option_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's PsyOptions writer token account (burned by close_position)
+    #[account(mut, token::mint = psy_writer_mint, token::authority = user)]
+    pub user_psy_writer_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Address-checked PsyOptions American program
+    #[account(address = PSY_AMERICAN_PROGRAM_ID)]
+    pub psy_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// User's token account of the series' gate mint; required when the
+    /// series is gated
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Protocol config (protocol-wide pause)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+/// Migrates `contracts` paired PsyOptions contracts into this series
+/// Closes the PsyOptions pair into our collateral vault → mints a pair covering the same collateral here
+///
+/// Minting here is guarded like `mint`: the same pause, freeze, knock-out,
+/// expiry, settlement, credential, lot-size and minimum-size checks apply.
+pub fn handler(ctx: Context<MigratePsyPosition>, contracts: u64) -> Result<()> {
+    // Validation
+    validate_amount(contracts)?;
+    validate_protocol_not_paused(&ctx.accounts.protocol_config)?;
+    validate_not_paused(&ctx.accounts.option_context)?;
+    validate_not_knocked_out(&ctx.accounts.option_context)?;
+    validate_not_frozen(&ctx.accounts.option_context)?;
+    validate_not_legacy_put(&ctx.accounts.option_context)?;
+    validate_not_expired(ctx.accounts.option_context.expiration)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+    validate_credential(
+        &ctx.accounts.option_context,
+        &ctx.accounts.user.key(),
+        credential_state(&ctx.accounts.credential_account),
+    )?;
+
+    let market = PsyOptionMarket::load(&ctx.accounts.psy_option_market.to_account_info())?;
+    market.validate_matches(&ctx.accounts.option_context)?;
+    require_keys_eq!(
+        ctx.accounts.psy_option_mint.key(),
+        market.option_mint,
+        ErrorCode::MigrationParameterMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.psy_writer_mint.key(),
+        market.writer_token_mint,
+        ErrorCode::MigrationParameterMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.psy_underlying_pool.key(),
+        market.underlying_asset_pool,
+        ErrorCode::MigrationParameterMismatch
+    );
+
+    let expected_amount = contracts
+        .checked_mul(market.underlying_amount_per_contract)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // 1. Close the PsyOptions pair; released underlying lands in our collateral vault
+    let vault_before = ctx.accounts.collateral_vault.amount;
+    close_position(
+        ClosePosition {
+            psy_program: &ctx.accounts.psy_program.to_account_info(),
+            user_authority: &ctx.accounts.user.to_account_info(),
+            option_market: &ctx.accounts.psy_option_market.to_account_info(),
+            option_mint: &ctx.accounts.psy_option_mint.to_account_info(),
+            option_token_src: &ctx.accounts.user_psy_option_account.to_account_info(),
+            writer_token_mint: &ctx.accounts.psy_writer_mint.to_account_info(),
+            writer_token_src: &ctx.accounts.user_psy_writer_account.to_account_info(),
+            underlying_asset_pool: &ctx.accounts.psy_underlying_pool.to_account_info(),
+            underlying_asset_dest: &ctx.accounts.collateral_vault.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+        },
+        contracts,
+    )?;
+
+    ctx.accounts.collateral_vault.reload()?;
+    let received = ctx
+        .accounts
+        .collateral_vault
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require_eq!(
+        received,
+        expected_amount,
+        ErrorCode::MigrationParameterMismatch
+    );
+
+    // Options covering the received collateral at this series' contract size
+    let multiplier = ctx.accounts.option_context.contract_multiplier;
+    require!(
+        received.is_multiple_of(multiplier),
+        ErrorCode::MigrationParameterMismatch
+    );
+    let options = received / multiplier;
+    validate_lot_multiple(&ctx.accounts.option_context, options)?;
+    validate_min_mint_amount(&ctx.accounts.option_context, options)?;
+
+    // 2. Mint the equivalent pair (OptionContext PDA signs as mint authority)
+    let option_context = &ctx.accounts.option_context;
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_mint_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_mint_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.option_mint.to_account_info(),
+                to: ctx.accounts.user_option_account.to_account_info(),
+                authority: option_context.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        options,
+    )?;
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.redemption_mint.to_account_info(),
+                to: ctx.accounts.user_redemption_account.to_account_info(),
+                authority: option_context.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        options,
+    )?;
+
+    // 3. Update total supply and vault accounting (OptionContext bookkeeping)
+    let series_key = ctx.accounts.option_context.key();
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.credit_collateral(received)?;
+    option_context.total_supply = option_context
+        .total_supply
+        .checked_add(options)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(OptionsMinted {
+        option_context: series_key,
+        user: ctx.accounts.user.key(),
+        amount: options,
+        escrow: received,
+        total_supply: option_context.total_supply,
+    });
+
+    msg!(
+        "Migrated {} PsyOptions contracts ({} collateral) into series {}. Total supply: {}",
+        contracts,
+        received,
+        series_key,
+        option_context.total_supply
+    );
+
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(ctx.accounts.collateral_vault.to_account_info()),
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
+}
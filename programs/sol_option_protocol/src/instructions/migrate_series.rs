@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesMigrated;
+use crate::instructions::{LegacyState, OptionData};
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::ProtocolConfig;
+
+/// Permissionless: grows a series account to the current `OptionData` layout
+/// and steps its version up (the payer covers the extra rent)
+#[derive(Accounts)]
+pub struct MigrateSeries<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: May predate the current layout, so it is loaded by hand; owner
+    /// checked here, discriminator in the handler
+    #[account(mut, owner = crate::ID)]
+    pub option_context: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Legacy series only: the mints and vaults the series stores, which its
+    /// strike units and vault ledger are backfilled from
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub consideration_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub collateral_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub consideration_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Legacy series only: names the protocol admin, who becomes the series
+    /// authority
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+}
+
+impl<'info> MigrateSeries<'info> {
+    /// Live state of the legacy `series`' mints and vaults, and the
+    /// protocol admin
+    fn legacy_state(&self, series: &OptionData) -> Result<LegacyState> {
+        let (
+            Some(collateral_mint),
+            Some(consideration_mint),
+            Some(collateral_vault),
+            Some(consideration_vault),
+            Some(protocol_config),
+        ) = (
+            &self.collateral_mint,
+            &self.consideration_mint,
+            &self.collateral_vault,
+            &self.consideration_vault,
+            &self.protocol_config,
+        )
+        else {
+            return err!(ErrorCode::LegacySeriesAccounts);
+        };
+        require!(
+            collateral_mint.key() == series.collateral_mint
+                && consideration_mint.key() == series.consideration_mint
+                && collateral_vault.key() == series.collateral_vault
+                && consideration_vault.key() == series.consideration_vault,
+            ErrorCode::LegacySeriesAccounts
+        );
+        Ok(LegacyState {
+            collateral_decimals: collateral_mint.decimals,
+            consideration_decimals: consideration_mint.decimals,
+            collateral_held: collateral_vault.amount,
+            consideration_held: consideration_vault.amount,
+            authority: protocol_config.admin,
+        })
+    }
+}
+
+pub fn handler(ctx: Context<MigrateSeries>) -> Result<()> {
+    let info = ctx.accounts.option_context.to_account_info();
+    require!(
+        info.try_borrow_data()?
+            .starts_with(OptionData::DISCRIMINATOR),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    // 1. Grow legacy series and zero what they never wrote
+    let space = 8 + OptionData::INIT_SPACE;
+    let legacy_len = info.data_len();
+    if legacy_len < space {
+        require!(
+            (OptionData::LEGACY_SIZE..OptionData::VERSION_OFFSET).contains(&legacy_len),
+            anchor_lang::error::ErrorCode::AccountDidNotDeserialize
+        );
+        let shortfall = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(space)?;
+        OptionData::open_legacy_fields(&mut info.try_borrow_mut_data()?);
+    }
+
+    // 2. Step the series up to the current version
+    let mut series = OptionData::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let from_version = series.version;
+    require!(
+        from_version < OptionData::CURRENT_VERSION,
+        ErrorCode::SeriesUpToDate
+    );
+    let legacy = if from_version == 0 {
+        Some(ctx.accounts.legacy_state(&series)?)
+    } else {
+        None
+    };
+    series.migrate(legacy.as_ref())?;
+    series.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(SeriesMigrated {
+        option_context: info.key(),
+        from_version,
+        to_version: series.version,
+    });
+
+    msg!(
+        "Series {} migrated from layout version {} to {}",
+        info.key(),
+        from_version,
+        series.version
+    );
+
+    // The backfilled ledger must match the vaults it was read from
+    if legacy.is_some() {
+        check_series(
+            &series,
+            SeriesAccounts {
+                collateral_vault: ctx
+                    .accounts
+                    .collateral_vault
+                    .as_ref()
+                    .map(|vault| vault.to_account_info()),
+                consideration_vault: ctx
+                    .accounts
+                    .consideration_vault
+                    .as_ref()
+                    .map(|vault| vault.to_account_info()),
+                ..SeriesAccounts::default()
+            },
+        )?;
+    }
+
+    Ok(())
+}
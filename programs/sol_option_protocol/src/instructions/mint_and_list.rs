@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use spl_marketplace::cpi::accounts::PlaceOrder;
+use spl_marketplace::program::SplMarketplace;
+
+use crate::errors::ErrorCode;
+use crate::instructions::mint_options;
+// The generated client modules of OptionContext come along for the nesting
+use crate::instructions::option::*;
+
+/// `mint` followed by a marketplace ask for the freshly minted options
+#[derive(Accounts)]
+pub struct MintAndList<'info> {
+    pub option: OptionContext<'info>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: Market the options are listed on; the marketplace checks that
+    /// its base mint is the series' option mint
+    #[account(mut)]
+    pub market: UncheckedAccount<'info>,
+
+    /// CHECK: Order PDA of the market's next order id; created by the marketplace
+    #[account(mut)]
+    pub order: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA of the order; created by the marketplace
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+}
+
+/// Mints `amount` options to the user and lists all of them at `price`
+/// (quote tokens per option token). Deposits losing a transfer fee mint
+/// fewer options; the ask is sized by what arrived. Remaining accounts
+/// are forwarded to `place_order` for option mints with a transfer hook.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintAndList<'info>>,
+    amount: u64,
+    price: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    require_keys_eq!(
+        accounts.option.option_owner.key(),
+        accounts.option.user.key(),
+        ErrorCode::ListingNotOwned
+    );
+
+    let held = accounts.option.user_option_account.amount;
+    mint_options::handler(
+        Context::new(
+            ctx.program_id,
+            &mut accounts.option,
+            &[],
+            Default::default(),
+        ),
+        amount,
+    )?;
+    accounts.option.user_option_account.reload()?;
+    let minted = accounts
+        .option
+        .user_option_account
+        .amount
+        .checked_sub(held)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let option = &accounts.option;
+    spl_marketplace::cpi::place_order(
+        CpiContext::new(
+            accounts.marketplace_program.to_account_info(),
+            PlaceOrder {
+                user: option.user.to_account_info(),
+                market: accounts.market.to_account_info(),
+                order: accounts.order.to_account_info(),
+                deposit_mint: option.option_mint.to_account_info(),
+                user_deposit_account: option.user_option_account.to_account_info(),
+                escrow: accounts.escrow.to_account_info(),
+                token_program: option.token_program.to_account_info(),
+                system_program: option.system_program.to_account_info(),
+                linked_series: Some(option.option_context.to_account_info()),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        price,
+        minted,
+        false,
+    )?;
+
+    msg!("Listed {} minted options at {}", minted, price);
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::mint_options::{mint, MintParts};
+use crate::instructions::OptionData;
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats, WriterPosition};
+
+/// `mint` without the leg owners or associated account creation: every token
+/// account must already exist, and the legs go to whichever accounts of the
+/// option and redemption mints are passed
+#[derive(Accounts)]
+pub struct MintAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(constraint = collateral_mint.key() == option_context.collateral_mint)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(constraint = consideration_mint.key() == option_context.consideration_mint)]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = option_mint.key() == option_context.option_mint)]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = redemption_mint.key() == option_context.redemption_mint)]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = collateral_vault.key() == option_context.collateral_vault)]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = collateral_mint, token::authority = user)]
+    pub user_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = consideration_mint, token::authority = user)]
+    pub user_consideration_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the long leg (any owner)
+    #[account(mut, token::mint = option_mint)]
+    pub user_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the short leg (any owner)
+    #[account(mut, token::mint = redemption_mint)]
+    pub user_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Same optional trailing accounts as `OptionContext`
+    #[account(
+        mut,
+        seeds = [WriterPosition::SEED, option_context.key().as_ref(), user.key().as_ref()],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Option<Account<'info, WriterPosition>>,
+
+    #[account(
+        mut,
+        constraint = fee_vault.owner == protocol_config.key() @ ErrorCode::InvalidFeeVault
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [FeeStats::SEED, user.key().as_ref(), consideration_mint.key().as_ref()],
+        bump = fee_stats.bump
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    #[account(mut)]
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+
+    #[account(mut)]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(mut)]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> MintAccounts<'info> {
+    fn parts(&mut self) -> MintParts<'_, 'info> {
+        MintParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            protocol_config: &self.protocol_config,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            option_mint: &self.option_mint,
+            redemption_mint: &self.redemption_mint,
+            collateral_vault: &mut self.collateral_vault,
+            consideration_vault: &mut self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_option_account: &self.user_option_account,
+            user_redemption_account: &self.user_redemption_account,
+            token_program: &self.token_program,
+            writer_position: &mut self.writer_position,
+            fee_vault: &self.fee_vault,
+            fee_stats: &mut self.fee_stats,
+            referrer_account: &self.referrer_account,
+            referral_stats: &mut self.referral_stats,
+            insurance_fund: &mut self.insurance_fund,
+            insurance_vault: &self.insurance_vault,
+            credential_account: &self.credential_account,
+        }
+    }
+}
+
+/// Same deposit, fee and bookkeeping as `mint`
+pub fn handler(ctx: Context<MintAccounts>, amount: u64) -> Result<()> {
+    mint(ctx.accounts.parts(), amount)
+}
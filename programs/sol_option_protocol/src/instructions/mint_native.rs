@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::native_sol::NativeAccount;
+use crate::instructions::{mint_options, OptionContext};
+
+/// `mint` paying in native SOL: wraps up to `lamports` into the user's WSOL
+/// account, mints `amount`, and returns what the escrow and fee didn't use
+/// as lamports
+pub fn handler(ctx: Context<OptionContext>, amount: u64, lamports: u64) -> Result<()> {
+    let native = NativeAccount::wrap(ctx.accounts, lamports)?;
+    mint_options::handler(ctx, amount)?;
+    native.unwrap()
+}
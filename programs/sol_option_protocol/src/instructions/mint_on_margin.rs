@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::MarginContext;
+use crate::invariants::check_series;
+use crate::utils::validation::{
+    credential_state, validate_amount, validate_credential, validate_not_expired,
+    validate_not_frozen, validate_not_knocked_out, validate_not_legacy_put, validate_not_paused,
+    validate_not_settled, validate_protocol_not_paused,
+};
+
+/// Writes `amount` options on margin: only the long leg is minted, backed by
+/// the writer's position instead of the redemption pool
+/// `deposit` (may be 0) adds margin in the same step; afterwards the position
+/// must hold at least the initial requirement at the live oracle price
+pub fn handler(ctx: Context<MarginContext>, amount: u64, deposit: u64) -> Result<()> {
+    // Validation
+    validate_amount(amount)?;
+    validate_protocol_not_paused(&ctx.accounts.protocol_config)?;
+    validate_not_paused(&ctx.accounts.option_context)?;
+    validate_not_knocked_out(&ctx.accounts.option_context)?;
+    validate_not_frozen(&ctx.accounts.option_context)?;
+    validate_not_legacy_put(&ctx.accounts.option_context)?;
+    validate_not_expired(ctx.accounts.option_context.expiration)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+    validate_credential(
+        &ctx.accounts.option_context,
+        &ctx.accounts.user.key(),
+        credential_state(&ctx.accounts.credential_account),
+    )?;
+    require!(
+        ctx.accounts.option_context.margin_enabled(),
+        ErrorCode::MarginDisabled
+    );
+    require_keys_eq!(
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.user.key(),
+        ErrorCode::Unauthorized
+    );
+
+    // 1. Move the deposit into the backing vault
+    if deposit > 0 {
+        ctx.accounts.deposit(deposit)?;
+    }
+
+    // 2. Mint option tokens to the writer (LONG position; the short is the position)
+    ctx.accounts.mint_options(amount)?;
+
+    // 3. Position and series bookkeeping
+    ctx.accounts.credit_margin(deposit)?;
+    ctx.accounts.record_margin_write(amount)?;
+
+    // 4. Initial margin check at the live price
+    let price = ctx.accounts.live_price()?;
+    let required = ctx
+        .accounts
+        .requirement(price, ctx.accounts.option_context.initial_margin_bps)?;
+    require!(
+        ctx.accounts.writer_position.margin_collateral >= required,
+        ErrorCode::InsufficientMargin
+    );
+    let health = ctx.accounts.record_health(price)?;
+
+    msg!(
+        "Wrote {} options on margin in series {} (margin {}, health {} bps)",
+        amount,
+        ctx.accounts.option_context.key(),
+        ctx.accounts.writer_position.margin_collateral,
+        health
+    );
+
+    check_series(&ctx.accounts.option_context, ctx.accounts.series_accounts())
+}
@@ -1,32 +1,217 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount, TokenInterface};
 
-use crate::instructions::OptionContext;
 use crate::errors::ErrorCode;
-use crate::utils::validation::validate_amount;
+use crate::events::OptionsMinted;
+use crate::instructions::protocol_fee::{charge_fee, fee_due, FeeAccounts};
+use crate::instructions::vault_deposit::deposit_received;
+use crate::instructions::{OptionContext, OptionData};
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats, WriterPosition};
+use crate::utils::math::calculate_strike_value;
+use crate::utils::validation::{
+    credential_state, validate_amount, validate_credential, validate_lot_multiple,
+    validate_min_mint_amount, validate_not_expired, validate_not_frozen, validate_not_knocked_out,
+    validate_not_legacy_put, validate_not_paused, validate_not_settled,
+    validate_protocol_not_paused,
+};
 
 /// Mints option and redemption tokens by depositing collateral
-/// User deposits collateral → receives 1:1 option + redemption tokens
+/// Call: user deposits collateral → receives 1:1 option + redemption tokens
+/// Put: user deposits the strike value in consideration → receives the same pair
+/// Binary: user deposits the binary payout in consideration → receives the same pair
+/// Forward: user deposits the collateral and its strike value in consideration
+/// The two legs go to the option/redemption accounts passed in, which may belong
+/// to different owners (e.g. long leg straight to a buyer)
+/// Writers also pay the protocol's mint fee on the escrow, in the escrow mint
+/// (discounted by their volume tier, waived for exempt accounts)
+/// Options are minted against what the vaults actually received, so a mint
+/// that withholds a transfer fee mints fewer than `amount` (in whole lots)
 pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+    mint(ctx.accounts.mint_parts(), amount)
+}
+
+/// Accounts `mint` works on, borrowed from `OptionContext` or `MintAccounts`
+pub struct MintParts<'a, 'info> {
+    pub user: &'a Signer<'info>,
+    pub option_context: &'a mut Account<'info, OptionData>,
+    pub protocol_config: &'a Account<'info, ProtocolConfig>,
+    pub collateral_mint: &'a InterfaceAccount<'info, Mint>,
+    pub consideration_mint: &'a InterfaceAccount<'info, Mint>,
+    pub option_mint: &'a InterfaceAccount<'info, Mint>,
+    pub redemption_mint: &'a InterfaceAccount<'info, Mint>,
+    pub collateral_vault: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub consideration_vault: &'a mut InterfaceAccount<'info, TokenAccount>,
+    pub user_collateral_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_consideration_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_option_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_redemption_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub writer_position: &'a mut Option<Account<'info, WriterPosition>>,
+    pub fee_vault: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub fee_stats: &'a mut Option<Account<'info, FeeStats>>,
+    pub referrer_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub referral_stats: &'a mut Option<Account<'info, ReferralStats>>,
+    pub insurance_fund: &'a mut Option<Account<'info, InsuranceFund>>,
+    pub insurance_vault: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub credential_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> OptionContext<'info> {
+    pub(crate) fn mint_parts(&mut self) -> MintParts<'_, 'info> {
+        MintParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            protocol_config: &self.protocol_config,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            option_mint: &self.option_mint,
+            redemption_mint: &self.redemption_mint,
+            collateral_vault: &mut self.collateral_vault,
+            consideration_vault: &mut self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_option_account: &self.user_option_account,
+            user_redemption_account: &self.user_redemption_account,
+            token_program: &self.token_program,
+            writer_position: &mut self.writer_position,
+            fee_vault: &self.fee_vault,
+            fee_stats: &mut self.fee_stats,
+            referrer_account: &self.referrer_account,
+            referral_stats: &mut self.referral_stats,
+            insurance_fund: &mut self.insurance_fund,
+            insurance_vault: &self.insurance_vault,
+            credential_account: &self.credential_account,
+        }
+    }
+}
+
+/// `mint` over whichever accounts struct carried the call
+pub(crate) fn mint(accounts: MintParts<'_, '_>, amount: u64) -> Result<()> {
     // Validation
     validate_amount(amount)?;
+    validate_lot_multiple(accounts.option_context, amount)?;
+    validate_min_mint_amount(accounts.option_context, amount)?;
+    validate_protocol_not_paused(accounts.protocol_config)?;
+    validate_not_paused(accounts.option_context)?;
+    validate_not_knocked_out(accounts.option_context)?;
+    validate_not_frozen(accounts.option_context)?;
+    validate_not_legacy_put(accounts.option_context)?;
+    validate_not_expired(accounts.option_context.expiration)?;
+    validate_not_settled(accounts.option_context)?;
+    validate_credential(
+        accounts.option_context,
+        &accounts.user.key(),
+        credential_state(accounts.credential_account),
+    )?;
+
+    let option_context = &accounts.option_context;
+    let underlying = option_context.underlying_amount(amount)?;
 
-    let option_context = &ctx.accounts.option_context;
-
-    // 1. Transfer collateral from user to collateral vault
-    msg!("Transferring {} collateral tokens to vault", amount);
-    token::transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::TransferChecked {
-                from: ctx.accounts.user_collateral_account.to_account_info(),
-                mint: ctx.accounts.collateral_mint.to_account_info(),
-                to: ctx.accounts.collateral_vault.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+    // 1. Lock the writer's obligation in the matching vault
+    let escrow = option_context.escrow_locked(underlying)?;
+    let fee = fee_due(
+        accounts.protocol_config,
+        &accounts.user.key(),
+        escrow,
+        accounts.protocol_config.mint_fee_bps,
+        accounts.fee_stats.as_deref(),
+        accounts.consideration_mint.decimals,
+    )?;
+    let escrow_received = if option_context.escrows_consideration() {
+        msg!("Transferring {} consideration tokens to vault", escrow);
+        let received = deposit_received(
+            &accounts.token_program.to_account_info(),
+            &accounts.user_consideration_account.to_account_info(),
+            accounts.consideration_mint,
+            accounts.consideration_vault,
+            &accounts.user.to_account_info(),
+            escrow,
+        )?;
+        charge_fee(
+            option_context.key(),
+            FeeAccounts {
+                token_program: accounts.token_program.to_account_info(),
+                from: accounts.user_consideration_account.to_account_info(),
+                payer: accounts.user.to_account_info(),
+                mint: accounts.consideration_mint,
+                fee_vault: accounts.fee_vault.as_deref(),
+                referrer_account: accounts.referrer_account.as_deref(),
+                referral_stats: accounts.referral_stats.as_mut(),
+                insurance_fund: accounts.insurance_fund.as_mut(),
+                insurance_vault: accounts.insurance_vault.as_deref(),
             },
-        ),
-        amount,
-        ctx.accounts.collateral_mint.decimals,
+            fee,
+            accounts.protocol_config.referral_share_bps,
+            accounts.protocol_config.insurance_share_bps,
+        )?;
+        received
+    } else {
+        msg!("Transferring {} collateral tokens to vault", escrow);
+        let received = deposit_received(
+            &accounts.token_program.to_account_info(),
+            &accounts.user_collateral_account.to_account_info(),
+            accounts.collateral_mint,
+            accounts.collateral_vault,
+            &accounts.user.to_account_info(),
+            escrow,
+        )?;
+        charge_fee(
+            option_context.key(),
+            FeeAccounts {
+                token_program: accounts.token_program.to_account_info(),
+                from: accounts.user_collateral_account.to_account_info(),
+                payer: accounts.user.to_account_info(),
+                mint: accounts.collateral_mint,
+                fee_vault: accounts.fee_vault.as_deref(),
+                referrer_account: accounts.referrer_account.as_deref(),
+                referral_stats: accounts.referral_stats.as_mut(),
+                insurance_fund: accounts.insurance_fund.as_mut(),
+                insurance_vault: accounts.insurance_vault.as_deref(),
+            },
+            fee,
+            accounts.protocol_config.referral_share_bps,
+            accounts.protocol_config.insurance_share_bps,
+        )?;
+        received
+    };
+
+    // Forwards also lock the strike the long side pays on delivery
+    let strike_locked = option_context.strike_locked(underlying)?;
+    let strike_received = if strike_locked > 0 {
+        msg!(
+            "Transferring {} consideration tokens to vault",
+            strike_locked
+        );
+        deposit_received(
+            &accounts.token_program.to_account_info(),
+            &accounts.user_consideration_account.to_account_info(),
+            accounts.consideration_mint,
+            accounts.consideration_vault,
+            &accounts.user.to_account_info(),
+            strike_locked,
+        )?
+    } else {
+        0
+    };
+
+    // Transfer fees withheld by the mint shrink the deposit: only what reached
+    // the vaults backs options
+    let minted = option_context.covered_amount(amount, escrow_received, strike_received)?;
+    require!(minted > 0, ErrorCode::DepositShortfall);
+    validate_min_mint_amount(option_context, minted)?;
+    if minted < amount {
+        msg!(
+            "Vaults received {} of {} escrow; minting {}",
+            escrow_received,
+            escrow,
+            minted
+        );
+    }
+    let notional = calculate_strike_value(
+        option_context.underlying_amount(minted)?,
+        option_context.strike_price,
+        option_context.strike_scale,
     )?;
 
     // Create PDA signer seeds for minting (OptionSeries signs as mint authority)
@@ -34,7 +219,7 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
     let consideration_key = option_context.consideration_mint;
     let strike_price_bytes = option_context.strike_price.to_le_bytes();
     let expiration_bytes = option_context.expiration.to_le_bytes();
-    let is_put_byte = [option_context.is_put as u8];
+    let option_type_byte = [option_context.option_type as u8];
     let bump = option_context.bump;
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -43,54 +228,82 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
         consideration_key.as_ref(),
         strike_price_bytes.as_ref(),
         expiration_bytes.as_ref(),
-        &is_put_byte,
+        &option_type_byte,
         &[bump],
     ]];
 
     // 2. Mint option tokens to user (LONG position)
-    msg!("Minting {} option tokens to user", amount);
+    msg!("Minting {} option tokens to user", minted);
     token::mint_to(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::MintTo {
-                mint: ctx.accounts.option_mint.to_account_info(),
-                to: ctx.accounts.user_option_account.to_account_info(),
+                mint: accounts.option_mint.to_account_info(),
+                to: accounts.user_option_account.to_account_info(),
                 authority: option_context.to_account_info(),
             },
             signer_seeds,
         ),
-        amount,
+        minted,
     )?;
 
     // 3. Mint redemption tokens to user (SHORT position)
-    msg!("Minting {} redemption tokens to user", amount);
+    msg!("Minting {} redemption tokens to user", minted);
     token::mint_to(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::MintTo {
-                mint: ctx.accounts.redemption_mint.to_account_info(),
-                to: ctx.accounts.user_redemption_account.to_account_info(),
+                mint: accounts.redemption_mint.to_account_info(),
+                to: accounts.user_redemption_account.to_account_info(),
                 authority: option_context.to_account_info(),
             },
             signer_seeds,
         ),
-        amount,
+        minted,
     )?;
 
-    // 4. Update total supply (OptionContext bookkeeping)
-    let series_key = ctx.accounts.option_context.key();
-    let option_context = &mut ctx.accounts.option_context;
+    // 4. Update total supply and vault accounting (OptionContext bookkeeping)
+    let series_key = accounts.option_context.key();
+    let option_context = &mut *accounts.option_context;
+    option_context.credit_escrow(escrow_received)?;
+    option_context.credit_consideration(strike_received)?;
     option_context.total_supply = option_context
         .total_supply
-        .checked_add(amount)
+        .checked_add(minted)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    // Writer position, when the user tracks one
+    if let Some(position) = accounts.writer_position.as_mut() {
+        position.record_mint(minted)?;
+    }
+
+    // Fee tier volume (strike notional), when the user tracks it
+    if let Some(stats) = accounts.fee_stats.as_mut() {
+        stats.record(notional);
+    }
+
+    emit!(OptionsMinted {
+        option_context: series_key,
+        user: accounts.user.key(),
+        amount: minted,
+        escrow: escrow_received,
+        total_supply: option_context.total_supply,
+    });
+
     msg!(
         "Minted {} options for series {}. Total supply: {}",
-        amount,
+        minted,
         series_key,
         option_context.total_supply
     );
 
-    Ok(())
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(accounts.consideration_vault.to_account_info()),
+            option_mint: Some(accounts.option_mint.to_account_info()),
+            redemption_mint: Some(accounts.redemption_mint.to_account_info()),
+        },
+    )
 }
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::series_transfer::{transfer_series_tokens, SeriesTransfer};
+use crate::instructions::VerticalSpreadContext;
+use crate::state::VerticalSpread;
+use crate::utils::margin::spread_max_loss;
+use crate::utils::validation::{
+    validate_amount, validate_not_expired, validate_not_paused, validate_not_settled,
+    validate_protocol_not_paused, validate_spread_legs,
+};
+
+/// Writes `amount` spread tokens: locks `amount` long options of the
+/// further-OTM series plus the spread's max loss (the strike difference), and
+/// mints spread tokens paying the short series' intrinsic value at expiry
+///
+/// Writing the short series directly would need its full backing; the locked
+/// longs cover everything beyond the strike difference.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VerticalSpreadContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let short = &ctx.accounts.short_option_context;
+    let long = &ctx.accounts.long_option_context;
+
+    // Validation
+    validate_amount(amount)?;
+    validate_spread_legs(short, long)?;
+    validate_protocol_not_paused(&ctx.accounts.protocol_config)?;
+    validate_not_paused(short)?;
+    validate_not_expired(short.expiration)?;
+    validate_not_settled(long)?;
+    let backing = spread_max_loss(
+        short.underlying_amount(amount)?,
+        short.strike_price,
+        long.strike_price,
+        short.strike_scale,
+        short.is_put(),
+    )?;
+
+    // 1. Deposit the max loss and lock the long options (the writer signs)
+    for (from, mint, to, transfer_amount) in [
+        (
+            &ctx.accounts.user_backing_account,
+            &ctx.accounts.backing_mint,
+            &ctx.accounts.spread_vault,
+            backing,
+        ),
+        (
+            &ctx.accounts.user_long_option_account,
+            &ctx.accounts.long_option_mint,
+            &ctx.accounts.long_escrow,
+            amount,
+        ),
+    ] {
+        transfer_series_tokens(
+            SeriesTransfer {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: from.to_account_info(),
+                mint,
+                to: to.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+            ctx.remaining_accounts,
+            transfer_amount,
+            &[],
+        )?;
+    }
+
+    // 2. Record the write (first use fills in the spread and the position)
+    let short_series = ctx.accounts.short_option_context.key();
+    let long_series = ctx.accounts.long_option_context.key();
+    let spread_key = ctx.accounts.vertical_spread.key();
+    let spread_mint = ctx.accounts.spread_mint.key();
+    let writer = ctx.accounts.user.key();
+    let spread = &mut ctx.accounts.vertical_spread;
+    if spread.short_series == Pubkey::default() {
+        spread.short_series = short_series;
+        spread.long_series = long_series;
+        spread.spread_mint = spread_mint;
+        spread.bump = ctx.bumps.vertical_spread;
+    }
+    spread.written = spread
+        .written
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    spread.backing = spread
+        .backing
+        .checked_add(backing)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let position_bump = ctx.bumps.position;
+    let position = ctx.accounts.position()?;
+    if position.writer == Pubkey::default() {
+        position.vertical_spread = spread_key;
+        position.writer = writer;
+        position.bump = position_bump.ok_or(ErrorCode::SpreadPositionRequired)?;
+    }
+    position.written = position
+        .written
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // 3. Mint the spread tokens (the spread signs)
+    let spread = &ctx.accounts.vertical_spread;
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.spread_mint.to_account_info(),
+                to: ctx.accounts.user_spread_account.to_account_info(),
+                authority: spread.to_account_info(),
+            },
+            &[&[
+                VerticalSpread::SEED,
+                short_series.as_ref(),
+                long_series.as_ref(),
+                &[spread.bump],
+            ]],
+        ),
+        amount,
+    )?;
+
+    msg!(
+        "Wrote {} spread tokens of {} ({} / {}) backed by {}: {} written",
+        amount,
+        spread_key,
+        short_series,
+        long_series,
+        backing,
+        spread.written
+    );
+
+    Ok(())
+}
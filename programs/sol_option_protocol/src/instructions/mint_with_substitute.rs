@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::{OptionsMinted, SubstituteDeposited};
+use crate::instructions::protocol_fee::{charge_fee, fee_due, FeeAccounts};
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::{
+    CollateralBasket, FeeStats, InsuranceFund, ProtocolConfig, ReferralStats, WriterPosition,
+};
+use crate::utils::math::calculate_strike_value;
+use crate::utils::validation::{
+    credential_state, validate_amount, validate_credential, validate_feature_enabled,
+    validate_lot_multiple, validate_min_mint_amount, validate_not_expired, validate_not_frozen,
+    validate_not_knocked_out, validate_not_legacy_put, validate_not_paused, validate_not_settled,
+    validate_protocol_not_paused,
+};
+
+/// Mint against a governance-approved substitute for the escrow mint
+#[derive(Accounts)]
+pub struct MintWithSubstitute<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Mint writers normally escrow (collateral for calls, consideration otherwise)
+    #[account(
+        constraint = escrow_mint.key() == option_context.margin_mint()
+    )]
+    pub escrow_mint: InterfaceAccount<'info, Mint>,
+
+    /// Consideration mint (validated against stored value in option_context;
+    /// fee tiers count volume in it)
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// Substitutes governance accepts for the escrow mint
+    #[account(
+        seeds = [CollateralBasket::SEED, escrow_mint.key().as_ref()],
+        bump = collateral_basket.bump
+    )]
+    pub collateral_basket: Account<'info, CollateralBasket>,
+
+    /// Protocol config (feature switches)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Substitute deposited at par (same decimals as the escrow mint)
+    #[account(
+        constraint = collateral_basket.accepts(&substitute_mint.key())
+            && substitute_mint.decimals == escrow_mint.decimals
+            @ ErrorCode::SubstituteNotAccepted
+    )]
+    pub substitute_mint: InterfaceAccount<'info, Mint>,
+
+    /// The series' vault for this substitute (created on first deposit)
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [
+            CollateralBasket::VAULT_SEED,
+            option_context.key().as_ref(),
+            substitute_mint.key().as_ref()
+        ],
+        bump,
+        token::mint = substitute_mint,
+        token::authority = option_context,
+    )]
+    pub basket_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = substitute_mint,
+        token::authority = user,
+    )]
+    pub user_substitute_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    /// User's option token account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = option_mint,
+        associated_token::authority = user,
+    )]
+    pub user_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's redemption token account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = redemption_mint,
+        associated_token::authority = user,
+    )]
+    pub user_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// User's writer position, recorded into when passed
+    #[account(
+        mut,
+        seeds = [WriterPosition::SEED, option_context.key().as_ref(), user.key().as_ref()],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Option<Account<'info, WriterPosition>>,
+
+    /// Protocol fee account of the substitute mint; only needed while the
+    /// mint fee is non-zero
+    #[account(
+        mut,
+        constraint = fee_vault.owner == protocol_config.key() @ ErrorCode::InvalidFeeVault
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// User's volume in the series' consideration mint, recorded into when passed
+    #[account(
+        mut,
+        seeds = [
+            FeeStats::SEED,
+            user.key().as_ref(),
+            consideration_mint.key().as_ref()
+        ],
+        bump = fee_stats.bump
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    /// Referrer's token account of the charged mint; with their stats, takes
+    /// the referral share of the fee
+    #[account(mut)]
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Referrer's earnings in the charged mint
+    #[account(mut)]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+
+    /// Insurance fund of the charged mint; only needed while the insurance
+    /// share of the fee is non-zero
+    #[account(mut)]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    /// Token account of the insurance fund in the charged mint
+    #[account(mut)]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// User's token account of the series' gate mint; required when the
+    /// series is gated
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Mints option and redemption tokens like `mint`, escrowing the substitute
+/// instead of the escrow mint; the deposit counts at par in vault accounting
+/// and every later escrow payout draws on the substitute vaults pro rata; the
+/// mint fee is paid in the substitute
+pub fn handler(ctx: Context<MintWithSubstitute>, amount: u64) -> Result<()> {
+    // Validation
+    let option_context = &ctx.accounts.option_context;
+    require!(
+        option_context.accepts_basket,
+        ErrorCode::SubstituteNotAccepted
+    );
+    validate_feature_enabled(
+        &ctx.accounts.protocol_config,
+        ProtocolConfig::FEATURE_COLLATERAL_BASKETS,
+    )?;
+    validate_amount(amount)?;
+    validate_lot_multiple(option_context, amount)?;
+    validate_min_mint_amount(option_context, amount)?;
+    validate_protocol_not_paused(&ctx.accounts.protocol_config)?;
+    validate_not_paused(option_context)?;
+    validate_not_knocked_out(option_context)?;
+    validate_not_frozen(option_context)?;
+    validate_not_legacy_put(option_context)?;
+    validate_credential(
+        option_context,
+        &ctx.accounts.user.key(),
+        credential_state(&ctx.accounts.credential_account),
+    )?;
+    validate_not_expired(option_context.expiration)?;
+    validate_not_settled(option_context)?;
+
+    let substitute_mint = ctx.accounts.substitute_mint.key();
+    let slot = option_context
+        .basket_slot(&substitute_mint)
+        .ok_or(ErrorCode::SubstituteNotAccepted)?;
+    let underlying = option_context.underlying_amount(amount)?;
+    let escrow = option_context.escrow_locked(underlying)?;
+
+    // 1. Lock the writer's obligation in the substitute's vault
+    token::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::TransferChecked {
+                from: ctx.accounts.user_substitute_account.to_account_info(),
+                mint: ctx.accounts.substitute_mint.to_account_info(),
+                to: ctx.accounts.basket_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        escrow,
+        ctx.accounts.substitute_mint.decimals,
+    )?;
+    // The tier is read off consideration-denominated volume whatever the escrow mint
+    let notional = calculate_strike_value(
+        underlying,
+        option_context.strike_price,
+        option_context.strike_scale,
+    )?;
+    let fee = fee_due(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.user.key(),
+        escrow,
+        ctx.accounts.protocol_config.mint_fee_bps,
+        ctx.accounts.fee_stats.as_deref(),
+        ctx.accounts.consideration_mint.decimals,
+    )?;
+    charge_fee(
+        option_context.key(),
+        FeeAccounts {
+            token_program: ctx.accounts.token_program.to_account_info(),
+            from: ctx.accounts.user_substitute_account.to_account_info(),
+            payer: ctx.accounts.user.to_account_info(),
+            mint: &ctx.accounts.substitute_mint,
+            fee_vault: ctx.accounts.fee_vault.as_deref(),
+            referrer_account: ctx.accounts.referrer_account.as_deref(),
+            referral_stats: ctx.accounts.referral_stats.as_mut(),
+            insurance_fund: ctx.accounts.insurance_fund.as_mut(),
+            insurance_vault: ctx.accounts.insurance_vault.as_deref(),
+        },
+        fee,
+        ctx.accounts.protocol_config.referral_share_bps,
+        ctx.accounts.protocol_config.insurance_share_bps,
+    )?;
+
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    // 2. Mint the pair (OptionContext PDA signs as mint authority)
+    for (mint, to) in [
+        (&ctx.accounts.option_mint, &ctx.accounts.user_option_account),
+        (
+            &ctx.accounts.redemption_mint,
+            &ctx.accounts.user_redemption_account,
+        ),
+    ] {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    // 3. Vault accounting counts the substitute at par; the slot tracks where it sits
+    let series_key = ctx.accounts.option_context.key();
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.credit_escrow(escrow)?;
+    option_context.basket_mints[slot] = substitute_mint;
+    option_context.basket_escrow[slot] = option_context.basket_escrow[slot]
+        .checked_add(escrow)
+        .ok_or(ErrorCode::MathOverflow)?;
+    option_context.total_supply = option_context
+        .total_supply
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    if let Some(position) = ctx.accounts.writer_position.as_mut() {
+        position.record_mint(amount)?;
+    }
+    if let Some(stats) = ctx.accounts.fee_stats.as_mut() {
+        stats.record(notional);
+    }
+
+    emit!(SubstituteDeposited {
+        option_context: series_key,
+        substitute_mint,
+        amount,
+        escrow,
+    });
+    emit!(OptionsMinted {
+        option_context: series_key,
+        user: ctx.accounts.user.key(),
+        amount,
+        escrow,
+        total_supply: ctx.accounts.option_context.total_supply,
+    });
+
+    msg!(
+        "Minted {} options for series {} against {} of {}",
+        amount,
+        series_key,
+        escrow,
+        substitute_mint
+    );
+
+    check_series(
+        &ctx.accounts.option_context,
+        SeriesAccounts {
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
+}
@@ -1,24 +1,387 @@
+pub mod accept_config_admin;
+pub mod accept_series_authority;
+pub mod accrue_funding;
+pub mod announce_version;
+pub mod apply_change;
+pub mod attest_settlement;
+pub mod basket_payout;
+pub mod burn_compact;
+pub mod burn_native;
 pub mod burn_paired;
+pub mod burn_vertical_spread;
+pub mod cancel_emergency_withdrawal;
+pub mod cancel_pending;
+pub mod claim_all;
+pub mod claim_net_payoff;
+pub mod claim_vertical_spread;
+pub mod close_series;
+pub mod close_spread;
+pub mod close_vault_listing;
+pub mod combo_context;
+pub mod create_combo;
+pub mod create_option_vault;
 pub mod create_series;
+pub mod create_series_batch;
+pub mod create_spread;
+pub mod decompose_combo;
+pub mod deposit_margin;
+pub mod deposit_to_vault;
+pub mod describe_series;
+pub mod enable_collateral_yield;
+pub mod execute_emergency_withdrawal;
 pub mod exercise;
+pub mod exercise_and_sell;
+pub mod exercise_compact;
+pub mod exercise_native;
+pub mod exit_position;
+pub mod freeze_series;
+pub mod initialize_config;
+pub mod lend_escrow;
+pub mod liquidate;
+pub mod list_vault_collateral;
+pub mod margin_context;
+pub mod migrate_psy_position;
+pub mod migrate_series;
+pub mod mint_and_list;
+pub mod mint_compact;
+pub mod mint_native;
+pub mod mint_on_margin;
 pub mod mint_options;
+pub mod mint_vertical_spread;
+pub mod mint_with_substitute;
+pub mod native_sol;
+pub mod observe_price;
+pub mod open_fee_stats;
+pub mod open_insurance_fund;
+pub mod open_openbook_market;
+pub mod open_referral_stats;
+pub mod open_series_market;
+pub mod open_writer_position;
+pub mod option;
+pub mod pay_insurance;
+pub mod propose_config_admin;
+pub mod propose_governance_admin;
+pub mod propose_series_authority;
+pub mod protocol_fee;
+pub mod psy_close_position;
+pub mod psy_close_post_expiration;
+pub mod psy_exercise_option;
+pub mod psy_mint_option;
+pub mod queue_emergency_withdrawal;
+pub mod record_knockout;
+pub mod recover_unclaimed;
 pub mod redeem;
+pub mod redeem_compact;
 pub mod redeem_consideration;
-pub mod option;
+pub mod redeem_exercised_share;
+pub mod redeem_native;
+pub mod redeem_vertical_spread;
+pub mod reset_oracle_reference;
+pub mod roll_position;
+pub mod schedule_change;
+pub mod seed_openbook_market;
+pub mod series_transfer;
+pub mod set_approved_mint;
+pub mod set_collateral_basket;
+pub mod set_creation_bond;
+pub mod set_expiry_grid;
+pub mod set_expiry_limits;
+pub mod set_features;
+pub mod set_fee_exempt;
+pub mod set_fee_tiers;
+pub mod set_fees;
+pub mod set_guardian;
+pub mod set_insurance_share;
+pub mod set_mint_allowlist;
+pub mod set_mint_extensions;
+pub mod set_protocol_paused;
+pub mod set_referral_share;
+pub mod set_series_emergency_paused;
+pub mod set_series_paused;
+pub mod set_strike_tick;
+pub mod set_timelock_delay;
+pub mod set_token_account_frozen;
+pub mod set_treasury;
+pub mod set_unclaimed_timeout;
+pub mod set_yield_venue;
+pub mod settle_expiry;
+pub mod settle_forward;
+pub mod settle_margin_position;
+pub mod settle_spread;
+pub mod settle_vault_round;
+pub mod settle_vertical_spread;
+pub mod simulate_redemption;
+pub mod spread_context;
+pub mod start_vault_round;
+pub mod swap_and_exercise;
+pub mod sweep_dust;
+pub mod unwind_escrow;
+pub mod update_rate_limits;
+pub mod update_series_metadata;
+pub mod vault_deposit;
+pub mod vault_round;
+pub mod vault_shares;
+pub mod vertical_spread_context;
+pub mod withdraw_fees;
+pub mod withdraw_from_vault;
+pub mod withdraw_margin;
+pub mod yield_context;
 
 // Note: Glob imports are required for Anchor's #[program] macro
 // The handler name collision is intentional - each module's handler is accessed via module path
-#[allow(ambiguous_glob_reexports)]
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use accept_config_admin::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use accept_series_authority::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use accrue_funding::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use announce_version::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use apply_change::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use attest_settlement::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use basket_payout::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use burn_compact::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use burn_native::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use burn_paired::*;
-#[allow(ambiguous_glob_reexports)]
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use burn_vertical_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use cancel_emergency_withdrawal::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use cancel_pending::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use claim_all::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use claim_net_payoff::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use claim_vertical_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use close_series::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use close_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use close_vault_listing::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use combo_context::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use create_combo::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use create_option_vault::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use create_series::*;
-#[allow(ambiguous_glob_reexports)]
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use create_series_batch::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use create_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use decompose_combo::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use deposit_margin::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use deposit_to_vault::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use describe_series::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use enable_collateral_yield::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use execute_emergency_withdrawal::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use exercise::*;
-#[allow(ambiguous_glob_reexports)]
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use exercise_and_sell::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use exercise_compact::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use exercise_native::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use exit_position::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use freeze_series::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use initialize_config::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use lend_escrow::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use liquidate::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use list_vault_collateral::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use margin_context::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use migrate_psy_position::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use migrate_series::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use mint_and_list::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use mint_compact::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use mint_native::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use mint_on_margin::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use mint_options::*;
-#[allow(ambiguous_glob_reexports)]
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use mint_vertical_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use mint_with_substitute::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use native_sol::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use observe_price::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use open_fee_stats::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use open_insurance_fund::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use open_openbook_market::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use open_referral_stats::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use open_series_market::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use open_writer_position::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use option::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use pay_insurance::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use propose_config_admin::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use propose_governance_admin::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use propose_series_authority::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use protocol_fee::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use psy_close_position::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use psy_close_post_expiration::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use psy_exercise_option::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use psy_mint_option::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use queue_emergency_withdrawal::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use record_knockout::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use recover_unclaimed::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use redeem::*;
-#[allow(ambiguous_glob_reexports)]
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use redeem_compact::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use redeem_consideration::*;
-#[allow(ambiguous_glob_reexports)]
-pub use option::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use redeem_exercised_share::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use redeem_native::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use redeem_vertical_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use reset_oracle_reference::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use roll_position::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use schedule_change::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use seed_openbook_market::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use series_transfer::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_approved_mint::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_collateral_basket::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_creation_bond::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_expiry_grid::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_expiry_limits::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_features::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_fee_exempt::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_fee_tiers::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_fees::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_guardian::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_insurance_share::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_mint_allowlist::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_mint_extensions::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_protocol_paused::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_referral_share::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_series_emergency_paused::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_series_paused::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_strike_tick::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_timelock_delay::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_token_account_frozen::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_treasury::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_unclaimed_timeout::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use set_yield_venue::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use settle_expiry::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use settle_forward::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use settle_margin_position::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use settle_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use settle_vault_round::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use settle_vertical_spread::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use simulate_redemption::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use spread_context::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use start_vault_round::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use swap_and_exercise::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use sweep_dust::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use unwind_escrow::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use update_rate_limits::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use update_series_metadata::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use vault_deposit::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use vault_round::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use vault_shares::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use vertical_spread_context::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use withdraw_fees::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use withdraw_from_vault::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use withdraw_margin::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
+pub use yield_context::*;
@@ -0,0 +1,81 @@
+//! Native SOL for series backed by wrapped SOL.
+//!
+//! One mint of such a series is the token program's native mint (both can't
+//! be, they must differ), so the user's associated account on that side
+//! holds WSOL. The `*_native` instructions wrap lamports into it before
+//! the wrapped instruction runs, then close it back to the user. Closing a
+//! native account delivers everything it holds, balance and rent, as lamports.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionContext;
+
+/// The user's WSOL account of a series (collateral or consideration side)
+pub struct NativeAccount<'info> {
+    token_program: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+}
+
+impl<'info> NativeAccount<'info> {
+    /// Finds the user's WSOL account; fails unless one of the series' mints
+    /// is the native mint
+    pub fn of(accounts: &OptionContext<'info>) -> Result<Self> {
+        let account = if accounts.user_collateral_account.is_native() {
+            accounts.user_collateral_account.to_account_info()
+        } else if accounts.user_consideration_account.is_native() {
+            accounts.user_consideration_account.to_account_info()
+        } else {
+            return err!(ErrorCode::NotNativeSeries);
+        };
+        Ok(Self {
+            token_program: accounts.token_program.to_account_info(),
+            account,
+            owner: accounts.user.to_account_info(),
+        })
+    }
+
+    /// Moves `lamports` from the user into their WSOL account and syncs its
+    /// token balance, then reloads both user accounts so the wrapped
+    /// instruction sees it
+    pub fn wrap(accounts: &mut OptionContext<'info>, lamports: u64) -> Result<Self> {
+        let native = Self::of(accounts)?;
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: native.owner.clone(),
+                        to: native.account.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+            token::sync_native(CpiContext::new(
+                native.token_program.clone(),
+                token::SyncNative {
+                    account: native.account.clone(),
+                },
+            ))?;
+            accounts.user_collateral_account.reload()?;
+            accounts.user_consideration_account.reload()?;
+        }
+        Ok(native)
+    }
+
+    /// Closes the WSOL account to the user: what the instruction didn't use
+    /// and whatever it paid out arrive as lamports
+    pub fn unwrap(self) -> Result<()> {
+        token::close_account(CpiContext::new(
+            self.token_program,
+            token::CloseAccount {
+                account: self.account,
+                destination: self.owner.clone(),
+                authority: self.owner,
+            },
+        ))
+    }
+}
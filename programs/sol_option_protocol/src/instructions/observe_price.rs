@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::oracle::{resolve_price, PriceRequest};
+
+/// Permissionless oracle crank: records a guarded reading that later reads
+/// (settlement, margin, price bands) use as their deviation reference
+#[derive(Accounts)]
+pub struct ObservePrice<'info> {
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Consideration mint (validated against stored value in option_context)
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Validated against option_context.primary_oracle by the oracle module
+    pub primary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated against option_context.secondary_oracle by the oracle module
+    pub secondary_oracle: Option<UncheckedAccount<'info>>,
+}
+
+/// Reads the oracle hierarchy at the current time and stores the accepted price
+pub fn handler(ctx: Context<ObservePrice>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+    let now = Clock::get()?.unix_timestamp;
+
+    let primary_account = ctx
+        .accounts
+        .primary_oracle
+        .as_ref()
+        .map(|a| a.to_account_info());
+    let secondary_account = ctx
+        .accounts
+        .secondary_oracle
+        .as_ref()
+        .map(|a| a.to_account_info());
+
+    let request = PriceRequest {
+        reference_time: now,
+        late_window: 0,
+        previous_price: option_context.last_oracle_price,
+        previous_update: option_context.last_oracle_update,
+        strike_exponent: option_context.strike_exponent,
+    };
+
+    let (observed_price, price, source) = resolve_price(
+        &option_context.primary_oracle,
+        primary_account.as_ref(),
+        &option_context.secondary_oracle,
+        secondary_account.as_ref(),
+        &request,
+    )?;
+
+    // Never move the reference backwards in time
+    require!(
+        price.publish_time > option_context.last_oracle_update,
+        ErrorCode::StaleOraclePrice
+    );
+
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.last_oracle_price = observed_price;
+    option_context.last_oracle_update = price.publish_time;
+
+    msg!(
+        "Observed price {} (source: {:?}, published {})",
+        observed_price,
+        source,
+        price.publish_time
+    );
+
+    Ok(())
+}
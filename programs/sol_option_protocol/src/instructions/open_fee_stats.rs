@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::FeeStats;
+
+/// User opting in to volume tracking for fee tiers in one quote mint
+#[derive(Accounts)]
+pub struct OpenFeeStats<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + FeeStats::INIT_SPACE,
+        seeds = [FeeStats::SEED, user.key().as_ref(), consideration_mint.key().as_ref()],
+        bump
+    )]
+    pub fee_stats: Account<'info, FeeStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Starts empty: only trades made after opening (with the account passed) count
+pub fn handler(ctx: Context<OpenFeeStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.fee_stats;
+    stats.user = ctx.accounts.user.key();
+    stats.consideration_mint = ctx.accounts.consideration_mint.key();
+    stats.volume = 0;
+    stats.bump = ctx.bumps.fee_stats;
+
+    msg!(
+        "Opened fee stats for {} in {}",
+        stats.user,
+        stats.consideration_mint
+    );
+
+    Ok(())
+}
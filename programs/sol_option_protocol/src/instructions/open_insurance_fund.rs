@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::InsuranceFundOpened;
+use crate::state::{InsuranceFund, ProtocolConfig};
+
+/// Admin-only creation of the insurance fund of one mint
+#[derive(Accounts)]
+pub struct OpenInsuranceFund<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + InsuranceFund::INIT_SPACE,
+        seeds = [InsuranceFund::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The fund holds its tokens in any token account it owns (usually its
+/// associated account), which fees then pass alongside the fund
+pub fn handler(ctx: Context<OpenInsuranceFund>) -> Result<()> {
+    let fund = &mut ctx.accounts.insurance_fund;
+    fund.mint = ctx.accounts.mint.key();
+    fund.contributed = 0;
+    fund.paid_out = 0;
+    fund.bump = ctx.bumps.insurance_fund;
+
+    emit!(InsuranceFundOpened {
+        insurance_fund: fund.key(),
+        mint: fund.mint,
+    });
+
+    msg!("Opened insurance fund for {}", fund.mint);
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::OpenbookMarketOpened;
+use crate::instructions::OptionData;
+use crate::openbook::{
+    create_market_ix, market_expiry, market_name, MarketAddresses, OPENBOOK_PROGRAM_ID,
+};
+use crate::utils::validation::validate_not_expired;
+
+/// OpenBook market parameters the series authority chooses
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenbookMarketParams {
+    /// Quote (consideration) units per price lot
+    pub quote_lot_size: i64,
+    /// Base (option token) units per size lot
+    pub base_lot_size: i64,
+    /// Fees in units of 1e-6; a negative maker fee is a rebate
+    pub maker_fee: i64,
+    pub taker_fee: i64,
+}
+
+/// Series authority creating an OpenBook v2 market for its option token
+#[derive(Accounts)]
+pub struct OpenOpenbookMarket<'info> {
+    /// Pays for the market and becomes its fee and close admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ ErrorCode::Unauthorized,
+        has_one = option_mint @ ErrorCode::InvalidOptionMint,
+        has_one = consideration_mint @ ErrorCode::InvalidStrikeCurrency
+    )]
+    pub option_context: Box<Account<'info, OptionData>>,
+
+    #[account(mint::token_program = token_program)]
+    pub option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub consideration_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: OpenBook v2
+    #[account(address = OPENBOOK_PROGRAM_ID @ ErrorCode::InvalidOpenbookProgram)]
+    pub openbook_program: UncheckedAccount<'info>,
+
+    /// New market account (a fresh keypair); created by OpenBook
+    #[account(mut)]
+    pub market: Signer<'info>,
+
+    /// CHECK: Market authority PDA of OpenBook; checked by OpenBook
+    pub market_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Zeroed book side allocated to OpenBook; checked by OpenBook
+    #[account(mut)]
+    pub bids: UncheckedAccount<'info>,
+
+    /// CHECK: Zeroed book side allocated to OpenBook; checked by OpenBook
+    #[account(mut)]
+    pub asks: UncheckedAccount<'info>,
+
+    /// CHECK: Zeroed event heap allocated to OpenBook; checked by OpenBook
+    #[account(mut)]
+    pub event_heap: UncheckedAccount<'info>,
+
+    /// CHECK: Market authority's option token account; created by OpenBook
+    #[account(mut)]
+    pub market_base_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Market authority's consideration account; created by OpenBook
+    #[account(mut)]
+    pub market_quote_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Event authority PDA of OpenBook; checked by OpenBook
+    pub event_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates an OpenBook v2 market trading the series' option token against
+/// its consideration, without oracles and expiring with the series. The
+/// market is named after the series' canonical symbol. The authority keeps
+/// managing it on OpenBook; the series doesn't record it.
+pub fn handler(ctx: Context<OpenOpenbookMarket>, params: OpenbookMarketParams) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let option_context = &accounts.option_context;
+    validate_not_expired(option_context.expiration)?;
+
+    let ix = create_market_ix(
+        &MarketAddresses {
+            market: accounts.market.key(),
+            bids: accounts.bids.key(),
+            asks: accounts.asks.key(),
+            event_heap: accounts.event_heap.key(),
+            base_mint: accounts.option_mint.key(),
+            quote_mint: accounts.consideration_mint.key(),
+            token_program: accounts.token_program.key(),
+        },
+        &accounts.authority.key(),
+        &accounts.authority.key(),
+        market_name(option_context),
+        &params,
+        market_expiry(option_context),
+    );
+    invoke(
+        &ix,
+        &[
+            accounts.market.to_account_info(),
+            accounts.market_authority.to_account_info(),
+            accounts.bids.to_account_info(),
+            accounts.asks.to_account_info(),
+            accounts.event_heap.to_account_info(),
+            accounts.authority.to_account_info(),
+            accounts.market_base_vault.to_account_info(),
+            accounts.market_quote_vault.to_account_info(),
+            accounts.option_mint.to_account_info(),
+            accounts.consideration_mint.to_account_info(),
+            accounts.system_program.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.associated_token_program.to_account_info(),
+            accounts.event_authority.to_account_info(),
+            accounts.openbook_program.to_account_info(),
+        ],
+    )?;
+
+    emit!(OpenbookMarketOpened {
+        option_context: option_context.key(),
+        market: accounts.market.key(),
+        quote_lot_size: params.quote_lot_size,
+        base_lot_size: params.base_lot_size,
+    });
+
+    msg!(
+        "OpenBook market {} opened for {}",
+        accounts.market.key(),
+        option_context.key()
+    );
+
+    Ok(())
+}
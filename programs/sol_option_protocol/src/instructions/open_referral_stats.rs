@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::ReferralStats;
+
+/// Referrer opting in to earning the referral share of fees paid in one mint
+#[derive(Accounts)]
+pub struct OpenReferralStats<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferralStats::INIT_SPACE,
+        seeds = [ReferralStats::SEED, referrer.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub referral_stats: Account<'info, ReferralStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenReferralStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.referral_stats;
+    stats.referrer = ctx.accounts.referrer.key();
+    stats.mint = ctx.accounts.mint.key();
+    stats.earned = 0;
+    stats.referred_fees = 0;
+    stats.bump = ctx.bumps.referral_stats;
+
+    msg!(
+        "Opened referral stats for {} in {}",
+        stats.referrer,
+        stats.mint
+    );
+
+    Ok(())
+}
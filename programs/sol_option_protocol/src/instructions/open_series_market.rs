@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_marketplace::cpi::accounts::{CreateMarket, LinkSeries};
+use spl_marketplace::program::SplMarketplace;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesMarketOpened;
+use crate::instructions::OptionData;
+
+/// Series authority opening the marketplace market its option token trades on
+#[derive(Accounts)]
+pub struct OpenSeriesMarket<'info> {
+    /// Pays for the market and becomes its authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+        constraint = option_context.market == Pubkey::default() @ ErrorCode::MarketAlreadyOpened
+    )]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint @ ErrorCode::InvalidOptionMint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+            @ ErrorCode::InvalidStrikeCurrency
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: Market PDA of the option mint against the consideration mint;
+    /// created by the marketplace unless someone already opened it
+    #[account(
+        mut,
+        seeds = [b"market", option_mint.key().as_ref(), consideration_mint.key().as_ref()],
+        bump,
+        seeds::program = marketplace_program.key()
+    )]
+    pub market: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the market trading the series' option token (quoted in its
+/// consideration) on the series. Creates the market and links it to the
+/// series when it doesn't exist yet; a market someone else opened first is
+/// adopted as is, since the marketplace lets anyone create it. One-time.
+pub fn handler(ctx: Context<OpenSeriesMarket>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let program = accounts.marketplace_program.to_account_info();
+
+    if accounts.market.data_is_empty() {
+        spl_marketplace::cpi::create_market(CpiContext::new(
+            program.clone(),
+            CreateMarket {
+                creator: accounts.authority.to_account_info(),
+                base_mint: accounts.option_mint.to_account_info(),
+                quote_mint: accounts.consideration_mint.to_account_info(),
+                market: accounts.market.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+            },
+        ))?;
+        spl_marketplace::cpi::link_series(CpiContext::new(
+            program,
+            LinkSeries {
+                authority: accounts.authority.to_account_info(),
+                market: accounts.market.to_account_info(),
+                series: accounts.option_context.to_account_info(),
+            },
+        ))?;
+    }
+
+    let market = ctx.accounts.market.key();
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.market = market;
+
+    emit!(SeriesMarketOpened {
+        option_context: option_context.key(),
+        market,
+    });
+
+    msg!(
+        "Series {} trades on market {}",
+        option_context.key(),
+        market
+    );
+
+    Ok(())
+}
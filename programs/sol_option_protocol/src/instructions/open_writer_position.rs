@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::OptionData;
+use crate::state::WriterPosition;
+
+/// Writer opting in to per-series position tracking
+#[derive(Accounts)]
+pub struct OpenWriterPosition<'info> {
+    #[account(mut)]
+    pub writer: Signer<'info>,
+
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        init,
+        payer = writer,
+        space = 8 + WriterPosition::INIT_SPACE,
+        seeds = [WriterPosition::SEED, option_context.key().as_ref(), writer.key().as_ref()],
+        bump
+    )]
+    pub writer_position: Account<'info, WriterPosition>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Starts empty: only mints made after opening (with the position passed) count
+pub fn handler(ctx: Context<OpenWriterPosition>) -> Result<()> {
+    let position = &mut ctx.accounts.writer_position;
+    position.option_context = ctx.accounts.option_context.key();
+    position.writer = ctx.accounts.writer.key();
+    position.outstanding = 0;
+    position.total_written = 0;
+    position.margin_written = 0;
+    position.margin_collateral = 0;
+    position.spread_series = Pubkey::default();
+    position.spread_strike = 0;
+    position.spread_locked = 0;
+    position.health_bps = u64::MAX;
+    position.bump = ctx.bumps.writer_position;
+
+    msg!(
+        "Opened writer position for {} in series {}",
+        position.writer,
+        position.option_context
+    );
+
+    Ok(())
+}
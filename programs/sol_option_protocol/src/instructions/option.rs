@@ -1,5 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::metadata::TOKEN_METADATA_PROGRAM_ID;
+use crate::oracle::{OracleFeed, SettlementSource};
+use crate::state::{
+    ApprovedMint, CollateralBasket, CreatorStats, FeeStats, InsuranceFund, ProtocolConfig,
+    ReferralStats, StrikeTick, WriterPosition,
+};
+use crate::utils::math::{
+    calculate_funding_index, calculate_put_collateral, calculate_strike_value,
+};
 
 /// Core data struct stored on-chain representing an option series
 ///
@@ -9,32 +21,771 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 /// - consideration_mint
 /// - strike_price
 /// - expiration
-/// - is_put
+/// - option_type (as a u8)
 ///
 /// Stored Data (NOT used in PDA derivation, but stored in the account):
 /// - Derived PDAs (option_mint, redemption_mint, vaults)
 /// - Runtime tracking (total_supply, exercised_amount)
+/// - Settlement (oracle hierarchy and the recorded settlement price)
+/// - Off-chain metadata URI (set at creation; changeable only while empty or
+///   before any options are minted)
+///
+/// Layout: fixed-size fields come first so their byte offsets are stable for
+/// getProgramAccounts memcmp filters (see the `*_OFFSET` constants below).
+/// They end with the schema fields (`version` and the `reserved` space new
+/// fields are carved from), so raw readers find the version at
+/// `VERSION_OFFSET`. Variable-length fields (Strings) must always stay at the
+/// end. Series written with the legacy layout (`LEGACY_SIZE` bytes, `is_put`
+/// where `option_type` sits) share the leading fields byte for byte;
+/// `migrate_series` grows them and backfills the rest.
 #[account]
+#[derive(InitSpace, Default)]
 pub struct OptionData {
     // === CORE PARAMETERS (used in PDA derivation) ===
-    pub collateral_mint: Pubkey,      // The collateral token mint
-    pub consideration_mint: Pubkey,   // The strike currency mint (e.g., USDC)
-    pub strike_price: u64,            // Strike price
-    pub expiration: i64,              // Expiration timestamp
-    pub is_put: bool,                 // Put or Call option
-    pub bump: u8,                     // PDA bump seed
+    pub collateral_mint: Pubkey,    // The collateral token mint
+    pub consideration_mint: Pubkey, // The strike currency mint (e.g., USDC)
+    pub strike_price: u64,          // Strike price
+    pub expiration: i64,            // Expiration timestamp
+    pub option_type: OptionType,    // Contract kind (Call, Put or binary); one byte
+    pub bump: u8,                   // PDA bump seed
 
     // === DERIVED ADDRESSES (stored for convenience, NOT in PDA seeds) ===
-    pub option_mint: Pubkey,          // Option token mint PDA
-    pub redemption_mint: Pubkey,      // Redemption token mint PDA
-    pub collateral_vault: Pubkey,     // Collateral vault PDA
-    pub consideration_vault: Pubkey,  // Consideration vault PDA
+    pub option_mint: Pubkey,         // Option token mint PDA
+    pub redemption_mint: Pubkey,     // Redemption token mint PDA
+    pub collateral_vault: Pubkey,    // Collateral vault PDA
+    pub consideration_vault: Pubkey, // Consideration vault PDA
 
     // === RUNTIME DATA (tracked over time) ===
-    pub total_supply: u64,            // Total option tokens minted
-    pub exercised_amount: u64,        // Total options exercised
+    pub total_supply: u64,     // Total option tokens minted
+    pub exercised_amount: u64, // Total options exercised
+
+    // === SETTLEMENT (oracle hierarchy, recorded by settle_expiry) ===
+    pub primary_oracle: OracleFeed, // Preferred settlement price feed
+    pub secondary_oracle: OracleFeed, // Fallback feed used when the primary is stale or missing
+    pub settlement_price: u64,      // Settlement price in strike units (0 until settled)
+    pub settlement_source: SettlementSource, // Which feed produced settlement_price
+    pub last_oracle_price: u64,     // Last guarded reading in strike units (deviation reference)
+    pub last_oracle_update: i64,    // Publish time of last_oracle_price
+
+    // === AUTHORITY (two-step transfer via propose/accept) ===
+    pub authority: Pubkey,         // Series authority (creator by default)
+    pub pending_authority: Pubkey, // Proposed successor; default() when none
+
+    // === STATUS (read by linked marketplace markets at STATUS_OFFSET) ===
+    pub status: u8, // Bit flags; any non-zero value halts trading
+
+    // === EXERCISE STYLE (immutable after creation) ===
+    pub exercise_style: ExerciseStyle, // American (any time) or European (window after expiry)
+    pub exercise_window: u32,          // European exercise window in seconds; 0 for American
+    pub exercise_cutoff: u32,          // American: exercise closes this many seconds before expiry
+    pub settlement_delay: u32,         // Seconds after exercise closes before redeem opens
+
+    // === EXPIRY SNAPSHOT (recorded once by settle_expiry; redeem pays from it) ===
+    pub settled_at: i64, // Settlement time; 0 until settled (series frozen after)
+    pub settled_collateral: u64, // Collateral owed to redemption tokens after netting
+    pub settled_consideration: u64, // Consideration owed to redemption tokens after netting
+    pub settled_supply: u64, // Redemption tokens outstanding at settlement
+
+    // === EXPIRY NETTING (ITM options netted at the settlement price) ===
+    pub settled_option_supply: u64, // Option tokens outstanding at settlement
+    pub net_option_collateral: u64, // Collateral owed to option tokens (ITM calls)
+    pub net_option_consideration: u64, // Consideration owed to option tokens (ITM puts)
+
+    // === EARLY CLAIMS (redeem_consideration) ===
+    pub exercised_claimed: u64, // Exercised units already converted by redemption holders
+
+    // === VAULT ACCOUNTING (payouts never read live vault balances) ===
+    pub collateral_deposited: u64, // Collateral credited to the collateral vault by the program
+    pub collateral_paid: u64,      // Collateral paid out of the collateral vault
+    pub consideration_received: u64, // Consideration credited to the consideration vault
+    pub consideration_paid: u64,   // Consideration paid out of the consideration vault
+
+    // === SHORT PAYOUTS (redeem and redeem_consideration) ===
+    pub consideration_claimed: u64, // Consideration paid to redemption token holders
+    pub collateral_redeemed: u64,   // Collateral paid to redemption token holders
+
+    // === MARGIN (partially collateralized writing through WriterPositions) ===
+    pub initial_margin_bps: u16, // Buffer required to write or withdraw; 0 disables margin
+    pub maintenance_margin_bps: u16, // Buffer below which a position can be liquidated
+    pub margin_written: u64,     // Options written on margin and not yet bought back
+    pub margin_collateral: u64,  // Margin held in the backing vault, outside vault accounting
+    pub spread_hedged: u64,      // Long options locked in spreads against margin positions
+
+    // === CREATION BOND (anti-spam, refunded when the series is closed) ===
+    pub creation_bond: u64, // Lamports held in this account on top of its rent
+
+    // === CONTRACT SIZING (immutable after creation) ===
+    pub lot_size: u64, // Mint/exercise/burn amounts must be multiples of this; 0 = any
+    pub min_mint_amount: u64, // Smallest amount a single mint may write; 0 = any
+    pub contract_multiplier: u64, // Collateral units one option token covers (1 = 1:1)
+
+    // === STRIKE UNITS (immutable after creation) ===
+    pub strike_exponent: u8, // Decimals strike_price is quoted in (consideration per whole collateral)
+    pub strike_scale: u8, // Divisor exponent of strike math: collateral + strike_exponent - consideration decimals
+
+    // === BINARY PAYOUT (immutable after creation) ===
+    pub binary_payout: u64, // Binary series: consideration paid per collateral unit ITM, quoted like the strike; 0 otherwise
+
+    // === KNOCK-OUT BARRIER (barrier immutable after creation) ===
+    pub knockout_barrier: u64, // Price in strike units that kills the series (calls: at/above, puts: at/below); 0 = none
+    pub knocked_out_at: i64,   // Time record_knockout saw the barrier breached; 0 while alive
+
+    // === EMERGENCY FREEZE (guardian, for compromised series) ===
+    pub frozen_at: i64, // Time freeze_series froze the series; 0 unless frozen
+
+    // === FUNDING (perpetual series: longs pay shorts when they exercise) ===
+    pub funding_rate_bps: u32, // Annual funding as bps of the strike; 0 for dated series
+    pub funding_started_at: i64, // Creation time funding accrues from
+    pub funding_index: u64, // Funding owed per whole collateral unit (strike units) as of funding_updated_at
+    pub funding_updated_at: i64, // Last accrual (accrue_funding or exercise)
+    pub funding_collected: u64, // Consideration paid as funding by exercising longs
+    pub funding_claimed: u64, // Funding paid out to redemption holders
+
+    // === COLLATERAL BASKET (governance-approved substitutes for the escrow mint) ===
+    pub accepts_basket: bool, // Writers may escrow substitutes (immutable after creation)
+    pub basket_mints: [Pubkey; CollateralBasket::MAX_MINTS], // Substitutes by slot; default() = unused
+    pub basket_escrow: [u64; CollateralBasket::MAX_MINTS], // Escrow in each substitute's vault, at par
+
+    // === TRANSFER HOOK (permissioned series; immutable after creation) ===
+    pub transfer_hook_program: Pubkey, // Program the mints' transfer hook calls; default() = none
+
+    // === FREEZE AUTHORITY (regulated series; immutable after creation) ===
+    pub freezable: bool, // Series PDA is freeze authority of both mints
+    pub compliance_authority: Pubkey, // Freezes and thaws holder accounts; default() = authority
+
+    // === CREDENTIAL GATE (permissioned series; immutable after creation) ===
+    pub gate_mint: Pubkey, // Credential writers and exercisers must hold; default() = open
+
+    // === YIELD (escrow lent to a governance-approved venue; opt-in before writing) ===
+    pub yield_venue: Pubkey, // YieldVenue the escrow is lent through; default() = none
+    pub yield_principal: u64, // Escrow lent out at par and not yet unwound
+    pub yield_shares: u64,   // Venue shares held for it in the series' share vault
+
+    // === MARKETPLACE (set once by open_series_market) ===
+    pub market: Pubkey, // Market trading the option token; default() = none
+
+    // === SCHEMA (new fields take their bytes from `reserved`; migrate_series upgrades) ===
+    pub version: u8, // Layout version (at VERSION_OFFSET); 0 before versioning
+    pub reserved: [u64; 16], // Zeroed space for future fields
+
+    // === METADATA (the authority can set the URI while empty or before any mint) ===
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String, // Off-chain JSON (descriptions, project info, risk disclosures); empty if unset
+    #[max_len(MAX_UNDERLYING_SYMBOL_LEN)]
+    pub underlying_symbol: String, // Ticker used for the canonical option symbol (e.g. "SOL")
+}
+
+impl OptionData {
+    // memcmp filter offsets (include the 8-byte account discriminator)
+    pub const COLLATERAL_MINT_OFFSET: usize = 8;
+    pub const CONSIDERATION_MINT_OFFSET: usize = 40;
+    pub const STRIKE_PRICE_OFFSET: usize = 72;
+    pub const EXPIRATION_OFFSET: usize = 80;
+    pub const OPTION_TYPE_OFFSET: usize = 88;
+    pub const OPTION_MINT_OFFSET: usize = 90;
+    pub const REDEMPTION_MINT_OFFSET: usize = 122;
+    pub const AUTHORITY_OFFSET: usize = 341;
+    pub const STATUS_OFFSET: usize = 405;
+    pub const VERSION_OFFSET: usize = 927;
+
+    /// Bytes `version` and `reserved` take up
+    pub const SCHEMA_LEN: usize = 1 + 16 * 8;
+
+    /// Bytes a legacy series serialized (through `exercised_amount`); its
+    /// `is_put` byte reads as `option_type` Call or Put
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 4 * 32 + 8 + 8;
+
+    /// Layout version new series are written with and migrate_series upgrades to
+    pub const CURRENT_VERSION: u8 = 1;
+
+    // `status` bit flags
+    pub const STATUS_PAUSED: u8 = 1 << 0;
+    pub const STATUS_KNOCKED_OUT: u8 = 1 << 1;
+    pub const STATUS_EMERGENCY_PAUSED: u8 = 1 << 2;
+    pub const STATUS_FROZEN: u8 = 1 << 3;
+    /// Put migrated from the legacy layout: it escrows collateral like a
+    /// call, so it can only be burned and redeemed, and settles without a price
+    pub const STATUS_LEGACY_PUT: u8 = 1 << 4;
+
+    /// Steps a series written with an older layout up to `CURRENT_VERSION`;
+    /// fields added since read as zero until their step backfills them.
+    /// Version 0 is the legacy layout, backfilled from `legacy`.
+    pub fn migrate(&mut self, legacy: Option<&LegacyState>) -> Result<()> {
+        while self.version < Self::CURRENT_VERSION {
+            if self.version == 0 {
+                self.backfill_legacy(legacy.ok_or(ErrorCode::LegacySeriesAccounts)?)?;
+            }
+            self.version += 1;
+        }
+        Ok(())
+    }
+
+    /// 0 → 1: a legacy series gains the sizing, strike units, vault ledger
+    /// and authority it predates. Everything else keeps its zero default.
+    fn backfill_legacy(&mut self, legacy: &LegacyState) -> Result<()> {
+        // Legacy puts escrowed collateral like calls, which no put does now;
+        // they keep that escrow in a burn-and-redeem-only mode
+        if self.option_type == OptionType::Put {
+            self.status |= Self::STATUS_LEGACY_PUT;
+        }
+        self.contract_multiplier = 1;
+        // Legacy strikes are consideration units per whole collateral unit
+        self.strike_exponent = legacy.consideration_decimals;
+        self.strike_scale = legacy.collateral_decimals;
+        // Legacy vaults held nothing but the series' funds
+        self.collateral_deposited = legacy.collateral_held;
+        self.consideration_received = legacy.consideration_held;
+        self.authority = legacy.authority;
+        Ok(())
+    }
+
+    /// Zeroes everything past the fields of a legacy series grown to the
+    /// current size, so the fields added since read as zero and the Strings
+    /// as empty
+    pub fn open_legacy_fields(data: &mut [u8]) {
+        data[Self::LEGACY_SIZE..].fill(0);
+    }
+
+    /// Puts with the current put payoff (legacy puts pay out like calls)
+    pub fn is_put(&self) -> bool {
+        self.payoff_type() == OptionType::Put
+    }
+
+    pub fn is_legacy_put(&self) -> bool {
+        self.status & Self::STATUS_LEGACY_PUT != 0
+    }
+
+    /// Contract kind the escrow and payout math follows: `option_type`, or
+    /// Call for a legacy put, which escrowed collateral like one
+    pub fn payoff_type(&self) -> OptionType {
+        if self.is_legacy_put() {
+            OptionType::Call
+        } else {
+            self.option_type
+        }
+    }
+
+    /// Cash-or-nothing series: never exercised, settled in cash at expiry
+    pub fn is_binary(&self) -> bool {
+        self.option_type.is_binary()
+    }
+
+    /// Forwards: fully funded, never exercised, delivered at the strike at expiry
+    pub fn is_forward(&self) -> bool {
+        self.option_type == OptionType::Forward
+    }
+
+    /// Whether writers escrow consideration (puts and binaries) rather than
+    /// the collateral itself (calls and forwards)
+    pub fn escrows_consideration(&self) -> bool {
+        !matches!(self.payoff_type(), OptionType::Call | OptionType::Forward)
+    }
+
+    /// Paused by the series authority or, in an emergency, the admin or guardian
+    pub fn is_paused(&self) -> bool {
+        self.status & (Self::STATUS_PAUSED | Self::STATUS_EMERGENCY_PAUSED) != 0
+    }
+
+    /// Key that freezes and thaws holders' accounts of a freezable series:
+    /// the compliance authority, or the series authority when none was set
+    pub fn compliance_key(&self) -> Pubkey {
+        if self.compliance_authority == Pubkey::default() {
+            self.authority
+        } else {
+            self.compliance_authority
+        }
+    }
+
+    /// Perpetual series never expire (see `PERPETUAL_EXPIRATION`)
+    pub fn is_perpetual(&self) -> bool {
+        self.expiration == PERPETUAL_EXPIRATION
+    }
+
+    /// Brings `funding_index` up to `now`: the funding accrued since creation
+    /// at the series' fixed rate (computed from the start, so frequent
+    /// accruals can't round it away)
+    pub fn accrue_funding(&mut self, now: i64) -> Result<()> {
+        if self.funding_rate_bps == 0 || now <= self.funding_updated_at {
+            return Ok(());
+        }
+        self.funding_index = calculate_funding_index(
+            self.strike_price,
+            self.funding_rate_bps,
+            now.saturating_sub(self.funding_started_at),
+        )?;
+        self.funding_updated_at = now;
+        Ok(())
+    }
+
+    /// Funding `underlying` collateral units owe at the current index, in
+    /// consideration (rounded down)
+    pub fn funding_owed(&self, underlying: u64) -> Result<u64> {
+        calculate_strike_value(underlying, self.funding_index, self.strike_scale)
+    }
+
+    pub fn is_knocked_out(&self) -> bool {
+        self.status & Self::STATUS_KNOCKED_OUT != 0
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.status & Self::STATUS_FROZEN != 0
+    }
+
+    /// Whether `price` is at or beyond the knock-out barrier (false without one)
+    pub fn barrier_breached(&self, price: u64) -> bool {
+        if self.knockout_barrier == 0 {
+            false
+        } else if self.option_type.is_put_payoff() {
+            price <= self.knockout_barrier
+        } else {
+            price >= self.knockout_barrier
+        }
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.settled_at != 0
+    }
+
+    /// End (exclusive) of the European exercise window; equals expiration for American
+    pub fn exercise_window_end(&self) -> Result<i64> {
+        self.expiration
+            .checked_add(self.exercise_window as i64)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))
+    }
+
+    /// Time redeem opens: the end of exercise (the knock-out, for knocked-out
+    /// series) plus the settlement grace period; frozen series skip the grace
+    /// period and open at the freeze
+    pub fn redemption_opens_at(&self) -> Result<i64> {
+        if self.is_frozen() {
+            return Ok(self.frozen_at);
+        }
+        let exercise_end = if self.is_knocked_out() {
+            self.knocked_out_at
+        } else {
+            self.exercise_window_end()?
+        };
+        exercise_end
+            .checked_add(self.settlement_delay as i64)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))
+    }
+
+    /// Collateral units `amount` option tokens cover (what every strike,
+    /// backing and payoff calculation is applied to)
+    pub fn underlying_amount(&self, amount: u64) -> Result<u64> {
+        amount
+            .checked_mul(self.contract_multiplier)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))
+    }
+
+    pub fn margin_enabled(&self) -> bool {
+        self.initial_margin_bps > 0
+    }
+
+    /// Vault that holds margin: the asset writers escrow (collateral for calls,
+    /// consideration for puts)
+    pub fn margin_vault(&self) -> Pubkey {
+        if self.escrows_consideration() {
+            self.consideration_vault
+        } else {
+            self.collateral_vault
+        }
+    }
+
+    /// Mint of the asset writers escrow (held in `margin_vault`)
+    pub fn margin_mint(&self) -> Pubkey {
+        if self.escrows_consideration() {
+            self.consideration_mint
+        } else {
+            self.collateral_mint
+        }
+    }
+
+    /// Collateral the series accounts for; tokens donated to the vault are ignored
+    pub fn collateral_balance(&self) -> u64 {
+        self.collateral_deposited
+            .saturating_sub(self.collateral_paid)
+    }
+
+    /// Consideration the series accounts for; tokens donated to the vault are ignored
+    pub fn consideration_balance(&self) -> u64 {
+        self.consideration_received
+            .saturating_sub(self.consideration_paid)
+    }
+
+    /// How far `live_balance` falls short of what `vault` should hold: its
+    /// accounted balance, plus margin and less escrow lent out for the backing
+    /// vault (0 for other accounts)
+    pub fn vault_shortfall(&self, vault: &Pubkey, live_balance: u64) -> u64 {
+        let accounted = if *vault == self.collateral_vault {
+            self.collateral_balance()
+        } else if *vault == self.consideration_vault {
+            self.consideration_balance()
+        } else {
+            return 0;
+        };
+        let (margin, lent) = if *vault == self.margin_vault() {
+            (self.margin_collateral, self.yield_principal)
+        } else {
+            (0, 0)
+        };
+        accounted
+            .saturating_add(margin)
+            .saturating_sub(lent)
+            .saturating_sub(live_balance)
+    }
+
+    pub fn credit_collateral(&mut self, amount: u64) -> Result<()> {
+        self.collateral_deposited = self
+            .collateral_deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Fails if the payout exceeds the accounted balance, whatever the vault holds
+    pub fn debit_collateral(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.collateral_balance(),
+            ErrorCode::InsufficientCollateral
+        );
+        self.collateral_paid = self
+            .collateral_paid
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn credit_consideration(&mut self, amount: u64) -> Result<()> {
+        self.consideration_received = self
+            .consideration_received
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Fails if the payout exceeds the accounted balance, whatever the vault holds
+    pub fn debit_consideration(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.consideration_balance(),
+            ErrorCode::InsufficientCollateral
+        );
+        self.consideration_paid = self
+            .consideration_paid
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// What writers escrow for `underlying` collateral units: the collateral
+    /// itself for calls and forwards, the strike value in consideration for
+    /// puts and the binary payout for binaries (rounded up, see
+    /// `calculate_put_collateral`)
+    pub fn escrow_locked(&self, underlying: u64) -> Result<u64> {
+        match self.payoff_type() {
+            OptionType::Call | OptionType::Forward => Ok(underlying),
+            OptionType::Put => {
+                calculate_put_collateral(underlying, self.strike_price, self.strike_scale)
+            }
+            OptionType::BinaryCall | OptionType::BinaryPut => {
+                calculate_put_collateral(underlying, self.binary_payout, self.strike_scale)
+            }
+        }
+    }
+
+    /// What the escrow pays out for `underlying` collateral units (burns and
+    /// exercises); consideration rounds down so split payouts never exceed
+    /// the deposits
+    pub fn escrow_payout(&self, underlying: u64) -> Result<u64> {
+        match self.payoff_type() {
+            OptionType::Call | OptionType::Forward => Ok(underlying),
+            OptionType::Put => {
+                calculate_strike_value(underlying, self.strike_price, self.strike_scale)
+            }
+            OptionType::BinaryCall | OptionType::BinaryPut => {
+                calculate_strike_value(underlying, self.binary_payout, self.strike_scale)
+            }
+        }
+    }
+
+    /// The largest amount, at most `amount` and in whole lots, whose escrow and
+    /// strike lock fit in what the vaults actually received (`amount` itself
+    /// unless the mint withheld a transfer fee on the deposit)
+    pub fn covered_amount(
+        &self,
+        amount: u64,
+        escrow_received: u64,
+        strike_received: u64,
+    ) -> Result<u64> {
+        let covers = |amount: u64| -> Result<bool> {
+            let underlying = self.underlying_amount(amount)?;
+            Ok(self.escrow_locked(underlying)? <= escrow_received
+                && self.strike_locked(underlying)? <= strike_received)
+        };
+        if covers(amount)? {
+            return Ok(amount);
+        }
+
+        // Start from the received share of the deposit, then step down by lots
+        // past the rounding up of consideration deposits
+        let underlying = self.underlying_amount(amount)?;
+        let share = |received: u64, locked: u64| -> u64 {
+            if locked == 0 {
+                amount
+            } else {
+                (amount as u128 * received as u128 / locked as u128) as u64
+            }
+        };
+        let step = self.lot_size.max(1);
+        let mut covered = share(escrow_received, self.escrow_locked(underlying)?)
+            .min(share(strike_received, self.strike_locked(underlying)?));
+        covered -= covered % step;
+        while covered > 0 && !covers(covered)? {
+            covered -= step;
+        }
+        Ok(covered)
+    }
+
+    /// Consideration minting locks besides the escrow for `underlying` units:
+    /// the strike value the long side pays for forwards (rounded up), nothing
+    /// for the other types
+    pub fn strike_locked(&self, underlying: u64) -> Result<u64> {
+        if self.is_forward() {
+            calculate_put_collateral(underlying, self.strike_price, self.strike_scale)
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// What burning `underlying` units refunds of `strike_locked` (rounded down)
+    pub fn strike_payout(&self, underlying: u64) -> Result<u64> {
+        if self.is_forward() {
+            calculate_strike_value(underlying, self.strike_price, self.strike_scale)
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Escrow held in substitute vaults (the primary vault holds the rest)
+    pub fn basket_escrow_total(&self) -> u64 {
+        self.basket_escrow.iter().sum()
+    }
+
+    /// Slot of `mint` among the series' substitutes, taking the first free
+    /// one for a new substitute; None when every slot is taken
+    pub fn basket_slot(&self, mint: &Pubkey) -> Option<usize> {
+        self.basket_mints
+            .iter()
+            .position(|m| m == mint)
+            .or_else(|| {
+                self.basket_mints
+                    .iter()
+                    .position(|m| *m == Pubkey::default())
+            })
+    }
+
+    /// Splits an escrow payout between the substitute vaults, pro rata to
+    /// what each holds (rounded up and capped at the payout), and the primary
+    /// vault, which pays the rest and so never more than its own share
+    pub fn split_escrow_payout(
+        &self,
+        amount: u64,
+    ) -> Result<(u64, [u64; CollateralBasket::MAX_MINTS])> {
+        let mut shares = [0u64; CollateralBasket::MAX_MINTS];
+        let escrow = self.escrow_balance();
+        if self.basket_escrow_total() == 0 || escrow == 0 {
+            return Ok((amount, shares));
+        }
+
+        let mut remaining = amount;
+        for (share, held) in shares.iter_mut().zip(self.basket_escrow) {
+            let pro_rata = (amount as u128)
+                .checked_mul(held as u128)
+                .ok_or(ErrorCode::MathOverflow)?
+                .div_ceil(escrow as u128);
+            *share = u64::try_from(pro_rata)
+                .map_err(|_| error!(ErrorCode::MathOverflow))?
+                .min(held)
+                .min(remaining);
+            remaining -= *share;
+        }
+        Ok((remaining, shares))
+    }
+
+    /// Writers' escrow still accounted for (collateral for calls,
+    /// consideration for puts and binaries)
+    pub fn escrow_balance(&self) -> u64 {
+        if self.escrows_consideration() {
+            self.consideration_balance()
+        } else {
+            self.collateral_balance()
+        }
+    }
+
+    pub fn credit_escrow(&mut self, amount: u64) -> Result<()> {
+        if self.escrows_consideration() {
+            self.credit_consideration(amount)
+        } else {
+            self.credit_collateral(amount)
+        }
+    }
+
+    pub fn debit_escrow(&mut self, amount: u64) -> Result<()> {
+        if self.escrows_consideration() {
+            self.debit_consideration(amount)
+        } else {
+            self.debit_collateral(amount)
+        }
+    }
+}
+
+/// Expiration of a perpetual series: it never expires, never settles, and
+/// its longs pay funding instead
+pub const PERPETUAL_EXPIRATION: i64 = i64::MAX;
+
+/// Highest annual funding rate a perpetual series may charge (100% of the strike)
+pub const MAX_FUNDING_RATE_BPS: u32 = 10_000;
+
+/// Maximum length of the optional series metadata URI
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+/// Maximum length of the underlying ticker used in canonical symbols
+pub const MAX_UNDERLYING_SYMBOL_LEN: usize = 10;
+
+/// Series settings supplied at creation that are NOT part of the PDA seeds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct SeriesConfig {
+    pub primary_oracle: OracleFeed,
+    pub secondary_oracle: OracleFeed,
+    pub metadata_uri: String,
+    pub underlying_symbol: String,
+    pub exercise_style: ExerciseStyle,
+    pub exercise_window: u32,
+    pub exercise_cutoff: u32,
+    pub settlement_delay: u32,
+    pub initial_margin_bps: u16,
+    pub maintenance_margin_bps: u16,
+    pub lot_size: u64,
+    pub min_mint_amount: u64,
+    /// Collateral units one option token covers; 0 means 1
+    pub contract_multiplier: u64,
+    /// Decimals of `strike_price`; None quotes it in raw consideration units
+    /// (the consideration mint's decimals)
+    pub strike_exponent: Option<u8>,
+    /// Binary series only: consideration paid per collateral unit if the
+    /// series finishes in the money, quoted like the strike
+    pub binary_payout: u64,
+    /// Knock-out barrier in strike units (above the strike for calls, below
+    /// it for puts; needs a primary oracle); 0 means none
+    pub knockout_barrier: u64,
+    /// Perpetual series only: annual funding longs pay shorts, in bps of the
+    /// strike
+    pub funding_rate_bps: u32,
+    /// Lets writers escrow the governance basket's substitutes for the escrow
+    /// mint (not with margin, funding or forwards)
+    pub accepts_basket: bool,
+    /// Compliance program the option and redemption mints' Token-2022
+    /// transfer hook calls (Token-2022 series only); default() means none
+    pub transfer_hook_program: Pubkey,
+    /// Makes the series PDA freeze authority of the option and redemption
+    /// mints, so holders' accounts can be frozen through
+    /// `set_token_account_frozen` (regulated series)
+    pub freezable: bool,
+    /// Key allowed to freeze and thaw a freezable series' token accounts;
+    /// default() leaves it to the series authority
+    pub compliance_authority: Pubkey,
+    /// Credential mint (e.g. a KYC NFT or soulbound token) users must hold
+    /// to mint or exercise; default() leaves the series permissionless
+    pub gate_mint: Pubkey,
+}
+
+/// Live state of a legacy series' mints and vaults, which `migrate` backfills
+/// its strike units and vault ledger from, and the authority it is given
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LegacyState {
+    pub collateral_decimals: u8,
+    pub consideration_decimals: u8,
+    pub collateral_held: u64,
+    pub consideration_held: u64,
+    /// Legacy series had no authority; they get the protocol admin
+    pub authority: Pubkey,
+}
+
+/// Kind of contract a series writes; stored (and used in the PDA seeds) as its
+/// one-byte discriminant, so new kinds can be added without moving any address
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+#[repr(u8)]
+pub enum OptionType {
+    /// Right to buy collateral at the strike
+    #[default]
+    Call = 0,
+    /// Right to sell collateral at the strike
+    Put = 1,
+    /// Cash-or-nothing: pays `binary_payout` in consideration if the
+    /// settlement price finishes above the strike
+    BinaryCall = 2,
+    /// Cash-or-nothing: pays `binary_payout` in consideration if the
+    /// settlement price finishes below the strike
+    BinaryPut = 3,
+    /// Fully funded forward: no optionality, holders receive the collateral
+    /// and writers the strike at expiry, whatever the price
+    Forward = 4,
+}
+
+impl OptionType {
+    pub fn is_binary(self) -> bool {
+        matches!(self, Self::BinaryCall | Self::BinaryPut)
+    }
+
+    /// Pays when the price finishes below the strike (puts and binary puts)
+    pub fn is_put_payoff(self) -> bool {
+        matches!(self, Self::Put | Self::BinaryPut)
+    }
+
+    pub fn is_forward(self) -> bool {
+        self == Self::Forward
+    }
+}
+
+impl TryFrom<u8> for OptionType {
+    type Error = anchor_lang::error::Error;
+
+    /// Parses a PDA seed or account byte, rejecting unknown kinds
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Call),
+            1 => Ok(Self::Put),
+            2 => Ok(Self::BinaryCall),
+            3 => Ok(Self::BinaryPut),
+            4 => Ok(Self::Forward),
+            _ => err!(ErrorCode::InvalidOptionType),
+        }
+    }
+}
+
+/// When option holders may exercise
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+pub enum ExerciseStyle {
+    /// Any time before expiration (minus the optional `exercise_cutoff`)
+    #[default]
+    American,
+    /// Only during `[expiration, expiration + exercise_window)`; redemption opens after
+    European,
 }
 
+/// Longest European exercise window a series may configure (7 days)
+pub const MAX_EXERCISE_WINDOW: u32 = 7 * 24 * 60 * 60;
+
+/// Longest American exercise cutoff before expiration a series may configure (1 day)
+pub const MAX_EXERCISE_CUTOFF: u32 = 24 * 60 * 60;
+
+/// Longest grace period between the end of exercise and redemption (7 days)
+pub const MAX_SETTLEMENT_DELAY: u32 = 7 * 24 * 60 * 60;
+
 /// Unified accounts struct for all option operations (mint, burn, exercise, redeem)
 /// Client sends the OptionContext PDA and all account addresses are validated against stored values
 #[derive(Accounts)]
@@ -46,74 +797,162 @@ pub struct OptionContext<'info> {
     #[account(mut)]
     pub option_context: Account<'info, OptionData>,
 
+    /// Protocol config (mint and exercise fees)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     /// Collateral mint (validated against stored value in option_context)
     #[account(
         constraint = collateral_mint.key() == option_context.collateral_mint
     )]
-    pub collateral_mint: Account<'info, Mint>,
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
 
     /// Consideration mint (validated against stored value in option_context)
     #[account(
         constraint = consideration_mint.key() == option_context.consideration_mint
     )]
-    pub consideration_mint: Account<'info, Mint>,
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
 
     /// Option mint (validated against stored value in option_context)
     #[account(
         mut,
         constraint = option_mint.key() == option_context.option_mint
     )]
-    pub option_mint: Account<'info, Mint>,
+    pub option_mint: InterfaceAccount<'info, Mint>,
 
     /// Redemption mint (validated against stored value in option_context)
     #[account(
         mut,
         constraint = redemption_mint.key() == option_context.redemption_mint
     )]
-    pub redemption_mint: Account<'info, Mint>,
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
 
     /// Collateral vault (validated against stored value in option_context)
     #[account(
         mut,
         constraint = collateral_vault.key() == option_context.collateral_vault
     )]
-    pub collateral_vault: Account<'info, TokenAccount>,
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Consideration vault (validated against stored value in option_context)
     #[account(
         mut,
         constraint = consideration_vault.key() == option_context.consideration_vault
     )]
-    pub consideration_vault: Account<'info, TokenAccount>,
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Owner of the long-leg token account; usually the user, but mint
+    /// may send option tokens to anyone (the token program checks burns)
+    pub option_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Owner of the short-leg token account; usually the user, but mint
+    /// may send redemption tokens to anyone (the token program checks burns)
+    pub redemption_owner: UncheckedAccount<'info>,
+
+    /// User's collateral token account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = user,
+    )]
+    pub user_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's consideration token account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = consideration_mint,
+        associated_token::authority = user,
+    )]
+    pub user_consideration_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Option token account of `option_owner` (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = option_mint,
+        associated_token::authority = option_owner,
+    )]
+    pub user_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redemption token account of `redemption_owner` (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = redemption_mint,
+        associated_token::authority = redemption_owner,
+    )]
+    pub user_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 
-    /// User's collateral token account
+    /// User's writer position; mint and burn record into it when passed
+    /// (trailing and optional, so clients that don't track positions omit it)
+    #[account(
+        mut,
+        seeds = [WriterPosition::SEED, option_context.key().as_ref(), user.key().as_ref()],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Option<Account<'info, WriterPosition>>,
+
+    /// Protocol fee account of the charged mint (escrow mint on mint,
+    /// consideration on exercise); only needed while that fee is non-zero
+    #[account(
+        mut,
+        constraint = fee_vault.owner == protocol_config.key() @ ErrorCode::InvalidFeeVault
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// User's volume in the consideration mint; mint and exercise record into
+    /// it and discount the fee by the tier it reaches when passed
+    #[account(
+        mut,
+        seeds = [FeeStats::SEED, user.key().as_ref(), consideration_mint.key().as_ref()],
+        bump = fee_stats.bump
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    /// Referrer's token account of the charged mint; with their stats, takes
+    /// the referral share of the fee
     #[account(mut)]
-    pub user_collateral_account: Account<'info, TokenAccount>,
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    /// User's consideration token account
+    /// Referrer's earnings in the charged mint
     #[account(mut)]
-    pub user_consideration_account: Account<'info, TokenAccount>,
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
 
-    /// User's option token account
+    /// Insurance fund of the charged mint; only needed while the insurance
+    /// share of the fee is non-zero
     #[account(mut)]
-    pub user_option_account: Account<'info, TokenAccount>,
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
 
-    /// User's redemption token account
+    /// Token account of the insurance fund in the charged mint
     #[account(mut)]
-    pub user_redemption_account: Account<'info, TokenAccount>,
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    pub token_program: Program<'info, Token>,
+    /// User's token account of the series' gate mint; mint and exercise of a
+    /// gated series require it
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
-
-
+/// Creating a series: its account and vaults are initialized here, its two
+/// mints in the handler, and both backing mints are read. Every deserialized
+/// account is boxed so the series (and any field it grows) lives on the heap
+/// rather than in the 4KB stack frame of `try_accounts`. OptionData stays a
+/// borsh account, since its Strings and enums rule out zero_copy.
 #[derive(Accounts)]
 #[instruction(
     collateral_mint_key: Pubkey,
     consideration_mint_key: Pubkey,
     strike_price: u64,
     expiration: i64,
-    is_put: bool,
+    option_type: OptionType,
 )]
 pub struct OptionCreate<'info> {
     #[account(mut)]
@@ -123,46 +962,93 @@ pub struct OptionCreate<'info> {
     #[account(
         init,
         payer = user,
-        space = 8 + std::mem::size_of::<OptionData>(),
+        space = 8 + OptionData::INIT_SPACE,
         seeds = [
             b"option_context",
             collateral_mint_key.as_ref(),
             consideration_mint_key.as_ref(),
             strike_price.to_le_bytes().as_ref(),
             expiration.to_le_bytes().as_ref(),
-            &[is_put as u8],
+            &[option_type as u8],
         ],
         bump
     )]
-    pub option_context: Account<'info, OptionData>,
-
-    /// Collateral mint (provided by client)
-    pub collateral_mint: Account<'info, Mint>,
+    pub option_context: Box<Account<'info, OptionData>>,
 
-    /// Consideration/strike currency mint (provided by client)
-    pub consideration_mint: Account<'info, Mint>,
+    /// Protocol config (creation rate limits)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
 
-    /// Option token mint PDA - INITIALIZE it
+    /// Creator's rate-limit tracking account (created on first series)
     #[account(
-        init,
+        init_if_needed,
         payer = user,
+        space = 8 + CreatorStats::INIT_SPACE,
+        seeds = [CreatorStats::SEED, user.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Box<Account<'info, CreatorStats>>,
+
+    /// Collateral mint (provided by client; must match the seed argument)
+    ///
+    /// Owned by `token_program` (SPL Token or Token-2022), like the consideration
+    /// mint; the series' own mints and vaults are created under it too.
+    #[account(
+        constraint = collateral_mint.key() == collateral_mint_key @ ErrorCode::InvalidUnderlyingMint,
+        constraint = collateral_mint_key != consideration_mint_key @ ErrorCode::IdenticalMints,
+        mint::token_program = token_program,
+    )]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Consideration/strike currency mint (provided by client; must match the seed argument)
+    #[account(
+        constraint = consideration_mint.key() == consideration_mint_key @ ErrorCode::InvalidStrikeCurrency,
+        mint::token_program = token_program,
+    )]
+    pub consideration_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Strike tick PDA of the consideration mint; may not exist (any
+    /// strike allowed), only deserialized when governance has created it
+    #[account(
+        seeds = [StrikeTick::SEED, consideration_mint.key().as_ref()],
+        bump
+    )]
+    pub strike_tick: UncheckedAccount<'info>,
+
+    /// CHECK: Approval PDA of the collateral mint; may not exist (not approved),
+    /// only read while the mint allowlist is enabled
+    #[account(
+        seeds = [ApprovedMint::SEED, collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_approval: UncheckedAccount<'info>,
+
+    /// CHECK: Approval PDA of the consideration mint (same as above)
+    #[account(
+        seeds = [ApprovedMint::SEED, consideration_mint.key().as_ref()],
+        bump
+    )]
+    pub consideration_approval: UncheckedAccount<'info>,
+
+    /// CHECK: Option token mint PDA - created and initialized by the handler
+    /// (sized for the transfer hook extension when the series has one)
+    #[account(
+        mut,
         seeds = [b"option_mint", option_context.key().as_ref()],
         bump,
-        mint::decimals = collateral_mint.decimals,
-        mint::authority = option_context,
     )]
-    pub option_mint: Account<'info, Mint>,
+    pub option_mint: UncheckedAccount<'info>,
 
-    /// Redemption token mint PDA - INITIALIZE it
+    /// CHECK: Redemption token mint PDA - created like the option mint
     #[account(
-        init,
-        payer = user,
+        mut,
         seeds = [b"redemption_mint", option_context.key().as_ref()],
         bump,
-        mint::decimals = collateral_mint.decimals,
-        mint::authority = option_context,
     )]
-    pub redemption_mint: Account<'info, Mint>,
+    pub redemption_mint: UncheckedAccount<'info>,
 
     /// Collateral vault PDA - INITIALIZE it
     #[account(
@@ -173,7 +1059,7 @@ pub struct OptionCreate<'info> {
         token::mint = collateral_mint,
         token::authority = option_context,
     )]
-    pub collateral_vault: Account<'info, TokenAccount>,
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Consideration vault PDA - INITIALIZE it
     #[account(
@@ -184,9 +1070,24 @@ pub struct OptionCreate<'info> {
         token::mint = consideration_mint,
         token::authority = option_context,
     )]
-    pub consideration_vault: Account<'info, TokenAccount>,
+    pub consideration_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Token Metadata program; optional together with the two metadata
+    /// accounts below (omitted, the series gets no token metadata until
+    /// `update_series_metadata`)
+    #[account(address = TOKEN_METADATA_PROGRAM_ID @ ErrorCode::InvalidMetadataAccount)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metadata PDA of the option mint (checked and created by the
+    /// Token Metadata program CPI)
+    #[account(mut)]
+    pub option_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metadata PDA of the redemption mint (same as above)
+    #[account(mut)]
+    pub redemption_metadata: Option<UncheckedAccount<'info>>,
 }
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::InsurancePaidOut;
+use crate::instructions::OptionData;
+use crate::state::{InsuranceFund, ProtocolConfig};
+use crate::utils::validation::validate_amount;
+
+/// Admin-only payout from an insurance fund into a series vault
+#[derive(Accounts)]
+pub struct PayInsurance<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::SEED, mint.key().as_ref()],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Token account of the fund (any token account it owns)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = insurance_fund,
+    )]
+    pub insurance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub option_context: Account<'info, OptionData>,
+
+    /// Collateral or consideration vault of the series
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = vault.key() == option_context.collateral_vault
+            || vault.key() == option_context.consideration_vault
+            @ ErrorCode::InvalidInsuranceFund
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Tops a series vault back up to what its accounting says it holds (e.g.
+/// after a fee-on-transfer mishap); pays at most the shortfall, and the token
+/// program refuses more than the fund holds
+pub fn handler(ctx: Context<PayInsurance>, amount: u64) -> Result<()> {
+    validate_amount(amount)?;
+    let vault = &ctx.accounts.vault;
+    let shortfall = ctx
+        .accounts
+        .option_context
+        .vault_shortfall(&vault.key(), vault.amount);
+    require!(amount <= shortfall, ErrorCode::NoVaultShortfall);
+
+    let mint_key = ctx.accounts.mint.key();
+    let bump = ctx.accounts.insurance_fund.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[InsuranceFund::SEED, mint_key.as_ref(), &[bump]]];
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::TransferChecked {
+                from: ctx.accounts.insurance_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.insurance_fund.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let fund = &mut ctx.accounts.insurance_fund;
+    fund.record_payout(amount)?;
+
+    emit!(InsurancePaidOut {
+        option_context: ctx.accounts.option_context.key(),
+        mint: mint_key,
+        vault: ctx.accounts.vault.key(),
+        amount,
+        shortfall,
+        paid_out: fund.paid_out,
+    });
+
+    msg!(
+        "Insurance paid {} of {} into {} (shortfall {})",
+        amount,
+        mint_key,
+        ctx.accounts.vault.key(),
+        shortfall
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigAdminProposed;
+use crate::state::ProtocolConfig;
+
+/// Current admin nominating a successor (step 1 of 2)
+#[derive(Accounts)]
+pub struct ProposeConfigAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Records `new_admin` as pending; `Pubkey::default()` cancels a proposal
+pub fn handler(ctx: Context<ProposeConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    config.pending_admin = new_admin;
+
+    emit!(ConfigAdminProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+    });
+
+    msg!("Protocol admin proposed: {}", new_admin);
+
+    Ok(())
+}
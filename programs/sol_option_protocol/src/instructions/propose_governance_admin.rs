@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::{ConfigAdminProposed, GovernanceAdminProposed};
+use crate::governance::governance_treasury;
+use crate::state::ProtocolConfig;
+
+/// Current admin nominating a Realms governance as successor (step 1 of 2)
+#[derive(Accounts)]
+pub struct ProposeGovernanceAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Governance account of the DAO, checked by its account type tag;
+    /// its owner is the governance program the treasury is derived under
+    pub governance: UncheckedAccount<'info>,
+}
+
+/// Records the governance's native treasury as pending admin; a proposal
+/// executing `accept_config_admin` completes the transfer
+pub fn handler(ctx: Context<ProposeGovernanceAdmin>) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let treasury = governance_treasury(&governance.to_account_info())?;
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.pending_admin = treasury;
+
+    emit!(ConfigAdminProposed {
+        admin: config.admin,
+        pending_admin: treasury,
+    });
+    emit!(GovernanceAdminProposed {
+        governance_program: *governance.owner,
+        governance: governance.key(),
+        pending_admin: treasury,
+    });
+
+    msg!(
+        "Protocol admin proposed: governance {} (treasury {})",
+        governance.key(),
+        treasury
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesAuthorityProposed;
+use crate::instructions::OptionData;
+
+/// Current series authority nominating a successor (step 1 of 2)
+#[derive(Accounts)]
+pub struct ProposeSeriesAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Records `new_authority` as pending; nothing changes until it accepts.
+/// Proposing `Pubkey::default()` cancels an outstanding proposal.
+pub fn handler(ctx: Context<ProposeSeriesAuthority>, new_authority: Pubkey) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.pending_authority = new_authority;
+
+    emit!(SeriesAuthorityProposed {
+        option_context: option_context.key(),
+        authority: option_context.authority,
+        pending_authority: new_authority,
+    });
+
+    msg!(
+        "Series {} authority proposed: {}",
+        option_context.key(),
+        new_authority
+    );
+
+    Ok(())
+}
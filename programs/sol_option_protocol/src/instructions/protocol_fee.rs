@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::{FeeCharged, InsuranceContributed, ReferralPaid};
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats};
+use crate::utils::math::calculate_fee;
+
+/// Fee `user` owes at `fee_bps` on `base`: nothing for exempt accounts, less
+/// the discount of the tier reached by the volume in `stats` (counted in a
+/// consideration mint with `decimals`, before this trade)
+pub fn fee_due(
+    config: &ProtocolConfig,
+    user: &Pubkey,
+    base: u64,
+    fee_bps: u16,
+    stats: Option<&FeeStats>,
+    decimals: u8,
+) -> Result<u64> {
+    if config.is_fee_exempt(user) {
+        return Ok(0);
+    }
+    let fee = calculate_fee(base, fee_bps)?;
+    let discount_bps = stats.map_or(0, |stats| config.fee_discount_bps(stats.volume, decimals));
+    Ok(fee - calculate_fee(fee, discount_bps)?)
+}
+
+/// Parts of a fee: the referrer's, the insurance fund's and what the fee
+/// vault keeps
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub referral: u64,
+    pub insurance: u64,
+    pub protocol: u64,
+}
+
+/// Splits `fee`: `referral_share_bps` of it to the referrer when `referred`,
+/// then `insurance_share_bps` of the rest to the insurance fund. Both shares
+/// round down, so the dust stays with the protocol
+pub fn split_fee(
+    fee: u64,
+    referral_share_bps: u16,
+    insurance_share_bps: u16,
+    referred: bool,
+) -> Result<FeeSplit> {
+    let referral = if referred {
+        calculate_fee(fee, referral_share_bps)?
+    } else {
+        0
+    };
+    let insurance = calculate_fee(fee - referral, insurance_share_bps)?;
+    Ok(FeeSplit {
+        referral,
+        insurance,
+        protocol: fee - referral - insurance,
+    })
+}
+
+/// Token accounts a fee moves between
+pub struct FeeAccounts<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    /// Payer's token account of `mint`
+    pub from: AccountInfo<'info>,
+    /// Signer paying the fee
+    pub payer: AccountInfo<'info>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    /// Token account of the ProtocolConfig PDA that only `withdraw_fees` empties
+    pub fee_vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    /// Referrer's token account of `mint`, passed together with `referral_stats`
+    pub referrer_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub referral_stats: Option<&'a mut Account<'info, ReferralStats>>,
+    /// Insurance fund of `mint`, passed together with a token account it owns
+    pub insurance_fund: Option<&'a mut Account<'info, InsuranceFund>>,
+    pub insurance_vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Moves `fee` of `accounts.mint` from the payer to the protocol. With a
+/// referrer, `referral_share_bps` of it goes to the referrer's account instead
+/// and is added to their stats; payers can't refer themselves. Of the
+/// protocol's part, `insurance_share_bps` goes to the mint's insurance fund.
+/// Nothing is read for a zero fee, and the insurance and fee vaults only when
+/// their part isn't
+pub fn charge_fee(
+    option_context: Pubkey,
+    accounts: FeeAccounts<'_, '_>,
+    fee: u64,
+    referral_share_bps: u16,
+    insurance_share_bps: u16,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    let mint = accounts.mint;
+    let split = split_fee(
+        fee,
+        referral_share_bps,
+        insurance_share_bps,
+        accounts.referrer_account.is_some(),
+    )?;
+
+    if let Some(referrer_account) = accounts.referrer_account {
+        let stats = accounts.referral_stats.ok_or(ErrorCode::InvalidReferral)?;
+        require!(
+            referrer_account.mint == mint.key()
+                && stats.mint == mint.key()
+                && stats.referrer == referrer_account.owner
+                && referrer_account.owner != accounts.payer.key(),
+            ErrorCode::InvalidReferral
+        );
+        if split.referral > 0 {
+            token::transfer_checked(
+                CpiContext::new(
+                    accounts.token_program.clone(),
+                    token::TransferChecked {
+                        from: accounts.from.clone(),
+                        mint: mint.to_account_info(),
+                        to: referrer_account.to_account_info(),
+                        authority: accounts.payer.clone(),
+                    },
+                ),
+                split.referral,
+                mint.decimals,
+            )?;
+            stats.record(split.referral);
+
+            emit!(ReferralPaid {
+                option_context,
+                referrer: stats.referrer,
+                mint: mint.key(),
+                amount: split.referral,
+            });
+        }
+    }
+
+    if split.insurance > 0 {
+        let fund = accounts
+            .insurance_fund
+            .ok_or(ErrorCode::InvalidInsuranceFund)?;
+        let vault = accounts
+            .insurance_vault
+            .ok_or(ErrorCode::InvalidInsuranceFund)?;
+        require!(
+            fund.mint == mint.key() && vault.mint == mint.key() && vault.owner == fund.key(),
+            ErrorCode::InvalidInsuranceFund
+        );
+
+        token::transfer_checked(
+            CpiContext::new(
+                accounts.token_program.clone(),
+                token::TransferChecked {
+                    from: accounts.from.clone(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: accounts.payer.clone(),
+                },
+            ),
+            split.insurance,
+            mint.decimals,
+        )?;
+        fund.record_contribution(split.insurance)?;
+
+        emit!(InsuranceContributed {
+            option_context,
+            mint: mint.key(),
+            amount: split.insurance,
+            contributed: fund.contributed,
+        });
+    }
+
+    if split.protocol > 0 {
+        let fee_vault = accounts.fee_vault.ok_or(ErrorCode::InvalidFeeVault)?;
+        require_keys_eq!(fee_vault.mint, mint.key(), ErrorCode::InvalidFeeVault);
+
+        token::transfer_checked(
+            CpiContext::new(
+                accounts.token_program.clone(),
+                token::TransferChecked {
+                    from: accounts.from.clone(),
+                    mint: mint.to_account_info(),
+                    to: fee_vault.to_account_info(),
+                    authority: accounts.payer.clone(),
+                },
+            ),
+            split.protocol,
+            mint.decimals,
+        )?;
+    }
+
+    emit!(FeeCharged {
+        option_context,
+        payer: accounts.payer.key(),
+        mint: mint.key(),
+        amount: fee,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn referrers_are_paid_before_the_insurance_share() {
+        let split = split_fee(1_000, 2_000, 1_000, true).unwrap();
+        assert_eq!(
+            split,
+            FeeSplit {
+                referral: 200,
+                insurance: 80,
+                protocol: 720,
+            }
+        );
+    }
+
+    #[test]
+    fn unreferred_fees_skip_the_referral_share() {
+        let split = split_fee(1_000, 2_000, 1_000, false).unwrap();
+        assert_eq!(
+            split,
+            FeeSplit {
+                referral: 0,
+                insurance: 100,
+                protocol: 900,
+            }
+        );
+    }
+
+    #[test]
+    fn dust_stays_with_the_protocol() {
+        let split = split_fee(7, 5_000, 5_000, true).unwrap();
+        assert_eq!((split.referral, split.insurance, split.protocol), (3, 2, 2));
+
+        for fee in [0, 1, 9_999, u64::MAX] {
+            let split = split_fee(fee, 10_000, 10_000, true).unwrap();
+            assert_eq!(split.referral + split.insurance + split.protocol, fee);
+            let split = split_fee(fee, 0, 0, true).unwrap();
+            assert_eq!(split.protocol, fee);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::instructions::burn_paired::{burn, BurnParts};
+use crate::instructions::OptionData;
+use crate::state::WriterPosition;
+
+/// `burn` in the account order of PsyOptions American `close_position`
+///
+/// The first nine accounts are PsyOptions' (underlying = collateral, writer
+/// token = redemption token, option market = series, which must be writable
+/// here). The accounts this protocol needs on top follow them: the
+/// consideration leg pays back the escrow of puts, binaries and forwards.
+#[derive(Accounts)]
+pub struct PsyClosePosition<'info> {
+    pub user_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub option_market: Box<Account<'info, OptionData>>,
+
+    #[account(mut, constraint = option_mint.key() == option_market.option_mint)]
+    pub option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, token::mint = option_mint, token::authority = user_authority)]
+    pub option_token_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = writer_token_mint.key() == option_market.redemption_mint)]
+    pub writer_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, token::mint = writer_token_mint, token::authority = user_authority)]
+    pub writer_token_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = underlying_asset_pool.key() == option_market.collateral_vault)]
+    pub underlying_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = underlying_asset_mint,
+        token::authority = user_authority
+    )]
+    pub underlying_asset_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // === This protocol's accounts ===
+    #[account(constraint = underlying_asset_mint.key() == option_market.collateral_mint)]
+    pub underlying_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(constraint = quote_asset_mint.key() == option_market.consideration_mint)]
+    pub quote_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, constraint = quote_asset_pool.key() == option_market.consideration_vault)]
+    pub quote_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = quote_asset_mint, token::authority = user_authority)]
+    pub quote_asset_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's writer position; recorded into when passed
+    #[account(
+        mut,
+        seeds = [
+            WriterPosition::SEED,
+            option_market.key().as_ref(),
+            user_authority.key().as_ref()
+        ],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Option<Account<'info, WriterPosition>>,
+}
+
+impl<'info> PsyClosePosition<'info> {
+    fn parts(&mut self) -> BurnParts<'_, 'info> {
+        BurnParts {
+            user: &self.user_authority,
+            option_context: &mut self.option_market,
+            collateral_mint: &self.underlying_asset_mint,
+            consideration_mint: &self.quote_asset_mint,
+            option_mint: &self.option_mint,
+            redemption_mint: &self.writer_token_mint,
+            collateral_vault: &self.underlying_asset_pool,
+            consideration_vault: &self.quote_asset_pool,
+            user_collateral_account: &self.underlying_asset_dest,
+            user_consideration_account: &self.quote_asset_dest,
+            user_option_account: &self.option_token_src,
+            user_redemption_account: &self.writer_token_src,
+            token_program: &self.token_program,
+            writer_position: &mut self.writer_position,
+        }
+    }
+}
+
+/// Same refund and bookkeeping as `burn`; `size` is in option token units
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PsyClosePosition<'info>>,
+    size: u64,
+) -> Result<()> {
+    burn(ctx.accounts.parts(), ctx.remaining_accounts, size)
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::instructions::redeem::{redeem, RedeemParts};
+use crate::instructions::OptionData;
+
+/// `redeem` in the account order of PsyOptions American `close_post_expiration`
+///
+/// The first eight accounts are PsyOptions' (underlying = collateral, writer
+/// token = redemption token, option market = series, which must be writable
+/// here). `clock` is kept for position only. The accounts this protocol needs
+/// on top follow them: redeeming also pays the consideration share, which
+/// PsyOptions leaves to `burn_writer_for_quote`.
+#[derive(Accounts)]
+pub struct PsyClosePostExpiration<'info> {
+    pub user_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub option_market: Box<Account<'info, OptionData>>,
+
+    #[account(mut, constraint = writer_token_mint.key() == option_market.redemption_mint)]
+    pub writer_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, token::mint = writer_token_mint, token::authority = user_authority)]
+    pub writer_token_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = underlying_asset_pool.key() == option_market.collateral_vault)]
+    pub underlying_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = underlying_asset_mint,
+        token::authority = user_authority
+    )]
+    pub underlying_asset_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+
+    // === This protocol's accounts ===
+    #[account(constraint = underlying_asset_mint.key() == option_market.collateral_mint)]
+    pub underlying_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(constraint = quote_asset_mint.key() == option_market.consideration_mint)]
+    pub quote_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, constraint = quote_asset_pool.key() == option_market.consideration_vault)]
+    pub quote_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = quote_asset_mint, token::authority = user_authority)]
+    pub quote_asset_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> PsyClosePostExpiration<'info> {
+    fn parts(&mut self) -> RedeemParts<'_, 'info> {
+        RedeemParts {
+            user: &self.user_authority,
+            option_context: &mut self.option_market,
+            collateral_mint: &self.underlying_asset_mint,
+            consideration_mint: &self.quote_asset_mint,
+            redemption_mint: &self.writer_token_mint,
+            collateral_vault: &self.underlying_asset_pool,
+            consideration_vault: &self.quote_asset_pool,
+            user_collateral_account: &self.underlying_asset_dest,
+            user_consideration_account: &self.quote_asset_dest,
+            user_redemption_account: &self.writer_token_src,
+            token_program: &self.token_program,
+        }
+    }
+}
+
+/// Same pro-rata payout as `redeem`; `size` is in redemption token units
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PsyClosePostExpiration<'info>>,
+    size: u64,
+) -> Result<()> {
+    redeem(ctx.accounts.parts(), ctx.remaining_accounts, size)
+}
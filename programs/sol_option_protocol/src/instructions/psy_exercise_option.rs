@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::exercise::{exercise, ExerciseParts};
+use crate::instructions::OptionData;
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats};
+
+/// `exercise` in the account order of PsyOptions American `exercise_option`
+///
+/// The first thirteen accounts are PsyOptions' (underlying = collateral,
+/// quote = consideration, option market = series, which must be writable
+/// here). `option_authority` must be `user_authority`, which also owns
+/// `underlying_asset_dest` (puts deliver collateral from it). `fee_owner`
+/// and `clock` are kept for position only. The accounts this protocol needs
+/// on top follow them.
+#[derive(Accounts)]
+pub struct PsyExerciseOption<'info> {
+    pub user_authority: Signer<'info>,
+
+    #[account(
+        constraint = option_authority.key() == user_authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub option_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub option_market: Box<Account<'info, OptionData>>,
+
+    #[account(mut, constraint = option_mint.key() == option_market.option_mint)]
+    pub option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, token::mint = option_mint, token::authority = user_authority)]
+    pub exerciser_option_token_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = underlying_asset_pool.key() == option_market.collateral_vault)]
+    pub underlying_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = underlying_asset_mint,
+        token::authority = user_authority
+    )]
+    pub underlying_asset_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = quote_asset_pool.key() == option_market.consideration_vault)]
+    pub quote_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = quote_asset_mint, token::authority = user_authority)]
+    pub quote_asset_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: PsyOptions' fee owner slot; unused (fees go to `fee_vault`)
+    pub fee_owner: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+
+    // === This protocol's accounts ===
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(constraint = underlying_asset_mint.key() == option_market.collateral_mint)]
+    pub underlying_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(constraint = quote_asset_mint.key() == option_market.consideration_mint)]
+    pub quote_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Same optional fee and credential accounts as `OptionContext`
+    #[account(
+        mut,
+        constraint = fee_vault.owner == protocol_config.key() @ ErrorCode::InvalidFeeVault
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            FeeStats::SEED,
+            user_authority.key().as_ref(),
+            quote_asset_mint.key().as_ref()
+        ],
+        bump = fee_stats.bump
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    #[account(mut)]
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+
+    #[account(mut)]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(mut)]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> PsyExerciseOption<'info> {
+    fn parts(&mut self) -> ExerciseParts<'_, 'info> {
+        ExerciseParts {
+            user: &self.user_authority,
+            option_context: &mut self.option_market,
+            protocol_config: &self.protocol_config,
+            collateral_mint: &self.underlying_asset_mint,
+            consideration_mint: &self.quote_asset_mint,
+            option_mint: &self.option_mint,
+            collateral_vault: &mut self.underlying_asset_pool,
+            consideration_vault: &mut self.quote_asset_pool,
+            user_collateral_account: &self.underlying_asset_dest,
+            user_consideration_account: &self.quote_asset_src,
+            user_option_account: &self.exerciser_option_token_src,
+            token_program: &self.token_program,
+            fee_vault: &self.fee_vault,
+            fee_stats: &mut self.fee_stats,
+            referrer_account: &self.referrer_account,
+            referral_stats: &mut self.referral_stats,
+            insurance_fund: &mut self.insurance_fund,
+            insurance_vault: &self.insurance_vault,
+            credential_account: &self.credential_account,
+        }
+    }
+}
+
+/// Same payments, fee and bookkeeping as `exercise`; `size` is in option
+/// token units
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PsyExerciseOption<'info>>,
+    size: u64,
+) -> Result<()> {
+    exercise(ctx.accounts.parts(), ctx.remaining_accounts, size, |_| {
+        Ok(())
+    })
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::mint_options::{mint, MintParts};
+use crate::instructions::OptionData;
+use crate::state::{FeeStats, InsuranceFund, ProtocolConfig, ReferralStats, WriterPosition};
+
+/// `mint` in the account order of PsyOptions American `mint_option`
+///
+/// The first fifteen accounts are PsyOptions' (underlying = collateral,
+/// writer token = redemption token, option market = series, which must be
+/// writable here). `fee_owner`, `clock` and `rent` are kept for position
+/// only. The accounts this protocol needs on top follow them.
+#[derive(Accounts)]
+pub struct PsyMintOption<'info> {
+    pub user_authority: Signer<'info>,
+
+    #[account(constraint = underlying_asset_mint.key() == option_market.collateral_mint)]
+    pub underlying_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, constraint = underlying_asset_pool.key() == option_market.collateral_vault)]
+    pub underlying_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = underlying_asset_mint,
+        token::authority = user_authority
+    )]
+    pub underlying_asset_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = option_mint.key() == option_market.option_mint)]
+    pub option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Receives the long leg (any owner)
+    #[account(mut, token::mint = option_mint)]
+    pub minted_option_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = writer_token_mint.key() == option_market.redemption_mint)]
+    pub writer_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Receives the short leg (any owner)
+    #[account(mut, token::mint = writer_token_mint)]
+    pub minted_writer_token_dest: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub option_market: Box<Account<'info, OptionData>>,
+
+    /// CHECK: PsyOptions' fee owner slot; unused (fees go to `fee_vault`)
+    pub fee_owner: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+
+    // === This protocol's accounts ===
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(constraint = quote_asset_mint.key() == option_market.consideration_mint)]
+    pub quote_asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, constraint = quote_asset_pool.key() == option_market.consideration_vault)]
+    pub quote_asset_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pays the escrow of series that lock consideration (puts, binaries, forwards)
+    #[account(mut, token::mint = quote_asset_mint, token::authority = user_authority)]
+    pub quote_asset_src: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Same optional trailing accounts as `OptionContext`
+    #[account(
+        mut,
+        seeds = [
+            WriterPosition::SEED,
+            option_market.key().as_ref(),
+            user_authority.key().as_ref()
+        ],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Option<Account<'info, WriterPosition>>,
+
+    #[account(
+        mut,
+        constraint = fee_vault.owner == protocol_config.key() @ ErrorCode::InvalidFeeVault
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            FeeStats::SEED,
+            user_authority.key().as_ref(),
+            quote_asset_mint.key().as_ref()
+        ],
+        bump = fee_stats.bump
+    )]
+    pub fee_stats: Option<Account<'info, FeeStats>>,
+
+    #[account(mut)]
+    pub referrer_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+
+    #[account(mut)]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(mut)]
+    pub insurance_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> PsyMintOption<'info> {
+    fn parts(&mut self) -> MintParts<'_, 'info> {
+        MintParts {
+            user: &self.user_authority,
+            option_context: &mut self.option_market,
+            protocol_config: &self.protocol_config,
+            collateral_mint: &self.underlying_asset_mint,
+            consideration_mint: &self.quote_asset_mint,
+            option_mint: &self.option_mint,
+            redemption_mint: &self.writer_token_mint,
+            collateral_vault: &mut self.underlying_asset_pool,
+            consideration_vault: &mut self.quote_asset_pool,
+            user_collateral_account: &self.underlying_asset_src,
+            user_consideration_account: &self.quote_asset_src,
+            user_option_account: &self.minted_option_dest,
+            user_redemption_account: &self.minted_writer_token_dest,
+            token_program: &self.token_program,
+            writer_position: &mut self.writer_position,
+            fee_vault: &self.fee_vault,
+            fee_stats: &mut self.fee_stats,
+            referrer_account: &self.referrer_account,
+            referral_stats: &mut self.referral_stats,
+            insurance_fund: &mut self.insurance_fund,
+            insurance_vault: &self.insurance_vault,
+            credential_account: &self.credential_account,
+        }
+    }
+}
+
+/// Same deposit, fee and bookkeeping as `mint`; `size` is in option token units
+pub fn handler(ctx: Context<PsyMintOption>, size: u64) -> Result<()> {
+    mint(ctx.accounts.parts(), size)
+}
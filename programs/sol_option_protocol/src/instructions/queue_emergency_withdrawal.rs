@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawalQueued;
+use crate::instructions::OptionData;
+use crate::state::{EmergencyWithdrawal, ProtocolConfig, WithdrawalVault};
+use crate::utils::validation::validate_amount;
+
+/// Admin queueing a timelocked withdrawal from one of a series' vaults: its
+/// collateral, consideration, basket or yield share vault
+#[derive(Accounts)]
+pub struct QueueEmergencyWithdrawal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        constraint = WithdrawalVault::of(
+            &option_context,
+            &option_context.key(),
+            &vault.key(),
+            &vault.mint
+        )
+        .is_some() @ ErrorCode::InvalidWithdrawalVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = destination.mint == vault.mint @ ErrorCode::InvalidWithdrawalVault)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + EmergencyWithdrawal::INIT_SPACE,
+        seeds = [EmergencyWithdrawal::SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub withdrawal: Account<'info, EmergencyWithdrawal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Last-resort path for funds no user instruction can recover. Nothing moves
+/// until `EmergencyWithdrawal::TIMELOCK` has passed, and the guardian can
+/// cancel at any point before execution.
+pub fn handler(
+    ctx: Context<QueueEmergencyWithdrawal>,
+    amount: u64,
+    justification_hash: [u8; 32],
+) -> Result<()> {
+    validate_amount(amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let executable_at = now
+        .checked_add(EmergencyWithdrawal::TIMELOCK)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let withdrawal = &mut ctx.accounts.withdrawal;
+    withdrawal.option_context = ctx.accounts.option_context.key();
+    withdrawal.vault = ctx.accounts.vault.key();
+    withdrawal.destination = ctx.accounts.destination.key();
+    withdrawal.amount = amount;
+    withdrawal.justification_hash = justification_hash;
+    withdrawal.queued_at = now;
+    withdrawal.executable_at = executable_at;
+    withdrawal.bump = ctx.bumps.withdrawal;
+
+    emit!(EmergencyWithdrawalQueued {
+        withdrawal: withdrawal.key(),
+        option_context: withdrawal.option_context,
+        vault: withdrawal.vault,
+        destination: withdrawal.destination,
+        amount,
+        justification_hash,
+        executable_at,
+    });
+
+    msg!(
+        "Emergency withdrawal of {} from {} queued, executable at {}",
+        amount,
+        withdrawal.vault,
+        executable_at
+    );
+
+    Ok(())
+}
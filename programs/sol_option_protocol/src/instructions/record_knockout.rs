@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesKnockedOut;
+use crate::instructions::OptionData;
+use crate::oracle::{resolve_price, PriceRequest};
+use crate::utils::validation::{validate_not_expired, validate_not_settled};
+
+/// Permissionless knock-out crank: anyone can show the oracle has breached a
+/// series' barrier before expiry
+#[derive(Accounts)]
+pub struct RecordKnockout<'info> {
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// CHECK: Validated against option_context.primary_oracle by the oracle module
+    pub primary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated against option_context.secondary_oracle by the oracle module
+    pub secondary_oracle: Option<UncheckedAccount<'info>>,
+}
+
+/// Reads the oracle hierarchy at the current time and, if the price is at or
+/// beyond the barrier (above it for calls, below it for puts), marks the series
+/// knocked out: minting and exercise stop, it can settle right away, and every
+/// option expires worthless so the vaults go back to redemption holders
+pub fn handler(ctx: Context<RecordKnockout>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+
+    // Validation
+    require!(
+        option_context.knockout_barrier > 0,
+        ErrorCode::InvalidBarrier
+    );
+    require!(
+        !option_context.is_knocked_out(),
+        ErrorCode::SeriesKnockedOut
+    );
+    validate_not_expired(option_context.expiration)?;
+    validate_not_settled(option_context)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let primary_account = ctx
+        .accounts
+        .primary_oracle
+        .as_ref()
+        .map(|a| a.to_account_info());
+    let secondary_account = ctx
+        .accounts
+        .secondary_oracle
+        .as_ref()
+        .map(|a| a.to_account_info());
+
+    let request = PriceRequest {
+        reference_time: now,
+        late_window: 0,
+        previous_price: option_context.last_oracle_price,
+        previous_update: option_context.last_oracle_update,
+        strike_exponent: option_context.strike_exponent,
+    };
+
+    let (observed_price, price, source) = resolve_price(
+        &option_context.primary_oracle,
+        primary_account.as_ref(),
+        &option_context.secondary_oracle,
+        secondary_account.as_ref(),
+        &request,
+    )?;
+    require!(
+        option_context.barrier_breached(observed_price),
+        ErrorCode::BarrierNotBreached
+    );
+
+    // Knock the series out (OptionContext bookkeeping)
+    let series_key = ctx.accounts.option_context.key();
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.status |= OptionData::STATUS_KNOCKED_OUT;
+    option_context.knocked_out_at = now;
+    if price.publish_time > option_context.last_oracle_update {
+        option_context.last_oracle_price = observed_price;
+        option_context.last_oracle_update = price.publish_time;
+    }
+
+    emit!(SeriesKnockedOut {
+        option_context: series_key,
+        barrier: option_context.knockout_barrier,
+        price: observed_price,
+        knocked_out_at: now,
+    });
+
+    msg!(
+        "Series {} knocked out at {} (barrier {}, source: {:?})",
+        series_key,
+        observed_price,
+        option_context.knockout_barrier,
+        source
+    );
+
+    Ok(())
+}
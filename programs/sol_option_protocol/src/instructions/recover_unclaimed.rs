@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::UnclaimedRecovered;
+use crate::instructions::sweep_dust::{sweep_vaults_to_treasury, SweepDust};
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::utils::validation::validate_settled;
+
+/// Permissionless recovery of an abandoned series: once the configured timeout
+/// after expiration has passed, everything left in the vaults goes to the
+/// treasury and the vaults are closed, whatever tokens are still outstanding
+/// (same accounts as sweep_dust, substitute vaults included)
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SweepDust<'info>>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+    let timeout = ctx.accounts.protocol_config.unclaimed_timeout;
+
+    // Validation
+    validate_settled(option_context)?;
+    require!(timeout > 0, ErrorCode::RecoveryDisabled);
+    let recoverable_at = option_context
+        .expiration
+        .checked_add(timeout)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        Clock::get()?.unix_timestamp >= recoverable_at,
+        ErrorCode::UnclaimedTimeoutNotElapsed
+    );
+
+    let (collateral, consideration) =
+        sweep_vaults_to_treasury(ctx.accounts, ctx.remaining_accounts)?;
+
+    let series_key = ctx.accounts.option_context.key();
+    let treasury = ctx.accounts.protocol_config.treasury;
+    emit!(UnclaimedRecovered {
+        option_context: series_key,
+        treasury,
+        collateral,
+        consideration,
+        redemption_supply: ctx.accounts.redemption_mint.supply,
+        option_supply: ctx.accounts.option_mint.supply,
+    });
+
+    msg!(
+        "Recovered {} collateral and {} consideration from series {} to treasury {}",
+        collateral,
+        consideration,
+        series_key,
+        treasury
+    );
+
+    // The vaults are closed by now, so only the mints are checked
+    check_series(
+        &ctx.accounts.option_context,
+        SeriesAccounts {
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
+}
@@ -1,50 +1,113 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount, TokenInterface};
 
-use crate::instructions::OptionContext;
+use crate::errors::ErrorCode;
+use crate::events::Redeemed;
+use crate::instructions::basket_payout::pay_basket_shares;
+use crate::instructions::{OptionContext, OptionData};
+use crate::invariants::{check_series, SeriesAccounts};
 use crate::utils::{
-    math::calculate_pro_rata_share,
-    validation::{validate_amount, validate_expired},
+    math::calculate_pro_rata_share_u128,
+    validation::{validate_amount, validate_redemption_open, validate_settled},
 };
 
-/// Redeems redemption tokens for pro-rata share of vault assets after expiry
+/// Redeems redemption tokens for pro-rata share of vault assets after settlement
 /// Post-expiry: User burns redemption tokens → receives pro-rata collateral + consideration
-pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+/// Shares come from the settle_expiry snapshot, so redemption order doesn't matter
+/// The denominator is the redemption mint supply at settlement, not `total_supply`,
+/// which still counts redemption tokens burned by early claims
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    redeem(ctx.accounts.redeem_parts(), ctx.remaining_accounts, amount)
+}
+
+/// Accounts `redeem` works on, borrowed from `OptionContext` or `RedeemAccounts`
+pub struct RedeemParts<'a, 'info> {
+    pub user: &'a Signer<'info>,
+    pub option_context: &'a mut Account<'info, OptionData>,
+    pub collateral_mint: &'a InterfaceAccount<'info, Mint>,
+    pub consideration_mint: &'a InterfaceAccount<'info, Mint>,
+    pub redemption_mint: &'a InterfaceAccount<'info, Mint>,
+    pub collateral_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub consideration_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_collateral_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_consideration_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub user_redemption_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'info> OptionContext<'info> {
+    pub(crate) fn redeem_parts(&mut self) -> RedeemParts<'_, 'info> {
+        RedeemParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            redemption_mint: &self.redemption_mint,
+            collateral_vault: &self.collateral_vault,
+            consideration_vault: &self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_redemption_account: &self.user_redemption_account,
+            token_program: &self.token_program,
+        }
+    }
+}
+
+/// `redeem` over whichever accounts struct carried the call
+pub(crate) fn redeem<'info>(
+    accounts: RedeemParts<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
     // Validation
     validate_amount(amount)?;
-    validate_expired(ctx.accounts.option_context.expiration)?;
+    validate_settled(accounts.option_context)?;
+    validate_redemption_open(accounts.option_context)?;
 
-    let option_context = &ctx.accounts.option_context;
+    let option_context = &accounts.option_context;
 
     // Get mint decimals
-    let collateral_decimals = ctx.accounts.collateral_mint.decimals;
-    let strike_decimals = ctx.accounts.consideration_mint.decimals;
+    let collateral_decimals = accounts.collateral_mint.decimals;
+    let consideration_decimals = accounts.consideration_mint.decimals;
 
-    // Get current vault balances
-    let collateral_balance = ctx.accounts.collateral_vault.amount;
-    let consideration_balance = ctx.accounts.consideration_vault.amount;
-
-    // Calculate pro-rata shares using utils
-    let collateral_payout = calculate_pro_rata_share(
-        collateral_balance,
+    // Calculate pro-rata shares of the settlement snapshot using utils
+    let collateral_payout = calculate_pro_rata_share_u128(
+        option_context.settled_collateral,
         amount,
-        option_context.total_supply,
+        option_context.settled_supply,
     )?;
 
-    let consideration_payout = calculate_pro_rata_share(
-        consideration_balance,
+    let consideration_payout = calculate_pro_rata_share_u128(
+        option_context.settled_consideration,
         amount,
-        option_context.total_supply,
+        option_context.settled_supply,
     )?;
 
+    // Substitutes in the series' basket pay their pro-rata part of the escrow leg
+    let escrows_consideration = option_context.escrows_consideration();
+    let (escrow_paid, escrow_decimals) = if escrows_consideration {
+        (consideration_payout, consideration_decimals)
+    } else {
+        (collateral_payout, collateral_decimals)
+    };
+    let (primary_paid, basket_shares) = option_context.split_escrow_payout(escrow_paid)?;
+    let (collateral_from_vault, consideration_from_vault) = if escrows_consideration {
+        (collateral_payout, primary_paid)
+    } else {
+        (primary_paid, consideration_payout)
+    };
+
     // 1. Burn redemption tokens from user (destroys their claim)
     token::burn(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            accounts.token_program.to_account_info(),
             token::Burn {
-                mint: ctx.accounts.redemption_mint.to_account_info(),
-                from: ctx.accounts.user_redemption_account.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
+                mint: accounts.redemption_mint.to_account_info(),
+                from: accounts.user_redemption_account.to_account_info(),
+                authority: accounts.user.to_account_info(),
             },
         ),
         amount,
@@ -55,7 +118,7 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
     let consideration_mint_key = option_context.consideration_mint;
     let strike_price_bytes = option_context.strike_price.to_le_bytes();
     let expiration_bytes = option_context.expiration.to_le_bytes();
-    let is_put_byte = [option_context.is_put as u8];
+    let option_type_byte = [option_context.option_type as u8];
     let bump = option_context.bump;
 
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -64,45 +127,79 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
         consideration_mint_key.as_ref(),
         strike_price_bytes.as_ref(),
         expiration_bytes.as_ref(),
-        &is_put_byte,
+        &option_type_byte,
         &[bump],
     ]];
 
     // 2. Transfer collateral from vault to user (if any)
-    if collateral_payout > 0 {
+    if collateral_from_vault > 0 {
         token::transfer_checked(
             CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
+                accounts.token_program.to_account_info(),
                 token::TransferChecked {
-                    from: ctx.accounts.collateral_vault.to_account_info(),
-                    mint: ctx.accounts.collateral_mint.to_account_info(),
-                    to: ctx.accounts.user_collateral_account.to_account_info(),
+                    from: accounts.collateral_vault.to_account_info(),
+                    mint: accounts.collateral_mint.to_account_info(),
+                    to: accounts.user_collateral_account.to_account_info(),
                     authority: option_context.to_account_info(),
                 },
                 signer_seeds,
             ),
-            collateral_payout,
+            collateral_from_vault,
             collateral_decimals,
         )?;
     }
 
     // 3. Transfer consideration from vault to user (if any)
-    if consideration_payout > 0 {
+    if consideration_from_vault > 0 {
         token::transfer_checked(
             CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
+                accounts.token_program.to_account_info(),
                 token::TransferChecked {
-                    from: ctx.accounts.consideration_vault.to_account_info(),
-                    mint: ctx.accounts.consideration_mint.to_account_info(),
-                    to: ctx.accounts.user_consideration_account.to_account_info(),
+                    from: accounts.consideration_vault.to_account_info(),
+                    mint: accounts.consideration_mint.to_account_info(),
+                    to: accounts.user_consideration_account.to_account_info(),
                     authority: option_context.to_account_info(),
                 },
                 signer_seeds,
             ),
-            consideration_payout,
-            strike_decimals,
+            consideration_from_vault,
+            consideration_decimals,
         )?;
     }
+    pay_basket_shares(
+        accounts.option_context,
+        &accounts.token_program.to_account_info(),
+        remaining_accounts,
+        &basket_shares,
+        escrow_decimals,
+        signer_seeds,
+    )?;
+
+    // Update vault accounting and short payouts (OptionContext bookkeeping)
+    let option_context = &mut *accounts.option_context;
+    option_context.debit_collateral(collateral_payout)?;
+    option_context.debit_consideration(consideration_payout)?;
+    option_context.collateral_redeemed = option_context
+        .collateral_redeemed
+        .checked_add(collateral_payout)
+        .ok_or(ErrorCode::MathOverflow)?;
+    option_context.consideration_claimed = option_context
+        .consideration_claimed
+        .checked_add(consideration_payout)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(Redeemed {
+        option_context: option_context.key(),
+        user: accounts.user.key(),
+        amount,
+        collateral: collateral_payout,
+        consideration: consideration_payout,
+        redemption_supply: accounts
+            .redemption_mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?,
+    });
 
     msg!(
         "Redeemed {} tokens. Collateral: {}, Consideration: {}",
@@ -111,5 +208,13 @@ pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
         consideration_payout
     );
 
-    Ok(())
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(accounts.consideration_vault.to_account_info()),
+            redemption_mint: Some(accounts.redemption_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
 }
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::instructions::redeem::{redeem, RedeemParts};
+use crate::instructions::OptionData;
+
+/// `redeem` with only the short leg and both vaults: no protocol config, long
+/// leg, leg owners, optional accounts or associated account creation
+#[derive(Accounts)]
+pub struct RedeemAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(constraint = collateral_mint.key() == option_context.collateral_mint)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(constraint = consideration_mint.key() == option_context.consideration_mint)]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = redemption_mint.key() == option_context.redemption_mint)]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = collateral_vault.key() == option_context.collateral_vault)]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = collateral_mint, token::authority = user)]
+    pub user_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = consideration_mint, token::authority = user)]
+    pub user_consideration_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = redemption_mint, token::authority = user)]
+    pub user_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RedeemAccounts<'info> {
+    fn parts(&mut self) -> RedeemParts<'_, 'info> {
+        RedeemParts {
+            user: &self.user,
+            option_context: &mut self.option_context,
+            collateral_mint: &self.collateral_mint,
+            consideration_mint: &self.consideration_mint,
+            redemption_mint: &self.redemption_mint,
+            collateral_vault: &self.collateral_vault,
+            consideration_vault: &self.consideration_vault,
+            user_collateral_account: &self.user_collateral_account,
+            user_consideration_account: &self.user_consideration_account,
+            user_redemption_account: &self.user_redemption_account,
+            token_program: &self.token_program,
+        }
+    }
+}
+
+/// Same pro-rata payout as `redeem`
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemAccounts<'info>>,
+    amount: u64,
+) -> Result<()> {
+    redeem(ctx.accounts.parts(), ctx.remaining_accounts, amount)
+}
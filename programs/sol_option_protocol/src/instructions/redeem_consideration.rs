@@ -1,35 +1,104 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{ TransferChecked};
 use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::TransferChecked;
 
-
-use crate::instructions::OptionContext;
 use crate::errors::ErrorCode;
-use crate::utils::math::calculate_pro_rata_share_u128;
+use crate::events::ConsiderationClaimed;
+use crate::instructions::OptionContext;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::utils::{
+    math::{calculate_pro_rata_share_u128, calculate_strike_value},
+    validation::{validate_amount, validate_not_settled},
+};
 
-/// Allows SHORT token holders to claim their pro-rata share of consideration
+/// Allows SHORT token holders to claim exercise proceeds before expiry
 /// Greek.fi compliance: Key capital efficiency feature for option writers
-
-pub fn handler(ctx: Context<OptionContext>) -> Result<()> {
+///
+/// Burns `amount` redemption tokens and pays out `amount` exercised units:
+/// - Call: the strike payment for `amount` (consideration vault)
+/// - Put: `amount` delivered collateral (collateral vault)
+///
+/// Perpetual series also pay the converted units' pro-rata share of the
+/// funding collected from exercisers that hasn't been claimed yet.
+///
+/// Each redemption token is backed by one unit that is either still collateral
+/// for an outstanding option or already exercised, so converting exercised
+/// units leaves every remaining token fully backed. Burning is what ties the
+/// payout to the claim: the same tokens can never be claimed twice.
+pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
     let option_context = &ctx.accounts.option_context;
+    validate_amount(amount)?;
+    validate_not_settled(option_context)?;
+
+    // Validation: User must hold `amount` SHORT tokens of this series
+    require_keys_eq!(
+        ctx.accounts.user_redemption_account.mint,
+        option_context.redemption_mint,
+        ErrorCode::InvalidRedemptionMint
+    );
+    require!(
+        ctx.accounts.user_redemption_account.amount >= amount,
+        ErrorCode::NoShortTokens
+    );
 
-    // Validation: User must have SHORT tokens
-    let user_short_balance = ctx.accounts.user_consideration_account.amount;
-    require!(user_short_balance > 0, ErrorCode::NoShortTokens);
+    // Validation: Only exercised units that haven't been converted yet
+    let unclaimed = option_context
+        .exercised_amount
+        .checked_sub(option_context.exercised_claimed)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(amount <= unclaimed, ErrorCode::InsufficientExercisedAmount);
+
+    let underlying = option_context.underlying_amount(amount)?;
+    let funding_share = calculate_pro_rata_share_u128(
+        option_context
+            .funding_collected
+            .saturating_sub(option_context.funding_claimed),
+        amount,
+        unclaimed,
+    )?;
 
-    // Validation: Consideration vault must have funds
-    let consideration_vault_balance = ctx.accounts.consideration_vault.amount;
-    require!(consideration_vault_balance > 0, ErrorCode::NoCashAvailable);
+    // Calls pay the strike, puts pay the delivered collateral
+    // (capped at the accounted balance so split exercises' rounding can't block the last claim)
+    let (vault, mint, destination, decimals, claimable) = if option_context.is_put() {
+        (
+            &ctx.accounts.collateral_vault,
+            &ctx.accounts.collateral_mint,
+            &ctx.accounts.user_collateral_account,
+            ctx.accounts.collateral_mint.decimals,
+            underlying.min(option_context.collateral_balance()),
+        )
+    } else {
+        let strike_payment = calculate_strike_value(
+            underlying,
+            option_context.strike_price,
+            option_context.strike_scale,
+        )?
+        .checked_add(funding_share)
+        .ok_or(ErrorCode::MathOverflow)?;
+        (
+            &ctx.accounts.consideration_vault,
+            &ctx.accounts.consideration_mint,
+            &ctx.accounts.user_consideration_account,
+            ctx.accounts.consideration_mint.decimals,
+            strike_payment.min(option_context.consideration_balance()),
+        )
+    };
+    require!(claimable > 0, ErrorCode::NoCashAvailable);
 
-    // Calculate user's total share using utils pro-rata formula
-    let user_total_share = calculate_pro_rata_share_u128(
-        consideration_vault_balance,
-        user_short_balance,
-        option_context.total_supply,
+    // 1. Burn redemption tokens from user (destroys the converted claim)
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.redemption_mint.to_account_info(),
+                from: ctx.accounts.user_redemption_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
     )?;
 
-
-    // Transfer consideration from vault to user (OptionSeries PDA signs)
+    // 2. Transfer proceeds from vault to user (OptionSeries PDA signs)
     let option_series_key = option_context.key();
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"option_context",
@@ -37,14 +106,14 @@ pub fn handler(ctx: Context<OptionContext>) -> Result<()> {
         option_context.consideration_mint.as_ref(),
         &option_context.strike_price.to_le_bytes(),
         &option_context.expiration.to_le_bytes(),
-        &[option_context.is_put as u8],
+        &[option_context.option_type as u8],
         &[option_context.bump],
     ]];
 
     let transfer_accounts = TransferChecked {
-        from: ctx.accounts.consideration_vault.to_account_info(),
-        mint: ctx.accounts.consideration_mint.to_account_info(),
-        to: ctx.accounts.user_consideration_account.to_account_info(),
+        from: vault.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination.to_account_info(),
         authority: option_context.to_account_info(),
     };
 
@@ -54,26 +123,85 @@ pub fn handler(ctx: Context<OptionContext>) -> Result<()> {
         signer_seeds,
     );
 
-    let vault_balance_u128 = ctx.accounts.consideration_vault.amount as u128;
-    let claimable_u128 = core::cmp::min(user_total_share as u128, vault_balance_u128);
-    let claimable = claimable_u128 as u64;
-    require!(claimable > 0, ErrorCode::NoCashAvailable);
-
+    token::transfer_checked(cpi_ctx, claimable, decimals)?;
 
-    token::transfer_checked(
-        cpi_ctx,
-        claimable,
-        ctx.accounts.consideration_mint.decimals,
-    )?;
+    // 3. Put: funding is paid separately, in consideration
+    let put_funding = if option_context.is_put() {
+        funding_share.min(option_context.consideration_balance())
+    } else {
+        0
+    };
+    if put_funding > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.consideration_vault.to_account_info(),
+                    mint: ctx.accounts.consideration_mint.to_account_info(),
+                    to: ctx.accounts.user_consideration_account.to_account_info(),
+                    authority: option_context.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            put_funding,
+            ctx.accounts.consideration_mint.decimals,
+        )?;
+    }
 
     // Update tracking (OptionSeries bookkeeping)
-
+    let option_context = &mut ctx.accounts.option_context;
+    if option_context.is_put() {
+        option_context.debit_consideration(put_funding)?;
+        option_context.debit_collateral(claimable)?;
+        option_context.collateral_redeemed = option_context
+            .collateral_redeemed
+            .checked_add(claimable)
+            .ok_or(ErrorCode::MathOverflow)?;
+    } else {
+        option_context.debit_consideration(claimable)?;
+        option_context.consideration_claimed = option_context
+            .consideration_claimed
+            .checked_add(claimable)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    option_context.funding_claimed = option_context
+        .funding_claimed
+        .checked_add(funding_share)
+        .ok_or(ErrorCode::MathOverflow)?;
+    option_context.exercised_claimed = option_context
+        .exercised_claimed
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(ConsiderationClaimed {
+        option_context: option_series_key,
+        user: ctx.accounts.user.key(),
+        amount,
+        proceeds: claimable,
+        funding: put_funding,
+        exercised_claimed: option_context.exercised_claimed,
+        redemption_supply: ctx
+            .accounts
+            .redemption_mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?,
+    });
 
     msg!(
-        "User {} claimed {} consideration from option series {}",
+        "User {} converted {} exercised units into {} from option series {}",
         ctx.accounts.user.key(),
+        amount,
         claimable,
         option_series_key
     );
-    Ok(())
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(ctx.accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(ctx.accounts.consideration_vault.to_account_info()),
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+        },
+    )
 }
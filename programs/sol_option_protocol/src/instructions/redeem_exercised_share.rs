@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionContext;
+use crate::utils::{math::calculate_pro_rata_share_u128, validation::validate_amount};
+
+/// Claims the exercised fraction of a redemption position before expiry
+/// Of `amount` redemption tokens, burns only their pro-rata share of the
+/// unconverted exercised units and pays those out (see redeem_consideration);
+/// the rest of the position stays with the user, backed by locked collateral
+pub fn handler(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+    validate_amount(amount)?;
+    require!(
+        ctx.accounts.user_redemption_account.amount >= amount,
+        ErrorCode::NoShortTokens
+    );
+
+    let option_context = &ctx.accounts.option_context;
+    let unclaimed = option_context
+        .exercised_amount
+        .checked_sub(option_context.exercised_claimed)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Exercised fraction of the position: amount × unclaimed / outstanding claims
+    let units =
+        calculate_pro_rata_share_u128(unclaimed, amount, ctx.accounts.redemption_mint.supply)?;
+    require!(units > 0, ErrorCode::NoCashAvailable);
+
+    msg!(
+        "Exercised share of {} redemption tokens: {} units",
+        amount,
+        units
+    );
+    crate::instructions::redeem_consideration::handler(ctx, units)
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::native_sol::NativeAccount;
+use crate::instructions::{redeem, OptionContext};
+
+/// `redeem` paying the WSOL side of the payout (collateral or consideration)
+/// as native SOL
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let native = NativeAccount::of(ctx.accounts)?;
+    redeem::handler(ctx, amount)?;
+    native.unwrap()
+}
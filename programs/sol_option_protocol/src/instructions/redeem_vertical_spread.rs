@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::VerticalSpreadContext;
+use crate::utils::math::calculate_pro_rata_share_u128;
+
+/// Writer takes their share of what's left for writers after settlement, in
+/// proportion to what their position wrote
+pub fn handler(ctx: Context<VerticalSpreadContext>) -> Result<()> {
+    // Validation
+    require!(
+        ctx.accounts.vertical_spread.is_settled(),
+        ErrorCode::SeriesNotSettled
+    );
+    let written = ctx.accounts.position()?.written;
+    require!(written > 0, ErrorCode::NothingToExit);
+    let spread = &ctx.accounts.vertical_spread;
+    let share = calculate_pro_rata_share_u128(spread.writer_payout, written, spread.written)?;
+
+    // 1. Pay the share
+    ctx.accounts.pay_out(share)?;
+
+    // 2. Bookkeeping (spread and position)
+    let spread = &mut ctx.accounts.vertical_spread;
+    spread.written -= written;
+    spread.writer_payout -= share;
+    let spread_key = spread.key();
+    ctx.accounts.position()?.written = 0;
+
+    msg!(
+        "Redeemed {} written spread tokens of {} for {}",
+        written,
+        spread_key,
+        share
+    );
+
+    Ok(())
+}
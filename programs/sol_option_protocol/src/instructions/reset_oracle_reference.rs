@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::OracleReferenceReset;
+use crate::instructions::OptionData;
+use crate::state::ProtocolConfig;
+
+/// Admin clearing a series' deviation reference
+#[derive(Accounts)]
+pub struct ResetOracleReference<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin @ ErrorCode::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Escape hatch for a reference a genuine move left behind: the next guarded
+/// read is checked against nothing and becomes the new reference, without
+/// waiting for the old one to age out of the staleness window. Not behind the
+/// timelock: it changes no guard setting, and a delay would defeat it
+pub fn handler(ctx: Context<ResetOracleReference>) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+    let previous_price = option_context.last_oracle_price;
+    option_context.last_oracle_price = 0;
+    option_context.last_oracle_update = 0;
+
+    emit!(OracleReferenceReset {
+        option_context: option_context.key(),
+        previous_price,
+        reset_by: ctx.accounts.admin.key(),
+    });
+
+    msg!(
+        "Series {} oracle reference {} cleared",
+        option_context.key(),
+        previous_price
+    );
+
+    Ok(())
+}
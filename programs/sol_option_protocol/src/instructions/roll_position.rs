@@ -0,0 +1,350 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::{ProtocolConfig, WriterPosition};
+use crate::utils::validation::{
+    credential_state, validate_amount, validate_credential, validate_lot_multiple,
+    validate_min_mint_amount, validate_not_expired, validate_not_frozen, validate_not_knocked_out,
+    validate_not_legacy_put, validate_not_paused, validate_not_settled,
+    validate_protocol_not_paused, validate_roll_target, validate_vault_balance,
+};
+
+/// Moves paired tokens from one series to the same strike at a later expiry
+#[derive(Accounts)]
+pub struct RollPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Series the pairs are burned in
+    #[account(mut)]
+    pub from_option_context: Box<Account<'info, OptionData>>,
+
+    /// Option mint (validated against stored value in from_option_context)
+    #[account(
+        mut,
+        constraint = from_option_mint.key() == from_option_context.option_mint
+    )]
+    pub from_option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Redemption mint (validated against stored value in from_option_context)
+    #[account(
+        mut,
+        constraint = from_redemption_mint.key() == from_option_context.redemption_mint
+    )]
+    pub from_redemption_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vault holding from_option_context's escrow (collateral for calls,
+    /// consideration otherwise)
+    #[account(
+        mut,
+        constraint = from_vault.key() == from_option_context.margin_vault()
+    )]
+    pub from_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Series the pairs are minted in (same mints, strike and type; later expiry)
+    #[account(mut)]
+    pub to_option_context: Box<Account<'info, OptionData>>,
+
+    /// Protocol config (global pause)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Option mint (validated against stored value in to_option_context)
+    #[account(
+        mut,
+        constraint = to_option_mint.key() == to_option_context.option_mint
+    )]
+    pub to_option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Redemption mint (validated against stored value in to_option_context)
+    #[account(
+        mut,
+        constraint = to_redemption_mint.key() == to_option_context.redemption_mint
+    )]
+    pub to_redemption_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vault holding to_option_context's escrow
+    #[account(
+        mut,
+        constraint = to_vault.key() == to_option_context.margin_vault()
+    )]
+    pub to_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Mint of the escrowed asset (shared by both series)
+    #[account(
+        constraint = escrow_mint.key() == from_option_context.margin_mint()
+    )]
+    pub escrow_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// User's account of the escrowed asset (covers rounding differences)
+    #[account(mut, token::mint = escrow_mint, token::authority = user)]
+    pub user_escrow_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's option tokens in the expiring series (burned)
+    #[account(mut, token::mint = from_option_mint, token::authority = user)]
+    pub user_from_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's redemption tokens in the expiring series (burned)
+    #[account(mut, token::mint = from_redemption_mint, token::authority = user)]
+    pub user_from_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's option token account in the target series (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = to_option_mint,
+        associated_token::authority = user,
+    )]
+    pub user_to_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's redemption token account in the target series (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = to_redemption_mint,
+        associated_token::authority = user,
+    )]
+    pub user_to_redemption_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// User's writer position in the expiring series (records the burn when passed)
+    #[account(
+        mut,
+        seeds = [
+            WriterPosition::SEED,
+            from_option_context.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump = from_writer_position.bump
+    )]
+    pub from_writer_position: Option<Box<Account<'info, WriterPosition>>>,
+
+    /// User's writer position in the target series (records the mint when passed)
+    #[account(
+        mut,
+        seeds = [
+            WriterPosition::SEED,
+            to_option_context.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump = to_writer_position.bump
+    )]
+    pub to_writer_position: Option<Box<Account<'info, WriterPosition>>>,
+
+    /// User's token account of the target series' gate mint; required
+    /// when that series is gated
+    pub credential_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Burns `amount` paired tokens in `from_option_context` and mints `amount`
+/// pairs in `to_option_context`, moving the escrow straight from one vault to
+/// the other so the writer never has to fund both positions at once
+///
+/// The two escrows only differ by rounding (puts round the locked strike value
+/// up and the refund down): any shortfall comes from the user, any excess goes
+/// back to them.
+pub fn handler(ctx: Context<RollPosition>, amount: u64) -> Result<()> {
+    let from = &ctx.accounts.from_option_context;
+    let to = &ctx.accounts.to_option_context;
+
+    // Validation
+    validate_amount(amount)?;
+    validate_roll_target(from, to)?;
+    validate_lot_multiple(from, amount)?;
+    validate_not_settled(from)?;
+    validate_lot_multiple(to, amount)?;
+    validate_min_mint_amount(to, amount)?;
+    validate_protocol_not_paused(&ctx.accounts.protocol_config)?;
+    validate_not_paused(to)?;
+    validate_not_knocked_out(to)?;
+    validate_not_frozen(to)?;
+    validate_not_legacy_put(to)?;
+    validate_credential(
+        to,
+        &ctx.accounts.user.key(),
+        credential_state(&ctx.accounts.credential_account),
+    )?;
+    validate_not_expired(to.expiration)?;
+    validate_not_settled(to)?;
+
+    let underlying = from.underlying_amount(amount)?;
+    let refund = from.escrow_payout(underlying)?;
+    validate_vault_balance(from.escrow_balance(), refund)?;
+    let locked = to.escrow_locked(underlying)?;
+    let moved = refund.min(locked);
+
+    // 1. Burn both legs in the expiring series
+    for (mint, account) in [
+        (
+            &ctx.accounts.from_option_mint,
+            &ctx.accounts.user_from_option_account,
+        ),
+        (
+            &ctx.accounts.from_redemption_mint,
+            &ctx.accounts.user_from_redemption_account,
+        ),
+    ] {
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: mint.to_account_info(),
+                    from: account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    // 2. Move the escrow between vaults (the expiring series signs); rounding
+    //    differences settle with the user
+    let from_signer: &[&[&[u8]]] = &[&[
+        b"option_context",
+        from.collateral_mint.as_ref(),
+        from.consideration_mint.as_ref(),
+        &from.strike_price.to_le_bytes(),
+        &from.expiration.to_le_bytes(),
+        &[from.option_type as u8],
+        &[from.bump],
+    ]];
+    let decimals = ctx.accounts.escrow_mint.decimals;
+    for (destination, transfer_amount) in [
+        (&ctx.accounts.to_vault, moved),
+        (&ctx.accounts.user_escrow_account, refund - moved),
+    ] {
+        if transfer_amount > 0 {
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::TransferChecked {
+                        from: ctx.accounts.from_vault.to_account_info(),
+                        mint: ctx.accounts.escrow_mint.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: from.to_account_info(),
+                    },
+                    from_signer,
+                ),
+                transfer_amount,
+                decimals,
+            )?;
+        }
+    }
+    if locked > moved {
+        token::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: ctx.accounts.user_escrow_account.to_account_info(),
+                    mint: ctx.accounts.escrow_mint.to_account_info(),
+                    to: ctx.accounts.to_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            locked - moved,
+            decimals,
+        )?;
+    }
+
+    // 3. Mint both legs in the target series (the target series signs)
+    let to_signer: &[&[&[u8]]] = &[&[
+        b"option_context",
+        to.collateral_mint.as_ref(),
+        to.consideration_mint.as_ref(),
+        &to.strike_price.to_le_bytes(),
+        &to.expiration.to_le_bytes(),
+        &[to.option_type as u8],
+        &[to.bump],
+    ]];
+    for (mint, account) in [
+        (
+            &ctx.accounts.to_option_mint,
+            &ctx.accounts.user_to_option_account,
+        ),
+        (
+            &ctx.accounts.to_redemption_mint,
+            &ctx.accounts.user_to_redemption_account,
+        ),
+    ] {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: mint.to_account_info(),
+                    to: account.to_account_info(),
+                    authority: to.to_account_info(),
+                },
+                to_signer,
+            ),
+            amount,
+        )?;
+    }
+
+    // 4. Supply and vault accounting of both series
+    let from = &mut ctx.accounts.from_option_context;
+    from.debit_escrow(refund)?;
+    from.total_supply = from
+        .total_supply
+        .checked_sub(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let from_key = from.key();
+    let to = &mut ctx.accounts.to_option_context;
+    to.credit_escrow(locked)?;
+    to.total_supply = to
+        .total_supply
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Writer positions, when the user tracks them
+    if let Some(position) = ctx.accounts.from_writer_position.as_mut() {
+        position.record_burn(amount);
+    }
+    if let Some(position) = ctx.accounts.to_writer_position.as_mut() {
+        position.record_mint(amount)?;
+    }
+
+    msg!(
+        "Rolled {} pairs from {} to {}: moved {} escrow (refunded {}, topped up {})",
+        amount,
+        from_key,
+        ctx.accounts.to_option_context.key(),
+        moved,
+        refund - moved,
+        locked - moved
+    );
+
+    let accounts = &ctx.accounts;
+    check_series(
+        &accounts.from_option_context,
+        SeriesAccounts {
+            option_mint: Some(accounts.from_option_mint.to_account_info()),
+            redemption_mint: Some(accounts.from_redemption_mint.to_account_info()),
+            ..SeriesAccounts::escrow_vault(
+                &accounts.from_option_context,
+                accounts.from_vault.to_account_info(),
+            )
+        },
+    )?;
+    check_series(
+        &accounts.to_option_context,
+        SeriesAccounts {
+            option_mint: Some(accounts.to_option_mint.to_account_info()),
+            redemption_mint: Some(accounts.to_redemption_mint.to_account_info()),
+            ..SeriesAccounts::escrow_vault(
+                &accounts.to_option_context,
+                accounts.to_vault.to_account_info(),
+            )
+        },
+    )
+}
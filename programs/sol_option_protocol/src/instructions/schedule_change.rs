@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ChangeScheduled;
+use crate::state::{ParameterChange, PendingChange, ProtocolConfig};
+
+/// Admin scheduling a timelocked parameter change
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct ScheduleChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PendingChange::INIT_SPACE,
+        seeds = [PendingChange::SEED, id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The change is checked now and can be applied once the protocol's current
+/// `timelock_delay` has passed; the admin or guardian may cancel it until then
+pub fn handler(ctx: Context<ScheduleChange>, id: u64, change: ParameterChange) -> Result<()> {
+    change.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let executable_at = now
+        .checked_add(ctx.accounts.protocol_config.timelock_delay)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let pending_change = &mut ctx.accounts.pending_change;
+    pending_change.id = id;
+    pending_change.change = change.clone();
+    pending_change.scheduled_at = now;
+    pending_change.executable_at = executable_at;
+    pending_change.bump = ctx.bumps.pending_change;
+
+    emit!(ChangeScheduled {
+        pending_change: pending_change.key(),
+        id,
+        change,
+        executable_at,
+    });
+
+    msg!(
+        "Parameter change {} scheduled, executable at {}",
+        id,
+        executable_at
+    );
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+
+use crate::errors::ErrorCode;
+use crate::instructions::mint_options;
+use crate::openbook::{place_ask_ix, OrderAccounts, OPENBOOK_PROGRAM_ID};
+// The generated client modules of OptionContext come along for the nesting
+use crate::instructions::option::*;
+
+/// `mint` followed by an OpenBook v2 ask of the freshly minted options
+#[derive(Accounts)]
+pub struct SeedOpenbookMarket<'info> {
+    pub option: OptionContext<'info>,
+
+    /// CHECK: OpenBook v2
+    #[account(address = OPENBOOK_PROGRAM_ID @ ErrorCode::InvalidOpenbookProgram)]
+    pub openbook_program: UncheckedAccount<'info>,
+
+    /// CHECK: The user's OpenBook open orders account; checked by OpenBook
+    #[account(mut)]
+    pub open_orders_account: UncheckedAccount<'info>,
+
+    /// CHECK: Market of the series' option token; OpenBook checks its base
+    /// vault, so a market of another token refuses the ask
+    #[account(mut)]
+    pub market: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by OpenBook against the market
+    #[account(mut)]
+    pub bids: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by OpenBook against the market
+    #[account(mut)]
+    pub asks: UncheckedAccount<'info>,
+
+    /// CHECK: Checked by OpenBook against the market
+    #[account(mut)]
+    pub event_heap: UncheckedAccount<'info>,
+
+    /// CHECK: The market's base (option token) vault; checked by OpenBook
+    #[account(mut)]
+    pub market_base_vault: UncheckedAccount<'info>,
+}
+
+/// Mints `amount` options to the user and asks `price_lots` for `base_lots`
+/// lots of them on the market (lot sizes are the market's). The ask can't
+/// exceed what the mint delivered, so only freshly minted options are listed.
+pub fn handler(
+    ctx: Context<SeedOpenbookMarket>,
+    amount: u64,
+    price_lots: i64,
+    base_lots: i64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    require_keys_eq!(
+        accounts.option.option_owner.key(),
+        accounts.option.user.key(),
+        ErrorCode::ListingNotOwned
+    );
+    require!(price_lots > 0 && base_lots > 0, ErrorCode::InvalidAmount);
+
+    let held = accounts.option.user_option_account.amount;
+    mint_options::handler(
+        Context::new(
+            ctx.program_id,
+            &mut accounts.option,
+            &[],
+            Default::default(),
+        ),
+        amount,
+    )?;
+
+    let option = &accounts.option;
+    let ix = place_ask_ix(
+        &OrderAccounts {
+            owner: option.user.key(),
+            open_orders_account: accounts.open_orders_account.key(),
+            user_token_account: option.user_option_account.key(),
+            market: accounts.market.key(),
+            bids: accounts.bids.key(),
+            asks: accounts.asks.key(),
+            event_heap: accounts.event_heap.key(),
+            market_vault: accounts.market_base_vault.key(),
+            token_program: option.token_program.key(),
+        },
+        price_lots,
+        base_lots,
+    );
+    invoke(
+        &ix,
+        &[
+            option.user.to_account_info(),
+            accounts.open_orders_account.to_account_info(),
+            option.user_option_account.to_account_info(),
+            accounts.market.to_account_info(),
+            accounts.bids.to_account_info(),
+            accounts.asks.to_account_info(),
+            accounts.event_heap.to_account_info(),
+            accounts.market_base_vault.to_account_info(),
+            option.token_program.to_account_info(),
+            accounts.openbook_program.to_account_info(),
+        ],
+    )?;
+
+    // The ask may only take freshly minted options, never ones held before
+    accounts.option.user_option_account.reload()?;
+    require!(
+        accounts.option.user_option_account.amount >= held,
+        ErrorCode::ListingNotOwned
+    );
+
+    msg!("Seeded OpenBook with {} lots at {}", base_lots, price_lots);
+
+    Ok(())
+}
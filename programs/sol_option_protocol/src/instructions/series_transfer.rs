@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::Mint;
+
+/// Accounts of a `transfer_checked` of series tokens
+pub struct SeriesTransfer<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+/// Transfers option or redemption tokens, whose mint may carry a Token-2022
+/// transfer hook (see `SeriesConfig::transfer_hook_program`). The hook's
+/// program, its extra-account-metas PDA and the accounts that PDA lists are
+/// picked by address from `hook_accounts` (the instruction's remaining
+/// accounts); mints without a hook ignore them. PDAs sign with `signer_seeds`.
+pub fn transfer_series_tokens<'info>(
+    accounts: SeriesTransfer<'_, 'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        accounts.token_program.key,
+        accounts.from,
+        accounts.mint.to_account_info(),
+        accounts.to,
+        accounts.authority,
+        hook_accounts,
+        amount,
+        accounts.mint.decimals,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::ApprovedMintUpdated;
+use crate::state::{ApprovedMint, ProtocolConfig};
+use crate::utils::validation::validate_not_timelocked;
+
+/// Admin-only approval (or revocation) of a collateral/consideration mint
+#[derive(Accounts)]
+pub struct SetApprovedMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Approval of the mint (created on first update)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ApprovedMint::INIT_SPACE,
+        seeds = [ApprovedMint::SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub approved_mint: Account<'info, ApprovedMint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Revoking stops new series on the mint; existing series are unaffected
+pub fn handler(ctx: Context<SetApprovedMint>, approved: bool) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    let mint = ctx.accounts.mint.key();
+    apply(
+        &mut ctx.accounts.approved_mint,
+        mint,
+        ctx.bumps.approved_mint,
+        approved,
+    );
+    Ok(())
+}
+
+/// Records the approval (directly, or from a scheduled change)
+pub(crate) fn apply(approved_mint: &mut ApprovedMint, mint: Pubkey, bump: u8, approved: bool) {
+    approved_mint.mint = mint;
+    approved_mint.bump = bump;
+    approved_mint.approved = approved;
+
+    emit!(ApprovedMintUpdated { mint, approved });
+
+    msg!("Mint {} approved: {}", mint, approved);
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::CollateralBasketUpdated;
+use crate::state::{CollateralBasket, ProtocolConfig};
+use crate::utils::validation::validate_basket_mints;
+
+/// Admin-only update of the substitutes accepted for an escrow mint
+#[derive(Accounts)]
+pub struct SetCollateralBasket<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub base_mint: InterfaceAccount<'info, Mint>,
+
+    /// Basket of the mint (created on first update)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CollateralBasket::INIT_SPACE,
+        seeds = [CollateralBasket::SEED, base_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_basket: Account<'info, CollateralBasket>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the whole list (empty stops accepting substitutes); removing a
+/// mint only stops new deposits, series keep paying out what they hold of it
+pub fn handler(ctx: Context<SetCollateralBasket>, mints: Vec<Pubkey>) -> Result<()> {
+    let base_mint = ctx.accounts.base_mint.key();
+    validate_basket_mints(&base_mint, &mints)?;
+
+    let basket = &mut ctx.accounts.collateral_basket;
+    basket.base_mint = base_mint;
+    basket.bump = ctx.bumps.collateral_basket;
+    basket.mints = [Pubkey::default(); CollateralBasket::MAX_MINTS];
+    basket.mints[..mints.len()].copy_from_slice(&mints);
+
+    msg!("Collateral basket for {} updated: {:?}", base_mint, mints);
+
+    emit!(CollateralBasketUpdated { base_mint, mints });
+
+    Ok(())
+}
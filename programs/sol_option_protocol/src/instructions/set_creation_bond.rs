@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::CreationBondUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_not_timelocked;
+
+/// Admin-only update of the series creation bond
+#[derive(Accounts)]
+pub struct SetCreationBond<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// 0 disables the bond; series created earlier keep the bond they paid
+pub fn handler(ctx: Context<SetCreationBond>, creation_bond: u64) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(&mut ctx.accounts.protocol_config, creation_bond);
+    Ok(())
+}
+
+/// Sets the bond (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, creation_bond: u64) {
+    config.creation_bond = creation_bond;
+
+    emit!(CreationBondUpdated { creation_bond });
+
+    msg!("Creation bond updated: {} lamports", creation_bond);
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ExpiryGridUpdated;
+use crate::state::ProtocolConfig;
+
+/// Admin-only update of the expiry grid and its allowlist
+#[derive(Accounts)]
+pub struct SetExpiryGrid<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// 0 `interval` disables the grid; `offset` must be a point within the first
+/// interval (weekly Fridays 08:00 UTC: interval 604_800, offset 115_200).
+/// Existing series keep their expirations; the allowlist is replaced whole.
+pub fn handler(
+    ctx: Context<SetExpiryGrid>,
+    interval: i64,
+    offset: i64,
+    exempt: Vec<Pubkey>,
+) -> Result<()> {
+    require!(interval >= 0, ErrorCode::InvalidExpiryGrid);
+    require!(
+        interval == 0 || (0..interval).contains(&offset),
+        ErrorCode::InvalidExpiryGrid
+    );
+    require!(
+        exempt.len() <= ProtocolConfig::MAX_EXPIRY_GRID_EXEMPT,
+        ErrorCode::InvalidExpiryGrid
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.expiry_grid_interval = interval;
+    config.expiry_grid_offset = if interval == 0 { 0 } else { offset };
+    config.expiry_grid_exempt = [Pubkey::default(); ProtocolConfig::MAX_EXPIRY_GRID_EXEMPT];
+    config.expiry_grid_exempt[..exempt.len()].copy_from_slice(&exempt);
+
+    emit!(ExpiryGridUpdated {
+        interval,
+        offset: config.expiry_grid_offset,
+        exempt,
+    });
+
+    msg!(
+        "Expiry grid updated: every {}s from {}",
+        interval,
+        config.expiry_grid_offset
+    );
+
+    Ok(())
+}
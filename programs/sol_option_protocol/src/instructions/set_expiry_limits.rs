@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ExpiryLimitsUpdated;
+use crate::state::ProtocolConfig;
+
+/// Admin-only update of the time-to-expiry limits for new series
+#[derive(Accounts)]
+pub struct SetExpiryLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// 0 disables either limit; series created earlier keep their expirations
+pub fn handler(
+    ctx: Context<SetExpiryLimits>,
+    min_time_to_expiry: i64,
+    max_time_to_expiry: i64,
+) -> Result<()> {
+    require!(
+        min_time_to_expiry >= 0 && max_time_to_expiry >= 0,
+        ErrorCode::InvalidConfigValue
+    );
+    require!(
+        max_time_to_expiry == 0 || min_time_to_expiry <= max_time_to_expiry,
+        ErrorCode::InvalidConfigValue
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.min_time_to_expiry = min_time_to_expiry;
+    config.max_time_to_expiry = max_time_to_expiry;
+
+    emit!(ExpiryLimitsUpdated {
+        min_time_to_expiry,
+        max_time_to_expiry,
+    });
+
+    msg!(
+        "Expiry limits updated: {}s to {}s",
+        min_time_to_expiry,
+        max_time_to_expiry
+    );
+
+    Ok(())
+}
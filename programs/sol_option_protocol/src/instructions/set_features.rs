@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FeaturesUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_features;
+
+/// Admin-only update of the protocol feature switches
+#[derive(Accounts)]
+pub struct SetFeatures<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Replaces the whole set of switched-off features; existing series keep
+/// working, only new series (and new substitute deposits) are refused
+pub fn handler(ctx: Context<SetFeatures>, disabled_features: u32) -> Result<()> {
+    validate_features(disabled_features)?;
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.disabled_features = disabled_features;
+
+    emit!(FeaturesUpdated { disabled_features });
+
+    msg!("Disabled features: {:#b}", disabled_features);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FeeExemptUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::{validate_fee_exempt, validate_not_timelocked};
+
+/// Admin-only update of the fee-exempt accounts
+#[derive(Accounts)]
+pub struct SetFeeExempt<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// The allowlist is replaced whole (empty clears it)
+pub fn handler(ctx: Context<SetFeeExempt>, accounts: Vec<Pubkey>) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(&mut ctx.accounts.protocol_config, accounts)
+}
+
+/// Replaces the allowlist (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, accounts: Vec<Pubkey>) -> Result<()> {
+    validate_fee_exempt(&accounts)?;
+
+    config.fee_exempt = [Pubkey::default(); ProtocolConfig::MAX_FEE_EXEMPT];
+    config.fee_exempt[..accounts.len()].copy_from_slice(&accounts);
+
+    msg!("Fee-exempt accounts updated: {:?}", accounts);
+
+    emit!(FeeExemptUpdated { accounts });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FeeTiersUpdated;
+use crate::state::{FeeTier, ProtocolConfig};
+use crate::utils::validation::{validate_fee_tiers, validate_not_timelocked};
+
+/// Admin-only update of the fee volume tiers
+#[derive(Accounts)]
+pub struct SetFeeTiers<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Replaces every tier (empty removes all discounts); volumes already recorded
+/// count toward the new tiers
+pub fn handler(ctx: Context<SetFeeTiers>, tiers: Vec<FeeTier>) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(&mut ctx.accounts.protocol_config, tiers)
+}
+
+/// Replaces the tiers (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, tiers: Vec<FeeTier>) -> Result<()> {
+    validate_fee_tiers(&tiers)?;
+
+    config.fee_tiers = [FeeTier::default(); ProtocolConfig::MAX_FEE_TIERS];
+    config.fee_tiers[..tiers.len()].copy_from_slice(&tiers);
+
+    msg!("Fee tiers updated: {:?}", tiers);
+
+    emit!(FeeTiersUpdated { tiers });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FeesUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::{validate_fee_bps, validate_not_timelocked};
+
+/// Admin-only update of the mint and exercise fees
+#[derive(Accounts)]
+pub struct SetFees<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Each fee is capped at `ProtocolConfig::MAX_FEE_BPS`; 0 disables it
+pub fn handler(ctx: Context<SetFees>, mint_fee_bps: u16, exercise_fee_bps: u16) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(
+        &mut ctx.accounts.protocol_config,
+        mint_fee_bps,
+        exercise_fee_bps,
+    )
+}
+
+/// Sets the fees (directly, or from a scheduled change)
+pub(crate) fn apply(
+    config: &mut ProtocolConfig,
+    mint_fee_bps: u16,
+    exercise_fee_bps: u16,
+) -> Result<()> {
+    validate_fee_bps(mint_fee_bps)?;
+    validate_fee_bps(exercise_fee_bps)?;
+
+    config.mint_fee_bps = mint_fee_bps;
+    config.exercise_fee_bps = exercise_fee_bps;
+
+    emit!(FeesUpdated {
+        mint_fee_bps,
+        exercise_fee_bps,
+    });
+
+    msg!(
+        "Fees updated: mint {} bps, exercise {} bps",
+        mint_fee_bps,
+        exercise_fee_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::GuardianUpdated;
+use crate::state::ProtocolConfig;
+
+/// Admin-only update of the guardian key
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// `Pubkey::default()` removes the guardian (the admin can still cancel)
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    let previous_guardian = config.guardian;
+    config.guardian = guardian;
+
+    emit!(GuardianUpdated {
+        previous_guardian,
+        new_guardian: guardian,
+    });
+
+    msg!("Guardian updated: {}", guardian);
+
+    Ok(())
+}
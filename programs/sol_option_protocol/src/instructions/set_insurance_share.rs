@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::InsuranceShareUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::{validate_insurance_share, validate_not_timelocked};
+
+/// Admin-only update of the insurance fund's share of protocol fees
+#[derive(Accounts)]
+pub struct SetInsuranceShare<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// 0 sends the protocol's whole part of each fee to the fee accounts
+pub fn handler(ctx: Context<SetInsuranceShare>, insurance_share_bps: u16) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(&mut ctx.accounts.protocol_config, insurance_share_bps)
+}
+
+/// Sets the share (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, insurance_share_bps: u16) -> Result<()> {
+    validate_insurance_share(insurance_share_bps)?;
+
+    config.insurance_share_bps = insurance_share_bps;
+
+    emit!(InsuranceShareUpdated {
+        insurance_share_bps
+    });
+
+    msg!(
+        "Insurance share updated: {} bps of protocol fees",
+        insurance_share_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::MintAllowlistUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_not_timelocked;
+
+/// Admin-only switch between a verified and a permissionless deployment
+#[derive(Accounts)]
+pub struct SetMintAllowlist<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// While enabled, new series need both mints approved with `set_approved_mint`
+pub fn handler(ctx: Context<SetMintAllowlist>, enabled: bool) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(&mut ctx.accounts.protocol_config, enabled);
+    Ok(())
+}
+
+/// Switches the allowlist (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, enabled: bool) {
+    config.mint_allowlist_enabled = enabled;
+
+    emit!(MintAllowlistUpdated { enabled });
+
+    msg!("Mint allowlist enabled: {}", enabled);
+}
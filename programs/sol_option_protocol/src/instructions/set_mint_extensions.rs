@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::MintExtensionsUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_mint_extension_flags;
+
+/// Admin-only update of the risky Token-2022 mint extensions series may use
+#[derive(Accounts)]
+pub struct SetMintExtensions<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Replaces the whole set of allowed extensions; only series created
+/// afterwards are checked against it
+pub fn handler(ctx: Context<SetMintExtensions>, allowed_mint_extensions: u32) -> Result<()> {
+    validate_mint_extension_flags(allowed_mint_extensions)?;
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.allowed_mint_extensions = allowed_mint_extensions;
+
+    emit!(MintExtensionsUpdated {
+        allowed_mint_extensions
+    });
+
+    msg!("Allowed mint extensions: {:#b}", allowed_mint_extensions);
+
+    Ok(())
+}
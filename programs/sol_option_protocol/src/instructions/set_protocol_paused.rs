@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ProtocolPauseUpdated;
+use crate::state::ProtocolConfig;
+
+/// Admin or guardian toggling the protocol-wide pause
+#[derive(Accounts)]
+pub struct SetProtocolPaused<'info> {
+    #[account(
+        constraint = pauser.key() == protocol_config.guardian
+            || pauser.key() == protocol_config.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Pausing blocks mint and exercise in every series. Burn, redeem and claims
+/// stay open so users can always exit.
+pub fn handler(ctx: Context<SetProtocolPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.protocol_config.paused = paused;
+
+    emit!(ProtocolPauseUpdated {
+        paused,
+        updated_by: ctx.accounts.pauser.key(),
+    });
+
+    msg!("Protocol paused: {}", paused);
+
+    Ok(())
+}
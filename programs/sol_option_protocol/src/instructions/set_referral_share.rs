@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ReferralShareUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::{validate_not_timelocked, validate_referral_share};
+
+/// Admin-only update of the referrers' share of protocol fees
+#[derive(Accounts)]
+pub struct SetReferralShare<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// 0 keeps every fee for the protocol
+pub fn handler(ctx: Context<SetReferralShare>, referral_share_bps: u16) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(&mut ctx.accounts.protocol_config, referral_share_bps)
+}
+
+/// Sets the share (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, referral_share_bps: u16) -> Result<()> {
+    validate_referral_share(referral_share_bps)?;
+
+    config.referral_share_bps = referral_share_bps;
+
+    emit!(ReferralShareUpdated { referral_share_bps });
+
+    msg!("Referral share updated: {} bps of fees", referral_share_bps);
+
+    Ok(())
+}
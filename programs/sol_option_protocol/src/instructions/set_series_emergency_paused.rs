@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesEmergencyPauseUpdated;
+use crate::instructions::OptionData;
+use crate::state::ProtocolConfig;
+
+/// Admin or guardian toggling a series' EMERGENCY_PAUSED status flag
+#[derive(Accounts)]
+pub struct SetSeriesEmergencyPaused<'info> {
+    #[account(
+        constraint = pauser.key() == protocol_config.guardian
+            || pauser.key() == protocol_config.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub pauser: Signer<'info>,
+
+    #[account(seeds = [ProtocolConfig::SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Blocks the same operations as the authority's pause, but on a separate flag
+/// the series authority can't clear
+pub fn handler(ctx: Context<SetSeriesEmergencyPaused>, paused: bool) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+    if paused {
+        option_context.status |= OptionData::STATUS_EMERGENCY_PAUSED;
+    } else {
+        option_context.status &= !OptionData::STATUS_EMERGENCY_PAUSED;
+    }
+
+    emit!(SeriesEmergencyPauseUpdated {
+        option_context: option_context.key(),
+        paused,
+        updated_by: ctx.accounts.pauser.key(),
+    });
+
+    msg!(
+        "Series {} emergency paused: {}",
+        option_context.key(),
+        paused
+    );
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesPauseUpdated;
+use crate::instructions::OptionData;
+
+/// Series authority toggling the PAUSED status flag
+#[derive(Accounts)]
+pub struct SetSeriesPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub option_context: Account<'info, OptionData>,
+}
+
+/// Pausing blocks mint and exercise here, and order placement/fills on any
+/// marketplace market linked to this series. Burn and redeem stay open.
+pub fn handler(ctx: Context<SetSeriesPaused>, paused: bool) -> Result<()> {
+    let option_context = &mut ctx.accounts.option_context;
+    if paused {
+        option_context.status |= OptionData::STATUS_PAUSED;
+    } else {
+        option_context.status &= !OptionData::STATUS_PAUSED;
+    }
+
+    emit!(SeriesPauseUpdated {
+        option_context: option_context.key(),
+        paused,
+    });
+
+    msg!("Series {} paused: {}", option_context.key(), paused);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::StrikeTickUpdated;
+use crate::state::{ProtocolConfig, StrikeTick};
+
+/// Admin-only update of a consideration mint's strike tick size
+#[derive(Accounts)]
+pub struct SetStrikeTick<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// Tick config of the mint (created on first update)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + StrikeTick::INIT_SPACE,
+        seeds = [StrikeTick::SEED, consideration_mint.key().as_ref()],
+        bump
+    )]
+    pub strike_tick: Account<'info, StrikeTick>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 0 allows any strike; series created earlier keep their strikes
+pub fn handler(ctx: Context<SetStrikeTick>, tick_size: u64) -> Result<()> {
+    let consideration_mint = ctx.accounts.consideration_mint.key();
+    let strike_tick = &mut ctx.accounts.strike_tick;
+    strike_tick.consideration_mint = consideration_mint;
+    strike_tick.bump = ctx.bumps.strike_tick;
+    strike_tick.tick_size = tick_size;
+
+    emit!(StrikeTickUpdated {
+        consideration_mint,
+        tick_size,
+    });
+
+    msg!(
+        "Strike tick for {} updated: {}",
+        consideration_mint,
+        tick_size
+    );
+
+    Ok(())
+}
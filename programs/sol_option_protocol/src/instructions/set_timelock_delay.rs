@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::TimelockDelayUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_timelock_delay;
+
+/// Admin-only update of the timelock on parameter changes
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// The delay can only grow directly; shortening it is itself a scheduled change
+pub fn handler(ctx: Context<SetTimelockDelay>, timelock_delay: i64) -> Result<()> {
+    require!(
+        timelock_delay >= ctx.accounts.protocol_config.timelock_delay,
+        ErrorCode::TimelockRequired
+    );
+    apply(&mut ctx.accounts.protocol_config, timelock_delay)
+}
+
+/// Sets the delay (directly, or from a scheduled change)
+pub(crate) fn apply(config: &mut ProtocolConfig, timelock_delay: i64) -> Result<()> {
+    validate_timelock_delay(timelock_delay)?;
+
+    config.timelock_delay = timelock_delay;
+
+    emit!(TimelockDelayUpdated { timelock_delay });
+
+    msg!("Timelock delay updated: {}s", timelock_delay);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::TokenAccountFrozen;
+use crate::instructions::OptionData;
+
+/// Compliance key freezing or thawing a holder's option or redemption token
+/// account; the series PDA, freeze authority of both mints, signs the CPI
+#[derive(Accounts)]
+pub struct SetTokenAccountFrozen<'info> {
+    /// The series' compliance authority (its series authority if none was set)
+    pub compliance: Signer<'info>,
+
+    #[account(
+        constraint = option_context.freezable @ ErrorCode::SeriesNotFreezable,
+        constraint = compliance.key() == option_context.compliance_key() @ ErrorCode::Unauthorized
+    )]
+    pub option_context: Account<'info, OptionData>,
+
+    /// The series' option or redemption mint
+    #[account(
+        constraint = mint.key() == option_context.option_mint
+            || mint.key() == option_context.redemption_mint @ ErrorCode::InvalidOptionMint,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// A frozen account can't send, receive or burn the series' tokens, so its
+/// holder can't trade, exercise or redeem until it is thawed
+pub fn handler(ctx: Context<SetTokenAccountFrozen>, frozen: bool) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        option_context.collateral_mint.as_ref(),
+        option_context.consideration_mint.as_ref(),
+        &strike_price_bytes,
+        &expiration_bytes,
+        &option_type_byte,
+        &[option_context.bump],
+    ]];
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let account = ctx.accounts.token_account.to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    let authority = option_context.to_account_info();
+    if frozen {
+        token::freeze_account(CpiContext::new_with_signer(
+            token_program,
+            token::FreezeAccount {
+                account,
+                mint,
+                authority,
+            },
+            signer_seeds,
+        ))?;
+    } else {
+        token::thaw_account(CpiContext::new_with_signer(
+            token_program,
+            token::ThawAccount {
+                account,
+                mint,
+                authority,
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    emit!(TokenAccountFrozen {
+        option_context: option_context.key(),
+        token_account: ctx.accounts.token_account.key(),
+        frozen,
+    });
+
+    msg!(
+        "Token account {} frozen: {}",
+        ctx.accounts.token_account.key(),
+        frozen
+    );
+
+    Ok(())
+}
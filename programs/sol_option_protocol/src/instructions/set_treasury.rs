@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::TreasuryUpdated;
+use crate::state::ProtocolConfig;
+
+/// Admin-only update of the treasury owner
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// `Pubkey::default()` unsets the treasury, which disables sweeps
+pub fn handler(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    let previous_treasury = config.treasury;
+    config.treasury = treasury;
+
+    emit!(TreasuryUpdated {
+        previous_treasury,
+        new_treasury: treasury,
+    });
+
+    msg!("Treasury updated: {}", treasury);
+
+    Ok(())
+}
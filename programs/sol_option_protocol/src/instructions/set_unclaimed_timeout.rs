@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::UnclaimedTimeoutUpdated;
+use crate::state::ProtocolConfig;
+
+/// Admin-only update of the unclaimed-funds recovery timeout
+#[derive(Accounts)]
+pub struct SetUnclaimedTimeout<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// 0 disables recovery; otherwise at least `MIN_UNCLAIMED_TIMEOUT`
+pub fn handler(ctx: Context<SetUnclaimedTimeout>, unclaimed_timeout: i64) -> Result<()> {
+    require!(
+        unclaimed_timeout == 0 || unclaimed_timeout >= ProtocolConfig::MIN_UNCLAIMED_TIMEOUT,
+        ErrorCode::InvalidConfigValue
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.unclaimed_timeout = unclaimed_timeout;
+
+    emit!(UnclaimedTimeoutUpdated { unclaimed_timeout });
+
+    msg!("Unclaimed-funds timeout updated: {}s", unclaimed_timeout);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::YieldVenueUpdated;
+use crate::state::{ProtocolConfig, YieldVenue};
+
+/// Lending reserve addresses of a venue (the program reads and writes them by
+/// position, so they are fixed once approved)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct YieldVenueParams {
+    pub lending_program: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub reserve: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub share_mint: Pubkey,
+}
+
+/// Admin-only approval of the lending reserve idle escrow of a mint may go to
+#[derive(Accounts)]
+pub struct SetYieldVenue<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub escrow_mint: InterfaceAccount<'info, Mint>,
+
+    /// Venue of the mint (created on first approval)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + YieldVenue::INIT_SPACE,
+        seeds = [YieldVenue::SEED, escrow_mint.key().as_ref()],
+        bump
+    )]
+    pub yield_venue: Account<'info, YieldVenue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Approves the reserve, or switches an approved one on and off. Series
+/// that lent through a venue unwind from its reserve, so the addresses can't
+/// change once set; disabling only stops opt-ins and new lending.
+pub fn handler(ctx: Context<SetYieldVenue>, params: YieldVenueParams, enabled: bool) -> Result<()> {
+    require!(
+        params.lending_program != Pubkey::default() && params.lending_program != crate::ID,
+        ErrorCode::InvalidYieldVenue
+    );
+
+    let escrow_mint = ctx.accounts.escrow_mint.key();
+    let proposed = YieldVenue {
+        escrow_mint,
+        lending_program: params.lending_program,
+        lending_market: params.lending_market,
+        lending_market_authority: params.lending_market_authority,
+        reserve: params.reserve,
+        reserve_liquidity_supply: params.reserve_liquidity_supply,
+        share_mint: params.share_mint,
+        enabled,
+        bump: ctx.bumps.yield_venue,
+    };
+
+    let venue = &mut ctx.accounts.yield_venue;
+    if venue.lending_program != Pubkey::default() {
+        require!(venue.same_reserve(&proposed), ErrorCode::InvalidYieldVenue);
+    }
+    venue.set_inner(proposed);
+
+    msg!(
+        "Yield venue for {}: {} (enabled: {})",
+        escrow_mint,
+        params.reserve,
+        enabled
+    );
+
+    emit!(YieldVenueUpdated {
+        escrow_mint,
+        lending_program: params.lending_program,
+        reserve: params.reserve,
+        enabled,
+    });
+
+    Ok(())
+}
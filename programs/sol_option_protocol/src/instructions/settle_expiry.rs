@@ -0,0 +1,303 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesSettled;
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::oracle::guard::{SETTLEMENT_FALLBACK_DELAY, SETTLEMENT_FALLBACK_WINDOW};
+use crate::oracle::{resolve_price, PriceRequest};
+use crate::utils::{
+    netting::{
+        calculate_binary_settlement, calculate_net_settlement, finishes_in_the_money,
+        intrinsic_value, NetSettlement,
+    },
+    validation::{validate_exercise_closed, validate_not_settled},
+};
+
+/// Permissionless settlement crank: anyone can record the settlement price
+/// and freeze the vault balances once the series has expired
+#[derive(Accounts)]
+pub struct SettleExpiry<'info> {
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Consideration mint (validated against stored value in option_context)
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// Collateral mint (validated against stored value in option_context)
+    #[account(
+        constraint = collateral_mint.key() == option_context.collateral_mint
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    /// Collateral vault (validated against stored value in option_context)
+    #[account(
+        constraint = collateral_vault.key() == option_context.collateral_vault
+    )]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Consideration vault (validated against stored value in option_context)
+    #[account(
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Validated against option_context.primary_oracle by the oracle module
+    pub primary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated against option_context.secondary_oracle by the oracle module
+    pub secondary_oracle: Option<UncheckedAccount<'info>>,
+}
+
+/// Records the settlement price from the oracle hierarchy and snapshots the
+/// vault balances and redemption supply, so every redeemer is paid from the
+/// same balances regardless of redemption order
+/// With a settlement price, outstanding ITM options are netted against the
+/// vaults in aggregate (see `calculate_net_settlement`); options written on
+/// margin are netted against margin instead, never against the redemption pool
+/// Binary series instead reserve their fixed payout for every option when the
+/// price finishes beyond the strike
+/// Falls through to the secondary feed when the primary is stale or missing;
+/// series without oracles settle with only the snapshot, and so do knocked-out
+/// and frozen series (as soon as they are knocked out or frozen)
+/// A series nobody settled within SETTLEMENT_FALLBACK_DELAY of expiration also
+/// accepts a price published up to SETTLEMENT_FALLBACK_WINDOW after it, so a
+/// missed window never leaves it unsettleable
+pub fn handler(ctx: Context<SettleExpiry>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+
+    // Validation
+    validate_exercise_closed(option_context)?;
+    validate_not_settled(option_context)?;
+    require!(
+        !option_context.is_forward(),
+        ErrorCode::ForwardSettlementOnly
+    );
+    require!(
+        option_context.spread_hedged == 0,
+        ErrorCode::SpreadsOutstanding
+    );
+
+    let oracle_reading = if settles_on_price(option_context) {
+        let primary_account = ctx
+            .accounts
+            .primary_oracle
+            .as_ref()
+            .map(|a| a.to_account_info());
+        let secondary_account = ctx
+            .accounts
+            .secondary_oracle
+            .as_ref()
+            .map(|a| a.to_account_info());
+
+        // Settlement prices must be published around expiration and pass each
+        // feed's guard; once the fallback opens, so may a bounded late one
+        let fallback_opens = option_context
+            .expiration
+            .checked_add(SETTLEMENT_FALLBACK_DELAY)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let late_window = if Clock::get()?.unix_timestamp >= fallback_opens {
+            SETTLEMENT_FALLBACK_WINDOW
+        } else {
+            0
+        };
+        let request = PriceRequest {
+            reference_time: option_context.expiration,
+            late_window,
+            previous_price: option_context.last_oracle_price,
+            previous_update: option_context.last_oracle_update,
+            strike_exponent: option_context.strike_exponent,
+        };
+
+        Some(resolve_price(
+            &option_context.primary_oracle,
+            primary_account.as_ref(),
+            &option_context.secondary_oracle,
+            secondary_account.as_ref(),
+            &request,
+        )?)
+    } else {
+        None
+    };
+
+    // Record the settlement (OptionContext bookkeeping)
+    let series_key = ctx.accounts.option_context.key();
+    // Internal accounting, not live balances: donations to the vaults can't skew shares
+    let collateral = ctx.accounts.option_context.collateral_balance();
+    let consideration = ctx.accounts.option_context.consideration_balance();
+    let redemption_supply = ctx.accounts.redemption_mint.supply;
+    let option_supply = ctx.accounts.option_mint.supply;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Net outstanding options against the vaults; without a price they expire worthless
+    let (net, margin_owed) = net_expiry(
+        &ctx.accounts.option_context,
+        oracle_reading
+            .as_ref()
+            .map(|(settlement_price, _, _)| *settlement_price),
+        option_supply,
+    )?;
+
+    let option_context = &mut ctx.accounts.option_context;
+    if let Some((settlement_price, price, source)) = oracle_reading {
+        option_context.settlement_price = settlement_price;
+        option_context.settlement_source = source;
+        option_context.last_oracle_price = settlement_price;
+        option_context.last_oracle_update = price.publish_time;
+
+        msg!(
+            "Settled series {} at {} (source: {:?}, published {})",
+            series_key,
+            settlement_price,
+            source,
+            price.publish_time
+        );
+    }
+
+    // Freeze the series: redeem and claim_net_payoff pay pro-rata from these values
+    option_context.settled_at = now;
+    option_context.settled_collateral = net.redemption_collateral;
+    option_context.settled_consideration = net.redemption_consideration;
+    option_context.settled_supply = redemption_supply;
+    option_context.settled_option_supply = option_supply;
+    option_context.net_option_collateral = net.option_collateral;
+    option_context.net_option_consideration = net.option_consideration;
+
+    // Margin owed to option holders joins the accounting claim_net_payoff pays
+    // from; the rest stays with the margin positions
+    if margin_owed > 0 {
+        option_context.margin_collateral = option_context
+            .margin_collateral
+            .checked_sub(margin_owed)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if option_context.is_put() {
+            option_context.credit_consideration(margin_owed)?;
+        } else {
+            option_context.credit_collateral(margin_owed)?;
+        }
+    }
+
+    emit!(SeriesSettled {
+        option_context: series_key,
+        settlement_price: option_context.settlement_price,
+        collateral,
+        consideration,
+        redemption_supply,
+        option_supply,
+        net_option_collateral: option_context.net_option_collateral,
+        net_option_consideration: option_context.net_option_consideration,
+        settled_at: now,
+    });
+
+    check_series(
+        option_context,
+        SeriesAccounts {
+            collateral_vault: Some(ctx.accounts.collateral_vault.to_account_info()),
+            consideration_vault: Some(ctx.accounts.consideration_vault.to_account_info()),
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+        },
+    )
+}
+
+/// Whether settlement reads a price: knocked-out and frozen series and
+/// legacy puts expire worthless, and series without oracles settle with only
+/// the snapshot
+pub fn settles_on_price(option_context: &OptionData) -> bool {
+    !option_context.is_knocked_out()
+        && !option_context.is_frozen()
+        && !option_context.is_legacy_put()
+        && (option_context.primary_oracle.is_configured()
+            || option_context.secondary_oracle.is_configured())
+}
+
+/// Splits the series' vault accounting at `settlement_price` (`None`: every
+/// option expires worthless) with `option_supply` options outstanding
+/// Returns the split, with the intrinsic value margin positions owe option
+/// holders already added to the option side, and that margin amount
+pub fn net_expiry(
+    option_context: &OptionData,
+    settlement_price: Option<u64>,
+    option_supply: u64,
+) -> Result<(NetSettlement, u64)> {
+    // Internal accounting, not live balances: donations to the vaults can't skew shares
+    let collateral = option_context.collateral_balance();
+    let consideration = option_context.consideration_balance();
+    let margin_written = option_context.margin_written;
+    let pool_options = option_supply.saturating_sub(margin_written);
+
+    let Some(settlement_price) = settlement_price else {
+        return Ok((
+            NetSettlement {
+                redemption_collateral: collateral,
+                redemption_consideration: consideration,
+                ..NetSettlement::default()
+            },
+            0,
+        ));
+    };
+
+    // Binaries pay a fixed amount in the money (and never write on margin)
+    if option_context.is_binary() {
+        let net = calculate_binary_settlement(
+            collateral,
+            consideration,
+            option_context.underlying_amount(pool_options)?,
+            finishes_in_the_money(
+                option_context.strike_price,
+                settlement_price,
+                option_context.option_type.is_put_payoff(),
+            ),
+            option_context.binary_payout,
+            option_context.strike_scale,
+        )?;
+        return Ok((net, 0));
+    }
+
+    let mut net = calculate_net_settlement(
+        collateral,
+        consideration,
+        option_context.underlying_amount(pool_options)?,
+        option_context.strike_price,
+        settlement_price,
+        option_context.strike_scale,
+        option_context.is_put(),
+    )?;
+    // Margin positions owe the intrinsic value of what they wrote
+    let margin_owed = intrinsic_value(
+        option_context.underlying_amount(margin_written)?,
+        option_context.strike_price,
+        settlement_price,
+        option_context.strike_scale,
+        option_context.is_put(),
+    )?
+    .min(option_context.margin_collateral);
+    if option_context.is_put() {
+        net.option_consideration = net
+            .option_consideration
+            .checked_add(margin_owed)
+            .ok_or(ErrorCode::MathOverflow)?;
+    } else {
+        net.option_collateral = net
+            .option_collateral
+            .checked_add(margin_owed)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    Ok((net, margin_owed))
+}
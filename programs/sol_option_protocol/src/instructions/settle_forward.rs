@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesSettled;
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::utils::{
+    netting::calculate_forward_settlement,
+    validation::{validate_exercise_closed, validate_not_settled},
+};
+
+/// Permissionless settlement crank for forwards: anyone can deliver an
+/// expired forward at its strike
+#[derive(Accounts)]
+pub struct SettleForward<'info> {
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Settles a forward at its stored strike, with no oracle: option holders are
+/// owed all the escrowed collateral (paid through claim_net_payoff) and
+/// redemption holders all the escrowed strike (paid through redeem)
+pub fn handler(ctx: Context<SettleForward>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+
+    // Validation
+    require!(option_context.is_forward(), ErrorCode::InvalidOptionType);
+    validate_exercise_closed(option_context)?;
+    validate_not_settled(option_context)?;
+
+    let series_key = option_context.key();
+    // Internal accounting, not live balances: donations to the vaults can't skew shares
+    let collateral = option_context.collateral_balance();
+    let consideration = option_context.consideration_balance();
+    let redemption_supply = ctx.accounts.redemption_mint.supply;
+    let option_supply = ctx.accounts.option_mint.supply;
+    let now = Clock::get()?.unix_timestamp;
+    let net = calculate_forward_settlement(collateral, consideration);
+
+    // Freeze the series: redeem and claim_net_payoff pay pro-rata from these values
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.settlement_price = option_context.strike_price;
+    option_context.settled_at = now;
+    option_context.settled_collateral = net.redemption_collateral;
+    option_context.settled_consideration = net.redemption_consideration;
+    option_context.settled_supply = redemption_supply;
+    option_context.settled_option_supply = option_supply;
+    option_context.net_option_collateral = net.option_collateral;
+    option_context.net_option_consideration = net.option_consideration;
+
+    emit!(SeriesSettled {
+        option_context: series_key,
+        settlement_price: option_context.settlement_price,
+        collateral,
+        consideration,
+        redemption_supply,
+        option_supply,
+        net_option_collateral: option_context.net_option_collateral,
+        net_option_consideration: option_context.net_option_consideration,
+        settled_at: now,
+    });
+
+    msg!(
+        "Delivered forward {} at {}: {} collateral to {} options, {} consideration to {} shorts",
+        series_key,
+        option_context.settlement_price,
+        collateral,
+        option_supply,
+        consideration,
+        redemption_supply
+    );
+
+    check_series(
+        option_context,
+        SeriesAccounts {
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
+}
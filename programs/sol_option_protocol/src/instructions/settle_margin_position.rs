@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::MarginContext;
+use crate::invariants::check_series;
+use crate::utils::{netting::intrinsic_value, validation::validate_settled};
+
+/// Closes a margin position after settlement: the intrinsic value of what it
+/// wrote already went to option holders at settlement, the rest of its
+/// margin returns to the writer
+///
+/// Margin is settled in aggregate, so the shortfall of a position that was
+/// never liquidated comes out of what the remaining margin writers get back.
+pub fn handler(ctx: Context<MarginContext>) -> Result<()> {
+    // Validation
+    validate_settled(&ctx.accounts.option_context)?;
+    require_keys_eq!(
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.user.key(),
+        ErrorCode::Unauthorized
+    );
+    let position = &ctx.accounts.writer_position;
+    require!(
+        position.margin_written > 0 || position.margin_collateral > 0,
+        ErrorCode::NothingToExit
+    );
+
+    let option_context = &ctx.accounts.option_context;
+    let owed = intrinsic_value(
+        option_context.underlying_amount(position.margin_written)?,
+        option_context.strike_price,
+        option_context.settlement_price,
+        option_context.strike_scale,
+        option_context.is_put(),
+    )?;
+    let released = position
+        .margin_collateral
+        .saturating_sub(owed)
+        .min(option_context.margin_collateral);
+
+    // 1. Return the remaining margin to the writer
+    if released > 0 {
+        ctx.accounts.release(released)?;
+    }
+
+    // 2. Close out the position (the owed part left margin at settlement)
+    let written = ctx.accounts.writer_position.margin_written;
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.margin_collateral -= released;
+    option_context.margin_written = option_context.margin_written.saturating_sub(written);
+    let position = &mut ctx.accounts.writer_position;
+    position.margin_written = 0;
+    position.margin_collateral = 0;
+    position.health_bps = u64::MAX;
+
+    msg!(
+        "Settled margin position of {} in series {}: {} written, {} returned",
+        position.writer,
+        ctx.accounts.option_context.key(),
+        written,
+        released
+    );
+
+    check_series(&ctx.accounts.option_context, ctx.accounts.series_accounts())
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::SpreadContext;
+use crate::state::WriterPosition;
+use crate::utils::{
+    math::calculate_pro_rata_share_u128,
+    validation::{validate_not_settled, validate_settled},
+};
+
+/// Permissionless crank once the long series has settled: claims the locked
+/// longs' netted payoff into the position's margin and closes the escrow
+///
+/// The short series can't settle while spreads are open, so its margin
+/// netting always sees the long leg's payoff.
+pub fn handler(ctx: Context<SpreadContext>) -> Result<()> {
+    // Validation
+    validate_settled(&ctx.accounts.long_option_context)?;
+    validate_not_settled(&ctx.accounts.option_context)?;
+    let locked = ctx.accounts.writer_position.spread_locked;
+    require!(locked > 0, ErrorCode::NothingToExit);
+
+    // Same pro-rata share claim_net_payoff pays the long series' option holders
+    let long = &ctx.accounts.long_option_context;
+    let net_option_payoff = if long.is_put() {
+        long.net_option_consideration
+    } else {
+        long.net_option_collateral
+    };
+    let payout =
+        calculate_pro_rata_share_u128(net_option_payoff, locked, long.settled_option_supply)?;
+
+    // 1. Burn the locked longs (the position signs for its escrow)
+    let option_context_key = ctx.accounts.option_context.key();
+    let writer = ctx.accounts.writer_position.writer;
+    let position_bump = ctx.accounts.writer_position.bump;
+    let position_seeds: &[&[&[u8]]] = &[&[
+        WriterPosition::SEED,
+        option_context_key.as_ref(),
+        writer.as_ref(),
+        &[position_bump],
+    ]];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.long_option_mint.to_account_info(),
+                from: ctx.accounts.spread_escrow.to_account_info(),
+                authority: ctx.accounts.writer_position.to_account_info(),
+            },
+            position_seeds,
+        ),
+        locked,
+    )?;
+
+    // 2. Move the payoff from the long series' vault into the position's margin
+    if payout > 0 {
+        let long = &ctx.accounts.long_option_context;
+        let mint = ctx.accounts.margin_mint();
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: ctx.accounts.long_vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: ctx.accounts.margin_vault.to_account_info(),
+                    authority: long.to_account_info(),
+                },
+                &[&[
+                    b"option_context",
+                    long.collateral_mint.as_ref(),
+                    long.consideration_mint.as_ref(),
+                    &long.strike_price.to_le_bytes(),
+                    &long.expiration.to_le_bytes(),
+                    &[long.option_type as u8],
+                    &[long.bump],
+                ]],
+            ),
+            payout,
+            mint.decimals,
+        )?;
+    }
+
+    // 3. Close the empty escrow, returning its rent to the writer
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.spread_escrow.to_account_info(),
+            destination: ctx.accounts.writer.to_account_info(),
+            authority: ctx.accounts.writer_position.to_account_info(),
+        },
+        position_seeds,
+    ))?;
+
+    // Bookkeeping: the long series pays out, the position's margin grows
+    let long = &mut ctx.accounts.long_option_context;
+    if long.is_put() {
+        long.debit_consideration(payout)?;
+    } else {
+        long.debit_collateral(payout)?;
+    }
+    let position = &mut ctx.accounts.writer_position;
+    position.spread_locked = 0;
+    position.margin_collateral = position
+        .margin_collateral
+        .checked_add(payout)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.spread_hedged = option_context.spread_hedged.saturating_sub(locked);
+    option_context.margin_collateral = option_context
+        .margin_collateral
+        .checked_add(payout)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!(
+        "Settled spread of {} in series {}: {} longs paid {} into margin",
+        writer,
+        option_context_key,
+        locked,
+        payout
+    );
+
+    ctx.accounts.check_invariants()
+}
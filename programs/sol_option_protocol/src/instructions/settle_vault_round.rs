@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+use anchor_spl::token_interface::TokenAccount;
+use spl_marketplace::cpi::accounts::CancelOrder;
+use spl_marketplace::program::SplMarketplace;
+use spl_marketplace::state::order::Order;
+
+use crate::errors::ErrorCode;
+use crate::events::VaultRoundSettled;
+use crate::state::OptionVault;
+// The generated client modules of VaultSeries come along for the nesting
+use crate::instructions::vault_round::*;
+
+/// Permissionless close of a vault round once its series redeems
+#[derive(Accounts)]
+pub struct SettleVaultRound<'info> {
+    #[account(
+        mut,
+        constraint = !option_vault.is_idle() @ ErrorCode::VaultRoundNotActive
+    )]
+    pub option_vault: Box<Account<'info, OptionVault>>,
+
+    /// CHECK: System-owned PDA holding the vault's tokens (gets the ask's rent back)
+    #[account(
+        mut,
+        seeds = [OptionVault::AUTHORITY_SEED, option_vault.key().as_ref()],
+        bump = option_vault.authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub series: VaultSeries<'info>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: The round's ask (closed when cancelled, so it may be empty)
+    #[account(mut, address = option_vault.ask @ ErrorCode::VaultSeriesMismatch)]
+    pub ask: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA of the ask; checked by the marketplace
+    #[account(mut)]
+    pub ask_escrow: UncheckedAccount<'info>,
+}
+
+/// Cancels what is left of the round's ask, then redeems all of the vault's
+/// redemption tokens, bringing the unexercised escrow (and the strike paid by
+/// exercisers) back into the vault's accounts. Options the ask didn't sell
+/// expired unexercised, so their escrow comes back with the rest. Deposits
+/// and withdrawals reopen, and the manager can start the next round.
+pub fn handler(ctx: Context<SettleVaultRound>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let authority = accounts.vault_authority.key();
+    accounts
+        .series
+        .validate_for(&accounts.option_vault, &authority)?;
+    require_keys_eq!(
+        accounts.series.option_context.key(),
+        accounts.option_vault.series,
+        ErrorCode::VaultSeriesMismatch
+    );
+
+    let option_vault_key = accounts.option_vault.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        OptionVault::AUTHORITY_SEED,
+        option_vault_key.as_ref(),
+        &[accounts.option_vault.authority_bump],
+    ]];
+    let unsold = if accounts.ask.data_is_empty() {
+        0
+    } else {
+        let data = accounts.ask.try_borrow_data()?;
+        Order::try_deserialize(&mut &data[..])?.remaining()
+    };
+    if unsold > 0 {
+        spl_marketplace::cpi::cancel_order(CpiContext::new_with_signer(
+            accounts.marketplace_program.to_account_info(),
+            CancelOrder {
+                user: accounts.vault_authority.to_account_info(),
+                order: accounts.ask.to_account_info(),
+                return_mint: accounts.series.option_mint.to_account_info(),
+                user_return_account: accounts.series.vault_option_account.to_account_info(),
+                escrow: accounts.ask_escrow.to_account_info(),
+                token_program: accounts.series.token_program.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    let redeemed = {
+        let data = accounts.series.vault_redemption_account.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut &data[..])?.amount
+    };
+    if redeemed > 0 {
+        invoke_as_vault(
+            &accounts.option_vault,
+            accounts
+                .series
+                .option_context_accounts(authority)
+                .to_account_metas(None),
+            crate::instruction::Redeem { amount: redeemed }.data(),
+            &accounts
+                .series
+                .infos(&accounts.vault_authority.to_account_info(), &[]),
+        )?;
+    }
+
+    let vault = &mut accounts.option_vault;
+    let series = vault.series;
+    vault.series = Pubkey::default();
+    vault.ask = Pubkey::default();
+    vault.written = 0;
+
+    emit!(VaultRoundSettled {
+        option_vault: vault.key(),
+        round: vault.round,
+        series,
+        redeemed,
+    });
+
+    msg!(
+        "Vault {} settled round {}: redeemed {}",
+        vault.key(),
+        vault.round,
+        redeemed
+    );
+
+    Ok(())
+}
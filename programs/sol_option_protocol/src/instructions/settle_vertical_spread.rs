@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+
+use crate::errors::ErrorCode;
+use crate::instructions::VerticalSpreadContext;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::VerticalSpread;
+use crate::utils::{
+    math::calculate_pro_rata_share_u128, netting::intrinsic_value, validation::validate_settled,
+};
+
+/// Permissionless crank once the long series has settled: claims the locked
+/// longs' netted payoff into the spread vault, then splits the vault between
+/// spread token holders (the short series' intrinsic value at the settlement
+/// price) and writers (the rest)
+///
+/// Both legs share expiry and primary oracle, so the long series' settlement
+/// price is the short series' too.
+pub fn handler(ctx: Context<VerticalSpreadContext>) -> Result<()> {
+    // Validation
+    validate_settled(&ctx.accounts.long_option_context)?;
+    let spread = &ctx.accounts.vertical_spread;
+    require!(
+        spread.short_series == ctx.accounts.short_option_context.key(),
+        ErrorCode::InvalidSpread
+    );
+    require!(!spread.is_settled(), ErrorCode::SeriesAlreadySettled);
+
+    // Same pro-rata share claim_net_payoff pays the long series' option holders
+    let long = &ctx.accounts.long_option_context;
+    let locked = ctx.accounts.long_escrow.amount;
+    let net_option_payoff = if long.is_put() {
+        long.net_option_consideration
+    } else {
+        long.net_option_collateral
+    };
+    let payout = if locked > 0 {
+        calculate_pro_rata_share_u128(net_option_payoff, locked, long.settled_option_supply)?
+    } else {
+        0
+    };
+
+    // 1. Burn the locked longs (the spread signs for its escrow)
+    let spread_seeds: &[&[&[u8]]] = &[&[
+        VerticalSpread::SEED,
+        spread.short_series.as_ref(),
+        spread.long_series.as_ref(),
+        &[spread.bump],
+    ]];
+    if locked > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: ctx.accounts.long_option_mint.to_account_info(),
+                    from: ctx.accounts.long_escrow.to_account_info(),
+                    authority: spread.to_account_info(),
+                },
+                spread_seeds,
+            ),
+            locked,
+        )?;
+    }
+
+    // 2. Move the payoff from the long series' vault into the spread vault
+    if payout > 0 {
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: ctx.accounts.long_vault.to_account_info(),
+                    mint: ctx.accounts.backing_mint.to_account_info(),
+                    to: ctx.accounts.spread_vault.to_account_info(),
+                    authority: long.to_account_info(),
+                },
+                &[&[
+                    b"option_context",
+                    long.collateral_mint.as_ref(),
+                    long.consideration_mint.as_ref(),
+                    &long.strike_price.to_le_bytes(),
+                    &long.expiration.to_le_bytes(),
+                    &[long.option_type as u8],
+                    &[long.bump],
+                ]],
+            ),
+            payout,
+            ctx.accounts.backing_mint.decimals,
+        )?;
+    }
+
+    // 3. Split the vault: holders get the short leg's intrinsic value
+    let short = &ctx.accounts.short_option_context;
+    let price = long.settlement_price;
+    let total = spread
+        .backing
+        .checked_add(payout)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let holder_payout = intrinsic_value(
+        short.underlying_amount(ctx.accounts.spread_mint.supply)?,
+        short.strike_price,
+        price,
+        short.strike_scale,
+        short.is_put(),
+    )?
+    .min(total);
+
+    // Bookkeeping: the long series pays out, the spread records its split
+    let long = &mut ctx.accounts.long_option_context;
+    if long.is_put() {
+        long.debit_consideration(payout)?;
+    } else {
+        long.debit_collateral(payout)?;
+    }
+    let spread = &mut ctx.accounts.vertical_spread;
+    spread.settled_at = Clock::get()?.unix_timestamp;
+    spread.settlement_price = price;
+    spread.holder_payout = holder_payout;
+    spread.writer_payout = total - holder_payout;
+    spread.backing = 0;
+
+    msg!(
+        "Settled vertical spread {} at {}: {} longs paid {}, {} to holders, {} to writers",
+        spread.key(),
+        price,
+        locked,
+        payout,
+        spread.holder_payout,
+        spread.writer_payout
+    );
+
+    let long = &ctx.accounts.long_option_context;
+    check_series(
+        long,
+        SeriesAccounts {
+            option_mint: Some(ctx.accounts.long_option_mint.to_account_info()),
+            ..SeriesAccounts::escrow_vault(long, ctx.accounts.long_vault.to_account_info())
+        },
+    )
+}
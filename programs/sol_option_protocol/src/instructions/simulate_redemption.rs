@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::instructions::settle_expiry::{net_expiry, settles_on_price};
+use crate::instructions::OptionData;
+use crate::utils::{
+    math::calculate_pro_rata_share_u128, netting::calculate_forward_settlement,
+    validation::validate_amount,
+};
+
+/// Read-only accounts needed to simulate a settlement: the series, whose
+/// internal vault accounting the split is computed from, and the mints whose
+/// supplies settlement snapshots
+#[derive(Accounts)]
+pub struct SimulateRedemption<'info> {
+    pub option_context: Account<'info, OptionData>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Returned to the caller via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RedemptionOutcome {
+    pub settlement_price: u64,
+    pub in_the_money: bool,
+    /// Option holder: netted payoff claim_net_payoff pays for `amount` options
+    /// (calls are paid in collateral, puts and binaries in consideration)
+    pub option_collateral: u64,
+    pub option_consideration: u64,
+    /// Redemption holder: pro-rata vault payout for `amount` redemption tokens
+    pub redemption_collateral: u64,
+    pub redemption_consideration: u64,
+}
+
+/// Simulates what `amount` option tokens and `amount` redemption tokens would
+/// receive if the series settled at `settlement_price` with the current vault
+/// accounting
+/// Uses the same split as settle_expiry (settle_forward for forwards), so the
+/// quotes match what claim_net_payoff and redeem pay after settlement
+/// Series that settle without a price (knocked out, frozen or without oracles)
+/// are simulated expiring worthless, as settle_expiry would settle them
+pub fn handler(
+    ctx: Context<SimulateRedemption>,
+    settlement_price: u64,
+    amount: u64,
+) -> Result<RedemptionOutcome> {
+    validate_amount(amount)?;
+
+    let option_context = &ctx.accounts.option_context;
+    let option_supply = ctx.accounts.option_mint.supply;
+    // Outstanding shorts, as settlement snapshots them (total_supply misses
+    // the redemption tokens early claims have burned)
+    let redemption_supply = ctx.accounts.redemption_mint.supply;
+
+    let net = if option_context.is_forward() {
+        calculate_forward_settlement(
+            option_context.collateral_balance(),
+            option_context.consideration_balance(),
+        )
+    } else {
+        let price = settles_on_price(option_context).then_some(settlement_price);
+        net_expiry(option_context, price, option_supply)?.0
+    };
+
+    Ok(RedemptionOutcome {
+        settlement_price,
+        in_the_money: net.in_the_money,
+        option_collateral: share(net.option_collateral, amount, option_supply)?,
+        option_consideration: share(net.option_consideration, amount, option_supply)?,
+        redemption_collateral: share(net.redemption_collateral, amount, redemption_supply)?,
+        redemption_consideration: share(net.redemption_consideration, amount, redemption_supply)?,
+    })
+}
+
+/// Pro-rata share of `balance` for `amount` of `supply` tokens (nothing when
+/// none are outstanding)
+fn share(balance: u64, amount: u64, supply: u64) -> Result<u64> {
+    if supply == 0 {
+        return Ok(0);
+    }
+    calculate_pro_rata_share_u128(balance, amount, supply)
+}
@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::series_transfer::{transfer_series_tokens, SeriesTransfer};
+use crate::instructions::{live_price, position_requirement, OptionData};
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::WriterPosition;
+use crate::utils::margin::health_factor_bps;
+
+/// Shared accounts struct for spreads (create, close, settle)
+///
+/// A spread hedges a margin position's short with long options of another
+/// series (same mints and expiry, further out of the money) locked in a token
+/// account owned by the position.
+#[derive(Accounts)]
+pub struct SpreadContext<'info> {
+    /// Writer (create, close) or any cranker (settle); pays for the escrow
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Series the position is short on margin
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        mut,
+        seeds = [
+            WriterPosition::SEED,
+            option_context.key().as_ref(),
+            writer_position.writer.as_ref(),
+        ],
+        bump = writer_position.bump
+    )]
+    pub writer_position: Account<'info, WriterPosition>,
+
+    /// CHECK: Position owner, receives the escrow's rent when the spread settles
+    #[account(
+        mut,
+        constraint = writer.key() == writer_position.writer @ ErrorCode::Unauthorized
+    )]
+    pub writer: UncheckedAccount<'info>,
+
+    /// Series whose long options hedge the short (fixed by the first spread)
+    #[account(
+        mut,
+        constraint = writer_position.spread_series == Pubkey::default()
+            || long_option_context.key() == writer_position.spread_series
+            @ ErrorCode::InvalidSpread
+    )]
+    pub long_option_context: Account<'info, OptionData>,
+
+    /// Long leg's option mint (validated against stored value in long_option_context)
+    #[account(
+        mut,
+        constraint = long_option_mint.key() == long_option_context.option_mint
+    )]
+    pub long_option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Locked long options, owned by the position
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [WriterPosition::SPREAD_ESCROW_SEED, writer_position.key().as_ref()],
+        bump,
+        token::mint = long_option_mint,
+        token::authority = writer_position
+    )]
+    pub spread_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's long-leg option account (locked from, unlocked to)
+    #[account(mut, token::mint = long_option_mint, token::authority = user)]
+    pub user_long_option_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Collateral mint (validated against stored value in option_context)
+    #[account(
+        constraint = collateral_mint.key() == option_context.collateral_mint
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    /// Consideration mint (validated against stored value in option_context)
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// Short series' vault of the backing asset (holds the position's margin)
+    #[account(
+        mut,
+        constraint = margin_vault.key() == option_context.margin_vault()
+    )]
+    pub margin_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Long series' vault of the same asset (pays the long leg's netted payoff)
+    #[account(
+        mut,
+        constraint = long_vault.key() == long_option_context.margin_vault()
+    )]
+    pub long_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's account of the backing asset (receives released margin)
+    #[account(
+        mut,
+        token::authority = user,
+        constraint = user_margin_account.mint == option_context.margin_mint()
+            @ ErrorCode::InvalidMarginAccount
+    )]
+    pub user_margin_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Validated against option_context.primary_oracle by the oracle module
+    pub primary_oracle: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated against option_context.secondary_oracle by the oracle module
+    pub secondary_oracle: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SpreadContext<'info> {
+    /// Mint of the asset margin is held in (shared by both legs)
+    pub fn margin_mint(&self) -> &InterfaceAccount<'info, Mint> {
+        if self.option_context.is_put() {
+            &self.consideration_mint
+        } else {
+            &self.collateral_mint
+        }
+    }
+
+    /// Current guarded price from the short series' oracle hierarchy
+    pub fn live_price(&self) -> Result<u64> {
+        live_price(
+            &self.option_context,
+            self.primary_oracle.as_ref(),
+            self.secondary_oracle.as_ref(),
+        )
+    }
+
+    /// Margin the position needs at `price` with a `margin_bps` buffer
+    pub fn requirement(&self, price: u64, margin_bps: u16) -> Result<u64> {
+        position_requirement(
+            &self.writer_position,
+            &self.option_context,
+            price,
+            margin_bps,
+        )
+    }
+
+    /// Records the position's health against the maintenance requirement at `price`
+    pub fn record_health(&mut self, price: u64) -> Result<u64> {
+        let requirement = self.requirement(price, self.option_context.maintenance_margin_bps)?;
+        let health = health_factor_bps(self.writer_position.margin_collateral, requirement)?;
+        self.writer_position.health_bps = health;
+        Ok(health)
+    }
+
+    /// Asserts the invariants of both legs' series against the vaults at hand
+    /// (and the long leg's option mint)
+    pub fn check_invariants(&self) -> Result<()> {
+        check_series(
+            &self.option_context,
+            SeriesAccounts::escrow_vault(&self.option_context, self.margin_vault.to_account_info()),
+        )?;
+        check_series(
+            &self.long_option_context,
+            SeriesAccounts {
+                option_mint: Some(self.long_option_mint.to_account_info()),
+                ..SeriesAccounts::escrow_vault(
+                    &self.long_option_context,
+                    self.long_vault.to_account_info(),
+                )
+            },
+        )
+    }
+
+    /// Moves `amount` locked long options out of the escrow (the position
+    /// signs); `hook_accounts` serve the option mint's transfer hook, if any
+    pub fn unlock(&self, amount: u64, hook_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let position = &self.writer_position;
+        let option_context_key = self.option_context.key();
+        transfer_series_tokens(
+            SeriesTransfer {
+                token_program: self.token_program.to_account_info(),
+                from: self.spread_escrow.to_account_info(),
+                mint: &self.long_option_mint,
+                to: self.user_long_option_account.to_account_info(),
+                authority: position.to_account_info(),
+            },
+            hook_accounts,
+            amount,
+            &[&[
+                WriterPosition::SEED,
+                option_context_key.as_ref(),
+                position.writer.as_ref(),
+                &[position.bump],
+            ]],
+        )
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+use spl_marketplace::program::SplMarketplace;
+
+use crate::errors::ErrorCode;
+use crate::events::VaultRoundStarted;
+use crate::state::OptionVault;
+// The generated client modules of VaultSeries come along for the nesting
+use crate::instructions::vault_round::*;
+
+/// Manager writing a vault round: `mint_and_list` signed by the vault authority
+#[derive(Accounts)]
+pub struct StartVaultRound<'info> {
+    pub manager: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = manager @ ErrorCode::Unauthorized,
+        constraint = option_vault.is_idle() @ ErrorCode::VaultRoundActive
+    )]
+    pub option_vault: Box<Account<'info, OptionVault>>,
+
+    /// CHECK: System-owned PDA signing the round and paying its accounts' rent
+    #[account(
+        mut,
+        seeds = [OptionVault::AUTHORITY_SEED, option_vault.key().as_ref()],
+        bump = option_vault.authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub series: VaultSeries<'info>,
+
+    pub marketplace_program: Program<'info, SplMarketplace>,
+
+    /// CHECK: Market of the series' option token; checked by the marketplace
+    #[account(mut)]
+    pub market: UncheckedAccount<'info>,
+
+    /// CHECK: Order PDA of the market's next order id; created by the marketplace
+    #[account(mut)]
+    pub order: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA of the order; created by the marketplace
+    #[account(mut)]
+    pub escrow: UncheckedAccount<'info>,
+}
+
+/// Writes `amount` options of the series out of the vault's escrow and lists
+/// them all at `price` (quote per option), opening the round: deposits and
+/// withdrawals wait until it settles. Premiums from fills land in the vault's
+/// quote account. The series must write what the vault was opened for.
+pub fn handler(ctx: Context<StartVaultRound>, amount: u64, price: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let authority = accounts.vault_authority.key();
+    accounts
+        .series
+        .validate_for(&accounts.option_vault, &authority)?;
+
+    let metas = crate::accounts::MintAndList {
+        option: accounts.series.option_context_accounts(authority),
+        marketplace_program: accounts.marketplace_program.key(),
+        market: accounts.market.key(),
+        order: accounts.order.key(),
+        escrow: accounts.escrow.key(),
+    }
+    .to_account_metas(None);
+    let infos = accounts.series.infos(
+        &accounts.vault_authority.to_account_info(),
+        &[
+            accounts.marketplace_program.to_account_info(),
+            accounts.market.to_account_info(),
+            accounts.order.to_account_info(),
+            accounts.escrow.to_account_info(),
+        ],
+    );
+    invoke_as_vault(
+        &accounts.option_vault,
+        metas,
+        crate::instruction::MintAndList { amount, price }.data(),
+        &infos,
+    )?;
+
+    let vault = &mut accounts.option_vault;
+    vault.series = accounts.series.option_context.key();
+    vault.ask = accounts.order.key();
+    vault.round += 1;
+    vault.written = amount;
+
+    emit!(VaultRoundStarted {
+        option_vault: vault.key(),
+        round: vault.round,
+        series: vault.series,
+        written: amount,
+        price,
+    });
+
+    msg!(
+        "Vault {} round {} lists {} at {}",
+        vault.key(),
+        vault.round,
+        amount,
+        price
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::exercise;
+use crate::jupiter::{self, JUPITER_PROGRAM_ID};
+// The generated client modules of OptionContext come along for the nesting
+use crate::instructions::option::*;
+
+/// `exercise` funded by a Jupiter swap of any token the user holds
+#[derive(Accounts)]
+pub struct SwapAndExercise<'info> {
+    pub option: OptionContext<'info>,
+
+    /// CHECK: Jupiter aggregator the route runs on
+    #[account(address = JUPITER_PROGRAM_ID @ ErrorCode::InvalidSwapProgram)]
+    pub jupiter_program: UncheckedAccount<'info>,
+}
+
+/// Swaps into what exercising `amount` options costs, then exercises them.
+/// `route_data` and the remaining accounts are the Jupiter swap instruction
+/// quoted off-chain; its output must land in the user's consideration
+/// account for calls, or their collateral account for puts. The user signs
+/// the route like any swap of their own. Set the route's output above the
+/// strike plus fee (and funding): a short swap fails the whole instruction.
+/// The route takes every remaining account, so basket series paying
+/// substitutes out are exercised with plain `exercise`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapAndExercise<'info>>,
+    amount: u64,
+    route_data: Vec<u8>,
+) -> Result<()> {
+    let SwapAndExercise {
+        option,
+        jupiter_program,
+    } = ctx.accounts;
+
+    // Calls pay the strike in consideration, puts deliver collateral
+    let paying = if option.option_context.is_put() {
+        &mut option.user_collateral_account
+    } else {
+        &mut option.user_consideration_account
+    };
+    let held = paying.amount;
+    jupiter::swap(
+        &jupiter_program.to_account_info(),
+        ctx.remaining_accounts,
+        route_data,
+    )?;
+    paying.reload()?;
+    let received = paying.amount.saturating_sub(held);
+    require!(received > 0, ErrorCode::SwapOutputMissing);
+
+    exercise::handler(
+        Context::new(ctx.program_id, option, &[], Default::default()),
+        amount,
+    )?;
+
+    msg!("Swapped into {} and exercised {} options", received, amount);
+
+    Ok(())
+}
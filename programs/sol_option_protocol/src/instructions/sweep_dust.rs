@@ -0,0 +1,349 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::DustSwept;
+use crate::instructions::basket_payout::BASKET_PAYOUT_ACCOUNTS;
+use crate::instructions::OptionData;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::state::{CollateralBasket, ProtocolConfig};
+use crate::utils::validation::validate_settled;
+
+/// Permissionless cleanup of a fully redeemed series: leftover vault dust goes
+/// to the treasury and the vaults are closed
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub option_context: Account<'info, OptionData>,
+
+    /// CHECK: Series authority, receives the vaults' rent (validated against option_context)
+    #[account(
+        mut,
+        constraint = authority.key() == option_context.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Collateral mint (validated against stored value in option_context)
+    #[account(
+        constraint = collateral_mint.key() == option_context.collateral_mint
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    /// Consideration mint (validated against stored value in option_context)
+    #[account(
+        constraint = consideration_mint.key() == option_context.consideration_mint
+    )]
+    pub consideration_mint: InterfaceAccount<'info, Mint>,
+
+    /// Option mint (validated against stored value in option_context)
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    /// Redemption mint (validated against stored value in option_context)
+    #[account(
+        constraint = redemption_mint.key() == option_context.redemption_mint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    /// Collateral vault (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = collateral_vault.key() == option_context.collateral_vault
+    )]
+    pub collateral_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Consideration vault (validated against stored value in option_context)
+    #[account(
+        mut,
+        constraint = consideration_vault.key() == option_context.consideration_vault
+    )]
+    pub consideration_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_collateral_account.owner == protocol_config.treasury
+            && treasury_collateral_account.mint == collateral_mint.key()
+            @ ErrorCode::InvalidTreasuryAccount
+    )]
+    pub treasury_collateral_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_consideration_account.owner == protocol_config.treasury
+            && treasury_consideration_account.mint == consideration_mint.key()
+            @ ErrorCode::InvalidTreasuryAccount
+    )]
+    pub treasury_consideration_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Once every redemption token is gone (no option token still has a netted
+/// payoff to claim and every margin position has settled), whatever remains in
+/// the vaults is rounding dust or donations nobody can claim: it moves to the
+/// treasury and the vault rent returns to the series authority
+///
+/// Basket series list one (mint, basket vault, treasury account) triple per
+/// substitute slot in use in `remaining_accounts`, in slot order; their vaults
+/// are swept and closed too.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SweepDust<'info>>) -> Result<()> {
+    let option_context = &ctx.accounts.option_context;
+
+    // Validation
+    validate_settled(option_context)?;
+    require!(
+        ctx.accounts.redemption_mint.supply == 0,
+        ErrorCode::SeriesNotFullyRedeemed
+    );
+    let options_owed =
+        option_context.net_option_collateral > 0 || option_context.net_option_consideration > 0;
+    require!(
+        ctx.accounts.option_mint.supply == 0 || !options_owed,
+        ErrorCode::SeriesNotFullyRedeemed
+    );
+    require!(
+        option_context.margin_collateral == 0,
+        ErrorCode::SeriesNotFullyRedeemed
+    );
+
+    let (collateral, consideration) =
+        sweep_vaults_to_treasury(ctx.accounts, ctx.remaining_accounts)?;
+
+    let series_key = ctx.accounts.option_context.key();
+    let treasury = ctx.accounts.protocol_config.treasury;
+    emit!(DustSwept {
+        option_context: series_key,
+        treasury,
+        collateral,
+        consideration,
+    });
+
+    msg!(
+        "Swept {} collateral and {} consideration from series {} to treasury {}",
+        collateral,
+        consideration,
+        series_key,
+        treasury
+    );
+
+    // The vaults are closed by now, so only the mints are checked
+    check_series(
+        &ctx.accounts.option_context,
+        SeriesAccounts {
+            option_mint: Some(ctx.accounts.option_mint.to_account_info()),
+            redemption_mint: Some(ctx.accounts.redemption_mint.to_account_info()),
+            ..SeriesAccounts::default()
+        },
+    )
+}
+
+/// Moves both vaults' full balances to the treasury accounts, closes the vaults
+/// (and the substitute vaults listed in `remaining_accounts`), refunds the
+/// creation bond and zeroes the series' accounted balances; returns the amounts
+/// moved out of the two series vaults
+pub(crate) fn sweep_vaults_to_treasury<'info>(
+    accounts: &mut SweepDust<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<(u64, u64)> {
+    require!(
+        accounts.protocol_config.treasury != Pubkey::default(),
+        ErrorCode::TreasuryNotSet
+    );
+    // Lent escrow only comes back through unwind_escrow (permissionless)
+    require!(
+        accounts.option_context.yield_shares == 0,
+        ErrorCode::YieldSharesOutstanding
+    );
+
+    let option_context = &accounts.option_context;
+
+    // OptionContext PDA signs vault transfers and closures
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_mint_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_mint_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    let collateral = accounts.collateral_vault.amount;
+    let consideration = accounts.consideration_vault.amount;
+    let sweeps = [
+        (
+            &accounts.collateral_vault,
+            &accounts.collateral_mint,
+            &accounts.treasury_collateral_account,
+            collateral,
+        ),
+        (
+            &accounts.consideration_vault,
+            &accounts.consideration_mint,
+            &accounts.treasury_consideration_account,
+            consideration,
+        ),
+    ];
+
+    for (vault, mint, destination, amount) in sweeps {
+        // 1. Move the vault's whole balance to the treasury
+        if amount > 0 {
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    token::TransferChecked {
+                        from: vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: option_context.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+
+        // 2. Close the empty vault, returning its rent to the series authority
+        token::close_account(CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: vault.to_account_info(),
+                destination: accounts.authority.to_account_info(),
+                authority: option_context.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    sweep_basket_vaults(accounts, remaining_accounts, signer_seeds)?;
+
+    // 3. Refund the creation bond to the series authority along with the rent
+    let bond = accounts.option_context.creation_bond;
+    if bond > 0 {
+        let option_context_info = accounts.option_context.to_account_info();
+        let authority_info = accounts.authority.to_account_info();
+        **option_context_info.try_borrow_mut_lamports()? -= bond;
+        **authority_info.try_borrow_mut_lamports()? += bond;
+    }
+
+    // Nothing is left to account for (OptionContext bookkeeping)
+    let option_context = &mut accounts.option_context;
+    let collateral_balance = option_context.collateral_balance();
+    let consideration_balance = option_context.consideration_balance();
+    option_context.debit_collateral(collateral_balance)?;
+    option_context.debit_consideration(consideration_balance)?;
+    option_context.margin_collateral = 0;
+    option_context.creation_bond = 0;
+    option_context.basket_escrow = [0; CollateralBasket::MAX_MINTS];
+
+    Ok((collateral, consideration))
+}
+
+/// Moves each substitute vault's full balance to the treasury and closes it;
+/// `accounts` holds one (mint, basket vault, treasury account) triple per slot
+/// in use, in slot order
+fn sweep_basket_vaults<'info>(
+    sweep: &SweepDust<'info>,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let option_context = &sweep.option_context;
+    let series_key = option_context.key();
+    let used = option_context
+        .basket_mints
+        .iter()
+        .take_while(|mint| **mint != Pubkey::default())
+        .count();
+    require!(
+        accounts.len() >= used * BASKET_PAYOUT_ACCOUNTS,
+        ErrorCode::InvalidBasketAccounts
+    );
+
+    for (slot, triple) in accounts
+        .chunks(BASKET_PAYOUT_ACCOUNTS)
+        .take(used)
+        .enumerate()
+    {
+        let (mint, vault, destination) = (&triple[0], &triple[1], &triple[2]);
+        require_keys_eq!(
+            mint.key(),
+            option_context.basket_mints[slot],
+            ErrorCode::InvalidBasketAccounts
+        );
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[
+                CollateralBasket::VAULT_SEED,
+                series_key.as_ref(),
+                mint.key().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            vault.key(),
+            expected_vault,
+            ErrorCode::InvalidBasketAccounts
+        );
+        let treasury_account =
+            TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+        require!(
+            treasury_account.owner == sweep.protocol_config.treasury
+                && treasury_account.mint == mint.key(),
+            ErrorCode::InvalidTreasuryAccount
+        );
+
+        // 1. Move the substitute vault's whole balance to the treasury
+        let amount = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?.amount;
+        if amount > 0 {
+            let decimals = Mint::try_deserialize(&mut &mint.try_borrow_data()?[..])?.decimals;
+            token::transfer_checked(
+                CpiContext::new_with_signer(
+                    sweep.token_program.to_account_info(),
+                    token::TransferChecked {
+                        from: vault.clone(),
+                        mint: mint.clone(),
+                        to: destination.clone(),
+                        authority: option_context.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                decimals,
+            )?;
+        }
+
+        // 2. Close it, returning its rent to the series authority
+        token::close_account(CpiContext::new_with_signer(
+            sweep.token_program.to_account_info(),
+            token::CloseAccount {
+                account: vault.clone(),
+                destination: sweep.authority.to_account_info(),
+                authority: option_context.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        msg!(
+            "Swept {} of substitute {} to the treasury",
+            amount,
+            mint.key()
+        );
+    }
+
+    Ok(())
+}
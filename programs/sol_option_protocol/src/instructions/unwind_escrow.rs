@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::EscrowUnwound;
+use crate::instructions::YieldContext;
+use crate::invariants::{check_series, SeriesAccounts};
+use crate::utils::validation::validate_amount;
+
+/// Permissionless: redeems `shares` of the series' venue shares back into its
+/// escrow vault. Exercises and redemptions that find the vault short prepend
+/// it, so escrow is only pulled back when a payout needs it. The shares carry
+/// their pro-rata part of the lent principal; what they fetch above it is
+/// yield credited to the writers' escrow, and a shortfall is debited from it.
+pub fn handler(ctx: Context<YieldContext>, shares: u64) -> Result<()> {
+    validate_amount(shares)?;
+    let option_context = &ctx.accounts.option_context;
+    require!(
+        shares <= option_context.yield_shares,
+        ErrorCode::InsufficientYieldShares
+    );
+    let principal = u64::try_from(
+        option_context.yield_principal as u128 * shares as u128
+            / option_context.yield_shares as u128,
+    )
+    .map_err(|_| error!(ErrorCode::MathOverflow))?;
+
+    let received = ctx.accounts.unwind(shares)?;
+
+    let option_context = &mut ctx.accounts.option_context;
+    option_context.yield_principal -= principal;
+    option_context.yield_shares -= shares;
+    if received >= principal {
+        option_context.credit_escrow(received - principal)?;
+    } else {
+        option_context.debit_escrow(principal - received)?;
+    }
+
+    emit!(EscrowUnwound {
+        option_context: option_context.key(),
+        shares,
+        principal,
+        received,
+    });
+
+    msg!(
+        "Series {} unwound {} shares for {} escrow",
+        option_context.key(),
+        shares,
+        received
+    );
+
+    check_series(
+        option_context,
+        SeriesAccounts::escrow_vault(option_context, ctx.accounts.escrow_vault.to_account_info()),
+    )
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::RateLimitsUpdated;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_not_timelocked;
+
+/// Admin-only update of the per-creator series creation limits
+#[derive(Accounts)]
+pub struct UpdateRateLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateRateLimits>,
+    max_series_per_epoch: u16,
+    creation_cooldown: i64,
+) -> Result<()> {
+    validate_not_timelocked(&ctx.accounts.protocol_config)?;
+    apply(
+        &mut ctx.accounts.protocol_config,
+        max_series_per_epoch,
+        creation_cooldown,
+    )
+}
+
+/// Sets the limits (directly, or from a scheduled change)
+pub(crate) fn apply(
+    config: &mut ProtocolConfig,
+    max_series_per_epoch: u16,
+    creation_cooldown: i64,
+) -> Result<()> {
+    require!(creation_cooldown >= 0, ErrorCode::InvalidConfigValue);
+
+    config.max_series_per_epoch = max_series_per_epoch;
+    config.creation_cooldown = creation_cooldown;
+
+    emit!(RateLimitsUpdated {
+        max_series_per_epoch,
+        creation_cooldown,
+    });
+
+    msg!(
+        "Rate limits updated: {} series/epoch, {}s cooldown",
+        max_series_per_epoch,
+        creation_cooldown
+    );
+
+    Ok(())
+}
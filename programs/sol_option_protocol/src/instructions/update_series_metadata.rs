@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::ErrorCode;
+use crate::events::SeriesMetadataUpdated;
+use crate::instructions::OptionData;
+use crate::metadata::{
+    write_series_metadata, SeriesMetadataAccounts, TokenMetadata, TOKEN_METADATA_PROGRAM_ID,
+};
+
+/// Series authority writing the token metadata of its option and redemption
+/// mints from the series' stored terms and metadata URI
+#[derive(Accounts)]
+pub struct UpdateSeriesMetadata<'info> {
+    /// Pays for the metadata accounts when the series has none yet
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub option_context: Account<'info, OptionData>,
+
+    #[account(
+        constraint = option_mint.key() == option_context.option_mint @ ErrorCode::InvalidOptionMint
+    )]
+    pub option_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = redemption_mint.key() == option_context.redemption_mint
+            @ ErrorCode::InvalidRedemptionMint
+    )]
+    pub redemption_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metadata PDA of the option mint (checked against the mint, then
+    /// by the Token Metadata program)
+    #[account(mut)]
+    pub option_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata PDA of the redemption mint (same as above)
+    #[account(mut)]
+    pub redemption_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Token Metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM_ID @ ErrorCode::InvalidMetadataAccount)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Rewrites both mints' names, symbols and URI from the series, creating their
+/// metadata accounts first if the series has none (series created without
+/// them, e.g. through `create_series_batch`).
+///
+/// The metadata URI is set at creation and immutable after: holders bought
+/// against the disclosures it points to, so this never changes it
+pub fn handler(ctx: Context<UpdateSeriesMetadata>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    write_series_metadata(
+        &accounts.option_context,
+        SeriesMetadataAccounts {
+            metadata_program: &accounts.token_metadata_program.to_account_info(),
+            option_metadata: &accounts.option_metadata.to_account_info(),
+            redemption_metadata: &accounts.redemption_metadata.to_account_info(),
+            option_mint: &accounts.option_mint.to_account_info(),
+            redemption_mint: &accounts.redemption_mint.to_account_info(),
+            payer: &accounts.authority.to_account_info(),
+            system_program: &accounts.system_program.to_account_info(),
+            rent: &accounts.rent.to_account_info(),
+        },
+    )?;
+
+    let option_context = &ctx.accounts.option_context;
+    let token_metadata = TokenMetadata::for_series(option_context, false);
+    emit!(SeriesMetadataUpdated {
+        option_context: option_context.key(),
+        name: token_metadata.name.clone(),
+        uri: token_metadata.uri,
+    });
+
+    msg!(
+        "Series {} metadata: {}",
+        option_context.key(),
+        token_metadata.name
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount};
+
+use crate::errors::ErrorCode;
+
+/// Transfers `amount` from `from` into `vault` and returns what the vault
+/// actually received, measured from its balance before and after: less than
+/// `amount` when the mint withholds a Token-2022 transfer fee
+pub fn deposit_received<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &mut InterfaceAccount<'info, TokenAccount>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let before = vault.amount;
+    token::transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            token::TransferChecked {
+                from: from.clone(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        mint.decimals,
+    )?;
+    vault.reload()?;
+    vault
+        .amount
+        .checked_sub(before)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
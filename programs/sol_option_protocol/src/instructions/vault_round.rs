@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_interface::TokenInterface;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::program::SolOptionProtocol;
+use crate::state::OptionVault;
+
+/// Series accounts of a vault's round, passed on to the `OptionContext`
+/// instructions the vault authority signs (as user and owner of both legs)
+///
+/// The options program validates them in that inner instruction; the vault
+/// only reads the series' terms. Nothing here is deserialized for writing, so
+/// the inner instruction's changes aren't overwritten on the way out.
+#[derive(Accounts)]
+pub struct VaultSeries<'info> {
+    /// CHECK: Series of the round; owner and discriminator checked by `terms`
+    #[account(mut)]
+    pub option_context: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    pub protocol_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    pub collateral_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    pub consideration_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    #[account(mut)]
+    pub option_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    #[account(mut)]
+    pub redemption_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    #[account(mut)]
+    pub collateral_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the options program
+    #[account(mut)]
+    pub consideration_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Vault authority's associated collateral account
+    #[account(mut)]
+    pub vault_collateral_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault authority's associated consideration account
+    #[account(mut)]
+    pub vault_consideration_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault authority's associated option token account
+    #[account(mut)]
+    pub vault_option_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault authority's associated redemption token account
+    #[account(mut)]
+    pub vault_redemption_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Protocol fee account of the escrow mint, while the mint fee is non-zero
+    #[account(mut)]
+    pub fee_vault: Option<UncheckedAccount<'info>>,
+
+    pub options_program: Program<'info, SolOptionProtocol>,
+}
+
+impl<'info> VaultSeries<'info> {
+    /// The series' current state
+    pub fn terms(&self) -> Result<OptionData> {
+        require_keys_eq!(
+            *self.option_context.owner,
+            crate::ID,
+            ErrorCode::VaultSeriesMismatch
+        );
+        let data = self.option_context.try_borrow_data()?;
+        OptionData::try_deserialize(&mut &data[..])
+    }
+
+    /// Checks the series writes what `vault` was opened for, and that the
+    /// token accounts passed are `authority`'s associated accounts of it
+    pub fn validate_for(&self, vault: &OptionVault, authority: &Pubkey) -> Result<OptionData> {
+        let terms = self.terms()?;
+        require!(
+            terms.collateral_mint == vault.underlying_mint
+                && terms.consideration_mint == vault.quote_mint
+                && terms.option_type == vault.option_type,
+            ErrorCode::VaultSeriesMismatch
+        );
+
+        let token_program = self.token_program.key();
+        for (account, mint) in [
+            (&self.vault_collateral_account, terms.collateral_mint),
+            (&self.vault_consideration_account, terms.consideration_mint),
+            (&self.vault_option_account, terms.option_mint),
+            (&self.vault_redemption_account, terms.redemption_mint),
+        ] {
+            require_keys_eq!(
+                account.key(),
+                get_associated_token_address_with_program_id(authority, &mint, &token_program),
+                ErrorCode::VaultSeriesMismatch
+            );
+        }
+        Ok(terms)
+    }
+
+    /// `OptionContext` of the series with `authority` as user and owner
+    pub fn option_context_accounts(&self, authority: Pubkey) -> crate::accounts::OptionContext {
+        crate::accounts::OptionContext {
+            user: authority,
+            option_context: self.option_context.key(),
+            protocol_config: self.protocol_config.key(),
+            collateral_mint: self.collateral_mint.key(),
+            consideration_mint: self.consideration_mint.key(),
+            option_mint: self.option_mint.key(),
+            redemption_mint: self.redemption_mint.key(),
+            collateral_vault: self.collateral_vault.key(),
+            consideration_vault: self.consideration_vault.key(),
+            option_owner: authority,
+            redemption_owner: authority,
+            user_collateral_account: self.vault_collateral_account.key(),
+            user_consideration_account: self.vault_consideration_account.key(),
+            user_option_account: self.vault_option_account.key(),
+            user_redemption_account: self.vault_redemption_account.key(),
+            token_program: self.token_program.key(),
+            associated_token_program: self.associated_token_program.key(),
+            system_program: self.system_program.key(),
+            writer_position: None,
+            fee_vault: self.fee_vault.as_ref().map(|fee_vault| fee_vault.key()),
+            fee_stats: None,
+            referrer_account: None,
+            referral_stats: None,
+            insurance_fund: None,
+            insurance_vault: None,
+            credential_account: None,
+        }
+    }
+
+    /// Account infos of `option_context_accounts`, plus `extra` accounts
+    pub fn infos(
+        &self,
+        authority: &AccountInfo<'info>,
+        extra: &[AccountInfo<'info>],
+    ) -> Vec<AccountInfo<'info>> {
+        let mut infos = vec![
+            authority.clone(),
+            self.option_context.to_account_info(),
+            self.protocol_config.to_account_info(),
+            self.collateral_mint.to_account_info(),
+            self.consideration_mint.to_account_info(),
+            self.option_mint.to_account_info(),
+            self.redemption_mint.to_account_info(),
+            self.collateral_vault.to_account_info(),
+            self.consideration_vault.to_account_info(),
+            self.vault_collateral_account.to_account_info(),
+            self.vault_consideration_account.to_account_info(),
+            self.vault_option_account.to_account_info(),
+            self.vault_redemption_account.to_account_info(),
+            self.token_program.to_account_info(),
+            self.associated_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            self.options_program.to_account_info(),
+        ];
+        if let Some(fee_vault) = &self.fee_vault {
+            infos.push(fee_vault.to_account_info());
+        }
+        infos.extend_from_slice(extra);
+        infos
+    }
+}
+
+/// Runs `data` (an instruction of this program) as the vault authority
+pub fn invoke_as_vault(
+    option_vault: &Account<OptionVault>,
+    accounts: Vec<AccountMeta>,
+    data: Vec<u8>,
+    infos: &[AccountInfo],
+) -> Result<()> {
+    let option_vault_key = option_vault.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        OptionVault::AUTHORITY_SEED,
+        option_vault_key.as_ref(),
+        &[option_vault.authority_bump],
+    ]];
+    invoke_signed(
+        &Instruction {
+            program_id: crate::ID,
+            accounts,
+            data,
+        },
+        infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self as token, Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::state::OptionVault;
+
+/// Shared accounts struct for buying into and leaving a vault (deposit, withdraw)
+///
+/// Both run between rounds only, with no re-listing open, when everything the
+/// vault owns sits in its authority's two accounts, so shares are priced off
+/// those balances alone.
+#[derive(Accounts)]
+pub struct VaultShares<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        has_one = underlying_mint,
+        has_one = quote_mint,
+        has_one = share_mint,
+        constraint = option_vault.is_idle() @ ErrorCode::VaultRoundActive,
+        constraint = !option_vault.listing_open() @ ErrorCode::VaultListingOpen
+    )]
+    pub option_vault: Box<Account<'info, OptionVault>>,
+
+    /// CHECK: System-owned PDA holding the vault's tokens
+    #[account(
+        seeds = [OptionVault::AUTHORITY_SEED, option_vault.key().as_ref()],
+        bump = option_vault.authority_bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_underlying_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = underlying_mint, token::token_program = token_program)]
+    pub user_underlying_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = quote_mint, token::token_program = token_program)]
+    pub user_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's share account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VaultShares<'info> {
+    /// Whether depositors bring the underlying (calls) rather than the quote
+    pub fn escrows_underlying(&self) -> bool {
+        self.option_vault.escrow_mint() == self.underlying_mint.key()
+    }
+
+    /// Pays `amount` of the vault's underlying (or quote) to the user
+    pub fn pay_out(&self, underlying: bool, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let (from, to, mint) = if underlying {
+            (
+                &self.vault_underlying_account,
+                &self.user_underlying_account,
+                &self.underlying_mint,
+            )
+        } else {
+            (
+                &self.vault_quote_account,
+                &self.user_quote_account,
+                &self.quote_mint,
+            )
+        };
+
+        let option_vault = self.option_vault.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            OptionVault::AUTHORITY_SEED,
+            option_vault.as_ref(),
+            &[self.option_vault.authority_bump],
+        ]];
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    /// Mints `shares` to the user
+    pub fn mint_shares(&self, shares: u64) -> Result<()> {
+        let option_vault = self.option_vault.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            OptionVault::AUTHORITY_SEED,
+            option_vault.as_ref(),
+            &[self.option_vault.authority_bump],
+        ]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::MintTo {
+                    mint: self.share_mint.to_account_info(),
+                    to: self.user_share_account.to_account_info(),
+                    authority: self.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )
+    }
+
+    /// Burns `shares` of the user's
+    pub fn burn_shares(&self, shares: u64) -> Result<()> {
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.share_mint.to_account_info(),
+                    from: self.user_share_account.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            shares,
+        )
+    }
+}
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::series_transfer::{transfer_series_tokens, SeriesTransfer};
+use crate::instructions::OptionData;
+use crate::state::{ProtocolConfig, VerticalSpread, VerticalSpreadPosition};
+
+/// Shared accounts struct for tokenized vertical spreads (mint, burn, settle,
+/// claim, redeem)
+///
+/// Spread tokens pay the short series' intrinsic value at expiry, backed by
+/// locked long options of a further-OTM series plus the strike difference.
+#[derive(Accounts)]
+pub struct VerticalSpreadContext<'info> {
+    /// Writer (mint, burn, redeem), holder (claim) or any cranker (settle)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Series whose intrinsic value spread tokens pay (only read)
+    pub short_option_context: Box<Account<'info, OptionData>>,
+
+    /// Series whose options writers lock (pays their netted payoff at settlement)
+    #[account(mut)]
+    pub long_option_context: Box<Account<'info, OptionData>>,
+
+    /// Protocol config (global pause)
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// Spread PDA (created by the first `mint_vertical_spread` for the pair)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + VerticalSpread::INIT_SPACE,
+        seeds = [
+            VerticalSpread::SEED,
+            short_option_context.key().as_ref(),
+            long_option_context.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vertical_spread: Box<Account<'info, VerticalSpread>>,
+
+    /// Spread token mint (same decimals as the options; the spread is authority)
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [VerticalSpread::MINT_SEED, vertical_spread.key().as_ref()],
+        bump,
+        mint::decimals = long_option_mint.decimals,
+        mint::authority = vertical_spread,
+    )]
+    pub spread_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Long leg's option mint (validated against stored value in long_option_context)
+    #[account(
+        mut,
+        constraint = long_option_mint.key() == long_option_context.option_mint
+            @ ErrorCode::InvalidSpread
+    )]
+    pub long_option_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Mint of the asset the short series' writers escrow
+    #[account(
+        constraint = backing_mint.key() == short_option_context.margin_mint()
+            @ ErrorCode::InvalidSpread
+    )]
+    pub backing_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Locked long options, owned by the spread
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [VerticalSpread::ESCROW_SEED, vertical_spread.key().as_ref()],
+        bump,
+        token::mint = long_option_mint,
+        token::authority = vertical_spread
+    )]
+    pub long_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Backing and settlement payoff, owned by the spread
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [VerticalSpread::VAULT_SEED, vertical_spread.key().as_ref()],
+        bump,
+        token::mint = backing_mint,
+        token::authority = vertical_spread
+    )]
+    pub spread_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Long series' vault of the backing asset (pays the locked longs' payoff)
+    #[account(
+        mut,
+        constraint = long_vault.key() == long_option_context.margin_vault()
+    )]
+    pub long_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's long-leg option account (locked from, unlocked to)
+    #[account(mut, token::mint = long_option_mint, token::authority = user)]
+    pub user_long_option_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's account of the backing asset (deposits from, payouts to)
+    #[account(mut, token::mint = backing_mint, token::authority = user)]
+    pub user_backing_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's spread token account (associated; created if missing)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = spread_mint,
+        associated_token::authority = user,
+    )]
+    pub user_spread_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// User's writer position in the spread (mint, burn and redeem only;
+    /// created by the first mint)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + VerticalSpreadPosition::INIT_SPACE,
+        seeds = [
+            VerticalSpreadPosition::SEED,
+            vertical_spread.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub position: Option<Box<Account<'info, VerticalSpreadPosition>>>,
+}
+
+impl<'info> VerticalSpreadContext<'info> {
+    /// The writer's position; fails when the instruction needs one and none was passed
+    pub fn position(&mut self) -> Result<&mut VerticalSpreadPosition> {
+        self.position
+            .as_deref_mut()
+            .map(|position| &mut **position)
+            .ok_or_else(|| error!(ErrorCode::SpreadPositionRequired))
+    }
+
+    /// Moves `amount` backing out of the spread vault to the user (the spread signs)
+    pub fn pay_out(&self, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        self.transfer_signed(
+            &self.spread_vault,
+            &self.backing_mint,
+            &self.user_backing_account,
+            &[],
+            amount,
+        )
+    }
+
+    /// Moves `amount` locked long options back to the user (the spread
+    /// signs); `hook_accounts` serve the option mint's transfer hook, if any
+    pub fn unlock(&self, amount: u64, hook_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        self.transfer_signed(
+            &self.long_escrow,
+            &self.long_option_mint,
+            &self.user_long_option_account,
+            hook_accounts,
+            amount,
+        )
+    }
+
+    /// Burns `amount` of the user's spread tokens
+    pub fn burn_spread_tokens(&self, amount: u64) -> Result<()> {
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.spread_mint.to_account_info(),
+                    from: self.user_spread_account.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    fn transfer_signed(
+        &self,
+        from: &InterfaceAccount<'info, TokenAccount>,
+        mint: &InterfaceAccount<'info, Mint>,
+        to: &InterfaceAccount<'info, TokenAccount>,
+        hook_accounts: &[AccountInfo<'info>],
+        amount: u64,
+    ) -> Result<()> {
+        let spread = &self.vertical_spread;
+        transfer_series_tokens(
+            SeriesTransfer {
+                token_program: self.token_program.to_account_info(),
+                from: from.to_account_info(),
+                mint,
+                to: to.to_account_info(),
+                authority: spread.to_account_info(),
+            },
+            hook_accounts,
+            amount,
+            &[&[
+                VerticalSpread::SEED,
+                spread.short_series.as_ref(),
+                spread.long_series.as_ref(),
+                &[spread.bump],
+            ]],
+        )
+    }
+}
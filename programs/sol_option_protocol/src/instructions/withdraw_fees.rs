@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface as token;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::FeesWithdrawn;
+use crate::state::ProtocolConfig;
+use crate::utils::validation::validate_amount;
+
+/// Admin-only withdrawal of collected protocol fees
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Fee account of the mint (any token account of the ProtocolConfig PDA)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = protocol_config,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// The token program refuses more than the fee account holds
+pub fn handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    validate_amount(amount)?;
+
+    let bump = ctx.accounts.protocol_config.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[ProtocolConfig::SEED, &[bump]]];
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::TransferChecked {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.protocol_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(FeesWithdrawn {
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    msg!("Withdrew {} fees of {}", amount, ctx.accounts.mint.key());
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::events::VaultSharesChanged;
+use crate::instructions::VaultShares;
+use crate::state::OptionVault;
+use crate::utils::validation::validate_amount;
+
+/// Burns `shares` between rounds for their part of both of the vault's
+/// balances (the escrow mint and whatever rounds brought in of the other)
+pub fn handler(ctx: Context<VaultShares>, shares: u64) -> Result<()> {
+    validate_amount(shares)?;
+
+    let accounts = &ctx.accounts;
+    let supply = accounts.share_mint.supply;
+    let underlying =
+        OptionVault::share_of(shares, supply, accounts.vault_underlying_account.amount)?;
+    let quote = OptionVault::share_of(shares, supply, accounts.vault_quote_account.amount)?;
+
+    accounts.burn_shares(shares)?;
+    accounts.pay_out(true, underlying)?;
+    accounts.pay_out(false, quote)?;
+
+    emit!(VaultSharesChanged {
+        option_vault: accounts.option_vault.key(),
+        user: accounts.user.key(),
+        deposit: false,
+        shares,
+        underlying,
+        quote,
+    });
+
+    msg!("{} withdrew {} vault shares", accounts.user.key(), shares);
+
+    Ok(())
+}
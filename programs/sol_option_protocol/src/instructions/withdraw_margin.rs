@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::MarginContext;
+use crate::invariants::check_series;
+use crate::utils::validation::validate_not_settled;
+
+/// Buys back margin-written options and/or withdraws margin before settlement
+/// Burns `burn` of the writer's option tokens against the position's short,
+/// then releases `withdraw` margin; whatever remains written must still meet
+/// the initial requirement at the live oracle price
+pub fn handler(ctx: Context<MarginContext>, burn: u64, withdraw: u64) -> Result<()> {
+    // Validation
+    require!(burn > 0 || withdraw > 0, ErrorCode::InvalidAmount);
+    validate_not_settled(&ctx.accounts.option_context)?;
+    require_keys_eq!(
+        ctx.accounts.writer_position.writer,
+        ctx.accounts.user.key(),
+        ErrorCode::Unauthorized
+    );
+
+    // 1. Burn bought-back options against the position's short
+    if burn > 0 {
+        ctx.accounts.record_margin_buyback(burn)?;
+        ctx.accounts.burn_options(burn)?;
+    }
+
+    // 2. Release the withdrawn margin
+    if withdraw > 0 {
+        ctx.accounts.debit_margin(withdraw)?;
+        ctx.accounts.release(withdraw)?;
+    }
+
+    // 3. Whatever is still written keeps the initial requirement
+    if ctx.accounts.writer_position.margin_written > 0 {
+        let price = ctx.accounts.live_price()?;
+        let required = ctx
+            .accounts
+            .requirement(price, ctx.accounts.option_context.initial_margin_bps)?;
+        require!(
+            ctx.accounts.writer_position.margin_collateral >= required,
+            ErrorCode::InsufficientMargin
+        );
+        ctx.accounts.record_health(price)?;
+    } else {
+        ctx.accounts.writer_position.health_bps = u64::MAX;
+    }
+
+    msg!(
+        "Bought back {} and withdrew {} margin from series {}",
+        burn,
+        withdraw,
+        ctx.accounts.option_context.key()
+    );
+
+    check_series(&ctx.accounts.option_context, ctx.accounts.series_accounts())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::lending::{deposit_instruction, redeem_instruction};
+use crate::state::YieldVenue;
+
+/// Shared accounts struct for lending a series' escrow (lend, unwind)
+///
+/// The escrow vault stays the series' vault of record: lent escrow is still
+/// counted in its accounting, and `OptionData::yield_principal` tracks what
+/// of it sits in the venue's reserve instead.
+#[derive(Accounts)]
+pub struct YieldContext<'info> {
+    /// Series authority when lending; anyone when unwinding
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub option_context: Box<Account<'info, OptionData>>,
+
+    #[account(
+        constraint = yield_venue.key() == option_context.yield_venue @ ErrorCode::YieldNotEnabled
+    )]
+    pub yield_venue: Box<Account<'info, YieldVenue>>,
+
+    /// Vault of the asset writers escrow (collateral for calls, consideration
+    /// for puts)
+    #[account(
+        mut,
+        constraint = escrow_vault.key() == option_context.margin_vault()
+            @ ErrorCode::InvalidCollateralVault
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Series-owned account holding the venue's shares
+    #[account(
+        mut,
+        seeds = [YieldVenue::SHARE_VAULT_SEED, option_context.key().as_ref()],
+        bump
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Token-lending program of the venue
+    #[account(address = yield_venue.lending_program @ ErrorCode::InvalidYieldVenue)]
+    pub lending_program: UncheckedAccount<'info>,
+
+    /// CHECK: Reserve of the venue
+    #[account(mut, address = yield_venue.reserve @ ErrorCode::InvalidYieldVenue)]
+    pub reserve: UncheckedAccount<'info>,
+
+    /// CHECK: Liquidity supply of the reserve
+    #[account(
+        mut,
+        address = yield_venue.reserve_liquidity_supply @ ErrorCode::InvalidYieldVenue
+    )]
+    pub reserve_liquidity_supply: UncheckedAccount<'info>,
+
+    /// CHECK: Collateral (share) mint of the reserve
+    #[account(mut, address = yield_venue.share_mint @ ErrorCode::InvalidYieldVenue)]
+    pub share_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Lending market of the reserve
+    #[account(address = yield_venue.lending_market @ ErrorCode::InvalidYieldVenue)]
+    pub lending_market: UncheckedAccount<'info>,
+
+    /// CHECK: Derived authority of the lending market
+    #[account(
+        address = yield_venue.lending_market_authority @ ErrorCode::InvalidYieldVenue
+    )]
+    pub lending_market_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> YieldContext<'info> {
+    /// Deposits `amount` of escrow into the reserve and returns the shares
+    /// the series received
+    pub fn lend(&mut self, amount: u64) -> Result<u64> {
+        let ix = deposit_instruction(
+            &self.yield_venue,
+            self.escrow_vault.key(),
+            self.share_vault.key(),
+            self.option_context.key(),
+            self.token_program.key(),
+            amount,
+        );
+        let before = self.share_vault.amount;
+        let accounts = [
+            self.escrow_vault.to_account_info(),
+            self.share_vault.to_account_info(),
+        ];
+        self.invoke_reserve(&ix, accounts)?;
+        self.share_vault.reload()?;
+        self.share_vault
+            .amount
+            .checked_sub(before)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))
+    }
+
+    /// Redeems `shares` from the reserve into the escrow vault and returns
+    /// the escrow it received
+    pub fn unwind(&mut self, shares: u64) -> Result<u64> {
+        let ix = redeem_instruction(
+            &self.yield_venue,
+            self.share_vault.key(),
+            self.escrow_vault.key(),
+            self.option_context.key(),
+            self.token_program.key(),
+            shares,
+        );
+        let before = self.escrow_vault.amount;
+        let accounts = [
+            self.share_vault.to_account_info(),
+            self.escrow_vault.to_account_info(),
+        ];
+        self.invoke_reserve(&ix, accounts)?;
+        self.escrow_vault.reload()?;
+        self.escrow_vault
+            .amount
+            .checked_sub(before)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))
+    }
+
+    /// Runs a reserve instruction signed by the series PDA, which owns both
+    /// the escrow vault and the share vault
+    fn invoke_reserve(
+        &self,
+        ix: &Instruction,
+        [source, destination]: [AccountInfo<'info>; 2],
+    ) -> Result<()> {
+        let option_context = &self.option_context;
+        let strike_price_bytes = option_context.strike_price.to_le_bytes();
+        let expiration_bytes = option_context.expiration.to_le_bytes();
+        let option_type_byte = [option_context.option_type as u8];
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"option_context",
+            option_context.collateral_mint.as_ref(),
+            option_context.consideration_mint.as_ref(),
+            strike_price_bytes.as_ref(),
+            expiration_bytes.as_ref(),
+            &option_type_byte,
+            &[option_context.bump],
+        ]];
+
+        invoke_signed(
+            ix,
+            &[
+                source,
+                destination,
+                self.reserve.to_account_info(),
+                self.reserve_liquidity_supply.to_account_info(),
+                self.share_mint.to_account_info(),
+                self.lending_market.to_account_info(),
+                self.lending_market_authority.to_account_info(),
+                option_context.to_account_info(),
+                self.token_program.to_account_info(),
+                self.lending_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
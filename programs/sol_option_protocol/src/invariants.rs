@@ -0,0 +1,188 @@
+//! Series accounting invariants.
+//!
+//! The option handlers keep their own ledger of each series (the deposit and
+//! payout counters, supplies, margin and lent escrow) next to the live vaults
+//! and mints. `assert_series_invariants` checks that the two still agree, so
+//! accounting drift fails the instruction that caused it instead of surfacing
+//! at settlement. Handlers call `check_series` last; it only checks when the
+//! program is built with the `invariants` feature.
+//!
+//! Every handler that moves a series' funds or changes its ledger calls it,
+//! directly or through the shared mint, exercise, burn, redeem and claim paths
+//! (so the compact, native, swap, sell, exit, claim-all, vault-round and
+//! PsyOptions entry points are covered too). Spreads and rolls check both
+//! series they touch. The handlers left out change nothing it asserts:
+//! - `create_option` and `create_series_batch` start an empty ledger, and
+//!   `close_series` only closes a series whose ledger is already empty;
+//! - combos and the vertical spread's mint, burn, claim and redeem move option
+//!   tokens and backing between holders and accounts the combo or spread owns,
+//!   never a series' vaults, mints or counters (`settle_vertical_spread`, which
+//!   pays out of the long series, is checked);
+//! - `queue_emergency_withdrawal` and `cancel_emergency_withdrawal` move
+//!   nothing, `execute_emergency_withdrawal` is checked;
+//! - knock-outs, freezes, funding accrual, oracle observations, attestations,
+//!   pauses, metadata, authority transfers, yield enabling, version steps past
+//!   the legacy one and the protocol-level setters only change status, price
+//!   or configuration fields (`migrate_series` checks the vault ledger it
+//!   backfills for a legacy series).
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+
+/// What a series' vaults hold and its mints have issued, as the token
+/// programs see them; None for accounts the handler doesn't take
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SeriesBalances {
+    pub collateral_vault: Option<u64>,
+    pub consideration_vault: Option<u64>,
+    pub option_supply: Option<u64>,
+    pub redemption_supply: Option<u64>,
+}
+
+/// Live accounts of a series a handler has at hand
+#[derive(Default)]
+pub struct SeriesAccounts<'info> {
+    pub collateral_vault: Option<AccountInfo<'info>>,
+    pub consideration_vault: Option<AccountInfo<'info>>,
+    pub option_mint: Option<AccountInfo<'info>>,
+    pub redemption_mint: Option<AccountInfo<'info>>,
+}
+
+impl<'info> SeriesAccounts<'info> {
+    /// Only `vault`, the series' escrow vault (`OptionData::margin_vault`:
+    /// collateral for calls and forwards, consideration otherwise)
+    pub fn escrow_vault(series: &OptionData, vault: AccountInfo<'info>) -> Self {
+        if series.escrows_consideration() {
+            Self {
+                consideration_vault: Some(vault),
+                ..Self::default()
+            }
+        } else {
+            Self {
+                collateral_vault: Some(vault),
+                ..Self::default()
+            }
+        }
+    }
+
+    /// Reads the balances from account data, so transfers made by CPI earlier
+    /// in the instruction are seen
+    pub fn balances(&self) -> Result<SeriesBalances> {
+        let amount = |info: &AccountInfo| -> Result<u64> {
+            Ok(TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?.amount)
+        };
+        let supply = |info: &AccountInfo| -> Result<u64> {
+            Ok(Mint::try_deserialize(&mut &info.try_borrow_data()?[..])?.supply)
+        };
+        Ok(SeriesBalances {
+            collateral_vault: self.collateral_vault.as_ref().map(amount).transpose()?,
+            consideration_vault: self.consideration_vault.as_ref().map(amount).transpose()?,
+            option_supply: self.option_mint.as_ref().map(supply).transpose()?,
+            redemption_supply: self.redemption_mint.as_ref().map(supply).transpose()?,
+        })
+    }
+}
+
+/// Asserts the series' invariants against `accounts` when the program is
+/// built with the `invariants` feature; a no-op otherwise
+pub fn check_series(series: &OptionData, accounts: SeriesAccounts) -> Result<()> {
+    if cfg!(feature = "invariants") {
+        assert_series_invariants(series, &accounts.balances()?)
+    } else {
+        Ok(())
+    }
+}
+
+/// Fails with `InvariantViolated`, logging the first broken invariant, unless
+/// - no vault has paid out more than was credited to it;
+/// - each vault holds what the series accounts for in it (less escrow held
+///   by substitute vaults or lent out, plus margin);
+/// - exercises, claims and margin writes stay within the options written;
+/// - the option and redemption supplies stay within what those counters allow;
+/// - until settlement, the escrow covers the options outstanding outside margin.
+pub fn assert_series_invariants(series: &OptionData, balances: &SeriesBalances) -> Result<()> {
+    ensure(
+        series.collateral_paid <= series.collateral_deposited,
+        "collateral paid out exceeds deposits",
+    )?;
+    ensure(
+        series.consideration_paid <= series.consideration_received,
+        "consideration paid out exceeds receipts",
+    )?;
+
+    let held_elsewhere = series
+        .basket_escrow_total()
+        .checked_add(series.yield_principal)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let expected = |vault: &Pubkey, accounted: u64| -> Result<u64> {
+        if *vault != series.margin_vault() {
+            return Ok(accounted);
+        }
+        Ok(accounted
+            .checked_add(series.margin_collateral)
+            .ok_or(ErrorCode::MathOverflow)?
+            .saturating_sub(held_elsewhere))
+    };
+    if let Some(held) = balances.collateral_vault {
+        ensure(
+            held >= expected(&series.collateral_vault, series.collateral_balance())?,
+            "collateral vault holds less than accounted",
+        )?;
+    }
+    if let Some(held) = balances.consideration_vault {
+        ensure(
+            held >= expected(&series.consideration_vault, series.consideration_balance())?,
+            "consideration vault holds less than accounted",
+        )?;
+    }
+
+    ensure(
+        series.exercised_amount <= series.total_supply,
+        "more options exercised than written",
+    )?;
+    ensure(
+        series.exercised_claimed <= series.exercised_amount,
+        "more exercises claimed than made",
+    )?;
+    ensure(
+        series.margin_written <= series.total_supply,
+        "more options written on margin than in total",
+    )?;
+    let unexercised = series.total_supply - series.exercised_amount;
+    if let Some(option_supply) = balances.option_supply {
+        ensure(
+            option_supply <= unexercised,
+            "option supply exceeds unexercised options",
+        )?;
+    }
+    if let Some(redemption_supply) = balances.redemption_supply {
+        let backed = series.total_supply - series.margin_written;
+        ensure(
+            redemption_supply <= backed.saturating_sub(series.exercised_claimed),
+            "redemption supply exceeds escrowed, unclaimed writes",
+        )?;
+    }
+
+    if series.settled_at == 0 {
+        if let Some(option_supply) = balances.option_supply {
+            let pool_options = option_supply.saturating_sub(series.margin_written);
+            let owed = series.escrow_payout(series.underlying_amount(pool_options)?)?;
+            ensure(
+                series.escrow_balance() >= owed,
+                "escrow does not cover outstanding options",
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn ensure(holds: bool, invariant: &str) -> Result<()> {
+    if !holds {
+        msg!("Invariant violated: {}", invariant);
+        return err!(ErrorCode::InvariantViolated);
+    }
+    Ok(())
+}
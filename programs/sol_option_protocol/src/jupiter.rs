@@ -0,0 +1,52 @@
+//! Jupiter aggregator adapter.
+//!
+//! Routes are quoted off-chain: Jupiter's swap-instructions API returns the
+//! swap instruction's data and accounts, which clients pass through as is.
+//! The protocol only forwards the route and never signs it with a PDA, so a
+//! swap can move nothing beyond what the user's own signature allows.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+
+use crate::errors::ErrorCode;
+
+/// Jupiter aggregator v6
+pub const JUPITER_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// Jupiter instruction of a route over `accounts`, keeping their signer and
+/// writable flags; `data` must at least hold an instruction discriminator
+pub fn route_instruction(accounts: &[AccountInfo], data: Vec<u8>) -> Result<Instruction> {
+    require!(data.len() >= 8, ErrorCode::InvalidSwapRoute);
+    Ok(Instruction {
+        program_id: JUPITER_PROGRAM_ID,
+        accounts: accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data,
+    })
+}
+
+/// CPI into Jupiter with a route built off-chain
+pub fn swap<'info>(
+    jupiter_program: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    data: Vec<u8>,
+) -> Result<()> {
+    require_keys_eq!(
+        jupiter_program.key(),
+        JUPITER_PROGRAM_ID,
+        ErrorCode::InvalidSwapProgram
+    );
+
+    let ix = route_instruction(accounts, data)?;
+    let mut infos = accounts.to_vec();
+    infos.push(jupiter_program.clone());
+    invoke(&ix, &infos)?;
+
+    Ok(())
+}
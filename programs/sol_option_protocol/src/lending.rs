@@ -0,0 +1,78 @@
+//! Token-lending adapter for Solend-style reserves (SPL token-lending and its
+//! forks).
+//!
+//! Depositing liquidity into a reserve mints its collateral token, a share of
+//! the reserve that grows as borrowers pay interest; redeeming the shares pays
+//! the liquidity back. Both need the reserve refreshed earlier in the same
+//! transaction (the program's `refresh_reserve`), which clients prepend.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::state::YieldVenue;
+
+/// Tag of `DepositReserveLiquidity` (liquidity in, collateral shares out)
+pub const DEPOSIT_RESERVE_LIQUIDITY: u8 = 4;
+
+/// Tag of `RedeemReserveCollateral` (collateral shares in, liquidity out)
+pub const REDEEM_RESERVE_COLLATERAL: u8 = 5;
+
+/// `DepositReserveLiquidity` of `amount` from `source` into `venue`'s reserve,
+/// minting its shares to `destination`
+pub fn deposit_instruction(
+    venue: &YieldVenue,
+    source: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    Instruction {
+        program_id: venue.lending_program,
+        accounts: vec![
+            AccountMeta::new(source, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new(venue.reserve, false),
+            AccountMeta::new(venue.reserve_liquidity_supply, false),
+            AccountMeta::new(venue.share_mint, false),
+            AccountMeta::new_readonly(venue.lending_market, false),
+            AccountMeta::new_readonly(venue.lending_market_authority, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+        data: instruction_data(DEPOSIT_RESERVE_LIQUIDITY, amount),
+    }
+}
+
+/// `RedeemReserveCollateral` of `shares` from `source`, paying the liquidity
+/// they are worth to `destination`
+pub fn redeem_instruction(
+    venue: &YieldVenue,
+    source: Pubkey,
+    destination: Pubkey,
+    authority: Pubkey,
+    token_program: Pubkey,
+    shares: u64,
+) -> Instruction {
+    Instruction {
+        program_id: venue.lending_program,
+        accounts: vec![
+            AccountMeta::new(source, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new(venue.reserve, false),
+            AccountMeta::new(venue.share_mint, false),
+            AccountMeta::new(venue.reserve_liquidity_supply, false),
+            AccountMeta::new_readonly(venue.lending_market, false),
+            AccountMeta::new_readonly(venue.lending_market_authority, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+        data: instruction_data(REDEEM_RESERVE_COLLATERAL, shares),
+    }
+}
+
+fn instruction_data(tag: u8, amount: u64) -> Vec<u8> {
+    let mut data = vec![tag];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
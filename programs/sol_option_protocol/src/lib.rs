@@ -2,22 +2,34 @@ use anchor_lang::prelude::*;
 
 use instructions::*;
 
+pub mod client;
 pub mod errors;
+pub mod events;
+pub mod governance;
 pub mod instructions;
+pub mod invariants;
+pub mod jupiter;
+pub mod lending;
+pub mod metadata;
+pub mod migration;
+pub mod openbook;
+pub mod oracle;
+pub mod state;
 pub mod utils;
+pub mod version;
+pub mod wormhole;
 
 // Re-export at crate root for Anchor's macro expansion
-pub use instructions::{OptionContext, OptionData, OptionCreate};
-
+pub use instructions::{
+    ExerciseStyle, OptionContext, OptionCreate, OptionData, OptionType, SeriesConfig,
+};
 
 declare_id!("7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP");
 
-
 #[program]
-pub mod sol_option_protocol {    
+pub mod sol_option_protocol {
     use super::*;
 
-
     /// CreateOption: Initializes OptionContext + vaults + mints
     pub fn create_option(
         ctx: Context<OptionCreate>,
@@ -25,9 +37,40 @@ pub mod sol_option_protocol {
         consideration_mint: Pubkey,
         strike_price: u64,
         expiration: i64,
-        is_put: bool,
+        option_type: OptionType,
+        config: SeriesConfig,
+    ) -> Result<()> {
+        instructions::create_series::handler(
+            ctx,
+            collateral_mint,
+            consideration_mint,
+            strike_price,
+            expiration,
+            option_type,
+            config,
+        )
+    }
+
+    /// CreateSeriesBatch: creates a strike ladder (one series per strike, shared
+    /// mints, expiry, type and config); per-strike PDAs in remaining_accounts
+    pub fn create_series_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSeriesBatch<'info>>,
+        collateral_mint: Pubkey,
+        consideration_mint: Pubkey,
+        expiration: i64,
+        option_type: OptionType,
+        strikes: Vec<u64>,
+        config: SeriesConfig,
     ) -> Result<()> {
-        instructions::create_series::handler(ctx, collateral_mint, consideration_mint, strike_price, expiration, is_put)
+        instructions::create_series_batch::handler(
+            ctx,
+            collateral_mint,
+            consideration_mint,
+            expiration,
+            option_type,
+            strikes,
+            config,
+        )
     }
 
     /// Mint: deposit collateral → mint option + redemption tokens 1:1
@@ -35,25 +78,802 @@ pub mod sol_option_protocol {
         instructions::mint_options::handler(ctx, amount)
     }
 
+    /// MintCompact: `mint` over `MintAccounts` (existing token accounts only,
+    /// no leg owners)
+    pub fn mint_compact(ctx: Context<MintAccounts>, amount: u64) -> Result<()> {
+        instructions::mint_compact::handler(ctx, amount)
+    }
+
+    /// MintAndList: `mint`, then list the minted options on a marketplace market
+    /// at `price` through `place_order` (an ask, in one transaction)
+    pub fn mint_and_list<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintAndList<'info>>,
+        amount: u64,
+        price: u64,
+    ) -> Result<()> {
+        instructions::mint_and_list::handler(ctx, amount, price)
+    }
+
+    /// CreateOptionVault: manager opens a pooled vault writing `option_type`
+    /// series of an underlying against a quote mint, with its share mint
+    pub fn create_option_vault(
+        ctx: Context<CreateOptionVault>,
+        option_type: OptionType,
+    ) -> Result<()> {
+        instructions::create_option_vault::handler(ctx, option_type)
+    }
+
+    /// DepositToVault: between rounds, deposit the vault's escrow mint (and
+    /// the matching part of its other balance) for shares
+    pub fn deposit_to_vault(ctx: Context<VaultShares>, amount: u64) -> Result<()> {
+        instructions::deposit_to_vault::handler(ctx, amount)
+    }
+
+    /// WithdrawFromVault: between rounds, burn shares for their part of both
+    /// of the vault's balances
+    pub fn withdraw_from_vault(ctx: Context<VaultShares>, shares: u64) -> Result<()> {
+        instructions::withdraw_from_vault::handler(ctx, shares)
+    }
+
+    /// StartVaultRound: manager writes `amount` options of a series from the
+    /// vault's escrow and lists them at `price` (`mint_and_list` as the vault)
+    pub fn start_vault_round(ctx: Context<StartVaultRound>, amount: u64, price: u64) -> Result<()> {
+        instructions::start_vault_round::handler(ctx, amount, price)
+    }
+
+    /// SettleVaultRound: anyone cancels the rest of a round's ask and redeems
+    /// the vault's short side once the series redeems
+    pub fn settle_vault_round(ctx: Context<SettleVaultRound>) -> Result<()> {
+        instructions::settle_vault_round::handler(ctx)
+    }
+
+    /// ListVaultCollateral: a put vault's manager lists assigned underlying
+    /// on the marketplace between rounds
+    pub fn list_vault_collateral(
+        ctx: Context<ListVaultCollateral>,
+        amount: u64,
+        price: u64,
+    ) -> Result<()> {
+        instructions::list_vault_collateral::handler(ctx, amount, price)
+    }
+
+    /// CloseVaultListing: cancels the rest of a put vault's listing (the
+    /// manager, or anyone once it sold out)
+    pub fn close_vault_listing(ctx: Context<CloseVaultListing>) -> Result<()> {
+        instructions::close_vault_listing::handler(ctx)
+    }
+
+    /// AttestSettlement: anyone records a settled series' result for
+    /// cross-chain consumers, optionally posting it through Wormhole
+    pub fn attest_settlement(ctx: Context<AttestSettlement>) -> Result<()> {
+        instructions::attest_settlement::handler(ctx)
+    }
+
+    /// OpenOpenbookMarket: the series authority creates an OpenBook v2 market
+    /// for the series' option token
+    pub fn open_openbook_market(
+        ctx: Context<OpenOpenbookMarket>,
+        params: OpenbookMarketParams,
+    ) -> Result<()> {
+        instructions::open_openbook_market::handler(ctx, params)
+    }
+
+    /// SeedOpenbookMarket: `mint` plus an OpenBook v2 ask of the minted options
+    pub fn seed_openbook_market(
+        ctx: Context<SeedOpenbookMarket>,
+        amount: u64,
+        price_lots: i64,
+        base_lots: i64,
+    ) -> Result<()> {
+        instructions::seed_openbook_market::handler(ctx, amount, price_lots, base_lots)
+    }
+
+    /// MintNative: `mint` for WSOL series paying in lamports (up to
+    /// `lamports` wrapped, the rest returned unwrapped)
+    pub fn mint_native(ctx: Context<OptionContext>, amount: u64, lamports: u64) -> Result<()> {
+        instructions::mint_native::handler(ctx, amount, lamports)
+    }
+
+    /// MintWithSubstitute: mint against a governance-approved substitute for the
+    /// escrow mint, held in the series' vault for that substitute
+    pub fn mint_with_substitute(ctx: Context<MintWithSubstitute>, amount: u64) -> Result<()> {
+        instructions::mint_with_substitute::handler(ctx, amount)
+    }
+
     /// Exercise: burn options, pay strike → receive collateral
-    pub fn exercise(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+    pub fn exercise<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::exercise::handler(ctx, amount)
     }
 
+    /// ExerciseCompact: `exercise` over `ExerciseAccounts` (no short leg)
+    pub fn exercise_compact<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExerciseAccounts<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::exercise_compact::handler(ctx, amount)
+    }
+
+    /// SwapAndExercise: `exercise` paid for by a Jupiter route swapping any
+    /// token the user holds into the strike (calls) or the collateral (puts)
+    pub fn swap_and_exercise<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapAndExercise<'info>>,
+        amount: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::swap_and_exercise::handler(ctx, amount, route_data)
+    }
+
+    /// ExerciseAndSell: flash `exercise` of calls, selling `sell_amount` of the
+    /// collateral paid out into a marketplace bid before the strike is collected
+    pub fn exercise_and_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExerciseAndSell<'info>>,
+        amount: u64,
+        sell_amount: u64,
+    ) -> Result<()> {
+        instructions::exercise_and_sell::handler(ctx, amount, sell_amount)
+    }
+
+    /// ExerciseNative: `exercise` for WSOL series, delivering up to `lamports`
+    /// wrapped and receiving the payout and the rest as lamports
+    pub fn exercise_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::exercise_native::handler(ctx, amount, lamports)
+    }
+
     /// Redeem: post-expiry pro-rata of collateral + consideration by burning redemption tokens
-    pub fn redeem(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+    pub fn redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::redeem::handler(ctx, amount)
     }
 
+    /// RedeemCompact: `redeem` over `RedeemAccounts` (no long leg or fee accounts)
+    pub fn redeem_compact<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemAccounts<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_compact::handler(ctx, amount)
+    }
+
+    /// RedeemNative: `redeem` for WSOL series, the WSOL payout as lamports
+    pub fn redeem_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_native::handler(ctx, amount)
+    }
+
+    /// ClaimNetPayoff: post-settlement, burn option tokens for their netted ITM payoff
+    pub fn claim_net_payoff<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::claim_net_payoff::handler(ctx, amount)
+    }
+
     /// Burn: burn both legs to reclaim 1:1 collateral anytime
-    pub fn burn(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+    pub fn burn<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
         instructions::burn_paired::handler(ctx, amount)
     }
 
+    /// BurnCompact: `burn` over `BurnAccounts` (no protocol config or fee accounts)
+    pub fn burn_compact<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnAccounts<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::burn_compact::handler(ctx, amount)
+    }
+
+    /// BurnNative: `burn` for WSOL series, the escrow returned as lamports
+    pub fn burn_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::burn_native::handler(ctx, amount)
+    }
+
+    /// RollPosition: burn `amount` pairs and re-mint them at the same strike
+    /// with a later expiry, moving the escrow between vaults
+    pub fn roll_position(ctx: Context<RollPosition>, amount: u64) -> Result<()> {
+        instructions::roll_position::handler(ctx, amount)
+    }
+
+    /// OpenFeeStats: opt in to recording this signer's mint and exercise volume in
+    /// a consideration mint, which earns fee tier discounts
+    pub fn open_fee_stats(ctx: Context<OpenFeeStats>) -> Result<()> {
+        instructions::open_fee_stats::handler(ctx)
+    }
+
+    /// OpenReferralStats: a referrer opts in to earning the referral share of fees
+    /// paid in a mint, recorded in their stats
+    pub fn open_referral_stats(ctx: Context<OpenReferralStats>) -> Result<()> {
+        instructions::open_referral_stats::handler(ctx)
+    }
+
+    /// OpenWriterPosition: opt in to recording this signer's written amount in a series
+    /// (mint and burn update it when the position account is passed)
+    pub fn open_writer_position(ctx: Context<OpenWriterPosition>) -> Result<()> {
+        instructions::open_writer_position::handler(ctx)
+    }
+
+    /// MintOnMargin: write `amount` options (long leg only) against the signer's
+    /// writer position, adding `deposit` margin; must meet the initial requirement
+    pub fn mint_on_margin(ctx: Context<MarginContext>, amount: u64, deposit: u64) -> Result<()> {
+        instructions::mint_on_margin::handler(ctx, amount, deposit)
+    }
+
+    /// DepositMargin: top up any writer's margin position
+    pub fn deposit_margin(ctx: Context<MarginContext>, amount: u64) -> Result<()> {
+        instructions::deposit_margin::handler(ctx, amount)
+    }
+
+    /// WithdrawMargin: burn `burn` options against the signer's margin short and
+    /// withdraw `withdraw` margin, staying above the initial requirement
+    pub fn withdraw_margin(ctx: Context<MarginContext>, burn: u64, withdraw: u64) -> Result<()> {
+        instructions::withdraw_margin::handler(ctx, burn, withdraw)
+    }
+
+    /// Liquidate: keepers burn `amount` options against a position below maintenance
+    /// margin and seize its pro-rata margin
+    pub fn liquidate(ctx: Context<MarginContext>, amount: u64) -> Result<()> {
+        instructions::liquidate::handler(ctx, amount)
+    }
+
+    /// SettleMarginPosition: after settlement, return what the writer's margin
+    /// doesn't owe option holders
+    pub fn settle_margin_position(ctx: Context<MarginContext>) -> Result<()> {
+        instructions::settle_margin_position::handler(ctx)
+    }
 
-    /// Allows SHORT token holders to claim pro-rata consideration before expiry
+    /// CreateSpread: lock `amount` long options of a further-OTM series (same mints
+    /// and expiry) against the signer's margin short, releasing the excess margin
+    pub fn create_spread<'info>(
+        ctx: Context<'_, '_, '_, 'info, SpreadContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::create_spread::handler(ctx, amount)
+    }
+
+    /// CloseSpread: unlock `amount` spread longs, staying above the initial requirement
+    pub fn close_spread<'info>(
+        ctx: Context<'_, '_, '_, 'info, SpreadContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::close_spread::handler(ctx, amount)
+    }
+
+    /// SettleSpread: after the long series settles, pay the locked longs' netted
+    /// payoff into the position's margin (required before the short series settles)
+    pub fn settle_spread(ctx: Context<SpreadContext>) -> Result<()> {
+        instructions::settle_spread::handler(ctx)
+    }
+
+    /// MintVerticalSpread: lock `amount` further-OTM longs plus the strike
+    /// difference and mint `amount` spread tokens paying the short series' intrinsic value
+    pub fn mint_vertical_spread<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerticalSpreadContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_vertical_spread::handler(ctx, amount)
+    }
+
+    /// BurnVerticalSpread: writer burns spread tokens before settlement for
+    /// their longs and backing
+    pub fn burn_vertical_spread<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerticalSpreadContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::burn_vertical_spread::handler(ctx, amount)
+    }
+
+    /// SettleVerticalSpread: permissionless crank after the long series settles,
+    /// splitting the spread vault between holders and writers
+    pub fn settle_vertical_spread(ctx: Context<VerticalSpreadContext>) -> Result<()> {
+        instructions::settle_vertical_spread::handler(ctx)
+    }
+
+    /// ClaimVerticalSpread: holder burns settled spread tokens for their payoff
+    pub fn claim_vertical_spread(ctx: Context<VerticalSpreadContext>, amount: u64) -> Result<()> {
+        instructions::claim_vertical_spread::handler(ctx, amount)
+    }
+
+    /// RedeemVerticalSpread: writer takes their share of the settled spread's remainder
+    pub fn redeem_vertical_spread(ctx: Context<VerticalSpreadContext>) -> Result<()> {
+        instructions::redeem_vertical_spread::handler(ctx)
+    }
+
+    /// CreateCombo: lock `amount` call and put options (a straddle or strangle)
+    /// behind `amount` combo tokens
+    pub fn create_combo<'info>(
+        ctx: Context<'_, '_, '_, 'info, ComboContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::create_combo::handler(ctx, amount)
+    }
+
+    /// DecomposeCombo: burn `amount` combo tokens for their call and put legs
+    pub fn decompose_combo<'info>(
+        ctx: Context<'_, '_, '_, 'info, ComboContext<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::decompose_combo::handler(ctx, amount)
+    }
+
+    /// Allows SHORT token holders to burn redemption tokens for exercise proceeds before expiry
     /// Greek.fi compliance: Key capital efficiency feature
-    pub fn redeem_consideration(ctx: Context<OptionContext>) -> Result<()> {
-        instructions::redeem_consideration::handler(ctx)
+    pub fn redeem_consideration(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+        instructions::redeem_consideration::handler(ctx, amount)
+    }
+
+    /// RedeemExercisedShare: burn only the exercised fraction of `amount` redemption tokens
+    /// for its proceeds, keeping the collateral-backed remainder
+    pub fn redeem_exercised_share(ctx: Context<OptionContext>, amount: u64) -> Result<()> {
+        instructions::redeem_exercised_share::handler(ctx, amount)
+    }
+
+    /// ExitPosition: unwind the user's option and redemption tokens in one transaction
+    /// (paired burn and early claim before settlement, payoff claim and redeem after)
+    pub fn exit_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>,
+    ) -> Result<()> {
+        instructions::exit_position::handler(ctx)
+    }
+
+    /// ClaimAll: after settlement, burn every redemption token held for both the
+    /// collateral and consideration legs
+    pub fn claim_all<'info>(ctx: Context<'_, '_, '_, 'info, OptionContext<'info>>) -> Result<()> {
+        instructions::claim_all::handler(ctx)
+    }
+
+    /// SweepDust: permissionless; moves a fully redeemed series' leftover vault dust
+    /// to the treasury and closes the vaults
+    pub fn sweep_dust<'info>(ctx: Context<'_, '_, '_, 'info, SweepDust<'info>>) -> Result<()> {
+        instructions::sweep_dust::handler(ctx)
+    }
+
+    /// RecoverUnclaimed: permissionless; once the unclaimed timeout after expiry has
+    /// passed, moves a series' remaining vault funds to the treasury and closes the vaults
+    pub fn recover_unclaimed<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepDust<'info>>,
+    ) -> Result<()> {
+        instructions::recover_unclaimed::handler(ctx)
+    }
+
+    /// CloseSeries: permissionless; once a settled series has no tokens or margin
+    /// left, closes its vaults and OptionContext and refunds the rent to the authority
+    pub fn close_series(ctx: Context<CloseSeries>) -> Result<()> {
+        instructions::close_series::handler(ctx)
+    }
+
+    /// ObservePrice: permissionless crank recording a guarded oracle reading
+    /// The stored reading is the deviation reference for later oracle consumers
+    pub fn observe_price(ctx: Context<ObservePrice>) -> Result<()> {
+        instructions::observe_price::handler(ctx)
+    }
+
+    /// RecordKnockout: permissionless crank killing a knock-out series whose
+    /// barrier the oracle shows breached; it then settles with every option worthless
+    pub fn record_knockout(ctx: Context<RecordKnockout>) -> Result<()> {
+        instructions::record_knockout::handler(ctx)
+    }
+
+    /// AccrueFunding: permissionless crank bringing a perpetual series' funding
+    /// index up to date
+    pub fn accrue_funding(ctx: Context<AccrueFunding>) -> Result<()> {
+        instructions::accrue_funding::handler(ctx)
+    }
+
+    /// SettleExpiry: permissionless post-expiry crank recording the settlement price
+    /// Walks the primary → secondary oracle hierarchy and records which feed was used
+    /// Snapshots vault balances and freezes the series; redeem pays from the snapshot
+    pub fn settle_expiry(ctx: Context<SettleExpiry>) -> Result<()> {
+        instructions::settle_expiry::handler(ctx)
+    }
+
+    /// SettleForward: permissionless post-expiry crank delivering a forward at its
+    /// strike; option holders claim the collateral, redemption holders the strike
+    pub fn settle_forward(ctx: Context<SettleForward>) -> Result<()> {
+        instructions::settle_forward::handler(ctx)
+    }
+
+    /// SimulateRedemption: view instruction returning expiry payoffs at a hypothetical price
+    /// Lets UIs draw payoff diagrams from the same split settle_expiry pays out
+    pub fn simulate_redemption(
+        ctx: Context<SimulateRedemption>,
+        settlement_price: u64,
+        amount: u64,
+    ) -> Result<RedemptionOutcome> {
+        instructions::simulate_redemption::handler(ctx, settlement_price, amount)
+    }
+
+    /// DescribeSeries: view instruction returning the series' canonical symbol
+    /// (e.g. "BONK-28FEB25-0.04-C"), formatted by the same code as its token metadata
+    pub fn describe_series(ctx: Context<DescribeSeries>) -> Result<String> {
+        instructions::describe_series::handler(ctx)
+    }
+
+    /// ProposeSeriesAuthority: current authority nominates a successor (step 1 of 2)
+    pub fn propose_series_authority(
+        ctx: Context<ProposeSeriesAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_series_authority::handler(ctx, new_authority)
+    }
+
+    /// AcceptSeriesAuthority: nominated successor signs to take over (step 2 of 2)
+    pub fn accept_series_authority(ctx: Context<AcceptSeriesAuthority>) -> Result<()> {
+        instructions::accept_series_authority::handler(ctx)
+    }
+
+    /// SetSeriesPaused: series authority halts/resumes mint, exercise and linked-market trading
+    pub fn set_series_paused(ctx: Context<SetSeriesPaused>, paused: bool) -> Result<()> {
+        instructions::set_series_paused::handler(ctx, paused)
+    }
+
+    /// SetTokenAccountFrozen: compliance key of a freezable series freezes or
+    /// thaws a holder's option or redemption token account
+    pub fn set_token_account_frozen(
+        ctx: Context<SetTokenAccountFrozen>,
+        frozen: bool,
+    ) -> Result<()> {
+        instructions::set_token_account_frozen::handler(ctx, frozen)
+    }
+
+    /// UpdateSeriesMetadata: series authority rewrites the option and
+    /// redemption mints' token metadata (created if missing) from the series'
+    /// stored terms and metadata URI
+    pub fn update_series_metadata(ctx: Context<UpdateSeriesMetadata>) -> Result<()> {
+        instructions::update_series_metadata::handler(ctx)
+    }
+
+    /// OpenSeriesMarket: series authority creates (or adopts) the marketplace
+    /// market of the option token against the consideration and records it
+    pub fn open_series_market(ctx: Context<OpenSeriesMarket>) -> Result<()> {
+        instructions::open_series_market::handler(ctx)
+    }
+
+    /// SetSeriesEmergencyPaused: admin or guardian halts/resumes a series on a flag
+    /// the series authority can't clear (burn and redeem stay open)
+    pub fn set_series_emergency_paused(
+        ctx: Context<SetSeriesEmergencyPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::set_series_emergency_paused::handler(ctx, paused)
+    }
+
+    /// FreezeSeries: admin or guardian permanently halts mint and exercise in a
+    /// compromised series; it then settles with every option worthless and
+    /// redeems pro rata right away, regardless of expiry
+    pub fn freeze_series(ctx: Context<FreezeSeries>) -> Result<()> {
+        instructions::freeze_series::handler(ctx)
+    }
+
+    /// ResetOracleReference: admin clears a series' deviation reference after a
+    /// genuine move past the threshold, so guarded reads resume at once
+    pub fn reset_oracle_reference(ctx: Context<ResetOracleReference>) -> Result<()> {
+        instructions::reset_oracle_reference::handler(ctx)
+    }
+
+    /// SetProtocolPaused: admin or guardian halts/resumes mint and exercise in every
+    /// series (burn and redeem stay open)
+    pub fn set_protocol_paused(ctx: Context<SetProtocolPaused>, paused: bool) -> Result<()> {
+        instructions::set_protocol_paused::handler(ctx, paused)
+    }
+
+    /// MigratePsyPosition: close a paired PsyOptions position into this series' vault
+    /// and mint the equivalent option + redemption pair, without unwinding
+    pub fn migrate_psy_position(ctx: Context<MigratePsyPosition>, contracts: u64) -> Result<()> {
+        instructions::migrate_psy_position::handler(ctx, contracts)
+    }
+
+    // === PsyOptions American compatibility interface ===
+    // Same instruction names (and therefore Anchor discriminators), the same
+    // size-based semantics and the same leading accounts as PsyOptions
+    // American, routed onto this protocol's handlers. Integrations built
+    // against that CPI interface switch backends by changing the program id,
+    // marking the option market (the series) writable and appending the
+    // accounts each adapter lists after PsyOptions' own; `size` is in option
+    // token units.
+    //
+    // PsyOptions              → this protocol
+    // mint_option             → mint
+    // exercise_option         → exercise
+    // close_position          → burn (paired option + writer/redemption tokens)
+    // close_post_expiration   → redeem
+
+    /// PsyOptions-compatible `mint`
+    pub fn mint_option(ctx: Context<PsyMintOption>, size: u64) -> Result<()> {
+        instructions::psy_mint_option::handler(ctx, size)
+    }
+
+    /// PsyOptions-compatible `exercise`
+    pub fn exercise_option<'info>(
+        ctx: Context<'_, '_, '_, 'info, PsyExerciseOption<'info>>,
+        size: u64,
+    ) -> Result<()> {
+        instructions::psy_exercise_option::handler(ctx, size)
+    }
+
+    /// PsyOptions-compatible `burn`
+    pub fn close_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, PsyClosePosition<'info>>,
+        size: u64,
+    ) -> Result<()> {
+        instructions::psy_close_position::handler(ctx, size)
+    }
+
+    /// PsyOptions-compatible `redeem`
+    pub fn close_post_expiration<'info>(
+        ctx: Context<'_, '_, '_, 'info, PsyClosePostExpiration<'info>>,
+        size: u64,
+    ) -> Result<()> {
+        instructions::psy_close_post_expiration::handler(ctx, size)
+    }
+
+    /// InitializeConfig: one-time ProtocolConfig creation by the upgrade authority
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::handler(ctx)
+    }
+
+    /// UpdateRateLimits: admin sets per-creator series creation limits (0 disables)
+    pub fn update_rate_limits(
+        ctx: Context<UpdateRateLimits>,
+        max_series_per_epoch: u16,
+        creation_cooldown: i64,
+    ) -> Result<()> {
+        instructions::update_rate_limits::handler(ctx, max_series_per_epoch, creation_cooldown)
+    }
+
+    /// ProposeConfigAdmin: current admin nominates a successor (step 1 of 2)
+    pub fn propose_config_admin(ctx: Context<ProposeConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_config_admin::handler(ctx, new_admin)
+    }
+
+    /// ProposeGovernanceAdmin: current admin nominates a Realms DAO (its governance's
+    /// native treasury) as successor (step 1 of 2)
+    pub fn propose_governance_admin(ctx: Context<ProposeGovernanceAdmin>) -> Result<()> {
+        instructions::propose_governance_admin::handler(ctx)
+    }
+
+    /// AcceptConfigAdmin: nominated successor signs to take over (step 2 of 2; a
+    /// governance accepts by executing it from a proposal)
+    pub fn accept_config_admin(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+        instructions::accept_config_admin::handler(ctx)
+    }
+
+    /// SetGuardian: admin sets the key allowed to cancel emergency withdrawals and pause
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    /// SetTreasury: admin sets the owner of the accounts receiving swept series funds
+    pub fn set_treasury(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+        instructions::set_treasury::handler(ctx, treasury)
+    }
+
+    /// SetUnclaimedTimeout: admin sets how long after expiry abandoned series funds
+    /// become recoverable (0 disables recovery)
+    pub fn set_unclaimed_timeout(
+        ctx: Context<SetUnclaimedTimeout>,
+        unclaimed_timeout: i64,
+    ) -> Result<()> {
+        instructions::set_unclaimed_timeout::handler(ctx, unclaimed_timeout)
+    }
+
+    /// SetCreationBond: admin sets the lamports locked in each new series until it
+    /// is closed (0 disables)
+    pub fn set_creation_bond(ctx: Context<SetCreationBond>, creation_bond: u64) -> Result<()> {
+        instructions::set_creation_bond::handler(ctx, creation_bond)
+    }
+
+    /// SetExpiryGrid: admin restricts new expirations to `offset + k * interval`
+    /// (0 interval disables) and sets the creators exempt from it
+    pub fn set_expiry_grid(
+        ctx: Context<SetExpiryGrid>,
+        interval: i64,
+        offset: i64,
+        exempt: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_expiry_grid::handler(ctx, interval, offset, exempt)
+    }
+
+    /// SetExpiryLimits: admin bounds the time to expiration of new series
+    /// (0 disables either limit)
+    pub fn set_expiry_limits(
+        ctx: Context<SetExpiryLimits>,
+        min_time_to_expiry: i64,
+        max_time_to_expiry: i64,
+    ) -> Result<()> {
+        instructions::set_expiry_limits::handler(ctx, min_time_to_expiry, max_time_to_expiry)
+    }
+
+    /// SetFees: admin sets the mint and exercise fees in basis points (capped at
+    /// `ProtocolConfig::MAX_FEE_BPS`; 0 disables)
+    pub fn set_fees(ctx: Context<SetFees>, mint_fee_bps: u16, exercise_fee_bps: u16) -> Result<()> {
+        instructions::set_fees::handler(ctx, mint_fee_bps, exercise_fee_bps)
+    }
+
+    /// SetFeeTiers: admin replaces the volume tiers that discount mint and
+    /// exercise fees (empty removes every discount)
+    pub fn set_fee_tiers(ctx: Context<SetFeeTiers>, tiers: Vec<state::FeeTier>) -> Result<()> {
+        instructions::set_fee_tiers::handler(ctx, tiers)
+    }
+
+    /// SetFeeExempt: admin replaces the accounts (e.g. market makers) that pay
+    /// no mint or exercise fee
+    pub fn set_fee_exempt(ctx: Context<SetFeeExempt>, accounts: Vec<Pubkey>) -> Result<()> {
+        instructions::set_fee_exempt::handler(ctx, accounts)
+    }
+
+    /// SetReferralShare: admin sets the share of each fee, in basis points, paid
+    /// to the referrer passed with it (0 keeps every fee for the protocol)
+    pub fn set_referral_share(
+        ctx: Context<SetReferralShare>,
+        referral_share_bps: u16,
+    ) -> Result<()> {
+        instructions::set_referral_share::handler(ctx, referral_share_bps)
+    }
+
+    /// SetInsuranceShare: admin sets the share of the protocol's part of each fee,
+    /// in basis points, paid into the mint's insurance fund (0 = none)
+    pub fn set_insurance_share(
+        ctx: Context<SetInsuranceShare>,
+        insurance_share_bps: u16,
+    ) -> Result<()> {
+        instructions::set_insurance_share::handler(ctx, insurance_share_bps)
+    }
+
+    /// OpenInsuranceFund: admin creates the insurance fund of a mint, which fees
+    /// in that mint then pay their insurance share into
+    pub fn open_insurance_fund(ctx: Context<OpenInsuranceFund>) -> Result<()> {
+        instructions::open_insurance_fund::handler(ctx)
+    }
+
+    /// PayInsurance: admin (or the governance treasury holding the role) covers a
+    /// series vault's shortfall against its accounting from the insurance fund
+    pub fn pay_insurance(ctx: Context<PayInsurance>, amount: u64) -> Result<()> {
+        instructions::pay_insurance::handler(ctx, amount)
+    }
+
+    /// WithdrawFees: admin moves collected protocol fees out of a fee account of
+    /// the ProtocolConfig PDA
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx, amount)
+    }
+
+    /// SetFeatures: admin switches off protocol features (`ProtocolConfig::FEATURE_*`
+    /// bits); new series and substitute deposits using them are refused
+    pub fn set_features(ctx: Context<SetFeatures>, disabled_features: u32) -> Result<()> {
+        instructions::set_features::handler(ctx, disabled_features)
+    }
+
+    /// SetMintExtensions: admin allows risky Token-2022 mint extensions
+    /// (`ProtocolConfig::MINT_EXTENSION_*` bits) on new series' collateral and
+    /// consideration mints; mints carrying any other one are refused
+    pub fn set_mint_extensions(
+        ctx: Context<SetMintExtensions>,
+        allowed_mint_extensions: u32,
+    ) -> Result<()> {
+        instructions::set_mint_extensions::handler(ctx, allowed_mint_extensions)
+    }
+
+    /// SetStrikeTick: admin sets the strike increment new series quoted in a
+    /// consideration mint must respect (0 allows any strike)
+    pub fn set_strike_tick(ctx: Context<SetStrikeTick>, tick_size: u64) -> Result<()> {
+        instructions::set_strike_tick::handler(ctx, tick_size)
+    }
+
+    /// SetApprovedMint: admin approves or revokes a mint for new series' collateral
+    /// and consideration (checked while the mint allowlist is enabled)
+    pub fn set_approved_mint(ctx: Context<SetApprovedMint>, approved: bool) -> Result<()> {
+        instructions::set_approved_mint::handler(ctx, approved)
+    }
+
+    /// SetMintAllowlist: admin requires approved mints for new series (true) or
+    /// lets any SPL Token or Token-2022 mint back one (false)
+    pub fn set_mint_allowlist(ctx: Context<SetMintAllowlist>, enabled: bool) -> Result<()> {
+        instructions::set_mint_allowlist::handler(ctx, enabled)
+    }
+
+    /// SetTimelockDelay: admin lengthens the delay on fee and allowlist changes
+    /// (shortening it is scheduled like any other change)
+    pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, timelock_delay: i64) -> Result<()> {
+        instructions::set_timelock_delay::handler(ctx, timelock_delay)
+    }
+
+    /// ScheduleChange: admin queues a fee, allowlist, creation bond or rate limit
+    /// change under `id`; it can be applied once the timelock delay has passed
+    pub fn schedule_change(
+        ctx: Context<ScheduleChange>,
+        id: u64,
+        change: state::ParameterChange,
+    ) -> Result<()> {
+        instructions::schedule_change::handler(ctx, id, change)
+    }
+
+    /// ApplyChange: admin applies a scheduled change whose timelock has passed
+    pub fn apply_change(ctx: Context<ApplyChange>) -> Result<()> {
+        instructions::apply_change::handler(ctx)
+    }
+
+    /// CancelPending: admin or guardian drops a scheduled change before it applies
+    pub fn cancel_pending(ctx: Context<CancelPending>) -> Result<()> {
+        instructions::cancel_pending::handler(ctx)
+    }
+
+    /// SetCollateralBasket: admin sets the substitutes basket series accept in
+    /// place of an escrow mint (an empty list stops new substitute deposits)
+    pub fn set_collateral_basket(
+        ctx: Context<SetCollateralBasket>,
+        mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_collateral_basket::handler(ctx, mints)
+    }
+
+    /// SetYieldVenue: admin approves the lending reserve idle escrow of a mint
+    /// may be lent to, or switches an approved one on and off
+    pub fn set_yield_venue(
+        ctx: Context<SetYieldVenue>,
+        params: YieldVenueParams,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_yield_venue::handler(ctx, params, enabled)
+    }
+
+    /// EnableCollateralYield: series authority opts a series with nothing
+    /// escrowed yet into lending through its escrow mint's venue
+    pub fn enable_collateral_yield(ctx: Context<EnableCollateralYield>) -> Result<()> {
+        instructions::enable_collateral_yield::handler(ctx)
+    }
+
+    /// LendEscrow: series authority lends idle escrow to the series' venue
+    pub fn lend_escrow(ctx: Context<YieldContext>, amount: u64) -> Result<()> {
+        instructions::lend_escrow::handler(ctx, amount)
+    }
+
+    /// UnwindEscrow: anyone redeems venue shares back into the escrow vault
+    /// (ahead of a payout the vault can't cover)
+    pub fn unwind_escrow(ctx: Context<YieldContext>, shares: u64) -> Result<()> {
+        instructions::unwind_escrow::handler(ctx, shares)
+    }
+
+    /// QueueEmergencyWithdrawal: admin queues a timelocked vault withdrawal (justification hash)
+    pub fn queue_emergency_withdrawal(
+        ctx: Context<QueueEmergencyWithdrawal>,
+        amount: u64,
+        justification_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::queue_emergency_withdrawal::handler(ctx, amount, justification_hash)
+    }
+
+    /// CancelEmergencyWithdrawal: guardian (or admin) cancels a queued withdrawal
+    pub fn cancel_emergency_withdrawal(ctx: Context<CancelEmergencyWithdrawal>) -> Result<()> {
+        instructions::cancel_emergency_withdrawal::handler(ctx)
+    }
+
+    /// ExecuteEmergencyWithdrawal: admin executes a queued withdrawal once the timelock elapsed
+    pub fn execute_emergency_withdrawal(ctx: Context<ExecuteEmergencyWithdrawal>) -> Result<()> {
+        instructions::execute_emergency_withdrawal::handler(ctx)
+    }
+
+    /// AnnounceVersion: permissionless, emits version/capabilities/config hash once per upgrade
+    pub fn announce_version(ctx: Context<AnnounceVersion>) -> Result<()> {
+        instructions::announce_version::handler(ctx)
+    }
+
+    /// MigrateSeries: permissionless, grows a series to the current account layout
+    /// and steps its version up
+    pub fn migrate_series(ctx: Context<MigrateSeries>) -> Result<()> {
+        instructions::migrate_series::handler(ctx)
     }
 }
@@ -0,0 +1,274 @@
+//! Metaplex Token Metadata adapter.
+//!
+//! Wallets and explorers name a token after its metadata account, a PDA of
+//! the Token Metadata program (seeds `["metadata", program, mint]`). Series
+//! give their option and redemption mints one, named after the canonical
+//! symbol, with the series PDA (already their mint authority) as update
+//! authority. Only the two instructions the protocol issues are encoded here.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionData;
+use crate::utils::symbol::{series_symbol, type_code};
+
+/// Mainnet Token Metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Seed prefix of metadata accounts
+pub const METADATA_SEED: &[u8] = b"metadata";
+
+/// Longest name and symbol the Token Metadata program accepts
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+/// Name suffix of redemption tokens (the writers' side)
+pub const REDEMPTION_SUFFIX: &str = "-W";
+
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+
+/// Metadata account of `mint`
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            METADATA_SEED,
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// What the protocol sets on a metadata account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl TokenMetadata {
+    /// Metadata of a series' option mint, or of its redemption mint when
+    /// `redemption` is set. The name is the canonical symbol (e.g.
+    /// "BONK-28FEB25-0.04-C", "-W" appended for redemption tokens) and the
+    /// symbol the ticker and type code ("BONK-C", "BONK-CW"), both truncated
+    /// to the Token Metadata limits. The URI is the series' metadata URI.
+    pub fn for_series(option_context: &OptionData, redemption: bool) -> Self {
+        let canonical = series_symbol(option_context);
+        let (name, kind) = if redemption {
+            (
+                truncated(&canonical, MAX_NAME_LENGTH - REDEMPTION_SUFFIX.len())
+                    + REDEMPTION_SUFFIX,
+                format!("-{}W", type_code(option_context.option_type)),
+            )
+        } else {
+            (
+                truncated(&canonical, MAX_NAME_LENGTH),
+                format!("-{}", type_code(option_context.option_type)),
+            )
+        };
+        let symbol = truncated(
+            &option_context.underlying_symbol,
+            MAX_SYMBOL_LENGTH - kind.len(),
+        ) + &kind;
+        Self {
+            name,
+            symbol,
+            uri: option_context.metadata_uri.clone(),
+        }
+    }
+
+    fn data_v2(&self) -> DataV2 {
+        DataV2 {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }
+    }
+}
+
+/// Leading `max` characters of an ASCII string
+fn truncated(value: &str, max: usize) -> String {
+    value.chars().take(max).collect()
+}
+
+/// Borsh layout of the program's `DataV2`. The protocol never sets creators,
+/// a collection or uses, and Borsh encodes `None` as one zero byte whatever
+/// the payload type, so those fields are left untyped.
+#[derive(AnchorSerialize)]
+struct DataV2 {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<()>,
+    collection: Option<()>,
+    uses: Option<()>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateMetadataAccountArgsV3 {
+    data: DataV2,
+    is_mutable: bool,
+    collection_details: Option<()>,
+}
+
+#[derive(AnchorSerialize)]
+struct UpdateMetadataAccountArgsV2 {
+    data: Option<DataV2>,
+    update_authority: Option<Pubkey>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+}
+
+fn instruction_data(discriminator: u8, args: impl AnchorSerialize) -> Vec<u8> {
+    let mut data = vec![discriminator];
+    // Writing into a Vec can't fail
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+/// `CreateMetadataAccountV3` for `mint`, mutable, with `authority` as both
+/// its mint authority and the update authority
+pub fn create_metadata_ix(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    metadata: &TokenMetadata,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata_address(mint), false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(System::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+        ],
+        data: instruction_data(
+            CREATE_METADATA_ACCOUNT_V3,
+            CreateMetadataAccountArgsV3 {
+                data: metadata.data_v2(),
+                is_mutable: true,
+                collection_details: None,
+            },
+        ),
+    }
+}
+
+/// `UpdateMetadataAccountV2` replacing the name, symbol and URI of `mint`'s
+/// metadata (everything else is kept)
+pub fn update_metadata_ix(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    metadata: &TokenMetadata,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata_address(mint), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: instruction_data(
+            UPDATE_METADATA_ACCOUNT_V2,
+            UpdateMetadataAccountArgsV2 {
+                data: Some(metadata.data_v2()),
+                update_authority: None,
+                primary_sale_happened: None,
+                is_mutable: None,
+            },
+        ),
+    }
+}
+
+/// Accounts writing a series' token metadata takes
+pub struct SeriesMetadataAccounts<'a, 'info> {
+    pub metadata_program: &'a AccountInfo<'info>,
+    pub option_metadata: &'a AccountInfo<'info>,
+    pub redemption_metadata: &'a AccountInfo<'info>,
+    pub option_mint: &'a AccountInfo<'info>,
+    pub redemption_mint: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+}
+
+/// Creates the metadata accounts of the series' option and redemption mints,
+/// or updates them when they already exist (the series PDA signs)
+pub fn write_series_metadata<'info>(
+    option_context: &Account<'info, OptionData>,
+    accounts: SeriesMetadataAccounts<'_, 'info>,
+) -> Result<()> {
+    require_keys_eq!(
+        accounts.metadata_program.key(),
+        TOKEN_METADATA_PROGRAM_ID,
+        ErrorCode::InvalidMetadataAccount
+    );
+
+    let collateral_mint_key = option_context.collateral_mint;
+    let consideration_mint_key = option_context.consideration_mint;
+    let strike_price_bytes = option_context.strike_price.to_le_bytes();
+    let expiration_bytes = option_context.expiration.to_le_bytes();
+    let option_type_byte = [option_context.option_type as u8];
+    let bump = option_context.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"option_context",
+        collateral_mint_key.as_ref(),
+        consideration_mint_key.as_ref(),
+        strike_price_bytes.as_ref(),
+        expiration_bytes.as_ref(),
+        &option_type_byte,
+        &[bump],
+    ]];
+
+    let authority = option_context.to_account_info();
+    for (metadata, mint, redemption) in [
+        (accounts.option_metadata, accounts.option_mint, false),
+        (accounts.redemption_metadata, accounts.redemption_mint, true),
+    ] {
+        require_keys_eq!(
+            metadata.key(),
+            metadata_address(mint.key),
+            ErrorCode::InvalidMetadataAccount
+        );
+        let token_metadata = TokenMetadata::for_series(option_context, redemption);
+        if metadata.data_is_empty() {
+            invoke_signed(
+                &create_metadata_ix(mint.key, authority.key, accounts.payer.key, &token_metadata),
+                &[
+                    metadata.clone(),
+                    mint.clone(),
+                    authority.clone(),
+                    accounts.payer.clone(),
+                    accounts.system_program.clone(),
+                    accounts.rent.clone(),
+                    accounts.metadata_program.clone(),
+                ],
+                signer_seeds,
+            )?;
+        } else {
+            invoke_signed(
+                &update_metadata_ix(mint.key, authority.key, &token_metadata),
+                &[
+                    metadata.clone(),
+                    authority.clone(),
+                    accounts.metadata_program.clone(),
+                ],
+                signer_seeds,
+            )?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! Adapters for migrating positions out of other option protocols.
+//!
+//! Greek.fi series share this protocol's model (collateral + consideration
+//! mints, 1:1 long/short pair), so a Greek.fi-style position maps directly onto
+//! an OptionContext. PsyOptions American markets are the on-chain source we can
+//! unwind trustlessly: closing a paired PsyOptions position releases its
+//! underlying straight into our collateral vault, which then backs the new pair.
+
+pub mod psy_american;
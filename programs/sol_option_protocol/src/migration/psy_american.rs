@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+
+use crate::errors::ErrorCode;
+use crate::instructions::{ExerciseStyle, OptionData, OptionType};
+
+/// PsyOptions American program
+pub const PSY_AMERICAN_PROGRAM_ID: Pubkey = pubkey!("R2y9ip6mxmWUj4pt54jP2hz2dgvMozy9VTSwMWE7evs");
+
+/// Anchor discriminator of the PsyOptions `OptionMarket` account
+pub const OPTION_MARKET_DISCRIMINATOR: [u8; 8] = [175, 238, 162, 97, 53, 122, 16, 29];
+
+/// Anchor discriminator of the PsyOptions `close_position` instruction
+pub const CLOSE_POSITION_DISCRIMINATOR: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+
+/// Mirror of the PsyOptions `OptionMarket` account (after the discriminator)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PsyOptionMarket {
+    pub option_mint: Pubkey,
+    pub writer_token_mint: Pubkey,
+    pub underlying_asset_mint: Pubkey,
+    pub quote_asset_mint: Pubkey,
+    pub underlying_amount_per_contract: u64,
+    pub quote_amount_per_contract: u64,
+    pub expiration_unix_timestamp: i64,
+    pub underlying_asset_pool: Pubkey,
+    pub quote_asset_pool: Pubkey,
+    pub mint_fee_account: Pubkey,
+    pub exercise_fee_account: Pubkey,
+    pub expired: bool,
+    pub bump_seed: u8,
+}
+
+impl PsyOptionMarket {
+    /// Deserializes an `OptionMarket`, checking owner and discriminator
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            PSY_AMERICAN_PROGRAM_ID,
+            ErrorCode::InvalidMigrationSource
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == OPTION_MARKET_DISCRIMINATOR,
+            ErrorCode::InvalidMigrationSource
+        );
+
+        let mut payload: &[u8] = &data[8..];
+        Self::deserialize(&mut payload).map_err(|_| error!(ErrorCode::InvalidMigrationSource))
+    }
+
+    /// Strike in the units of a series with `strike_scale` (the price at which
+    /// `calculate_strike_payment` charges the contract's quote amount)
+    pub fn strike_price(&self, strike_scale: u8) -> Result<u64> {
+        require!(
+            self.underlying_amount_per_contract > 0,
+            ErrorCode::InvalidMigrationSource
+        );
+
+        let strike = (self.quote_amount_per_contract as u128)
+            .checked_mul(10_u128.pow(strike_scale as u32))
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(self.underlying_amount_per_contract as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        u64::try_from(strike).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+
+    /// Verifies the PsyOptions market describes the same contract as `series`
+    pub fn validate_matches(&self, series: &OptionData) -> Result<()> {
+        require!(!self.expired, ErrorCode::MigrationParameterMismatch);
+        // PsyOptions American contracts are physically settled calls on their
+        // underlying, so they may only move into American call series
+        require!(
+            series.exercise_style == ExerciseStyle::American
+                && series.option_type == OptionType::Call,
+            ErrorCode::MigrationParameterMismatch
+        );
+        require_keys_eq!(
+            self.underlying_asset_mint,
+            series.collateral_mint,
+            ErrorCode::MigrationParameterMismatch
+        );
+        require_keys_eq!(
+            self.quote_asset_mint,
+            series.consideration_mint,
+            ErrorCode::MigrationParameterMismatch
+        );
+        require_eq!(
+            self.expiration_unix_timestamp,
+            series.expiration,
+            ErrorCode::MigrationParameterMismatch
+        );
+        require_eq!(
+            self.strike_price(series.strike_scale)?,
+            series.strike_price,
+            ErrorCode::MigrationParameterMismatch
+        );
+        Ok(())
+    }
+}
+
+/// Accounts for PsyOptions `close_position` (burn option + writer pair → underlying)
+pub struct ClosePosition<'a, 'info> {
+    pub psy_program: &'a AccountInfo<'info>,
+    pub user_authority: &'a AccountInfo<'info>,
+    pub option_market: &'a AccountInfo<'info>,
+    pub option_mint: &'a AccountInfo<'info>,
+    pub option_token_src: &'a AccountInfo<'info>,
+    pub writer_token_mint: &'a AccountInfo<'info>,
+    pub writer_token_src: &'a AccountInfo<'info>,
+    pub underlying_asset_pool: &'a AccountInfo<'info>,
+    pub underlying_asset_dest: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+/// CPI into PsyOptions `close_position` for `contracts` paired contracts
+pub fn close_position(accounts: ClosePosition, contracts: u64) -> Result<()> {
+    let mut data = CLOSE_POSITION_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&contracts.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: PSY_AMERICAN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.user_authority.key(), true),
+            AccountMeta::new_readonly(accounts.option_market.key(), false),
+            AccountMeta::new(accounts.option_mint.key(), false),
+            AccountMeta::new(accounts.option_token_src.key(), false),
+            AccountMeta::new(accounts.writer_token_mint.key(), false),
+            AccountMeta::new(accounts.writer_token_src.key(), false),
+            AccountMeta::new(accounts.underlying_asset_pool.key(), false),
+            AccountMeta::new(accounts.underlying_asset_dest.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            accounts.user_authority.clone(),
+            accounts.option_market.clone(),
+            accounts.option_mint.clone(),
+            accounts.option_token_src.clone(),
+            accounts.writer_token_mint.clone(),
+            accounts.writer_token_src.clone(),
+            accounts.underlying_asset_pool.clone(),
+            accounts.underlying_asset_dest.clone(),
+            accounts.token_program.clone(),
+            accounts.psy_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+//! OpenBook v2 adapter.
+//!
+//! Teams wanting deeper liquidity than the built-in marketplace can list a
+//! series' option token on an OpenBook v2 order book. The protocol creates
+//! the market (option mint as base, consideration as quote, no oracles,
+//! expiring with the series) and lets writers seed it with an ask of options
+//! minted in the same instruction. The book's bids, asks and event heap are
+//! large zeroed accounts clients allocate beforehand, as OpenBook requires.
+//! Only the two instructions the protocol issues are encoded here.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+use crate::instructions::{OpenbookMarketParams, OptionData};
+use crate::utils::symbol::series_symbol;
+
+/// Mainnet OpenBook v2
+pub const OPENBOOK_PROGRAM_ID: Pubkey = pubkey!("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb");
+
+/// Seed prefix of a market's authority (owner of its vaults)
+pub const MARKET_AUTHORITY_SEED: &[u8] = b"Market";
+
+/// Seed of the program's Anchor event authority
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Longest market name OpenBook accepts
+pub const MAX_MARKET_NAME_LENGTH: usize = 16;
+
+/// Anchor discriminators (`sha256("global:<name>")[..8]`)
+const CREATE_MARKET: [u8; 8] = [103, 226, 97, 235, 200, 188, 251, 254];
+const PLACE_ORDER: [u8; 8] = [51, 194, 155, 175, 109, 130, 96, 106];
+
+/// `Side::Ask` and `PlaceOrderType::Limit`
+const SIDE_ASK: u8 = 1;
+const ORDER_TYPE_LIMIT: u8 = 0;
+
+/// `SelfTradeBehavior::DecrementTake`
+const DECREMENT_TAKE: u8 = 0;
+
+/// Orders matched per `place_order` at most
+const MATCH_LIMIT: u8 = 10;
+
+pub fn market_authority_address(market: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[MARKET_AUTHORITY_SEED, market.as_ref()],
+        &OPENBOOK_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn event_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &OPENBOOK_PROGRAM_ID).0
+}
+
+/// Name of the series' market: its canonical symbol, truncated
+pub fn market_name(option_context: &OptionData) -> String {
+    series_symbol(option_context)
+        .chars()
+        .take(MAX_MARKET_NAME_LENGTH)
+        .collect()
+}
+
+/// Expiry of the series' market: the series' expiration, or none (0) for
+/// perpetual series
+pub fn market_expiry(option_context: &OptionData) -> i64 {
+    if option_context.is_perpetual() {
+        0
+    } else {
+        option_context.expiration
+    }
+}
+
+#[derive(AnchorSerialize)]
+struct OracleConfigParams {
+    conf_filter: f32,
+    max_staleness_slots: Option<u32>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateMarketArgs {
+    name: String,
+    oracle_config: OracleConfigParams,
+    quote_lot_size: i64,
+    base_lot_size: i64,
+    maker_fee: i64,
+    taker_fee: i64,
+    time_expiry: i64,
+}
+
+#[derive(AnchorSerialize)]
+struct PlaceOrderArgs {
+    side: u8,
+    price_lots: i64,
+    max_base_lots: i64,
+    max_quote_lots_including_fees: i64,
+    client_order_id: u64,
+    order_type: u8,
+    expiry_timestamp: u64,
+    self_trade_behavior: u8,
+    limit: u8,
+}
+
+fn instruction_data(discriminator: [u8; 8], args: impl AnchorSerialize) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    // Writing into a Vec can't fail
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+/// Optional accounts OpenBook expects as its own program id when absent
+fn absent() -> AccountMeta {
+    AccountMeta::new_readonly(OPENBOOK_PROGRAM_ID, false)
+}
+
+/// Accounts of a new market of `base_mint` against `quote_mint`
+pub struct MarketAddresses {
+    pub market: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// `CreateMarket` without oracles, with `admin` collecting fees and able to
+/// close the market, and nobody gating open orders or event consumption
+pub fn create_market_ix(
+    addresses: &MarketAddresses,
+    payer: &Pubkey,
+    admin: &Pubkey,
+    name: String,
+    params: &OpenbookMarketParams,
+    time_expiry: i64,
+) -> Instruction {
+    let market_authority = market_authority_address(&addresses.market);
+    let vault = |mint: &Pubkey| {
+        anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &market_authority,
+            mint,
+            &addresses.token_program,
+        )
+    };
+    Instruction {
+        program_id: OPENBOOK_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(addresses.market, true),
+            AccountMeta::new_readonly(market_authority, false),
+            AccountMeta::new(addresses.bids, false),
+            AccountMeta::new(addresses.asks, false),
+            AccountMeta::new(addresses.event_heap, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(vault(&addresses.base_mint), false),
+            AccountMeta::new(vault(&addresses.quote_mint), false),
+            AccountMeta::new_readonly(addresses.base_mint, false),
+            AccountMeta::new_readonly(addresses.quote_mint, false),
+            AccountMeta::new_readonly(System::id(), false),
+            AccountMeta::new_readonly(addresses.token_program, false),
+            AccountMeta::new_readonly(anchor_spl::associated_token::ID, false),
+            absent(),
+            absent(),
+            AccountMeta::new_readonly(*admin, false),
+            absent(),
+            absent(),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(event_authority_address(), false),
+            AccountMeta::new_readonly(OPENBOOK_PROGRAM_ID, false),
+        ],
+        data: instruction_data(
+            CREATE_MARKET,
+            CreateMarketArgs {
+                name,
+                oracle_config: OracleConfigParams {
+                    conf_filter: 0.1,
+                    max_staleness_slots: None,
+                },
+                quote_lot_size: params.quote_lot_size,
+                base_lot_size: params.base_lot_size,
+                maker_fee: params.maker_fee,
+                taker_fee: params.taker_fee,
+                time_expiry,
+            },
+        ),
+    }
+}
+
+/// Accounts of an order on an existing market
+pub struct OrderAccounts {
+    pub owner: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub user_token_account: Pubkey,
+    pub market: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub market_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Limit `PlaceOrder` asking `price_lots` for `base_lots` of the base token
+pub fn place_ask_ix(accounts: &OrderAccounts, price_lots: i64, base_lots: i64) -> Instruction {
+    Instruction {
+        program_id: OPENBOOK_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.owner, true),
+            AccountMeta::new(accounts.open_orders_account, false),
+            absent(),
+            AccountMeta::new(accounts.user_token_account, false),
+            AccountMeta::new(accounts.market, false),
+            AccountMeta::new(accounts.bids, false),
+            AccountMeta::new(accounts.asks, false),
+            AccountMeta::new(accounts.event_heap, false),
+            AccountMeta::new(accounts.market_vault, false),
+            absent(),
+            absent(),
+            AccountMeta::new_readonly(accounts.token_program, false),
+        ],
+        data: instruction_data(
+            PLACE_ORDER,
+            PlaceOrderArgs {
+                side: SIDE_ASK,
+                price_lots,
+                max_base_lots: base_lots,
+                max_quote_lots_including_fees: i64::MAX,
+                client_order_id: 0,
+                order_type: ORDER_TYPE_LIMIT,
+                expiry_timestamp: 0,
+                self_trade_behavior: DECREMENT_TAKE,
+                limit: MATCH_LIMIT,
+            },
+        ),
+    }
+}
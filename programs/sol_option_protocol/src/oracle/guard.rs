@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::oracle::OraclePrice;
+
+/// Staleness window used when a feed does not configure its own
+pub const DEFAULT_MAX_STALENESS: i64 = 60;
+
+/// Seconds after expiration settlement falls back to late prices, if it hasn't
+/// happened by then
+pub const SETTLEMENT_FALLBACK_DELAY: i64 = 5 * 60;
+
+/// Latest a fallback settlement price may be published, in seconds after
+/// expiration
+pub const SETTLEMENT_FALLBACK_WINDOW: i64 = 30 * 60;
+
+/// Basis-point denominator for confidence and deviation thresholds
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Per-feed thresholds enforced on every oracle read (settlement, observation,
+/// and any later consumer), so a single bad print can never be trusted
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+pub struct OracleGuard {
+    /// Max seconds between publish time and the reference time (0 = default)
+    pub max_staleness: u32,
+    /// Max confidence interval as bps of price (0 = unchecked)
+    pub max_confidence_bps: u16,
+    /// Max move vs the previous accepted reading, in bps (0 = unchecked)
+    pub max_deviation_bps: u16,
+}
+
+impl OracleGuard {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_confidence_bps as u64 <= BPS_DENOMINATOR
+                && self.max_deviation_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidOracleConfig
+        );
+        Ok(())
+    }
+
+    pub fn max_staleness(&self) -> i64 {
+        if self.max_staleness == 0 {
+            DEFAULT_MAX_STALENESS
+        } else {
+            self.max_staleness as i64
+        }
+    }
+
+    /// Rejects prices published too far from `reference_time`
+    /// (expiration for settlement, the current clock for observations), other
+    /// than prices published up to `late_window` seconds after it
+    pub fn check_staleness(
+        &self,
+        price: &OraclePrice,
+        reference_time: i64,
+        late_window: i64,
+    ) -> Result<()> {
+        let offset = price
+            .publish_time
+            .checked_sub(reference_time)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let age = offset.checked_abs().ok_or(ErrorCode::MathOverflow)?;
+        let late = (0..=late_window).contains(&offset);
+        require!(
+            age <= self.max_staleness() || late,
+            ErrorCode::StaleOraclePrice
+        );
+        Ok(())
+    }
+
+    /// Rejects prices whose confidence interval is too wide relative to the price
+    pub fn check_confidence(&self, price: &OraclePrice) -> Result<()> {
+        require!(price.price > 0, ErrorCode::InvalidOraclePrice);
+        if self.max_confidence_bps == 0 {
+            return Ok(());
+        }
+
+        let lhs = (price.conf as u128)
+            .checked_mul(BPS_DENOMINATOR as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let rhs = (price.price as u128)
+            .checked_mul(self.max_confidence_bps as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(lhs <= rhs, ErrorCode::OracleConfidenceTooWide);
+        Ok(())
+    }
+
+    /// Rejects prices that moved too far from the previous accepted reading
+    /// (both values in strike units; a zero `previous` means no history yet)
+    ///
+    /// A reference published more than the staleness window before the new
+    /// price no longer binds: a genuine move past the threshold only blocks
+    /// reads until the last accepted reading ages out, never for good.
+    pub fn check_deviation(
+        &self,
+        previous: u64,
+        previous_time: i64,
+        current: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        if self.max_deviation_bps == 0 || previous == 0 {
+            return Ok(());
+        }
+        if current_time.saturating_sub(previous_time) > self.max_staleness() {
+            return Ok(());
+        }
+
+        let diff = previous.abs_diff(current) as u128;
+        let lhs = diff
+            .checked_mul(BPS_DENOMINATOR as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let rhs = (previous as u128)
+            .checked_mul(self.max_deviation_bps as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(lhs <= rhs, ErrorCode::OraclePriceDeviation);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFERENCE: i64 = 1_700_000_000;
+
+    fn price(price: i64, conf: u64, publish_time: i64) -> OraclePrice {
+        OraclePrice {
+            price,
+            conf,
+            expo: -8,
+            publish_time,
+        }
+    }
+
+    fn guard(max_confidence_bps: u16, max_deviation_bps: u16) -> OracleGuard {
+        OracleGuard {
+            max_staleness: 0,
+            max_confidence_bps,
+            max_deviation_bps,
+        }
+    }
+
+    #[test]
+    fn thresholds_are_at_most_the_whole_price() {
+        assert!(guard(10_000, 10_000).validate().is_ok());
+        let err = guard(10_001, 0).validate().unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidOracleConfig.into());
+        let err = guard(0, 10_001).validate().unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidOracleConfig.into());
+    }
+
+    #[test]
+    fn staleness_defaults_and_allows_the_late_window() {
+        let default = OracleGuard::default();
+        assert_eq!(default.max_staleness(), DEFAULT_MAX_STALENESS);
+        let early = price(1, 0, REFERENCE - DEFAULT_MAX_STALENESS);
+        assert!(default.check_staleness(&early, REFERENCE, 0).is_ok());
+        let stale = price(1, 0, REFERENCE - DEFAULT_MAX_STALENESS - 1);
+        let err = default.check_staleness(&stale, REFERENCE, 0).unwrap_err();
+        assert_eq!(err, ErrorCode::StaleOraclePrice.into());
+
+        let late = price(1, 0, REFERENCE + 600);
+        assert!(default.check_staleness(&late, REFERENCE, 0).is_err());
+        assert!(default.check_staleness(&late, REFERENCE, 600).is_ok());
+
+        let custom = OracleGuard {
+            max_staleness: 3_600,
+            ..OracleGuard::default()
+        };
+        assert!(custom.check_staleness(&stale, REFERENCE, 0).is_ok());
+    }
+
+    #[test]
+    fn confidence_is_bounded_relative_to_the_price() {
+        // 100 of 10_000 is 100 bps
+        let reading = price(10_000, 100, REFERENCE);
+        assert!(guard(100, 0).check_confidence(&reading).is_ok());
+        let err = guard(99, 0).check_confidence(&reading).unwrap_err();
+        assert_eq!(err, ErrorCode::OracleConfidenceTooWide.into());
+        assert!(guard(0, 0)
+            .check_confidence(&price(10_000, 10_000, REFERENCE))
+            .is_ok());
+
+        let err = guard(0, 0)
+            .check_confidence(&price(0, 0, REFERENCE))
+            .unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidOraclePrice.into());
+    }
+
+    #[test]
+    fn deviation_binds_only_against_a_recent_reading() {
+        let guard = guard(0, 1_000);
+        assert!(guard
+            .check_deviation(100, REFERENCE, 110, REFERENCE)
+            .is_ok());
+        assert!(guard.check_deviation(100, REFERENCE, 90, REFERENCE).is_ok());
+        let err = guard
+            .check_deviation(100, REFERENCE, 111, REFERENCE)
+            .unwrap_err();
+        assert_eq!(err, ErrorCode::OraclePriceDeviation.into());
+
+        // No history yet, or history older than the staleness window
+        assert!(guard.check_deviation(0, REFERENCE, 500, REFERENCE).is_ok());
+        let aged = REFERENCE + DEFAULT_MAX_STALENESS + 1;
+        assert!(guard.check_deviation(100, REFERENCE, 500, aged).is_ok());
+        assert!(OracleGuard::default()
+            .check_deviation(100, REFERENCE, 500, REFERENCE)
+            .is_ok());
+    }
+}
@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+pub mod guard;
+pub mod pyth;
+pub mod stake_pool;
+pub mod switchboard;
+
+pub use guard::OracleGuard;
+pub use pyth::Pyth;
+pub use stake_pool::StakePool;
+pub use switchboard::Switchboard;
+
+/// A price backend: one oracle program whose feed accounts decode into an
+/// `OraclePrice`. Adding a backend means a new `OracleKind` variant and an
+/// implementation of this trait; guards and settlement stay backend-agnostic.
+pub trait PriceSource {
+    /// Program that owns this backend's feed accounts
+    const OWNER: Pubkey;
+
+    /// Decodes the latest price from feed account data (owner already checked)
+    fn decode(data: &[u8]) -> Result<OraclePrice>;
+
+    /// Reads the latest price from a feed account owned by `OWNER`
+    fn read_price(account: &AccountInfo) -> Result<OraclePrice> {
+        require_keys_eq!(*account.owner, Self::OWNER, ErrorCode::InvalidOracleAccount);
+        let data = account.try_borrow_data()?;
+        Self::decode(&data)
+    }
+}
+
+/// Oracle backend a series feed is read from
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+pub enum OracleKind {
+    /// No feed configured for this slot
+    #[default]
+    None,
+    /// Pyth pull-oracle `PriceUpdateV2` account
+    Pyth,
+    /// Switchboard on-demand `PullFeedAccountData` account
+    Switchboard,
+    /// SPL stake pool account: the pool token's exchange rate in SOL
+    StakePool,
+}
+
+/// A single configured price feed (kind + account address + guard thresholds)
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+pub struct OracleFeed {
+    pub kind: OracleKind,
+    pub feed: Pubkey,
+    pub guard: OracleGuard,
+}
+
+impl OracleFeed {
+    pub fn is_configured(&self) -> bool {
+        self.kind != OracleKind::None
+    }
+}
+
+/// Which feed in the hierarchy produced the settlement price
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+pub enum SettlementSource {
+    /// No settlement price recorded (not settled yet, or settled without oracles)
+    #[default]
+    Unsettled,
+    Primary,
+    Secondary,
+}
+
+/// Normalized oracle reading: `price × 10^expo` units of quote per unit of base
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// Converts the reading into strike units: consideration per whole
+    /// collateral token with `strike_exponent` decimals (the unit the series'
+    /// `strike_price` is quoted in)
+    pub fn to_strike_units(&self, strike_exponent: u8) -> Result<u64> {
+        require!(self.price > 0, ErrorCode::InvalidOraclePrice);
+
+        let scale = self.expo + strike_exponent as i32;
+        let price = self.price as u128;
+        let value = if scale >= 0 {
+            price
+                .checked_mul(
+                    10_u128
+                        .checked_pow(scale as u32)
+                        .ok_or(ErrorCode::MathOverflow)?,
+                )
+                .ok_or(ErrorCode::MathOverflow)?
+        } else {
+            price
+                .checked_div(
+                    10_u128
+                        .checked_pow((-scale) as u32)
+                        .ok_or(ErrorCode::MathOverflow)?,
+                )
+                .ok_or(ErrorCode::MathOverflow)?
+        };
+
+        u64::try_from(value).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+}
+
+/// Reads a price from `account` using the backend configured in `feed`
+pub fn read_price(feed: &OracleFeed, account: &AccountInfo) -> Result<OraclePrice> {
+    require_keys_eq!(account.key(), feed.feed, ErrorCode::InvalidOracleAccount);
+
+    match feed.kind {
+        OracleKind::Pyth => Pyth::read_price(account),
+        OracleKind::Switchboard => Switchboard::read_price(account),
+        OracleKind::StakePool => StakePool::read_price(account),
+        OracleKind::None => err!(ErrorCode::OracleNotConfigured),
+    }
+}
+
+/// Context shared by every feed read while walking the hierarchy
+#[derive(Clone, Copy, Debug)]
+pub struct PriceRequest {
+    /// Time the price should be published at (expiration, or now)
+    pub reference_time: i64,
+    /// Seconds after `reference_time` a price may still be published beyond
+    /// the staleness window (settlement's fallback; 0 otherwise)
+    pub late_window: i64,
+    /// Previous accepted price in strike units (0 = no history)
+    pub previous_price: u64,
+    /// Publish time of `previous_price`
+    pub previous_update: i64,
+    /// Decimals of the series' strike, used to convert into strike units
+    pub strike_exponent: u8,
+}
+
+/// Applies `feed`'s guard (staleness, confidence, deviation) to `price`,
+/// already converted into `strike_units`
+fn check_guard(
+    feed: &OracleFeed,
+    price: &OraclePrice,
+    strike_units: u64,
+    request: &PriceRequest,
+) -> Result<()> {
+    feed.guard
+        .check_staleness(price, request.reference_time, request.late_window)?;
+    feed.guard.check_confidence(price)?;
+    feed.guard.check_deviation(
+        request.previous_price,
+        request.previous_update,
+        strike_units,
+        price.publish_time,
+    )
+}
+
+/// Reads `feed` and applies its guard (staleness, confidence, deviation);
+/// returns the accepted price converted into strike units
+pub fn read_guarded_price(
+    feed: &OracleFeed,
+    account: Option<&AccountInfo>,
+    request: &PriceRequest,
+) -> Result<(u64, OraclePrice)> {
+    let account = account.ok_or(ErrorCode::OracleUnavailable)?;
+    let price = read_price(feed, account)?;
+    let strike_units = price.to_strike_units(request.strike_exponent)?;
+    check_guard(feed, &price, strike_units, request)?;
+    Ok((strike_units, price))
+}
+
+/// Walks the series' oracle hierarchy: a configured primary feed must be
+/// passed and readable, and is used when it passes its guard; only a guard
+/// failure falls through to the secondary feed. Callers (settlement is
+/// permissionless) therefore can't skip the primary by leaving it out.
+pub fn resolve_price(
+    primary: &OracleFeed,
+    primary_account: Option<&AccountInfo>,
+    secondary: &OracleFeed,
+    secondary_account: Option<&AccountInfo>,
+    request: &PriceRequest,
+) -> Result<(u64, OraclePrice, SettlementSource)> {
+    if primary.is_configured() {
+        let account = primary_account.ok_or(ErrorCode::PrimaryOracleRequired)?;
+        let price = read_price(primary, account)?;
+        let strike_units = price.to_strike_units(request.strike_exponent)?;
+        match check_guard(primary, &price, strike_units, request) {
+            Ok(()) => return Ok((strike_units, price, SettlementSource::Primary)),
+            Err(e) => msg!("Primary oracle rejected: {}", e),
+        }
+    }
+
+    if secondary.is_configured() {
+        match read_guarded_price(secondary, secondary_account, request) {
+            Ok((value, price)) => return Ok((value, price, SettlementSource::Secondary)),
+            Err(e) => msg!("Secondary oracle rejected: {}", e),
+        }
+    }
+
+    err!(ErrorCode::OracleUnavailable)
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::oracle::{OraclePrice, PriceSource};
+
+/// Pyth Solana receiver program (owner of `PriceUpdateV2` accounts)
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of the receiver's `PriceUpdateV2` account
+pub const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Mirror of the receiver's `VerificationLevel` enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
+
+/// Mirror of the Pythnet `PriceFeedMessage`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PriceFeedMessage {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+}
+
+/// Mirror of the receiver's `PriceUpdateV2` account (after the discriminator)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PriceUpdateV2 {
+    pub write_authority: Pubkey,
+    pub verification_level: VerificationLevel,
+    pub price_message: PriceFeedMessage,
+    pub posted_slot: u64,
+}
+
+/// Pyth pull-oracle backend (`PriceUpdateV2` accounts)
+pub struct Pyth;
+
+/// Deserializes `PriceUpdateV2` account data, checking the discriminator
+pub fn decode_price_update(data: &[u8]) -> Result<PriceUpdateV2> {
+    require!(
+        data.len() > 8 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+        ErrorCode::InvalidOracleAccount
+    );
+
+    let mut payload: &[u8] = &data[8..];
+    PriceUpdateV2::deserialize(&mut payload).map_err(|_| error!(ErrorCode::InvalidOracleAccount))
+}
+
+impl PriceSource for Pyth {
+    const OWNER: Pubkey = PYTH_RECEIVER_PROGRAM_ID;
+
+    /// Only fully verified updates are accepted: a partially verified update
+    /// was checked against a subset of Wormhole guardian signatures and must
+    /// not settle a series.
+    fn decode(data: &[u8]) -> Result<OraclePrice> {
+        let update = decode_price_update(data)?;
+        require!(
+            update.verification_level == VerificationLevel::Full,
+            ErrorCode::UnverifiedOraclePrice
+        );
+        let message = update.price_message;
+
+        Ok(OraclePrice {
+            price: message.price,
+            conf: message.conf,
+            expo: message.exponent,
+            publish_time: message.publish_time,
+        })
+    }
+}
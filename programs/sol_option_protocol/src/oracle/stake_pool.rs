@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::oracle::{OraclePrice, PriceSource};
+
+/// SPL stake pool program (owner of the `StakePool` accounts behind jitoSOL,
+/// bSOL and the other SPL liquid staking tokens)
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// `AccountType::StakePool` tag in the first byte of the account
+pub const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+
+// Byte offsets into the Borsh-encoded `StakePool`: the account type, three
+// authorities, the withdraw bump seed, then five pubkeys (validator list,
+// reserve stake, pool mint, manager fee account, token program)
+pub const POOL_MINT_OFFSET: usize = 1 + 3 * 32 + 1 + 2 * 32;
+pub const TOTAL_LAMPORTS_OFFSET: usize = 1 + 3 * 32 + 1 + 5 * 32;
+pub const POOL_TOKEN_SUPPLY_OFFSET: usize = TOTAL_LAMPORTS_OFFSET + 8;
+pub const LAST_UPDATE_EPOCH_OFFSET: usize = POOL_TOKEN_SUPPLY_OFFSET + 8;
+pub const MIN_ACCOUNT_LEN: usize = LAST_UPDATE_EPOCH_OFFSET + 8;
+
+/// Lamports and pool tokens both have 9 decimals; the rate keeps 9 as well
+const RATE_DECIMALS: u32 = 9;
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// The fields of a `StakePool` the exchange rate is derived from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakePoolRate {
+    pub pool_mint: Pubkey,
+    pub total_lamports: u64,
+    pub pool_token_supply: u64,
+    /// Epoch the pool's balances were last brought up to date
+    pub last_update_epoch: u64,
+}
+
+impl StakePoolRate {
+    /// SOL per pool token as an `OraclePrice`; the rate only moves when the
+    /// pool is updated at an epoch boundary, so a pool updated in `epoch` is
+    /// current as of `now`, and one that missed its update is stale
+    pub fn to_price(&self, epoch: u64, now: i64) -> Result<OraclePrice> {
+        require!(self.last_update_epoch == epoch, ErrorCode::StaleOraclePrice);
+        require!(self.pool_token_supply > 0, ErrorCode::InvalidOraclePrice);
+
+        let rate = (self.total_lamports as u128)
+            .checked_mul(10_u128.pow(RATE_DECIMALS))
+            .ok_or(ErrorCode::MathOverflow)?
+            / self.pool_token_supply as u128;
+
+        Ok(OraclePrice {
+            price: i64::try_from(rate).map_err(|_| error!(ErrorCode::MathOverflow))?,
+            conf: 0,
+            expo: -(RATE_DECIMALS as i32),
+            publish_time: now,
+        })
+    }
+}
+
+/// Reads the exchange-rate fields of `StakePool` account data, checking the
+/// account type
+pub fn decode_stake_pool(data: &[u8]) -> Result<StakePoolRate> {
+    require!(
+        data.len() >= MIN_ACCOUNT_LEN && data[0] == STAKE_POOL_ACCOUNT_TYPE,
+        ErrorCode::InvalidOracleAccount
+    );
+
+    Ok(StakePoolRate {
+        pool_mint: Pubkey::try_from(&data[POOL_MINT_OFFSET..POOL_MINT_OFFSET + 32])
+            .map_err(|_| error!(ErrorCode::InvalidOracleAccount))?,
+        total_lamports: read_u64(data, TOTAL_LAMPORTS_OFFSET),
+        pool_token_supply: read_u64(data, POOL_TOKEN_SUPPLY_OFFSET),
+        last_update_epoch: read_u64(data, LAST_UPDATE_EPOCH_OFFSET),
+    })
+}
+
+/// SPL stake pool backend: prices a liquid staking token in SOL from its
+/// pool's exchange rate (series with the LST as collateral and wrapped SOL as
+/// consideration, strikes quoted in SOL)
+pub struct StakePool;
+
+impl PriceSource for StakePool {
+    const OWNER: Pubkey = SPL_STAKE_POOL_PROGRAM_ID;
+
+    fn decode(data: &[u8]) -> Result<OraclePrice> {
+        let clock = Clock::get()?;
+        decode_stake_pool(data)?.to_price(clock.epoch, clock.unix_timestamp)
+    }
+}
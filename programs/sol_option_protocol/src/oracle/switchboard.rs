@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::oracle::{OraclePrice, PriceSource};
+
+/// Switchboard on-demand program (owner of `PullFeedAccountData` accounts)
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey =
+    pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+/// Anchor discriminator of the `PullFeedAccountData` account
+pub const PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+// Byte offsets into `PullFeedAccountData` (zero-copy, repr(C)), measured
+// from the start of the account data including the 8-byte discriminator.
+// 32 submissions × 64 bytes, then authority/queue/feed_hash and config fields.
+pub const LAST_UPDATE_TIMESTAMP_OFFSET: usize = 8 + 2208;
+pub const RESULT_VALUE_OFFSET: usize = 8 + 2256;
+pub const RESULT_STD_DEV_OFFSET: usize = RESULT_VALUE_OFFSET + 16;
+pub const MIN_ACCOUNT_LEN: usize = RESULT_STD_DEV_OFFSET + 16;
+
+/// Switchboard values are fixed-point with 18 decimals; they are rescaled to
+/// 8 decimals so they fit in the i64 `OraclePrice` representation
+const SWITCHBOARD_DECIMALS: u32 = 18;
+const NORMALIZED_DECIMALS: u32 = 8;
+
+fn read_i128(data: &[u8], offset: usize) -> i128 {
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&data[offset..offset + 16]);
+    i128::from_le_bytes(bytes)
+}
+
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    i64::from_le_bytes(bytes)
+}
+
+/// Switchboard on-demand backend (`PullFeedAccountData` accounts)
+pub struct Switchboard;
+
+impl PriceSource for Switchboard {
+    const OWNER: Pubkey = SWITCHBOARD_ON_DEMAND_PROGRAM_ID;
+
+    /// Reads the current result of the pull feed
+    fn decode(data: &[u8]) -> Result<OraclePrice> {
+        require!(
+            data.len() >= MIN_ACCOUNT_LEN && data[..8] == PULL_FEED_DISCRIMINATOR,
+            ErrorCode::InvalidOracleAccount
+        );
+
+        let divisor = 10_i128.pow(SWITCHBOARD_DECIMALS - NORMALIZED_DECIMALS);
+        let value = read_i128(data, RESULT_VALUE_OFFSET) / divisor;
+        let std_dev = read_i128(data, RESULT_STD_DEV_OFFSET) / divisor;
+
+        Ok(OraclePrice {
+            price: i64::try_from(value).map_err(|_| error!(ErrorCode::MathOverflow))?,
+            conf: u64::try_from(std_dev).map_err(|_| error!(ErrorCode::InvalidOraclePrice))?,
+            expo: -(NORMALIZED_DECIMALS as i32),
+            publish_time: read_i64(data, LAST_UPDATE_TIMESTAMP_OFFSET),
+        })
+    }
+}
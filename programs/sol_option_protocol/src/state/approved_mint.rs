@@ -0,0 +1,38 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Governance approval of a mint for use as collateral or consideration
+/// (seeds = ["approved_mint", mint])
+///
+/// Only consulted while the protocol config's `mint_allowlist_enabled` is set.
+#[account]
+#[derive(InitSpace)]
+pub struct ApprovedMint {
+    /// Mint the approval applies to
+    pub mint: Pubkey,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Whether new series may use the mint (cleared to revoke)
+    pub approved: bool,
+}
+
+impl ApprovedMint {
+    pub const SEED: &'static [u8] = b"approved_mint";
+
+    /// Whether `account` holds a current approval; false when governance never
+    /// approved the mint (the PDA doesn't exist yet)
+    pub fn is_approved(account: &AccountInfo) -> Result<bool> {
+        if account.data_is_empty() {
+            return Ok(false);
+        }
+        require_keys_eq!(
+            *account.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let data = account.try_borrow_data()?;
+        Ok(ApprovedMint::try_deserialize(&mut &data[..])?.approved)
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Governance-defined substitutes for one escrow mint (seeds =
+/// ["collateral_basket", base_mint]), e.g. USDT for USDC-backed puts
+/// Series that opt in accept the substitutes at par, each in its own vault
+#[account]
+#[derive(InitSpace)]
+pub struct CollateralBasket {
+    /// Escrow mint the substitutes stand in for
+    pub base_mint: Pubkey,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Accepted substitutes (Pubkey::default() marks unused slots)
+    pub mints: [Pubkey; CollateralBasket::MAX_MINTS],
+}
+
+impl CollateralBasket {
+    pub const SEED: &'static [u8] = b"collateral_basket";
+
+    /// Seed of a series' vault for one substitute
+    /// (seeds = ["basket_vault", option_context, substitute_mint])
+    pub const VAULT_SEED: &'static [u8] = b"basket_vault";
+
+    /// Substitutes per basket, and per series
+    pub const MAX_MINTS: usize = 2;
+
+    /// Whether `mint` is currently accepted in place of the base mint
+    pub fn accepts(&self, mint: &Pubkey) -> bool {
+        *mint != Pubkey::default() && self.mints.contains(mint)
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Straddle/strangle basket: call and put options of one underlying and
+/// expiry locked 1:1 behind a single combo token
+/// (PDA, seeds = ["combo", call_series, put_series])
+///
+/// Each combo token is a claim on one call and one put held in the combo's
+/// escrows; `decompose_combo` burns it to get both legs back.
+#[account]
+#[derive(InitSpace)]
+pub struct Combo {
+    /// Call leg's series
+    pub call_series: Pubkey,
+
+    /// Put leg's series (strike at or below the call's)
+    pub put_series: Pubkey,
+
+    /// Combo token mint (PDA, seeds = ["combo_mint", combo]; combo is authority)
+    pub combo_mint: Pubkey,
+
+    /// Pairs locked in the escrows (equals the combo token supply)
+    pub locked: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Combo {
+    pub const SEED: &'static [u8] = b"combo";
+    pub const MINT_SEED: &'static [u8] = b"combo_mint";
+
+    /// Token accounts (owned by the combo) holding each leg's locked options
+    /// (seeds = ["combo_escrow", combo, leg option mint])
+    pub const ESCROW_SEED: &'static [u8] = b"combo_escrow";
+
+    /// Straddle when both legs share a strike, strangle otherwise
+    pub fn is_straddle(call_strike: u64, put_strike: u64) -> bool {
+        call_strike == put_strike
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::ProtocolConfig;
+
+/// Per-creator series creation tracking (seeds = ["creator_stats", creator])
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorStats {
+    /// Creator this account tracks
+    pub creator: Pubkey,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Epoch `series_this_epoch` counts for
+    pub epoch: u64,
+
+    /// Series created in `epoch`
+    pub series_this_epoch: u16,
+
+    /// Timestamp of the creator's latest series
+    pub last_created_at: i64,
+
+    /// Lifetime series count
+    pub total_series: u64,
+}
+
+impl CreatorStats {
+    pub const SEED: &'static [u8] = b"creator_stats";
+
+    /// Enforces the configured rate limits and records one more creation
+    pub fn record_creation(&mut self, config: &ProtocolConfig, epoch: u64, now: i64) -> Result<()> {
+        if self.epoch != epoch {
+            self.epoch = epoch;
+            self.series_this_epoch = 0;
+        }
+
+        if config.max_series_per_epoch > 0 {
+            require!(
+                self.series_this_epoch < config.max_series_per_epoch,
+                ErrorCode::CreationRateLimited
+            );
+        }
+
+        if config.creation_cooldown > 0 && self.total_series > 0 {
+            let ready_at = self
+                .last_created_at
+                .checked_add(config.creation_cooldown)
+                .ok_or(ErrorCode::MathOverflow)?;
+            require!(now >= ready_at, ErrorCode::CreationCooldownActive);
+        }
+
+        self.series_this_epoch = self
+            .series_this_epoch
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_series = self
+            .total_series
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.last_created_at = now;
+
+        Ok(())
+    }
+}
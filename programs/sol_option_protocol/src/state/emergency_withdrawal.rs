@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::OptionData;
+use crate::state::{CollateralBasket, YieldVenue};
+
+/// Queued last-resort withdrawal from a series vault
+/// (PDA, seeds = ["emergency_withdrawal", vault]; at most one per vault)
+#[account]
+#[derive(InitSpace)]
+pub struct EmergencyWithdrawal {
+    /// Series whose vault is being drained
+    pub option_context: Pubkey,
+
+    /// Vault of the series (see `WithdrawalVault`)
+    pub vault: Pubkey,
+
+    /// Token account receiving the funds (fixed at queue time)
+    pub destination: Pubkey,
+
+    /// Amount to withdraw, in vault token units
+    pub amount: u64,
+
+    /// Hash of the published off-chain justification
+    pub justification_hash: [u8; 32],
+
+    /// Time the withdrawal was queued
+    pub queued_at: i64,
+
+    /// Earliest time the withdrawal can be executed
+    pub executable_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl EmergencyWithdrawal {
+    pub const SEED: &'static [u8] = b"emergency_withdrawal";
+
+    /// Public delay between queueing and execution (14 days)
+    pub const TIMELOCK: i64 = 14 * 24 * 60 * 60;
+}
+
+/// Series vaults an emergency withdrawal can drain, by the ledger bucket
+/// each is accounted in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WithdrawalVault {
+    /// `collateral_deposited`/`collateral_paid`, plus margin for calls
+    Collateral,
+    /// `consideration_received`/`consideration_paid`, plus margin for puts
+    Consideration,
+    /// Substitute vault of basket slot `n`: `basket_escrow[n]`, part of the
+    /// escrow
+    Basket(usize),
+    /// Share vault of the yield venue: `yield_shares`, which carry
+    /// `yield_principal` of the escrow
+    YieldShares,
+}
+
+impl WithdrawalVault {
+    /// Which of `series`' vaults `vault` (holding `mint`) is; None for
+    /// accounts the series doesn't own
+    pub fn of(
+        series: &OptionData,
+        series_key: &Pubkey,
+        vault: &Pubkey,
+        mint: &Pubkey,
+    ) -> Option<Self> {
+        if *vault == series.collateral_vault {
+            return Some(Self::Collateral);
+        }
+        if *vault == series.consideration_vault {
+            return Some(Self::Consideration);
+        }
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID).0;
+        if let Some(slot) = series
+            .basket_mints
+            .iter()
+            .position(|m| *m != Pubkey::default() && m == mint)
+        {
+            if *vault
+                == pda(&[
+                    CollateralBasket::VAULT_SEED,
+                    series_key.as_ref(),
+                    mint.as_ref(),
+                ])
+            {
+                return Some(Self::Basket(slot));
+            }
+        }
+        if series.yield_venue != Pubkey::default()
+            && *vault == pda(&[YieldVenue::SHARE_VAULT_SEED, series_key.as_ref()])
+        {
+            return Some(Self::YieldShares);
+        }
+        None
+    }
+}
+
+/// How much of an `amount` withdrawn from a vault holding `held` comes out of
+/// untracked tokens (donations), the `tracked` bucket and `margin`, in that
+/// order; returns the tracked and margin parts
+pub fn split_withdrawal(amount: u64, held: u64, tracked: u64, margin: u64) -> (u64, u64) {
+    let untracked = held.saturating_sub(tracked).saturating_sub(margin);
+    let from_tracked = amount.saturating_sub(untracked).min(tracked);
+    let from_margin = amount
+        .saturating_sub(untracked)
+        .saturating_sub(from_tracked)
+        .min(margin);
+    (from_tracked, from_margin)
+}
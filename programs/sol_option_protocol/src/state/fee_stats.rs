@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// A user's trading volume in one quote mint
+/// (PDA, seeds = ["fee_stats", user, consideration_mint]; opt-in)
+///
+/// Mints and exercises that pass the account add their strike notional to it,
+/// and the protocol's fee tiers discount the user's fees by the volume
+/// recorded before each one.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeStats {
+    /// Account the volume belongs to (the signer of the recorded trades)
+    pub user: Pubkey,
+
+    /// Mint the volume is counted in (the consideration of the series traded)
+    pub consideration_mint: Pubkey,
+
+    /// Lifetime strike notional minted and exercised, in raw consideration units
+    pub volume: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl FeeStats {
+    pub const SEED: &'static [u8] = b"fee_stats";
+
+    /// Adds a trade's strike notional (saturating: a full counter stays at the top tier)
+    pub fn record(&mut self, notional: u64) {
+        self.volume = self.volume.saturating_add(notional);
+    }
+}
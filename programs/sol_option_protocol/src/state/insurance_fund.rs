@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Protocol insurance fund in one mint
+/// (PDA, seeds = ["insurance_fund", mint])
+///
+/// Mints and exercises send `insurance_share_bps` of the protocol's part of
+/// their fee to a token account of this PDA, and the admin pays it out to
+/// series vaults that hold less than their accounting says.
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceFund {
+    /// Mint the fund holds
+    pub mint: Pubkey,
+
+    /// Lifetime fee shares paid in
+    pub contributed: u64,
+
+    /// Lifetime payouts to series vaults
+    pub paid_out: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl InsuranceFund {
+    pub const SEED: &'static [u8] = b"insurance_fund";
+
+    pub fn record_contribution(&mut self, amount: u64) -> Result<()> {
+        self.contributed = self
+            .contributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.paid_out = self
+            .paid_out
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+pub mod approved_mint;
+pub mod collateral_basket;
+pub mod combo;
+pub mod creator_stats;
+pub mod emergency_withdrawal;
+pub mod fee_stats;
+pub mod insurance_fund;
+pub mod option_vault;
+pub mod pending_change;
+pub mod program_version;
+pub mod protocol_config;
+pub mod referral_stats;
+pub mod settlement_attestation;
+pub mod strike_tick;
+pub mod vertical_spread;
+pub mod writer_position;
+pub mod yield_venue;
+
+pub use approved_mint::*;
+pub use collateral_basket::*;
+pub use combo::*;
+pub use creator_stats::*;
+pub use emergency_withdrawal::*;
+pub use fee_stats::*;
+pub use insurance_fund::*;
+pub use option_vault::*;
+pub use pending_change::*;
+pub use program_version::*;
+pub use protocol_config::*;
+pub use referral_stats::*;
+pub use settlement_attestation::*;
+pub use strike_tick::*;
+pub use vertical_spread::*;
+pub use writer_position::*;
+pub use yield_venue::*;
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::instructions::OptionType;
+
+/// Pooled writing vault (DOV): depositors pool the escrow asset for shares and
+/// the vault writes one series per round, listing the options it mints on the
+/// marketplace (PDA, seeds = ["option_vault", manager, underlying_mint,
+/// quote_mint, option_type])
+///
+/// Covered-call vaults pool the underlying; cash-secured put vaults pool the
+/// quote mint, and hold the underlying their puts are assigned until the
+/// manager re-lists it.
+///
+/// Tokens are held by the vault authority, a system-owned PDA (seeds =
+/// ["vault_authority", option_vault]) that signs as the user of the round's
+/// option and marketplace instructions and pays the rent of their accounts
+/// (the manager keeps it funded). The vault's balances are its authority's
+/// associated accounts of the underlying and the quote mint.
+#[account]
+#[derive(InitSpace)]
+pub struct OptionVault {
+    /// Picks each round's series and ask price
+    pub manager: Pubkey,
+
+    /// Collateral mint of the series the vault writes
+    pub underlying_mint: Pubkey,
+
+    /// Consideration mint of the series the vault writes (premiums are paid in it)
+    pub quote_mint: Pubkey,
+
+    /// Kind of series written each round
+    pub option_type: OptionType,
+
+    /// Depositors' share token (seeds = ["vault_shares", option_vault])
+    pub share_mint: Pubkey,
+
+    /// Series of the open round; default() between rounds
+    pub series: Pubkey,
+
+    /// Marketplace ask of the open round's options
+    pub ask: Pubkey,
+
+    /// Rounds started so far
+    pub round: u64,
+
+    /// Options written in the open round
+    pub written: u64,
+
+    /// Marketplace ask of re-listed assigned underlying (put vaults);
+    /// default() when none is open
+    pub listing: Pubkey,
+
+    /// PDA bumps
+    pub bump: u8,
+    pub authority_bump: u8,
+}
+
+impl OptionVault {
+    pub const SEED: &'static [u8] = b"option_vault";
+    pub const AUTHORITY_SEED: &'static [u8] = b"vault_authority";
+    pub const SHARE_MINT_SEED: &'static [u8] = b"vault_shares";
+
+    /// Whether no round is in progress
+    pub fn is_idle(&self) -> bool {
+        self.series == Pubkey::default()
+    }
+
+    /// Whether part of the underlying sits in a re-listing ask
+    pub fn listing_open(&self) -> bool {
+        self.listing != Pubkey::default()
+    }
+
+    /// Mint depositors bring: what the written series escrow
+    pub fn escrow_mint(&self) -> Pubkey {
+        match self.option_type {
+            OptionType::Put => self.quote_mint,
+            _ => self.underlying_mint,
+        }
+    }
+
+    /// Shares minted for depositing `amount` of the escrow mint into a vault
+    /// holding `escrow_balance` of it against `supply` shares; the first
+    /// deposit mints one share per unit
+    pub fn shares_for_deposit(amount: u64, escrow_balance: u64, supply: u64) -> Result<u64> {
+        if supply == 0 {
+            return Ok(amount);
+        }
+        require!(escrow_balance > 0, ErrorCode::VaultHasNoEscrow);
+        let shares = amount as u128 * supply as u128 / escrow_balance as u128;
+        u64::try_from(shares).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+
+    /// Part of the vault's `other_balance` (the mint depositors don't bring)
+    /// a deposit of `amount` must match, so it buys into both balances at the
+    /// vault's own ratio; rounded up
+    pub fn matching_deposit(amount: u64, escrow_balance: u64, other_balance: u64) -> Result<u64> {
+        if escrow_balance == 0 {
+            return Ok(0);
+        }
+        let matching = (amount as u128 * other_balance as u128).div_ceil(escrow_balance as u128);
+        u64::try_from(matching).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+
+    /// What `shares` of `supply` withdraw from a `balance`; rounded down
+    pub fn share_of(shares: u64, supply: u64, balance: u64) -> Result<u64> {
+        require!(shares <= supply, ErrorCode::InsufficientVaultShares);
+        if supply == 0 {
+            return Ok(0);
+        }
+        Ok((shares as u128 * balance as u128 / supply as u128) as u64)
+    }
+}
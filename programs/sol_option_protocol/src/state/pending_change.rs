@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::FeeTier;
+use crate::utils::validation::{
+    validate_fee_bps, validate_fee_exempt, validate_fee_tiers, validate_insurance_share,
+    validate_referral_share, validate_timelock_delay,
+};
+
+/// Timelocked admin parameter change, mirroring the setter it replaces
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, PartialEq, Eq)]
+pub enum ParameterChange {
+    /// `set_fees`
+    Fees {
+        mint_fee_bps: u16,
+        exercise_fee_bps: u16,
+    },
+    /// `set_fee_tiers`
+    FeeTiers {
+        #[max_len(4)]
+        tiers: Vec<FeeTier>,
+    },
+    /// `set_fee_exempt`
+    FeeExempt {
+        #[max_len(4)]
+        accounts: Vec<Pubkey>,
+    },
+    /// `set_referral_share`
+    ReferralShare { referral_share_bps: u16 },
+    /// `set_approved_mint`
+    ApprovedMint { mint: Pubkey, approved: bool },
+    /// `set_mint_allowlist`
+    MintAllowlist { enabled: bool },
+    /// `set_timelock_delay`, including shortening it
+    TimelockDelay { timelock_delay: i64 },
+    /// `set_insurance_share`
+    InsuranceShare { insurance_share_bps: u16 },
+    /// `set_creation_bond`
+    CreationBond { creation_bond: u64 },
+    /// `update_rate_limits`
+    RateLimits {
+        max_series_per_epoch: u16,
+        creation_cooldown: i64,
+    },
+}
+
+impl ParameterChange {
+    /// Runs the setter's own checks, so invalid changes fail when scheduled
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Fees {
+                mint_fee_bps,
+                exercise_fee_bps,
+            } => {
+                validate_fee_bps(*mint_fee_bps)?;
+                validate_fee_bps(*exercise_fee_bps)
+            }
+            Self::FeeTiers { tiers } => validate_fee_tiers(tiers),
+            Self::FeeExempt { accounts } => validate_fee_exempt(accounts),
+            Self::ReferralShare { referral_share_bps } => {
+                validate_referral_share(*referral_share_bps)
+            }
+            Self::ApprovedMint { mint, .. } => {
+                require!(*mint != Pubkey::default(), ErrorCode::InvalidPendingChange);
+                Ok(())
+            }
+            Self::MintAllowlist { .. } => Ok(()),
+            Self::TimelockDelay { timelock_delay } => validate_timelock_delay(*timelock_delay),
+            Self::InsuranceShare {
+                insurance_share_bps,
+            } => validate_insurance_share(*insurance_share_bps),
+            Self::CreationBond { .. } => Ok(()),
+            Self::RateLimits {
+                creation_cooldown, ..
+            } => {
+                require!(*creation_cooldown >= 0, ErrorCode::InvalidConfigValue);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Admin change waiting out the protocol's timelock
+/// (PDA, seeds = ["pending_change", id (u64 LE)])
+#[account]
+#[derive(InitSpace)]
+pub struct PendingChange {
+    /// Admin-chosen identifier (lets proposals derive the address up front)
+    pub id: u64,
+
+    /// Change applied by `apply_change`
+    pub change: ParameterChange,
+
+    /// Time the change was scheduled
+    pub scheduled_at: i64,
+
+    /// Earliest time the change can be applied
+    pub executable_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingChange {
+    pub const SEED: &'static [u8] = b"pending_change";
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_VERSION_LEN: usize = 16;
+
+/// Last version announced by this deployment (singleton PDA, seeds = ["program_version"])
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramVersion {
+    /// PDA bump
+    pub bump: u8,
+
+    /// Slot of the latest announcement
+    pub announced_slot: u64,
+
+    /// Hash of the ProtocolConfig account data at announcement time
+    pub config_hash: [u8; 32],
+
+    /// Semantic version of the latest announcement (empty before the first)
+    #[max_len(MAX_VERSION_LEN)]
+    pub version: String,
+}
+
+impl ProgramVersion {
+    pub const SEED: &'static [u8] = b"program_version";
+}
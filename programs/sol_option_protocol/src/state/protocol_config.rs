@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+
+/// Global protocol configuration (singleton PDA, seeds = ["protocol_config"])
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    /// Admin allowed to update protocol parameters
+    pub admin: Pubkey,
+
+    /// Proposed successor admin (Pubkey::default() when none; two-step transfer)
+    pub pending_admin: Pubkey,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Max series a single creator may create per Solana epoch (0 = unlimited)
+    pub max_series_per_epoch: u16,
+
+    /// Minimum seconds between two series created by the same creator (0 = none)
+    pub creation_cooldown: i64,
+
+    /// Guardian allowed to cancel queued emergency withdrawals and pause the protocol
+    /// or a series (Pubkey::default() when unset)
+    pub guardian: Pubkey,
+
+    /// Owner of the token accounts receiving swept series funds (Pubkey::default() when unset)
+    pub treasury: Pubkey,
+
+    /// Seconds after expiration before a series' unclaimed funds can be recovered (0 = disabled)
+    pub unclaimed_timeout: i64,
+
+    /// Lamports a creator locks in each new series, refunded when it is closed (0 = none)
+    pub creation_bond: u64,
+
+    /// Spacing of the expiry grid in seconds; expirations must fall on
+    /// `expiry_grid_offset + k * expiry_grid_interval` (0 = any expiration)
+    pub expiry_grid_interval: i64,
+
+    /// First grid point, in seconds since the Unix epoch (e.g. a Friday 08:00 UTC)
+    pub expiry_grid_offset: i64,
+
+    /// Creators allowed to list off-grid expirations (Pubkey::default() marks unused slots)
+    pub expiry_grid_exempt: [Pubkey; ProtocolConfig::MAX_EXPIRY_GRID_EXEMPT],
+
+    /// Shortest time to expiration a new series may have, in seconds (0 = none)
+    pub min_time_to_expiry: i64,
+
+    /// Longest time to expiration a new series may have, in seconds (0 = none)
+    pub max_time_to_expiry: i64,
+
+    /// Fee on minting, in basis points of the escrow locked (0 = none)
+    pub mint_fee_bps: u16,
+
+    /// Fee on exercise, in basis points of the strike paid (0 = none)
+    pub exercise_fee_bps: u16,
+
+    /// Features the admin has switched off (`FEATURE_*` bits; 0 = everything on)
+    pub disabled_features: u32,
+
+    /// Accounts (e.g. market makers) charged no mint or exercise fee
+    /// (Pubkey::default() marks unused slots)
+    pub fee_exempt: [Pubkey; ProtocolConfig::MAX_FEE_EXEMPT],
+
+    /// Volume discounts, by increasing `min_volume` (zeroed tiers are unused)
+    pub fee_tiers: [FeeTier; ProtocolConfig::MAX_FEE_TIERS],
+
+    /// Share of each fee paid to the referrer passed with it, in basis points (0 = none)
+    pub referral_share_bps: u16,
+
+    /// Protocol-wide pause set by the admin or guardian: blocks mint and
+    /// exercise in every series, never burn or redeem
+    pub paused: bool,
+
+    /// Whether new series need both mints approved by governance (an
+    /// `ApprovedMint` PDA); false keeps creation permissionless
+    pub mint_allowlist_enabled: bool,
+
+    /// Seconds a scheduled fee or allowlist change waits before it can be
+    /// applied (0 = changes apply immediately through the setters)
+    pub timelock_delay: i64,
+
+    /// Share of the protocol's part of each fee paid into the mint's
+    /// insurance fund, in basis points (0 = none)
+    pub insurance_share_bps: u16,
+
+    /// Risky Token-2022 mint extensions (`MINT_EXTENSION_*` bits) new series
+    /// may still use as collateral or consideration (0 = all refused)
+    pub allowed_mint_extensions: u32,
+}
+
+/// Fee discount earned by a user's recorded volume
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default,
+)]
+pub struct FeeTier {
+    /// Volume needed, in whole consideration tokens (e.g. 1_000_000 = $1M of USDC)
+    pub min_volume: u64,
+
+    /// Share of the fee waived, in basis points (10_000 = no fee)
+    pub discount_bps: u16,
+}
+
+impl ProtocolConfig {
+    pub const SEED: &'static [u8] = b"protocol_config";
+
+    /// Shortest allowed recovery timeout (1 year), so holders always have time to claim
+    pub const MIN_UNCLAIMED_TIMEOUT: i64 = 365 * 24 * 60 * 60;
+
+    /// Slots in the expiry grid allowlist
+    pub const MAX_EXPIRY_GRID_EXEMPT: usize = 4;
+
+    /// Highest mint or exercise fee (1%)
+    pub const MAX_FEE_BPS: u16 = 100;
+
+    /// Slots in the fee exemption allowlist
+    pub const MAX_FEE_EXEMPT: usize = 4;
+
+    /// Volume tiers
+    pub const MAX_FEE_TIERS: usize = 4;
+
+    /// Longest timelock on parameter changes (30 days)
+    pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+
+    /// Perpetual series (no expiry, funding-paying longs)
+    pub const FEATURE_PERPETUALS: u32 = 1 << 0;
+    /// Series written on margin
+    pub const FEATURE_MARGIN: u32 = 1 << 1;
+    /// Forward series
+    pub const FEATURE_FORWARDS: u32 = 1 << 2;
+    /// Binary (cash-or-nothing) series
+    pub const FEATURE_BINARIES: u32 = 1 << 3;
+    /// Knock-out barriers
+    pub const FEATURE_KNOCKOUTS: u32 = 1 << 4;
+    /// Substitute escrow through collateral baskets
+    pub const FEATURE_COLLATERAL_BASKETS: u32 = 1 << 5;
+    /// Every feature switch
+    pub const ALL_FEATURES: u32 = (1 << 6) - 1;
+
+    /// Non-transferable mints: vault deposits and payouts can never move
+    pub const MINT_EXTENSION_NON_TRANSFERABLE: u32 = 1 << 0;
+    /// Mints whose new accounts start frozen: the series vaults would be
+    /// unusable until the mint's freeze authority thaws them
+    pub const MINT_EXTENSION_FROZEN_BY_DEFAULT: u32 = 1 << 1;
+    /// Mints with a permanent delegate, who can move tokens out of the vaults
+    pub const MINT_EXTENSION_PERMANENT_DELEGATE: u32 = 1 << 2;
+    /// Every risky mint extension
+    pub const ALL_MINT_EXTENSIONS: u32 = (1 << 3) - 1;
+
+    /// Whether the admin leaves `feature` (a `FEATURE_*` bit) switched on
+    pub fn is_enabled(&self, feature: u32) -> bool {
+        self.disabled_features & feature == 0
+    }
+
+    /// Whether fee and allowlist changes must be scheduled with `schedule_change`
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock_delay > 0
+    }
+
+    /// Whether `user` trades without protocol fees
+    pub fn is_fee_exempt(&self, user: &Pubkey) -> bool {
+        *user != Pubkey::default() && self.fee_exempt.contains(user)
+    }
+
+    /// Discount of the highest tier `volume` (raw units of a mint with
+    /// `decimals`) reaches; 0 below the first tier
+    pub fn fee_discount_bps(&self, volume: u64, decimals: u8) -> u16 {
+        let unit = 10u128.pow(decimals as u32);
+        self.fee_tiers
+            .iter()
+            .filter(|tier| tier.min_volume > 0)
+            .take_while(|tier| (tier.min_volume as u128) * unit <= volume as u128)
+            .last()
+            .map_or(0, |tier| tier.discount_bps)
+    }
+
+    /// Whether `creator` may list expirations off the grid
+    pub fn is_expiry_grid_exempt(&self, creator: &Pubkey) -> bool {
+        *creator != Pubkey::default() && self.expiry_grid_exempt.contains(creator)
+    }
+}
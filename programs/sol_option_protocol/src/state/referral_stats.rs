@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// What a referrer (an integrator, say) has earned in one mint
+/// (PDA, seeds = ["referral_stats", referrer, mint])
+///
+/// Mints and exercises that pass a referrer's token account and stats send
+/// the protocol's referral share of their fee to the referrer.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralStats {
+    /// Owner of the token accounts that receive the referral share
+    pub referrer: Pubkey,
+
+    /// Mint the share is paid in
+    pub mint: Pubkey,
+
+    /// Lifetime referral share received
+    pub earned: u64,
+
+    /// Fees the share was paid out of
+    pub referred_fees: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReferralStats {
+    pub const SEED: &'static [u8] = b"referral_stats";
+
+    /// Adds one paid share (saturating: stats never block a fee)
+    pub fn record(&mut self, share: u64) {
+        self.earned = self.earned.saturating_add(share);
+        self.referred_fees = self.referred_fees.saturating_add(1);
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::{OptionData, OptionType};
+
+/// Settlement result of a series, kept for relayers and cross-chain mirrors
+/// (PDA, seeds = ["settlement_attestation", option_context])
+///
+/// Written once by `attest_settlement` after the series settles. The payout
+/// ratios are kept as their numerators and denominator: each redemption token
+/// is owed `settled_collateral / settled_supply` collateral and
+/// `settled_consideration / settled_supply` consideration, and each option
+/// token `net_option_collateral / settled_option_supply` collateral and
+/// `net_option_consideration / settled_option_supply` consideration.
+#[account]
+#[derive(InitSpace)]
+pub struct SettlementAttestation {
+    /// Series attested
+    pub option_context: Pubkey,
+
+    /// Series terms, so consumers needn't read the series itself
+    pub collateral_mint: Pubkey,
+    pub consideration_mint: Pubkey,
+    pub strike_price: u64,
+    pub expiration: i64,
+    pub option_type: OptionType,
+
+    /// Settlement price in strike units (0 when settled without oracles)
+    pub settlement_price: u64,
+    pub settled_at: i64,
+
+    /// Redemption token payouts
+    pub settled_collateral: u64,
+    pub settled_consideration: u64,
+    pub settled_supply: u64,
+
+    /// Option token payouts (ITM netting)
+    pub settled_option_supply: u64,
+    pub net_option_collateral: u64,
+    pub net_option_consideration: u64,
+
+    /// Whether the attestation was also posted as a Wormhole message
+    pub posted: bool,
+
+    /// Wormhole sequence of that message (0 when not posted)
+    pub wormhole_sequence: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SettlementAttestation {
+    pub const SEED: &'static [u8] = b"settlement_attestation";
+
+    /// First byte of the Wormhole payload, identifying its layout
+    pub const PAYLOAD_ID: u8 = 1;
+
+    /// Attestation of a settled series
+    pub fn of(option_context: Pubkey, series: &OptionData, bump: u8) -> Self {
+        Self {
+            option_context,
+            collateral_mint: series.collateral_mint,
+            consideration_mint: series.consideration_mint,
+            strike_price: series.strike_price,
+            expiration: series.expiration,
+            option_type: series.option_type,
+            settlement_price: series.settlement_price,
+            settled_at: series.settled_at,
+            settled_collateral: series.settled_collateral,
+            settled_consideration: series.settled_consideration,
+            settled_supply: series.settled_supply,
+            settled_option_supply: series.settled_option_supply,
+            net_option_collateral: series.net_option_collateral,
+            net_option_consideration: series.net_option_consideration,
+            posted: false,
+            wormhole_sequence: 0,
+            bump,
+        }
+    }
+
+    /// Wormhole payload: `PAYLOAD_ID`, then every field up to the netted
+    /// option payouts in declaration order, integers big-endian as EVM
+    /// contracts decode them (178 bytes)
+    pub fn payload(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(178);
+        payload.push(Self::PAYLOAD_ID);
+        payload.extend_from_slice(self.option_context.as_ref());
+        payload.extend_from_slice(self.collateral_mint.as_ref());
+        payload.extend_from_slice(self.consideration_mint.as_ref());
+        payload.extend_from_slice(&self.strike_price.to_be_bytes());
+        payload.extend_from_slice(&self.expiration.to_be_bytes());
+        payload.push(self.option_type as u8);
+        payload.extend_from_slice(&self.settlement_price.to_be_bytes());
+        payload.extend_from_slice(&self.settled_at.to_be_bytes());
+        for amount in [
+            self.settled_collateral,
+            self.settled_consideration,
+            self.settled_supply,
+            self.settled_option_supply,
+            self.net_option_collateral,
+            self.net_option_consideration,
+        ] {
+            payload.extend_from_slice(&amount.to_be_bytes());
+        }
+        payload
+    }
+}
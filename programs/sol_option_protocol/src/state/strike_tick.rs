@@ -0,0 +1,37 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Governance-set strike increment for one consideration mint
+/// (seeds = ["strike_tick", consideration_mint])
+#[account]
+#[derive(InitSpace)]
+pub struct StrikeTick {
+    /// Consideration mint the tick applies to
+    pub consideration_mint: Pubkey,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Strikes must be multiples of this many raw consideration units per
+    /// whole collateral token (0 = any strike)
+    pub tick_size: u64,
+}
+
+impl StrikeTick {
+    pub const SEED: &'static [u8] = b"strike_tick";
+
+    /// Tick size stored in `account`, or 0 when governance never set one
+    /// (the PDA doesn't exist yet)
+    pub fn tick_size_of(account: &AccountInfo) -> Result<u64> {
+        if account.data_is_empty() {
+            return Ok(0);
+        }
+        require_keys_eq!(
+            *account.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let data = account.try_borrow_data()?;
+        Ok(StrikeTick::try_deserialize(&mut &data[..])?.tick_size)
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+/// Tokenized vertical spread between two series of the same mints and expiry
+/// (PDA, seeds = ["vertical_spread", short_series, long_series])
+///
+/// Writers lock long options of the further-OTM `long_series` plus the
+/// spread's max loss and receive spread tokens, each paying the intrinsic
+/// value of one `short_series` option at expiry. The locked longs cover
+/// everything beyond the strike difference, so writers never post the short
+/// leg's full backing. All amounts are in the asset writers of the short
+/// series escrow (collateral for calls, consideration for puts).
+#[account]
+#[derive(InitSpace)]
+pub struct VerticalSpread {
+    /// Series whose intrinsic value spread tokens pay
+    pub short_series: Pubkey,
+
+    /// Further out-of-the-money series whose options writers lock
+    pub long_series: Pubkey,
+
+    /// Spread token mint (PDA, seeds = ["vertical_spread_mint", vertical_spread])
+    pub spread_mint: Pubkey,
+
+    /// Spread tokens written and not burned back by writers; after
+    /// settlement, what writers that haven't redeemed wrote
+    pub written: u64,
+
+    /// Max-loss backing deposited by writers (until settlement)
+    pub backing: u64,
+
+    /// Settlement timestamp (0 until `settle_vertical_spread`)
+    pub settled_at: i64,
+
+    /// Long series' settlement price, in strike units
+    pub settlement_price: u64,
+
+    /// Still owed to spread token holders after settlement
+    pub holder_payout: u64,
+
+    /// Still owed to writers after settlement
+    pub writer_payout: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VerticalSpread {
+    pub const SEED: &'static [u8] = b"vertical_spread";
+    pub const MINT_SEED: &'static [u8] = b"vertical_spread_mint";
+
+    /// Token account (owned by the spread) holding locked long options
+    pub const ESCROW_SEED: &'static [u8] = b"vertical_spread_escrow";
+
+    /// Token account (owned by the spread) holding backing and, after
+    /// settlement, the locked longs' payoff
+    pub const VAULT_SEED: &'static [u8] = b"vertical_spread_vault";
+
+    pub fn is_settled(&self) -> bool {
+        self.settled_at != 0
+    }
+}
+
+/// What one writer has written into a vertical spread
+/// (PDA, seeds = ["vertical_spread_position", vertical_spread, writer])
+///
+/// Spread tokens are fungible across writers, so the position is what entitles
+/// a writer to burn tokens for their backing or to their share of what's left
+/// after settlement.
+#[account]
+#[derive(InitSpace)]
+pub struct VerticalSpreadPosition {
+    /// Spread the position belongs to
+    pub vertical_spread: Pubkey,
+
+    /// Writer
+    pub writer: Pubkey,
+
+    /// Spread tokens written and not burned back
+    pub written: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VerticalSpreadPosition {
+    pub const SEED: &'static [u8] = b"vertical_spread_position";
+}
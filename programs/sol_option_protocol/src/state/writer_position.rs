@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+/// Amount one writer has outstanding in a series
+/// (PDA, seeds = ["writer_position", option_context, writer]; opt-in)
+///
+/// Redemption tokens are fungible and can change hands, so they can't say who
+/// wrote what. Writers that open a position get their mints and paired burns
+/// recorded here when they pass it to `mint` / `burn`.
+///
+/// The position is also the writer's margin account: options written on
+/// margin are backed only by `margin_collateral`, never by the redemption pool.
+#[account]
+#[derive(InitSpace)]
+pub struct WriterPosition {
+    /// Series the position belongs to
+    pub option_context: Pubkey,
+
+    /// Writer (the signer of the recorded mints)
+    pub writer: Pubkey,
+
+    /// Options written and not yet burned back
+    pub outstanding: u64,
+
+    /// Lifetime amount written through this position
+    pub total_written: u64,
+
+    /// Options written on margin (long leg only) and not yet bought back
+    pub margin_written: u64,
+
+    /// Margin held for `margin_written`, in the asset that backs the series
+    /// (collateral for calls, consideration for puts)
+    pub margin_collateral: u64,
+
+    /// Series whose long options hedge the margin short (default() until the
+    /// first `create_spread`; shares mints and expiry with this series)
+    pub spread_series: Pubkey,
+
+    /// Strike of `spread_series`
+    pub spread_strike: u64,
+
+    /// Long options of `spread_series` locked in the position's spread escrow
+    pub spread_locked: u64,
+
+    /// Health factor in bps at the last margin operation
+    /// (margin over the maintenance requirement; below 10_000 is liquidatable)
+    pub health_bps: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl WriterPosition {
+    pub const SEED: &'static [u8] = b"writer_position";
+
+    /// Token account (owned by the position) holding locked spread longs
+    pub const SPREAD_ESCROW_SEED: &'static [u8] = b"spread_escrow";
+
+    pub fn record_mint(&mut self, amount: u64) -> Result<()> {
+        self.outstanding = self
+            .outstanding
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_written = self
+            .total_written
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Paired burns of bought-back or acquired pairs only reduce the position
+    /// down to zero
+    pub fn record_burn(&mut self, amount: u64) {
+        self.outstanding = self.outstanding.saturating_sub(amount);
+    }
+}
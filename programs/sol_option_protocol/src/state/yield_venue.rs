@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Governance-approved lending reserve for one escrow mint (seeds =
+/// ["yield_venue", escrow_mint]), e.g. a Solend-style USDC reserve
+/// Series that opt in lend idle escrow to it and hold its share tokens
+#[account]
+#[derive(InitSpace)]
+pub struct YieldVenue {
+    /// Escrow mint the reserve lends out
+    pub escrow_mint: Pubkey,
+
+    /// Token-lending program of the reserve
+    pub lending_program: Pubkey,
+
+    /// Lending market the reserve belongs to, and its derived authority
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+
+    /// Reserve account, its liquidity supply and its collateral (share) mint
+    pub reserve: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub share_mint: Pubkey,
+
+    /// Whether series may opt in and lend; unwinding is always open
+    pub enabled: bool,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl YieldVenue {
+    pub const SEED: &'static [u8] = b"yield_venue";
+
+    /// Seed of a series' share token vault (seeds = ["yield_shares", option_context])
+    pub const SHARE_VAULT_SEED: &'static [u8] = b"yield_shares";
+
+    /// Whether the reserve addresses match `other`'s (the enabled flag aside)
+    pub fn same_reserve(&self, other: &YieldVenue) -> bool {
+        self.lending_program == other.lending_program
+            && self.lending_market == other.lending_market
+            && self.lending_market_authority == other.lending_market_authority
+            && self.reserve == other.reserve
+            && self.reserve_liquidity_supply == other.reserve_liquidity_supply
+            && self.share_mint == other.share_mint
+    }
+}
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::oracle::guard::BPS_DENOMINATOR;
+use crate::utils::math::{calculate_pro_rata_share_u128, calculate_put_collateral};
+use crate::utils::netting::intrinsic_value;
+
+/// Full backing for `amount` options in the asset that backs them: the
+/// collateral itself for calls, the strike value (rounded up) for puts
+pub fn full_backing(amount: u64, strike_price: u64, strike_scale: u8, is_put: bool) -> Result<u64> {
+    if is_put {
+        calculate_put_collateral(amount, strike_price, strike_scale)
+    } else {
+        Ok(amount)
+    }
+}
+
+/// Margin a position short `amount` options must hold at `price`: the
+/// options' intrinsic value plus `margin_bps` of their full backing, never
+/// more than the full backing itself
+///
+/// Deep out-of-the-money positions only need the buffer; as the price moves
+/// through the strike the requirement grows toward full collateralization.
+pub fn margin_requirement(
+    amount: u64,
+    strike_price: u64,
+    price: u64,
+    strike_scale: u8,
+    is_put: bool,
+    margin_bps: u16,
+) -> Result<u64> {
+    let backing = full_backing(amount, strike_price, strike_scale, is_put)?;
+    let intrinsic = intrinsic_value(amount, strike_price, price, strike_scale, is_put)?;
+    let buffer = (backing as u128)
+        .checked_mul(margin_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .div_ceil(BPS_DENOMINATOR as u128);
+    let buffer = u64::try_from(buffer).map_err(|_| error!(ErrorCode::MathOverflow))?;
+
+    Ok(intrinsic.saturating_add(buffer).min(backing))
+}
+
+/// Most `amount` spreads can lose at expiry, in the asset that backs the short
+/// leg (rounded up); fails unless the long leg is further out of the money
+/// - Call spread (long strike above short): `amount * (K_long - K_short) / K_long`
+///   collateral, reached when the price settles at the long strike
+/// - Put spread (long strike below short): the strike payment for `amount` at
+///   `K_short - K_long`, in consideration
+pub fn spread_max_loss(
+    amount: u64,
+    short_strike: u64,
+    long_strike: u64,
+    strike_scale: u8,
+    is_put: bool,
+) -> Result<u64> {
+    if is_put {
+        require!(long_strike < short_strike, ErrorCode::InvalidSpread);
+        calculate_put_collateral(amount, short_strike - long_strike, strike_scale)
+    } else {
+        require!(long_strike > short_strike, ErrorCode::InvalidSpread);
+        let loss = (amount as u128)
+            .checked_mul((long_strike - short_strike) as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            .div_ceil(long_strike as u128);
+        u64::try_from(loss).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+}
+
+/// Margin requirement of a position short `amount` options, `hedged` of which
+/// are covered by long options struck at `long_strike` (a spread): the hedged
+/// part needs at most its max loss, the rest the plain requirement
+#[allow(clippy::too_many_arguments)]
+pub fn spread_requirement(
+    amount: u64,
+    hedged: u64,
+    short_strike: u64,
+    long_strike: u64,
+    price: u64,
+    strike_scale: u8,
+    is_put: bool,
+    margin_bps: u16,
+) -> Result<u64> {
+    let hedged = hedged.min(amount);
+    let naked = margin_requirement(
+        amount - hedged,
+        short_strike,
+        price,
+        strike_scale,
+        is_put,
+        margin_bps,
+    )?;
+    if hedged == 0 {
+        return Ok(naked);
+    }
+    let covered = margin_requirement(
+        hedged,
+        short_strike,
+        price,
+        strike_scale,
+        is_put,
+        margin_bps,
+    )?
+    .min(spread_max_loss(
+        hedged,
+        short_strike,
+        long_strike,
+        strike_scale,
+        is_put,
+    )?);
+
+    naked
+        .checked_add(covered)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+/// Health factor in basis points: held margin over the requirement
+/// (10_000 = exactly at the requirement; `u64::MAX` when nothing is required)
+pub fn health_factor_bps(margin: u64, requirement: u64) -> Result<u64> {
+    if requirement == 0 {
+        return Ok(u64::MAX);
+    }
+    let health = (margin as u128)
+        .checked_mul(BPS_DENOMINATOR as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / requirement as u128;
+    Ok(u64::try_from(health).unwrap_or(u64::MAX))
+}
+
+/// Margin a liquidator receives for buying back `amount` of a position short
+/// `written` options: the pro-rata share of the position's margin, so the
+/// rest of the position keeps its health and no debt is left behind
+pub fn liquidation_seizure(margin: u64, amount: u64, written: u64) -> Result<u64> {
+    require!(amount <= written, ErrorCode::InvalidAmount);
+    calculate_pro_rata_share_u128(margin, amount, written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOL: u64 = 1_000_000_000;
+    const USDC: u64 = 1_000_000;
+    const STRIKE: u64 = 100 * USDC;
+
+    #[test]
+    fn out_of_the_money_positions_only_need_the_buffer() {
+        let requirement = margin_requirement(10 * SOL, STRIKE, 80 * USDC, 9, false, 2_000).unwrap();
+        assert_eq!(requirement, 2 * SOL);
+
+        let requirement = margin_requirement(10 * SOL, STRIKE, 120 * USDC, 9, true, 2_000).unwrap();
+        assert_eq!(requirement, 200 * USDC);
+    }
+
+    #[test]
+    fn the_requirement_never_exceeds_full_backing() {
+        let requirement =
+            margin_requirement(10 * SOL, STRIKE, 1_000 * USDC, 9, false, 2_000).unwrap();
+        assert_eq!(requirement, 10 * SOL);
+
+        let requirement = margin_requirement(10 * SOL, STRIKE, USDC, 9, true, 2_000).unwrap();
+        assert_eq!(requirement, 1_000 * USDC);
+    }
+
+    #[test]
+    fn put_backing_rounds_up() {
+        assert_eq!(full_backing(1, STRIKE, 9, true).unwrap(), 1);
+        assert_eq!(full_backing(SOL, STRIKE, 9, true).unwrap(), STRIKE);
+        assert_eq!(full_backing(SOL, STRIKE, 9, false).unwrap(), SOL);
+    }
+
+    #[test]
+    fn spread_max_loss_needs_the_long_leg_further_out() {
+        // 10 × (125 - 100) / 125
+        let loss = spread_max_loss(10 * SOL, STRIKE, 125 * USDC, 9, false).unwrap();
+        assert_eq!(loss, 2 * SOL);
+        let loss = spread_max_loss(10 * SOL, STRIKE, 80 * USDC, 9, true).unwrap();
+        assert_eq!(loss, 200 * USDC);
+
+        for (long, is_put) in [(80 * USDC, false), (125 * USDC, true), (STRIKE, false)] {
+            let err = spread_max_loss(SOL, STRIKE, long, 9, is_put).unwrap_err();
+            assert_eq!(err, ErrorCode::InvalidSpread.into());
+        }
+    }
+
+    #[test]
+    fn hedged_options_need_at_most_their_max_loss() {
+        let price = 1_000 * USDC;
+        let unhedged =
+            spread_requirement(10 * SOL, 0, STRIKE, 125 * USDC, price, 9, false, 2_000).unwrap();
+        assert_eq!(unhedged, 10 * SOL);
+
+        // 6 naked options at full backing, 4 covered at 4 × 25 / 125
+        let hedged = spread_requirement(
+            10 * SOL,
+            4 * SOL,
+            STRIKE,
+            125 * USDC,
+            price,
+            9,
+            false,
+            2_000,
+        )
+        .unwrap();
+        assert_eq!(hedged, 6 * SOL + 4 * SOL / 5);
+
+        let over_hedged = spread_requirement(
+            10 * SOL,
+            20 * SOL,
+            STRIKE,
+            125 * USDC,
+            price,
+            9,
+            false,
+            2_000,
+        )
+        .unwrap();
+        assert_eq!(over_hedged, 2 * SOL);
+    }
+
+    #[test]
+    fn health_is_margin_over_the_requirement() {
+        assert_eq!(health_factor_bps(150, 100).unwrap(), 15_000);
+        assert_eq!(health_factor_bps(99, 100).unwrap(), 9_900);
+        assert_eq!(health_factor_bps(0, 0).unwrap(), u64::MAX);
+        assert_eq!(health_factor_bps(u64::MAX, 1).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn liquidators_seize_a_pro_rata_share() {
+        assert_eq!(liquidation_seizure(10, 3, 4).unwrap(), 7);
+        assert_eq!(liquidation_seizure(10, 4, 4).unwrap(), 10);
+        let err = liquidation_seizure(10, 5, 4).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidAmount.into());
+    }
+}
@@ -1,5 +1,5 @@
-use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
 
 /// Calculates pro-rata share using the formula:
 /// payout = (vault_balance × user_amount) / total_supply
@@ -51,20 +51,78 @@ pub fn calculate_pro_rata_share_u128(
 }
 
 /// Calculates strike payment required for exercising options
-/// Formula: (amount × strike_price) / 10^collateral_decimals
+/// Formula: ceil((amount × strike_price) / 10^strike_scale)
+///
+/// `strike_scale` is the series' `OptionData::strike_scale`: the collateral
+/// decimals for strikes quoted in raw consideration units. The product is
+/// taken in u128, and the payment rounds UP so exercising a few raw units
+/// never takes collateral for nothing.
 ///
 /// Example: 100 BONK × $0.04 strike = $4 USDC
 /// (100_000 raw × 4_000_000) / 10^5 = 4_000_000 raw USDC ($4)
-pub fn calculate_strike_payment(
-    amount: u64,
+pub fn calculate_strike_payment(amount: u64, strike_price: u64, strike_scale: u8) -> Result<u64> {
+    let payment = strike_product(amount, strike_price)?.div_ceil(strike_denominator(strike_scale)?);
+
+    u64::try_from(payment).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Strike value the protocol pays out or records for `amount` collateral
+/// units (put exercises, burns, claims and payoffs), rounded DOWN so split
+/// payouts never exceed what was paid or locked
+/// Formula: (amount × strike_price) / 10^strike_scale
+pub fn calculate_strike_value(amount: u64, strike_price: u64, strike_scale: u8) -> Result<u64> {
+    let value = strike_product(amount, strike_price)? / strike_denominator(strike_scale)?;
+
+    u64::try_from(value).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Consideration a put writer locks for `amount` options: the strike value,
+/// rounded UP so the vault always covers exercises paid with
+/// `calculate_strike_value` (which rounds down), however they are split
+pub fn calculate_put_collateral(amount: u64, strike_price: u64, strike_scale: u8) -> Result<u64> {
+    calculate_strike_payment(amount, strike_price, strike_scale)
+}
+
+fn strike_product(amount: u64, strike_price: u64) -> Result<u128> {
+    (amount as u128)
+        .checked_mul(strike_price as u128)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+fn strike_denominator(strike_scale: u8) -> Result<u128> {
+    10_u128
+        .checked_pow(strike_scale as u32)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+/// Seconds in the (365-day) year funding rates are quoted over
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// Funding owed per whole collateral unit after `elapsed` seconds at
+/// `funding_rate_bps` a year, in strike units (rounded down)
+/// Formula: strike_price × funding_rate_bps × elapsed / (10_000 × year)
+pub fn calculate_funding_index(
     strike_price: u64,
-    collateral_decimals: u8,
+    funding_rate_bps: u32,
+    elapsed: i64,
 ) -> Result<u64> {
-    let payment = amount
-        .checked_mul(strike_price)
+    let elapsed = u128::try_from(elapsed.max(0)).map_err(|_| error!(ErrorCode::MathOverflow))?;
+    let index = (strike_price as u128)
+        .checked_mul(funding_rate_bps as u128)
+        .and_then(|value| value.checked_mul(elapsed))
         .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10_u64.pow(collateral_decimals as u32))
-        .ok_or(ErrorCode::MathOverflow)?;
+        / (10_000 * SECONDS_PER_YEAR as u128);
+
+    u64::try_from(index).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Protocol fee of `fee_bps` on `amount` (rounded down, so dust is never charged)
+/// Formula: amount × fee_bps / 10_000
+pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / 10_000;
 
-    Ok(payment)
+    u64::try_from(fee).map_err(|_| error!(ErrorCode::MathOverflow))
 }
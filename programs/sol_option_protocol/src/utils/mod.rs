@@ -1,7 +1,13 @@
-pub mod pda;
+pub mod margin;
 pub mod math;
+pub mod netting;
+pub mod pda;
+pub mod symbol;
 pub mod validation;
 
-pub use pda::*;
+pub use margin::*;
 pub use math::*;
+pub use netting::*;
+pub use pda::*;
+pub use symbol::*;
 pub use validation::*;
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::utils::math::calculate_strike_value;
+
+/// Aggregate split of the settled vaults between option and redemption holders
+///
+/// Unexercised in-the-money options are netted against the redemption side at
+/// the settlement price: instead of every holder paying the strike and taking
+/// delivery, longs receive only their intrinsic value and shorts keep the rest.
+/// - Call: longs receive `(S - K) / S` collateral per option
+/// - Put: longs receive `(K - S)` in consideration per option
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetSettlement {
+    pub in_the_money: bool,
+    /// Collateral reserved for all outstanding option tokens
+    pub option_collateral: u64,
+    /// Consideration reserved for all outstanding option tokens
+    pub option_consideration: u64,
+    /// Collateral left for all redemption tokens
+    pub redemption_collateral: u64,
+    /// Consideration left for all redemption tokens
+    pub redemption_consideration: u64,
+}
+
+/// Nets `outstanding_options` against the vaults at `settlement_price`
+/// Reservations round down (dust stays with redemption holders) and are
+/// capped at the vault balance
+pub fn calculate_net_settlement(
+    collateral: u64,
+    consideration: u64,
+    outstanding_options: u64,
+    strike_price: u64,
+    settlement_price: u64,
+    strike_scale: u8,
+    is_put: bool,
+) -> Result<NetSettlement> {
+    let in_the_money = finishes_in_the_money(strike_price, settlement_price, is_put);
+
+    let owed = intrinsic_value(
+        outstanding_options,
+        strike_price,
+        settlement_price,
+        strike_scale,
+        is_put,
+    )?;
+    let (option_collateral, option_consideration) = if is_put {
+        (0, owed.min(consideration))
+    } else {
+        (owed.min(collateral), 0)
+    };
+
+    Ok(NetSettlement {
+        in_the_money,
+        option_collateral,
+        option_consideration,
+        redemption_collateral: collateral - option_collateral,
+        redemption_consideration: consideration - option_consideration,
+    })
+}
+
+/// Splits the vaults of a binary (cash-or-nothing) series: in the money,
+/// every outstanding option is owed the fixed payout in consideration
+/// (rounded down and capped at the vault); otherwise shorts keep everything
+pub fn calculate_binary_settlement(
+    collateral: u64,
+    consideration: u64,
+    outstanding_options: u64,
+    in_the_money: bool,
+    binary_payout: u64,
+    strike_scale: u8,
+) -> Result<NetSettlement> {
+    let option_consideration = if in_the_money {
+        calculate_strike_value(outstanding_options, binary_payout, strike_scale)?.min(consideration)
+    } else {
+        0
+    };
+
+    Ok(NetSettlement {
+        in_the_money,
+        option_collateral: 0,
+        option_consideration,
+        redemption_collateral: collateral,
+        redemption_consideration: consideration - option_consideration,
+    })
+}
+
+/// Splits the vaults of a forward: delivery is mandatory, so option holders
+/// take all the collateral and redemption holders all the strike paid for it
+pub fn calculate_forward_settlement(collateral: u64, consideration: u64) -> NetSettlement {
+    NetSettlement {
+        in_the_money: true,
+        option_collateral: collateral,
+        option_consideration: 0,
+        redemption_collateral: 0,
+        redemption_consideration: consideration,
+    }
+}
+
+/// Whether a series finishes in the money at `price`: strictly above the
+/// strike for calls, strictly below it (and above zero) for puts
+pub fn finishes_in_the_money(strike_price: u64, price: u64, is_put: bool) -> bool {
+    if is_put {
+        price > 0 && price < strike_price
+    } else {
+        price > strike_price
+    }
+}
+
+/// Intrinsic value of `amount` options at `price`, in the asset that backs
+/// them (rounded down; zero when out of the money)
+/// - Call: `amount * (S - K) / S` collateral
+/// - Put: the strike payment for `amount` at `K - S`, in consideration
+pub fn intrinsic_value(
+    amount: u64,
+    strike_price: u64,
+    price: u64,
+    strike_scale: u8,
+    is_put: bool,
+) -> Result<u64> {
+    if is_put {
+        if price == 0 || price >= strike_price {
+            return Ok(0);
+        }
+        calculate_strike_value(amount, strike_price - price, strike_scale)
+    } else {
+        if price <= strike_price {
+            return Ok(0);
+        }
+        let value = (amount as u128)
+            .checked_mul((price - strike_price) as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(price as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        u64::try_from(value).map_err(|_| error!(ErrorCode::MathOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOL: u64 = 1_000_000_000;
+    const USDC: u64 = 1_000_000;
+
+    #[test]
+    fn itm_calls_reserve_their_intrinsic_collateral() {
+        let net = calculate_net_settlement(10 * SOL, 0, 4 * SOL, 100 * USDC, 125 * USDC, 9, false)
+            .unwrap();
+        assert!(net.in_the_money);
+        // 4 options × (125 - 100) / 125
+        assert_eq!(net.option_collateral, 4 * SOL / 5);
+        assert_eq!(net.redemption_collateral, 10 * SOL - 4 * SOL / 5);
+        assert_eq!(
+            (net.option_consideration, net.redemption_consideration),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn itm_puts_reserve_their_intrinsic_consideration() {
+        let net = calculate_net_settlement(0, 400 * USDC, 4 * SOL, 100 * USDC, 75 * USDC, 9, true)
+            .unwrap();
+        assert!(net.in_the_money);
+        assert_eq!(net.option_consideration, 100 * USDC);
+        assert_eq!(net.redemption_consideration, 300 * USDC);
+        assert_eq!(net.option_collateral, 0);
+    }
+
+    #[test]
+    fn reservations_are_capped_at_the_vault() {
+        let net = calculate_net_settlement(SOL / 2, 0, 4 * SOL, 100 * USDC, 200 * USDC, 9, false)
+            .unwrap();
+        assert_eq!(net.option_collateral, SOL / 2);
+        assert_eq!(net.redemption_collateral, 0);
+    }
+
+    #[test]
+    fn the_strike_and_a_zero_put_price_are_out_of_the_money() {
+        assert!(!finishes_in_the_money(100, 100, false));
+        assert!(!finishes_in_the_money(100, 100, true));
+        assert!(!finishes_in_the_money(100, 0, true));
+        assert!(finishes_in_the_money(100, 101, false));
+        assert!(finishes_in_the_money(100, 99, true));
+
+        assert_eq!(intrinsic_value(SOL, 100, 90, 9, false).unwrap(), 0);
+        assert_eq!(intrinsic_value(SOL, 100, 0, 9, true).unwrap(), 0);
+        let net = calculate_net_settlement(SOL, 0, SOL, 100, 100, 9, false).unwrap();
+        assert_eq!(net.redemption_collateral, SOL);
+    }
+
+    #[test]
+    fn binaries_pay_the_fixed_payout_only_in_the_money() {
+        let net = calculate_binary_settlement(0, 50 * USDC, 3 * SOL, true, 10 * USDC, 9).unwrap();
+        assert_eq!(net.option_consideration, 30 * USDC);
+        assert_eq!(net.redemption_consideration, 20 * USDC);
+
+        let net = calculate_binary_settlement(0, 20 * USDC, 3 * SOL, true, 10 * USDC, 9).unwrap();
+        assert_eq!(net.option_consideration, 20 * USDC);
+
+        let net = calculate_binary_settlement(0, 50 * USDC, 3 * SOL, false, 10 * USDC, 9).unwrap();
+        assert_eq!(net.option_consideration, 0);
+        assert_eq!(net.redemption_consideration, 50 * USDC);
+    }
+
+    #[test]
+    fn forwards_deliver_everything() {
+        let net = calculate_forward_settlement(2 * SOL, 200 * USDC);
+        assert_eq!(
+            (net.option_collateral, net.option_consideration),
+            (2 * SOL, 0)
+        );
+        assert_eq!(
+            (net.redemption_collateral, net.redemption_consideration),
+            (0, 200 * USDC)
+        );
+    }
+}
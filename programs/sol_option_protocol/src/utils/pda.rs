@@ -1,4 +1,7 @@
-use crate::instructions::OptionContext;
+use anchor_lang::prelude::*;
+
+use crate::instructions::{OptionContext, OptionType};
+use crate::state::{SettlementAttestation, YieldVenue};
 
 /// Generates PDA signer seeds for the OptionContext account
 /// This is used whenever the program needs to sign on behalf of the OptionSeries
@@ -12,7 +15,7 @@ pub fn get_option_context_signer_seeds<'a>(
     consideration_mint_bytes: &'a [u8; 32],
     strike_price_bytes: &'a [u8; 8],
     expiration_bytes: &'a [u8; 8],
-    is_put_bytes: &'a [u8; 1],
+    option_type_bytes: &'a [u8; 1],
     bump_bytes: &'a [u8; 1],
 ) -> [&'a [u8]; 7] {
     [
@@ -21,7 +24,96 @@ pub fn get_option_context_signer_seeds<'a>(
         consideration_mint_bytes,
         strike_price_bytes,
         expiration_bytes,
-        is_put_bytes,
+        option_type_bytes,
         bump_bytes,
     ]
 }
+
+// Address derivation for clients and CPI callers. These mirror the seeds the
+// account constraints check, read no sysvars, and return the canonical bump
+// alongside each address.
+
+/// Series PDA of the given terms
+pub fn derive_option_context(
+    collateral_mint: &Pubkey,
+    consideration_mint: &Pubkey,
+    strike_price: u64,
+    expiration: i64,
+    option_type: OptionType,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"option_context",
+            collateral_mint.as_ref(),
+            consideration_mint.as_ref(),
+            strike_price.to_le_bytes().as_ref(),
+            expiration.to_le_bytes().as_ref(),
+            &[option_type as u8],
+        ],
+        &crate::ID,
+    )
+}
+
+/// Option token mint of a series
+pub fn derive_option_mint(option_context: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"option_mint", option_context.as_ref()], &crate::ID)
+}
+
+/// Redemption token mint of a series
+pub fn derive_redemption_mint(option_context: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"redemption_mint", option_context.as_ref()], &crate::ID)
+}
+
+/// Collateral and consideration vaults of a series, in that order
+pub fn derive_vaults(option_context: &Pubkey) -> (Pubkey, Pubkey) {
+    let vault = |prefix: &[u8]| {
+        Pubkey::find_program_address(&[prefix, option_context.as_ref()], &crate::ID).0
+    };
+    (vault(b"collateral_vault"), vault(b"consideration_vault"))
+}
+
+/// Marketplace market of a base/quote pair, under `marketplace_program`
+pub fn derive_market(
+    marketplace_program: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"market", base_mint.as_ref(), quote_mint.as_ref()],
+        marketplace_program,
+    )
+}
+
+/// The `order_id`th order placed on a marketplace market
+pub fn derive_order(marketplace_program: &Pubkey, market: &Pubkey, order_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"order", market.as_ref(), order_id.to_le_bytes().as_ref()],
+        marketplace_program,
+    )
+}
+
+/// Escrow token account of a marketplace order
+pub fn derive_escrow(marketplace_program: &Pubkey, order: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow", order.as_ref()], marketplace_program)
+}
+
+/// Lending venue governance approved for an escrow mint
+pub fn derive_yield_venue(escrow_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[YieldVenue::SEED, escrow_mint.as_ref()], &crate::ID)
+}
+
+/// Account holding a series' venue shares
+pub fn derive_share_vault(option_context: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[YieldVenue::SHARE_VAULT_SEED, option_context.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Settlement attestation of a series
+pub fn derive_settlement_attestation(option_context: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SettlementAttestation::SEED, option_context.as_ref()],
+        &crate::ID,
+    )
+}
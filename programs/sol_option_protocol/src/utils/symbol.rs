@@ -0,0 +1,128 @@
+//! Canonical option symbols, e.g. "SOL-27JUN25-150-C"
+//!
+//! Format: `{UNDERLYING}-{DDMONYY}-{STRIKE}-{TYPE}`
+//! - UNDERLYING: ticker stored on the series, upper-cased
+//! - DDMONYY: UTC expiration date, or PERP for perpetual series
+//! - STRIKE: human-readable strike with trailing zeros trimmed (0.04, 150, 2.5)
+//! - TYPE: C/P for calls and puts, BC/BP for binaries, F for forwards
+//!
+//! Every venue formatting the same series parameters gets the same string.
+//! Nothing here touches accounts or syscalls, so clients linking the crate
+//! (`no-entrypoint`) name series exactly like the program does.
+
+use crate::instructions::{OptionData, OptionType, PERPETUAL_EXPIRATION};
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Converts a unix timestamp into a UTC (year, month, day) civil date
+/// (Howard Hinnant's days-from-civil inverse; valid for the full i64 day range we use)
+pub fn civil_date_from_timestamp(timestamp: i64) -> (i64, u32, u32) {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Formats an expiration timestamp as DDMONYY (e.g. 27JUN25)
+pub fn format_expiry(expiration: i64) -> String {
+    let (year, month, day) = civil_date_from_timestamp(expiration);
+    format!(
+        "{:02}{}{:02}",
+        day,
+        MONTHS[(month - 1) as usize],
+        year.rem_euclid(100)
+    )
+}
+
+/// Formats a raw strike (consideration base units) as a decimal with
+/// trailing zeros trimmed: 150_000_000 @ 6 decimals → "150", 40_000 → "0.04"
+pub fn format_strike(strike_price: u64, strike_decimals: u8) -> String {
+    let scale = 10_u128.pow(strike_decimals as u32);
+    let whole = strike_price as u128 / scale;
+    let fraction = strike_price as u128 % scale;
+
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{:0width$}", fraction, width = strike_decimals as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// Type code closing a symbol: C/P for calls and puts, BC/BP for binaries,
+/// F for forwards
+pub fn type_code(option_type: OptionType) -> &'static str {
+    match option_type {
+        OptionType::Call => "C",
+        OptionType::Put => "P",
+        OptionType::BinaryCall => "BC",
+        OptionType::BinaryPut => "BP",
+        OptionType::Forward => "F",
+    }
+}
+
+fn format_symbol(
+    underlying: &str,
+    strike_price: u64,
+    strike_decimals: u8,
+    expiration: i64,
+    code: &str,
+) -> String {
+    format!(
+        "{}-{}-{}-{}",
+        underlying.to_uppercase(),
+        if expiration == PERPETUAL_EXPIRATION {
+            "PERP".to_string()
+        } else {
+            format_expiry(expiration)
+        },
+        format_strike(strike_price, strike_decimals),
+        code
+    )
+}
+
+/// Builds the canonical symbol of a call or put from its parameters
+pub fn format_option_symbol(
+    underlying: &str,
+    strike_price: u64,
+    strike_decimals: u8,
+    expiration: i64,
+    is_put: bool,
+) -> String {
+    let option_type = if is_put {
+        OptionType::Put
+    } else {
+        OptionType::Call
+    };
+    format_symbol(
+        underlying,
+        strike_price,
+        strike_decimals,
+        expiration,
+        type_code(option_type),
+    )
+}
+
+/// Canonical descriptor of a stored series: its ticker, strike at the strike
+/// exponent, expiry and type code (e.g. "BONK-28FEB25-0.04-C"); token
+/// metadata, `describe_series` and the creation log all use it
+pub fn series_symbol(option_context: &OptionData) -> String {
+    format_symbol(
+        &option_context.underlying_symbol,
+        option_context.strike_price,
+        option_context.strike_exponent,
+        option_context.expiration,
+        type_code(option_context.option_type),
+    )
+}
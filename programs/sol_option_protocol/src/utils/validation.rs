@@ -1,5 +1,21 @@
-use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
+use crate::instructions::{
+    ExerciseStyle, OptionData, OptionType, MAX_EXERCISE_CUTOFF, MAX_EXERCISE_WINDOW,
+    MAX_FUNDING_RATE_BPS, MAX_METADATA_URI_LEN, MAX_SETTLEMENT_DELAY, MAX_UNDERLYING_SYMBOL_LEN,
+    PERPETUAL_EXPIRATION,
+};
+use crate::oracle::guard::BPS_DENOMINATOR;
+use crate::oracle::OracleFeed;
+use crate::state::{CollateralBasket, FeeTier, ProtocolConfig};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    default_account_state::DefaultAccountState, non_transferable::NonTransferable,
+    permanent_delegate::PermanentDelegate, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{
+    Account as TokenState, AccountState, Mint as MintState,
+};
+use anchor_spl::token_interface::TokenAccount;
 
 /// Validates that an amount is greater than zero
 pub fn validate_amount(amount: u64) -> Result<()> {
@@ -7,10 +23,127 @@ pub fn validate_amount(amount: u64) -> Result<()> {
     Ok(())
 }
 
-/// Validates that expiration is in the future
-pub fn validate_expiration(expiration: i64) -> Result<()> {
+/// Validates the contract sizing: a minimum mint must be a whole number of lots
+pub fn validate_lot_config(lot_size: u64, min_mint_amount: u64) -> Result<()> {
+    if lot_size > 0 {
+        require!(
+            min_mint_amount.is_multiple_of(lot_size),
+            ErrorCode::InvalidLotConfig
+        );
+    }
+    Ok(())
+}
+
+/// Maximum exponent of the divisor used by strike math
+pub const MAX_STRIKE_SCALE: u8 = 18;
+
+/// Validates the strike exponent against the series' mints and returns the
+/// strike scale: collateral decimals + strike exponent - consideration decimals
+///
+/// A negative scale would make one unit of strike worth less than one raw
+/// consideration token per raw collateral token, which the math can't express.
+pub fn validate_strike_exponent(
+    strike_exponent: u8,
+    collateral_decimals: u8,
+    consideration_decimals: u8,
+) -> Result<u8> {
+    let scale =
+        (collateral_decimals as i16) + (strike_exponent as i16) - (consideration_decimals as i16);
+    require!(
+        (0..=MAX_STRIKE_SCALE as i16).contains(&scale),
+        ErrorCode::InvalidStrikeExponent
+    );
+    Ok(scale as u8)
+}
+
+/// Validates that the strike is a whole number of ticks; `tick_size` is in raw
+/// consideration units per whole collateral token (0 allows any strike)
+pub fn validate_strike_tick(
+    strike_price: u64,
+    strike_exponent: u8,
+    consideration_decimals: u8,
+    tick_size: u64,
+) -> Result<()> {
+    if tick_size == 0 {
+        return Ok(());
+    }
+    // Compare both in whichever of the two units is finer
+    let on_tick = if strike_exponent >= consideration_decimals {
+        let scale = 10_u128
+            .checked_pow((strike_exponent - consideration_decimals) as u32)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let tick = (tick_size as u128)
+            .checked_mul(scale)
+            .ok_or(ErrorCode::MathOverflow)?;
+        (strike_price as u128).is_multiple_of(tick)
+    } else {
+        let scale = 10_u128
+            .checked_pow((consideration_decimals - strike_exponent) as u32)
+            .ok_or(ErrorCode::MathOverflow)?;
+        (strike_price as u128)
+            .checked_mul(scale)
+            .ok_or(ErrorCode::MathOverflow)?
+            .is_multiple_of(tick_size as u128)
+    };
+    require!(on_tick, ErrorCode::StrikeOffTick);
+    Ok(())
+}
+
+/// Validates that an amount is a whole number of the series' lots
+pub fn validate_lot_multiple(option_context: &OptionData, amount: u64) -> Result<()> {
+    if option_context.lot_size > 0 {
+        require!(
+            amount.is_multiple_of(option_context.lot_size),
+            ErrorCode::AmountNotLotMultiple
+        );
+    }
+    Ok(())
+}
+
+/// Validates that a mint writes at least the series' minimum amount
+pub fn validate_min_mint_amount(option_context: &OptionData, amount: u64) -> Result<()> {
+    require!(
+        amount >= option_context.min_mint_amount,
+        ErrorCode::BelowMinMintAmount
+    );
+    Ok(())
+}
+
+/// Validates that expiration is in the future and within the protocol's
+/// time-to-expiry limits (each disabled when zero)
+pub fn validate_expiration(config: &ProtocolConfig, expiration: i64) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
     require!(expiration > current_time, ErrorCode::ExpirationInPast);
+
+    let time_to_expiry = expiration - current_time;
+    require!(
+        time_to_expiry >= config.min_time_to_expiry,
+        ErrorCode::ExpirationTooSoon
+    );
+    require!(
+        config.max_time_to_expiry == 0 || time_to_expiry <= config.max_time_to_expiry,
+        ErrorCode::ExpirationTooFar
+    );
+    Ok(())
+}
+
+/// Validates that the expiration falls on the protocol's expiry grid, unless
+/// the grid is disabled or `creator` is on its allowlist
+pub fn validate_expiry_grid(
+    config: &ProtocolConfig,
+    creator: &Pubkey,
+    expiration: i64,
+) -> Result<()> {
+    if config.expiry_grid_interval == 0 || config.is_expiry_grid_exempt(creator) {
+        return Ok(());
+    }
+    let since_offset = expiration
+        .checked_sub(config.expiry_grid_offset)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        since_offset >= 0 && since_offset % config.expiry_grid_interval == 0,
+        ErrorCode::ExpirationOffGrid
+    );
     Ok(())
 }
 
@@ -34,8 +167,626 @@ pub fn validate_expired(expiration: i64) -> Result<()> {
     Ok(())
 }
 
+/// Validates the exercise window and cutoff against the style: European
+/// series need a bounded window, American series may set a bounded cutoff
+pub fn validate_exercise_style(style: ExerciseStyle, window: u32, cutoff: u32) -> Result<()> {
+    match style {
+        ExerciseStyle::American => require!(
+            window == 0 && cutoff <= MAX_EXERCISE_CUTOFF,
+            ErrorCode::InvalidExerciseWindow
+        ),
+        ExerciseStyle::European => require!(
+            window > 0 && window <= MAX_EXERCISE_WINDOW && cutoff == 0,
+            ErrorCode::InvalidExerciseWindow
+        ),
+    }
+    Ok(())
+}
+
+/// Validates that the series' exercise style permits exercising now
+/// American: before `expiration - exercise_cutoff`; European: inside the window
+/// Binary series are never exercised (they settle in cash), nor forwards
+/// (they deliver at expiry), knocked-out series or legacy puts
+pub fn validate_exercise_allowed(option_context: &OptionData) -> Result<()> {
+    require!(!option_context.is_binary(), ErrorCode::CashSettledOnly);
+    require!(
+        !option_context.is_forward(),
+        ErrorCode::ForwardSettlementOnly
+    );
+    validate_not_knocked_out(option_context)?;
+    validate_not_frozen(option_context)?;
+    validate_not_legacy_put(option_context)?;
+    let current_time = Clock::get()?.unix_timestamp;
+    match option_context.exercise_style {
+        ExerciseStyle::American => {
+            require!(
+                current_time < option_context.expiration,
+                ErrorCode::OptionExpired
+            );
+            let cutoff = option_context
+                .expiration
+                .checked_sub(option_context.exercise_cutoff as i64)
+                .ok_or(ErrorCode::MathOverflow)?;
+            require!(current_time < cutoff, ErrorCode::ExerciseCutoffPassed);
+        }
+        ExerciseStyle::European => require!(
+            current_time >= option_context.expiration
+                && current_time < option_context.exercise_window_end()?,
+            ErrorCode::OutsideExerciseWindow
+        ),
+    }
+    Ok(())
+}
+
+/// Validates that no more exercises can happen: after expiry, and for
+/// European series only once the exercise window has closed
+pub fn validate_exercise_closed(option_context: &OptionData) -> Result<()> {
+    // Knocked-out and frozen series can't be exercised anymore, expired or not
+    if option_context.is_knocked_out() || option_context.is_frozen() {
+        return Ok(());
+    }
+    validate_expired(option_context.expiration)?;
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= option_context.exercise_window_end()?,
+        ErrorCode::ExerciseWindowOpen
+    );
+    Ok(())
+}
+
+/// Validates the grace period between the end of exercise and redemption
+pub fn validate_settlement_delay(settlement_delay: u32) -> Result<()> {
+    require!(
+        settlement_delay <= MAX_SETTLEMENT_DELAY,
+        ErrorCode::InvalidSettlementDelay
+    );
+    Ok(())
+}
+
+/// Validates that settle_expiry has snapshotted the series (for redemption)
+pub fn validate_settled(option_context: &OptionData) -> Result<()> {
+    require!(option_context.is_settled(), ErrorCode::SeriesNotSettled);
+    Ok(())
+}
+
+/// Validates that the series' settlement grace period has elapsed
+pub fn validate_redemption_open(option_context: &OptionData) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= option_context.redemption_opens_at()?,
+        ErrorCode::RedemptionNotOpen
+    );
+    Ok(())
+}
+
+/// Validates that the series is not frozen by settlement (for operations
+/// that move vault balances outside of redemption)
+pub fn validate_not_settled(option_context: &OptionData) -> Result<()> {
+    require!(
+        !option_context.is_settled(),
+        ErrorCode::SeriesAlreadySettled
+    );
+    Ok(())
+}
+
+/// Validates that the series is not paused (for exposure-increasing operations)
+pub fn validate_not_paused(option_context: &OptionData) -> Result<()> {
+    require!(!option_context.is_paused(), ErrorCode::SeriesPaused);
+    Ok(())
+}
+
+/// Validates that the protocol is not paused (for exposure-increasing operations)
+pub fn validate_protocol_not_paused(config: &ProtocolConfig) -> Result<()> {
+    require!(!config.paused, ErrorCode::ProtocolPaused);
+    Ok(())
+}
+
+/// Validates that both mints of a new series are approved, when the protocol
+/// restricts series to allowlisted mints
+pub fn validate_mints_approved(
+    config: &ProtocolConfig,
+    collateral_approved: bool,
+    consideration_approved: bool,
+) -> Result<()> {
+    if config.mint_allowlist_enabled {
+        require!(
+            collateral_approved && consideration_approved,
+            ErrorCode::MintNotApproved
+        );
+    }
+    Ok(())
+}
+
+/// Risky extensions (`ProtocolConfig::MINT_EXTENSION_*` bits) of a Token-2022
+/// mint's account data; a default account state other than frozen and a
+/// permanent delegate slot left empty are harmless
+pub fn risky_mint_extensions(mint_data: &[u8]) -> Result<u32> {
+    let mint = StateWithExtensions::<MintState>::unpack(mint_data)?;
+    let mut extensions = 0;
+    if mint.get_extension::<NonTransferable>().is_ok() {
+        extensions |= ProtocolConfig::MINT_EXTENSION_NON_TRANSFERABLE;
+    }
+    if let Ok(default_state) = mint.get_extension::<DefaultAccountState>() {
+        if default_state.state == AccountState::Frozen as u8 {
+            extensions |= ProtocolConfig::MINT_EXTENSION_FROZEN_BY_DEFAULT;
+        }
+    }
+    if let Ok(delegate) = mint.get_extension::<PermanentDelegate>() {
+        if Option::<Pubkey>::from(delegate.delegate).is_some() {
+            extensions |= ProtocolConfig::MINT_EXTENSION_PERMANENT_DELEGATE;
+        }
+    }
+    Ok(extensions)
+}
+
+/// Validates a set of allowed mint extensions: only known `MINT_EXTENSION_*` bits
+pub fn validate_mint_extension_flags(allowed_mint_extensions: u32) -> Result<()> {
+    require!(
+        allowed_mint_extensions & !ProtocolConfig::ALL_MINT_EXTENSIONS == 0,
+        ErrorCode::InvalidMintExtensions
+    );
+    Ok(())
+}
+
+/// Validates that a new series' mint carries no risky extension the protocol
+/// config doesn't allow (`extensions` from `risky_mint_extensions`)
+pub fn validate_mint_extensions(config: &ProtocolConfig, extensions: u32) -> Result<()> {
+    require!(
+        extensions & !config.allowed_mint_extensions == 0,
+        ErrorCode::UnsupportedMintExtension
+    );
+    Ok(())
+}
+
+/// Validates that the admin may change a timelocked parameter directly
+pub fn validate_not_timelocked(config: &ProtocolConfig) -> Result<()> {
+    require!(!config.is_timelocked(), ErrorCode::TimelockRequired);
+    Ok(())
+}
+
+/// Validates a timelock delay: 0 (none) up to `ProtocolConfig::MAX_TIMELOCK_DELAY`
+pub fn validate_timelock_delay(timelock_delay: i64) -> Result<()> {
+    require!(
+        (0..=ProtocolConfig::MAX_TIMELOCK_DELAY).contains(&timelock_delay),
+        ErrorCode::InvalidTimelockDelay
+    );
+    Ok(())
+}
+
 /// Validates sufficient vault balance for a transfer
 pub fn validate_vault_balance(vault_balance: u64, required: u64) -> Result<()> {
     require!(vault_balance >= required, ErrorCode::InsufficientCollateral);
     Ok(())
 }
+
+/// Validates the oracle hierarchy: a fallback requires a primary feed,
+/// and the fallback must be a different feed
+pub fn validate_oracle_config(primary: &OracleFeed, secondary: &OracleFeed) -> Result<()> {
+    primary.guard.validate()?;
+    secondary.guard.validate()?;
+    if secondary.is_configured() {
+        require!(primary.is_configured(), ErrorCode::InvalidOracleConfig);
+        require_keys_neq!(primary.feed, secondary.feed, ErrorCode::InvalidOracleConfig);
+    }
+    Ok(())
+}
+
+/// Validates the margin parameters: both zero (margin disabled), or a
+/// maintenance buffer below the initial one on a series with a price feed
+pub fn validate_margin_config(
+    initial_margin_bps: u16,
+    maintenance_margin_bps: u16,
+    primary: &OracleFeed,
+) -> Result<()> {
+    if initial_margin_bps == 0 && maintenance_margin_bps == 0 {
+        return Ok(());
+    }
+    require!(
+        maintenance_margin_bps > 0
+            && maintenance_margin_bps < initial_margin_bps
+            && initial_margin_bps as u64 <= BPS_DENOMINATOR
+            && primary.is_configured(),
+        ErrorCode::InvalidMarginConfig
+    );
+    Ok(())
+}
+
+/// Validates the binary payout: required (with a primary oracle to settle
+/// against, and no margin writing) for binary series, unset for the others
+pub fn validate_binary_config(
+    option_type: OptionType,
+    binary_payout: u64,
+    primary: &OracleFeed,
+    initial_margin_bps: u16,
+) -> Result<()> {
+    if option_type.is_binary() {
+        require!(
+            binary_payout > 0 && primary.is_configured() && initial_margin_bps == 0,
+            ErrorCode::InvalidBinaryConfig
+        );
+    } else {
+        require!(binary_payout == 0, ErrorCode::InvalidBinaryConfig);
+    }
+    Ok(())
+}
+
+/// Validates the knock-out barrier: none (0), or beyond the strike in the
+/// option's direction (up-and-out calls, down-and-out puts) with a primary
+/// oracle for `record_knockout` to read
+pub fn validate_knockout_barrier(
+    option_type: OptionType,
+    strike_price: u64,
+    knockout_barrier: u64,
+    primary: &OracleFeed,
+) -> Result<()> {
+    if knockout_barrier == 0 {
+        return Ok(());
+    }
+    let beyond_strike = if option_type.is_put_payoff() {
+        knockout_barrier < strike_price
+    } else {
+        knockout_barrier > strike_price
+    };
+    require!(
+        beyond_strike && primary.is_configured(),
+        ErrorCode::InvalidBarrier
+    );
+    Ok(())
+}
+
+/// Validates perpetual mode: a series expiring at `PERPETUAL_EXPIRATION` must
+/// be American, not binary, without margin (nothing ever settles it) and charge
+/// at most `MAX_FUNDING_RATE_BPS`; dated series can't charge funding
+pub fn validate_perpetual_config(
+    option_type: OptionType,
+    expiration: i64,
+    funding_rate_bps: u32,
+    exercise_style: ExerciseStyle,
+    initial_margin_bps: u16,
+) -> Result<()> {
+    if expiration == PERPETUAL_EXPIRATION {
+        require!(
+            !option_type.is_binary()
+                && exercise_style == ExerciseStyle::American
+                && initial_margin_bps == 0
+                && funding_rate_bps <= MAX_FUNDING_RATE_BPS,
+            ErrorCode::InvalidPerpetualConfig
+        );
+    } else {
+        require!(funding_rate_bps == 0, ErrorCode::InvalidPerpetualConfig);
+    }
+    Ok(())
+}
+
+/// Validates a forward: dated (something has to settle it), American (there
+/// is no exercise window to wait for), without margin or a knock-out barrier
+pub fn validate_forward_config(
+    option_type: OptionType,
+    expiration: i64,
+    exercise_style: ExerciseStyle,
+    initial_margin_bps: u16,
+    knockout_barrier: u64,
+) -> Result<()> {
+    if option_type.is_forward() {
+        require!(
+            expiration != PERPETUAL_EXPIRATION
+                && exercise_style == ExerciseStyle::American
+                && initial_margin_bps == 0
+                && knockout_barrier == 0,
+            ErrorCode::InvalidForwardConfig
+        );
+    }
+    Ok(())
+}
+
+/// Validates the collateral basket opt-in: substitutes can't back margin
+/// (held outside vault accounting), funding (paid out of the primary vault)
+/// or forwards (which also lock the strike)
+pub fn validate_basket_config(
+    option_type: OptionType,
+    accepts_basket: bool,
+    funding_rate_bps: u32,
+    initial_margin_bps: u16,
+) -> Result<()> {
+    if accepts_basket {
+        require!(
+            !option_type.is_forward() && funding_rate_bps == 0 && initial_margin_bps == 0,
+            ErrorCode::InvalidCollateralBasket
+        );
+    }
+    Ok(())
+}
+
+/// Validates a series' transfer hook: Token-2022 extensions only exist on
+/// Token-2022 mints, so a hooked series must be created under that program
+pub fn validate_transfer_hook(
+    transfer_hook_program: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    if *transfer_hook_program != Pubkey::default() {
+        require_keys_eq!(
+            *token_program,
+            anchor_spl::token_2022::ID,
+            ErrorCode::TransferHookRequiresToken2022
+        );
+    }
+    Ok(())
+}
+
+/// Validates a series' freeze settings: only a freezable series can name a
+/// compliance authority
+pub fn validate_freeze_config(freezable: bool, compliance_authority: &Pubkey) -> Result<()> {
+    require!(
+        freezable || *compliance_authority == Pubkey::default(),
+        ErrorCode::InvalidFreezeConfig
+    );
+    Ok(())
+}
+
+/// Validates a governance basket: distinct substitutes, none of them the base
+/// mint itself
+pub fn validate_basket_mints(base_mint: &Pubkey, mints: &[Pubkey]) -> Result<()> {
+    require!(
+        mints.len() <= CollateralBasket::MAX_MINTS,
+        ErrorCode::InvalidCollateralBasket
+    );
+    for (i, mint) in mints.iter().enumerate() {
+        require!(
+            *mint != Pubkey::default() && mint != base_mint && !mints[..i].contains(mint),
+            ErrorCode::InvalidCollateralBasket
+        );
+    }
+    Ok(())
+}
+
+/// Validates a mint or exercise fee against the protocol maximum
+pub fn validate_fee_bps(fee_bps: u16) -> Result<()> {
+    require!(
+        fee_bps <= ProtocolConfig::MAX_FEE_BPS,
+        ErrorCode::FeeTooHigh
+    );
+    Ok(())
+}
+
+/// Validates volume tiers: at most `MAX_FEE_TIERS`, by strictly increasing
+/// non-zero volume, with discounts of at most 100% that never shrink
+pub fn validate_fee_tiers(tiers: &[FeeTier]) -> Result<()> {
+    require!(
+        tiers.len() <= ProtocolConfig::MAX_FEE_TIERS,
+        ErrorCode::InvalidFeeTiers
+    );
+    let mut previous = FeeTier::default();
+    for tier in tiers {
+        require!(
+            tier.min_volume > previous.min_volume
+                && tier.discount_bps >= previous.discount_bps
+                && tier.discount_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidFeeTiers
+        );
+        previous = *tier;
+    }
+    Ok(())
+}
+
+/// Validates the fee exemption allowlist: at most `MAX_FEE_EXEMPT` real accounts
+pub fn validate_fee_exempt(accounts: &[Pubkey]) -> Result<()> {
+    require!(
+        accounts.len() <= ProtocolConfig::MAX_FEE_EXEMPT && !accounts.contains(&Pubkey::default()),
+        ErrorCode::InvalidFeeExempt
+    );
+    Ok(())
+}
+
+/// Validates the referrers' share of fees (at most all of it)
+pub fn validate_referral_share(referral_share_bps: u16) -> Result<()> {
+    require!(
+        referral_share_bps as u64 <= BPS_DENOMINATOR,
+        ErrorCode::InvalidReferralShare
+    );
+    Ok(())
+}
+
+/// Validates the insurance fund's share of the protocol's part of fees
+pub fn validate_insurance_share(insurance_share_bps: u16) -> Result<()> {
+    require!(
+        insurance_share_bps as u64 <= BPS_DENOMINATOR,
+        ErrorCode::InvalidInsuranceShare
+    );
+    Ok(())
+}
+
+/// Validates a set of feature switches: only known `FEATURE_*` bits
+pub fn validate_features(disabled_features: u32) -> Result<()> {
+    require!(
+        disabled_features & !ProtocolConfig::ALL_FEATURES == 0,
+        ErrorCode::InvalidFeatures
+    );
+    Ok(())
+}
+
+/// Validates that `feature` (a `FEATURE_*` bit) is switched on
+pub fn validate_feature_enabled(config: &ProtocolConfig, feature: u32) -> Result<()> {
+    require!(config.is_enabled(feature), ErrorCode::FeatureDisabled);
+    Ok(())
+}
+
+/// Validates that every optional feature a new series uses is switched on
+pub fn validate_series_features(
+    config: &ProtocolConfig,
+    option_type: OptionType,
+    expiration: i64,
+    initial_margin_bps: u16,
+    knockout_barrier: u64,
+    accepts_basket: bool,
+) -> Result<()> {
+    for (used, feature) in [
+        (
+            expiration == PERPETUAL_EXPIRATION,
+            ProtocolConfig::FEATURE_PERPETUALS,
+        ),
+        (initial_margin_bps > 0, ProtocolConfig::FEATURE_MARGIN),
+        (option_type.is_forward(), ProtocolConfig::FEATURE_FORWARDS),
+        (option_type.is_binary(), ProtocolConfig::FEATURE_BINARIES),
+        (knockout_barrier > 0, ProtocolConfig::FEATURE_KNOCKOUTS),
+        (accepts_basket, ProtocolConfig::FEATURE_COLLATERAL_BASKETS),
+    ] {
+        if used {
+            validate_feature_enabled(config, feature)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validates that the series has not been knocked out (minting and exercise)
+pub fn validate_not_knocked_out(option_context: &OptionData) -> Result<()> {
+    require!(
+        !option_context.is_knocked_out(),
+        ErrorCode::SeriesKnockedOut
+    );
+    Ok(())
+}
+
+/// Validates that the series has not been frozen by the guardian (minting and exercise)
+pub fn validate_not_frozen(option_context: &OptionData) -> Result<()> {
+    require!(!option_context.is_frozen(), ErrorCode::SeriesFrozen);
+    Ok(())
+}
+
+/// Validates that the series is not a legacy put, which can only be burned
+/// and redeemed (minting and exercise)
+pub fn validate_not_legacy_put(option_context: &OptionData) -> Result<()> {
+    require!(
+        !option_context.is_legacy_put(),
+        ErrorCode::LegacyPutRestricted
+    );
+    Ok(())
+}
+
+/// Validates the credential of a gated series: `holder` must present an
+/// unfrozen token account of the series' gate mint holding at least one token
+/// (open series need none)
+pub fn validate_credential(
+    option_context: &OptionData,
+    holder: &Pubkey,
+    credential: Option<&TokenState>,
+) -> Result<()> {
+    if option_context.gate_mint == Pubkey::default() {
+        return Ok(());
+    }
+    let Some(credential) = credential else {
+        return err!(ErrorCode::CredentialRequired);
+    };
+    require!(
+        credential.mint == option_context.gate_mint
+            && credential.owner == *holder
+            && credential.amount > 0
+            && !credential.is_frozen(),
+        ErrorCode::CredentialRequired
+    );
+    Ok(())
+}
+
+/// Token state of an instruction's optional credential account
+pub fn credential_state<'a>(
+    account: &'a Option<Box<InterfaceAccount<'_, TokenAccount>>>,
+) -> Option<&'a TokenState> {
+    account.as_deref().map(|account| &***account)
+}
+
+/// Validates a spread's legs: same mints, side, expiry, exercise schedule and
+/// primary oracle (so both settle together at the same price), with the long
+/// leg further out of the money than the short; binaries, forwards,
+/// knock-outs and legacy puts can't be spread, nor series accepting a
+/// collateral basket
+pub fn validate_spread_legs(short: &OptionData, long: &OptionData) -> Result<()> {
+    require!(
+        short.collateral_mint == long.collateral_mint
+            && short.consideration_mint == long.consideration_mint
+            && short.option_type == long.option_type
+            && short.strike_exponent == long.strike_exponent
+            && short.contract_multiplier == long.contract_multiplier
+            && short.expiration == long.expiration
+            && short.exercise_style == long.exercise_style
+            && short.exercise_window == long.exercise_window
+            && short.primary_oracle.feed == long.primary_oracle.feed
+            && matches!(short.option_type, OptionType::Call | OptionType::Put)
+            && short.knockout_barrier == 0
+            && long.knockout_barrier == 0
+            && !short.is_legacy_put()
+            && !long.is_legacy_put()
+            && !short.accepts_basket
+            && !long.accepts_basket,
+        ErrorCode::InvalidSpread
+    );
+    let further_otm = if short.is_put() {
+        long.strike_price < short.strike_price
+    } else {
+        long.strike_price > short.strike_price
+    };
+    require!(further_otm, ErrorCode::InvalidSpread);
+    Ok(())
+}
+
+/// Validates a roll target: same mints, strike, strike units, type and
+/// contract size as the expiring series (so both escrow the same), expiring
+/// later; forwards, which also lock the strike, basket series and legacy
+/// puts can't be rolled
+pub fn validate_roll_target(from: &OptionData, to: &OptionData) -> Result<()> {
+    require!(
+        from.collateral_mint == to.collateral_mint
+            && from.consideration_mint == to.consideration_mint
+            && from.strike_price == to.strike_price
+            && from.strike_exponent == to.strike_exponent
+            && from.option_type == to.option_type
+            && from.contract_multiplier == to.contract_multiplier
+            && from.binary_payout == to.binary_payout
+            && !from.is_forward()
+            && !from.is_legacy_put()
+            && !from.accepts_basket
+            && !to.accepts_basket
+            && to.expiration > from.expiration,
+        ErrorCode::InvalidRoll
+    );
+    Ok(())
+}
+
+/// Validates a combo's legs: a call and a put with the same mints, strike
+/// units, contract size, lot size and expiry, the put struck at or below the
+/// call (equal strikes make a straddle, a lower put a strangle); legacy puts
+/// can't be combined
+pub fn validate_combo_legs(call: &OptionData, put: &OptionData) -> Result<()> {
+    require!(
+        call.option_type == OptionType::Call
+            && put.option_type == OptionType::Put
+            && !put.is_legacy_put()
+            && call.collateral_mint == put.collateral_mint
+            && call.consideration_mint == put.consideration_mint
+            && call.strike_exponent == put.strike_exponent
+            && call.contract_multiplier == put.contract_multiplier
+            && call.lot_size == put.lot_size
+            && call.expiration == put.expiration
+            && put.strike_price <= call.strike_price,
+        ErrorCode::InvalidCombo
+    );
+    Ok(())
+}
+
+/// Validates the optional series metadata URI fits in the reserved space
+pub fn validate_metadata_uri(uri: &str) -> Result<()> {
+    require!(
+        uri.len() <= MAX_METADATA_URI_LEN,
+        ErrorCode::MetadataUriTooLong
+    );
+    Ok(())
+}
+
+/// Validates the underlying ticker: 1-10 ASCII alphanumeric characters
+pub fn validate_underlying_symbol(symbol: &str) -> Result<()> {
+    require!(
+        !symbol.is_empty()
+            && symbol.len() <= MAX_UNDERLYING_SYMBOL_LEN
+            && symbol.bytes().all(|b| b.is_ascii_alphanumeric()),
+        ErrorCode::InvalidUnderlyingSymbol
+    );
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! Deployment version and capability flags reported by `announce_version`.
+//!
+//! Append to `CAPABILITIES` whenever a release adds an integrator-visible
+//! feature, so indexers can gate on it instead of on program version.
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const CAPABILITIES: &[&str] = &[
+    "oracle-settlement",
+    "series-metadata",
+    "authority-transfer",
+    "psy-american-aliases",
+    "psy-american-migration",
+    "creation-rate-limits",
+    "series-pause",
+    "emergency-withdrawal",
+    "european-exercise",
+    "exercise-cutoff",
+    "settlement-delay",
+    "expiry-netting",
+    "protocol-pause",
+    "series-freeze",
+    "token-metadata",
+    "transfer-hooks",
+    "freeze-authority",
+    "native-sol",
+    "credential-gates",
+    "mint-extension-checks",
+    "cpi-client",
+    "pda-helpers",
+    "mint-and-list",
+    "flash-exercise",
+    "series-markets",
+    "jupiter-swaps",
+    "collateral-yield",
+    "option-vaults",
+    "put-vaults",
+    "settlement-attestations",
+    "openbook-markets",
+    "compact-accounts",
+    "lifecycle-events",
+    "schema-versioning",
+];
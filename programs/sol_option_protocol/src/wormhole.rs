@@ -0,0 +1,194 @@
+//! Wormhole core bridge adapter.
+//!
+//! The protocol posts settlement attestations as Wormhole messages from one
+//! emitter, a PDA of this program (seeds `["emitter"]`), so guardians sign
+//! them into VAAs that EVM-side wrappers and cross-chain frontends verify.
+//! Each series gets one message account, also a PDA of this program (seeds
+//! `["settlement_message", option_context]`). Only `post_message` is encoded
+//! here.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::{system_instruction, sysvar};
+
+use crate::errors::ErrorCode;
+
+/// Mainnet Wormhole core bridge
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+
+/// Seeds of the core bridge's own accounts
+pub const BRIDGE_SEED: &[u8] = b"Bridge";
+pub const FEE_COLLECTOR_SEED: &[u8] = b"fee_collector";
+pub const SEQUENCE_SEED: &[u8] = b"Sequence";
+
+/// Seeds of the protocol's emitter and per-series message accounts
+pub const EMITTER_SEED: &[u8] = b"emitter";
+pub const MESSAGE_SEED: &[u8] = b"settlement_message";
+
+/// Offset of the message fee in the bridge account (after the guardian set
+/// index, the last lamports and the guardian set expiration time)
+pub const BRIDGE_FEE_OFFSET: usize = 16;
+
+/// Guardians sign the message once its slot is finalized
+pub const CONSISTENCY_FINALIZED: u8 = 1;
+
+const POST_MESSAGE: u8 = 1;
+
+pub fn bridge_address() -> Pubkey {
+    Pubkey::find_program_address(&[BRIDGE_SEED], &WORMHOLE_PROGRAM_ID).0
+}
+
+pub fn fee_collector_address() -> Pubkey {
+    Pubkey::find_program_address(&[FEE_COLLECTOR_SEED], &WORMHOLE_PROGRAM_ID).0
+}
+
+/// Sequence tracker of `emitter`'s messages
+pub fn sequence_address(emitter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SEQUENCE_SEED, emitter.as_ref()], &WORMHOLE_PROGRAM_ID).0
+}
+
+/// The protocol's emitter
+pub fn emitter_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EMITTER_SEED], &crate::ID)
+}
+
+/// Message account of `option_context`'s settlement
+pub fn message_address(option_context: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MESSAGE_SEED, option_context.as_ref()], &crate::ID)
+}
+
+#[derive(AnchorSerialize)]
+struct PostMessageData {
+    nonce: u32,
+    payload: Vec<u8>,
+    consistency_level: u8,
+}
+
+/// `PostMessage` of `payload` from the protocol's emitter into `message`
+pub fn post_message_ix(
+    payer: &Pubkey,
+    message: &Pubkey,
+    nonce: u32,
+    payload: Vec<u8>,
+) -> Instruction {
+    let emitter = emitter_address().0;
+    let mut data = vec![POST_MESSAGE];
+    // Writing into a Vec can't fail
+    PostMessageData {
+        nonce,
+        payload,
+        consistency_level: CONSISTENCY_FINALIZED,
+    }
+    .serialize(&mut data)
+    .unwrap();
+    Instruction {
+        program_id: WORMHOLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(bridge_address(), false),
+            AccountMeta::new(*message, true),
+            AccountMeta::new_readonly(emitter, true),
+            AccountMeta::new(sequence_address(&emitter), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(fee_collector_address(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(System::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Fee the bridge charges per message
+pub fn message_fee(bridge: &AccountInfo) -> Result<u64> {
+    require_keys_eq!(
+        *bridge.owner,
+        WORMHOLE_PROGRAM_ID,
+        ErrorCode::InvalidWormholeAccount
+    );
+    let data = bridge.try_borrow_data()?;
+    let fee = data
+        .get(BRIDGE_FEE_OFFSET..BRIDGE_FEE_OFFSET + 8)
+        .ok_or(ErrorCode::InvalidWormholeAccount)?;
+    Ok(u64::from_le_bytes(fee.try_into().unwrap()))
+}
+
+/// Sequence the emitter's next message gets (its tracker doesn't exist
+/// before the first one)
+pub fn next_sequence(sequence: &AccountInfo) -> Result<u64> {
+    if sequence.data_is_empty() {
+        return Ok(0);
+    }
+    let data = sequence.try_borrow_data()?;
+    let next = data.get(..8).ok_or(ErrorCode::InvalidWormholeAccount)?;
+    Ok(u64::from_le_bytes(next.try_into().unwrap()))
+}
+
+/// Accounts posting a settlement message takes
+pub struct WormholeAccounts<'a, 'info> {
+    pub wormhole_program: &'a AccountInfo<'info>,
+    pub bridge: &'a AccountInfo<'info>,
+    pub message: &'a AccountInfo<'info>,
+    pub emitter: &'a AccountInfo<'info>,
+    pub sequence: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Pays the bridge fee and posts `payload` as `option_context`'s settlement
+/// message; returns the message's sequence
+pub fn post_settlement(
+    accounts: WormholeAccounts,
+    option_context: &Pubkey,
+    payload: Vec<u8>,
+) -> Result<u64> {
+    let (emitter, emitter_bump) = emitter_address();
+    let (message, message_bump) = message_address(option_context);
+    require!(
+        accounts.wormhole_program.key() == WORMHOLE_PROGRAM_ID
+            && accounts.bridge.key() == bridge_address()
+            && accounts.emitter.key() == emitter
+            && accounts.message.key() == message
+            && accounts.sequence.key() == sequence_address(&emitter)
+            && accounts.fee_collector.key() == fee_collector_address(),
+        ErrorCode::InvalidWormholeAccount
+    );
+
+    let fee = message_fee(accounts.bridge)?;
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(accounts.payer.key, accounts.fee_collector.key, fee),
+            &[
+                accounts.payer.clone(),
+                accounts.fee_collector.clone(),
+                accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
+    let sequence = next_sequence(accounts.sequence)?;
+    invoke_signed(
+        &post_message_ix(accounts.payer.key, &message, 0, payload),
+        &[
+            accounts.bridge.clone(),
+            accounts.message.clone(),
+            accounts.emitter.clone(),
+            accounts.sequence.clone(),
+            accounts.payer.clone(),
+            accounts.fee_collector.clone(),
+            accounts.clock.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+            accounts.wormhole_program.clone(),
+        ],
+        &[
+            &[EMITTER_SEED, &[emitter_bump]],
+            &[MESSAGE_SEED, option_context.as_ref(), &[message_bump]],
+        ],
+    )?;
+
+    Ok(sequence)
+}
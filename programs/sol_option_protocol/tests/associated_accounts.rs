@@ -0,0 +1,50 @@
+//! The option instructions use the user's associated token accounts and
+//! create the ones that don't exist yet.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, Wallet, DAY, SOL, STRIKE};
+use solana_sdk::signature::Signer;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use sol_option_protocol::instruction as ix;
+
+/// Alice only holds SOL: her first mint creates her consideration, option and
+/// redemption accounts. A token account at any other address is rejected.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mint_creates_missing_accounts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = common::wallet_addresses(&series, &alice.pubkey());
+    h.create_ata(&sol, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    assert!(h.token_owner(&a.option).await.is_none());
+
+    h.option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.token_owner(&a.consideration).await, Some(alice.pubkey()));
+    assert_eq!(h.balance(&a.option).await, SOL);
+    assert_eq!(h.balance(&a.redemption).await, SOL);
+
+    // --- Accounts must be the associated ones ---------------------------------
+    let other = Wallet {
+        option: h
+            .create_token_account(&series.option_mint, &alice.pubkey())
+            .await,
+        ..a
+    };
+    let result = h
+        .option_ix(ix::Mint { amount: SOL }, &series, &alice, &other)
+        .await;
+    assert_anchor_error(result, AnchorErrorCode::AccountNotAssociatedTokenAccount);
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+}
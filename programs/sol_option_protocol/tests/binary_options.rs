@@ -0,0 +1,175 @@
+//! Binary (cash-or-nothing) series: writers lock a fixed payout in
+//! consideration, holders receive it at expiry if the settlement price
+//! finishes beyond the strike, nothing otherwise.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::net_expiry;
+use sol_option_protocol::oracle::{OracleFeed, OracleKind};
+use sol_option_protocol::utils::netting::{calculate_binary_settlement, finishes_in_the_money};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+const PAYOUT: u64 = 10 * USDC; // per SOL, quoted like the strike
+
+#[test]
+fn binary_settlement_pays_the_fixed_amount_beyond_the_strike() {
+    // 10 binary calls written: 100 USDC locked
+    let itm = calculate_binary_settlement(0, 100 * USDC, 10 * SOL, true, PAYOUT, 9).unwrap();
+    assert_eq!(itm.option_consideration, 100 * USDC);
+    assert_eq!(itm.redemption_consideration, 0);
+
+    let otm = calculate_binary_settlement(0, 100 * USDC, 10 * SOL, false, PAYOUT, 9).unwrap();
+    assert_eq!(otm.option_consideration, 0);
+    assert_eq!(otm.redemption_consideration, 100 * USDC);
+
+    // The strike itself is not beyond the strike, either way
+    assert!(!finishes_in_the_money(STRIKE, STRIKE, false));
+    assert!(!finishes_in_the_money(STRIKE, STRIKE, true));
+    assert!(finishes_in_the_money(STRIKE, STRIKE + 1, false));
+    assert!(finishes_in_the_money(STRIKE, STRIKE - 1, true));
+}
+
+#[test]
+fn binary_expiry_reserves_the_payout_for_outstanding_options() {
+    // 10 written (100 USDC locked), 4 of them still outstanding
+    let series = OptionData {
+        option_type: OptionType::BinaryCall,
+        strike_price: STRIKE,
+        strike_scale: 9,
+        contract_multiplier: 1,
+        binary_payout: PAYOUT,
+        consideration_received: 100 * USDC,
+        ..OptionData::default()
+    };
+    let (net, margin_owed) = net_expiry(&series, Some(160 * USDC), 4 * SOL).unwrap();
+    assert_eq!(margin_owed, 0);
+    assert_eq!(net.option_consideration, 40 * USDC);
+    assert_eq!(net.option_collateral, 0);
+    // 60 USDC left for the redemption tokens
+    assert_eq!(net.redemption_consideration, 60 * USDC);
+}
+
+#[test]
+fn binary_option_type_bytes() {
+    assert_eq!(OptionType::BinaryCall as u8, 2);
+    assert_eq!(OptionType::BinaryPut as u8, 3);
+    assert_eq!(OptionType::try_from(3).unwrap(), OptionType::BinaryPut);
+    assert!(OptionType::BinaryPut.is_put_payoff());
+    assert!(!OptionType::Put.is_binary());
+}
+
+fn binary_config(oracle: Pubkey) -> SeriesConfig {
+    SeriesConfig {
+        primary_oracle: OracleFeed {
+            kind: OracleKind::Pyth,
+            feed: oracle,
+            ..OracleFeed::default()
+        },
+        binary_payout: PAYOUT,
+        ..SeriesConfig::default()
+    }
+}
+
+/// Alice writes 10 binary calls (100 USDC locked) and sells 4 to Bob. They
+/// can't be exercised; SOL settles at $160, so every option claims 10 USDC.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn binary_call_pays_cash_in_the_money() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let oracle = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_typed_series(
+            &alice,
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::BinaryCall,
+            binary_config(oracle),
+        )
+        .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.binary_payout, PAYOUT);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&usdc, &a.consideration, 100 * USDC).await;
+    h.mint_to(&usdc, &b.consideration, 1_000 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.consideration_vault).await, 100 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    let result = h
+        .option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::CashSettledOnly);
+
+    h.set_pyth_price(&oracle, 160 * USDC, expiration).await;
+    h.warp_to(expiration + 1).await;
+    h.settle_with_oracle(&series, &oracle).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.net_option_consideration, 100 * USDC);
+
+    h.option_ix(ix::ClaimNetPayoff { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.consideration).await, 1_040 * USDC);
+    h.option_ix(ix::ClaimNetPayoff { amount: 6 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 60 * USDC);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+}
+
+/// Binaries need a payout and an oracle; other series can't set a payout
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn binary_config_is_validated() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+
+    let no_oracle = SeriesConfig {
+        binary_payout: PAYOUT,
+        ..SeriesConfig::default()
+    };
+    let no_payout = SeriesConfig {
+        binary_payout: 0,
+        ..binary_config(Pubkey::new_unique())
+    };
+    for (option_type, config) in [
+        (OptionType::BinaryPut, no_oracle),
+        (OptionType::BinaryCall, no_payout),
+        (OptionType::Call, binary_config(Pubkey::new_unique())),
+    ] {
+        let (_, ix) = create_series_ix(
+            &alice.pubkey(),
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            option_type,
+            config,
+        );
+        let result = h.send(&[ix], &[&alice]).await;
+        assert_anchor_error(result, ErrorCode::InvalidBinaryConfig);
+    }
+}
@@ -0,0 +1,190 @@
+//! Client builders: `sol_option_protocol::client` assembles mint, exercise and
+//! redeem instructions from a series' addresses, the same accounts a caller
+//! would otherwise list by hand (and on-chain callers pass through `cpi`).
+
+mod common;
+
+use anchor_lang::AccountSerialize;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::spl_token;
+use common::{
+    create_series_ix, option_context_metas_with, Harness, OptionExtras, Series, Wallet, DAY, SOL,
+    STRIKE, USDC,
+};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::client::{self, SeriesAddresses};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn addresses(series: &Series) -> SeriesAddresses {
+    SeriesAddresses {
+        option_context: series.option_context,
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        token_program: series.token_program,
+    }
+}
+
+#[test]
+fn builders_list_the_users_associated_accounts() {
+    let series = Series {
+        option_context: Pubkey::new_unique(),
+        collateral_mint: Pubkey::new_unique(),
+        consideration_mint: Pubkey::new_unique(),
+        option_mint: Pubkey::new_unique(),
+        redemption_mint: Pubkey::new_unique(),
+        collateral_vault: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        token_program: spl_token::ID,
+    };
+    let user = Pubkey::new_unique();
+    let ata =
+        |mint: &Pubkey| get_associated_token_address_with_program_id(&user, mint, &spl_token::ID);
+    let wallet = Wallet {
+        collateral: ata(&series.collateral_mint),
+        consideration: ata(&series.consideration_mint),
+        option: ata(&series.option_mint),
+        redemption: ata(&series.redemption_mint),
+    };
+
+    let ix = client::mint_ix(&addresses(&series), &user, SOL);
+    assert_eq!(ix.program_id, sol_option_protocol::ID);
+    assert_eq!(
+        ix.accounts,
+        option_context_metas_with(
+            &series,
+            &user,
+            (user, user),
+            &wallet,
+            OptionExtras::default()
+        )
+    );
+}
+
+#[test]
+fn series_addresses_come_from_the_series_account() {
+    let option_context = Pubkey::new_unique();
+    let data = OptionData {
+        collateral_mint: Pubkey::new_unique(),
+        option_mint: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        ..OptionData::default()
+    };
+    let series = SeriesAddresses::of(option_context, &data, spl_token::ID);
+    assert_eq!(series.option_context, option_context);
+    assert_eq!(series.collateral_mint, data.collateral_mint);
+    assert_eq!(series.option_mint, data.option_mint);
+    assert_eq!(series.consideration_vault, data.consideration_vault);
+}
+
+fn matches(data: &[u8], filters: &[client::Memcmp]) -> bool {
+    filters
+        .iter()
+        .all(|f| data.get(f.offset..f.offset + f.bytes.len()) == Some(&f.bytes[..]))
+}
+
+#[test]
+fn series_filters_match_the_serialized_series() {
+    let series = OptionData {
+        collateral_mint: Pubkey::new_unique(),
+        consideration_mint: Pubkey::new_unique(),
+        expiration: 1_900_000_000,
+        option_mint: Pubkey::new_unique(),
+        authority: Pubkey::new_unique(),
+        ..OptionData::default()
+    };
+    let mut data = Vec::new();
+    series.try_serialize(&mut data).unwrap();
+
+    assert!(matches(&data, &client::series_filter()));
+    assert!(matches(
+        &data,
+        &client::series_by_collateral_mint_filter(&series.collateral_mint)
+    ));
+    assert!(matches(
+        &data,
+        &client::series_by_consideration_mint_filter(&series.consideration_mint)
+    ));
+    assert!(matches(
+        &data,
+        &client::series_by_expiration_filter(series.expiration)
+    ));
+    assert!(matches(
+        &data,
+        &client::series_by_option_mint_filter(&series.option_mint)
+    ));
+    assert!(matches(
+        &data,
+        &client::series_by_authority_filter(&series.authority)
+    ));
+    assert!(!matches(
+        &data,
+        &client::series_by_authority_filter(&series.collateral_mint)
+    ));
+}
+
+/// Alice writes and Bob exercises with client-built instructions, then Alice
+/// redeems her share after expiry
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn client_instructions_drive_a_series() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    let addresses = SeriesAddresses::of(series.option_context, &data, series.token_program);
+    assert_eq!(addresses, self::addresses(&series));
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+
+    h.send(
+        &[client::mint_ix(&addresses, &alice.pubkey(), 2 * SOL)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    h.send(
+        &[client::exercise_ix(&addresses, &bob.pubkey(), SOL)],
+        &[&bob],
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, SOL);
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.send(
+        &[client::redeem_ix(&addresses, &alice.pubkey(), 2 * SOL)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+    assert_eq!(h.balance(&a.consideration).await, 150 * USDC);
+}
@@ -0,0 +1,87 @@
+//! close_series tears down a dead series and refunds its rent to the series
+//! authority.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+
+/// Alice writes 2 calls and burns them back, so nothing is outstanding at
+/// settlement: closing returns every account's rent to her.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn closes_an_empty_series() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    // --- Not before settlement ------------------------------------------------
+    let result = h.close_series(&series, &alice.pubkey()).await;
+    assert_anchor_error(result, ErrorCode::SeriesNotSettled);
+
+    h.option_ix(ix::Burn { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+
+    // --- Vaults and OptionContext close, rent goes to Alice --------------------
+    let rent = h.lamports(&series.option_context).await
+        + h.lamports(&series.collateral_vault).await
+        + h.lamports(&series.consideration_vault).await;
+    let before = h.lamports(&alice.pubkey()).await;
+    h.close_series(&series, &alice.pubkey()).await.unwrap();
+    assert_eq!(h.lamports(&alice.pubkey()).await - before, rent);
+    assert_eq!(h.lamports(&series.option_context).await, 0);
+    assert_eq!(h.lamports(&series.collateral_vault).await, 0);
+}
+
+/// A vault holding donated dust must be swept first; after sweep_dust closed
+/// the vaults, close_series only closes the OptionContext.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn dust_is_swept_before_closing() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let treasury = h.actor().await;
+    h.set_treasury(&treasury.pubkey()).await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let t = h.wallet(&series, &treasury.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    h.transfer(&alice, &a.collateral, &series.collateral_vault, 1)
+        .await;
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+
+    let result = h.close_series(&series, &alice.pubkey()).await;
+    assert_anchor_error(result, ErrorCode::VaultNotEmpty);
+
+    h.treasury_sweep(ix::SweepDust {}, &series, &alice.pubkey(), &t)
+        .await
+        .unwrap();
+    h.next_blockhash().await;
+    h.close_series(&series, &alice.pubkey()).await.unwrap();
+    assert_eq!(h.lamports(&series.option_context).await, 0);
+    assert_eq!(h.balance(&t.collateral).await, 1);
+}
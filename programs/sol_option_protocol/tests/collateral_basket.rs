@@ -0,0 +1,276 @@
+//! Collateral baskets: governance lists substitutes for an escrow mint (USDT
+//! for USDC-backed puts), writers of opted-in series escrow either, and every
+//! escrow payout draws on the substitute vaults pro rata.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{assert_anchor_error, basket_vault_address, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::CollateralBasket;
+use sol_option_protocol::utils::validation::{validate_basket_config, validate_basket_mints};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn basket_put(primary: u64, substitutes: [u64; CollateralBasket::MAX_MINTS]) -> OptionData {
+    let mut series = OptionData {
+        option_type: OptionType::Put,
+        accepts_basket: true,
+        consideration_received: primary + substitutes.iter().sum::<u64>(),
+        basket_escrow: substitutes,
+        ..OptionData::default()
+    };
+    for (slot, held) in substitutes.iter().enumerate() {
+        if *held > 0 {
+            series.basket_mints[slot] = Pubkey::new_unique();
+        }
+    }
+    series
+}
+
+#[test]
+fn payouts_split_pro_rata_across_vaults() {
+    // Without substitutes the primary vault pays everything
+    let (primary, shares) = basket_put(900, [0, 0]).split_escrow_payout(600).unwrap();
+    assert_eq!((primary, shares), (600, [0, 0]));
+
+    // Half the escrow sits in USDT: half of each payout comes from it
+    let series = basket_put(750, [750, 0]);
+    assert_eq!(series.basket_escrow_total(), 750);
+    assert_eq!(series.split_escrow_payout(600).unwrap(), (300, [300, 0]));
+
+    // Substitute shares round up, so the primary never pays more than its share
+    let series = basket_put(1, [1, 1]);
+    assert_eq!(series.split_escrow_payout(1).unwrap(), (0, [1, 0]));
+    assert_eq!(series.split_escrow_payout(2).unwrap(), (0, [1, 1]));
+    assert_eq!(series.split_escrow_payout(3).unwrap(), (1, [1, 1]));
+}
+
+#[test]
+fn substitutes_take_a_slot_each() {
+    let mut series = basket_put(100, [0, 0]);
+    let usdt = Pubkey::new_unique();
+    let pyusd = Pubkey::new_unique();
+    assert_eq!(series.basket_slot(&usdt), Some(0));
+    series.basket_mints[0] = usdt;
+    assert_eq!(series.basket_slot(&usdt), Some(0));
+    assert_eq!(series.basket_slot(&pyusd), Some(1));
+    series.basket_mints[1] = pyusd;
+    assert_eq!(series.basket_slot(&Pubkey::new_unique()), None);
+}
+
+#[test]
+fn baskets_are_validated() {
+    let usdc = Pubkey::new_unique();
+    let usdt = Pubkey::new_unique();
+    let pyusd = Pubkey::new_unique();
+    assert!(validate_basket_mints(&usdc, &[]).is_ok());
+    assert!(validate_basket_mints(&usdc, &[usdt, pyusd]).is_ok());
+    assert!(validate_basket_mints(&usdc, &[usdt, usdt]).is_err());
+    assert!(validate_basket_mints(&usdc, &[usdc]).is_err());
+    assert!(validate_basket_mints(&usdc, &[Pubkey::default()]).is_err());
+    assert!(validate_basket_mints(&usdc, &[usdt, pyusd, Pubkey::new_unique()]).is_err());
+
+    assert!(validate_basket_config(OptionType::Put, true, 0, 0).is_ok());
+    assert!(validate_basket_config(OptionType::Forward, true, 0, 0).is_err());
+    assert!(validate_basket_config(OptionType::Put, true, 500, 0).is_err());
+    assert!(validate_basket_config(OptionType::Call, true, 0, 2_000).is_err());
+    assert!(validate_basket_config(OptionType::Forward, false, 0, 0).is_ok());
+}
+
+/// Alice writes 10 $150 puts, 5 against USDC and 5 against USDT, and sells 4
+/// to Bob. Bob's exercise pays 300 of each; after expiry Alice redeems the
+/// 4 SOL delivered and 450 of each.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn usdt_backs_usdc_puts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let usdt = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    h.set_collateral_basket(&usdc, vec![usdt]).await.unwrap();
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        accepts_basket: true,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_typed_series(
+            &alice,
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::Put,
+            config,
+        )
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    let alice_usdt = h.create_ata(&usdt, &alice.pubkey()).await;
+    let bob_usdt = h.create_ata(&usdt, &bob.pubkey()).await;
+    h.mint_to(&usdc, &a.consideration, 750 * USDC).await;
+    h.mint_to(&usdt, &alice_usdt, 750 * USDC).await;
+    h.mint_to(&sol, &b.collateral, 4 * SOL).await;
+
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.mint_with_substitute(&series, &usdc, &usdt, &alice, &alice_usdt, 5 * SOL)
+        .await
+        .unwrap();
+    let vault = basket_vault_address(&series, &usdt);
+    assert_eq!(h.balance(&vault).await, 750 * USDC);
+    assert_eq!(h.balance(&a.option).await, 10 * SOL);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.escrow_balance(), 1_500 * USDC);
+    assert_eq!(state.basket_mints[0], usdt);
+    assert_eq!(state.basket_escrow[0], 750 * USDC);
+
+    // Exercising without the basket accounts fails
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    let result = h
+        .option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidBasketAccounts);
+    h.basket_option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b, &[usdt])
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.consideration).await, 300 * USDC);
+    assert_eq!(h.balance(&bob_usdt).await, 300 * USDC);
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.basket_option_ix(
+        ix::Redeem { amount: 10 * SOL },
+        &series,
+        &alice,
+        &a,
+        &[usdt],
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 4 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 450 * USDC);
+    assert_eq!(h.balance(&alice_usdt).await, 450 * USDC);
+    assert_eq!(h.balance(&vault).await, 0);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+}
+
+/// Dust donated to a basket vault outlives every redemption: the series can't
+/// close until a sweep listing the basket vault moved it to the treasury.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn basket_vaults_are_swept_before_closing() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let usdt = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let treasury = h.actor().await;
+    h.set_treasury(&treasury.pubkey()).await;
+    h.set_collateral_basket(&usdc, vec![usdt]).await.unwrap();
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        accepts_basket: true,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_typed_series(
+            &alice,
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::Put,
+            config,
+        )
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let t = h.wallet(&series, &treasury.pubkey()).await;
+    let alice_usdt = h.create_ata(&usdt, &alice.pubkey()).await;
+    let treasury_usdt = h.create_ata(&usdt, &treasury.pubkey()).await;
+    h.mint_to(&usdt, &alice_usdt, 150 * USDC + 5).await;
+    h.mint_with_substitute(&series, &usdc, &usdt, &alice, &alice_usdt, SOL)
+        .await
+        .unwrap();
+    let vault = basket_vault_address(&series, &usdt);
+    h.transfer(&alice, &alice_usdt, &vault, 5).await;
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.basket_option_ix(ix::Redeem { amount: SOL }, &series, &alice, &a, &[usdt])
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&vault).await, 5);
+
+    let result = h.close_series(&series, &alice.pubkey()).await;
+    assert_anchor_error(result, ErrorCode::BasketVaultsOpen);
+    let result = h
+        .treasury_sweep(ix::SweepDust {}, &series, &alice.pubkey(), &t)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidBasketAccounts);
+
+    let substitutes = [(usdt, treasury_usdt)];
+    h.basket_treasury_sweep(ix::SweepDust {}, &series, &alice.pubkey(), &t, &substitutes)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&treasury_usdt).await, 5);
+    assert_eq!(h.lamports(&vault).await, 0);
+    h.close_series(&series, &alice.pubkey()).await.unwrap();
+}
+
+/// Only listed substitutes, into series that opted in
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn unlisted_substitutes_are_rejected() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let usdt = h.create_mint(6).await;
+    let other = h.create_mint(6).await;
+    let alice = h.actor().await;
+    h.set_collateral_basket(&usdc, vec![usdt]).await.unwrap();
+
+    let result = h.set_collateral_basket(&usdc, vec![usdc]).await;
+    assert_anchor_error(result, ErrorCode::InvalidCollateralBasket);
+
+    let expiration = h.now().await + 30 * DAY;
+    let plain = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, true)
+        .await;
+    let alice_usdt = h.create_ata(&usdt, &alice.pubkey()).await;
+    h.mint_to(&usdt, &alice_usdt, 150 * USDC).await;
+    let result = h
+        .mint_with_substitute(&plain, &usdc, &usdt, &alice, &alice_usdt, SOL)
+        .await;
+    assert_anchor_error(result, ErrorCode::SubstituteNotAccepted);
+
+    let config = SeriesConfig {
+        accepts_basket: true,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_typed_series(
+            &alice,
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::Put,
+            config,
+        )
+        .await;
+    let alice_other = h.create_ata(&other, &alice.pubkey()).await;
+    h.mint_to(&other, &alice_other, 150 * USDC).await;
+    let result = h
+        .mint_with_substitute(&series, &usdc, &other, &alice, &alice_other, SOL)
+        .await;
+    assert_anchor_error(result, ErrorCode::SubstituteNotAccepted);
+}
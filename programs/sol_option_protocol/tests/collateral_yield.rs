@@ -0,0 +1,270 @@
+//! Collateral yield: governance approves a lending reserve per escrow mint,
+//! series opt in before anything is escrowed, their authority lends idle
+//! escrow and anyone unwinds it when a payout needs the liquidity.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::{
+    assert_anchor_error, create_series_ix, set_yield_venue_ix, Harness, Series, DAY, SOL, USDC,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instructions::YieldVenueParams;
+use sol_option_protocol::lending::{
+    deposit_instruction, redeem_instruction, DEPOSIT_RESERVE_LIQUIDITY, REDEEM_RESERVE_COLLATERAL,
+};
+use sol_option_protocol::state::YieldVenue;
+use sol_option_protocol::utils::pda::derive_yield_venue;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn venue() -> YieldVenue {
+    YieldVenue {
+        escrow_mint: Pubkey::new_unique(),
+        lending_program: Pubkey::new_unique(),
+        lending_market: Pubkey::new_unique(),
+        lending_market_authority: Pubkey::new_unique(),
+        reserve: Pubkey::new_unique(),
+        reserve_liquidity_supply: Pubkey::new_unique(),
+        share_mint: Pubkey::new_unique(),
+        enabled: true,
+        bump: 255,
+    }
+}
+
+fn params(share_mint: Pubkey) -> YieldVenueParams {
+    let venue = venue();
+    YieldVenueParams {
+        lending_program: venue.lending_program,
+        lending_market: venue.lending_market,
+        lending_market_authority: venue.lending_market_authority,
+        reserve: venue.reserve,
+        reserve_liquidity_supply: venue.reserve_liquidity_supply,
+        share_mint,
+    }
+}
+
+#[test]
+fn deposits_and_redemptions_follow_the_token_lending_layout() {
+    let venue = venue();
+    let (vault, shares) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let series = Pubkey::new_unique();
+    let token_program = anchor_spl::token::ID;
+
+    let deposit = deposit_instruction(&venue, vault, shares, series, token_program, 7);
+    assert_eq!(deposit.program_id, venue.lending_program);
+    assert_eq!(deposit.data[0], DEPOSIT_RESERVE_LIQUIDITY);
+    assert_eq!(deposit.data[1..], 7u64.to_le_bytes());
+    assert_eq!(
+        deposit.accounts,
+        vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new(shares, false),
+            AccountMeta::new(venue.reserve, false),
+            AccountMeta::new(venue.reserve_liquidity_supply, false),
+            AccountMeta::new(venue.share_mint, false),
+            AccountMeta::new_readonly(venue.lending_market, false),
+            AccountMeta::new_readonly(venue.lending_market_authority, false),
+            AccountMeta::new_readonly(series, true),
+            AccountMeta::new_readonly(token_program, false),
+        ]
+    );
+
+    let redeem = redeem_instruction(&venue, shares, vault, series, token_program, 5);
+    assert_eq!(redeem.data[0], REDEEM_RESERVE_COLLATERAL);
+    assert_eq!(redeem.data[1..], 5u64.to_le_bytes());
+    assert_eq!(redeem.accounts[0], AccountMeta::new(shares, false));
+    assert_eq!(redeem.accounts[1], AccountMeta::new(vault, false));
+    assert_eq!(
+        redeem.accounts[3],
+        AccountMeta::new(venue.share_mint, false)
+    );
+    assert_eq!(
+        redeem.accounts[4],
+        AccountMeta::new(venue.reserve_liquidity_supply, false)
+    );
+}
+
+/// Lent principal is still escrow: it isn't a shortfall the insurance fund covers
+#[test]
+fn lent_escrow_is_no_shortfall() {
+    let vault = Pubkey::new_unique();
+    let data = OptionData {
+        option_type: OptionType::Call,
+        collateral_vault: vault,
+        collateral_deposited: 10 * SOL,
+        yield_principal: 4 * SOL,
+        ..OptionData::default()
+    };
+    assert_eq!(data.vault_shortfall(&vault, 6 * SOL), 0);
+    assert_eq!(data.vault_shortfall(&vault, 5 * SOL), SOL);
+}
+
+async fn call_series(h: &mut Harness, writer: &Keypair, sol: Pubkey) -> Series {
+    let usdc = h.create_mint(6).await;
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &writer.pubkey(),
+        sol,
+        usdc,
+        150 * USDC,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[writer]).await.unwrap();
+    series
+}
+
+/// Only the admin approves venues, and an approved reserve can only be
+/// switched on and off, never swapped for another
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn admin_approves_a_fixed_reserve() {
+    let mut h = Harness::start().await;
+    let mallory = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let share_mint = h.create_mint(9).await;
+    let approved = params(share_mint);
+
+    let ix = set_yield_venue_ix(&mallory.pubkey(), &sol, approved, true);
+    assert_anchor_error(h.send(&[ix], &[&mallory]).await, ErrorCode::Unauthorized);
+
+    h.set_yield_venue(&sol, approved, true).await.unwrap();
+    let venue: YieldVenue = h.account(&derive_yield_venue(&sol).0).await;
+    assert_eq!((venue.escrow_mint, venue.reserve), (sol, approved.reserve));
+    assert!(venue.enabled);
+
+    let swapped = YieldVenueParams {
+        reserve: Pubkey::new_unique(),
+        ..approved
+    };
+    let result = h.set_yield_venue(&sol, swapped, true).await;
+    assert_anchor_error(result, ErrorCode::InvalidYieldVenue);
+
+    h.set_yield_venue(&sol, approved, false).await.unwrap();
+    let venue: YieldVenue = h.account(&derive_yield_venue(&sol).0).await;
+    assert!(!venue.enabled);
+}
+
+/// Series opt in while empty; writers of an existing series never had a say
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn series_opt_in_before_writing() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let share_mint = h.create_mint(9).await;
+    h.set_yield_venue(&sol, params(share_mint), true)
+        .await
+        .unwrap();
+
+    let series = call_series(&mut h, &alice, sol).await;
+    let result = h
+        .enable_collateral_yield(&bob, &series, &sol, &share_mint)
+        .await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+    h.enable_collateral_yield(&alice, &series, &sol, &share_mint)
+        .await
+        .unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    assert_eq!(data.yield_venue, derive_yield_venue(&sol).0);
+
+    let written = call_series(&mut h, &bob, sol).await;
+    let w = h.wallet(&written, &bob.pubkey()).await;
+    h.mint_to(&sol, &w.collateral, SOL).await;
+    h.option_ix(
+        sol_option_protocol::instruction::Mint { amount: SOL },
+        &written,
+        &bob,
+        &w,
+    )
+    .await
+    .unwrap();
+    let result = h
+        .enable_collateral_yield(&bob, &written, &sol, &share_mint)
+        .await;
+    assert_anchor_error(result, ErrorCode::YieldOptInTooLate);
+}
+
+/// A venue the admin switched off takes no new series
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn disabled_venues_take_no_series() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let share_mint = h.create_mint(9).await;
+    h.set_yield_venue(&sol, params(share_mint), false)
+        .await
+        .unwrap();
+
+    let series = call_series(&mut h, &alice, sol).await;
+    let result = h
+        .enable_collateral_yield(&alice, &series, &sol, &share_mint)
+        .await;
+    assert_anchor_error(result, ErrorCode::YieldVenueDisabled);
+}
+
+/// Only the series authority lends, and nobody unwinds shares the series lacks
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn lending_is_the_authoritys_call() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let share_mint = h.create_mint(9).await;
+    let venue = params(share_mint);
+    h.set_yield_venue(&sol, venue, true).await.unwrap();
+    let series = call_series(&mut h, &alice, sol).await;
+    h.enable_collateral_yield(&alice, &series, &sol, &share_mint)
+        .await
+        .unwrap();
+
+    let vault = series.collateral_vault;
+    let lend = sol_option_protocol::instruction::LendEscrow { amount: SOL };
+    let result = h.yield_ix(lend, &series, &bob, vault, &sol, venue).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+
+    let lend = sol_option_protocol::instruction::LendEscrow { amount: SOL };
+    let result = h.yield_ix(lend, &series, &alice, vault, &sol, venue).await;
+    assert_anchor_error(result, ErrorCode::InsufficientCollateral);
+
+    let unwind = sol_option_protocol::instruction::UnwindEscrow { shares: 1 };
+    let result = h.yield_ix(unwind, &series, &bob, vault, &sol, venue).await;
+    assert_anchor_error(result, ErrorCode::InsufficientYieldShares);
+}
+
+/// Shares still lent out keep a settled series from being swept or closed:
+/// only unwind_escrow brings that escrow back
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn lent_escrow_blocks_sweeps_and_closing() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let alice = h.actor().await;
+    let treasury = h.actor().await;
+    h.set_treasury(&treasury.pubkey()).await;
+    let series = call_series(&mut h, &alice, sol).await;
+    let t = h.wallet(&series, &treasury.pubkey()).await;
+
+    let state: OptionData = h.account(&series.option_context).await;
+    h.warp_to(state.expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let mut state: OptionData = h.account(&series.option_context).await;
+    state.yield_principal = SOL;
+    state.yield_shares = SOL;
+    h.store_series(&series, &state).await;
+
+    let sweep = sol_option_protocol::instruction::SweepDust {};
+    let result = h.treasury_sweep(sweep, &series, &alice.pubkey(), &t).await;
+    assert_anchor_error(result, ErrorCode::YieldSharesOutstanding);
+    let result = h.close_series(&series, &alice.pubkey()).await;
+    assert_anchor_error(result, ErrorCode::YieldSharesOutstanding);
+}
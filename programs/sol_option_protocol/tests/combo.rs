@@ -0,0 +1,131 @@
+//! Combos: a call and a put of one underlying and expiry locked 1:1 behind a
+//! single combo token, so straddles and strangles trade as one SPL token.
+
+mod common;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use common::{assert_anchor_error, combo_addresses, Harness, DAY, SOL, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::Combo;
+use sol_option_protocol::utils::validation::validate_combo_legs;
+use sol_option_protocol::{OptionData, OptionType};
+
+fn leg(option_type: OptionType, strike_price: u64) -> OptionData {
+    OptionData {
+        option_type,
+        strike_price,
+        expiration: 30 * DAY,
+        contract_multiplier: 1,
+        ..OptionData::default()
+    }
+}
+
+#[test]
+fn combo_legs_are_a_call_over_a_put() {
+    let call = leg(OptionType::Call, 150 * USDC);
+    // Straddle and strangle
+    assert!(validate_combo_legs(&call, &leg(OptionType::Put, 150 * USDC)).is_ok());
+    assert!(validate_combo_legs(&call, &leg(OptionType::Put, 140 * USDC)).is_ok());
+    assert!(Combo::is_straddle(150 * USDC, 150 * USDC));
+    assert!(!Combo::is_straddle(150 * USDC, 140 * USDC));
+
+    // Put struck above the call, two calls, or different expiries
+    assert!(validate_combo_legs(&call, &leg(OptionType::Put, 160 * USDC)).is_err());
+    assert!(validate_combo_legs(&call, &leg(OptionType::Call, 140 * USDC)).is_err());
+    let later = OptionData {
+        expiration: 60 * DAY,
+        ..leg(OptionType::Put, 150 * USDC)
+    };
+    assert!(validate_combo_legs(&call, &later).is_err());
+    let binary = leg(OptionType::BinaryPut, 150 * USDC);
+    assert!(validate_combo_legs(&call, &binary).is_err());
+}
+
+/// Alice writes 5 calls and 5 puts at $150, bundles them into 5 straddle
+/// tokens and sells 2 to Bob, who splits them back into a call and a put each
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn straddle_tokens_round_trip() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let call = h
+        .create_series(&alice, sol, usdc, 150 * USDC, expiration, false)
+        .await;
+    let put = h
+        .create_series(&alice, sol, usdc, 150 * USDC, expiration, true)
+        .await;
+    let ac = h.wallet(&call, &alice.pubkey()).await;
+    let ap = h.wallet(&put, &alice.pubkey()).await;
+    h.mint_to(&sol, &ac.collateral, 5 * SOL).await;
+    h.mint_to(&usdc, &ap.consideration, 750 * USDC).await;
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &call, &alice, &ac)
+        .await
+        .unwrap();
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &put, &alice, &ap)
+        .await
+        .unwrap();
+
+    h.combo_ix(ix::CreateCombo { amount: 5 * SOL }, &call, &put, &alice)
+        .await
+        .unwrap();
+    let (combo, combo_mint) = combo_addresses(&call, &put);
+    let state: Combo = h.account(&combo).await;
+    assert_eq!(state.locked, 5 * SOL);
+    assert_eq!(state.combo_mint, combo_mint);
+    assert_eq!(h.supply(&combo_mint).await, 5 * SOL);
+    assert_eq!(h.balance(&ac.option).await, 0);
+    assert_eq!(h.balance(&ap.option).await, 0);
+
+    // The combo token moves like any other SPL token
+    let alice_combo = get_associated_token_address(&alice.pubkey(), &combo_mint);
+    let bob_combo = h.create_ata(&combo_mint, &bob.pubkey()).await;
+    h.transfer(&alice, &alice_combo, &bob_combo, 2 * SOL).await;
+
+    // More than Bob holds can't be decomposed
+    let result = h
+        .combo_ix(ix::DecomposeCombo { amount: 3 * SOL }, &call, &put, &bob)
+        .await;
+    assert!(result.is_err());
+    h.next_blockhash().await;
+
+    h.combo_ix(ix::DecomposeCombo { amount: 2 * SOL }, &call, &put, &bob)
+        .await
+        .unwrap();
+    let bc = h.wallet(&call, &bob.pubkey()).await;
+    let bp = h.wallet(&put, &bob.pubkey()).await;
+    assert_eq!(h.balance(&bc.option).await, 2 * SOL);
+    assert_eq!(h.balance(&bp.option).await, 2 * SOL);
+    assert_eq!(h.supply(&combo_mint).await, 3 * SOL);
+    let state: Combo = h.account(&combo).await;
+    assert_eq!(state.locked, 3 * SOL);
+}
+
+/// A put struck above the call isn't a strangle
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn inverted_strikes_are_rejected() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let call = h
+        .create_series(&alice, sol, usdc, 140 * USDC, expiration, false)
+        .await;
+    let put = h
+        .create_series(&alice, sol, usdc, 160 * USDC, expiration, true)
+        .await;
+    let result = h
+        .combo_ix(ix::CreateCombo { amount: SOL }, &call, &put, &alice)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidCombo);
+}
@@ -0,0 +1,3504 @@
+//! Shared harness for the scenario tests.
+//!
+//! Both programs run as SBF builds under `solana-program-test`, loaded from
+//! `target/deploy` (run `anchor build` first; scenarios are `#[ignore]`d, run
+//! them with `cargo test -- --ignored`). Native processors are not an option:
+//! Anchor's CPI path (`solana-invoke`) has no off-chain stubs. SPL Token is the build
+//! bundled with program-test. The ProtocolConfig singleton is injected at
+//! genesis because program-test deployments have no ProgramData account for
+//! `initialize_config` to check.
+
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    spl_associated_token_account,
+};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::default_account_state::instruction as default_account_state_instruction;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::instruction as transfer_fee_instruction;
+use anchor_spl::token_2022::spl_token_2022::extension::{ExtensionType, StateWithExtensions};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account as SolanaAccount;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+use std::path::Path;
+
+use sol_option_protocol::governance::SPL_GOVERNANCE_PROGRAM_ID;
+use sol_option_protocol::instructions::{OpenbookMarketParams, YieldVenueParams};
+use sol_option_protocol::metadata::{metadata_address, TOKEN_METADATA_PROGRAM_ID};
+use sol_option_protocol::openbook::{event_authority_address, market_authority_address};
+use sol_option_protocol::oracle::pyth::{
+    PriceFeedMessage, PriceUpdateV2, VerificationLevel, PRICE_UPDATE_V2_DISCRIMINATOR,
+    PYTH_RECEIVER_PROGRAM_ID,
+};
+use sol_option_protocol::oracle::stake_pool::{
+    LAST_UPDATE_EPOCH_OFFSET, MIN_ACCOUNT_LEN as MIN_STAKE_POOL_LEN, POOL_MINT_OFFSET,
+    POOL_TOKEN_SUPPLY_OFFSET, SPL_STAKE_POOL_PROGRAM_ID, STAKE_POOL_ACCOUNT_TYPE,
+    TOTAL_LAMPORTS_OFFSET,
+};
+use sol_option_protocol::state::{
+    ApprovedMint, CollateralBasket, Combo, FeeStats, FeeTier, InsuranceFund, OptionVault,
+    ParameterChange, PendingChange, ProtocolConfig, ReferralStats, StrikeTick, VerticalSpread,
+    VerticalSpreadPosition, WriterPosition,
+};
+use sol_option_protocol::utils::pda::{
+    derive_escrow, derive_market, derive_option_context, derive_option_mint, derive_order,
+    derive_redemption_mint, derive_settlement_attestation, derive_share_vault, derive_vaults,
+    derive_yield_venue,
+};
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+pub const SOL: u64 = 1_000_000_000; // collateral, 9 decimals
+pub const USDC: u64 = 1_000_000; // consideration, 6 decimals
+pub const STRIKE: u64 = 150 * USDC;
+pub const HOUR: i64 = 60 * 60;
+pub const DAY: i64 = 24 * HOUR;
+
+pub struct Harness {
+    pub ctx: ProgramTestContext,
+    pub admin: Keypair,
+}
+
+/// A series and the PDAs `create_option` initializes for it
+#[derive(Clone, Copy)]
+pub struct Series {
+    pub option_context: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub consideration_mint: Pubkey,
+    pub option_mint: Pubkey,
+    pub redemption_mint: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub consideration_vault: Pubkey,
+    /// SPL Token or Token-2022, owning every mint and vault of the series
+    pub token_program: Pubkey,
+}
+
+/// A user's token accounts for one series
+#[derive(Clone, Copy)]
+pub struct Wallet {
+    pub collateral: Pubkey,
+    pub consideration: Pubkey,
+    pub option: Pubkey,
+    pub redemption: Pubkey,
+}
+
+/// An option vault and the accounts `create_option_vault` initializes for it
+#[derive(Clone, Copy)]
+pub struct Vault {
+    pub option_vault: Pubkey,
+    pub authority: Pubkey,
+    pub share_mint: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub underlying_account: Pubkey,
+    pub quote_account: Pubkey,
+}
+
+impl Harness {
+    /// Panics when the SBF builds are not available: scenarios are `#[ignore]`d
+    /// and run with `anchor build && cargo test -- --ignored`
+    pub async fn start() -> Self {
+        let deploy_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy");
+        for program in ["sol_option_protocol", "spl_marketplace"] {
+            assert!(
+                deploy_dir.join(format!("{program}.so")).exists(),
+                "{program}.so not found in target/deploy, run `anchor build`"
+            );
+        }
+        std::env::set_var("SBF_OUT_DIR", &deploy_dir);
+
+        let mut program_test =
+            ProgramTest::new("sol_option_protocol", sol_option_protocol::ID, None);
+        program_test.add_program("spl_marketplace", spl_marketplace::ID, None);
+        program_test.prefer_bpf(true);
+
+        let admin = Keypair::new();
+        let (config_key, bump) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let config = ProtocolConfig {
+            admin: admin.pubkey(),
+            pending_admin: Pubkey::default(),
+            bump,
+            max_series_per_epoch: 0,
+            creation_cooldown: 0,
+            guardian: Pubkey::default(),
+            treasury: Pubkey::default(),
+            unclaimed_timeout: 0,
+            creation_bond: 0,
+            expiry_grid_interval: 0,
+            expiry_grid_offset: 0,
+            expiry_grid_exempt: [Pubkey::default(); ProtocolConfig::MAX_EXPIRY_GRID_EXEMPT],
+            min_time_to_expiry: 0,
+            max_time_to_expiry: 0,
+            mint_fee_bps: 0,
+            exercise_fee_bps: 0,
+            disabled_features: 0,
+            fee_exempt: [Pubkey::default(); ProtocolConfig::MAX_FEE_EXEMPT],
+            fee_tiers: [FeeTier::default(); ProtocolConfig::MAX_FEE_TIERS],
+            referral_share_bps: 0,
+            paused: false,
+            mint_allowlist_enabled: false,
+            timelock_delay: 0,
+            insurance_share_bps: 0,
+            allowed_mint_extensions: 0,
+        };
+        program_test.add_account(
+            config_key,
+            program_account(&config, ProtocolConfig::INIT_SPACE),
+        );
+
+        let ctx = program_test.start_with_context().await;
+        Self { ctx, admin }
+    }
+
+    pub fn payer(&self) -> Keypair {
+        self.ctx.payer.insecure_clone()
+    }
+
+    pub async fn send(
+        &mut self,
+        ixs: &[Instruction],
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), BanksClientError> {
+        let payer = self.payer();
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await?;
+        let tx =
+            Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all_signers, blockhash);
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    /// `send`, returning the transaction's log messages (for `emitted`)
+    pub async fn send_logged(
+        &mut self,
+        ixs: &[Instruction],
+        signers: &[&Keypair],
+    ) -> std::result::Result<Vec<String>, BanksClientError> {
+        let payer = self.payer();
+        let mut all_signers = vec![&payer];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await?;
+        let tx =
+            Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all_signers, blockhash);
+        let processed = self
+            .ctx
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        processed
+            .result
+            .map_err(BanksClientError::TransactionError)?;
+        Ok(processed
+            .metadata
+            .map(|meta| meta.log_messages)
+            .unwrap_or_default())
+    }
+
+    /// Waits for a fresh blockhash, so retrying an otherwise identical
+    /// transaction isn't rejected as already processed
+    pub async fn next_blockhash(&mut self) {
+        self.ctx.get_new_latest_blockhash().await.unwrap();
+    }
+
+    /// Funded keypair for a scenario actor
+    pub async fn actor(&mut self) -> Keypair {
+        let actor = Keypair::new();
+        let ix =
+            system_instruction::transfer(&self.ctx.payer.pubkey(), &actor.pubkey(), 10_000_000_000);
+        self.send(&[ix], &[]).await.unwrap();
+        actor
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        self.ctx
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+    }
+
+    pub async fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    // === SPL Token helpers ===
+
+    pub async fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        self.create_mint_under(&spl_token::ID, decimals).await
+    }
+
+    /// Like `create_mint`, owned by Token-2022 (without extensions)
+    pub async fn create_mint_2022(&mut self, decimals: u8) -> Pubkey {
+        self.create_mint_under(&spl_token_2022::ID, decimals).await
+    }
+
+    /// Token-2022 mint with the transfer-fee extension: every transfer
+    /// withholds `fee_bps` of the amount, up to `max_fee` (the payer is also
+    /// the fee authorities)
+    pub async fn create_mint_with_transfer_fee(
+        &mut self,
+        decimals: u8,
+        fee_bps: u16,
+        max_fee: u64,
+    ) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap();
+        let ixs = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(len),
+                len as u64,
+                &spl_token_2022::ID,
+            ),
+            transfer_fee_instruction::initialize_transfer_fee_config(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                Some(&payer.pubkey()),
+                Some(&payer.pubkey()),
+                fee_bps,
+                max_fee,
+            )
+            .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ];
+        self.send(&ixs, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    /// Token-2022 mint with one of the extensions series refuse by default:
+    /// `NonTransferable`, `PermanentDelegate` (the payer) or
+    /// `DefaultAccountState` (frozen, the payer being freeze authority)
+    pub async fn create_mint_with_extension(
+        &mut self,
+        decimals: u8,
+        extension: ExtensionType,
+    ) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let len =
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[extension])
+                .unwrap();
+        let initialize_extension = match extension {
+            ExtensionType::NonTransferable => {
+                spl_token_2022::instruction::initialize_non_transferable_mint(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                )
+            }
+            ExtensionType::PermanentDelegate => {
+                spl_token_2022::instruction::initialize_permanent_delegate(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    &payer.pubkey(),
+                )
+            }
+            ExtensionType::DefaultAccountState => {
+                default_account_state_instruction::initialize_default_account_state(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    &spl_token_2022::state::AccountState::Frozen,
+                )
+            }
+            other => panic!("unsupported mint extension {other:?}"),
+        }
+        .unwrap();
+        let ixs = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(len),
+                len as u64,
+                &spl_token_2022::ID,
+            ),
+            initialize_extension,
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                Some(&payer.pubkey()),
+                decimals,
+            )
+            .unwrap(),
+        ];
+        self.send(&ixs, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    async fn create_mint_under(&mut self, token_program: &Pubkey, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let ixs = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                token_program,
+            ),
+            spl_token_2022::instruction::initialize_mint2(
+                token_program,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ];
+        self.send(&ixs, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    pub async fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let token_program = self.token_program_of(mint).await;
+        let account = Keypair::new();
+        let payer = self.payer();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let ixs = [
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &token_program,
+            ),
+            spl_token_2022::instruction::initialize_account3(
+                &token_program,
+                &account.pubkey(),
+                mint,
+                owner,
+            )
+            .unwrap(),
+        ];
+        self.send(&ixs, &[&account]).await.unwrap();
+        account.pubkey()
+    }
+
+    /// Mints from a mint created by `create_mint` (payer is the mint authority)
+    pub async fn mint_to(&mut self, mint: &Pubkey, account: &Pubkey, amount: u64) {
+        let payer = self.payer();
+        let token_program = self.token_program_of(mint).await;
+        let ix = spl_token_2022::instruction::mint_to(
+            &token_program,
+            mint,
+            account,
+            &payer.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+        self.send(&[ix], &[]).await.unwrap();
+    }
+
+    pub async fn transfer(&mut self, owner: &Keypair, from: &Pubkey, to: &Pubkey, amount: u64) {
+        let token_program = self.token_program_of(from).await;
+        #[allow(deprecated)]
+        let ix = spl_token_2022::instruction::transfer(
+            &token_program,
+            from,
+            to,
+            &owner.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap();
+        self.send(&[ix], &[owner]).await.unwrap();
+    }
+
+    pub async fn balance(&mut self, account: &Pubkey) -> u64 {
+        let data = self
+            .ctx
+            .banks_client
+            .get_account(*account)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)
+            .unwrap()
+            .base
+            .amount
+    }
+
+    /// Overwrites the amount held by token account `account` (simulating tokens
+    /// lost in transfer)
+    pub async fn set_token_balance(&mut self, account: &Pubkey, amount: u64) {
+        let mut stored = self
+            .ctx
+            .banks_client
+            .get_account(*account)
+            .await
+            .unwrap()
+            .unwrap();
+        // The base state leads the account under both token programs
+        let base = &mut stored.data[..spl_token::state::Account::LEN];
+        let mut state = spl_token::state::Account::unpack(base).unwrap();
+        state.amount = amount;
+        spl_token::state::Account::pack(state, base).unwrap();
+        self.ctx.set_account(account, &stored.into());
+    }
+
+    /// Overwrites the supply of `mint` (for token holdings written outside
+    /// the harness, like a legacy series' positions)
+    pub async fn set_supply(&mut self, mint: &Pubkey, amount: u64) {
+        let mut stored = self
+            .ctx
+            .banks_client
+            .get_account(*mint)
+            .await
+            .unwrap()
+            .unwrap();
+        let base = &mut stored.data[..spl_token::state::Mint::LEN];
+        let mut state = spl_token::state::Mint::unpack(base).unwrap();
+        state.supply = amount;
+        spl_token::state::Mint::pack(state, base).unwrap();
+        self.ctx.set_account(mint, &stored.into());
+    }
+
+    pub async fn supply(&mut self, mint: &Pubkey) -> u64 {
+        let data = self
+            .ctx
+            .banks_client
+            .get_account(*mint)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)
+            .unwrap()
+            .base
+            .supply
+    }
+
+    /// Token program owning `address` (a mint or token account)
+    pub async fn token_program_of(&mut self, address: &Pubkey) -> Pubkey {
+        self.ctx
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap()
+            .owner
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let data = self
+            .ctx
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        T::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    // === Options program ===
+
+    pub async fn create_series(
+        &mut self,
+        creator: &Keypair,
+        collateral_mint: Pubkey,
+        consideration_mint: Pubkey,
+        strike_price: u64,
+        expiration: i64,
+        is_put: bool,
+    ) -> Series {
+        self.create_series_with(
+            creator,
+            collateral_mint,
+            consideration_mint,
+            strike_price,
+            expiration,
+            is_put,
+            SeriesConfig::default(),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_series_with(
+        &mut self,
+        creator: &Keypair,
+        collateral_mint: Pubkey,
+        consideration_mint: Pubkey,
+        strike_price: u64,
+        expiration: i64,
+        is_put: bool,
+        config: SeriesConfig,
+    ) -> Series {
+        self.create_typed_series(
+            creator,
+            collateral_mint,
+            consideration_mint,
+            strike_price,
+            expiration,
+            call_or_put(is_put),
+            config,
+        )
+        .await
+    }
+
+    /// Like `create_series_with` for any `OptionType` (e.g. binaries)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_typed_series(
+        &mut self,
+        creator: &Keypair,
+        collateral_mint: Pubkey,
+        consideration_mint: Pubkey,
+        strike_price: u64,
+        expiration: i64,
+        option_type: OptionType,
+        config: SeriesConfig,
+    ) -> Series {
+        let token_program = self.token_program_of(&collateral_mint).await;
+        let (series, ix) = create_series_ix_under(
+            &token_program,
+            &creator.pubkey(),
+            collateral_mint,
+            consideration_mint,
+            strike_price,
+            expiration,
+            option_type,
+            config,
+        );
+        self.send(&[ix], &[creator]).await.unwrap();
+        series
+    }
+
+    /// Creates one call/put series per strike with `create_series_batch`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_series_batch(
+        &mut self,
+        creator: &Keypair,
+        collateral_mint: Pubkey,
+        consideration_mint: Pubkey,
+        strikes: &[u64],
+        expiration: i64,
+        is_put: bool,
+        config: SeriesConfig,
+    ) -> std::result::Result<Vec<Series>, BanksClientError> {
+        let token_program = self.token_program_of(&collateral_mint).await;
+        let series: Vec<Series> = strikes
+            .iter()
+            .map(|&strike| {
+                let option_type = call_or_put(is_put);
+                Series {
+                    token_program,
+                    ..series_addresses(
+                        collateral_mint,
+                        consideration_mint,
+                        strike,
+                        expiration,
+                        option_type,
+                    )
+                }
+            })
+            .collect();
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let (creator_stats, _) = Pubkey::find_program_address(
+            &[b"creator_stats", creator.pubkey().as_ref()],
+            &sol_option_protocol::ID,
+        );
+        let (strike_tick, _) = Pubkey::find_program_address(
+            &[StrikeTick::SEED, consideration_mint.as_ref()],
+            &sol_option_protocol::ID,
+        );
+        let mut accounts = sol_option_protocol::accounts::CreateSeriesBatch {
+            user: creator.pubkey(),
+            protocol_config,
+            creator_stats,
+            collateral_mint,
+            consideration_mint,
+            strike_tick,
+            collateral_approval: approved_mint_address(&collateral_mint),
+            consideration_approval: approved_mint_address(&consideration_mint),
+            token_program,
+            system_program: anchor_lang::system_program::ID,
+        }
+        .to_account_metas(None);
+        for s in &series {
+            for key in [
+                s.option_context,
+                s.option_mint,
+                s.redemption_mint,
+                s.collateral_vault,
+                s.consideration_vault,
+            ] {
+                accounts.push(AccountMeta::new(key, false));
+            }
+        }
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: sol_option_protocol::instruction::CreateSeriesBatch {
+                collateral_mint,
+                consideration_mint,
+                expiration,
+                option_type: call_or_put(is_put),
+                strikes: strikes.to_vec(),
+                config,
+            }
+            .data(),
+        };
+        // Each strike derives and creates five PDAs
+        let budget = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        self.send(&[budget, ix], &[creator]).await?;
+        Ok(series)
+    }
+
+    /// Creates `owner`'s associated token account for `mint` unless it exists
+    pub async fn create_ata(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let payer = self.payer();
+        let token_program = self.token_program_of(mint).await;
+        let ix =
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                owner,
+                mint,
+                &token_program,
+            );
+        self.send(&[ix], &[]).await.unwrap();
+        get_associated_token_address_with_program_id(owner, mint, &token_program)
+    }
+
+    /// Owner of a token account, `None` if it doesn't exist yet
+    pub async fn token_owner(&mut self, account: &Pubkey) -> Option<Pubkey> {
+        let account = self.ctx.banks_client.get_account(*account).await.unwrap()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data);
+        Some(state.unwrap().base.owner)
+    }
+
+    /// `owner`'s associated token accounts for every mint of the series (the
+    /// collateral and consideration ones are shared with other series on the
+    /// same mints)
+    pub async fn wallet(&mut self, series: &Series, owner: &Pubkey) -> Wallet {
+        Wallet {
+            collateral: self.create_ata(&series.collateral_mint, owner).await,
+            consideration: self.create_ata(&series.consideration_mint, owner).await,
+            option: self.create_ata(&series.option_mint, owner).await,
+            redemption: self.create_ata(&series.redemption_mint, owner).await,
+        }
+    }
+
+    /// Owners of the wallet's option and redemption accounts (the user for
+    /// accounts the instruction will create)
+    async fn leg_owners(&mut self, user: &Pubkey, wallet: &Wallet) -> (Pubkey, Pubkey) {
+        (
+            self.token_owner(&wallet.option).await.unwrap_or(*user),
+            self.token_owner(&wallet.redemption).await.unwrap_or(*user),
+        )
+    }
+
+    /// Any `Context<OptionContext>` instruction (mint, exercise, redeem, burn, ...)
+    pub async fn option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: option_context_metas(series, &user.pubkey(), owners, wallet),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// `mint_and_list` of `amount` options listed at `price` on `market`;
+    /// returns the order opened
+    pub async fn mint_and_list(
+        &mut self,
+        user: &Keypair,
+        series: &Series,
+        wallet: &Wallet,
+        market: Pubkey,
+        amount: u64,
+        price: u64,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let market_state: spl_marketplace::state::Market = self.account(&market).await;
+        let (order, _) = derive_order(&spl_marketplace::ID, &market, market_state.next_order_id);
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::MintAndList {
+                option: option_context_accounts(
+                    series,
+                    &user.pubkey(),
+                    owners,
+                    wallet,
+                    OptionExtras::default(),
+                ),
+                marketplace_program: spl_marketplace::ID,
+                market,
+                order,
+                escrow: derive_escrow(&spl_marketplace::ID, &order).0,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::MintAndList { amount, price }.data(),
+        };
+        self.send(&[ix], &[user]).await?;
+        Ok(order)
+    }
+
+    /// `exercise_and_sell` of `amount` calls, selling `sell_amount` collateral
+    /// into `bid` (placed on `market` by the owner of `bidder_collateral_account`)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn exercise_and_sell(
+        &mut self,
+        user: &Keypair,
+        series: &Series,
+        wallet: &Wallet,
+        market: Pubkey,
+        bid: Pubkey,
+        bidder_collateral_account: Pubkey,
+        amount: u64,
+        sell_amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ExerciseAndSell {
+                option: option_context_accounts(
+                    series,
+                    &user.pubkey(),
+                    owners,
+                    wallet,
+                    OptionExtras::default(),
+                ),
+                marketplace_program: spl_marketplace::ID,
+                market,
+                bid,
+                bid_escrow: derive_escrow(&spl_marketplace::ID, &bid).0,
+                bidder_collateral_account,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ExerciseAndSell {
+                amount,
+                sell_amount,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// `open_openbook_market` of `series` on `openbook_program`, creating
+    /// `market` over the pre-allocated `bids`, `asks` and `event_heap`
+    pub async fn open_openbook_market(
+        &mut self,
+        authority: &Keypair,
+        series: &Series,
+        openbook_program: Pubkey,
+        market: &Keypair,
+        book: [Pubkey; 3],
+        params: OpenbookMarketParams,
+    ) -> std::result::Result<(), BanksClientError> {
+        let market_authority = market_authority_address(&market.pubkey());
+        let vault = |mint: &Pubkey| {
+            get_associated_token_address_with_program_id(
+                &market_authority,
+                mint,
+                &series.token_program,
+            )
+        };
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::OpenOpenbookMarket {
+                authority: authority.pubkey(),
+                option_context: series.option_context,
+                option_mint: series.option_mint,
+                consideration_mint: series.consideration_mint,
+                openbook_program,
+                market: market.pubkey(),
+                market_authority,
+                bids: book[0],
+                asks: book[1],
+                event_heap: book[2],
+                market_base_vault: vault(&series.option_mint),
+                market_quote_vault: vault(&series.consideration_mint),
+                event_authority: event_authority_address(),
+                token_program: series.token_program,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::OpenOpenbookMarket { params }.data(),
+        };
+        self.send(&[ix], &[authority, market]).await
+    }
+
+    /// `seed_openbook_market` minting `amount` and asking `price_lots` for
+    /// `base_lots` on `market` (with its `bids`, `asks` and `event_heap`)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn seed_openbook_market(
+        &mut self,
+        user: &Keypair,
+        series: &Series,
+        wallet: &Wallet,
+        openbook_program: Pubkey,
+        open_orders_account: Pubkey,
+        market: Pubkey,
+        book: [Pubkey; 3],
+        amount: u64,
+        price_lots: i64,
+        base_lots: i64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SeedOpenbookMarket {
+                option: option_context_accounts(
+                    series,
+                    &user.pubkey(),
+                    owners,
+                    wallet,
+                    OptionExtras::default(),
+                ),
+                openbook_program,
+                open_orders_account,
+                market,
+                bids: book[0],
+                asks: book[1],
+                event_heap: book[2],
+                market_base_vault: get_associated_token_address_with_program_id(
+                    &market_authority_address(&market),
+                    &series.option_mint,
+                    &series.token_program,
+                ),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SeedOpenbookMarket {
+                amount,
+                price_lots,
+                base_lots,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// `swap_and_exercise` running `route` (its accounts and data) on `jupiter_program`
+    pub async fn swap_and_exercise(
+        &mut self,
+        user: &Keypair,
+        series: &Series,
+        wallet: &Wallet,
+        jupiter_program: Pubkey,
+        route: (Vec<AccountMeta>, Vec<u8>),
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let (route_accounts, route_data) = route;
+        let mut accounts = sol_option_protocol::accounts::SwapAndExercise {
+            option: option_context_accounts(
+                series,
+                &user.pubkey(),
+                owners,
+                wallet,
+                OptionExtras::default(),
+            ),
+            jupiter_program,
+        }
+        .to_account_metas(None);
+        accounts.extend(route_accounts);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: sol_option_protocol::instruction::SwapAndExercise { amount, route_data }.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Like `option_ix`, presenting `credential` (the user's account of a gated
+    /// series' gate mint)
+    pub async fn credentialed_option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+        credential: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let extras = OptionExtras {
+            credential_account: Some(credential),
+            ..OptionExtras::default()
+        };
+        let accounts = option_context_metas_with(series, &user.pubkey(), owners, wallet, extras);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Like `option_ix`, passing the user's WriterPosition so mint/burn record into it
+    pub async fn tracked_option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let position = writer_position_address(series, &user.pubkey());
+        let extras = OptionExtras {
+            writer_position: Some(position),
+            ..OptionExtras::default()
+        };
+        let accounts = option_context_metas_with(series, &user.pubkey(), owners, wallet, extras);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Like `option_ix`, passing the protocol fee account of `fee_mint`
+    /// (created if missing) and the user's fee stats if they opened them
+    pub async fn fee_option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+        fee_mint: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let fee_vault = self.create_ata(fee_mint, &protocol_config_address()).await;
+        let stats = fee_stats_address(&user.pubkey(), &series.consideration_mint);
+        let fee_stats = self
+            .ctx
+            .banks_client
+            .get_account(stats)
+            .await
+            .unwrap()
+            .map(|_| stats);
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let extras = OptionExtras {
+            fee_vault: Some(fee_vault),
+            fee_stats,
+            ..OptionExtras::default()
+        };
+        let accounts = option_context_metas_with(series, &user.pubkey(), owners, wallet, extras);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Like `fee_option_ix`, also passing the insurance fund of `fee_mint` and its
+    /// associated token account
+    pub async fn insured_option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+        fee_mint: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let fee_vault = self.create_ata(fee_mint, &protocol_config_address()).await;
+        let insurance_fund = insurance_fund_address(fee_mint);
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let extras = OptionExtras {
+            fee_vault: Some(fee_vault),
+            insurance_fund: Some(insurance_fund),
+            insurance_vault: Some(get_associated_token_address(&insurance_fund, fee_mint)),
+            ..OptionExtras::default()
+        };
+        let accounts = option_context_metas_with(series, &user.pubkey(), owners, wallet, extras);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// `user` opens their fee stats in `consideration_mint`
+    pub async fn open_fee_stats(&mut self, user: &Keypair, consideration_mint: &Pubkey) -> Pubkey {
+        let fee_stats = fee_stats_address(&user.pubkey(), consideration_mint);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::OpenFeeStats {
+                user: user.pubkey(),
+                consideration_mint: *consideration_mint,
+                fee_stats,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::OpenFeeStats {}.data(),
+        };
+        self.send(&[ix], &[user]).await.unwrap();
+        fee_stats
+    }
+
+    /// Admin replaces the fee volume tiers
+    pub async fn set_fee_tiers(
+        &mut self,
+        tiers: Vec<FeeTier>,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetFeeTiers {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetFeeTiers { tiers }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin replaces the fee-exempt accounts
+    pub async fn set_fee_exempt(
+        &mut self,
+        accounts: Vec<Pubkey>,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetFeeExempt {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetFeeExempt { accounts }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Like `fee_option_ix`, sending the referral share of the fee to `referrer`
+    /// (their associated account, created if missing, and their stats)
+    pub async fn referred_option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+        fee_mint: &Pubkey,
+        referrer: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let fee_vault = self.create_ata(fee_mint, &protocol_config_address()).await;
+        let referrer_account = self.create_ata(fee_mint, referrer).await;
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let extras = OptionExtras {
+            fee_vault: Some(fee_vault),
+            referrer_account: Some(referrer_account),
+            referral_stats: Some(referral_stats_address(referrer, fee_mint)),
+            ..OptionExtras::default()
+        };
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: option_context_metas_with(series, &user.pubkey(), owners, wallet, extras),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// `referrer` opens their referral stats in `mint`
+    pub async fn open_referral_stats(&mut self, referrer: &Keypair, mint: &Pubkey) -> Pubkey {
+        let referral_stats = referral_stats_address(&referrer.pubkey(), mint);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::OpenReferralStats {
+                referrer: referrer.pubkey(),
+                mint: *mint,
+                referral_stats,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::OpenReferralStats {}.data(),
+        };
+        self.send(&[ix], &[referrer]).await.unwrap();
+        referral_stats
+    }
+
+    /// Admin sets the insurance fund's share of protocol fees
+    pub async fn set_insurance_share(
+        &mut self,
+        insurance_share_bps: u16,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetInsuranceShare {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetInsuranceShare {
+                insurance_share_bps,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin opens the insurance fund of `mint`; returns the fund's associated
+    /// token account (created here)
+    pub async fn open_insurance_fund(&mut self, mint: &Pubkey) -> Pubkey {
+        let admin = self.admin.insecure_clone();
+        let insurance_fund = insurance_fund_address(mint);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::OpenInsuranceFund {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                mint: *mint,
+                insurance_fund,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::OpenInsuranceFund {}.data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+        self.create_ata(mint, &insurance_fund).await
+    }
+
+    /// Admin pays `amount` from the insurance fund of `mint` into `vault` of `series`
+    pub async fn pay_insurance(
+        &mut self,
+        series: &Series,
+        mint: &Pubkey,
+        vault: &Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let insurance_fund = insurance_fund_address(mint);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::PayInsurance {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                mint: *mint,
+                insurance_fund,
+                insurance_vault: get_associated_token_address(&insurance_fund, mint),
+                option_context: series.option_context,
+                vault: *vault,
+                token_program: series.token_program,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::PayInsurance { amount }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin sets the referrers' share of fees
+    pub async fn set_referral_share(
+        &mut self,
+        referral_share_bps: u16,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetReferralShare {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetReferralShare { referral_share_bps }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin sets the guardian key
+    pub async fn set_guardian(&mut self, guardian: &Pubkey) {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetGuardian {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetGuardian {
+                guardian: *guardian,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    /// `pauser` (admin or guardian) pauses or resumes the protocol
+    pub async fn set_protocol_paused(
+        &mut self,
+        pauser: &Keypair,
+        paused: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetProtocolPaused {
+                pauser: pauser.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetProtocolPaused { paused }.data(),
+        };
+        self.send(&[ix], &[pauser]).await
+    }
+
+    /// Series `authority` pauses or resumes `series`
+    pub async fn set_series_paused(
+        &mut self,
+        authority: &Keypair,
+        series: &Series,
+        paused: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetSeriesPaused {
+                authority: authority.pubkey(),
+                option_context: series.option_context,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetSeriesPaused { paused }.data(),
+        };
+        self.send(&[ix], &[authority]).await
+    }
+
+    /// `compliance` freezes or thaws `account`, a holder's account of `mint`
+    /// (`series`' option or redemption mint)
+    pub async fn set_token_account_frozen(
+        &mut self,
+        compliance: &Keypair,
+        series: &Series,
+        mint: &Pubkey,
+        account: &Pubkey,
+        frozen: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetTokenAccountFrozen {
+                compliance: compliance.pubkey(),
+                option_context: series.option_context,
+                mint: *mint,
+                token_account: *account,
+                token_program: series.token_program,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetTokenAccountFrozen { frozen }.data(),
+        };
+        self.send(&[ix], &[compliance]).await
+    }
+
+    /// `pauser` (admin or guardian) sets or clears `series`' emergency pause
+    pub async fn set_series_emergency_paused(
+        &mut self,
+        pauser: &Keypair,
+        series: &Series,
+        paused: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetSeriesEmergencyPaused {
+                pauser: pauser.pubkey(),
+                protocol_config: protocol_config_address(),
+                option_context: series.option_context,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetSeriesEmergencyPaused { paused }.data(),
+        };
+        self.send(&[ix], &[pauser]).await
+    }
+
+    /// `guardian` (guardian or admin) freezes a compromised series
+    pub async fn freeze_series(
+        &mut self,
+        guardian: &Keypair,
+        series: &Series,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::FreezeSeries {
+                guardian: guardian.pubkey(),
+                protocol_config: protocol_config_address(),
+                option_context: series.option_context,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::FreezeSeries {}.data(),
+        };
+        self.send(&[ix], &[guardian]).await
+    }
+
+    /// Canonical symbol `describe_series` returns for the series (simulated,
+    /// read from return data)
+    pub async fn describe_series(&mut self, series: &Series) -> String {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::DescribeSeries {
+                option_context: series.option_context,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::DescribeSeries {}.data(),
+        };
+        let payer = self.payer();
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let tx =
+            Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], blockhash);
+        let simulation = self
+            .ctx
+            .banks_client
+            .simulate_transaction(tx)
+            .await
+            .unwrap();
+        simulation.result.unwrap().unwrap();
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, sol_option_protocol::ID);
+        String::try_from_slice(&return_data.data).unwrap()
+    }
+
+    /// Series authority rewrites both mints' token metadata (`metadata`
+    /// overrides the two metadata accounts)
+    pub async fn update_series_metadata(
+        &mut self,
+        authority: &Keypair,
+        series: &Series,
+        metadata: Option<(Pubkey, Pubkey)>,
+    ) -> std::result::Result<(), BanksClientError> {
+        let (option_metadata, redemption_metadata) = metadata.unwrap_or((
+            metadata_address(&series.option_mint),
+            metadata_address(&series.redemption_mint),
+        ));
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::UpdateSeriesMetadata {
+                authority: authority.pubkey(),
+                option_context: series.option_context,
+                option_mint: series.option_mint,
+                redemption_mint: series.redemption_mint,
+                option_metadata,
+                redemption_metadata,
+                token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+                system_program: anchor_lang::system_program::ID,
+                rent: anchor_lang::solana_program::sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::UpdateSeriesMetadata {}.data(),
+        };
+        self.send(&[ix], &[authority]).await
+    }
+
+    /// Admin withdraws `amount` of collected `mint` fees to `destination`
+    pub async fn withdraw_fees(
+        &mut self,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::WithdrawFees {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                mint: *mint,
+                fee_vault: fee_vault_address(mint),
+                destination: *destination,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::WithdrawFees { amount }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// `writer` opens their WriterPosition in `series`
+    pub async fn open_writer_position(&mut self, series: &Series, writer: &Keypair) -> Pubkey {
+        let writer_position = writer_position_address(series, &writer.pubkey());
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::OpenWriterPosition {
+                writer: writer.pubkey(),
+                option_context: series.option_context,
+                writer_position,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::OpenWriterPosition {}.data(),
+        };
+        self.send(&[ix], &[writer]).await.unwrap();
+        writer_position
+    }
+
+    /// Permissionless settle_expiry for a series without oracles
+    pub async fn settle(&mut self, series: &Series) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SettleExpiry {
+                option_context: series.option_context,
+                consideration_mint: series.consideration_mint,
+                collateral_mint: series.collateral_mint,
+                option_mint: series.option_mint,
+                redemption_mint: series.redemption_mint,
+                collateral_vault: series.collateral_vault,
+                consideration_vault: series.consideration_vault,
+                primary_oracle: None,
+                secondary_oracle: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SettleExpiry {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// `attest_settlement` of `series`, posting it to Wormhole with `wormhole`
+    /// (its accounts in declaration order after `system_program`)
+    pub async fn attest_settlement(
+        &mut self,
+        series: &Series,
+        wormhole: Option<[Pubkey; 8]>,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let payer = self.payer();
+        let attestation = derive_settlement_attestation(&series.option_context).0;
+        let account = |index: usize| wormhole.map(|accounts| accounts[index]);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::AttestSettlement {
+                payer: payer.pubkey(),
+                option_context: series.option_context,
+                attestation,
+                system_program: anchor_lang::system_program::ID,
+                wormhole_program: account(0),
+                wormhole_bridge: account(1),
+                wormhole_message: account(2),
+                wormhole_emitter: account(3),
+                wormhole_sequence: account(4),
+                wormhole_fee_collector: account(5),
+                clock: account(6),
+                rent: account(7),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::AttestSettlement {}.data(),
+        };
+        self.send(&[ix], &[]).await.map(|_| attestation)
+    }
+
+    /// settle_expiry for a series whose primary oracle is the Pyth feed `oracle`
+    pub async fn settle_with_oracle(
+        &mut self,
+        series: &Series,
+        oracle: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SettleExpiry {
+                option_context: series.option_context,
+                consideration_mint: series.consideration_mint,
+                collateral_mint: series.collateral_mint,
+                option_mint: series.option_mint,
+                redemption_mint: series.redemption_mint,
+                collateral_vault: series.collateral_vault,
+                consideration_vault: series.consideration_vault,
+                primary_oracle: Some(*oracle),
+                secondary_oracle: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SettleExpiry {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// observe_price for a series whose primary oracle is the Pyth feed `oracle`
+    pub async fn observe_price(
+        &mut self,
+        series: &Series,
+        oracle: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ObservePrice {
+                option_context: series.option_context,
+                consideration_mint: series.consideration_mint,
+                primary_oracle: Some(*oracle),
+                secondary_oracle: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ObservePrice {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// `admin` clears the deviation reference of `series`
+    pub async fn reset_oracle_reference(
+        &mut self,
+        admin: &Keypair,
+        series: &Series,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ResetOracleReference {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                option_context: series.option_context,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ResetOracleReference {}.data(),
+        };
+        self.send(&[ix], &[admin]).await
+    }
+
+    /// record_knockout for a series whose primary oracle is the Pyth feed `oracle`
+    pub async fn record_knockout(
+        &mut self,
+        series: &Series,
+        oracle: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::RecordKnockout {
+                option_context: series.option_context,
+                primary_oracle: Some(*oracle),
+                secondary_oracle: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::RecordKnockout {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// settle_forward crank delivering a forward at its strike
+    pub async fn settle_forward(
+        &mut self,
+        series: &Series,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SettleForward {
+                option_context: series.option_context,
+                option_mint: series.option_mint,
+                redemption_mint: series.redemption_mint,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SettleForward {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// accrue_funding crank for a perpetual series
+    pub async fn accrue_funding(
+        &mut self,
+        series: &Series,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::AccrueFunding {
+                option_context: series.option_context,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::AccrueFunding {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// Writes a fully verified Pyth `PriceUpdateV2` for `feed`: `price` in
+    /// 6-decimal quote units (exponent -6), published at `publish_time`
+    pub async fn set_pyth_price(&mut self, feed: &Pubkey, price: u64, publish_time: i64) {
+        let update = PriceUpdateV2 {
+            write_authority: Pubkey::new_unique(),
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                feed_id: feed.to_bytes(),
+                price: price as i64,
+                conf: 0,
+                exponent: -6,
+                publish_time,
+                prev_publish_time: publish_time - 1,
+                ema_price: price as i64,
+                ema_conf: 0,
+            },
+            posted_slot: 1,
+        };
+        let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        update.serialize(&mut data).unwrap();
+        let account = SolanaAccount {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: PYTH_RECEIVER_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.ctx.set_account(feed, &account.into());
+    }
+
+    /// Writes an up-to-date SPL `StakePool` account at `pool` for `pool_mint`,
+    /// holding `total_lamports` against `pool_token_supply` pool tokens
+    pub async fn set_stake_pool_rate(
+        &mut self,
+        pool: &Pubkey,
+        pool_mint: &Pubkey,
+        total_lamports: u64,
+        pool_token_supply: u64,
+    ) {
+        let clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        let mut data = vec![0u8; MIN_STAKE_POOL_LEN];
+        data[0] = STAKE_POOL_ACCOUNT_TYPE;
+        data[POOL_MINT_OFFSET..POOL_MINT_OFFSET + 32].copy_from_slice(pool_mint.as_ref());
+        data[TOTAL_LAMPORTS_OFFSET..TOTAL_LAMPORTS_OFFSET + 8]
+            .copy_from_slice(&total_lamports.to_le_bytes());
+        data[POOL_TOKEN_SUPPLY_OFFSET..POOL_TOKEN_SUPPLY_OFFSET + 8]
+            .copy_from_slice(&pool_token_supply.to_le_bytes());
+        data[LAST_UPDATE_EPOCH_OFFSET..LAST_UPDATE_EPOCH_OFFSET + 8]
+            .copy_from_slice(&clock.epoch.to_le_bytes());
+        let account = SolanaAccount {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: SPL_STAKE_POOL_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.ctx.set_account(pool, &account.into());
+    }
+
+    /// Stores a Realms `GovernanceV2` account (only its type tag) owned by the
+    /// SPL Governance program and returns its address
+    pub async fn set_governance_account(&mut self) -> Pubkey {
+        let governance = Pubkey::new_unique();
+        let mut data = vec![0u8; 256];
+        data[0] = 18; // GovernanceAccountType::GovernanceV2
+        let account = SolanaAccount {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: SPL_GOVERNANCE_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.ctx.set_account(&governance, &account.into());
+        governance
+    }
+
+    /// Admin nominates `governance`'s native treasury as the next admin
+    pub async fn propose_governance_admin(
+        &mut self,
+        governance: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ProposeGovernanceAdmin {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                governance: *governance,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ProposeGovernanceAdmin {}.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Any `Context<MarginContext>` instruction against `writer`'s position,
+    /// signed by `user` with their wallet (margin moves in the backing asset)
+    pub async fn margin_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        writer: &Pubkey,
+        user: &Keypair,
+        wallet: &Wallet,
+        oracle: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let state: sol_option_protocol::OptionData = self.account(&series.option_context).await;
+        let margin_account = if state.is_put() {
+            wallet.consideration
+        } else {
+            wallet.collateral
+        };
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::MarginContext {
+                user: user.pubkey(),
+                option_context: series.option_context,
+                writer_position: writer_position_address(series, writer),
+                protocol_config: protocol_config_address(),
+                collateral_mint: series.collateral_mint,
+                consideration_mint: series.consideration_mint,
+                option_mint: series.option_mint,
+                margin_vault: state.margin_vault(),
+                user_margin_account: margin_account,
+                user_option_account: wallet.option,
+                primary_oracle: Some(*oracle),
+                secondary_oracle: None,
+                token_program: spl_token::ID,
+                credential_account: None,
+            }
+            .to_account_metas(None),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Any `Context<SpreadContext>` instruction: `writer`'s margin short in
+    /// `short` hedged by longs of `long` held in `wallet` (the user's `long` wallet)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn spread_ix(
+        &mut self,
+        data: impl InstructionData,
+        short: &Series,
+        long: &Series,
+        writer: &Pubkey,
+        user: &Keypair,
+        wallet: &Wallet,
+        oracle: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let short_state: sol_option_protocol::OptionData =
+            self.account(&short.option_context).await;
+        let long_state: sol_option_protocol::OptionData = self.account(&long.option_context).await;
+        let margin_account = if short_state.is_put() {
+            wallet.consideration
+        } else {
+            wallet.collateral
+        };
+        let writer_position = writer_position_address(short, writer);
+        let (spread_escrow, _) = Pubkey::find_program_address(
+            &[WriterPosition::SPREAD_ESCROW_SEED, writer_position.as_ref()],
+            &sol_option_protocol::ID,
+        );
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SpreadContext {
+                user: user.pubkey(),
+                option_context: short.option_context,
+                writer_position,
+                writer: *writer,
+                long_option_context: long.option_context,
+                long_option_mint: long.option_mint,
+                spread_escrow,
+                user_long_option_account: wallet.option,
+                collateral_mint: short.collateral_mint,
+                consideration_mint: short.consideration_mint,
+                margin_vault: short_state.margin_vault(),
+                long_vault: long_state.margin_vault(),
+                user_margin_account: margin_account,
+                primary_oracle: Some(*oracle),
+                secondary_oracle: None,
+                token_program: spl_token::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// roll_position for `user`'s associated accounts (no writer positions)
+    pub async fn roll(
+        &mut self,
+        user: &Keypair,
+        from: &Series,
+        to: &Series,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let from_state: sol_option_protocol::OptionData = self.account(&from.option_context).await;
+        let to_state: sol_option_protocol::OptionData = self.account(&to.option_context).await;
+        let owner = user.pubkey();
+        let escrow_mint = from_state.margin_mint();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::RollPosition {
+                user: owner,
+                from_option_context: from.option_context,
+                from_option_mint: from.option_mint,
+                from_redemption_mint: from.redemption_mint,
+                from_vault: from_state.margin_vault(),
+                to_option_context: to.option_context,
+                protocol_config: protocol_config_address(),
+                to_option_mint: to.option_mint,
+                to_redemption_mint: to.redemption_mint,
+                to_vault: to_state.margin_vault(),
+                escrow_mint,
+                user_escrow_account: get_associated_token_address(&owner, &escrow_mint),
+                user_from_option_account: get_associated_token_address(&owner, &from.option_mint),
+                user_from_redemption_account: get_associated_token_address(
+                    &owner,
+                    &from.redemption_mint,
+                ),
+                user_to_option_account: get_associated_token_address(&owner, &to.option_mint),
+                user_to_redemption_account: get_associated_token_address(
+                    &owner,
+                    &to.redemption_mint,
+                ),
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+                from_writer_position: None,
+                to_writer_position: None,
+                credential_account: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::RollPosition { amount }.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Any `Context<ComboContext>` instruction (create_combo, decompose_combo)
+    /// for `user`'s associated accounts
+    pub async fn combo_ix(
+        &mut self,
+        data: impl InstructionData,
+        call: &Series,
+        put: &Series,
+        user: &Keypair,
+    ) -> std::result::Result<(), BanksClientError> {
+        let (combo, combo_mint) = combo_addresses(call, put);
+        let escrow = |mint: &Pubkey| {
+            Pubkey::find_program_address(
+                &[Combo::ESCROW_SEED, combo.as_ref(), mint.as_ref()],
+                &sol_option_protocol::ID,
+            )
+            .0
+        };
+        let owner = user.pubkey();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ComboContext {
+                user: owner,
+                call_option_context: call.option_context,
+                put_option_context: put.option_context,
+                combo,
+                combo_mint,
+                call_option_mint: call.option_mint,
+                put_option_mint: put.option_mint,
+                call_escrow: escrow(&call.option_mint),
+                put_escrow: escrow(&put.option_mint),
+                user_call_option_account: get_associated_token_address(&owner, &call.option_mint),
+                user_put_option_account: get_associated_token_address(&owner, &put.option_mint),
+                user_combo_account: get_associated_token_address(&owner, &combo_mint),
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Any `Context<VerticalSpreadContext>` instruction for `user`'s associated
+    /// accounts; the writer position is passed when `with_position` is set
+    pub async fn vertical_spread_ix(
+        &mut self,
+        data: impl InstructionData,
+        short: &Series,
+        long: &Series,
+        user: &Keypair,
+        with_position: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let short_state: sol_option_protocol::OptionData =
+            self.account(&short.option_context).await;
+        let long_state: sol_option_protocol::OptionData = self.account(&long.option_context).await;
+        let (spread, spread_mint) = vertical_spread_addresses(short, long);
+        let pda = |seed: &[u8]| {
+            Pubkey::find_program_address(&[seed, spread.as_ref()], &sol_option_protocol::ID).0
+        };
+        let owner = user.pubkey();
+        let backing_mint = short_state.margin_mint();
+        let position = Pubkey::find_program_address(
+            &[
+                VerticalSpreadPosition::SEED,
+                spread.as_ref(),
+                owner.as_ref(),
+            ],
+            &sol_option_protocol::ID,
+        )
+        .0;
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::VerticalSpreadContext {
+                user: owner,
+                short_option_context: short.option_context,
+                long_option_context: long.option_context,
+                protocol_config: protocol_config_address(),
+                vertical_spread: spread,
+                spread_mint,
+                long_option_mint: long.option_mint,
+                backing_mint,
+                long_escrow: pda(VerticalSpread::ESCROW_SEED),
+                spread_vault: pda(VerticalSpread::VAULT_SEED),
+                long_vault: long_state.margin_vault(),
+                user_long_option_account: get_associated_token_address(&owner, &long.option_mint),
+                user_backing_account: get_associated_token_address(&owner, &backing_mint),
+                user_spread_account: get_associated_token_address(&owner, &spread_mint),
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+                position: with_position.then_some(position),
+            }
+            .to_account_metas(None),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Admin points the protocol treasury at `treasury`
+    pub async fn set_treasury(&mut self, treasury: &Pubkey) {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetTreasury {
+                admin: admin.pubkey(),
+                protocol_config,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetTreasury {
+                treasury: *treasury,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    /// Admin sets the unclaimed-funds recovery timeout
+    pub async fn set_unclaimed_timeout(&mut self, unclaimed_timeout: i64) {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetUnclaimedTimeout {
+                admin: admin.pubkey(),
+                protocol_config,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetUnclaimedTimeout { unclaimed_timeout }
+                .data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    /// Admin sets the lamports bonded into each new series
+    pub async fn set_creation_bond(
+        &mut self,
+        creation_bond: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetCreationBond {
+                admin: admin.pubkey(),
+                protocol_config,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetCreationBond { creation_bond }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    pub async fn set_expiry_grid(&mut self, interval: i64, offset: i64, exempt: Vec<Pubkey>) {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetExpiryGrid {
+                admin: admin.pubkey(),
+                protocol_config,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetExpiryGrid {
+                interval,
+                offset,
+                exempt,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    pub async fn set_expiry_limits(&mut self, min_time_to_expiry: i64, max_time_to_expiry: i64) {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetExpiryLimits {
+                admin: admin.pubkey(),
+                protocol_config,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetExpiryLimits {
+                min_time_to_expiry,
+                max_time_to_expiry,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    pub async fn set_strike_tick(&mut self, consideration_mint: &Pubkey, tick_size: u64) {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let (strike_tick, _) = Pubkey::find_program_address(
+            &[StrikeTick::SEED, consideration_mint.as_ref()],
+            &sol_option_protocol::ID,
+        );
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetStrikeTick {
+                admin: admin.pubkey(),
+                protocol_config,
+                consideration_mint: *consideration_mint,
+                strike_tick,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetStrikeTick { tick_size }.data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    /// Admin approves or revokes `mint` for new series
+    pub async fn set_approved_mint(&mut self, mint: &Pubkey, approved: bool) {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetApprovedMint {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                mint: *mint,
+                approved_mint: approved_mint_address(mint),
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetApprovedMint { approved }.data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    /// Admin switches the mint allowlist on or off
+    pub async fn set_mint_allowlist(&mut self, enabled: bool) {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetMintAllowlist {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetMintAllowlist { enabled }.data(),
+        };
+        self.send(&[ix], &[&admin]).await.unwrap();
+    }
+
+    /// Admin sets the timelock on parameter changes
+    pub async fn set_timelock_delay(
+        &mut self,
+        timelock_delay: i64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetTimelockDelay {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetTimelockDelay { timelock_delay }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin schedules `change` under `id`
+    pub async fn schedule_change(
+        &mut self,
+        id: u64,
+        change: ParameterChange,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ScheduleChange {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                pending_change: pending_change_address(id),
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ScheduleChange { id, change }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin applies the change scheduled under `id` (`mint` for mint approvals)
+    pub async fn apply_change(
+        &mut self,
+        id: u64,
+        mint: Option<&Pubkey>,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ApplyChange {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+                pending_change: pending_change_address(id),
+                approved_mint: mint.map(approved_mint_address),
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ApplyChange {}.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// `canceller` (admin or guardian) drops the change scheduled under `id`
+    pub async fn cancel_pending(
+        &mut self,
+        canceller: &Keypair,
+        id: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::CancelPending {
+                canceller: canceller.pubkey(),
+                protocol_config: protocol_config_address(),
+                admin: self.admin.pubkey(),
+                pending_change: pending_change_address(id),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::CancelPending {}.data(),
+        };
+        self.send(&[ix], &[canceller]).await
+    }
+
+    /// Admin sets the mint and exercise fees
+    pub async fn set_fees(
+        &mut self,
+        mint_fee_bps: u16,
+        exercise_fee_bps: u16,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetFees {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetFees {
+                mint_fee_bps,
+                exercise_fee_bps,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin switches off `disabled_features` (`ProtocolConfig::FEATURE_*` bits)
+    pub async fn set_features(
+        &mut self,
+        disabled_features: u32,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetFeatures {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetFeatures { disabled_features }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin allows `allowed_mint_extensions` (`ProtocolConfig::MINT_EXTENSION_*` bits)
+    pub async fn set_mint_extensions(
+        &mut self,
+        allowed_mint_extensions: u32,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetMintExtensions {
+                admin: admin.pubkey(),
+                protocol_config: protocol_config_address(),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetMintExtensions {
+                allowed_mint_extensions,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin sets the substitutes accepted in place of `base_mint`
+    pub async fn set_collateral_basket(
+        &mut self,
+        base_mint: &Pubkey,
+        mints: Vec<Pubkey>,
+    ) -> std::result::Result<(), BanksClientError> {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let admin = self.admin.insecure_clone();
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SetCollateralBasket {
+                admin: admin.pubkey(),
+                protocol_config,
+                base_mint: *base_mint,
+                collateral_basket: collateral_basket_address(base_mint),
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SetCollateralBasket { mints }.data(),
+        };
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// Admin approves (or switches) the lending venue of `escrow_mint`
+    pub async fn set_yield_venue(
+        &mut self,
+        escrow_mint: &Pubkey,
+        params: YieldVenueParams,
+        enabled: bool,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let ix = set_yield_venue_ix(&admin.pubkey(), escrow_mint, params, enabled);
+        self.send(&[ix], &[&admin]).await
+    }
+
+    /// `authority` opts `series` into lending through its escrow mint's venue
+    pub async fn enable_collateral_yield(
+        &mut self,
+        authority: &Keypair,
+        series: &Series,
+        escrow_mint: &Pubkey,
+        share_mint: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::EnableCollateralYield {
+                authority: authority.pubkey(),
+                option_context: series.option_context,
+                yield_venue: derive_yield_venue(escrow_mint).0,
+                share_mint: *share_mint,
+                share_vault: derive_share_vault(&series.option_context).0,
+                token_program: series.token_program,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::EnableCollateralYield {}.data(),
+        };
+        self.send(&[ix], &[authority]).await
+    }
+
+    /// `user` runs `data` (`LendEscrow` or `UnwindEscrow`) on `series`, whose
+    /// escrow is `escrow_vault` and lends through `params`' reserve
+    pub async fn yield_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        escrow_vault: Pubkey,
+        escrow_mint: &Pubkey,
+        params: YieldVenueParams,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::YieldContext {
+                user: user.pubkey(),
+                option_context: series.option_context,
+                yield_venue: derive_yield_venue(escrow_mint).0,
+                escrow_vault,
+                share_vault: derive_share_vault(&series.option_context).0,
+                lending_program: params.lending_program,
+                reserve: params.reserve,
+                reserve_liquidity_supply: params.reserve_liquidity_supply,
+                share_mint: params.share_mint,
+                lending_market: params.lending_market,
+                lending_market_authority: params.lending_market_authority,
+                token_program: series.token_program,
+            }
+            .to_account_metas(None),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// `user` mints `amount` options escrowing `substitute` from `from`
+    /// instead of the series' escrow mint
+    pub async fn mint_with_substitute(
+        &mut self,
+        series: &Series,
+        escrow_mint: &Pubkey,
+        substitute: &Pubkey,
+        user: &Keypair,
+        from: &Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let wallet = wallet_addresses(series, &user.pubkey());
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::MintWithSubstitute {
+                user: user.pubkey(),
+                option_context: series.option_context,
+                consideration_mint: series.consideration_mint,
+                escrow_mint: *escrow_mint,
+                collateral_basket: collateral_basket_address(escrow_mint),
+                protocol_config: protocol_config_address(),
+                substitute_mint: *substitute,
+                basket_vault: basket_vault_address(series, substitute),
+                user_substitute_account: *from,
+                option_mint: series.option_mint,
+                redemption_mint: series.redemption_mint,
+                user_option_account: wallet.option,
+                user_redemption_account: wallet.redemption,
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+                writer_position: None,
+                fee_vault: None,
+                fee_stats: None,
+                referrer_account: None,
+                referral_stats: None,
+                insurance_fund: None,
+                insurance_vault: None,
+                credential_account: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::MintWithSubstitute { amount }.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Like `option_ix` for a series holding `substitutes` (in slot order),
+    /// paying their shares of any escrow payout to `user`'s associated accounts
+    pub async fn basket_option_ix(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        user: &Keypair,
+        wallet: &Wallet,
+        substitutes: &[Pubkey],
+    ) -> std::result::Result<(), BanksClientError> {
+        let owners = self.leg_owners(&user.pubkey(), wallet).await;
+        let mut accounts = option_context_metas(series, &user.pubkey(), owners, wallet);
+        for substitute in substitutes {
+            let destination = self.create_ata(substitute, &user.pubkey()).await;
+            accounts.push(AccountMeta::new_readonly(*substitute, false));
+            accounts.push(AccountMeta::new(
+                basket_vault_address(series, substitute),
+                false,
+            ));
+            accounts.push(AccountMeta::new(destination, false));
+        }
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Lamport balance of `address`
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(*address).await.unwrap()
+    }
+
+    /// Permissionless sweep_dust / recover_unclaimed into `treasury`'s token accounts
+    pub async fn treasury_sweep(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        authority: &Pubkey,
+        treasury: &Wallet,
+    ) -> std::result::Result<(), BanksClientError> {
+        self.basket_treasury_sweep(data, series, authority, treasury, &[])
+            .await
+    }
+
+    /// `treasury_sweep` of a basket series, each `(substitute, treasury account)`
+    /// listed in slot order
+    pub async fn basket_treasury_sweep(
+        &mut self,
+        data: impl InstructionData,
+        series: &Series,
+        authority: &Pubkey,
+        treasury: &Wallet,
+        substitutes: &[(Pubkey, Pubkey)],
+    ) -> std::result::Result<(), BanksClientError> {
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let mut accounts = sol_option_protocol::accounts::SweepDust {
+            protocol_config,
+            option_context: series.option_context,
+            authority: *authority,
+            collateral_mint: series.collateral_mint,
+            consideration_mint: series.consideration_mint,
+            option_mint: series.option_mint,
+            redemption_mint: series.redemption_mint,
+            collateral_vault: series.collateral_vault,
+            consideration_vault: series.consideration_vault,
+            treasury_collateral_account: treasury.collateral,
+            treasury_consideration_account: treasury.consideration,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None);
+        for (substitute, destination) in substitutes {
+            accounts.push(AccountMeta::new_readonly(*substitute, false));
+            accounts.push(AccountMeta::new(
+                basket_vault_address(series, substitute),
+                false,
+            ));
+            accounts.push(AccountMeta::new(*destination, false));
+        }
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts,
+            data: data.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// Permissionless close_series, refunding rent to `authority`
+    pub async fn close_series(
+        &mut self,
+        series: &Series,
+        authority: &Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::CloseSeries {
+                option_context: series.option_context,
+                authority: *authority,
+                option_mint: series.option_mint,
+                redemption_mint: series.redemption_mint,
+                collateral_vault: series.collateral_vault,
+                consideration_vault: series.consideration_vault,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::CloseSeries {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    // === Option vaults ===
+
+    /// `create_option_vault` of `manager` writing `option_type` series
+    pub async fn create_option_vault(
+        &mut self,
+        manager: &Keypair,
+        underlying_mint: Pubkey,
+        quote_mint: Pubkey,
+        option_type: OptionType,
+    ) -> std::result::Result<Vault, BanksClientError> {
+        let vault = vault_addresses(&manager.pubkey(), underlying_mint, quote_mint, option_type);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::CreateOptionVault {
+                manager: manager.pubkey(),
+                underlying_mint,
+                quote_mint,
+                option_vault: vault.option_vault,
+                vault_authority: vault.authority,
+                share_mint: vault.share_mint,
+                vault_underlying_account: vault.underlying_account,
+                vault_quote_account: vault.quote_account,
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::CreateOptionVault { option_type }.data(),
+        };
+        self.send(&[ix], &[manager]).await.map(|_| vault)
+    }
+
+    /// `deposit_to_vault` or `withdraw_from_vault` (`data`) of `user`
+    pub async fn vault_shares_ix(
+        &mut self,
+        data: impl InstructionData,
+        vault: &Vault,
+        user: &Keypair,
+        user_underlying_account: Pubkey,
+        user_quote_account: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::VaultShares {
+                user: user.pubkey(),
+                option_vault: vault.option_vault,
+                vault_authority: vault.authority,
+                underlying_mint: vault.underlying_mint,
+                quote_mint: vault.quote_mint,
+                share_mint: vault.share_mint,
+                vault_underlying_account: vault.underlying_account,
+                vault_quote_account: vault.quote_account,
+                user_underlying_account,
+                user_quote_account,
+                user_share_account: get_associated_token_address_with_program_id(
+                    &user.pubkey(),
+                    &vault.share_mint,
+                    &spl_token::ID,
+                ),
+                token_program: spl_token::ID,
+                associated_token_program: spl_associated_token_account::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: data.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+
+    /// Sends the vault authority lamports for the rent of its round accounts
+    pub async fn fund_vault_authority(&mut self, vault: &Vault) {
+        let ix =
+            system_instruction::transfer(&self.ctx.payer.pubkey(), &vault.authority, 1_000_000_000);
+        self.send(&[ix], &[]).await.unwrap();
+    }
+
+    /// `start_vault_round` writing `amount` options of `series` listed at
+    /// `price` on `market`; returns the ask opened
+    pub async fn start_vault_round(
+        &mut self,
+        manager: &Keypair,
+        vault: &Vault,
+        series: &Series,
+        market: Pubkey,
+        amount: u64,
+        price: u64,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let market_state: spl_marketplace::state::Market = self.account(&market).await;
+        let (order, _) = derive_order(&spl_marketplace::ID, &market, market_state.next_order_id);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::StartVaultRound {
+                manager: manager.pubkey(),
+                option_vault: vault.option_vault,
+                vault_authority: vault.authority,
+                series: vault_series_accounts(series, vault),
+                marketplace_program: spl_marketplace::ID,
+                market,
+                order,
+                escrow: derive_escrow(&spl_marketplace::ID, &order).0,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::StartVaultRound { amount, price }.data(),
+        };
+        self.send(&[ix], &[manager]).await?;
+        Ok(order)
+    }
+
+    /// Permissionless `settle_vault_round` of the round writing `series`
+    pub async fn settle_vault_round(
+        &mut self,
+        vault: &Vault,
+        series: &Series,
+        ask: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::SettleVaultRound {
+                option_vault: vault.option_vault,
+                vault_authority: vault.authority,
+                series: vault_series_accounts(series, vault),
+                marketplace_program: spl_marketplace::ID,
+                ask,
+                ask_escrow: derive_escrow(&spl_marketplace::ID, &ask).0,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::SettleVaultRound {}.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// `list_vault_collateral` of `amount` assigned underlying at `price`
+    /// on `market`; returns the listing opened
+    pub async fn list_vault_collateral(
+        &mut self,
+        manager: &Keypair,
+        vault: &Vault,
+        market: Pubkey,
+        amount: u64,
+        price: u64,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let market_state: spl_marketplace::state::Market = self.account(&market).await;
+        let (order, _) = derive_order(&spl_marketplace::ID, &market, market_state.next_order_id);
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::ListVaultCollateral {
+                manager: manager.pubkey(),
+                option_vault: vault.option_vault,
+                vault_authority: vault.authority,
+                underlying_mint: vault.underlying_mint,
+                vault_underlying_account: vault.underlying_account,
+                marketplace_program: spl_marketplace::ID,
+                market,
+                order,
+                escrow: derive_escrow(&spl_marketplace::ID, &order).0,
+                token_program: spl_token::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::ListVaultCollateral { amount, price }.data(),
+        };
+        self.send(&[ix], &[manager]).await?;
+        Ok(order)
+    }
+
+    /// `close_vault_listing` of `listing`, called by `caller`
+    pub async fn close_vault_listing(
+        &mut self,
+        caller: &Keypair,
+        vault: &Vault,
+        listing: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::CloseVaultListing {
+                caller: caller.pubkey(),
+                option_vault: vault.option_vault,
+                vault_authority: vault.authority,
+                underlying_mint: vault.underlying_mint,
+                vault_underlying_account: vault.underlying_account,
+                marketplace_program: spl_marketplace::ID,
+                listing,
+                listing_escrow: derive_escrow(&spl_marketplace::ID, &listing).0,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::CloseVaultListing {}.data(),
+        };
+        self.send(&[ix], &[caller]).await
+    }
+
+    /// `migrate_series` of `option_context`, paid for by `payer`
+    pub async fn migrate_series(
+        &mut self,
+        payer: &Keypair,
+        option_context: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::MigrateSeries {
+                payer: payer.pubkey(),
+                option_context,
+                system_program: anchor_lang::system_program::ID,
+                collateral_mint: None,
+                consideration_mint: None,
+                collateral_vault: None,
+                consideration_vault: None,
+                protocol_config: None,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::MigrateSeries {}.data(),
+        };
+        self.send(&[ix], &[payer]).await
+    }
+
+    /// `migrate_series` of a legacy `series`, passing the mints, vaults and
+    /// protocol config the legacy step backfills from
+    pub async fn migrate_legacy_series(
+        &mut self,
+        payer: &Keypair,
+        series: &Series,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::MigrateSeries {
+                payer: payer.pubkey(),
+                option_context: series.option_context,
+                system_program: anchor_lang::system_program::ID,
+                collateral_mint: Some(series.collateral_mint),
+                consideration_mint: Some(series.consideration_mint),
+                collateral_vault: Some(series.collateral_vault),
+                consideration_vault: Some(series.consideration_vault),
+                protocol_config: Some(protocol_config_address()),
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::MigrateSeries {}.data(),
+        };
+        self.send(&[ix], &[payer]).await
+    }
+
+    /// Overwrites the stored state of `series` (for states no instruction
+    /// sequence in the harness reaches)
+    pub async fn store_series(&mut self, series: &Series, state: &sol_option_protocol::OptionData) {
+        let mut stored = self
+            .ctx
+            .banks_client
+            .get_account(series.option_context)
+            .await
+            .unwrap()
+            .unwrap();
+        state.try_serialize(&mut &mut stored.data[..]).unwrap();
+        self.ctx.set_account(&series.option_context, &stored.into());
+    }
+
+    /// Cuts the data of the program account at `address` down to `len` bytes
+    /// and its lamports to the rent of that, as an older layout left it
+    pub async fn truncate_account(&mut self, address: &Pubkey, len: usize) {
+        let mut stored = self
+            .ctx
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap();
+        stored.data.truncate(len);
+        stored.lamports = Rent::default().minimum_balance(len);
+        self.ctx.set_account(address, &stored.into());
+    }
+
+    /// Replaces the data of the program account at `address` with `data`
+    /// and its lamports with the rent of that, as an older layout wrote it
+    pub async fn store_account_data(&mut self, address: &Pubkey, data: Vec<u8>) {
+        let mut stored = self
+            .ctx
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap();
+        stored.lamports = Rent::default().minimum_balance(data.len());
+        stored.data = data;
+        self.ctx.set_account(address, &stored.into());
+    }
+
+    // === Marketplace program ===
+
+    pub async fn create_market(
+        &mut self,
+        creator: &Keypair,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+    ) -> Pubkey {
+        let (market, _) = Pubkey::find_program_address(
+            &[b"market", base_mint.as_ref(), quote_mint.as_ref()],
+            &spl_marketplace::ID,
+        );
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::CreateMarket {
+                creator: creator.pubkey(),
+                base_mint,
+                quote_mint,
+                market,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::CreateMarket {}.data(),
+        };
+        self.send(&[ix], &[creator]).await.unwrap();
+        market
+    }
+
+    pub async fn migrate_market(
+        &mut self,
+        payer: &Keypair,
+        market: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::MigrateMarket {
+                payer: payer.pubkey(),
+                market,
+                system_program: anchor_lang::system_program::ID,
+                admin: None,
+                protocol_config: None,
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::MigrateMarket {}.data(),
+        };
+        self.send(&[ix], &[payer]).await
+    }
+
+    /// `migrate_market` of a legacy `market`, signed by the protocol admin
+    /// the legacy step backfills as its authority
+    pub async fn migrate_legacy_market(
+        &mut self,
+        payer: &Keypair,
+        market: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let admin = self.admin.insecure_clone();
+        let (protocol_config, _) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::MigrateMarket {
+                payer: payer.pubkey(),
+                market,
+                system_program: anchor_lang::system_program::ID,
+                admin: Some(admin.pubkey()),
+                protocol_config: Some(protocol_config),
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::MigrateMarket {}.data(),
+        };
+        self.send(&[ix], &[payer, &admin]).await
+    }
+
+    pub async fn link_series(&mut self, authority: &Keypair, market: Pubkey, series: &Series) {
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::LinkSeries {
+                authority: authority.pubkey(),
+                market,
+                series: series.option_context,
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::LinkSeries {}.data(),
+        };
+        self.send(&[ix], &[authority]).await.unwrap();
+    }
+
+    /// `open_series_market` of `series`, returning the market's address
+    pub async fn open_series_market(
+        &mut self,
+        authority: &Keypair,
+        series: &Series,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let (market, _) = derive_market(
+            &spl_marketplace::ID,
+            &series.option_mint,
+            &series.consideration_mint,
+        );
+        let ix = Instruction {
+            program_id: sol_option_protocol::ID,
+            accounts: sol_option_protocol::accounts::OpenSeriesMarket {
+                authority: authority.pubkey(),
+                option_context: series.option_context,
+                option_mint: series.option_mint,
+                consideration_mint: series.consideration_mint,
+                marketplace_program: spl_marketplace::ID,
+                market,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sol_option_protocol::instruction::OpenSeriesMarket {}.data(),
+        };
+        self.send(&[ix], &[authority]).await.map(|_| market)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn place_order(
+        &mut self,
+        user: &Keypair,
+        market: Pubkey,
+        deposit_mint: Pubkey,
+        user_deposit_account: Pubkey,
+        linked_series: Option<Pubkey>,
+        price: u64,
+        size: u64,
+        is_buy: bool,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let market_state: spl_marketplace::state::Market = self.account(&market).await;
+        let (order, _) = Pubkey::find_program_address(
+            &[
+                b"order",
+                market.as_ref(),
+                &market_state.next_order_id.to_le_bytes(),
+            ],
+            &spl_marketplace::ID,
+        );
+        let (escrow, _) =
+            Pubkey::find_program_address(&[b"escrow", order.as_ref()], &spl_marketplace::ID);
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::PlaceOrder {
+                user: user.pubkey(),
+                market,
+                order,
+                deposit_mint,
+                user_deposit_account,
+                escrow,
+                token_program: spl_token::ID,
+                system_program: anchor_lang::system_program::ID,
+                linked_series,
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::PlaceOrder {
+                price,
+                size,
+                is_buy,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[user]).await?;
+        Ok(order)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fill_order(
+        &mut self,
+        taker: &Keypair,
+        market: Pubkey,
+        maker_order: Pubkey,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+        taker_base_account: Pubkey,
+        taker_quote_account: Pubkey,
+        maker_receive_account: Pubkey,
+        linked_series: Option<Pubkey>,
+        fill_size: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let (maker_escrow, _) =
+            Pubkey::find_program_address(&[b"escrow", maker_order.as_ref()], &spl_marketplace::ID);
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::FillOrder {
+                taker: taker.pubkey(),
+                market,
+                maker_order,
+                base_mint,
+                quote_mint,
+                maker_escrow,
+                taker_base_account,
+                taker_quote_account,
+                maker_receive_account,
+                token_program: spl_token::ID,
+                linked_series,
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::FillOrder { fill_size }.data(),
+        };
+        self.send(&[ix], &[taker]).await
+    }
+
+    pub async fn cancel_order(
+        &mut self,
+        user: &Keypair,
+        order: Pubkey,
+        return_mint: Pubkey,
+        user_return_account: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let (escrow, _) =
+            Pubkey::find_program_address(&[b"escrow", order.as_ref()], &spl_marketplace::ID);
+        let ix = Instruction {
+            program_id: spl_marketplace::ID,
+            accounts: spl_marketplace::accounts::CancelOrder {
+                user: user.pubkey(),
+                order,
+                return_mint,
+                user_return_account,
+                escrow,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: spl_marketplace::instruction::CancelOrder {}.data(),
+        };
+        self.send(&[ix], &[user]).await
+    }
+}
+
+/// `create_option` for the series, with the addresses it will derive
+/// (tests that expect a rejection tamper with the instruction before sending)
+pub fn create_series_ix(
+    creator: &Pubkey,
+    collateral_mint: Pubkey,
+    consideration_mint: Pubkey,
+    strike_price: u64,
+    expiration: i64,
+    option_type: OptionType,
+    config: SeriesConfig,
+) -> (Series, Instruction) {
+    create_series_ix_under(
+        &spl_token::ID,
+        creator,
+        collateral_mint,
+        consideration_mint,
+        strike_price,
+        expiration,
+        option_type,
+        config,
+    )
+}
+
+/// `create_series_ix` for mints of `token_program`
+#[allow(clippy::too_many_arguments)]
+pub fn create_series_ix_under(
+    token_program: &Pubkey,
+    creator: &Pubkey,
+    collateral_mint: Pubkey,
+    consideration_mint: Pubkey,
+    strike_price: u64,
+    expiration: i64,
+    option_type: OptionType,
+    config: SeriesConfig,
+) -> (Series, Instruction) {
+    let series = Series {
+        token_program: *token_program,
+        ..series_addresses(
+            collateral_mint,
+            consideration_mint,
+            strike_price,
+            expiration,
+            option_type,
+        )
+    };
+    let (protocol_config, _) =
+        Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+    let (creator_stats, _) = Pubkey::find_program_address(
+        &[b"creator_stats", creator.as_ref()],
+        &sol_option_protocol::ID,
+    );
+    let (strike_tick, _) = Pubkey::find_program_address(
+        &[StrikeTick::SEED, consideration_mint.as_ref()],
+        &sol_option_protocol::ID,
+    );
+    let ix = Instruction {
+        program_id: sol_option_protocol::ID,
+        accounts: sol_option_protocol::accounts::OptionCreate {
+            user: *creator,
+            option_context: series.option_context,
+            protocol_config,
+            creator_stats,
+            collateral_mint,
+            consideration_mint,
+            strike_tick,
+            collateral_approval: approved_mint_address(&collateral_mint),
+            consideration_approval: approved_mint_address(&consideration_mint),
+            option_mint: series.option_mint,
+            redemption_mint: series.redemption_mint,
+            collateral_vault: series.collateral_vault,
+            consideration_vault: series.consideration_vault,
+            system_program: anchor_lang::system_program::ID,
+            token_program: series.token_program,
+            rent: anchor_lang::solana_program::sysvar::rent::ID,
+            token_metadata_program: None,
+            option_metadata: None,
+            redemption_metadata: None,
+        }
+        .to_account_metas(None),
+        data: sol_option_protocol::instruction::CreateOption {
+            collateral_mint,
+            consideration_mint,
+            strike_price,
+            expiration,
+            option_type,
+            config,
+        }
+        .data(),
+    };
+    (series, ix)
+}
+
+pub fn call_or_put(is_put: bool) -> OptionType {
+    if is_put {
+        OptionType::Put
+    } else {
+        OptionType::Call
+    }
+}
+
+pub fn series_addresses(
+    collateral_mint: Pubkey,
+    consideration_mint: Pubkey,
+    strike_price: u64,
+    expiration: i64,
+    option_type: OptionType,
+) -> Series {
+    let (option_context, _) = derive_option_context(
+        &collateral_mint,
+        &consideration_mint,
+        strike_price,
+        expiration,
+        option_type,
+    );
+    let (collateral_vault, consideration_vault) = derive_vaults(&option_context);
+    Series {
+        option_context,
+        collateral_mint,
+        consideration_mint,
+        option_mint: derive_option_mint(&option_context).0,
+        redemption_mint: derive_redemption_mint(&option_context).0,
+        collateral_vault,
+        consideration_vault,
+        token_program: spl_token::ID,
+    }
+}
+
+/// `owner`'s associated token account addresses for the series, without
+/// creating them (the option instructions create missing ones)
+pub fn wallet_addresses(series: &Series, owner: &Pubkey) -> Wallet {
+    Wallet {
+        collateral: series_ata(series, owner, &series.collateral_mint),
+        consideration: series_ata(series, owner, &series.consideration_mint),
+        option: series_ata(series, owner, &series.option_mint),
+        redemption: series_ata(series, owner, &series.redemption_mint),
+    }
+}
+
+/// `owner`'s associated token account for `mint`, under the series' token program
+pub fn series_ata(series: &Series, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, &series.token_program)
+}
+
+/// `leg_owners`: owners of the wallet's (option, redemption) accounts
+pub fn option_context_metas(
+    series: &Series,
+    user: &Pubkey,
+    leg_owners: (Pubkey, Pubkey),
+    wallet: &Wallet,
+) -> Vec<AccountMeta> {
+    option_context_metas_with(series, user, leg_owners, wallet, OptionExtras::default())
+}
+
+/// Optional trailing accounts of `OptionContext`
+#[derive(Clone, Copy, Default)]
+pub struct OptionExtras {
+    pub writer_position: Option<Pubkey>,
+    pub fee_vault: Option<Pubkey>,
+    pub fee_stats: Option<Pubkey>,
+    pub referrer_account: Option<Pubkey>,
+    pub referral_stats: Option<Pubkey>,
+    pub insurance_fund: Option<Pubkey>,
+    pub insurance_vault: Option<Pubkey>,
+    pub credential_account: Option<Pubkey>,
+}
+
+/// Addresses `create_option_vault` derives for a vault of SPL Token mints
+pub fn vault_addresses(
+    manager: &Pubkey,
+    underlying_mint: Pubkey,
+    quote_mint: Pubkey,
+    option_type: OptionType,
+) -> Vault {
+    let program_id = sol_option_protocol::ID;
+    let (option_vault, _) = Pubkey::find_program_address(
+        &[
+            OptionVault::SEED,
+            manager.as_ref(),
+            underlying_mint.as_ref(),
+            quote_mint.as_ref(),
+            &[option_type as u8],
+        ],
+        &program_id,
+    );
+    let (authority, _) = Pubkey::find_program_address(
+        &[OptionVault::AUTHORITY_SEED, option_vault.as_ref()],
+        &program_id,
+    );
+    let (share_mint, _) = Pubkey::find_program_address(
+        &[OptionVault::SHARE_MINT_SEED, option_vault.as_ref()],
+        &program_id,
+    );
+    Vault {
+        option_vault,
+        authority,
+        share_mint,
+        underlying_mint,
+        quote_mint,
+        underlying_account: get_associated_token_address_with_program_id(
+            &authority,
+            &underlying_mint,
+            &spl_token::ID,
+        ),
+        quote_account: get_associated_token_address_with_program_id(
+            &authority,
+            &quote_mint,
+            &spl_token::ID,
+        ),
+    }
+}
+
+/// `VaultSeries` of `series` for a round of `vault`
+pub fn vault_series_accounts(
+    series: &Series,
+    vault: &Vault,
+) -> sol_option_protocol::accounts::VaultSeries {
+    let wallet = wallet_addresses(series, &vault.authority);
+    sol_option_protocol::accounts::VaultSeries {
+        option_context: series.option_context,
+        protocol_config: protocol_config_address(),
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        vault_collateral_account: wallet.collateral,
+        vault_consideration_account: wallet.consideration,
+        vault_option_account: wallet.option,
+        vault_redemption_account: wallet.redemption,
+        token_program: series.token_program,
+        associated_token_program: spl_associated_token_account::ID,
+        system_program: anchor_lang::system_program::ID,
+        fee_vault: None,
+        options_program: sol_option_protocol::ID,
+    }
+}
+
+/// `option_context_metas` with optional accounts
+pub fn option_context_metas_with(
+    series: &Series,
+    user: &Pubkey,
+    leg_owners: (Pubkey, Pubkey),
+    wallet: &Wallet,
+    extras: OptionExtras,
+) -> Vec<AccountMeta> {
+    option_context_accounts(series, user, leg_owners, wallet, extras).to_account_metas(None)
+}
+
+/// `OptionContext` accounts, for instructions nesting them
+pub fn option_context_accounts(
+    series: &Series,
+    user: &Pubkey,
+    leg_owners: (Pubkey, Pubkey),
+    wallet: &Wallet,
+    extras: OptionExtras,
+) -> sol_option_protocol::accounts::OptionContext {
+    sol_option_protocol::accounts::OptionContext {
+        user: *user,
+        option_context: series.option_context,
+        protocol_config: protocol_config_address(),
+        collateral_mint: series.collateral_mint,
+        consideration_mint: series.consideration_mint,
+        option_mint: series.option_mint,
+        redemption_mint: series.redemption_mint,
+        collateral_vault: series.collateral_vault,
+        consideration_vault: series.consideration_vault,
+        option_owner: leg_owners.0,
+        redemption_owner: leg_owners.1,
+        user_collateral_account: wallet.collateral,
+        user_consideration_account: wallet.consideration,
+        user_option_account: wallet.option,
+        user_redemption_account: wallet.redemption,
+        token_program: series.token_program,
+        associated_token_program: spl_associated_token_account::ID,
+        system_program: anchor_lang::system_program::ID,
+        writer_position: extras.writer_position,
+        fee_vault: extras.fee_vault,
+        fee_stats: extras.fee_stats,
+        referrer_account: extras.referrer_account,
+        referral_stats: extras.referral_stats,
+        insurance_fund: extras.insurance_fund,
+        insurance_vault: extras.insurance_vault,
+        credential_account: extras.credential_account,
+    }
+}
+
+/// ApprovedMint PDA of `mint`
+pub fn approved_mint_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ApprovedMint::SEED, mint.as_ref()],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// PendingChange PDA scheduled under `id`
+pub fn pending_change_address(id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PendingChange::SEED, id.to_le_bytes().as_ref()],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// InsuranceFund PDA of `mint`
+pub fn insurance_fund_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[InsuranceFund::SEED, mint.as_ref()],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// ReferralStats PDA of `referrer` in `mint`
+pub fn referral_stats_address(referrer: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ReferralStats::SEED, referrer.as_ref(), mint.as_ref()],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// FeeStats PDA of `user` in `consideration_mint`
+pub fn fee_stats_address(user: &Pubkey, consideration_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[FeeStats::SEED, user.as_ref(), consideration_mint.as_ref()],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// Protocol fee account of `mint` (the ProtocolConfig PDA's associated account)
+pub fn fee_vault_address(mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&protocol_config_address(), mint)
+}
+
+/// The ProtocolConfig singleton
+pub fn protocol_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID).0
+}
+
+/// CollateralBasket PDA of an escrow mint
+pub fn collateral_basket_address(base_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[CollateralBasket::SEED, base_mint.as_ref()],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// `set_yield_venue` signed by `admin`
+pub fn set_yield_venue_ix(
+    admin: &Pubkey,
+    escrow_mint: &Pubkey,
+    params: YieldVenueParams,
+    enabled: bool,
+) -> Instruction {
+    Instruction {
+        program_id: sol_option_protocol::ID,
+        accounts: sol_option_protocol::accounts::SetYieldVenue {
+            admin: *admin,
+            protocol_config: protocol_config_address(),
+            escrow_mint: *escrow_mint,
+            yield_venue: derive_yield_venue(escrow_mint).0,
+            system_program: anchor_lang::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: sol_option_protocol::instruction::SetYieldVenue { params, enabled }.data(),
+    }
+}
+
+/// The series' vault for a substitute mint
+pub fn basket_vault_address(series: &Series, substitute: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            CollateralBasket::VAULT_SEED,
+            series.option_context.as_ref(),
+            substitute.as_ref(),
+        ],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// WriterPosition PDA of `writer` in `series`
+/// Combo PDA of a call/put pair and its combo token mint
+pub fn combo_addresses(call: &Series, put: &Series) -> (Pubkey, Pubkey) {
+    let (combo, _) = Pubkey::find_program_address(
+        &[
+            Combo::SEED,
+            call.option_context.as_ref(),
+            put.option_context.as_ref(),
+        ],
+        &sol_option_protocol::ID,
+    );
+    let (combo_mint, _) = Pubkey::find_program_address(
+        &[Combo::MINT_SEED, combo.as_ref()],
+        &sol_option_protocol::ID,
+    );
+    (combo, combo_mint)
+}
+
+/// Vertical spread PDA of a short/long pair and its spread token mint
+pub fn vertical_spread_addresses(short: &Series, long: &Series) -> (Pubkey, Pubkey) {
+    let (spread, _) = Pubkey::find_program_address(
+        &[
+            VerticalSpread::SEED,
+            short.option_context.as_ref(),
+            long.option_context.as_ref(),
+        ],
+        &sol_option_protocol::ID,
+    );
+    let (spread_mint, _) = Pubkey::find_program_address(
+        &[VerticalSpread::MINT_SEED, spread.as_ref()],
+        &sol_option_protocol::ID,
+    );
+    (spread, spread_mint)
+}
+
+pub fn writer_position_address(series: &Series, writer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            WriterPosition::SEED,
+            series.option_context.as_ref(),
+            writer.as_ref(),
+        ],
+        &sol_option_protocol::ID,
+    )
+    .0
+}
+
+/// Rent-exempt account owned by the options program holding `value`
+pub fn program_account<T: AccountSerialize>(value: &T, init_space: usize) -> SolanaAccount {
+    let mut data = Vec::with_capacity(8 + init_space);
+    value.try_serialize(&mut data).unwrap();
+    data.resize(8 + init_space, 0);
+    SolanaAccount {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: sol_option_protocol::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Events of type `E` among the `emit!` logs ("Program data: <base64>")
+pub fn emitted<E: anchor_lang::Event>(logs: &[String]) -> Vec<E> {
+    use base64::Engine;
+
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| base64::engine::general_purpose::STANDARD.decode(data).ok())
+        .filter(|data| data.starts_with(E::DISCRIMINATOR))
+        .map(|data| E::try_from_slice(&data[E::DISCRIMINATOR.len()..]).unwrap())
+        .collect()
+}
+
+/// Asserts that the transaction failed with the given Anchor error code
+pub fn assert_anchor_error(
+    result: std::result::Result<(), BanksClientError>,
+    code: impl Into<u32>,
+) {
+    use solana_sdk::instruction::InstructionError;
+    use solana_sdk::transaction::TransactionError;
+
+    let expected = code.into();
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(actual),
+        )))
+        | Err(BanksClientError::SimulationError {
+            err: TransactionError::InstructionError(_, InstructionError::Custom(actual)),
+            ..
+        }) => assert_eq!(actual, expected, "unexpected error code"),
+        other => panic!("expected custom error {expected}, got {other:?}"),
+    }
+}
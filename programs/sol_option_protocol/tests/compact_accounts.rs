@@ -0,0 +1,170 @@
+//! Compact entry points: `mint_compact`, `exercise_compact`, `redeem_compact`
+//! and `burn_compact` run the same handlers as their `OptionContext`
+//! counterparts over only the accounts each one touches.
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_spl::token::spl_token;
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::client::{self, SeriesAddresses};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn unique_series() -> SeriesAddresses {
+    SeriesAddresses {
+        option_context: Pubkey::new_unique(),
+        collateral_mint: Pubkey::new_unique(),
+        consideration_mint: Pubkey::new_unique(),
+        option_mint: Pubkey::new_unique(),
+        redemption_mint: Pubkey::new_unique(),
+        collateral_vault: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        token_program: spl_token::ID,
+    }
+}
+
+fn lists(ix: &Instruction, key: &Pubkey) -> bool {
+    ix.accounts.iter().any(|meta| meta.pubkey == *key)
+}
+
+#[test]
+fn compact_instructions_pass_fewer_accounts() {
+    let series = unique_series();
+    let user = Pubkey::new_unique();
+    let full = client::mint_ix(&series, &user, SOL).accounts.len();
+    assert_eq!(full, 26);
+    assert_eq!(
+        client::mint_compact_ix(&series, &user, SOL).accounts.len(),
+        22
+    );
+    assert_eq!(
+        client::exercise_compact_ix(&series, &user, SOL)
+            .accounts
+            .len(),
+        19
+    );
+    assert_eq!(
+        client::redeem_compact_ix(&series, &user, SOL)
+            .accounts
+            .len(),
+        11
+    );
+    assert_eq!(
+        client::burn_compact_ix(&series, &user, SOL).accounts.len(),
+        14
+    );
+}
+
+#[test]
+fn compact_instructions_leave_out_legs_they_dont_touch() {
+    let series = unique_series();
+    let user = Pubkey::new_unique();
+    let exercise = client::exercise_compact_ix(&series, &user, SOL);
+    assert!(!lists(&exercise, &series.redemption_mint));
+    let redeem = client::redeem_compact_ix(&series, &user, SOL);
+    assert!(!lists(&redeem, &series.option_mint));
+    assert!(!lists(&redeem, &client::protocol_config_address()));
+    let burn = client::burn_compact_ix(&series, &user, SOL);
+    assert!(!lists(&burn, &client::protocol_config_address()));
+}
+
+/// Alice writes, burns part back and sells an option to Bob, who exercises it;
+/// Alice redeems the rest after expiry, all through the compact instructions
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn compact_instructions_drive_a_series() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    let addresses = SeriesAddresses::of(series.option_context, &data, series.token_program);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+
+    let mint = client::mint_compact_ix(&addresses, &alice.pubkey(), 3 * SOL);
+    h.send(&[mint], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.option).await, 3 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 3 * SOL);
+
+    let burn = client::burn_compact_ix(&addresses, &alice.pubkey(), SOL);
+    h.send(&[burn], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    let exercise = client::exercise_compact_ix(&addresses, &bob.pubkey(), SOL);
+    h.send(&[exercise], &[&bob]).await.unwrap();
+    assert_eq!(h.balance(&b.collateral).await, SOL);
+    assert_eq!(h.balance(&b.consideration).await, 0);
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let redeem = client::redeem_compact_ix(&addresses, &alice.pubkey(), 2 * SOL);
+    h.send(&[redeem], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 150 * USDC);
+}
+
+/// Without associated account creation, the compact instructions only take
+/// the user's own accounts where they pay or burn
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn compact_burn_needs_the_users_own_accounts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let mallory = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig::default(),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    let addresses = SeriesAddresses::of(series.option_context, &data, series.token_program);
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.wallet(&series, &mallory.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    let mint = client::mint_compact_ix(&addresses, &alice.pubkey(), SOL);
+    h.send(&[mint], &[&alice]).await.unwrap();
+
+    let mut burn = client::burn_compact_ix(&addresses, &mallory.pubkey(), SOL);
+    let option_meta = burn
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == addresses.associated(&mallory.pubkey(), &series.option_mint))
+        .unwrap();
+    option_meta.pubkey = a.option;
+    let result = h.send(&[burn], &[&mallory]).await;
+    assert_anchor_error(result, AnchorErrorCode::ConstraintTokenOwner);
+}
@@ -0,0 +1,77 @@
+//! Contract multiplier: one option token can cover several units of
+//! collateral, like a listed equity option covering 100 shares.
+
+mod common;
+
+use common::{Harness, DAY, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::OptionData;
+use sol_option_protocol::SeriesConfig;
+
+const MULTIPLIER: u64 = 100;
+
+/// Shares have 0 decimals and one contract covers 100 of them: minting,
+/// exercising and burning all move 100 shares (and 100 × the strike) per token.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn amounts_scale_by_the_multiplier() {
+    let mut h = Harness::start().await;
+    let shares = h.create_mint(0).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        contract_multiplier: MULTIPLIER,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, shares, usdc, STRIKE, expiration, false, config)
+        .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.contract_multiplier, MULTIPLIER);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&shares, &a.collateral, 300).await;
+    h.mint_to(&usdc, &a.consideration, 20_000 * USDC).await;
+
+    // 3 contracts lock 300 shares
+    h.option_ix(ix::Mint { amount: 3 }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.option).await, 3);
+    assert_eq!(h.balance(&series.collateral_vault).await, 300);
+
+    // Exercising one contract buys 100 shares for 100 × $150
+    h.option_ix(ix::Exercise { amount: 1 }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.consideration_vault).await, 15_000 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 200);
+
+    // Burning a pair returns the 100 shares behind it
+    h.option_ix(ix::Burn { amount: 1 }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.collateral_vault).await, 100);
+    assert_eq!(h.balance(&a.collateral).await, 200);
+}
+
+/// A zero multiplier in the config is stored as the 1:1 default
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn zero_multiplier_means_one_to_one() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.contract_multiplier, 1);
+}
@@ -0,0 +1,75 @@
+//! create_option cross-checks the mint arguments (which seed the series PDA)
+//! against the mint accounts it is handed.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+// Account order of `OptionCreate`
+const COLLATERAL_MINT_INDEX: usize = 4;
+const CONSIDERATION_MINT_INDEX: usize = 5;
+
+/// Seeds name one pair of mints while the accounts carry another: the series
+/// would be addressed as SOL/USDC but hold something else.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mint_accounts_must_match_arguments() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let other = h.create_mint(9).await;
+    let alice = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+
+    let (_, mut ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig::default(),
+    );
+    ix.accounts[COLLATERAL_MINT_INDEX].pubkey = other;
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::InvalidUnderlyingMint);
+
+    let (_, mut ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig::default(),
+    );
+    ix.accounts[CONSIDERATION_MINT_INDEX].pubkey = other;
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::InvalidStrikeCurrency);
+}
+
+/// A series can't write options on a mint against itself
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mints_must_differ() {
+    let mut h = Harness::start().await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+
+    let (_, ix) = create_series_ix(
+        &alice.pubkey(),
+        usdc,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig::default(),
+    );
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::IdenticalMints);
+}
@@ -0,0 +1,61 @@
+//! The admin-configured creation bond: locked in each new series and refunded
+//! to the series authority when the series is closed.
+
+mod common;
+
+use common::{Harness, DAY, SOL, STRIKE};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::OptionData;
+
+/// With a 1 SOL bond, Alice's series holds the bond on top of its rent until
+/// she redeems everything and the series is swept.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn bond_is_held_until_the_series_is_closed() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let treasury = h.actor().await;
+    h.set_creation_bond(LAMPORTS_PER_SOL).await.unwrap();
+    h.set_treasury(&treasury.pubkey()).await;
+
+    // --- Creation moves the bond into the series account ----------------------
+    let before = h.lamports(&alice.pubkey()).await;
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    assert!(before - h.lamports(&alice.pubkey()).await > LAMPORTS_PER_SOL);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.creation_bond, LAMPORTS_PER_SOL);
+    let bonded = h.lamports(&series.option_context).await;
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let t = h.wallet(&series, &treasury.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    h.option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    // --- Closing the series refunds it to the authority -----------------------
+    let before = h.lamports(&alice.pubkey()).await;
+    h.treasury_sweep(ix::SweepDust {}, &series, &alice.pubkey(), &t)
+        .await
+        .unwrap();
+    assert_eq!(
+        h.lamports(&series.option_context).await,
+        bonded - LAMPORTS_PER_SOL
+    );
+    assert!(h.lamports(&alice.pubkey()).await - before > LAMPORTS_PER_SOL);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.creation_bond, 0);
+}
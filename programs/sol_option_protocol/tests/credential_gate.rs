@@ -0,0 +1,119 @@
+//! Credential gates: a series created with a `gate_mint` only lets users who
+//! hold that credential (e.g. a KYC NFT) mint or exercise. They present their
+//! token account of the gate mint as the trailing `credential_account`.
+
+mod common;
+
+use anchor_spl::token_2022::spl_token_2022::state::{Account as TokenState, AccountState};
+use common::{create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::utils::validation::validate_credential;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn gated(gate_mint: Pubkey) -> OptionData {
+    OptionData {
+        gate_mint,
+        ..OptionData::default()
+    }
+}
+
+fn credential(mint: Pubkey, owner: Pubkey, amount: u64) -> TokenState {
+    TokenState {
+        mint,
+        owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenState::default()
+    }
+}
+
+#[test]
+fn open_series_need_no_credential() {
+    let holder = Pubkey::new_unique();
+    assert!(validate_credential(&gated(Pubkey::default()), &holder, None).is_ok());
+}
+
+#[test]
+fn gated_series_need_the_holders_credential() {
+    let gate = Pubkey::new_unique();
+    let holder = Pubkey::new_unique();
+    let series = gated(gate);
+
+    let held = credential(gate, holder, 1);
+    assert!(validate_credential(&series, &holder, Some(&held)).is_ok());
+    assert!(validate_credential(&series, &holder, None).is_err());
+
+    let other_mint = credential(Pubkey::new_unique(), holder, 1);
+    let borrowed = credential(gate, Pubkey::new_unique(), 1);
+    let empty = credential(gate, holder, 0);
+    let revoked = TokenState {
+        state: AccountState::Frozen,
+        ..held
+    };
+    for account in [other_mint, borrowed, empty, revoked] {
+        assert!(validate_credential(&series, &holder, Some(&account)).is_err());
+    }
+}
+
+/// Only holders of the gate token write or exercise; Bob can still receive
+/// options, but must get the credential before he exercises them
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn gated_series_admit_credential_holders() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let kyc = h.create_mint(0).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            gate_mint: kyc,
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    assert_eq!(data.gate_mint, kyc);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+
+    // Alice has no credential yet
+    assert!(h
+        .option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .is_err());
+    let alice_kyc = h.create_ata(&kyc, &alice.pubkey()).await;
+    h.mint_to(&kyc, &alice_kyc, 1).await;
+    h.credentialed_option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a, alice_kyc)
+        .await
+        .unwrap();
+
+    // Bob can't exercise with Alice's credential, only with his own
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    assert!(h
+        .credentialed_option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b, alice_kyc)
+        .await
+        .is_err());
+    let bob_kyc = h.create_ata(&kyc, &bob.pubkey()).await;
+    h.mint_to(&kyc, &bob_kyc, 1).await;
+    h.credentialed_option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b, bob_kyc)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, SOL);
+}
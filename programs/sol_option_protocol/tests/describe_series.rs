@@ -0,0 +1,96 @@
+//! describe_series: the canonical descriptor of a series (ticker, strike at
+//! its exponent, expiry, type), formatted by the same helper as its token
+//! metadata and returned through return data.
+
+mod common;
+
+use common::{create_series_ix, Harness, DAY, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instructions::PERPETUAL_EXPIRATION;
+use sol_option_protocol::utils::symbol::{
+    format_expiry, format_option_symbol, series_symbol, type_code,
+};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+const FEB_28_2025: i64 = 1_740_700_800;
+
+fn series(option_type: OptionType) -> OptionData {
+    OptionData {
+        option_type,
+        underlying_symbol: "BONK".to_string(),
+        strike_price: 40_000,
+        strike_exponent: 6,
+        expiration: FEB_28_2025,
+        ..OptionData::default()
+    }
+}
+
+#[test]
+fn every_type_has_its_own_code() {
+    let symbols = [
+        (OptionType::Call, "BONK-28FEB25-0.04-C"),
+        (OptionType::Put, "BONK-28FEB25-0.04-P"),
+        (OptionType::BinaryCall, "BONK-28FEB25-0.04-BC"),
+        (OptionType::BinaryPut, "BONK-28FEB25-0.04-BP"),
+        (OptionType::Forward, "BONK-28FEB25-0.04-F"),
+    ];
+    for (option_type, symbol) in symbols {
+        assert_eq!(series_symbol(&series(option_type)), symbol);
+        assert!(symbol.ends_with(type_code(option_type)));
+    }
+}
+
+#[test]
+fn calls_and_puts_match_the_parameter_form() {
+    for (option_type, is_put) in [(OptionType::Call, false), (OptionType::Put, true)] {
+        assert_eq!(
+            series_symbol(&series(option_type)),
+            format_option_symbol("bonk", 40_000, 6, FEB_28_2025, is_put)
+        );
+    }
+}
+
+#[test]
+fn strike_follows_the_exponent_and_perpetuals_show_perp() {
+    // Same raw strike quoted with two more decimals
+    let finer = OptionData {
+        strike_exponent: 8,
+        ..series(OptionType::Call)
+    };
+    assert_eq!(series_symbol(&finer), "BONK-28FEB25-0.0004-C");
+
+    let perpetual = OptionData {
+        expiration: PERPETUAL_EXPIRATION,
+        ..series(OptionType::Put)
+    };
+    assert_eq!(series_symbol(&perpetual), "BONK-PERP-0.04-P");
+}
+
+/// A SOL call at $150 describes itself as SOL-{expiry}-150-C
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn describe_series_returns_the_symbol() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "sol".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+
+    let symbol = h.describe_series(&series).await;
+    assert_eq!(symbol, format!("SOL-{}-150-C", format_expiry(expiration)));
+}
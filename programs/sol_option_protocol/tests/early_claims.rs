@@ -0,0 +1,189 @@
+//! redeem_consideration: writers burn redemption tokens for exercise proceeds
+//! before expiry, one exercised unit per token.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::OptionData;
+
+/// Alice writes 10 calls and Bob exercises 4. Alice converts the 4 exercised
+/// units to cash early, then redeems the 6 collateral-backed tokens at expiry.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn covered_call_early_consideration_claim() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    // --- Alice converts 3 exercised units: 3 x 150 USDC ------------------------
+    h.option_ix(
+        ix::RedeemConsideration { amount: 3 * SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 450 * USDC);
+    assert_eq!(h.balance(&a.redemption).await, 7 * SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, 150 * USDC);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.exercised_claimed, 3 * SOL);
+    assert_eq!(state.consideration_claimed, 450 * USDC);
+    assert_eq!(state.collateral_redeemed, 0);
+
+    // --- Only 1 exercised unit is left to convert -------------------------------
+    let result = h
+        .option_ix(
+            ix::RedeemConsideration { amount: 2 * SOL },
+            &series,
+            &alice,
+            &a,
+        )
+        .await;
+    assert_anchor_error(result, ErrorCode::InsufficientExercisedAmount);
+    h.option_ix(ix::RedeemConsideration { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 600 * USDC);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+
+    // --- The remaining 6 tokens are exactly covered by 6 SOL ---------------------
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 6 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 6 * SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+    assert_eq!(h.supply(&series.redemption_mint).await, 0);
+}
+
+/// Alice writes 10 calls and Bob exercises 4, so 40% of her position is cash.
+/// Claiming the exercised share of 5 tokens burns 2 of them for 300 USDC.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn exercised_share_keeps_collateral_fraction() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    h.option_ix(
+        ix::RedeemExercisedShare { amount: 5 * SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 300 * USDC);
+    assert_eq!(h.balance(&a.redemption).await, 8 * SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 6 * SOL);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.exercised_claimed, 2 * SOL);
+}
+
+/// Early claims burn redemption tokens without touching `total_supply`, so the
+/// two diverge. Alice and Carol each write 5 calls, Bob exercises 4 of Alice's
+/// and she converts them early: 6 shorts remain against 6 SOL, and settlement
+/// shares follow the redemption mint, not the 10 options ever written.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn payouts_follow_redemption_supply() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    let c = h.wallet(&series, &carol.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 5 * SOL).await;
+    h.mint_to(&sol, &c.collateral, 5 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &carol, &c)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    h.option_ix(
+        ix::RedeemConsideration { amount: 4 * SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.total_supply, 10 * SOL);
+    assert_eq!(state.settled_supply, 6 * SOL);
+    assert_eq!(state.settled_collateral, 6 * SOL);
+
+    h.option_ix(ix::Redeem { amount: 5 * SOL }, &series, &carol, &c)
+        .await
+        .unwrap();
+    h.option_ix(ix::Redeem { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&c.collateral).await, 5 * SOL);
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+    assert_eq!(h.balance(&a.consideration).await, 600 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
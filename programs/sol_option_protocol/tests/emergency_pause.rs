@@ -0,0 +1,120 @@
+//! Emergency pauses: the admin or guardian can halt mint and exercise across
+//! the protocol or in one series, while burn and redeem stay open so holders
+//! and writers can always exit.
+
+mod common;
+
+use common::{assert_anchor_error, protocol_config_address, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::ProtocolConfig;
+use sol_option_protocol::OptionData;
+
+#[test]
+fn either_pause_flag_halts_a_series() {
+    let mut series = OptionData::default();
+    assert!(!series.is_paused());
+    series.status = OptionData::STATUS_PAUSED;
+    assert!(series.is_paused());
+    series.status = OptionData::STATUS_EMERGENCY_PAUSED;
+    assert!(series.is_paused());
+    // Clearing the authority's flag leaves the emergency pause in place
+    series.status &= !OptionData::STATUS_PAUSED;
+    assert!(series.is_paused());
+}
+
+/// Carol, the guardian, pauses the protocol: Alice can't write and Bob can't
+/// exercise, but Alice still burns pairs. Once resumed both go through.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn protocol_pause_blocks_mint_and_exercise() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+    h.set_guardian(&carol.pubkey()).await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+
+    let result = h.set_protocol_paused(&bob, true).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+    h.set_protocol_paused(&carol, true).await.unwrap();
+    let state: ProtocolConfig = h.account(&protocol_config_address()).await;
+    assert!(state.paused);
+
+    let result = h
+        .option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::ProtocolPaused);
+    let result = h
+        .option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::ProtocolPaused);
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    h.set_protocol_paused(&carol, false).await.unwrap();
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 4 * SOL);
+}
+
+/// The admin halts one series. Alice, its authority, can't lift the emergency
+/// pause, but can still burn and, after expiry, redeem.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn series_emergency_pause_outlasts_the_authority() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let admin = h.admin.insecure_clone();
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    let result = h.set_series_emergency_paused(&alice, &series, true).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+    h.set_series_emergency_paused(&admin, &series, true)
+        .await
+        .unwrap();
+    h.set_series_paused(&alice, &series, false).await.unwrap();
+    let result = h
+        .option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesPaused);
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 4 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 10 * SOL);
+}
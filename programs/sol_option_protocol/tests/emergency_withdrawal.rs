@@ -0,0 +1,91 @@
+//! Emergency withdrawals: which series vaults an admin can queue one from,
+//! and how a withdrawal is split between donations, the vault's ledger
+//! bucket and margin.
+
+use anchor_lang::prelude::Pubkey;
+
+use sol_option_protocol::state::{split_withdrawal, CollateralBasket, WithdrawalVault, YieldVenue};
+use sol_option_protocol::OptionData;
+
+fn series() -> (OptionData, Pubkey) {
+    let series = OptionData {
+        collateral_vault: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        ..Default::default()
+    };
+    (series, Pubkey::new_unique())
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &sol_option_protocol::ID).0
+}
+
+#[test]
+fn primary_vaults_are_withdrawable() {
+    let (series, key) = series();
+    let mint = Pubkey::new_unique();
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &series.collateral_vault, &mint),
+        Some(WithdrawalVault::Collateral)
+    );
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &series.consideration_vault, &mint),
+        Some(WithdrawalVault::Consideration)
+    );
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &Pubkey::new_unique(), &mint),
+        None
+    );
+}
+
+#[test]
+fn basket_and_share_vaults_are_withdrawable() {
+    let (mut series, key) = series();
+    let usdt = Pubkey::new_unique();
+    let basket_vault = pda(&[CollateralBasket::VAULT_SEED, key.as_ref(), usdt.as_ref()]);
+    let share_vault = pda(&[YieldVenue::SHARE_VAULT_SEED, key.as_ref()]);
+    let share_mint = Pubkey::new_unique();
+
+    // Not before the series holds the substitute or lends
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &basket_vault, &usdt),
+        None
+    );
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &share_vault, &share_mint),
+        None
+    );
+
+    series.basket_mints[1] = usdt;
+    series.yield_venue = Pubkey::new_unique();
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &basket_vault, &usdt),
+        Some(WithdrawalVault::Basket(1))
+    );
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &share_vault, &share_mint),
+        Some(WithdrawalVault::YieldShares)
+    );
+    // Another series' basket vault, or this one under the wrong mint
+    let other = pda(&[
+        CollateralBasket::VAULT_SEED,
+        Pubkey::new_unique().as_ref(),
+        usdt.as_ref(),
+    ]);
+    assert_eq!(WithdrawalVault::of(&series, &key, &other, &usdt), None);
+    assert_eq!(
+        WithdrawalVault::of(&series, &key, &basket_vault, &Pubkey::new_unique()),
+        None
+    );
+}
+
+#[test]
+fn donations_leave_first_then_the_bucket_then_margin() {
+    // 100 held: 60 tracked, 30 margin, 10 donated
+    assert_eq!(split_withdrawal(5, 100, 60, 30), (0, 0));
+    assert_eq!(split_withdrawal(50, 100, 60, 30), (40, 0));
+    assert_eq!(split_withdrawal(90, 100, 60, 30), (60, 20));
+    assert_eq!(split_withdrawal(100, 100, 60, 30), (60, 30));
+    // A vault short of its ledger has no donations to spend
+    assert_eq!(split_withdrawal(50, 40, 60, 0), (50, 0));
+}
@@ -0,0 +1,99 @@
+//! European series: exercise only inside `[expiration, expiration + window)`,
+//! redemption only after the window closes.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{ExerciseStyle, SeriesConfig};
+
+const BONK: u64 = 100_000; // collateral, 5 decimals
+const STRIKE: u64 = 40_000; // $0.04
+const WINDOW: u32 = 60 * 60;
+
+/// Alice writes 1,000 European calls and sells 400 to Bob. Bob can only
+/// exercise once expiry arrives; the series settles once the window closes.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn european_exercise_window() {
+    let mut h = Harness::start().await;
+    let bonk = h.create_mint(5).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        exercise_style: ExerciseStyle::European,
+        exercise_window: WINDOW,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, bonk, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&bonk, &a.collateral, 1_000 * BONK).await;
+    h.mint_to(&usdc, &b.consideration, 100 * USDC).await;
+
+    h.option_ix(
+        ix::Mint {
+            amount: 1_000 * BONK,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 400 * BONK).await;
+
+    // Failing attempts use distinct amounts so no transaction repeats a signature
+    // --- Before expiry: no exercise ----------------------------------------------
+    let result = h
+        .option_ix(ix::Exercise { amount: 100 * BONK }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::OutsideExerciseWindow);
+
+    // --- Inside the window: Bob exercises, Alice cannot redeem yet -----------------
+    h.warp_to(expiration).await;
+    h.option_ix(ix::Exercise { amount: 400 * BONK }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&b.collateral).await, 400 * BONK);
+    assert_eq!(h.balance(&b.consideration).await, 84 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 600 * BONK);
+    assert_eq!(h.balance(&series.consideration_vault).await, 16 * USDC);
+
+    let result = h.settle(&series).await;
+    assert_anchor_error(result, ErrorCode::ExerciseWindowOpen);
+
+    // --- After the window: exercise closed, redemption open ------------------------
+    h.warp_to(expiration + WINDOW as i64).await;
+    h.transfer(&alice, &a.option, &b.option, 50 * BONK).await;
+    let result = h
+        .option_ix(ix::Exercise { amount: 50 * BONK }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::OutsideExerciseWindow);
+
+    h.settle(&series).await.unwrap();
+    h.option_ix(
+        ix::Redeem {
+            amount: 1_000 * BONK,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(h.balance(&a.collateral).await, 600 * BONK);
+    assert_eq!(h.balance(&a.consideration).await, 16 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+}
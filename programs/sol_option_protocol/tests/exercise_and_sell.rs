@@ -0,0 +1,178 @@
+//! Flash exercise: `exercise_and_sell` pays a call's collateral out first,
+//! sells it into a resting marketplace bid and only then collects the strike,
+//! so an in-the-money holder needs no consideration up front.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, Series, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+async fn series(h: &mut Harness, writer: &Keypair, option_type: OptionType) -> Series {
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &writer.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        option_type,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[writer]).await.unwrap();
+    series
+}
+
+/// Bob holds one call and no USDC; Carol bids 200 USDC for a SOL, so Bob
+/// exercises, sells the SOL to her and keeps the 50 USDC of intrinsic value
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn holders_exercise_without_funding_the_strike() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+    let series = series(&mut h, &alice, OptionType::Call).await;
+    let (sol, usdc) = (series.collateral_mint, series.consideration_mint);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    h.option_ix(
+        sol_option_protocol::instruction::Mint { amount: SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+
+    let market = h.create_market(&carol, sol, usdc).await;
+    let carol_sol = h.create_ata(&sol, &carol.pubkey()).await;
+    let carol_usdc = h.create_ata(&usdc, &carol.pubkey()).await;
+    h.mint_to(&usdc, &carol_usdc, 200 * USDC).await;
+    let bid = h
+        .place_order(
+            &carol,
+            market,
+            usdc,
+            carol_usdc,
+            None,
+            200 * USDC,
+            SOL,
+            true,
+        )
+        .await
+        .unwrap();
+
+    h.exercise_and_sell(&bob, &series, &b, market, bid, carol_sol, SOL, SOL)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.option).await, 0);
+    assert_eq!(h.balance(&b.collateral).await, 0);
+    assert_eq!(h.balance(&b.consideration).await, 50 * USDC);
+    assert_eq!(h.balance(&carol_sol).await, SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, STRIKE);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
+
+/// A bid below the strike can't cover it: nothing is exercised or sold
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn bids_below_the_strike_fail_the_exercise() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let carol = h.actor().await;
+    let series = series(&mut h, &alice, OptionType::Call).await;
+    let (sol, usdc) = (series.collateral_mint, series.consideration_mint);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    h.option_ix(
+        sol_option_protocol::instruction::Mint { amount: SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    let market = h.create_market(&carol, sol, usdc).await;
+    let carol_sol = h.create_ata(&sol, &carol.pubkey()).await;
+    let carol_usdc = h.create_ata(&usdc, &carol.pubkey()).await;
+    h.mint_to(&usdc, &carol_usdc, 100 * USDC).await;
+    let bid = h
+        .place_order(
+            &carol,
+            market,
+            usdc,
+            carol_usdc,
+            None,
+            100 * USDC,
+            SOL,
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert!(h
+        .exercise_and_sell(&alice, &series, &a, market, bid, carol_sol, SOL, SOL)
+        .await
+        .is_err());
+    assert_eq!(h.balance(&a.option).await, SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, SOL);
+    assert_eq!(h.balance(&carol_sol).await, 0);
+}
+
+/// Puts pay out the strike, not collateral, so there is nothing to sell
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn puts_cannot_be_flash_exercised() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let carol = h.actor().await;
+    let series = series(&mut h, &alice, OptionType::Put).await;
+    let (sol, usdc) = (series.collateral_mint, series.consideration_mint);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&usdc, &a.consideration, STRIKE).await;
+    h.option_ix(
+        sol_option_protocol::instruction::Mint { amount: SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    let market = h.create_market(&carol, sol, usdc).await;
+    let carol_sol = h.create_ata(&sol, &carol.pubkey()).await;
+    let carol_usdc = h.create_ata(&usdc, &carol.pubkey()).await;
+    h.mint_to(&usdc, &carol_usdc, 200 * USDC).await;
+    let bid = h
+        .place_order(
+            &carol,
+            market,
+            usdc,
+            carol_usdc,
+            None,
+            200 * USDC,
+            SOL,
+            true,
+        )
+        .await
+        .unwrap();
+
+    let result = h
+        .exercise_and_sell(&alice, &series, &a, market, bid, carol_sol, SOL, SOL)
+        .await;
+    assert_anchor_error(result, ErrorCode::FlashExerciseNeedsCall);
+}
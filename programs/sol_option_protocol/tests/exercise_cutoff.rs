@@ -0,0 +1,66 @@
+//! American exercise closes at expiration, or `exercise_cutoff` seconds
+//! before it when the series configures one.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::SeriesConfig;
+
+const CUTOFF: u32 = 60 * 60;
+
+/// Bob holds 3 calls on a series with a one-hour cutoff: he can exercise
+/// two hours before expiry, but not inside the last hour or after expiry.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn american_exercise_cutoff() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        exercise_cutoff: CUTOFF,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 450 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 3 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 3 * SOL).await;
+
+    // --- Before the cutoff: exercise works --------------------------------------
+    h.warp_to(expiration - 2 * CUTOFF as i64).await;
+    h.option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, SOL);
+    assert_eq!(h.balance(&b.consideration).await, 300 * USDC);
+
+    // --- Inside the last hour: closed -------------------------------------------
+    h.warp_to(expiration - CUTOFF as i64 / 2).await;
+    let result = h
+        .option_ix(ix::Exercise { amount: SOL / 2 }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::ExerciseCutoffPassed);
+
+    // --- After expiry: expired, vault untouched ---------------------------------
+    h.warp_to(expiration + 1).await;
+    let result = h
+        .option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::OptionExpired);
+    assert_eq!(h.balance(&series.collateral_vault).await, 2 * SOL);
+}
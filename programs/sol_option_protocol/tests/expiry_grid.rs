@@ -0,0 +1,54 @@
+//! The admin-configured expiry grid: new series must expire on it unless
+//! their creator is allowlisted.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+const WEEK: i64 = 7 * DAY;
+/// Friday 1970-01-02 08:00 UTC
+const FRIDAY_0800: i64 = DAY + 8 * 60 * 60;
+
+/// Weekly Friday 08:00 UTC grid: Alice can only list a Friday expiry, while
+/// the allowlisted market maker can list any time.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn expirations_must_fall_on_the_grid() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let maker = h.actor().await;
+    h.set_expiry_grid(WEEK, FRIDAY_0800, vec![maker.pubkey()])
+        .await;
+
+    let now = h.now().await;
+    let friday = now + WEEK - (now - FRIDAY_0800).rem_euclid(WEEK);
+    let off_grid = friday + 60 * 60;
+
+    let (_, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        off_grid,
+        OptionType::Call,
+        SeriesConfig::default(),
+    );
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::ExpirationOffGrid);
+
+    h.create_series(&alice, sol, usdc, STRIKE, friday, false)
+        .await;
+    h.create_series(&maker, sol, usdc, STRIKE, off_grid, false)
+        .await;
+
+    // Disabling the grid lets anyone list any expiration again
+    h.set_expiry_grid(0, 0, vec![]).await;
+    h.create_series(&alice, sol, usdc, STRIKE, off_grid, true)
+        .await;
+}
@@ -0,0 +1,42 @@
+//! Admin-configured time-to-expiry limits for new series.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, HOUR, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+/// Between 1 hour and 2 years: a 5-second or a 3-year series is rejected,
+/// anything in between lists.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn expirations_must_be_within_limits() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    h.set_expiry_limits(HOUR, 2 * 365 * DAY).await;
+
+    let now = h.now().await;
+    for (expiration, error) in [
+        (now + 5, ErrorCode::ExpirationTooSoon),
+        (now + 3 * 365 * DAY, ErrorCode::ExpirationTooFar),
+    ] {
+        let (_, ix) = create_series_ix(
+            &alice.pubkey(),
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::Call,
+            SeriesConfig::default(),
+        );
+        let result = h.send(&[ix], &[&alice]).await;
+        assert_anchor_error(result, error);
+    }
+
+    h.create_series(&alice, sol, usdc, STRIKE, now + 30 * DAY, false)
+        .await;
+}
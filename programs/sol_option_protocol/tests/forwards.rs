@@ -0,0 +1,156 @@
+//! Forwards: fully funded contracts with no optionality. Writers escrow the
+//! collateral and its strike value; at expiry holders take the collateral and
+//! writers the strike, whatever the price.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::PERPETUAL_EXPIRATION;
+use sol_option_protocol::utils::netting::calculate_forward_settlement;
+use sol_option_protocol::utils::validation::validate_forward_config;
+use sol_option_protocol::{ExerciseStyle, OptionData, OptionType, SeriesConfig};
+
+#[test]
+fn forwards_escrow_collateral_and_strike() {
+    let series = OptionData {
+        option_type: OptionType::Forward,
+        strike_price: STRIKE,
+        strike_scale: 9,
+        contract_multiplier: 1,
+        ..OptionData::default()
+    };
+    assert_eq!(OptionType::try_from(4).unwrap(), OptionType::Forward);
+    assert!(!series.escrows_consideration());
+    assert_eq!(series.escrow_locked(10 * SOL).unwrap(), 10 * SOL);
+    assert_eq!(series.strike_locked(10 * SOL).unwrap(), 1_500 * USDC);
+    assert_eq!(series.strike_payout(SOL).unwrap(), 150 * USDC);
+
+    // Other types lock nothing beyond their escrow
+    let call = OptionData {
+        option_type: OptionType::Call,
+        ..series
+    };
+    assert_eq!(call.strike_locked(10 * SOL).unwrap(), 0);
+}
+
+#[test]
+fn delivery_ignores_the_price() {
+    let net = calculate_forward_settlement(10 * SOL, 1_500 * USDC);
+    assert_eq!(net.option_collateral, 10 * SOL);
+    assert_eq!(net.redemption_consideration, 1_500 * USDC);
+    assert_eq!(net.redemption_collateral, 0);
+    assert_eq!(net.option_consideration, 0);
+}
+
+#[test]
+fn forward_config_is_validated() {
+    let american = ExerciseStyle::American;
+    assert!(validate_forward_config(OptionType::Forward, 30 * DAY, american, 0, 0).is_ok());
+    assert!(
+        validate_forward_config(OptionType::Forward, PERPETUAL_EXPIRATION, american, 0, 0).is_err()
+    );
+    let european = ExerciseStyle::European;
+    assert!(validate_forward_config(OptionType::Forward, 30 * DAY, european, 0, 0).is_err());
+    assert!(validate_forward_config(OptionType::Forward, 30 * DAY, american, 2_000, 0).is_err());
+    assert!(validate_forward_config(OptionType::Forward, 30 * DAY, american, 0, 200).is_err());
+    // Options aren't affected
+    assert!(validate_forward_config(OptionType::Call, 30 * DAY, european, 0, 0).is_ok());
+}
+
+/// Alice writes 10 forwards at $150, locking 10 SOL and 1,500 USDC, and
+/// sells 4 to Bob. Nobody can exercise or settle them on a price; after
+/// expiry Bob takes 4 SOL, and Alice her 6 SOL plus the 1,500 USDC.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn forwards_deliver_at_the_strike() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_typed_series(
+            &alice,
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::Forward,
+            SeriesConfig::default(),
+        )
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 11 * SOL).await;
+    h.mint_to(&usdc, &a.consideration, 1_650 * USDC).await;
+    h.option_ix(ix::Mint { amount: 11 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.collateral_vault).await, 11 * SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, 1_650 * USDC);
+
+    // Burning a pair hands back both legs
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+    assert_eq!(h.balance(&a.consideration).await, 150 * USDC);
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+
+    let result = h
+        .option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::ForwardSettlementOnly);
+
+    h.warp_to(expiration + 1).await;
+    let result = h.settle(&series).await;
+    assert_anchor_error(result, ErrorCode::ForwardSettlementOnly);
+    h.settle_forward(&series).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.settlement_price, STRIKE);
+    assert_eq!(state.net_option_collateral, 10 * SOL);
+    assert_eq!(state.settled_consideration, 1_500 * USDC);
+
+    h.option_ix(ix::ClaimNetPayoff { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 4 * SOL);
+    h.option_ix(ix::ClaimNetPayoff { amount: 6 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.option_ix(ix::Redeem { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 7 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 1_650 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+}
+
+/// A forward has to settle, so it can't be perpetual
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn perpetual_forwards_are_rejected() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let (_, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        PERPETUAL_EXPIRATION,
+        OptionType::Forward,
+        SeriesConfig::default(),
+    );
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::InvalidForwardConfig);
+}
@@ -0,0 +1,200 @@
+//! Freeze authority: regulated series make their PDA freeze authority of the
+//! option and redemption mints. Their compliance key (the series authority
+//! unless one was named) freezes and thaws holders' accounts through
+//! `set_token_account_frozen`.
+
+mod common;
+
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::state::{Account, AccountState, Mint};
+use common::{create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::utils::validation::validate_freeze_config;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn regulated(freezable: bool, compliance_authority: Pubkey) -> SeriesConfig {
+    SeriesConfig {
+        underlying_symbol: "SOL".to_string(),
+        freezable,
+        compliance_authority,
+        ..SeriesConfig::default()
+    }
+}
+
+#[test]
+fn only_freezable_series_name_a_compliance_authority() {
+    let compliance = Pubkey::new_unique();
+    assert!(validate_freeze_config(true, &compliance).is_ok());
+    assert!(validate_freeze_config(true, &Pubkey::default()).is_ok());
+    assert!(validate_freeze_config(false, &Pubkey::default()).is_ok());
+    assert!(validate_freeze_config(false, &compliance).is_err());
+}
+
+#[test]
+fn compliance_key_falls_back_to_the_series_authority() {
+    let authority = Pubkey::new_unique();
+    let series = OptionData {
+        authority,
+        freezable: true,
+        ..OptionData::default()
+    };
+    assert_eq!(series.compliance_key(), authority);
+
+    let compliance = Pubkey::new_unique();
+    let delegated = OptionData {
+        compliance_authority: compliance,
+        ..series
+    };
+    assert_eq!(delegated.compliance_key(), compliance);
+}
+
+async fn freeze_authority_of(h: &mut Harness, mint: &Pubkey) -> COption<Pubkey> {
+    let account = h
+        .ctx
+        .banks_client
+        .get_account(*mint)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .base
+        .freeze_authority
+}
+
+async fn is_frozen(h: &mut Harness, token_account: &Pubkey) -> bool {
+    let account = h
+        .ctx
+        .banks_client
+        .get_account(*token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .state
+        == AccountState::Frozen
+}
+
+/// The compliance key freezes Bob's options, which blocks his exercise until
+/// it thaws them; the series authority can't freeze in its place
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn compliance_key_freezes_and_thaws_holders() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let compliance = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        regulated(true, compliance.pubkey()),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    for mint in [series.option_mint, series.redemption_mint] {
+        assert_eq!(
+            freeze_authority_of(&mut h, &mint).await,
+            COption::Some(series.option_context)
+        );
+    }
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+
+    let option_mint = series.option_mint;
+    assert!(h
+        .set_token_account_frozen(&alice, &series, &option_mint, &b.option, true)
+        .await
+        .is_err());
+    h.set_token_account_frozen(&compliance, &series, &option_mint, &b.option, true)
+        .await
+        .unwrap();
+    assert!(is_frozen(&mut h, &b.option).await);
+    assert!(h
+        .option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b)
+        .await
+        .is_err());
+
+    h.set_token_account_frozen(&compliance, &series, &option_mint, &b.option, false)
+        .await
+        .unwrap();
+    assert!(!is_frozen(&mut h, &b.option).await);
+    h.option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, SOL);
+}
+
+/// Series created without the flag have mints nobody can freeze
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn unfreezable_series_have_no_freeze_authority() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        regulated(false, Pubkey::default()),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    assert_eq!(
+        freeze_authority_of(&mut h, &series.option_mint).await,
+        COption::None
+    );
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let redemption_mint = series.redemption_mint;
+    assert!(h
+        .set_token_account_frozen(&alice, &series, &redemption_mint, &a.redemption, true)
+        .await
+        .is_err());
+}
+
+/// A compliance authority without the freezable flag is rejected at creation
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn compliance_authority_needs_the_flag() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (_, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        regulated(false, Pubkey::new_unique()),
+    );
+    assert!(h.send(&[ix], &[&alice]).await.is_err());
+}
@@ -0,0 +1,67 @@
+//! Realms governance as protocol admin: the admin nominates a DAO's native
+//! treasury, and proposals then execute admin instructions signed by it.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::{assert_anchor_error, protocol_config_address, Harness};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::governance::{
+    governance_treasury, native_treasury_address, SPL_GOVERNANCE_PROGRAM_ID,
+};
+use sol_option_protocol::state::ProtocolConfig;
+
+fn treasury_of(tag: u8, owner: Pubkey) -> Result<Pubkey> {
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = vec![0u8; 64];
+    data[0] = tag;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    let treasury = governance_treasury(&account)?;
+    assert_eq!(treasury, native_treasury_address(&owner, &key));
+    Ok(treasury)
+}
+
+#[test]
+fn only_governance_accounts_name_a_treasury() {
+    // GovernanceV2 under mainnet Realms or a DAO's own deployment
+    assert!(treasury_of(18, SPL_GOVERNANCE_PROGRAM_ID).is_ok());
+    assert!(treasury_of(3, Pubkey::new_unique()).is_ok());
+    // A RealmV2 or ProposalV2 isn't a governance
+    assert!(treasury_of(16, SPL_GOVERNANCE_PROGRAM_ID).is_err());
+    assert!(treasury_of(14, SPL_GOVERNANCE_PROGRAM_ID).is_err());
+    // Wallets and this program's own accounts can't stand in for one
+    assert!(treasury_of(18, System::id()).is_err());
+    assert!(treasury_of(18, sol_option_protocol::ID).is_err());
+}
+
+/// The admin nominates the DAO's treasury; a wallet isn't accepted as a governance
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn admin_nominates_a_dao_treasury() {
+    let mut h = Harness::start().await;
+    let governance = h.set_governance_account().await;
+    let wallet = h.actor().await;
+
+    let result = h.propose_governance_admin(&wallet.pubkey()).await;
+    assert_anchor_error(result, ErrorCode::InvalidGovernance);
+
+    h.propose_governance_admin(&governance).await.unwrap();
+    let state: ProtocolConfig = h.account(&protocol_config_address()).await;
+    assert_eq!(
+        state.pending_admin,
+        native_treasury_address(&SPL_GOVERNANCE_PROGRAM_ID, &governance)
+    );
+    assert_eq!(state.admin, h.admin.pubkey());
+}
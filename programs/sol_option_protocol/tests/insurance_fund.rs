@@ -0,0 +1,127 @@
+//! Insurance fund: a share of the protocol's part of each fee is paid into the
+//! fund of the charged mint, and the admin pays it out to series vaults that
+//! hold less than their accounting says.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{
+    assert_anchor_error, fee_vault_address, insurance_fund_address, Harness, DAY, SOL, STRIKE, USDC,
+};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::{InsuranceFund, ParameterChange};
+use sol_option_protocol::utils::validation::validate_insurance_share;
+use sol_option_protocol::{OptionData, OptionType};
+
+#[test]
+fn insurance_shares_are_capped() {
+    assert!(validate_insurance_share(0).is_ok());
+    assert!(validate_insurance_share(10_000).is_ok());
+    assert!(validate_insurance_share(10_001).is_err());
+    let change = ParameterChange::InsuranceShare {
+        insurance_share_bps: 10_001,
+    };
+    assert!(change.validate().is_err());
+}
+
+#[test]
+fn shortfalls_are_measured_against_the_accounting() {
+    let mut series = OptionData {
+        collateral_vault: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        collateral_deposited: 10 * SOL,
+        collateral_paid: 2 * SOL,
+        consideration_received: 300 * USDC,
+        ..OptionData::default()
+    };
+    let collateral_vault = series.collateral_vault;
+    let consideration_vault = series.consideration_vault;
+    assert_eq!(series.vault_shortfall(&collateral_vault, 8 * SOL), 0);
+    assert_eq!(series.vault_shortfall(&collateral_vault, 9 * SOL), 0);
+    assert_eq!(series.vault_shortfall(&collateral_vault, 7 * SOL), SOL);
+    assert_eq!(
+        series.vault_shortfall(&consideration_vault, 299 * USDC),
+        USDC
+    );
+
+    // Margin sits in the backing vault on top of the accounted escrow
+    series.margin_collateral = SOL;
+    assert_eq!(series.vault_shortfall(&collateral_vault, 8 * SOL), SOL);
+    series.option_type = OptionType::Put;
+    assert_eq!(series.vault_shortfall(&collateral_vault, 8 * SOL), 0);
+
+    // Other accounts are never short
+    let other = Pubkey::new_unique();
+    assert_eq!(series.vault_shortfall(&other, 0), 0);
+
+    let mut fund = InsuranceFund {
+        mint: other,
+        contributed: u64::MAX,
+        paid_out: 0,
+        bump: 255,
+    };
+    assert!(fund.record_contribution(1).is_err());
+    fund.record_payout(5).unwrap();
+    assert_eq!(fund.paid_out, 5);
+}
+
+/// With a 0.1% mint fee and a 20% insurance share, Alice writing 10 calls
+/// pays 0.002 SOL into the SOL fund and 0.008 SOL to the fee account. When
+/// 0.001 SOL goes missing from the collateral vault, the admin covers exactly
+/// that from the fund.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn fees_fund_insurance_that_covers_shortfalls() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    h.set_fees(10, 0).await.unwrap();
+    let result = h.set_insurance_share(10_001).await;
+    assert_anchor_error(result, ErrorCode::InvalidInsuranceShare);
+    h.set_insurance_share(2_000).await.unwrap();
+    let insurance_vault = h.open_insurance_fund(&sol).await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 11 * SOL).await;
+
+    // An insurance share is due, so the fund is required
+    let result = h
+        .fee_option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a, &sol)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidInsuranceFund);
+    h.insured_option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a, &sol)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&insurance_vault).await, 2_000_000);
+    assert_eq!(h.balance(&fee_vault_address(&sol)).await, 8_000_000);
+    let fund: InsuranceFund = h.account(&insurance_fund_address(&sol)).await;
+    assert_eq!(fund.contributed, 2_000_000);
+
+    // Nothing is missing yet
+    let result = h
+        .pay_insurance(&series, &sol, &series.collateral_vault, 1)
+        .await;
+    assert_anchor_error(result, ErrorCode::NoVaultShortfall);
+
+    h.set_token_balance(&series.collateral_vault, 10 * SOL - 1_000_000)
+        .await;
+    let result = h
+        .pay_insurance(&series, &sol, &series.collateral_vault, 1_000_001)
+        .await;
+    assert_anchor_error(result, ErrorCode::NoVaultShortfall);
+    h.pay_insurance(&series, &sol, &series.collateral_vault, 1_000_000)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.collateral_vault).await, 10 * SOL);
+    assert_eq!(h.balance(&insurance_vault).await, 1_000_000);
+    let fund: InsuranceFund = h.account(&insurance_fund_address(&sol)).await;
+    assert_eq!(fund.paid_out, 1_000_000);
+}
@@ -0,0 +1,154 @@
+//! Series invariants: live vaults cover the series' accounting, supplies stay
+//! within its counters, and escrow covers the options outstanding.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{SOL, USDC};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::invariants::{assert_series_invariants, SeriesBalances};
+use sol_option_protocol::{OptionData, OptionType};
+
+/// 3 SOL calls struck at $150: one exercised, its strike not yet claimed
+fn call_series() -> OptionData {
+    OptionData {
+        collateral_vault: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        strike_price: 150 * USDC,
+        strike_scale: 9,
+        contract_multiplier: 1,
+        total_supply: 3 * SOL,
+        exercised_amount: SOL,
+        collateral_deposited: 3 * SOL,
+        collateral_paid: SOL,
+        consideration_received: 150 * USDC,
+        ..OptionData::default()
+    }
+}
+
+fn balances() -> SeriesBalances {
+    SeriesBalances {
+        collateral_vault: Some(2 * SOL),
+        consideration_vault: Some(150 * USDC),
+        option_supply: Some(2 * SOL),
+        redemption_supply: Some(3 * SOL),
+    }
+}
+
+fn violated(series: &OptionData, balances: &SeriesBalances) -> bool {
+    assert_series_invariants(series, balances)
+        .is_err_and(|error| error == ErrorCode::InvariantViolated.into())
+}
+
+#[test]
+fn consistent_series_passes() {
+    assert!(assert_series_invariants(&call_series(), &balances()).is_ok());
+
+    // Donations to the vaults don't break anything
+    let donated = SeriesBalances {
+        collateral_vault: Some(5 * SOL),
+        ..balances()
+    };
+    assert!(assert_series_invariants(&call_series(), &donated).is_ok());
+
+    // Accounts the handler didn't take are skipped
+    assert!(assert_series_invariants(&call_series(), &SeriesBalances::default()).is_ok());
+}
+
+#[test]
+fn vaults_must_hold_what_is_accounted() {
+    let short = SeriesBalances {
+        collateral_vault: Some(2 * SOL - 1),
+        ..balances()
+    };
+    assert!(violated(&call_series(), &short));
+
+    let short = SeriesBalances {
+        consideration_vault: Some(150 * USDC - 1),
+        ..balances()
+    };
+    assert!(violated(&call_series(), &short));
+
+    // Margin sits in the backing vault on top of the escrow; lent escrow doesn't
+    let margin = OptionData {
+        margin_collateral: SOL,
+        ..call_series()
+    };
+    assert!(violated(&margin, &balances()));
+    let lent = OptionData {
+        yield_principal: SOL,
+        ..margin
+    };
+    assert!(assert_series_invariants(&lent, &balances()).is_ok());
+}
+
+#[test]
+fn payouts_never_exceed_deposits() {
+    let overpaid = OptionData {
+        collateral_paid: 4 * SOL,
+        ..call_series()
+    };
+    assert!(violated(&overpaid, &SeriesBalances::default()));
+}
+
+#[test]
+fn supplies_stay_within_the_counters() {
+    let minted_twice = SeriesBalances {
+        option_supply: Some(2 * SOL + 1),
+        ..balances()
+    };
+    assert!(violated(&call_series(), &minted_twice));
+
+    // Claimed exercises burned their redemption tokens
+    let claimed = OptionData {
+        exercised_claimed: SOL,
+        ..call_series()
+    };
+    assert!(violated(&claimed, &balances()));
+    let burned = SeriesBalances {
+        redemption_supply: Some(2 * SOL),
+        ..balances()
+    };
+    assert!(assert_series_invariants(&claimed, &burned).is_ok());
+
+    let over_claimed = OptionData {
+        exercised_claimed: 2 * SOL,
+        ..call_series()
+    };
+    assert!(violated(&over_claimed, &SeriesBalances::default()));
+}
+
+#[test]
+fn escrow_covers_outstanding_options_until_settlement() {
+    // A put writer's 300 USDC cover 2 SOL of puts at $150, not 3
+    let put = OptionData {
+        option_type: OptionType::Put,
+        total_supply: 3 * SOL,
+        exercised_amount: 0,
+        collateral_deposited: 0,
+        collateral_paid: 0,
+        consideration_received: 300 * USDC,
+        ..call_series()
+    };
+    let outstanding = |options| SeriesBalances {
+        option_supply: Some(options),
+        ..SeriesBalances::default()
+    };
+    assert!(assert_series_invariants(&put, &outstanding(2 * SOL)).is_ok());
+    assert!(violated(&put, &outstanding(3 * SOL)));
+
+    // Options written on margin are backed by the positions instead
+    let margin = OptionData {
+        margin_written: SOL,
+        ..put.clone()
+    };
+    assert!(assert_series_invariants(&margin, &outstanding(3 * SOL)).is_ok());
+
+    // Settlement nets the options against the vaults
+    let settled = OptionData {
+        settled_at: 1,
+        ..put
+    };
+    assert!(assert_series_invariants(&settled, &outstanding(3 * SOL)).is_ok());
+}
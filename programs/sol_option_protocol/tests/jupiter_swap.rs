@@ -0,0 +1,105 @@
+//! Jupiter swaps: `swap_and_exercise` runs a route quoted off-chain into what
+//! exercising costs (the strike for calls, the collateral for puts) and then
+//! exercises, so holders can pay with whatever token their wallet holds.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, SOL, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::jupiter::{route_instruction, JUPITER_PROGRAM_ID};
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+#[test]
+fn routes_keep_their_account_flags() {
+    let (user, pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let owner = Pubkey::new_unique();
+    let (mut user_lamports, mut pool_lamports) = (0, 0);
+    let (mut user_data, mut pool_data) = (vec![], vec![]);
+    let accounts = [
+        AccountInfo::new(
+            &user,
+            true,
+            true,
+            &mut user_lamports,
+            &mut user_data,
+            &owner,
+            false,
+            0,
+        ),
+        AccountInfo::new(
+            &pool,
+            false,
+            false,
+            &mut pool_lamports,
+            &mut pool_data,
+            &owner,
+            false,
+            0,
+        ),
+    ];
+
+    let data = vec![0xe5, 0x17, 0xcb, 0x97, 0x7a, 0xe3, 0xad, 0x2a, 1];
+    let ix = route_instruction(&accounts, data.clone()).unwrap();
+    assert_eq!(ix.program_id, JUPITER_PROGRAM_ID);
+    assert_eq!(ix.data, data);
+    assert_eq!(
+        ix.accounts,
+        vec![
+            AccountMeta::new(user, true),
+            AccountMeta::new_readonly(pool, false)
+        ]
+    );
+}
+
+#[test]
+fn routes_need_an_instruction_discriminator() {
+    assert_eq!(
+        route_instruction(&[], vec![1, 2, 3]).unwrap_err(),
+        error!(ErrorCode::InvalidSwapRoute)
+    );
+}
+
+/// A route can only run on Jupiter itself, never on a program of the caller's choosing
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn other_programs_cannot_stand_in_for_jupiter() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        150 * USDC,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    h.option_ix(
+        sol_option_protocol::instruction::Mint { amount: SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    let route = (vec![AccountMeta::new(a.consideration, false)], vec![0; 8]);
+    let result = h
+        .swap_and_exercise(&alice, &series, &a, spl_marketplace::ID, route, SOL)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidSwapProgram);
+    assert_eq!(h.balance(&a.option).await, SOL);
+}
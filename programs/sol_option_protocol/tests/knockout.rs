@@ -0,0 +1,126 @@
+//! Knock-out barriers: once the oracle shows the barrier breached, anyone can
+//! kill the series. Nothing can be minted or exercised afterwards, and the
+//! vaults go back to the writers.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::oracle::{OracleFeed, OracleKind};
+use sol_option_protocol::utils::validation::validate_knockout_barrier;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+const BARRIER: u64 = 200 * USDC;
+
+fn pyth(feed: Pubkey) -> OracleFeed {
+    OracleFeed {
+        kind: OracleKind::Pyth,
+        feed,
+        ..OracleFeed::default()
+    }
+}
+
+#[test]
+fn barrier_is_breached_at_or_beyond_it() {
+    let mut series = OptionData {
+        strike_price: STRIKE,
+        knockout_barrier: BARRIER,
+        ..OptionData::default()
+    };
+    assert!(!series.barrier_breached(BARRIER - 1));
+    assert!(series.barrier_breached(BARRIER));
+    assert!(series.barrier_breached(BARRIER + 1));
+
+    // Down-and-out put
+    series.option_type = OptionType::Put;
+    series.knockout_barrier = 100 * USDC;
+    assert!(series.barrier_breached(100 * USDC));
+    assert!(!series.barrier_breached(100 * USDC + 1));
+
+    // No barrier, never breached
+    series.knockout_barrier = 0;
+    assert!(!series.barrier_breached(0));
+}
+
+#[test]
+fn barrier_must_lie_beyond_the_strike() {
+    let oracle = pyth(Pubkey::new_unique());
+    assert!(validate_knockout_barrier(OptionType::Call, STRIKE, BARRIER, &oracle).is_ok());
+    assert!(validate_knockout_barrier(OptionType::Call, STRIKE, STRIKE, &oracle).is_err());
+    assert!(validate_knockout_barrier(OptionType::Put, STRIKE, BARRIER, &oracle).is_err());
+    assert!(validate_knockout_barrier(OptionType::Put, STRIKE, 100 * USDC, &oracle).is_ok());
+    // Someone has to be able to read the barrier
+    let none = OracleFeed::default();
+    assert!(validate_knockout_barrier(OptionType::Call, STRIKE, BARRIER, &none).is_err());
+    assert!(validate_knockout_barrier(OptionType::Call, STRIKE, 0, &none).is_ok());
+}
+
+/// Alice writes 10 up-and-out calls (barrier $200) and sells 4 to Bob. SOL
+/// trades at $210 before expiry: the series is knocked out, settles at once,
+/// and Alice redeems all 10 SOL while Bob's options are worth nothing.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn knocked_out_series_returns_collateral_to_writers() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let oracle = Pubkey::new_unique();
+
+    let now = h.now().await;
+    let expiration = now + 30 * DAY;
+    let config = SeriesConfig {
+        primary_oracle: pyth(oracle),
+        knockout_barrier: BARRIER,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 11 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 1_000 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+
+    // Below the barrier nothing happens
+    h.set_pyth_price(&oracle, 190 * USDC, now).await;
+    let result = h.record_knockout(&series, &oracle).await;
+    assert_anchor_error(result, ErrorCode::BarrierNotBreached);
+
+    h.next_blockhash().await;
+    h.set_pyth_price(&oracle, 210 * USDC, now).await;
+    h.record_knockout(&series, &oracle).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert!(state.is_knocked_out());
+    assert_eq!(state.knocked_out_at, now);
+
+    let result = h
+        .option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesKnockedOut);
+    let result = h
+        .option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesKnockedOut);
+
+    // No need to wait for expiry
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::ClaimNetPayoff { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 0);
+    h.option_ix(ix::Redeem { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 11 * SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
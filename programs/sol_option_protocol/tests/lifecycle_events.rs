@@ -0,0 +1,152 @@
+//! Lifecycle events: creating a series and every mint, burn, exercise, early
+//! claim, redemption and netted payoff claim emit a structured event with the series, the user,
+//! the amounts moved and the resulting supply, so indexers don't parse logs.
+
+mod common;
+
+use anchor_lang::Event;
+use base64::Engine;
+use common::{create_series_ix, emitted, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::client::{self, SeriesAddresses};
+use sol_option_protocol::events::{
+    ConsiderationClaimed, NetPayoffClaimed, OptionsExercised, OptionsMinted, PairBurned, Redeemed,
+    SeriesCreated,
+};
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+#[test]
+fn events_are_read_back_from_program_data_logs() {
+    let event = OptionsMinted {
+        option_context: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        amount: 2 * SOL,
+        escrow: 2 * SOL,
+        total_supply: 5 * SOL,
+    };
+    let logs = vec![
+        "Program log: Minting 2000000000 option tokens to user".to_string(),
+        format!(
+            "Program data: {}",
+            base64::engine::general_purpose::STANDARD.encode(event.data())
+        ),
+    ];
+    let minted = emitted::<OptionsMinted>(&logs);
+    assert_eq!(minted.len(), 1);
+    assert_eq!(minted[0].option_context, event.option_context);
+    assert_eq!(minted[0].total_supply, 5 * SOL);
+    assert!(emitted::<PairBurned>(&logs).is_empty());
+}
+
+/// Alice writes 3 calls and burns one pair back, Bob exercises one, Alice
+/// claims its strike early, then redeems the last pair and claims the payoff
+/// of its option (worthless without an oracle) after expiry
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn every_lifecycle_action_emits_its_event() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, create) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    let logs = h.send_logged(&[create], &[&alice]).await.unwrap();
+    let created = emitted::<SeriesCreated>(&logs);
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].option_context, series.option_context);
+    assert_eq!(created[0].authority, alice.pubkey());
+    assert_eq!(created[0].option_mint, series.option_mint);
+    assert_eq!(
+        (created[0].strike_price, created[0].expiration),
+        (STRIKE, expiration)
+    );
+
+    let data: OptionData = h.account(&series.option_context).await;
+    let addresses = SeriesAddresses::of(series.option_context, &data, series.token_program);
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+
+    let mint = client::mint_ix(&addresses, &alice.pubkey(), 3 * SOL);
+    let logs = h.send_logged(&[mint], &[&alice]).await.unwrap();
+    let minted = emitted::<OptionsMinted>(&logs);
+    assert_eq!(minted.len(), 1);
+    assert_eq!(minted[0].user, alice.pubkey());
+    assert_eq!((minted[0].amount, minted[0].escrow), (3 * SOL, 3 * SOL));
+    assert_eq!(minted[0].total_supply, 3 * SOL);
+
+    let accounts = client::option_context_accounts(&addresses, &alice.pubkey());
+    let burn = client::option_ix(accounts, ix::Burn { amount: SOL });
+    let logs = h.send_logged(&[burn], &[&alice]).await.unwrap();
+    let burned = emitted::<PairBurned>(&logs);
+    assert_eq!(burned.len(), 1);
+    assert_eq!((burned[0].amount, burned[0].refund), (SOL, SOL));
+    assert_eq!(
+        (burned[0].strike_refund, burned[0].total_supply),
+        (0, 2 * SOL)
+    );
+
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    let exercise = client::exercise_ix(&addresses, &bob.pubkey(), SOL);
+    let logs = h.send_logged(&[exercise], &[&bob]).await.unwrap();
+    let exercised = emitted::<OptionsExercised>(&logs);
+    assert_eq!(exercised.len(), 1);
+    assert_eq!(exercised[0].user, bob.pubkey());
+    assert_eq!(
+        (exercised[0].amount, exercised[0].strike_payment),
+        (SOL, 150 * USDC)
+    );
+    assert_eq!(
+        (exercised[0].exercised_amount, exercised[0].option_supply),
+        (SOL, SOL)
+    );
+
+    let accounts = client::option_context_accounts(&addresses, &alice.pubkey());
+    let claim = client::option_ix(accounts, ix::RedeemConsideration { amount: SOL });
+    let logs = h.send_logged(&[claim], &[&alice]).await.unwrap();
+    let claimed = emitted::<ConsiderationClaimed>(&logs);
+    assert_eq!(claimed.len(), 1);
+    assert_eq!((claimed[0].amount, claimed[0].proceeds), (SOL, 150 * USDC));
+    assert_eq!(
+        (claimed[0].exercised_claimed, claimed[0].redemption_supply),
+        (SOL, SOL)
+    );
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let redeem = client::redeem_ix(&addresses, &alice.pubkey(), SOL);
+    let logs = h.send_logged(&[redeem], &[&alice]).await.unwrap();
+    let redeemed = emitted::<Redeemed>(&logs);
+    assert_eq!(redeemed.len(), 1);
+    assert_eq!(
+        (redeemed[0].collateral, redeemed[0].consideration),
+        (SOL, 0)
+    );
+    assert_eq!(redeemed[0].redemption_supply, 0);
+
+    let accounts = client::option_context_accounts(&addresses, &alice.pubkey());
+    let claim = client::option_ix(accounts, ix::ClaimNetPayoff { amount: SOL });
+    let logs = h.send_logged(&[claim], &[&alice]).await.unwrap();
+    let claimed = emitted::<NetPayoffClaimed>(&logs);
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].user, alice.pubkey());
+    assert_eq!((claimed[0].collateral, claimed[0].consideration), (0, 0));
+    assert_eq!(claimed[0].option_supply, 0);
+}
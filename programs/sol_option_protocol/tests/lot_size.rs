@@ -0,0 +1,67 @@
+//! Per-series contract sizing: amounts must be whole lots, and a single mint
+//! must write at least the series' minimum.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::SeriesConfig;
+
+const LOT: u64 = SOL / 10;
+
+/// Lots of 0.1 SOL with a 1 SOL minimum: Alice can't mint dust or less than
+/// the minimum, and exercises and burns must also be whole lots.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn amounts_must_be_whole_lots() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        lot_size: LOT,
+        min_mint_amount: SOL,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.mint_to(&usdc, &a.consideration, 300 * USDC).await;
+
+    // --- Mint: whole lots, at least the minimum --------------------------------
+    let result = h
+        .option_ix(ix::Mint { amount: SOL + 1 }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::AmountNotLotMultiple);
+    let result = h
+        .option_ix(ix::Mint { amount: 5 * LOT }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::BelowMinMintAmount);
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    // --- Exercise and burn: whole lots, no minimum -----------------------------
+    let result = h
+        .option_ix(ix::Exercise { amount: LOT - 1 }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::AmountNotLotMultiple);
+    h.option_ix(ix::Exercise { amount: LOT }, &series, &alice, &a)
+        .await
+        .unwrap();
+    let result = h
+        .option_ix(ix::Burn { amount: LOT / 2 }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::AmountNotLotMultiple);
+    h.option_ix(ix::Burn { amount: LOT }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.collateral_vault).await, 2 * SOL - 2 * LOT);
+}
@@ -0,0 +1,260 @@
+//! Margin writing: oracle-derived requirements, health factors, keeper
+//! liquidation, and settlement of margin shorts against their own margin.
+
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::Pubkey;
+use common::{assert_anchor_error, Harness, Series, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::oracle::{OracleFeed, OracleKind};
+use sol_option_protocol::state::WriterPosition;
+use sol_option_protocol::utils::margin::{
+    health_factor_bps, liquidation_seizure, margin_requirement,
+};
+use sol_option_protocol::{OptionData, SeriesConfig};
+
+#[test]
+fn otm_requirement_is_the_buffer() {
+    // 10 calls struck at $150 with SOL at $100: 20% of the collateral
+    let required = margin_requirement(10 * SOL, STRIKE, 100 * USDC, 9, false, 2_000).unwrap();
+    assert_eq!(required, 2 * SOL);
+}
+
+#[test]
+fn requirement_adds_intrinsic_value_up_to_full_backing() {
+    // At $200 each call is worth (200 - 150) / 200 = 0.25 SOL
+    let required = margin_requirement(10 * SOL, STRIKE, 200 * USDC, 9, false, 2_000).unwrap();
+    assert_eq!(required, 2 * SOL + 2 * SOL + SOL / 2);
+
+    // Deep in the money the requirement stops at the collateral itself
+    let required = margin_requirement(10 * SOL, STRIKE, 10_000 * USDC, 9, false, 2_000).unwrap();
+    assert_eq!(required, 10 * SOL);
+}
+
+#[test]
+fn put_requirement_is_in_consideration() {
+    // 10 puts struck at $150 with SOL at $100: $500 intrinsic + 10% of $1,500
+    let required = margin_requirement(10 * SOL, STRIKE, 100 * USDC, 9, true, 1_000).unwrap();
+    assert_eq!(required, 650 * USDC);
+}
+
+#[test]
+fn health_factor_is_margin_over_requirement() {
+    assert_eq!(health_factor_bps(2 * SOL, SOL).unwrap(), 20_000);
+    assert_eq!(health_factor_bps(SOL, 2 * SOL).unwrap(), 5_000);
+    assert_eq!(health_factor_bps(0, 0).unwrap(), u64::MAX);
+}
+
+#[test]
+fn liquidation_seizes_pro_rata_margin() {
+    assert_eq!(
+        liquidation_seizure(2 * SOL, 4 * SOL, 10 * SOL).unwrap(),
+        4 * SOL / 5
+    );
+    assert!(liquidation_seizure(2 * SOL, 11 * SOL, 10 * SOL).is_err());
+}
+
+/// SOL/USDC calls with a 20% initial and 10% maintenance buffer, priced by a
+/// Pyth feed at `price`
+async fn margin_series(h: &mut Harness, creator: &Keypair, price: u64) -> (Series, Pubkey, i64) {
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let oracle = Pubkey::new_unique();
+    let now = h.now().await;
+    h.set_pyth_price(&oracle, price, now).await;
+
+    let expiration = now + 30 * DAY;
+    let config = SeriesConfig {
+        primary_oracle: OracleFeed {
+            kind: OracleKind::Pyth,
+            feed: oracle,
+            ..OracleFeed::default()
+        },
+        initial_margin_bps: 2_000,
+        maintenance_margin_bps: 1_000,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(creator, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    (series, oracle, expiration)
+}
+
+/// Alice writes 10 calls on 2 SOL of margin at $100. The price doubles and
+/// her position drops under maintenance; Bob buys 4 of her options and
+/// liquidates them for 0.8 SOL of her margin.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn keeper_liquidates_underwater_position() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let (series, oracle, _) = margin_series(&mut h, &alice, 100 * USDC).await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&series.collateral_mint, &a.collateral, 3 * SOL)
+        .await;
+    h.open_writer_position(&series, &alice).await;
+    let position = common::writer_position_address(&series, &alice.pubkey());
+    let alice_key = alice.pubkey();
+
+    // --- 20% of the collateral writes the calls; one more doesn't fit ----------
+    let write = |amount, deposit| ix::MintOnMargin { amount, deposit };
+    h.margin_ix(
+        write(10 * SOL, 2 * SOL),
+        &series,
+        &alice_key,
+        &alice,
+        &a,
+        &oracle,
+    )
+    .await
+    .unwrap();
+    let result = h
+        .margin_ix(write(SOL, 0), &series, &alice_key, &alice, &a, &oracle)
+        .await;
+    assert_anchor_error(result, ErrorCode::InsufficientMargin);
+
+    assert_eq!(h.balance(&a.option).await, 10 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 0);
+    let state: WriterPosition = h.account(&position).await;
+    assert_eq!(state.margin_written, 10 * SOL);
+    assert_eq!(state.margin_collateral, 2 * SOL);
+    assert_eq!(state.health_bps, 20_000);
+
+    // --- Healthy positions can't be liquidated --------------------------------
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    let liquidate = |amount| ix::Liquidate { amount };
+    let result = h
+        .margin_ix(liquidate(4 * SOL), &series, &alice_key, &bob, &b, &oracle)
+        .await;
+    assert_anchor_error(result, ErrorCode::PositionHealthy);
+
+    // --- At $200: 2.5 SOL intrinsic + 1 SOL buffer against 2 SOL of margin ------
+    let now = h.now().await;
+    h.set_pyth_price(&oracle, 200 * USDC, now).await;
+    h.margin_ix(liquidate(4 * SOL), &series, &alice_key, &bob, &b, &oracle)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&b.option).await, 0);
+    assert_eq!(h.balance(&b.collateral).await, 4 * SOL / 5);
+    let state: WriterPosition = h.account(&position).await;
+    assert_eq!(state.margin_written, 6 * SOL);
+    assert_eq!(state.margin_collateral, 6 * SOL / 5);
+    let series_state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(series_state.margin_written, 6 * SOL);
+    assert_eq!(series_state.margin_collateral, 6 * SOL / 5);
+
+    // --- A top-up from anyone restores the position ---------------------------
+    h.mint_to(&series.collateral_mint, &b.collateral, SOL).await;
+    let top_up = ix::DepositMargin { amount: SOL };
+    h.margin_ix(top_up, &series, &alice_key, &bob, &b, &oracle)
+        .await
+        .unwrap();
+    let state: WriterPosition = h.account(&position).await;
+    assert_eq!(state.margin_collateral, 2 * SOL + SOL / 5);
+    assert!(state.health_bps > 10_000);
+}
+
+/// Carol writes one fully collateralized call and Alice writes 4 on margin.
+/// Settled at $200 each option is owed 0.25 SOL: Carol's from the pool,
+/// Alice's from her margin, which returns the rest to her.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn margin_shorts_settle_against_margin() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let carol = h.actor().await;
+    let (series, oracle, expiration) = margin_series(&mut h, &carol, 100 * USDC).await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let c = h.wallet(&series, &carol.pubkey()).await;
+    h.mint_to(&series.collateral_mint, &a.collateral, 2 * SOL)
+        .await;
+    h.mint_to(&series.collateral_mint, &c.collateral, SOL).await;
+
+    h.option_ix(ix::Mint { amount: SOL }, &series, &carol, &c)
+        .await
+        .unwrap();
+    h.open_writer_position(&series, &alice).await;
+    let alice_key = alice.pubkey();
+    let write = ix::MintOnMargin {
+        amount: 4 * SOL,
+        deposit: 2 * SOL,
+    };
+    h.margin_ix(write, &series, &alice_key, &alice, &a, &oracle)
+        .await
+        .unwrap();
+
+    // --- Settlement nets each side against its own backing --------------------
+    h.set_pyth_price(&oracle, 200 * USDC, expiration).await;
+    h.warp_to(expiration + 1).await;
+    h.settle_with_oracle(&series, &oracle).await.unwrap();
+
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.settled_collateral, 3 * SOL / 4);
+    assert_eq!(state.net_option_collateral, SOL + SOL / 4);
+    assert_eq!(state.margin_collateral, SOL);
+
+    // --- Alice's margin returns what her calls didn't cost ----------------------
+    let settle = ix::SettleMarginPosition {};
+    h.margin_ix(settle, &series, &alice_key, &alice, &a, &oracle)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+
+    // --- Option holders collect 0.25 SOL each, Carol redeems the rest ----------
+    h.option_ix(ix::ClaimNetPayoff { amount: 4 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+    h.option_ix(ix::ExitPosition {}, &series, &carol, &c)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&c.collateral).await, SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
+
+/// Margin moves only between the vault and the signer's own accounts of the
+/// backing asset: Bob can't top up from Alice's account, and Alice can't pass
+/// her consideration account for a call series' margin
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn margin_accounts_belong_to_the_signer() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let (series, oracle, _) = margin_series(&mut h, &alice, 100 * USDC).await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&series.collateral_mint, &a.collateral, 3 * SOL)
+        .await;
+    h.open_writer_position(&series, &alice).await;
+    let alice_key = alice.pubkey();
+
+    let top_up = ix::DepositMargin { amount: SOL };
+    let result = h
+        .margin_ix(top_up, &series, &alice_key, &bob, &a, &oracle)
+        .await;
+    assert_anchor_error(result, AnchorErrorCode::ConstraintTokenOwner);
+
+    let swapped = common::Wallet {
+        collateral: a.consideration,
+        ..a
+    };
+    let top_up = ix::DepositMargin { amount: SOL };
+    let result = h
+        .margin_ix(top_up, &series, &alice_key, &alice, &swapped, &oracle)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidMarginAccount);
+
+    let top_up = ix::DepositMargin { amount: SOL };
+    h.margin_ix(top_up, &series, &alice_key, &alice, &a, &oracle)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+}
@@ -0,0 +1,191 @@
+//! Marketplace client builders: `spl_marketplace::client` derives a market's
+//! order and escrow addresses and builds `place_order` and `cancel_order`
+//! from the market's and order's state, as a vault program trading through
+//! CPI would.
+
+mod common;
+
+use anchor_lang::AccountSerialize;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use common::{create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{OptionType, SeriesConfig};
+use spl_marketplace::client;
+use spl_marketplace::state::market::Market;
+use spl_marketplace::state::order::Order;
+
+fn market(base_mint: Pubkey, quote_mint: Pubkey, linked_series: Pubkey) -> Market {
+    Market {
+        base_mint,
+        quote_mint,
+        bump: 255,
+        next_order_id: 7,
+        total_orders_placed: 7,
+        total_orders_filled: 0,
+        total_base_volume: 0,
+        total_quote_volume: 0,
+        authority: Pubkey::new_unique(),
+        pending_authority: Pubkey::default(),
+        linked_series,
+        version: Market::CURRENT_VERSION,
+        reserved: [0; 64],
+    }
+}
+
+fn matches(data: &[u8], filters: &[client::Memcmp]) -> bool {
+    filters
+        .iter()
+        .all(|f| data.get(f.offset..f.offset + f.bytes.len()) == Some(&f.bytes[..]))
+}
+
+#[test]
+fn filters_match_the_serialized_accounts() {
+    let (base, quote) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = Vec::new();
+    market(base, quote, Pubkey::default())
+        .try_serialize(&mut data)
+        .unwrap();
+    assert!(matches(&data, &client::markets_by_base_mint_filter(&base)));
+    assert!(matches(
+        &data,
+        &client::markets_by_quote_mint_filter(&quote)
+    ));
+    assert!(!matches(&data, &client::orders_filter()));
+
+    let order = Order {
+        market: Pubkey::new_unique(),
+        order_id: 7,
+        owner: Pubkey::new_unique(),
+        is_buy: true,
+        price: 2 * USDC,
+        size: SOL,
+        filled: 0,
+        bump: 255,
+        created_at: 0,
+    };
+    let mut data = Vec::new();
+    order.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), Order::SIZE);
+    assert_eq!(
+        data[Order::ORDER_ID_OFFSET..Order::ORDER_ID_OFFSET + 8],
+        7u64.to_le_bytes()
+    );
+    assert!(matches(
+        &data,
+        &client::orders_in_market_filter(&order.market)
+    ));
+    assert!(matches(
+        &data,
+        &client::orders_of_owner_filter(&order.owner)
+    ));
+    assert!(matches(
+        &data,
+        &client::market_side_filter(&order.market, true)
+    ));
+    assert!(!matches(
+        &data,
+        &client::market_side_filter(&order.market, false)
+    ));
+    assert!(!matches(&data, &client::markets_filter()));
+}
+
+#[test]
+fn orders_escrow_the_side_they_pay_with() {
+    let (base, quote) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let market_key = client::market_address(&base, &quote);
+    let state = market(base, quote, Pubkey::default());
+    let user = Pubkey::new_unique();
+
+    let (order, bid) =
+        client::place_order_ix(&market_key, &state, &user, &spl_token::ID, USDC, SOL, true);
+    assert_eq!(order, client::order_address(&market_key, 7));
+    assert_eq!(bid.program_id, spl_marketplace::ID);
+    // user, market, order, deposit mint, deposit account, escrow, token program,
+    // system program, linked series
+    assert_eq!(bid.accounts[3].pubkey, quote);
+    assert_eq!(
+        bid.accounts[4].pubkey,
+        get_associated_token_address(&user, &quote)
+    );
+    assert_eq!(bid.accounts[5].pubkey, client::escrow_address(&order));
+    assert_eq!(bid.accounts[8].pubkey, spl_marketplace::ID);
+
+    let (_, ask) =
+        client::place_order_ix(&market_key, &state, &user, &spl_token::ID, USDC, SOL, false);
+    assert_eq!(ask.accounts[3].pubkey, base);
+}
+
+#[test]
+fn linked_markets_pass_their_series() {
+    let series = Pubkey::new_unique();
+    let state = market(Pubkey::new_unique(), Pubkey::new_unique(), series);
+    let market_key = client::market_address(&state.base_mint, &state.quote_mint);
+    let (_, ix) = client::place_order_ix(
+        &market_key,
+        &state,
+        &Pubkey::new_unique(),
+        &spl_token::ID,
+        USDC,
+        SOL,
+        false,
+    );
+    assert_eq!(ix.accounts[8].pubkey, series);
+}
+
+/// Alice lists her options with a client-built order and cancels it again
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn client_orders_place_and_cancel() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, create) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[create], &[&alice]).await.unwrap();
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    let market = h.create_market(&alice, series.option_mint, usdc).await;
+    assert_eq!(market, client::market_address(&series.option_mint, &usdc));
+    h.link_series(&alice, market, &series).await;
+
+    let state: Market = h.account(&market).await;
+    let (order, place) = client::place_order_ix(
+        &market,
+        &state,
+        &alice.pubkey(),
+        &spl_token::ID,
+        2 * USDC,
+        2 * SOL,
+        false,
+    );
+    h.send(&[place], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.option).await, 0);
+    assert_eq!(h.balance(&client::escrow_address(&order)).await, 2 * SOL);
+
+    let order_state: Order = h.account(&order).await;
+    let state: Market = h.account(&market).await;
+    let cancel = client::cancel_order_ix(&order, &order_state, &state, &spl_token::ID);
+    h.send(&[cancel], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.option).await, 2 * SOL);
+}
@@ -0,0 +1,123 @@
+//! Mint and list: `mint_and_list` writes options and lists every one it
+//! minted as a marketplace ask in the same instruction, so a writer never
+//! holds unlisted options between the two steps.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, Series, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::{OptionType, SeriesConfig};
+use spl_marketplace::state::order::Order;
+
+async fn call_series(h: &mut Harness, writer: &Keypair) -> Series {
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &writer.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[writer]).await.unwrap();
+    series
+}
+
+/// Alice goes from collateral to a live ask at 2 USDC; Bob lifts half of it
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn minted_options_go_straight_to_the_book() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+    let usdc = series.consideration_mint;
+    let market = h.create_market(&alice, series.option_mint, usdc).await;
+    h.link_series(&alice, market, &series).await;
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&series.collateral_mint, &a.collateral, 2 * SOL)
+        .await;
+    h.mint_to(&usdc, &b.consideration, 10 * USDC).await;
+
+    let ask = h
+        .mint_and_list(&alice, &series, &a, market, 2 * SOL, 2 * USDC)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 0);
+    assert_eq!(h.balance(&a.option).await, 0);
+    assert_eq!(h.balance(&a.redemption).await, 2 * SOL);
+    let order: Order = h.account(&ask).await;
+    assert_eq!(order.owner, alice.pubkey());
+    assert!(!order.is_buy);
+    assert_eq!((order.price, order.size), (2 * USDC, 2 * SOL));
+
+    h.fill_order(
+        &bob,
+        market,
+        ask,
+        series.option_mint,
+        usdc,
+        b.option,
+        b.consideration,
+        a.consideration,
+        Some(series.option_context),
+        SOL,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&b.option).await, SOL);
+    assert_eq!(h.balance(&a.consideration).await, 2 * USDC);
+}
+
+/// A market for another token refuses the ask, and the mint is undone with it
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn a_failed_listing_mints_nothing() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+    let market = h
+        .create_market(&alice, series.redemption_mint, series.consideration_mint)
+        .await;
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&series.collateral_mint, &a.collateral, SOL).await;
+    assert!(h
+        .mint_and_list(&alice, &series, &a, market, SOL, 2 * USDC)
+        .await
+        .is_err());
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+    assert_eq!(h.balance(&a.redemption).await, 0);
+}
+
+/// Options minted to someone else's account can't be listed by the writer
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn only_the_writers_own_options_are_listed() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+    let market = h
+        .create_market(&alice, series.option_mint, series.consideration_mint)
+        .await;
+
+    let mut a = h.wallet(&series, &alice.pubkey()).await;
+    a.option = h.wallet(&series, &bob.pubkey()).await.option;
+    h.mint_to(&series.collateral_mint, &a.collateral, SOL).await;
+    let result = h
+        .mint_and_list(&alice, &series, &a, market, SOL, 2 * USDC)
+        .await
+        .map(|_| ());
+    assert_anchor_error(result, ErrorCode::ListingNotOwned);
+}
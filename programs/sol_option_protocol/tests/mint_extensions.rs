@@ -0,0 +1,158 @@
+//! Token-2022 mint extensions: series refuse collateral and consideration
+//! mints that are non-transferable, freeze new accounts by default or have a
+//! permanent delegate, unless the admin allows the extension through
+//! `set_mint_extensions`.
+
+mod common;
+
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::default_account_state::DefaultAccountState;
+use anchor_spl::token_2022::spl_token_2022::extension::non_transferable::NonTransferable;
+use anchor_spl::token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{AccountState, Mint as MintState};
+use common::{create_series_ix_under, Harness, DAY, STRIKE};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::state::ProtocolConfig;
+use sol_option_protocol::utils::validation::{
+    risky_mint_extensions, validate_mint_extension_flags,
+};
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+/// Account data of an initialized Token-2022 mint carrying `extensions`,
+/// which `init` sets up
+fn mint_data(
+    extensions: &[ExtensionType],
+    init: impl FnOnce(&mut StateWithExtensionsMut<MintState>),
+) -> Vec<u8> {
+    let len = ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap();
+    let mut data = vec![0; len];
+    let mut mint = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+    init(&mut mint);
+    mint.base = MintState {
+        decimals: 6,
+        is_initialized: true,
+        ..MintState::default()
+    };
+    mint.pack_base();
+    mint.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn plain_mints_have_no_risky_extension() {
+    let data = mint_data(&[], |_| {});
+    assert_eq!(risky_mint_extensions(&data).unwrap(), 0);
+}
+
+#[test]
+fn risky_extensions_are_detected() {
+    let data = mint_data(&[ExtensionType::NonTransferable], |mint| {
+        mint.init_extension::<NonTransferable>(true).unwrap();
+    });
+    assert_eq!(
+        risky_mint_extensions(&data).unwrap(),
+        ProtocolConfig::MINT_EXTENSION_NON_TRANSFERABLE
+    );
+
+    let data = mint_data(&[ExtensionType::PermanentDelegate], |mint| {
+        let extension = mint.init_extension::<PermanentDelegate>(true).unwrap();
+        extension.delegate = Some(Pubkey::new_unique()).try_into().unwrap();
+    });
+    assert_eq!(
+        risky_mint_extensions(&data).unwrap(),
+        ProtocolConfig::MINT_EXTENSION_PERMANENT_DELEGATE
+    );
+
+    let data = mint_data(&[ExtensionType::DefaultAccountState], |mint| {
+        let extension = mint.init_extension::<DefaultAccountState>(true).unwrap();
+        extension.state = AccountState::Frozen as u8;
+    });
+    assert_eq!(
+        risky_mint_extensions(&data).unwrap(),
+        ProtocolConfig::MINT_EXTENSION_FROZEN_BY_DEFAULT
+    );
+}
+
+#[test]
+fn harmless_settings_of_risky_extensions_pass() {
+    let data = mint_data(&[ExtensionType::DefaultAccountState], |mint| {
+        let extension = mint.init_extension::<DefaultAccountState>(true).unwrap();
+        extension.state = AccountState::Initialized as u8;
+    });
+    assert_eq!(risky_mint_extensions(&data).unwrap(), 0);
+
+    let data = mint_data(&[ExtensionType::PermanentDelegate], |mint| {
+        mint.init_extension::<PermanentDelegate>(true).unwrap();
+    });
+    assert_eq!(risky_mint_extensions(&data).unwrap(), 0);
+}
+
+#[test]
+fn only_known_extensions_are_allowed() {
+    assert!(validate_mint_extension_flags(0).is_ok());
+    assert!(validate_mint_extension_flags(ProtocolConfig::ALL_MINT_EXTENSIONS).is_ok());
+    assert!(validate_mint_extension_flags(ProtocolConfig::ALL_MINT_EXTENSIONS + 1).is_err());
+}
+
+fn sol_config() -> SeriesConfig {
+    SeriesConfig {
+        underlying_symbol: "SOL".to_string(),
+        ..SeriesConfig::default()
+    }
+}
+
+/// A collateral mint with a permanent delegate is refused until the admin
+/// allows the extension; a consideration mint freezing new accounts still is
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn risky_mints_need_the_admins_consent() {
+    let mut h = Harness::start().await;
+    let delegated = h
+        .create_mint_with_extension(9, ExtensionType::PermanentDelegate)
+        .await;
+    let frozen = h
+        .create_mint_with_extension(6, ExtensionType::DefaultAccountState)
+        .await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+
+    let create = |collateral: Pubkey, consideration: Pubkey, strike: u64| {
+        create_series_ix_under(
+            &spl_token_2022::ID,
+            &alice.pubkey(),
+            collateral,
+            consideration,
+            strike,
+            expiration,
+            OptionType::Call,
+            sol_config(),
+        )
+        .1
+    };
+
+    assert!(h
+        .send(&[create(delegated, usdc, STRIKE)], &[&alice])
+        .await
+        .is_err());
+    assert!(h
+        .set_mint_extensions(ProtocolConfig::ALL_MINT_EXTENSIONS + 1)
+        .await
+        .is_err());
+    h.set_mint_extensions(ProtocolConfig::MINT_EXTENSION_PERMANENT_DELEGATE)
+        .await
+        .unwrap();
+    h.send(&[create(delegated, usdc, STRIKE)], &[&alice])
+        .await
+        .unwrap();
+
+    assert!(h
+        .send(&[create(delegated, frozen, 2 * STRIKE)], &[&alice])
+        .await
+        .is_err());
+}
@@ -0,0 +1,81 @@
+//! mint deposits from the signer but sends each leg to the token accounts it is
+//! given, whoever owns them.
+
+mod common;
+
+use common::{Harness, Wallet, DAY, SOL, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+
+/// Alice, a market maker, writes 3 calls with the long leg going straight to
+/// Bob's option account; she keeps the 3 redemption tokens.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mints_legs_to_separate_accounts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+
+    let split = Wallet {
+        option: b.option,
+        ..a
+    };
+    h.option_ix(ix::Mint { amount: 3 * SOL }, &series, &alice, &split)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&a.collateral).await, 0);
+    assert_eq!(h.balance(&a.option).await, 0);
+    assert_eq!(h.balance(&a.redemption).await, 3 * SOL);
+    assert_eq!(h.balance(&b.option).await, 3 * SOL);
+    assert_eq!(h.balance(&b.redemption).await, 0);
+}
+
+/// Carol, a custodian, funds 2 calls for Dave: he receives both legs and can
+/// unwind the position himself, collateral landing in his own account.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn custodian_funds_mint_for_beneficiary() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let carol = h.actor().await;
+    let dave = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&carol, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let c = h.wallet(&series, &carol.pubkey()).await;
+    let d = h.wallet(&series, &dave.pubkey()).await;
+    h.mint_to(&sol, &c.collateral, 2 * SOL).await;
+
+    let on_behalf = Wallet {
+        option: d.option,
+        redemption: d.redemption,
+        ..c
+    };
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &carol, &on_behalf)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&c.collateral).await, 0);
+    assert_eq!(h.balance(&d.option).await, 2 * SOL);
+    assert_eq!(h.balance(&d.redemption).await, 2 * SOL);
+
+    h.option_ix(ix::Burn { amount: 2 * SOL }, &series, &dave, &d)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&d.collateral).await, 2 * SOL);
+    assert_eq!(h.balance(&c.collateral).await, 0);
+}
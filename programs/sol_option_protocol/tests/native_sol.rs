@@ -0,0 +1,174 @@
+//! Native SOL: on series backed by wrapped SOL, `mint_native`,
+//! `exercise_native`, `burn_native` and `redeem_native` take and pay
+//! lamports. They wrap into the user's WSOL account for the instruction and
+//! close it afterwards.
+
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::{create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+fn sol_config() -> SeriesConfig {
+    SeriesConfig {
+        underlying_symbol: "SOL".to_string(),
+        ..SeriesConfig::default()
+    }
+}
+
+/// Alice writes 2 calls on WSOL from lamports, Bob exercises one and gets
+/// SOL back, and Alice burns the other pair for her SOL
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn wsol_call_in_lamports() {
+    let mut h = Harness::start().await;
+    let wsol = spl_token::native_mint::ID;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        wsol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        sol_config(),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+
+    // Up to 3 SOL wrapped; the unused 1 SOL and the account's rent come back
+    let rent = h.lamports(&a.collateral).await;
+    let before = h.lamports(&alice.pubkey()).await;
+    h.option_ix(
+        ix::MintNative {
+            amount: 2 * SOL,
+            lamports: 3 * SOL,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    assert_eq!(before - h.lamports(&alice.pubkey()).await, 2 * SOL - rent);
+    assert_eq!(h.balance(&series.collateral_vault).await, 2 * SOL);
+    assert_eq!(h.balance(&a.option).await, 2 * SOL);
+    assert_eq!(h.lamports(&a.collateral).await, 0);
+
+    // Bob pays the strike in USDC and receives lamports
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+    let rent = h.lamports(&b.collateral).await;
+    let before = h.lamports(&bob.pubkey()).await;
+    h.option_ix(
+        ix::ExerciseNative {
+            amount: SOL,
+            lamports: 0,
+        },
+        &series,
+        &bob,
+        &b,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.lamports(&bob.pubkey()).await - before, SOL + rent);
+    assert_eq!(h.balance(&b.consideration).await, 0);
+
+    // Alice burns her last pair; the WSOL account it recreates is closed again
+    let before = h.lamports(&alice.pubkey()).await;
+    h.option_ix(ix::BurnNative { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.lamports(&alice.pubkey()).await - before, SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
+
+/// After expiry Alice redeems her unexercised calls straight into lamports
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn wsol_redeem_in_lamports() {
+    let mut h = Harness::start().await;
+    let wsol = spl_token::native_mint::ID;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        wsol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        sol_config(),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.option_ix(
+        ix::MintNative {
+            amount: 2 * SOL,
+            lamports: 2 * SOL,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let before = h.lamports(&alice.pubkey()).await;
+    h.option_ix(ix::RedeemNative { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.lamports(&alice.pubkey()).await - before, 2 * SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+    assert_eq!(h.lamports(&a.collateral).await, 0);
+}
+
+/// Series without a WSOL side have nothing to wrap
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn native_mint_needs_a_wsol_series() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        sol_config(),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+
+    let result = h
+        .option_ix(
+            ix::MintNative {
+                amount: SOL,
+                lamports: SOL,
+            },
+            &series,
+            &alice,
+            &a,
+        )
+        .await;
+    assert!(result.is_err());
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+}
@@ -0,0 +1,85 @@
+//! Expiry netting: unexercised ITM options are settled in aggregate at the
+//! settlement price instead of per-user exercises racing redemption.
+
+mod common;
+
+use common::{SOL, STRIKE, USDC};
+
+use sol_option_protocol::instructions::net_expiry;
+use sol_option_protocol::utils::netting::{calculate_net_settlement, NetSettlement};
+use sol_option_protocol::OptionData;
+
+#[test]
+fn itm_call_nets_intrinsic_value_in_collateral() {
+    // 6 SOL + 600 USDC in the vaults, 2 options outstanding, settles at 200:
+    // each option is worth (200 - 150) / 200 = 0.25 SOL
+    let net = calculate_net_settlement(6 * SOL, 600 * USDC, 2 * SOL, STRIKE, 200 * USDC, 9, false)
+        .unwrap();
+    assert_eq!(
+        net,
+        NetSettlement {
+            in_the_money: true,
+            option_collateral: SOL / 2,
+            option_consideration: 0,
+            redemption_collateral: 6 * SOL - SOL / 2,
+            redemption_consideration: 600 * USDC,
+        }
+    );
+}
+
+#[test]
+fn itm_put_nets_intrinsic_value_in_consideration() {
+    // 4 puts outstanding against 600 USDC, settles at 120: 30 USDC each
+    let net =
+        calculate_net_settlement(0, 600 * USDC, 4 * SOL, STRIKE, 120 * USDC, 9, true).unwrap();
+    assert!(net.in_the_money);
+    assert_eq!(net.option_collateral, 0);
+    assert_eq!(net.option_consideration, 120 * USDC);
+    assert_eq!(net.redemption_consideration, 480 * USDC);
+}
+
+#[test]
+fn otm_and_at_the_money_reserve_nothing() {
+    for (price, is_put) in [(150 * USDC, false), (100 * USDC, false), (150 * USDC, true)] {
+        let net = calculate_net_settlement(6 * SOL, 600 * USDC, 2 * SOL, STRIKE, price, 9, is_put)
+            .unwrap();
+        assert!(!net.in_the_money);
+        assert_eq!(net.redemption_collateral, 6 * SOL);
+        assert_eq!(net.redemption_consideration, 600 * USDC);
+    }
+}
+
+#[test]
+fn reservation_is_capped_at_the_vault() {
+    let net = calculate_net_settlement(0, 10 * USDC, 4 * SOL, STRIKE, 120 * USDC, 9, true).unwrap();
+    assert_eq!(net.option_consideration, 10 * USDC);
+    assert_eq!(net.redemption_consideration, 0);
+}
+
+#[test]
+fn intrinsic_value_rounds_down() {
+    // 1 raw unit of an option settling at 200 is worth 0.25 raw units: nothing
+    let net = calculate_net_settlement(6 * SOL, 0, 1, STRIKE, 200 * USDC, 9, false).unwrap();
+    assert_eq!(net.option_collateral, 0);
+    assert_eq!(net.redemption_collateral, 6 * SOL);
+}
+
+#[test]
+fn margin_owed_joins_the_option_side() {
+    // 6 SOL in the pool for 6 options, 2 more written on 1 SOL of margin;
+    // settles at 200: each option is worth 0.25 SOL
+    let series = OptionData {
+        strike_price: STRIKE,
+        strike_scale: 9,
+        contract_multiplier: 1,
+        collateral_deposited: 6 * SOL,
+        margin_written: 2 * SOL,
+        margin_collateral: SOL,
+        ..OptionData::default()
+    };
+    let (net, margin_owed) = net_expiry(&series, Some(200 * USDC), 8 * SOL).unwrap();
+    assert_eq!(margin_owed, SOL / 2);
+    assert_eq!(net.option_collateral, 3 * SOL / 2 + SOL / 2);
+    // The pool only pays for the 6 options it backs
+    assert_eq!(net.redemption_collateral, 6 * SOL - 3 * SOL / 2);
+}
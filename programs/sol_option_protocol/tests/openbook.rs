@@ -0,0 +1,190 @@
+//! OpenBook v2 listings: `open_openbook_market` creates an order book for a
+//! series' option token against its consideration, and `seed_openbook_market`
+//! mints options and asks for them on it in one instruction.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::{assert_anchor_error, create_series_ix, Harness, Series, DAY, SOL, USDC};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instructions::OpenbookMarketParams;
+use sol_option_protocol::instructions::PERPETUAL_EXPIRATION;
+use sol_option_protocol::openbook::{
+    create_market_ix, market_authority_address, market_expiry, market_name, place_ask_ix,
+    MarketAddresses, OrderAccounts, OPENBOOK_PROGRAM_ID,
+};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+const PARAMS: OpenbookMarketParams = OpenbookMarketParams {
+    quote_lot_size: 100,
+    base_lot_size: 1_000_000,
+    maker_fee: -200,
+    taker_fee: 400,
+};
+
+fn series_data() -> OptionData {
+    OptionData {
+        underlying_symbol: "BONK".to_string(),
+        strike_price: 4,
+        strike_exponent: 2,
+        expiration: 1_740_700_800, // 28 Feb 2025
+        ..OptionData::default()
+    }
+}
+
+#[test]
+fn markets_are_named_and_expire_with_the_series() {
+    let mut series = series_data();
+    assert_eq!(market_name(&series), "BONK-28FEB25-0.0");
+    assert_eq!(market_expiry(&series), 1_740_700_800);
+    series.expiration = PERPETUAL_EXPIRATION;
+    assert_eq!(market_expiry(&series), 0);
+}
+
+#[test]
+fn create_market_has_no_oracles_and_one_admin() {
+    let market = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let addresses = MarketAddresses {
+        market,
+        bids: Pubkey::new_unique(),
+        asks: Pubkey::new_unique(),
+        event_heap: Pubkey::new_unique(),
+        base_mint: Pubkey::new_unique(),
+        quote_mint: Pubkey::new_unique(),
+        token_program: anchor_spl::token::ID,
+    };
+    let ix = create_market_ix(&addresses, &admin, &admin, "BONK".to_string(), &PARAMS, 1);
+
+    assert_eq!(ix.program_id, OPENBOOK_PROGRAM_ID);
+    assert_eq!(ix.accounts.len(), 21);
+    assert_eq!(ix.accounts[0], AccountMeta::new(market, true));
+    assert_eq!(ix.accounts[1].pubkey, market_authority_address(&market));
+    // Oracles and the open orders and consume events admins are absent
+    for absent in [13, 14, 16, 17] {
+        assert_eq!(ix.accounts[absent].pubkey, OPENBOOK_PROGRAM_ID);
+    }
+    assert_eq!(ix.accounts[15].pubkey, admin);
+    assert_eq!(ix.accounts[18].pubkey, admin);
+
+    assert_eq!(ix.data[..8], [103, 226, 97, 235, 200, 188, 251, 254]);
+    assert_eq!(ix.data[8..16], [4, 0, 0, 0, b'B', b'O', b'N', b'K']);
+    // conf_filter, then no staleness limit
+    assert_eq!(ix.data[16..20], 0.1f32.to_le_bytes());
+    assert_eq!(ix.data[20], 0);
+    assert_eq!(ix.data[21..29], 100i64.to_le_bytes());
+    assert_eq!(ix.data[ix.data.len() - 8..], 1i64.to_le_bytes());
+}
+
+#[test]
+fn seeding_places_a_limit_ask() {
+    let owner = Pubkey::new_unique();
+    let accounts = OrderAccounts {
+        owner,
+        open_orders_account: Pubkey::new_unique(),
+        user_token_account: Pubkey::new_unique(),
+        market: Pubkey::new_unique(),
+        bids: Pubkey::new_unique(),
+        asks: Pubkey::new_unique(),
+        event_heap: Pubkey::new_unique(),
+        market_vault: Pubkey::new_unique(),
+        token_program: anchor_spl::token::ID,
+    };
+    let ix = place_ask_ix(&accounts, 20, 5);
+
+    assert_eq!(ix.accounts.len(), 12);
+    assert_eq!(ix.accounts[0], AccountMeta::new_readonly(owner, true));
+    assert_eq!(ix.data[..8], [51, 194, 155, 175, 109, 130, 96, 106]);
+    assert_eq!(ix.data[8], 1); // Side::Ask
+    assert_eq!(ix.data[9..17], 20i64.to_le_bytes());
+    assert_eq!(ix.data[17..25], 5i64.to_le_bytes());
+}
+
+async fn call_series(h: &mut Harness, writer: &Keypair) -> Series {
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &writer.pubkey(),
+        sol,
+        usdc,
+        150 * USDC,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[writer]).await.unwrap();
+    series
+}
+
+fn book() -> [Pubkey; 3] {
+    [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ]
+}
+
+/// Only the series authority opens a market, and only on OpenBook
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn markets_are_opened_by_the_authority_on_openbook() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let mallory = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+
+    let market = Keypair::new();
+    let result = h
+        .open_openbook_market(
+            &mallory,
+            &series,
+            OPENBOOK_PROGRAM_ID,
+            &market,
+            book(),
+            PARAMS,
+        )
+        .await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+
+    let impostor = Pubkey::new_unique();
+    let result = h
+        .open_openbook_market(&alice, &series, impostor, &market, book(), PARAMS)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidOpenbookProgram);
+}
+
+/// Seeding goes to OpenBook only, and mints nothing when refused
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn seeding_requires_openbook() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&series.collateral_mint, &a.collateral, SOL).await;
+
+    let impostor = Pubkey::new_unique();
+    let (open_orders, market) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let result = h
+        .seed_openbook_market(
+            &alice,
+            &series,
+            &a,
+            impostor,
+            open_orders,
+            market,
+            book(),
+            SOL,
+            20,
+            5,
+        )
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidOpenbookProgram);
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+}
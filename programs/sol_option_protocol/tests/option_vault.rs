@@ -0,0 +1,198 @@
+//! Option vaults (DOVs): depositors pool collateral for shares, and each
+//! round the manager writes covered calls out of the pool and lists them on
+//! the series market. Settling the round redeems the pool's escrow, and
+//! shares withdraw their part of the collateral plus the premiums earned.
+
+mod common;
+
+use common::{
+    assert_anchor_error, create_series_ix, Harness, Series, Vault, DAY, SOL, STRIKE, USDC,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::OptionVault;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+#[test]
+fn first_deposit_mints_one_share_per_unit() {
+    assert_eq!(
+        OptionVault::shares_for_deposit(5 * SOL, 0, 0).unwrap(),
+        5 * SOL
+    );
+}
+
+#[test]
+fn later_deposits_are_priced_off_the_escrow_balance() {
+    // The pool grew 10 -> 12 SOL against 10 shares: 6 SOL buys 5 shares
+    assert_eq!(OptionVault::shares_for_deposit(6, 12, 10).unwrap(), 5);
+    // Rounded down in the vault's favour
+    assert_eq!(OptionVault::shares_for_deposit(5, 12, 10).unwrap(), 4);
+    assert!(OptionVault::shares_for_deposit(5, 0, 10).is_err());
+}
+
+#[test]
+fn matching_deposits_keep_the_vaults_ratio() {
+    assert_eq!(OptionVault::matching_deposit(SOL, 0, 0).unwrap(), 0);
+    assert_eq!(
+        OptionVault::matching_deposit(SOL, 2 * SOL, 4 * USDC).unwrap(),
+        2 * USDC
+    );
+    // Rounded up, so a depositor never buys into the premiums for free
+    assert_eq!(OptionVault::matching_deposit(1, 3, 2).unwrap(), 1);
+}
+
+#[test]
+fn shares_withdraw_their_part_rounded_down() {
+    assert_eq!(OptionVault::share_of(2, 3, 2 * USDC).unwrap(), 1_333_333);
+    assert_eq!(OptionVault::share_of(3, 3, 2 * USDC).unwrap(), 2 * USDC);
+    assert!(OptionVault::share_of(4, 3, 2 * USDC).is_err());
+}
+
+async fn call_series(h: &mut Harness, manager: &Keypair, vault: &Vault) -> Series {
+    let expiration = h.now().await + 7 * DAY;
+    let (series, ix) = create_series_ix(
+        &manager.pubkey(),
+        vault.underlying_mint,
+        vault.quote_mint,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[manager]).await.unwrap();
+    series
+}
+
+/// Alice and Carol pool 3 SOL; the round sells 1 of 2 calls written at
+/// 2 USDC, nobody exercises, and Alice leaves with her SOL plus 2/3 of the
+/// premium
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn a_round_earns_premium_for_the_pool() {
+    let mut h = Harness::start().await;
+    let manager = h.actor().await;
+    let alice = h.actor().await;
+    let carol = h.actor().await;
+    let bob = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let vault = h
+        .create_option_vault(&manager, sol, usdc, OptionType::Call)
+        .await
+        .unwrap();
+    h.fund_vault_authority(&vault).await;
+
+    let alice_sol = h.create_ata(&sol, &alice.pubkey()).await;
+    let alice_usdc = h.create_ata(&usdc, &alice.pubkey()).await;
+    let carol_sol = h.create_ata(&sol, &carol.pubkey()).await;
+    let carol_usdc = h.create_ata(&usdc, &carol.pubkey()).await;
+    h.mint_to(&sol, &alice_sol, 2 * SOL).await;
+    h.mint_to(&sol, &carol_sol, 2 * SOL).await;
+    let deposit = ix::DepositToVault { amount: 2 * SOL };
+    h.vault_shares_ix(deposit, &vault, &alice, alice_sol, alice_usdc)
+        .await
+        .unwrap();
+    let deposit = ix::DepositToVault { amount: SOL };
+    h.vault_shares_ix(deposit, &vault, &carol, carol_sol, carol_usdc)
+        .await
+        .unwrap();
+    assert_eq!(h.supply(&vault.share_mint).await, 3 * SOL);
+
+    let series = call_series(&mut h, &manager, &vault).await;
+    let market = h.open_series_market(&manager, &series).await.unwrap();
+    let ask = h
+        .start_vault_round(&manager, &vault, &series, market, 2 * SOL, 2 * USDC)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&vault.underlying_account).await, SOL);
+    let state: OptionVault = h.account(&vault.option_vault).await;
+    assert_eq!((state.round, state.written, state.ask), (1, 2 * SOL, ask));
+
+    // The pool is locked while the round runs
+    h.next_blockhash().await;
+    let deposit = ix::DepositToVault { amount: SOL };
+    let result = h
+        .vault_shares_ix(deposit, &vault, &carol, carol_sol, carol_usdc)
+        .await;
+    assert_anchor_error(result, ErrorCode::VaultRoundActive);
+
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&usdc, &b.consideration, 10 * USDC).await;
+    h.fill_order(
+        &bob,
+        market,
+        ask,
+        series.option_mint,
+        usdc,
+        b.option,
+        b.consideration,
+        vault.quote_account,
+        Some(series.option_context),
+        SOL,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&vault.quote_account).await, 2 * USDC);
+
+    let data: OptionData = h.account(&series.option_context).await;
+    h.warp_to(data.expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.settle_vault_round(&vault, &series, ask).await.unwrap();
+    assert_eq!(h.balance(&vault.underlying_account).await, 3 * SOL);
+    assert!(h
+        .account::<OptionVault>(&vault.option_vault)
+        .await
+        .is_idle());
+
+    let withdraw = ix::WithdrawFromVault { shares: 2 * SOL };
+    h.vault_shares_ix(withdraw, &vault, &alice, alice_sol, alice_usdc)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&alice_sol).await, 2 * SOL);
+    assert_eq!(h.balance(&alice_usdc).await, 1_333_333);
+    assert_eq!(h.supply(&vault.share_mint).await, SOL);
+}
+
+/// Only the manager picks the series and price of a round
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn only_the_manager_starts_a_round() {
+    let mut h = Harness::start().await;
+    let manager = h.actor().await;
+    let mallory = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let vault = h
+        .create_option_vault(&manager, sol, usdc, OptionType::Call)
+        .await
+        .unwrap();
+    h.fund_vault_authority(&vault).await;
+    let series = call_series(&mut h, &manager, &vault).await;
+    let market = h.open_series_market(&manager, &series).await.unwrap();
+
+    let result = h
+        .start_vault_round(&mallory, &vault, &series, market, SOL, USDC)
+        .await
+        .map(|_| ());
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+}
+
+/// Vaults write plain calls and puts only
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn binary_vaults_are_refused() {
+    let mut h = Harness::start().await;
+    let manager = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let result = h
+        .create_option_vault(&manager, sol, usdc, OptionType::BinaryCall)
+        .await
+        .map(|_| ());
+    assert_anchor_error(result, ErrorCode::UnsupportedVaultType);
+}
@@ -0,0 +1,94 @@
+//! Deviation guard: a read that moved too far from the last accepted one is
+//! rejected, but only while that reference is within the feed's staleness
+//! window, and the admin can clear the reference outright, so a genuine move
+//! never leaves a series unable to observe or settle.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::{assert_anchor_error, Harness, DAY, STRIKE, USDC};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::oracle::{OracleFeed, OracleGuard, OracleKind};
+use sol_option_protocol::{OptionData, SeriesConfig};
+
+const GUARD: OracleGuard = OracleGuard {
+    max_staleness: 60,
+    max_confidence_bps: 0,
+    max_deviation_bps: 1_000,
+};
+
+#[test]
+fn moves_past_the_threshold_fail_until_the_reference_ages_out() {
+    let t = 1_700_000_000;
+    assert!(GUARD.check_deviation(STRIKE, t, 160 * USDC, t + 30).is_ok());
+    assert_eq!(
+        GUARD
+            .check_deviation(STRIKE, t, 200 * USDC, t + 30)
+            .unwrap_err(),
+        error!(ErrorCode::OraclePriceDeviation)
+    );
+    assert_eq!(
+        GUARD
+            .check_deviation(STRIKE, t, 200 * USDC, t + 60)
+            .unwrap_err(),
+        error!(ErrorCode::OraclePriceDeviation)
+    );
+    assert!(GUARD.check_deviation(STRIKE, t, 200 * USDC, t + 61).is_ok());
+    assert!(GUARD.check_deviation(0, 0, 200 * USDC, t).is_ok());
+}
+
+/// SOL jumps from $150 to $200 against a 10% guard: observations fail until
+/// the admin clears the reference, and a later jump to $300 clears on its own
+/// once the last accepted reading is older than the staleness window
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn a_genuine_move_never_blocks_observations_for_good() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let oracle = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        primary_oracle: OracleFeed {
+            kind: OracleKind::Pyth,
+            feed: oracle,
+            guard: GUARD,
+        },
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+
+    let now = h.now().await;
+    h.set_pyth_price(&oracle, 150 * USDC, now).await;
+    h.observe_price(&series, &oracle).await.unwrap();
+
+    h.warp_to(now + 10).await;
+    h.set_pyth_price(&oracle, 200 * USDC, now + 10).await;
+    let result = h.observe_price(&series, &oracle).await;
+    assert_anchor_error(result, ErrorCode::OraclePriceDeviation);
+
+    // Only the admin clears the reference
+    let result = h.reset_oracle_reference(&alice, &series).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+    let admin = h.admin.insecure_clone();
+    h.reset_oracle_reference(&admin, &series).await.unwrap();
+    h.observe_price(&series, &oracle).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.last_oracle_price, 200 * USDC);
+
+    h.warp_to(now + 20).await;
+    h.set_pyth_price(&oracle, 300 * USDC, now + 20).await;
+    let result = h.observe_price(&series, &oracle).await;
+    assert_anchor_error(result, ErrorCode::OraclePriceDeviation);
+
+    h.warp_to(now + 71).await;
+    h.set_pyth_price(&oracle, 300 * USDC, now + 71).await;
+    h.observe_price(&series, &oracle).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.last_oracle_price, 300 * USDC);
+}
@@ -0,0 +1,135 @@
+//! Oracle hierarchy: a configured primary feed must be passed and readable,
+//! and only a guard failure on its own data falls through to the secondary.
+
+use anchor_lang::prelude::*;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::oracle::pyth::{
+    PriceFeedMessage, PriceUpdateV2, VerificationLevel, PRICE_UPDATE_V2_DISCRIMINATOR,
+    PYTH_RECEIVER_PROGRAM_ID,
+};
+use sol_option_protocol::oracle::{
+    resolve_price, OracleFeed, OracleGuard, OracleKind, PriceRequest, SettlementSource,
+};
+
+const EXPIRATION: i64 = 1_700_000_000;
+
+/// Fully verified Pyth update at `price` dollars (exponent -8)
+fn price_update(price: i64, publish_time: i64) -> Vec<u8> {
+    let update = PriceUpdateV2 {
+        write_authority: Pubkey::new_unique(),
+        verification_level: VerificationLevel::Full,
+        price_message: PriceFeedMessage {
+            feed_id: [7; 32],
+            price: price * 100_000_000,
+            conf: 0,
+            exponent: -8,
+            publish_time,
+            prev_publish_time: publish_time - 1,
+            ema_price: price * 100_000_000,
+            ema_conf: 0,
+        },
+        posted_slot: 1,
+    };
+    let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+    update.serialize(&mut data).unwrap();
+    data
+}
+
+fn pyth(feed: Pubkey) -> OracleFeed {
+    OracleFeed {
+        kind: OracleKind::Pyth,
+        feed,
+        guard: OracleGuard::default(),
+    }
+}
+
+fn request() -> PriceRequest {
+    PriceRequest {
+        reference_time: EXPIRATION,
+        late_window: 0,
+        previous_price: 0,
+        previous_update: 0,
+        strike_exponent: 0,
+    }
+}
+
+/// Resolves with a primary at $100 published `primary_age` before expiration
+/// and a fresh secondary at $90; `pass_primary` false leaves the primary
+/// account out, `primary_key` overrides the address it is passed at
+fn resolve(
+    primary: Option<&OracleFeed>,
+    primary_age: i64,
+    pass_primary: bool,
+    primary_key: Option<Pubkey>,
+) -> Result<(u64, SettlementSource)> {
+    let (primary_feed, secondary_feed) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let primary_config = primary.copied().unwrap_or_else(|| pyth(primary_feed));
+    let account_key = primary_key.unwrap_or(primary_config.feed);
+
+    let mut primary_lamports = 1_000_000;
+    let mut primary_data = price_update(100, EXPIRATION - primary_age);
+    let primary_account = AccountInfo::new(
+        &account_key,
+        false,
+        false,
+        &mut primary_lamports,
+        &mut primary_data,
+        &PYTH_RECEIVER_PROGRAM_ID,
+        false,
+        0,
+    );
+    let mut secondary_lamports = 1_000_000;
+    let mut secondary_data = price_update(90, EXPIRATION);
+    let secondary_account = AccountInfo::new(
+        &secondary_feed,
+        false,
+        false,
+        &mut secondary_lamports,
+        &mut secondary_data,
+        &PYTH_RECEIVER_PROGRAM_ID,
+        false,
+        0,
+    );
+
+    resolve_price(
+        &primary_config,
+        pass_primary.then_some(&primary_account),
+        &pyth(secondary_feed),
+        Some(&secondary_account),
+        &request(),
+    )
+    .map(|(value, _, source)| (value, source))
+}
+
+#[test]
+fn a_passing_primary_is_used() {
+    let resolved = resolve(None, 0, true, None).unwrap();
+    assert_eq!(resolved, (100, SettlementSource::Primary));
+}
+
+#[test]
+fn a_stale_primary_falls_through_to_the_secondary() {
+    let stale = OracleGuard::default().max_staleness() + 1;
+    let resolved = resolve(None, stale, true, None).unwrap();
+    assert_eq!(resolved, (90, SettlementSource::Secondary));
+}
+
+#[test]
+fn the_primary_cannot_be_skipped() {
+    let result = resolve(None, 0, false, None);
+    assert_eq!(
+        result.unwrap_err(),
+        error!(ErrorCode::PrimaryOracleRequired)
+    );
+
+    let result = resolve(None, 0, true, Some(Pubkey::new_unique()));
+    assert_eq!(result.unwrap_err(), error!(ErrorCode::InvalidOracleAccount));
+}
+
+#[test]
+fn an_unconfigured_primary_reads_the_secondary() {
+    let none = OracleFeed::default();
+    let resolved = resolve(Some(&none), 0, false, None).unwrap();
+    assert_eq!(resolved, (90, SettlementSource::Secondary));
+}
@@ -0,0 +1,128 @@
+//! PDA helpers: `utils::pda::derive_*` compute series and marketplace
+//! addresses from their seeds alone, the same ones the programs check.
+
+mod common;
+
+use anchor_spl::token::spl_token;
+use common::{create_series_ix, Harness, DAY, STRIKE};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::client::SeriesAddresses;
+use sol_option_protocol::utils::pda::{
+    derive_escrow, derive_market, derive_option_context, derive_option_mint, derive_order,
+    derive_redemption_mint, derive_vaults,
+};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+use spl_marketplace::client as market_client;
+
+#[test]
+fn series_addresses_follow_the_seeds() {
+    let (collateral, consideration) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let expiration = 1_750_000_000;
+    let (option_context, bump) = derive_option_context(
+        &collateral,
+        &consideration,
+        STRIKE,
+        expiration,
+        OptionType::Put,
+    );
+    assert_eq!(
+        Pubkey::create_program_address(
+            &[
+                b"option_context",
+                collateral.as_ref(),
+                consideration.as_ref(),
+                &STRIKE.to_le_bytes(),
+                &expiration.to_le_bytes(),
+                &[OptionType::Put as u8],
+                &[bump],
+            ],
+            &sol_option_protocol::ID,
+        )
+        .unwrap(),
+        option_context
+    );
+
+    // Every term is part of the address
+    let (call, _) = derive_option_context(
+        &collateral,
+        &consideration,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+    );
+    assert_ne!(call, option_context);
+
+    let seeded = |prefix: &[u8]| {
+        Pubkey::find_program_address(&[prefix, option_context.as_ref()], &sol_option_protocol::ID).0
+    };
+    assert_eq!(
+        derive_option_mint(&option_context).0,
+        seeded(b"option_mint")
+    );
+    assert_eq!(
+        derive_redemption_mint(&option_context).0,
+        seeded(b"redemption_mint")
+    );
+    assert_eq!(
+        derive_vaults(&option_context),
+        (seeded(b"collateral_vault"), seeded(b"consideration_vault"))
+    );
+}
+
+#[test]
+fn marketplace_addresses_match_its_client() {
+    let marketplace = spl_marketplace::ID;
+    let (base, quote) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (market, _) = derive_market(&marketplace, &base, &quote);
+    assert_eq!(market, market_client::market_address(&base, &quote));
+    let (order, _) = derive_order(&marketplace, &market, 3);
+    assert_eq!(order, market_client::order_address(&market, 3));
+    assert_eq!(
+        derive_escrow(&marketplace, &order).0,
+        market_client::escrow_address(&order)
+    );
+}
+
+/// The derived addresses are the ones `create_option` records
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn created_series_live_at_the_derived_addresses() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+
+    let derived = SeriesAddresses::derive(
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        spl_token::ID,
+    );
+    assert_eq!(derived.option_context, series.option_context);
+    let data: OptionData = h.account(&derived.option_context).await;
+    assert_eq!(
+        SeriesAddresses::of(derived.option_context, &data, spl_token::ID),
+        derived
+    );
+    let (_, bump) = derive_option_context(&sol, &usdc, STRIKE, expiration, OptionType::Call);
+    assert_eq!(data.bump, bump);
+}
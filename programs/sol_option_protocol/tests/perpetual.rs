@@ -0,0 +1,180 @@
+//! Perpetual series: no expiry, exercisable at any time, with longs paying a
+//! fixed funding rate to the writers for as long as they hold.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::{MAX_FUNDING_RATE_BPS, PERPETUAL_EXPIRATION};
+use sol_option_protocol::utils::math::calculate_funding_index;
+use sol_option_protocol::utils::symbol::format_option_symbol;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+const RATE_BPS: u32 = 500; // 5% of the strike a year
+const YEAR: i64 = 365 * DAY;
+
+#[test]
+fn funding_index_grows_linearly_with_time() {
+    assert_eq!(
+        calculate_funding_index(STRIKE, RATE_BPS, YEAR).unwrap(),
+        7_500_000
+    );
+    assert_eq!(
+        calculate_funding_index(STRIKE, RATE_BPS, YEAR / 2).unwrap(),
+        3_750_000
+    );
+    assert_eq!(calculate_funding_index(STRIKE, RATE_BPS, 0).unwrap(), 0);
+    assert_eq!(calculate_funding_index(STRIKE, 0, YEAR).unwrap(), 0);
+}
+
+#[test]
+fn accrual_is_measured_from_creation() {
+    let mut series = OptionData {
+        strike_price: STRIKE,
+        strike_scale: 9,
+        expiration: PERPETUAL_EXPIRATION,
+        funding_rate_bps: RATE_BPS,
+        ..OptionData::default()
+    };
+    assert!(series.is_perpetual());
+
+    // Cranking every second accrues exactly what one accrual a year later would
+    for now in 1..=600 {
+        series.accrue_funding(now).unwrap();
+    }
+    series.accrue_funding(YEAR).unwrap();
+    assert_eq!(series.funding_index, 7_500_000);
+    assert_eq!(series.funding_updated_at, YEAR);
+    // 4 SOL owe 30 USDC
+    assert_eq!(series.funding_owed(4 * SOL).unwrap(), 30 * USDC);
+
+    // Time never runs backwards
+    series.accrue_funding(YEAR - DAY).unwrap();
+    assert_eq!(series.funding_index, 7_500_000);
+}
+
+#[test]
+fn perpetual_symbol_has_no_date() {
+    let symbol = format_option_symbol("sol", STRIKE, 6, PERPETUAL_EXPIRATION, false);
+    assert_eq!(symbol, "SOL-PERP-150-C");
+}
+
+/// Alice writes 10 perpetual calls and sells 4 to Bob. A year later Bob
+/// exercises them, paying 600 USDC strike plus 30 USDC funding, all of which
+/// Alice collects by converting 4 exercised units.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn exercise_pays_funding_to_writers() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let config = SeriesConfig {
+        funding_rate_bps: RATE_BPS,
+        ..SeriesConfig::default()
+    };
+    let created_at = h.now().await;
+    let series = h
+        .create_series_with(
+            &alice,
+            sol,
+            usdc,
+            STRIKE,
+            PERPETUAL_EXPIRATION,
+            false,
+            config,
+        )
+        .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert!(state.is_perpetual());
+    assert_eq!(state.funding_started_at, created_at);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 1_000 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+
+    h.warp_to(created_at + YEAR).await;
+    h.accrue_funding(&series).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.funding_index, 7_500_000);
+
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 4 * SOL);
+    assert_eq!(h.balance(&b.consideration).await, 370 * USDC);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.funding_collected, 30 * USDC);
+
+    h.option_ix(
+        ix::RedeemConsideration { amount: 4 * SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 630 * USDC);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+}
+
+/// Only perpetual series charge funding, within the cap, and never on margin
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn funding_config_is_validated() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let dated = h.now().await + 30 * DAY;
+
+    for (expiration, option_type, config) in [
+        (
+            dated,
+            OptionType::Call,
+            SeriesConfig {
+                funding_rate_bps: RATE_BPS,
+                ..SeriesConfig::default()
+            },
+        ),
+        (
+            PERPETUAL_EXPIRATION,
+            OptionType::Put,
+            SeriesConfig {
+                funding_rate_bps: MAX_FUNDING_RATE_BPS + 1,
+                ..SeriesConfig::default()
+            },
+        ),
+        (
+            PERPETUAL_EXPIRATION,
+            OptionType::Call,
+            SeriesConfig {
+                funding_rate_bps: RATE_BPS,
+                initial_margin_bps: 2_000,
+                ..SeriesConfig::default()
+            },
+        ),
+    ] {
+        let (_, ix) = create_series_ix(
+            &alice.pubkey(),
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            option_type,
+            config,
+        );
+        let result = h.send(&[ix], &[&alice]).await;
+        assert_anchor_error(result, ErrorCode::InvalidPerpetualConfig);
+    }
+}
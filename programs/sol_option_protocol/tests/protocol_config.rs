@@ -0,0 +1,200 @@
+//! Protocol-level parameters: the admin's mint and exercise fees, their
+//! volume tiers and exemptions, and the feature switches new series are
+//! checked against.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{
+    assert_anchor_error, create_series_ix, protocol_config_address, Harness, DAY, STRIKE, USDC,
+};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instructions::protocol_fee::fee_due;
+use sol_option_protocol::instructions::PERPETUAL_EXPIRATION;
+use sol_option_protocol::state::{FeeStats, FeeTier, ProtocolConfig};
+use sol_option_protocol::utils::validation::{
+    validate_features, validate_fee_bps, validate_fee_exempt, validate_fee_tiers,
+    validate_series_features,
+};
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+fn config(disabled_features: u32) -> ProtocolConfig {
+    ProtocolConfig {
+        admin: Pubkey::new_unique(),
+        pending_admin: Pubkey::default(),
+        bump: 255,
+        max_series_per_epoch: 0,
+        creation_cooldown: 0,
+        guardian: Pubkey::default(),
+        treasury: Pubkey::default(),
+        unclaimed_timeout: 0,
+        creation_bond: 0,
+        expiry_grid_interval: 0,
+        expiry_grid_offset: 0,
+        expiry_grid_exempt: [Pubkey::default(); ProtocolConfig::MAX_EXPIRY_GRID_EXEMPT],
+        min_time_to_expiry: 0,
+        max_time_to_expiry: 0,
+        mint_fee_bps: 0,
+        exercise_fee_bps: 0,
+        disabled_features,
+        fee_exempt: [Pubkey::default(); ProtocolConfig::MAX_FEE_EXEMPT],
+        fee_tiers: [FeeTier::default(); ProtocolConfig::MAX_FEE_TIERS],
+        referral_share_bps: 0,
+        paused: false,
+        mint_allowlist_enabled: false,
+        timelock_delay: 0,
+        insurance_share_bps: 0,
+        allowed_mint_extensions: 0,
+    }
+}
+
+#[test]
+fn fees_are_capped() {
+    assert!(validate_fee_bps(0).is_ok());
+    assert!(validate_fee_bps(ProtocolConfig::MAX_FEE_BPS).is_ok());
+    assert!(validate_fee_bps(ProtocolConfig::MAX_FEE_BPS + 1).is_err());
+}
+
+fn tier(min_volume: u64, discount_bps: u16) -> FeeTier {
+    FeeTier {
+        min_volume,
+        discount_bps,
+    }
+}
+
+#[test]
+fn volume_tiers_discount_the_fee() {
+    let mut config = config(0);
+    config.fee_tiers[0] = tier(100_000, 2_000);
+    config.fee_tiers[1] = tier(1_000_000, 5_000);
+    assert_eq!(config.fee_discount_bps(0, 6), 0);
+    assert_eq!(config.fee_discount_bps(99_999 * USDC, 6), 0);
+    assert_eq!(config.fee_discount_bps(100_000 * USDC, 6), 2_000);
+    assert_eq!(config.fee_discount_bps(u64::MAX, 6), 5_000);
+
+    let user = Pubkey::new_unique();
+    let stats = FeeStats {
+        user,
+        consideration_mint: Pubkey::new_unique(),
+        volume: 250_000 * USDC,
+        bump: 255,
+    };
+    // 0.25% of 600 USDC is 1.5 USDC; the first tier waives a fifth of it
+    assert_eq!(
+        fee_due(&config, &user, 600 * USDC, 25, None, 6).unwrap(),
+        1_500_000
+    );
+    assert_eq!(
+        fee_due(&config, &user, 600 * USDC, 25, Some(&stats), 6).unwrap(),
+        1_200_000
+    );
+
+    config.fee_exempt[0] = user;
+    assert!(config.is_fee_exempt(&user));
+    assert!(!config.is_fee_exempt(&Pubkey::default()));
+    assert_eq!(fee_due(&config, &user, 600 * USDC, 25, None, 6).unwrap(), 0);
+}
+
+#[test]
+fn fee_overrides_are_validated() {
+    assert!(validate_fee_tiers(&[]).is_ok());
+    assert!(validate_fee_tiers(&[tier(1, 100), tier(2, 100), tier(3, 10_000)]).is_ok());
+    assert!(validate_fee_tiers(&[tier(0, 100)]).is_err());
+    assert!(validate_fee_tiers(&[tier(2, 100), tier(1, 200)]).is_err());
+    assert!(validate_fee_tiers(&[tier(1, 200), tier(2, 100)]).is_err());
+    assert!(validate_fee_tiers(&[tier(1, 10_001)]).is_err());
+    assert!(
+        validate_fee_tiers(&[tier(1, 1), tier(2, 2), tier(3, 3), tier(4, 4), tier(5, 5)]).is_err()
+    );
+
+    assert!(validate_fee_exempt(&[Pubkey::new_unique()]).is_ok());
+    assert!(validate_fee_exempt(&[Pubkey::default()]).is_err());
+    let five: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    assert!(validate_fee_exempt(&five).is_err());
+}
+
+#[test]
+fn only_known_features_switch_off() {
+    assert!(validate_features(0).is_ok());
+    assert!(validate_features(ProtocolConfig::ALL_FEATURES).is_ok());
+    assert!(validate_features(ProtocolConfig::ALL_FEATURES + 1).is_err());
+
+    let config = config(ProtocolConfig::FEATURE_MARGIN);
+    assert!(!config.is_enabled(ProtocolConfig::FEATURE_MARGIN));
+    assert!(config.is_enabled(ProtocolConfig::FEATURE_FORWARDS));
+}
+
+#[test]
+fn series_are_checked_against_the_switches() {
+    let dated = 30 * DAY;
+    let forwards_off = config(ProtocolConfig::FEATURE_FORWARDS);
+    assert!(validate_series_features(&forwards_off, OptionType::Call, dated, 0, 0, false).is_ok());
+    assert!(
+        validate_series_features(&forwards_off, OptionType::Forward, dated, 0, 0, false).is_err()
+    );
+
+    let perpetual = PERPETUAL_EXPIRATION;
+    let all_off = config(ProtocolConfig::ALL_FEATURES);
+    // A plain dated call or put uses no optional feature
+    assert!(validate_series_features(&all_off, OptionType::Put, dated, 0, 0, false).is_ok());
+    assert!(validate_series_features(&all_off, OptionType::Put, perpetual, 0, 0, false).is_err());
+    assert!(validate_series_features(&all_off, OptionType::Put, dated, 2_000, 0, false).is_err());
+    assert!(validate_series_features(&all_off, OptionType::Put, dated, 0, 100, false).is_err());
+    assert!(validate_series_features(&all_off, OptionType::Put, dated, 0, 0, true).is_err());
+    assert!(
+        validate_series_features(&all_off, OptionType::BinaryCall, dated, 0, 0, false).is_err()
+    );
+}
+
+/// The admin sets fees within the cap and switches forwards off, after which
+/// new forward series are refused
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn admin_sets_fees_and_features() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    h.set_fees(10, 25).await.unwrap();
+    let result = h.set_fees(ProtocolConfig::MAX_FEE_BPS + 1, 0).await;
+    assert_anchor_error(result, ErrorCode::FeeTooHigh);
+    let result = h.set_features(1 << 31).await;
+    assert_anchor_error(result, ErrorCode::InvalidFeatures);
+    h.set_features(ProtocolConfig::FEATURE_FORWARDS)
+        .await
+        .unwrap();
+
+    let state: ProtocolConfig = h.account(&protocol_config_address()).await;
+    assert_eq!(state.mint_fee_bps, 10);
+    assert_eq!(state.exercise_fee_bps, 25);
+    assert!(!state.is_enabled(ProtocolConfig::FEATURE_FORWARDS));
+
+    let expiration = h.now().await + 30 * DAY;
+    let (_, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Forward,
+        SeriesConfig::default(),
+    );
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::FeatureDisabled);
+
+    // Switched back on, the same series goes through
+    h.set_features(0).await.unwrap();
+    h.create_typed_series(
+        &alice,
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Forward,
+        SeriesConfig::default(),
+    )
+    .await;
+}
@@ -0,0 +1,205 @@
+//! Protocol fees: mints pay a share of the escrow and exercises a share of the
+//! strike into fee accounts of the ProtocolConfig PDA, which only the admin
+//! can withdraw from. Volume tiers discount them, exempt accounts skip them and
+//! referrers passed along take a share.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use common::{assert_anchor_error, fee_vault_address, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::{FeeStats, FeeTier, ReferralStats};
+use sol_option_protocol::utils::math::calculate_fee;
+use sol_option_protocol::utils::validation::validate_referral_share;
+
+#[test]
+fn fees_round_down() {
+    assert_eq!(calculate_fee(10 * SOL, 10).unwrap(), 10_000_000);
+    assert_eq!(calculate_fee(600 * USDC, 25).unwrap(), 1_500_000);
+    assert_eq!(calculate_fee(399, 25).unwrap(), 0);
+    assert_eq!(calculate_fee(u64::MAX, 0).unwrap(), 0);
+}
+
+#[test]
+fn referral_shares_are_recorded() {
+    assert!(validate_referral_share(0).is_ok());
+    assert!(validate_referral_share(10_000).is_ok());
+    assert!(validate_referral_share(10_001).is_err());
+
+    // 30% of a 1.5 USDC fee
+    let share = calculate_fee(1_500_000, 3_000).unwrap();
+    assert_eq!(share, 450_000);
+    let mut stats = ReferralStats {
+        referrer: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        earned: u64::MAX - 1,
+        referred_fees: 0,
+        bump: 255,
+    };
+    stats.record(share);
+    assert_eq!(stats.earned, u64::MAX);
+    assert_eq!(stats.referred_fees, 1);
+}
+
+/// With a 0.1% mint fee and a 0.25% exercise fee, Alice writing 10 calls pays
+/// 0.01 SOL and Bob exercising 4 pays 1.5 USDC; the admin withdraws both
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mint_and_exercise_fees_reach_the_fee_accounts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    h.set_fees(10, 25).await.unwrap();
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 11 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 700 * USDC).await;
+
+    // A fee is due, so the fee account is required
+    let result = h
+        .option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidFeeVault);
+    h.fee_option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a, &sol)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 990_000_000);
+    assert_eq!(h.balance(&series.collateral_vault).await, 10 * SOL);
+    assert_eq!(h.balance(&fee_vault_address(&sol)).await, 10_000_000);
+
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.fee_option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b, &usdc)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.consideration).await, 98_500_000);
+    assert_eq!(h.balance(&series.consideration_vault).await, 600 * USDC);
+    assert_eq!(h.balance(&fee_vault_address(&usdc)).await, 1_500_000);
+
+    // Burns charge nothing
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    let treasury = h.actor().await;
+    let destination = h.create_ata(&usdc, &treasury.pubkey()).await;
+    h.withdraw_fees(&usdc, &destination, 1_500_000)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&destination).await, 1_500_000);
+    assert_eq!(h.balance(&fee_vault_address(&usdc)).await, 0);
+}
+
+/// Bob tracks his USDC volume: his first 4 exercises (600 USDC) pay the full
+/// 0.25%, after which he reaches the 500 USDC tier and pays half. Alice, a
+/// fee-exempt market maker, writes without paying the mint fee.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn tiers_and_exemptions_reduce_fees() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    h.set_fees(10, 25).await.unwrap();
+    h.set_fee_exempt(vec![alice.pubkey()]).await.unwrap();
+    let tiers = vec![FeeTier {
+        min_volume: 500,
+        discount_bps: 5_000,
+    }];
+    h.set_fee_tiers(tiers).await.unwrap();
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 1_000 * USDC).await;
+    h.fee_option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a, &sol)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 0);
+    assert_eq!(h.balance(&fee_vault_address(&sol)).await, 0);
+
+    let stats = h.open_fee_stats(&bob, &usdc).await;
+    h.transfer(&alice, &a.option, &b.option, 6 * SOL).await;
+    h.fee_option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b, &usdc)
+        .await
+        .unwrap();
+    let state: FeeStats = h.account(&stats).await;
+    assert_eq!(state.volume, 600 * USDC);
+    assert_eq!(h.balance(&fee_vault_address(&usdc)).await, 1_500_000);
+
+    h.fee_option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b, &usdc)
+        .await
+        .unwrap();
+    assert_eq!(
+        h.balance(&fee_vault_address(&usdc)).await,
+        1_500_000 + 375_000
+    );
+    let state: FeeStats = h.account(&stats).await;
+    assert_eq!(state.volume, 900 * USDC);
+}
+
+/// With a 30% referral share, Carol's integration sends Bob's exercise: 0.45
+/// USDC of his 1.5 USDC fee goes to Carol and the rest to the protocol. Bob
+/// can't name himself as referrer.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn referrers_take_their_share() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+    h.set_fees(0, 25).await.unwrap();
+    let result = h.set_referral_share(10_001).await;
+    assert_anchor_error(result, ErrorCode::InvalidReferralShare);
+    h.set_referral_share(3_000).await.unwrap();
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 700 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+
+    h.open_referral_stats(&bob, &usdc).await;
+    let exercise = ix::Exercise { amount: 4 * SOL };
+    let result = h
+        .referred_option_ix(exercise, &series, &bob, &b, &usdc, &bob.pubkey())
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidReferral);
+
+    let stats = h.open_referral_stats(&carol, &usdc).await;
+    let exercise = ix::Exercise { amount: 4 * SOL };
+    h.referred_option_ix(exercise, &series, &bob, &b, &usdc, &carol.pubkey())
+        .await
+        .unwrap();
+    let carol_usdc = get_associated_token_address(&carol.pubkey(), &usdc);
+    assert_eq!(h.balance(&carol_usdc).await, 450_000);
+    assert_eq!(h.balance(&fee_vault_address(&usdc)).await, 1_050_000);
+    assert_eq!(h.balance(&b.consideration).await, 98_500_000);
+    let state: ReferralStats = h.account(&stats).await;
+    assert_eq!(state.earned, 450_000);
+    assert_eq!(state.referred_fees, 1);
+}
@@ -0,0 +1,169 @@
+//! PsyOptions American interface: `mint_option`, `exercise_option`,
+//! `close_position` and `close_post_expiration` take PsyOptions' instruction
+//! data and leading accounts, and run the `mint`, `exercise`, `burn` and
+//! `redeem` handlers.
+
+mod common;
+
+use anchor_lang::Discriminator;
+use anchor_spl::token::spl_token;
+use common::{create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::client::{self, SeriesAddresses};
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::migration::psy_american::CLOSE_POSITION_DISCRIMINATOR;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn unique_series() -> SeriesAddresses {
+    SeriesAddresses {
+        option_context: Pubkey::new_unique(),
+        collateral_mint: Pubkey::new_unique(),
+        consideration_mint: Pubkey::new_unique(),
+        option_mint: Pubkey::new_unique(),
+        redemption_mint: Pubkey::new_unique(),
+        collateral_vault: Pubkey::new_unique(),
+        consideration_vault: Pubkey::new_unique(),
+        token_program: spl_token::ID,
+    }
+}
+
+#[test]
+fn instructions_share_psy_discriminators() {
+    assert_eq!(
+        ix::ClosePosition::DISCRIMINATOR,
+        CLOSE_POSITION_DISCRIMINATOR
+    );
+    let data = client::psy_close_position_ix(&unique_series(), &Pubkey::new_unique(), 5).data;
+    assert_eq!(data[..8], CLOSE_POSITION_DISCRIMINATOR);
+    assert_eq!(data[8..], 5u64.to_le_bytes());
+}
+
+#[test]
+fn psy_accounts_lead_in_psy_order() {
+    let series = unique_series();
+    let user = Pubkey::new_unique();
+    let ata = |mint: &Pubkey| series.associated(&user, mint);
+
+    let close = client::psy_close_position_ix(&series, &user, SOL);
+    let keys: Vec<Pubkey> = close.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(
+        keys[..9],
+        [
+            user,
+            series.option_context,
+            series.option_mint,
+            ata(&series.option_mint),
+            series.redemption_mint,
+            ata(&series.redemption_mint),
+            series.collateral_vault,
+            ata(&series.collateral_mint),
+            spl_token::ID,
+        ]
+    );
+    // The series is PsyOptions' read-only option market, written here
+    assert!(close.accounts[1].is_writable);
+
+    let mint = client::psy_mint_option_ix(&series, &user, SOL);
+    let keys: Vec<Pubkey> = mint.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(
+        keys[..9],
+        [
+            user,
+            series.collateral_mint,
+            series.collateral_vault,
+            ata(&series.collateral_mint),
+            series.option_mint,
+            ata(&series.option_mint),
+            series.redemption_mint,
+            ata(&series.redemption_mint),
+            series.option_context,
+        ]
+    );
+
+    let exercise = client::psy_exercise_option_ix(&series, &user, SOL);
+    let keys: Vec<Pubkey> = exercise.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(
+        keys[..9],
+        [
+            user,
+            user,
+            series.option_context,
+            series.option_mint,
+            ata(&series.option_mint),
+            series.collateral_vault,
+            ata(&series.collateral_mint),
+            series.consideration_vault,
+            ata(&series.consideration_mint),
+        ]
+    );
+
+    let close = client::psy_close_post_expiration_ix(&series, &user, SOL);
+    let keys: Vec<Pubkey> = close.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(
+        keys[..7],
+        [
+            user,
+            series.option_context,
+            series.redemption_mint,
+            ata(&series.redemption_mint),
+            series.collateral_vault,
+            ata(&series.collateral_mint),
+            spl_token::ID,
+        ]
+    );
+}
+
+/// Alice writes, closes part and sells an option to Bob, who exercises it;
+/// Alice closes the rest after expiry, all through the PsyOptions interface
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn psy_interface_drives_a_series() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig::default(),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    let addresses = SeriesAddresses::of(series.option_context, &data, series.token_program);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+
+    let mint = client::psy_mint_option_ix(&addresses, &alice.pubkey(), 3 * SOL);
+    h.send(&[mint], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.option).await, 3 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 3 * SOL);
+
+    let close = client::psy_close_position_ix(&addresses, &alice.pubkey(), SOL);
+    h.send(&[close], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    let exercise = client::psy_exercise_option_ix(&addresses, &bob.pubkey(), SOL);
+    h.send(&[exercise], &[&bob]).await.unwrap();
+    assert_eq!(h.balance(&b.collateral).await, SOL);
+    assert_eq!(h.balance(&b.consideration).await, 0);
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let close = client::psy_close_post_expiration_ix(&addresses, &alice.pubkey(), 2 * SOL);
+    h.send(&[close], &[&alice]).await.unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 150 * USDC);
+}
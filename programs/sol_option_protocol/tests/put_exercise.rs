@@ -0,0 +1,176 @@
+//! Put exercise: writers lock the strike value in consideration, holders
+//! deliver the underlying and receive the strike from the vault.
+
+mod common;
+
+use common::{Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::net_expiry;
+use sol_option_protocol::utils::math::{calculate_put_collateral, calculate_strike_value};
+use sol_option_protocol::{OptionData, OptionType};
+
+#[test]
+fn put_collateral_rounds_up() {
+    // 1 raw BONK (5 decimals) at a 0.04 USDC strike is worth 0.4 raw USDC
+    assert_eq!(calculate_put_collateral(1, 40_000, 5).unwrap(), 1);
+    assert_eq!(calculate_strike_value(1, 40_000, 5).unwrap(), 0);
+
+    // Exact multiples do not round
+    assert_eq!(
+        calculate_put_collateral(10 * SOL, STRIKE, 9).unwrap(),
+        1_500 * USDC
+    );
+}
+
+#[test]
+fn put_collateral_covers_split_exercises() {
+    // Minted one raw unit at a time, exercised all at once: locked ≥ paid out
+    let strike = 40_000;
+    let locked: u64 = (0..25)
+        .map(|_| calculate_put_collateral(1, strike, 5).unwrap())
+        .sum();
+    let paid = calculate_strike_value(25, strike, 5).unwrap();
+    assert_eq!(locked, 25);
+    assert_eq!(paid, 10);
+    assert!(locked >= paid);
+}
+
+#[test]
+fn escrow_follows_option_type() {
+    // Calls escrow the collateral itself, puts the strike value in consideration
+    let mut series = OptionData {
+        strike_price: 40_000,
+        strike_scale: 5,
+        contract_multiplier: 1,
+        ..OptionData::default()
+    };
+    assert_eq!(series.escrow_locked(3).unwrap(), 3);
+    assert_eq!(series.escrow_payout(3).unwrap(), 3);
+    series.credit_escrow(3).unwrap();
+    assert_eq!(series.collateral_deposited, 3);
+
+    series.option_type = OptionType::Put;
+    assert_eq!(series.escrow_balance(), 0);
+    assert_eq!(series.escrow_locked(3).unwrap(), 2); // 1.2 raw USDC, rounded up
+    assert_eq!(series.escrow_payout(3).unwrap(), 1); // rounded down
+    series.credit_escrow(2).unwrap();
+    assert_eq!(series.consideration_received, 2);
+    assert_eq!(series.escrow_balance(), 2);
+    series.debit_escrow(1).unwrap();
+    assert!(series.debit_escrow(2).is_err());
+    assert_eq!(series.collateral_balance(), 3);
+}
+
+#[test]
+fn put_expiry_payoff() {
+    // 10 puts written (1500 USDC locked), all outstanding, strike 150
+    let series = OptionData {
+        option_type: OptionType::Put,
+        strike_price: STRIKE,
+        strike_scale: 9,
+        contract_multiplier: 1,
+        consideration_received: 1_500 * USDC,
+        ..OptionData::default()
+    };
+
+    // Settles at 120: in the money, holders are owed 30 USDC per option
+    let (itm, margin_owed) = net_expiry(&series, Some(120 * USDC), 10 * SOL).unwrap();
+    assert!(itm.in_the_money);
+    assert_eq!(margin_owed, 0);
+    assert_eq!(itm.option_collateral, 0);
+    assert_eq!(itm.option_consideration, 300 * USDC);
+    assert_eq!(itm.redemption_collateral, 0);
+    assert_eq!(itm.redemption_consideration, 1_200 * USDC);
+
+    // Settles at 180: out of the money, writers keep the cash
+    let (otm, _) = net_expiry(&series, Some(180 * USDC), 10 * SOL).unwrap();
+    assert!(!otm.in_the_money);
+    assert_eq!(otm.option_consideration, 0);
+    assert_eq!(otm.redemption_consideration, 1_500 * USDC);
+
+    // Without a price (knocked out, frozen, no oracle) every put expires worthless
+    let (worthless, _) = net_expiry(&series, None, 10 * SOL).unwrap();
+    assert_eq!(worthless.option_consideration, 0);
+    assert_eq!(worthless.redemption_consideration, 1_500 * USDC);
+}
+
+#[test]
+fn option_type_byte_round_trips() {
+    // The seed/account byte is the variant's discriminant; unknown kinds fail
+    assert_eq!(OptionType::Call as u8, 0);
+    assert_eq!(OptionType::Put as u8, 1);
+    assert_eq!(OptionType::try_from(1).unwrap(), OptionType::Put);
+    assert!(OptionType::try_from(5).is_err());
+}
+
+/// Alice writes 10 cash-secured puts and sells 4 to Bob, who exercises them.
+/// Alice unwinds 2 with a paired burn and redeems the rest after expiry.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn cash_secured_put_lifecycle() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, true)
+        .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.option_type, OptionType::Put);
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&usdc, &a.consideration, 2_000 * USDC).await;
+    h.mint_to(&sol, &b.collateral, 4 * SOL).await;
+
+    // --- Alice writes 10 puts: 10 x 150 USDC locked -----------------------------
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&a.consideration).await, 500 * USDC);
+    assert_eq!(h.balance(&a.option).await, 10 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 10 * SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, 1_500 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+
+    // --- Bob exercises 4: delivers 4 SOL, receives 600 USDC ---------------------
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&b.option).await, 0);
+    assert_eq!(h.balance(&b.collateral).await, 0);
+    assert_eq!(h.balance(&b.consideration).await, 600 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 4 * SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, 900 * USDC);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.exercised_amount, 4 * SOL);
+
+    // --- Alice burns 2 pairs: 300 USDC back --------------------------------------
+    h.option_ix(ix::Burn { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&a.consideration).await, 800 * USDC);
+    assert_eq!(h.balance(&series.consideration_vault).await, 600 * USDC);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.total_supply, 8 * SOL);
+
+    // --- After expiry Alice redeems 8: delivered SOL + remaining cash ------------
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 8 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&a.collateral).await, 4 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 1_400 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+}
@@ -0,0 +1,204 @@
+//! Cash-secured put vaults: depositors pool the quote mint and each round
+//! the vault writes puts against it. Underlying the puts are assigned is held
+//! by the vault until the manager re-lists it on a spot market; the listing
+//! pays back into the vault's quote balance.
+
+mod common;
+
+use common::{
+    assert_anchor_error, create_series_ix, Harness, Series, Vault, DAY, SOL, STRIKE, USDC,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::OptionVault;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+async fn put_series(h: &mut Harness, manager: &Keypair, vault: &Vault) -> Series {
+    let expiration = h.now().await + 7 * DAY;
+    let (series, ix) = create_series_ix(
+        &manager.pubkey(),
+        vault.underlying_mint,
+        vault.quote_mint,
+        STRIKE,
+        expiration,
+        OptionType::Put,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[manager]).await.unwrap();
+    series
+}
+
+/// Alice pools 300 USDC; the round sells 1 of 2 puts at 5 USDC and Bob
+/// exercises it. The vault re-lists the SOL it was assigned at 160 USDC,
+/// Carol buys it, and Alice leaves with 315 USDC
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn assigned_underlying_is_relisted_for_the_pool() {
+    let mut h = Harness::start().await;
+    let manager = h.actor().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let vault = h
+        .create_option_vault(&manager, sol, usdc, OptionType::Put)
+        .await
+        .unwrap();
+    h.fund_vault_authority(&vault).await;
+
+    // Shares follow the quote mint's decimals
+    let alice_sol = h.create_ata(&sol, &alice.pubkey()).await;
+    let alice_usdc = h.create_ata(&usdc, &alice.pubkey()).await;
+    h.mint_to(&usdc, &alice_usdc, 300 * USDC).await;
+    let deposit = ix::DepositToVault { amount: 300 * USDC };
+    h.vault_shares_ix(deposit, &vault, &alice, alice_sol, alice_usdc)
+        .await
+        .unwrap();
+    assert_eq!(h.supply(&vault.share_mint).await, 300 * USDC);
+
+    let series = put_series(&mut h, &manager, &vault).await;
+    let market = h.open_series_market(&manager, &series).await.unwrap();
+    let ask = h
+        .start_vault_round(&manager, &vault, &series, market, 2 * SOL, 5 * USDC)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&vault.quote_account).await, 0);
+
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&usdc, &b.consideration, 5 * USDC).await;
+    h.mint_to(&sol, &b.collateral, SOL).await;
+    h.fill_order(
+        &bob,
+        market,
+        ask,
+        series.option_mint,
+        usdc,
+        b.option,
+        b.consideration,
+        vault.quote_account,
+        Some(series.option_context),
+        SOL,
+    )
+    .await
+    .unwrap();
+    h.option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    let data: OptionData = h.account(&series.option_context).await;
+    h.warp_to(data.expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.settle_vault_round(&vault, &series, ask).await.unwrap();
+    assert_eq!(h.balance(&vault.underlying_account).await, SOL);
+    assert_eq!(h.balance(&vault.quote_account).await, 155 * USDC);
+
+    let spot = h.create_market(&manager, sol, usdc).await;
+    let listing = h
+        .list_vault_collateral(&manager, &vault, spot, SOL, 160 * USDC)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&vault.underlying_account).await, 0);
+
+    // Shares wait for the listing, and only the manager closes it unsold
+    let withdraw = ix::WithdrawFromVault { shares: 300 * USDC };
+    let result = h
+        .vault_shares_ix(withdraw, &vault, &alice, alice_sol, alice_usdc)
+        .await;
+    assert_anchor_error(result, ErrorCode::VaultListingOpen);
+    let result = h.close_vault_listing(&bob, &vault, listing).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+
+    let carol_sol = h.create_ata(&sol, &carol.pubkey()).await;
+    let carol_usdc = h.create_ata(&usdc, &carol.pubkey()).await;
+    h.mint_to(&usdc, &carol_usdc, 160 * USDC).await;
+    h.fill_order(
+        &carol,
+        spot,
+        listing,
+        sol,
+        usdc,
+        carol_sol,
+        carol_usdc,
+        vault.quote_account,
+        None,
+        SOL,
+    )
+    .await
+    .unwrap();
+    h.next_blockhash().await;
+    h.close_vault_listing(&bob, &vault, listing).await.unwrap();
+    assert!(!h
+        .account::<OptionVault>(&vault.option_vault)
+        .await
+        .listing_open());
+
+    h.next_blockhash().await;
+    let withdraw = ix::WithdrawFromVault { shares: 300 * USDC };
+    h.vault_shares_ix(withdraw, &vault, &alice, alice_sol, alice_usdc)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&alice_usdc).await, 315 * USDC);
+    assert_eq!(h.balance(&alice_sol).await, 0);
+}
+
+/// The manager takes unsold underlying back off the book
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn manager_closes_an_unsold_listing() {
+    let mut h = Harness::start().await;
+    let manager = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let vault = h
+        .create_option_vault(&manager, sol, usdc, OptionType::Put)
+        .await
+        .unwrap();
+    h.fund_vault_authority(&vault).await;
+    h.mint_to(&sol, &vault.underlying_account, 2 * SOL).await;
+
+    let spot = h.create_market(&manager, sol, usdc).await;
+    let listing = h
+        .list_vault_collateral(&manager, &vault, spot, 2 * SOL, 160 * USDC)
+        .await
+        .unwrap();
+    h.next_blockhash().await;
+    let result = h
+        .list_vault_collateral(&manager, &vault, spot, SOL, 160 * USDC)
+        .await
+        .map(|_| ());
+    assert_anchor_error(result, ErrorCode::VaultListingOpen);
+
+    h.close_vault_listing(&manager, &vault, listing)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&vault.underlying_account).await, 2 * SOL);
+}
+
+/// Call vaults are never assigned underlying to re-list
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn call_vaults_dont_relist() {
+    let mut h = Harness::start().await;
+    let manager = h.actor().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let vault = h
+        .create_option_vault(&manager, sol, usdc, OptionType::Call)
+        .await
+        .unwrap();
+    h.fund_vault_authority(&vault).await;
+    h.mint_to(&sol, &vault.underlying_account, SOL).await;
+    let spot = h.create_market(&manager, sol, usdc).await;
+
+    let result = h
+        .list_vault_collateral(&manager, &vault, spot, SOL, 160 * USDC)
+        .await
+        .map(|_| ());
+    assert_anchor_error(result, ErrorCode::NotAPutVault);
+}
@@ -0,0 +1,116 @@
+//! Pyth adapter: owner, discriminator and verification checks on
+//! `PriceUpdateV2` accounts, and conversion into strike units.
+
+use anchor_lang::prelude::*;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::oracle::pyth::{
+    PriceFeedMessage, PriceUpdateV2, VerificationLevel, PRICE_UPDATE_V2_DISCRIMINATOR,
+    PYTH_RECEIVER_PROGRAM_ID,
+};
+use sol_option_protocol::oracle::{
+    read_guarded_price, OracleFeed, OracleGuard, OracleKind, PriceRequest,
+};
+
+const PUBLISH_TIME: i64 = 1_700_000_000;
+
+/// SOL/USD at $150.25 ± $0.05 (Pyth exponent -8)
+fn price_update(verification_level: VerificationLevel) -> Vec<u8> {
+    let update = PriceUpdateV2 {
+        write_authority: Pubkey::new_unique(),
+        verification_level,
+        price_message: PriceFeedMessage {
+            feed_id: [7; 32],
+            price: 15_025_000_000,
+            conf: 5_000_000,
+            exponent: -8,
+            publish_time: PUBLISH_TIME,
+            prev_publish_time: PUBLISH_TIME - 1,
+            ema_price: 15_000_000_000,
+            ema_conf: 4_000_000,
+        },
+        posted_slot: 1,
+    };
+    let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+    update.serialize(&mut data).unwrap();
+    data
+}
+
+fn read(owner: Pubkey, mut data: Vec<u8>, guard: OracleGuard) -> Result<u64> {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    let feed = OracleFeed {
+        kind: OracleKind::Pyth,
+        feed: key,
+        guard,
+    };
+    let request = PriceRequest {
+        reference_time: PUBLISH_TIME,
+        late_window: 0,
+        previous_price: 0,
+        previous_update: 0,
+        strike_exponent: 6,
+    };
+    read_guarded_price(&feed, Some(&account), &request).map(|(value, _)| value)
+}
+
+fn assert_error(result: Result<u64>, code: ErrorCode) {
+    assert_eq!(result.unwrap_err(), error!(code));
+}
+
+#[test]
+fn reads_fully_verified_update_in_strike_units() {
+    let data = price_update(VerificationLevel::Full);
+    let value = read(PYTH_RECEIVER_PROGRAM_ID, data, OracleGuard::default()).unwrap();
+    assert_eq!(value, 150_250_000);
+}
+
+#[test]
+fn rejects_partially_verified_update() {
+    let data = price_update(VerificationLevel::Partial { num_signatures: 5 });
+    let result = read(PYTH_RECEIVER_PROGRAM_ID, data, OracleGuard::default());
+    assert_error(result, ErrorCode::UnverifiedOraclePrice);
+}
+
+#[test]
+fn rejects_foreign_owner_and_discriminator() {
+    let data = price_update(VerificationLevel::Full);
+    let result = read(Pubkey::new_unique(), data, OracleGuard::default());
+    assert_error(result, ErrorCode::InvalidOracleAccount);
+
+    let mut data = price_update(VerificationLevel::Full);
+    data[0] ^= 1;
+    let result = read(PYTH_RECEIVER_PROGRAM_ID, data, OracleGuard::default());
+    assert_error(result, ErrorCode::InvalidOracleAccount);
+}
+
+#[test]
+fn applies_confidence_guard() {
+    // 0.05 / 150.25 is ~3.3 bps
+    let tight = OracleGuard {
+        max_confidence_bps: 3,
+        ..OracleGuard::default()
+    };
+    let data = price_update(VerificationLevel::Full);
+    assert_error(
+        read(PYTH_RECEIVER_PROGRAM_ID, data, tight),
+        ErrorCode::OracleConfidenceTooWide,
+    );
+
+    let loose = OracleGuard {
+        max_confidence_bps: 4,
+        ..OracleGuard::default()
+    };
+    let data = price_update(VerificationLevel::Full);
+    assert!(read(PYTH_RECEIVER_PROGRAM_ID, data, loose).is_ok());
+}
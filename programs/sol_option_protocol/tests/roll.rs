@@ -0,0 +1,75 @@
+//! roll_position: paired tokens move to the same strike at a later expiry,
+//! with the escrow going straight from one vault to the other.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::utils::validation::validate_roll_target;
+use sol_option_protocol::{OptionData, OptionType};
+
+fn series(option_type: OptionType, strike_price: u64, expiration: i64) -> OptionData {
+    OptionData {
+        option_type,
+        strike_price,
+        expiration,
+        contract_multiplier: 1,
+        ..OptionData::default()
+    }
+}
+
+#[test]
+fn rolls_only_move_out_in_time() {
+    let from = series(OptionType::Call, STRIKE, 30 * DAY);
+    assert!(validate_roll_target(&from, &series(OptionType::Call, STRIKE, 60 * DAY)).is_ok());
+    // Earlier or same expiry, other strike, other type
+    assert!(validate_roll_target(&from, &series(OptionType::Call, STRIKE, 30 * DAY)).is_err());
+    assert!(validate_roll_target(&from, &series(OptionType::Call, STRIKE, 7 * DAY)).is_err());
+    assert!(validate_roll_target(&from, &series(OptionType::Call, 2 * STRIKE, 60 * DAY)).is_err());
+    assert!(validate_roll_target(&from, &series(OptionType::Put, STRIKE, 60 * DAY)).is_err());
+}
+
+/// Alice wrote 10 puts for June, and rolls 6 of them to July without any more
+/// USDC: the 900 USDC escrow moves vault to vault
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn put_roll_moves_the_escrow() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let june = h.now().await + 30 * DAY;
+    let from = h.create_series(&alice, sol, usdc, STRIKE, june, true).await;
+    let to = h
+        .create_series(&alice, sol, usdc, STRIKE, june + 30 * DAY, true)
+        .await;
+    let a = h.wallet(&from, &alice.pubkey()).await;
+    h.mint_to(&usdc, &a.consideration, 1_500 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &from, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 0);
+
+    h.roll(&alice, &from, &to, 6 * SOL).await.unwrap();
+    let t = h.wallet(&to, &alice.pubkey()).await;
+    assert_eq!(h.balance(&a.option).await, 4 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 4 * SOL);
+    assert_eq!(h.balance(&t.option).await, 6 * SOL);
+    assert_eq!(h.balance(&t.redemption).await, 6 * SOL);
+    assert_eq!(h.balance(&from.consideration_vault).await, 600 * USDC);
+    assert_eq!(h.balance(&to.consideration_vault).await, 900 * USDC);
+    assert_eq!(h.balance(&a.consideration).await, 0);
+
+    let state: OptionData = h.account(&to.option_context).await;
+    assert_eq!(state.total_supply, 6 * SOL);
+    assert_eq!(state.escrow_balance(), 900 * USDC);
+
+    // Can't roll back in time
+    h.next_blockhash().await;
+    let result = h.roll(&alice, &to, &from, SOL).await;
+    assert_anchor_error(result, ErrorCode::InvalidRoll);
+}
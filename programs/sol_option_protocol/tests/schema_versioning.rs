@@ -0,0 +1,502 @@
+//! Schema versioning: series and markets carry a layout `version` and zeroed
+//! `reserved` space, and the permissionless `migrate_series` and
+//! `migrate_market` grow accounts written before versioning to the current
+//! layout and step their version up. Legacy series get their ledger back
+//! from their vaults and the protocol admin as authority; legacy puts, which
+//! escrowed collateral like calls, can then only be burned and redeemed.
+
+mod common;
+
+use anchor_lang::prelude::AccountInfo;
+use anchor_lang::{
+    AccountDeserialize, AccountSerialize, Discriminator, InstructionData, Space, ToAccountMetas,
+};
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::LegacyState;
+use sol_option_protocol::instructions::{MAX_METADATA_URI_LEN, MAX_UNDERLYING_SYMBOL_LEN};
+use sol_option_protocol::state::ProtocolConfig;
+use sol_option_protocol::utils::validation::{validate_not_legacy_put, validate_spread_legs};
+use sol_option_protocol::{OptionData, OptionType};
+use spl_marketplace::errors::ErrorCode as MarketErrorCode;
+use spl_marketplace::series_policy::{self, SeriesStatus};
+use spl_marketplace::state::market::Market;
+
+fn legacy_state() -> LegacyState {
+    LegacyState {
+        collateral_decimals: 9,
+        consideration_decimals: 6,
+        collateral_held: 3 * SOL,
+        consideration_held: 150 * USDC,
+        authority: Pubkey::new_unique(),
+    }
+}
+
+#[test]
+fn migrate_steps_up_to_the_current_version() {
+    let mut series = OptionData::default();
+    assert_eq!(series.version, 0);
+    let err = series.migrate(None).unwrap_err();
+    assert_eq!(err, ErrorCode::LegacySeriesAccounts.into());
+
+    let legacy = legacy_state();
+    series.migrate(Some(&legacy)).unwrap();
+    assert_eq!(series.version, OptionData::CURRENT_VERSION);
+    assert_eq!(series.authority, legacy.authority);
+    assert!(!series.is_legacy_put());
+    assert_eq!(series.contract_multiplier, 1);
+    assert_eq!((series.strike_exponent, series.strike_scale), (6, 9));
+    assert_eq!(series.collateral_deposited, 3 * SOL);
+    assert_eq!(series.consideration_received, 150 * USDC);
+    series.migrate(None).unwrap();
+    assert_eq!(series.version, OptionData::CURRENT_VERSION);
+
+    let mut data = legacy_market_data(Pubkey::new_unique(), Pubkey::new_unique(), 254);
+    data.resize(Market::SIZE, 0);
+    let mut market = Market::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(market.version, 0);
+    let err = market.migrate(None).unwrap_err();
+    assert_eq!(err, MarketErrorCode::LegacyMarketAuthority.into());
+    let admin = Pubkey::new_unique();
+    market.migrate(Some(admin)).unwrap();
+    assert_eq!(market.version, Market::CURRENT_VERSION);
+    assert_eq!(market.authority, admin);
+    market.migrate(None).unwrap();
+    assert_eq!(market.authority, admin);
+}
+
+#[test]
+fn protocol_admin_is_read_from_the_config() {
+    assert_eq!(
+        series_policy::PROTOCOL_CONFIG_DISCRIMINATOR,
+        ProtocolConfig::DISCRIMINATOR
+    );
+    assert_eq!(series_policy::PROTOCOL_CONFIG_SEED, ProtocolConfig::SEED);
+
+    let admin = Pubkey::new_unique();
+    let mut data = ProtocolConfig::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(admin.as_ref());
+    data.resize(8 + ProtocolConfig::INIT_SPACE, 0);
+    let (key, _) = Pubkey::find_program_address(&[ProtocolConfig::SEED], &sol_option_protocol::ID);
+    let owner = sol_option_protocol::ID;
+    let mut lamports = 0;
+    let info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(series_policy::protocol_admin(&info).unwrap(), admin);
+
+    let other = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut copy = info.try_borrow_data().unwrap().to_vec();
+    let info = AccountInfo::new(
+        &other,
+        false,
+        false,
+        &mut lamports,
+        &mut copy,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(
+        series_policy::protocol_admin(&info).unwrap_err(),
+        MarketErrorCode::InvalidProtocolConfig.into()
+    );
+}
+
+#[test]
+fn legacy_puts_keep_their_call_like_escrow() {
+    let mut series = OptionData {
+        option_type: OptionType::Put,
+        strike_price: STRIKE,
+        ..Default::default()
+    };
+    let legacy = legacy_state();
+    series.migrate(Some(&legacy)).unwrap();
+    assert_eq!(series.version, OptionData::CURRENT_VERSION);
+    assert_eq!(series.option_type, OptionType::Put);
+    assert!(series.is_legacy_put());
+    assert_eq!(series.authority, legacy.authority);
+
+    // Collateral backs each option one for one, as it did before versioning
+    assert!(!series.is_put());
+    assert!(!series.escrows_consideration());
+    assert_eq!(series.escrow_locked(SOL).unwrap(), SOL);
+    assert_eq!(series.escrow_payout(SOL).unwrap(), SOL);
+
+    let err = validate_not_legacy_put(&series).unwrap_err();
+    assert_eq!(err, ErrorCode::LegacyPutRestricted.into());
+    let put = OptionData {
+        status: 0,
+        strike_price: STRIKE / 2,
+        ..series.clone()
+    };
+    assert_eq!(
+        validate_spread_legs(&series, &put).unwrap_err(),
+        ErrorCode::InvalidSpread.into()
+    );
+}
+
+#[test]
+fn full_series_fits_the_allocated_space() {
+    let series = OptionData {
+        metadata_uri: "u".repeat(MAX_METADATA_URI_LEN),
+        underlying_symbol: "S".repeat(MAX_UNDERLYING_SYMBOL_LEN),
+        version: OptionData::CURRENT_VERSION,
+        reserved: [u64::MAX; 16],
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    series.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), 8 + OptionData::INIT_SPACE);
+}
+
+#[test]
+fn version_sits_at_its_fixed_offset() {
+    let series = OptionData {
+        metadata_uri: "u".repeat(MAX_METADATA_URI_LEN),
+        version: 0xAB,
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    series.try_serialize(&mut data).unwrap();
+    assert_eq!(data[OptionData::VERSION_OFFSET], 0xAB);
+    assert_eq!(series_policy::VERSION_OFFSET, OptionData::VERSION_OFFSET);
+    assert_eq!(series_policy::STATUS_OFFSET, OptionData::STATUS_OFFSET);
+    assert_eq!(
+        series_policy::EXPIRATION_OFFSET,
+        OptionData::EXPIRATION_OFFSET
+    );
+    assert_eq!(
+        series_policy::OPTION_MINT_OFFSET,
+        OptionData::OPTION_MINT_OFFSET
+    );
+}
+
+#[test]
+fn legacy_series_reads_as_version_zero_once_grown() {
+    let series = OptionData {
+        strike_price: STRIKE,
+        option_type: OptionType::Put,
+        total_supply: 5,
+        exercised_amount: 2,
+        authority: Pubkey::new_unique(),
+        metadata_uri: "u".repeat(MAX_METADATA_URI_LEN),
+        underlying_symbol: "SOL".to_string(),
+        version: OptionData::CURRENT_VERSION,
+        reserved: [u64::MAX; 16],
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    series.try_serialize(&mut data).unwrap();
+
+    // What a legacy series held (`8 + size_of` bytes, padding included),
+    // left as realloc grows it
+    data.truncate(OptionData::LEGACY_SIZE);
+    data.resize(8 + 232, 0xAA);
+    data.resize(8 + OptionData::INIT_SPACE, 0);
+
+    OptionData::open_legacy_fields(&mut data);
+    let legacy = OptionData::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(legacy.version, 0);
+    assert_eq!(legacy.reserved, [0; 16]);
+    assert_eq!(legacy.strike_price, STRIKE);
+    assert_eq!(legacy.option_type, OptionType::Put);
+    assert_eq!(legacy.total_supply, 5);
+    assert_eq!(legacy.exercised_amount, 2);
+    assert_eq!(legacy.authority, Pubkey::default());
+    assert_eq!(legacy.contract_multiplier, 0);
+    assert_eq!(legacy.metadata_uri, "");
+    assert_eq!(legacy.underlying_symbol, "");
+}
+
+#[test]
+fn linked_markets_refuse_unknown_series_layouts() {
+    let series = OptionData {
+        option_mint: Pubkey::new_unique(),
+        expiration: 1_700_000_000,
+        version: OptionData::CURRENT_VERSION,
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    series.try_serialize(&mut data).unwrap();
+    data.resize(8 + OptionData::INIT_SPACE, 0);
+
+    let key = Pubkey::new_unique();
+    let owner = sol_option_protocol::ID;
+    let mut lamports = 0;
+    let status = {
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        SeriesStatus::load(&info).unwrap()
+    };
+    assert_eq!(status.option_mint, series.option_mint);
+    assert_eq!(status.expiration, series.expiration);
+
+    for version in [0, OptionData::CURRENT_VERSION + 1] {
+        data[OptionData::VERSION_OFFSET] = version;
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            SeriesStatus::load(&info).err().unwrap(),
+            MarketErrorCode::UnsupportedSeriesVersion.into()
+        );
+    }
+}
+
+#[test]
+fn market_size_covers_the_serialized_market() {
+    let mut data = legacy_market_data(Pubkey::new_unique(), Pubkey::new_unique(), 254);
+    assert_eq!(data.len(), 113);
+    data.resize(Market::SIZE, 0);
+    let market = Market::try_deserialize(&mut &data[..]).unwrap();
+    let mut serialized = Vec::new();
+    market.try_serialize(&mut serialized).unwrap();
+    assert_eq!(serialized.len(), Market::SIZE);
+}
+
+#[test]
+fn legacy_market_reads_as_version_zero_once_grown() {
+    let (base, quote) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = legacy_market_data(base, quote, 254);
+    data.resize(Market::SIZE, 0);
+    let market = Market::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!((market.base_mint, market.quote_mint), (base, quote));
+    assert_eq!(market.bump, 254);
+    assert_eq!(market.next_order_id, 7);
+    assert_eq!(
+        (market.total_orders_placed, market.total_orders_filled),
+        (7, 3)
+    );
+    assert_eq!(
+        (market.total_base_volume, market.total_quote_volume),
+        (500, 75_000)
+    );
+    assert_eq!(market.authority, Pubkey::default());
+    assert_eq!(market.linked_series, Pubkey::default());
+    assert_eq!(market.version, 0);
+}
+
+/// A market as the pre-versioning program wrote it (`LEGACY_SIZE` bytes: the
+/// mints, bump, order counter and statistics)
+fn legacy_market_data(base_mint: Pubkey, quote_mint: Pubkey, bump: u8) -> Vec<u8> {
+    let mut data = Market::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(base_mint.as_ref());
+    data.extend_from_slice(quote_mint.as_ref());
+    data.push(bump);
+    for counter in [7u64, 7, 3, 500, 75_000] {
+        data.extend_from_slice(&counter.to_le_bytes());
+    }
+    assert_eq!(data.len(), Market::LEGACY_SIZE);
+    data
+}
+
+/// New series are current; a call cut back to the legacy layout is grown,
+/// keeps its terms, gets its ledger back from its vaults and is stepped up
+/// exactly once
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn migrate_series_upgrades_legacy_accounts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.option_ix(ix::Mint { amount: 3 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    let created: OptionData = h.account(&series.option_context).await;
+    assert_eq!(created.version, OptionData::CURRENT_VERSION);
+    assert_eq!(created.reserved, [0; 16]);
+
+    let result = h.migrate_series(&bob, series.option_context).await;
+    assert_anchor_error(result, ErrorCode::SeriesUpToDate);
+
+    let space = 8 + OptionData::INIT_SPACE;
+    h.truncate_account(&series.option_context, OptionData::LEGACY_SIZE)
+        .await;
+    let result = h.migrate_series(&bob, series.option_context).await;
+    assert_anchor_error(result, ErrorCode::LegacySeriesAccounts);
+    h.migrate_legacy_series(&bob, &series).await.unwrap();
+
+    let migrated: OptionData = h.account(&series.option_context).await;
+    assert_eq!(migrated.version, OptionData::CURRENT_VERSION);
+    assert_eq!(migrated.strike_price, created.strike_price);
+    assert_eq!(migrated.expiration, created.expiration);
+    assert_eq!(migrated.total_supply, 3 * SOL);
+    assert_eq!(migrated.contract_multiplier, 1);
+    assert_eq!(migrated.strike_scale, created.strike_scale);
+    assert_eq!(migrated.collateral_balance(), 3 * SOL);
+    assert_eq!(migrated.authority, h.admin.pubkey());
+    let stored = h
+        .ctx
+        .banks_client
+        .get_account(series.option_context)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.data.len(), space);
+
+    let result = h.migrate_series(&bob, series.option_context).await;
+    assert_anchor_error(result, ErrorCode::SeriesUpToDate);
+
+    // The migrated call trades like a new one
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+}
+
+/// A legacy put, written when puts escrowed collateral like calls, migrates
+/// with that escrow intact: its holders can burn and redeem, but nobody can
+/// mint or exercise it
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn migrate_series_restricts_legacy_puts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, true)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+
+    // Alice wrote 3 SOL of the put before versioning, escrowing the SOL
+    let mut state: OptionData = h.account(&series.option_context).await;
+    state.total_supply = 3 * SOL;
+    h.store_series(&series, &state).await;
+    h.truncate_account(&series.option_context, OptionData::LEGACY_SIZE)
+        .await;
+    h.set_token_balance(&series.collateral_vault, 3 * SOL).await;
+    for (mint, account) in [
+        (series.option_mint, a.option),
+        (series.redemption_mint, a.redemption),
+    ] {
+        h.set_supply(&mint, 3 * SOL).await;
+        h.set_token_balance(&account, 3 * SOL).await;
+    }
+
+    h.migrate_legacy_series(&alice, &series).await.unwrap();
+    let migrated: OptionData = h.account(&series.option_context).await;
+    assert_eq!(migrated.option_type, OptionType::Put);
+    assert!(migrated.is_legacy_put());
+    assert_eq!(migrated.authority, h.admin.pubkey());
+    assert_eq!(migrated.collateral_balance(), 3 * SOL);
+
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    let result = h
+        .option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::LegacyPutRestricted);
+    h.mint_to(&usdc, &a.consideration, 150 * USDC).await;
+    let result = h
+        .option_ix(ix::Exercise { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::LegacyPutRestricted);
+
+    // Burning returns the escrowed SOL one for one
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+
+    // After expiry it settles without a price and redeems the rest
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 4 * SOL);
+}
+
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn migrate_market_upgrades_legacy_accounts() {
+    let mut h = Harness::start().await;
+    let base = h.create_mint(9).await;
+    let quote = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let market = h.create_market(&alice, base, quote).await;
+    let created: Market = h.account(&market).await;
+    assert_eq!(created.version, Market::CURRENT_VERSION);
+    let result = h.migrate_market(&bob, market).await;
+    assert_anchor_error(result, MarketErrorCode::MarketUpToDate);
+
+    let legacy = legacy_market_data(base, quote, created.bump);
+    h.store_account_data(&market, legacy).await;
+    let result = h.migrate_market(&bob, market).await;
+    assert_anchor_error(result, MarketErrorCode::LegacyMarketAuthority);
+    h.migrate_legacy_market(&bob, market).await.unwrap();
+
+    let migrated: Market = h.account(&market).await;
+    assert_eq!(migrated.version, Market::CURRENT_VERSION);
+    assert_eq!(migrated.base_mint, base);
+    assert_eq!(migrated.next_order_id, 7);
+    assert_eq!(migrated.total_quote_volume, 75_000);
+    assert_eq!(migrated.authority, h.admin.pubkey());
+    assert_eq!(migrated.pending_authority, Pubkey::default());
+    let stored = h
+        .ctx
+        .banks_client
+        .get_account(market)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.data.len(), Market::SIZE);
+
+    // The backfilled authority can hand the market on
+    let admin = h.admin.insecure_clone();
+    let propose = Instruction {
+        program_id: spl_marketplace::ID,
+        accounts: spl_marketplace::accounts::ProposeMarketAuthority {
+            authority: admin.pubkey(),
+            market,
+        }
+        .to_account_metas(None),
+        data: spl_marketplace::instruction::ProposeMarketAuthority {
+            new_authority: alice.pubkey(),
+        }
+        .data(),
+    };
+    h.send(&[propose], &[&admin]).await.unwrap();
+}
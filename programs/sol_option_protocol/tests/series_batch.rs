@@ -0,0 +1,94 @@
+//! create_series_batch: a whole strike ladder for one expiry in a single
+//! instruction, each series identical to what create_option would create.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::OptionData;
+use sol_option_protocol::state::CreatorStats;
+use sol_option_protocol::SeriesConfig;
+
+/// Three call strikes land as three independent, usable series
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn ladder_creates_every_strike() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let strikes = [140 * USDC, 150 * USDC, 160 * USDC];
+    let ladder = h
+        .create_series_batch(
+            &alice,
+            sol,
+            usdc,
+            &strikes,
+            expiration,
+            false,
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ladder.len(), strikes.len());
+
+    for (series, strike) in ladder.iter().zip(strikes) {
+        let state: OptionData = h.account(&series.option_context).await;
+        assert_eq!(state.strike_price, strike);
+        assert_eq!(state.expiration, expiration);
+        assert_eq!(state.collateral_mint, sol);
+        assert_eq!(state.consideration_mint, usdc);
+        assert_eq!(state.option_mint, series.option_mint);
+        assert_eq!(state.authority, alice.pubkey());
+        assert_eq!(state.contract_multiplier, 1);
+        assert_eq!(state.strike_exponent, 6);
+        assert_eq!(h.supply(&series.option_mint).await, 0);
+    }
+
+    // Every series counts against the creator's rate limits
+    let (stats, _) = anchor_lang::prelude::Pubkey::find_program_address(
+        &[CreatorStats::SEED, alice.pubkey().as_ref()],
+        &sol_option_protocol::ID,
+    );
+    let stats: CreatorStats = h.account(&stats).await;
+    assert_eq!(stats.total_series, strikes.len() as u64);
+
+    // The middle strike works like any other series
+    let series = ladder[1];
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.option).await, 2 * SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 2 * SOL);
+    assert_eq!(h.balance(&ladder[0].collateral_vault).await, 0);
+}
+
+/// Strikes must be strictly increasing (no duplicates or unordered ladders)
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn unordered_strikes_are_rejected() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+
+    for strikes in [
+        vec![150 * USDC, 140 * USDC],
+        vec![150 * USDC, 150 * USDC],
+        vec![],
+    ] {
+        let config = SeriesConfig::default();
+        let result = h
+            .create_series_batch(&alice, sol, usdc, &strikes, expiration, true, config)
+            .await;
+        assert_anchor_error(result.map(|_| ()), ErrorCode::InvalidBatch);
+    }
+}
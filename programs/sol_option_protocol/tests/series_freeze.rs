@@ -0,0 +1,92 @@
+//! Series freeze: when a series is compromised (e.g. its collateral mint was
+//! exploited) the guardian freezes it for good. Minting and exercise stop, and
+//! the series settles and redeems pro rata right away, whatever its expiry.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, HOUR, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{OptionData, SeriesConfig};
+
+#[test]
+fn frozen_series_redeem_from_the_freeze() {
+    let mut series = OptionData {
+        expiration: 30 * DAY,
+        settlement_delay: HOUR as u32,
+        ..OptionData::default()
+    };
+    assert_eq!(series.redemption_opens_at().unwrap(), 30 * DAY + HOUR);
+
+    // No grace period, and no waiting for expiry
+    series.status |= OptionData::STATUS_FROZEN;
+    series.frozen_at = DAY;
+    assert!(series.is_frozen());
+    assert!(!series.is_paused());
+    assert_eq!(series.redemption_opens_at().unwrap(), DAY);
+}
+
+/// Alice writes 10 calls and sells 4 to Bob, who exercises 2. Carol, the
+/// guardian, freezes the series a month before expiry: nothing more can be
+/// minted or exercised, it settles at once and Alice redeems her half of the
+/// redemption tokens for half the vaults without waiting out the grace period.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn frozen_series_redeems_pro_rata_immediately() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+    h.set_guardian(&carol.pubkey()).await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        settlement_delay: HOUR as u32,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 11 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    // Only the guardian (or admin) can freeze
+    let result = h.freeze_series(&bob, &series).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+    h.freeze_series(&carol, &series).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert!(state.is_frozen());
+    assert_eq!(state.frozen_at, h.now().await);
+    let result = h.freeze_series(&carol, &series).await;
+    assert_anchor_error(result, ErrorCode::SeriesFrozen);
+
+    let result = h
+        .option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesFrozen);
+    let result = h
+        .option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesFrozen);
+
+    // 8 SOL and 300 USDC left for 10 redemption tokens
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 5 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 150 * USDC);
+}
@@ -0,0 +1,89 @@
+//! Series markets: `open_series_market` creates the marketplace market of a
+//! series' option token against its consideration, links it to the series
+//! and records it on OptionData, so clients know where the series trades.
+
+mod common;
+
+use common::{assert_anchor_error, create_series_ix, Harness, Series, DAY, USDC};
+use solana_sdk::signature::{Keypair, Signer};
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+use spl_marketplace::state::market::Market;
+
+async fn call_series(h: &mut Harness, writer: &Keypair) -> Series {
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix(
+        &writer.pubkey(),
+        sol,
+        usdc,
+        150 * USDC,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    h.send(&[ix], &[writer]).await.unwrap();
+    series
+}
+
+/// The market is created, linked to the series and recorded on it
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn authority_opens_a_linked_market() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+    let data: OptionData = h.account(&series.option_context).await;
+    assert_eq!(data.market, Default::default());
+
+    let market = h.open_series_market(&alice, &series).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    assert_eq!(data.market, market);
+    let book: Market = h.account(&market).await;
+    assert_eq!(book.base_mint, series.option_mint);
+    assert_eq!(book.quote_mint, series.consideration_mint);
+    assert_eq!(book.authority, alice.pubkey());
+    assert_eq!(book.linked_series, series.option_context);
+
+    h.next_blockhash().await;
+    let result = h.open_series_market(&alice, &series).await.map(|_| ());
+    assert_anchor_error(result, ErrorCode::MarketAlreadyOpened);
+}
+
+/// Only the series authority records its market
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn others_cannot_open_the_market() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let mallory = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+
+    let result = h.open_series_market(&mallory, &series).await.map(|_| ());
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+}
+
+/// A market someone already created is adopted rather than blocking the series
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn existing_markets_are_adopted() {
+    let mut h = Harness::start().await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let series = call_series(&mut h, &alice).await;
+    let existing = h
+        .create_market(&bob, series.option_mint, series.consideration_mint)
+        .await;
+
+    let market = h.open_series_market(&alice, &series).await.unwrap();
+    assert_eq!(market, existing);
+    let data: OptionData = h.account(&series.option_context).await;
+    assert_eq!(data.market, existing);
+    let book: Market = h.account(&market).await;
+    assert_eq!(book.authority, bob.pubkey());
+}
@@ -0,0 +1,256 @@
+//! settle_expiry snapshots the vaults: redeemers are paid from the same
+//! balances whatever order they redeem in, and the series is frozen after.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::{assert_anchor_error, Harness, DAY, HOUR, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::oracle::guard::{SETTLEMENT_FALLBACK_DELAY, SETTLEMENT_FALLBACK_WINDOW};
+use sol_option_protocol::oracle::{OracleFeed, OracleGuard, OracleKind, OraclePrice};
+use sol_option_protocol::{OptionData, SeriesConfig};
+
+/// Alice and Carol each write 5 calls; Bob exercises 4. After settlement both
+/// writers receive exactly half of the 6 SOL + 600 USDC left in the vaults.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn redemption_is_order_independent() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let carol = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    let c = h.wallet(&series, &carol.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 5 * SOL).await;
+    h.mint_to(&sol, &c.collateral, 5 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &carol, &c)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    // --- Expired but not settled: no redemption yet -----------------------------
+    h.warp_to(expiration + 1).await;
+    let result = h
+        .option_ix(ix::Redeem { amount: 2 * SOL }, &series, &carol, &c)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesNotSettled);
+
+    // --- Settlement records the vaults and outstanding claims ------------------
+    h.settle(&series).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert!(state.is_settled());
+    assert_eq!(state.settled_collateral, 6 * SOL);
+    assert_eq!(state.settled_consideration, 600 * USDC);
+    assert_eq!(state.settled_supply, 10 * SOL);
+
+    // --- Carol redeems first, Alice second: identical payouts ------------------
+    h.option_ix(ix::Redeem { amount: 5 * SOL }, &series, &carol, &c)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&c.collateral).await, 3 * SOL);
+    assert_eq!(h.balance(&c.consideration).await, 300 * USDC);
+
+    h.option_ix(ix::Redeem { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 3 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 300 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.collateral_redeemed, 6 * SOL);
+    assert_eq!(state.consideration_claimed, 600 * USDC);
+
+    // --- Frozen: no more paired burns or mints ----------------------------------
+    let result = h
+        .option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::SeriesAlreadySettled);
+}
+
+/// With a one-hour settlement delay the series can be settled right after
+/// expiry, but redemption only opens an hour later.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn redeem_waits_for_settlement_delay() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        settlement_delay: HOUR as u32,
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let result = h
+        .option_ix(ix::Redeem { amount: SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::RedemptionNotOpen);
+
+    h.warp_to(expiration + HOUR).await;
+    h.option_ix(ix::Redeem { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
+
+/// claim_all burns the whole short position for both legs in one call.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn claim_all_pays_both_legs() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 5 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 300 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 2 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::ClaimAll {}, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.redemption).await, 0);
+    assert_eq!(h.balance(&a.collateral).await, 3 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 300 * USDC);
+}
+
+/// Minting stops at expiration, before settlement: collateral deposited into a
+/// dead series would only dilute the writers waiting to redeem.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mint_closes_at_expiration() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 3 * SOL).await;
+    h.option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    h.warp_to(expiration).await;
+    let result = h
+        .option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await;
+    assert_anchor_error(result, ErrorCode::OptionExpired);
+    assert_eq!(h.supply(&series.redemption_mint).await, SOL);
+}
+
+#[test]
+fn late_prices_pass_only_within_the_fallback_window() {
+    let expiration = 1_700_000_000;
+    let guard = OracleGuard::default();
+    let published = |publish_time| OraclePrice {
+        price: 150_000_000,
+        conf: 0,
+        expo: -6,
+        publish_time,
+    };
+    let late = published(expiration + 10 * 60);
+    assert!(guard
+        .check_staleness(&published(expiration - 60), expiration, 0)
+        .is_ok());
+    assert!(guard.check_staleness(&late, expiration, 0).is_err());
+    assert!(guard
+        .check_staleness(&late, expiration, SETTLEMENT_FALLBACK_WINDOW)
+        .is_ok());
+    let too_late = published(expiration + SETTLEMENT_FALLBACK_WINDOW + 1);
+    assert!(guard
+        .check_staleness(&too_late, expiration, SETTLEMENT_FALLBACK_WINDOW)
+        .is_err());
+    let early = published(expiration - 61);
+    assert!(guard
+        .check_staleness(&early, expiration, SETTLEMENT_FALLBACK_WINDOW)
+        .is_err());
+}
+
+/// No price landed around expiration: once the fallback opens, the series
+/// settles on the first price published within the window after it
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn a_missed_window_falls_back_to_a_late_price() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let oracle = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        primary_oracle: OracleFeed {
+            kind: OracleKind::Pyth,
+            feed: oracle,
+            ..OracleFeed::default()
+        },
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+
+    h.set_pyth_price(&oracle, 160 * USDC, expiration + 2 * 60)
+        .await;
+    h.warp_to(expiration + 2 * 60).await;
+    let result = h.settle_with_oracle(&series, &oracle).await;
+    assert_anchor_error(result, ErrorCode::OracleUnavailable);
+
+    h.warp_to(expiration + SETTLEMENT_FALLBACK_DELAY).await;
+    h.settle_with_oracle(&series, &oracle).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.settlement_price, 160 * USDC);
+}
@@ -0,0 +1,161 @@
+//! Settlement attestations: `attest_settlement` records a settled series'
+//! price and payout ratios in a PDA relayers can read, and can post the same
+//! result as a Wormhole message so EVM-side wrappers mirror settlement.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::SettlementAttestation;
+use sol_option_protocol::wormhole::{
+    bridge_address, emitter_address, fee_collector_address, message_address, post_message_ix,
+    sequence_address, WORMHOLE_PROGRAM_ID,
+};
+use sol_option_protocol::{OptionData, OptionType};
+
+fn attestation() -> SettlementAttestation {
+    let series = OptionData {
+        collateral_mint: Pubkey::new_unique(),
+        consideration_mint: Pubkey::new_unique(),
+        strike_price: STRIKE,
+        expiration: 1_700_000_000,
+        option_type: OptionType::Put,
+        settlement_price: 140 * USDC,
+        settled_at: 1_700_000_100,
+        settled_collateral: 6 * SOL,
+        settled_consideration: 600 * USDC,
+        settled_supply: 10 * SOL,
+        settled_option_supply: 4 * SOL,
+        net_option_collateral: 0,
+        net_option_consideration: 40 * USDC,
+        ..OptionData::default()
+    };
+    SettlementAttestation::of(Pubkey::new_unique(), &series, 255)
+}
+
+#[test]
+fn payload_is_big_endian_in_declaration_order() {
+    let attestation = attestation();
+    let payload = attestation.payload();
+    assert_eq!(payload.len(), 178);
+    assert_eq!(payload[0], SettlementAttestation::PAYLOAD_ID);
+    assert_eq!(&payload[1..33], attestation.option_context.as_ref());
+    assert_eq!(&payload[65..97], attestation.consideration_mint.as_ref());
+    assert_eq!(&payload[97..105], &STRIKE.to_be_bytes());
+    assert_eq!(payload[113], OptionType::Put as u8);
+    assert_eq!(&payload[114..122], &(140 * USDC).to_be_bytes());
+    assert_eq!(&payload[170..178], &(40 * USDC).to_be_bytes());
+}
+
+#[test]
+fn messages_come_from_the_protocol_emitter() {
+    let payer = Pubkey::new_unique();
+    let (message, _) = message_address(&Pubkey::new_unique());
+    let payload = vec![7, 8, 9];
+    let ix = post_message_ix(&payer, &message, 42, payload);
+    let emitter = emitter_address().0;
+
+    assert_eq!(ix.program_id, WORMHOLE_PROGRAM_ID);
+    // PostMessage, nonce, Borsh payload, finalized
+    assert_eq!(ix.data, vec![1, 42, 0, 0, 0, 3, 0, 0, 0, 7, 8, 9, 1]);
+    assert_eq!(ix.accounts[0], AccountMeta::new(bridge_address(), false));
+    assert_eq!(ix.accounts[1], AccountMeta::new(message, true));
+    assert_eq!(ix.accounts[2], AccountMeta::new_readonly(emitter, true));
+    assert_eq!(
+        ix.accounts[3],
+        AccountMeta::new(sequence_address(&emitter), false)
+    );
+    assert_eq!(ix.accounts[4], AccountMeta::new(payer, true));
+    assert_eq!(
+        ix.accounts[5],
+        AccountMeta::new(fee_collector_address(), false)
+    );
+}
+
+/// Bob exercises 4 of 10 calls; once the series settles anyone attests it,
+/// once, with the snapshot redeemers are paid from
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn settled_series_are_attested_once() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 600 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 4 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    let result = h.attest_settlement(&series, None).await.map(|_| ());
+    assert_anchor_error(result, ErrorCode::SeriesNotSettled);
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let address = h.attest_settlement(&series, None).await.unwrap();
+    let data: OptionData = h.account(&series.option_context).await;
+    let attestation: SettlementAttestation = h.account(&address).await;
+    assert_eq!(attestation.option_context, series.option_context);
+    assert_eq!(attestation.settled_at, data.settled_at);
+    assert_eq!(
+        (
+            attestation.settled_collateral,
+            attestation.settled_consideration
+        ),
+        (6 * SOL, 600 * USDC)
+    );
+    assert_eq!(attestation.settled_supply, 10 * SOL);
+    assert!(!attestation.posted);
+
+    h.next_blockhash().await;
+    assert!(h.attest_settlement(&series, None).await.is_err());
+}
+
+/// Wormhole accounts other than the bridge's and the protocol's own are refused
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn foreign_wormhole_accounts_are_refused() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+
+    let emitter = emitter_address().0;
+    let accounts = [
+        WORMHOLE_PROGRAM_ID,
+        Pubkey::new_unique(),
+        message_address(&series.option_context).0,
+        emitter,
+        sequence_address(&emitter),
+        fee_collector_address(),
+        sysvar::clock::ID,
+        sysvar::rent::ID,
+    ];
+    let result = h
+        .attest_settlement(&series, Some(accounts))
+        .await
+        .map(|_| ());
+    assert_anchor_error(result, ErrorCode::InvalidWormholeAccount);
+}
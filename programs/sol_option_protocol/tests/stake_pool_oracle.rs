@@ -0,0 +1,175 @@
+//! Stake-pool adapter: liquid staking tokens priced in SOL from their SPL
+//! stake pool's exchange rate, so LST series can quote strikes in SOL.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::{Harness, DAY};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::oracle::stake_pool::{
+    decode_stake_pool, LAST_UPDATE_EPOCH_OFFSET, MIN_ACCOUNT_LEN, POOL_MINT_OFFSET,
+    POOL_TOKEN_SUPPLY_OFFSET, TOTAL_LAMPORTS_OFFSET,
+};
+use sol_option_protocol::oracle::{OracleFeed, OracleGuard, OracleKind, PriceSource, StakePool};
+use sol_option_protocol::{OptionData, SeriesConfig};
+
+const LAMPORTS: u64 = 1_000_000_000; // both the LST and wrapped SOL have 9 decimals
+const EPOCH: u64 = 600;
+const NOW: i64 = 1_700_000_000;
+
+/// jitoSOL-style pool: 1,100 SOL staked against 1,000 pool tokens (1.1 SOL each)
+fn stake_pool(mint: &Pubkey, total_lamports: u64, supply: u64, epoch: u64) -> Vec<u8> {
+    let mut data = vec![0u8; MIN_ACCOUNT_LEN];
+    data[0] = 1;
+    data[POOL_MINT_OFFSET..POOL_MINT_OFFSET + 32].copy_from_slice(mint.as_ref());
+    data[TOTAL_LAMPORTS_OFFSET..TOTAL_LAMPORTS_OFFSET + 8]
+        .copy_from_slice(&total_lamports.to_le_bytes());
+    data[POOL_TOKEN_SUPPLY_OFFSET..POOL_TOKEN_SUPPLY_OFFSET + 8]
+        .copy_from_slice(&supply.to_le_bytes());
+    data[LAST_UPDATE_EPOCH_OFFSET..LAST_UPDATE_EPOCH_OFFSET + 8]
+        .copy_from_slice(&epoch.to_le_bytes());
+    data
+}
+
+#[test]
+fn exchange_rate_is_read_in_sol_strike_units() {
+    let mint = Pubkey::new_unique();
+    let rate = decode_stake_pool(&stake_pool(
+        &mint,
+        1_100 * LAMPORTS,
+        1_000 * LAMPORTS,
+        EPOCH,
+    ))
+    .unwrap();
+    assert_eq!(rate.pool_mint, mint);
+    assert_eq!(rate.last_update_epoch, EPOCH);
+
+    let price = rate.to_price(EPOCH, NOW).unwrap();
+    assert_eq!(price.publish_time, NOW);
+    assert_eq!(price.conf, 0);
+    // Strikes quoted in lamports (9 decimals) per whole pool token
+    assert_eq!(price.to_strike_units(9).unwrap(), 1_100_000_000);
+}
+
+#[test]
+fn rejects_stale_empty_and_foreign_pools() {
+    let mint = Pubkey::new_unique();
+    let rate = decode_stake_pool(&stake_pool(
+        &mint,
+        1_100 * LAMPORTS,
+        1_000 * LAMPORTS,
+        EPOCH,
+    ))
+    .unwrap();
+    // The pool missed this epoch's update: its rate lags the staking rewards
+    assert_eq!(
+        rate.to_price(EPOCH + 1, NOW).unwrap_err(),
+        error!(ErrorCode::StaleOraclePrice)
+    );
+
+    let empty = decode_stake_pool(&stake_pool(&mint, 0, 0, EPOCH)).unwrap();
+    assert_eq!(
+        empty.to_price(EPOCH, NOW).unwrap_err(),
+        error!(ErrorCode::InvalidOraclePrice)
+    );
+
+    // Validator lists (and anything else) aren't pools
+    let mut data = stake_pool(&mint, 1_100 * LAMPORTS, 1_000 * LAMPORTS, EPOCH);
+    data[0] = 2;
+    assert!(decode_stake_pool(&data).is_err());
+    assert!(decode_stake_pool(&data[..MIN_ACCOUNT_LEN - 1]).is_err());
+
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let mut data = stake_pool(&mint, 1_100 * LAMPORTS, 1_000 * LAMPORTS, EPOCH);
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(
+        StakePool::read_price(&account).unwrap_err(),
+        error!(ErrorCode::InvalidOracleAccount)
+    );
+}
+
+/// Alice writes 10 jitoSOL calls struck at 1.05 SOL, paid in wrapped SOL,
+/// and sells 4 to Bob. The pool trades at 1.1 SOL at expiry: Bob's options
+/// are netted to 4 × 0.05 / 1.1 jitoSOL and Alice keeps the rest.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn lst_calls_settle_on_the_pool_rate() {
+    let mut h = Harness::start().await;
+    let jito_sol = h.create_mint(9).await;
+    let wsol = h.create_mint(9).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let pool = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        primary_oracle: OracleFeed {
+            kind: OracleKind::StakePool,
+            feed: pool,
+            guard: OracleGuard {
+                max_staleness: DAY as u32,
+                ..OracleGuard::default()
+            },
+        },
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(
+            &alice,
+            jito_sol,
+            wsol,
+            1_050_000_000,
+            expiration,
+            false,
+            config,
+        )
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&jito_sol, &a.collateral, 10 * LAMPORTS).await;
+    h.option_ix(
+        ix::Mint {
+            amount: 10 * LAMPORTS,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 4 * LAMPORTS).await;
+
+    h.warp_to(expiration + 60).await;
+    h.set_stake_pool_rate(&pool, &jito_sol, 1_100 * LAMPORTS, 1_000 * LAMPORTS)
+        .await;
+    h.settle_with_oracle(&series, &pool).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.settlement_price, 1_100_000_000);
+
+    h.option_ix(
+        ix::ClaimNetPayoff {
+            amount: 4 * LAMPORTS,
+        },
+        &series,
+        &bob,
+        &b,
+    )
+    .await
+    .unwrap();
+    // 4 × (1.1 − 1.05) / 1.1
+    assert_eq!(h.balance(&b.collateral).await, 181_818_181);
+}
@@ -0,0 +1,79 @@
+//! Strike units: a series can quote its strike with more decimals than the
+//! consideration mint, so sub-raw-unit strikes on low-priced collateral work.
+
+mod common;
+
+use common::{Harness, DAY, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::instructions::OptionData;
+use sol_option_protocol::utils::validation::validate_strike_exponent;
+use sol_option_protocol::SeriesConfig;
+
+const BONK: u64 = 100_000; // collateral, 5 decimals
+
+/// $0.000023 per BONK is 2.3 raw USDC per whole BONK, which raw consideration
+/// units can't express; a 10-decimal strike can.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn exercise_pays_strike_in_series_units() {
+    let mut h = Harness::start().await;
+    let bonk = h.create_mint(5).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        strike_exponent: Some(10),
+        ..SeriesConfig::default()
+    };
+    let strike = 230_000; // $0.000023 with 10 decimals
+    let series = h
+        .create_series_with(&alice, bonk, usdc, strike, expiration, false, config)
+        .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.strike_exponent, 10);
+    assert_eq!(state.strike_scale, 9);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&bonk, &a.collateral, 1_000_000 * BONK).await;
+    h.mint_to(&usdc, &a.consideration, 100 * USDC).await;
+    h.option_ix(
+        ix::Mint {
+            amount: 1_000_000 * BONK,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+
+    // 1M BONK × $0.000023 = $23
+    h.option_ix(
+        ix::Exercise {
+            amount: 1_000_000 * BONK,
+        },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&series.consideration_vault).await, 23 * USDC);
+    assert_eq!(h.balance(&a.consideration).await, 77 * USDC);
+}
+
+#[test]
+fn strike_scale_combines_mint_decimals() {
+    // Legacy convention: strike in raw consideration, scale = collateral decimals
+    assert_eq!(validate_strike_exponent(6, 9, 6).unwrap(), 9);
+    assert_eq!(validate_strike_exponent(10, 5, 6).unwrap(), 9);
+    assert_eq!(validate_strike_exponent(0, 6, 6).unwrap(), 0);
+
+    // One strike unit worth less than a raw consideration token per raw collateral
+    assert!(validate_strike_exponent(0, 5, 6).is_err());
+    // Divisor beyond u64 math
+    assert!(validate_strike_exponent(18, 9, 6).is_err());
+}
@@ -0,0 +1,101 @@
+//! Strike math: exercisers' payments round up and the protocol's payouts
+//! round down, with u128 intermediates, across decimal combinations.
+
+mod common;
+
+use common::{SOL, USDC};
+use proptest::prelude::*;
+
+use sol_option_protocol::utils::math::{
+    calculate_put_collateral, calculate_strike_payment, calculate_strike_value,
+};
+
+#[test]
+fn dust_exercises_still_pay() {
+    // 1 raw BONK (5 decimals) at a 0.04 USDC strike is worth 0.4 raw USDC
+    assert_eq!(calculate_strike_payment(1, 40_000, 5).unwrap(), 1);
+    assert_eq!(calculate_strike_value(1, 40_000, 5).unwrap(), 0);
+    assert_eq!(calculate_strike_payment(0, 40_000, 5).unwrap(), 0);
+
+    // Exact multiples do not round
+    assert_eq!(
+        calculate_strike_payment(10 * SOL, 150 * USDC, 9).unwrap(),
+        1_500 * USDC
+    );
+    assert_eq!(
+        calculate_strike_value(10 * SOL, 150 * USDC, 9).unwrap(),
+        1_500 * USDC
+    );
+}
+
+#[test]
+fn large_amounts_do_not_overflow_the_product() {
+    // 10B SOL at $150: the raw product is 1.5e27, the payment $1.5T
+    let amount = 10_000_000_000 * SOL;
+    assert_eq!(
+        calculate_strike_payment(amount, 150 * USDC, 9).unwrap(),
+        1_500_000_000_000 * USDC
+    );
+
+    // Payments that don't fit a u64 still fail
+    assert!(calculate_strike_payment(u64::MAX, u64::MAX, 0).is_err());
+    assert!(calculate_strike_value(u64::MAX, 2, 0).is_err());
+    assert!(calculate_strike_payment(1, 1, 39).is_err());
+}
+
+proptest! {
+    #[test]
+    fn payment_is_the_exact_quotient_rounded_up(
+        amount in any::<u64>(),
+        strike in any::<u64>(),
+        scale in 0u8..=18,
+    ) {
+        let product = amount as u128 * strike as u128;
+        let denominator = 10u128.pow(scale as u32);
+        let floor = product / denominator;
+        let ceil = product.div_ceil(denominator);
+
+        match calculate_strike_value(amount, strike, scale) {
+            Ok(value) => prop_assert_eq!(value as u128, floor),
+            Err(_) => prop_assert!(floor > u64::MAX as u128),
+        }
+        match calculate_strike_payment(amount, strike, scale) {
+            Ok(payment) => {
+                prop_assert_eq!(payment as u128, ceil);
+                prop_assert_eq!(payment > 0, product > 0);
+            }
+            Err(_) => prop_assert!(ceil > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn split_exercises_pay_at_least_the_whole(
+        parts in prop::collection::vec(1u64..=SOL / 20, 1..20),
+        strike in 1u64..=10_000 * USDC,
+        collateral_decimals in 0u8..=9,
+    ) {
+        let whole: u64 = parts.iter().sum();
+        let pay = |amount| calculate_strike_payment(amount, strike, collateral_decimals);
+        let value = |amount| calculate_strike_value(amount, strike, collateral_decimals);
+        let paid: u128 = parts.iter().map(|&part| pay(part).unwrap() as u128).sum();
+        let paid_out: u128 = parts.iter().map(|&part| value(part).unwrap() as u128).sum();
+
+        prop_assert!(paid >= pay(whole).unwrap() as u128);
+        prop_assert!(paid_out <= value(whole).unwrap() as u128);
+    }
+
+    #[test]
+    fn put_collateral_covers_any_split_of_payouts(
+        minted in prop::collection::vec(1u64..=SOL / 20, 1..20),
+        strike in 1u64..=10_000 * USDC,
+        collateral_decimals in 0u8..=9,
+    ) {
+        let lock = |amount| calculate_put_collateral(amount, strike, collateral_decimals);
+        let value = |amount| calculate_strike_value(amount, strike, collateral_decimals);
+        let locked: u128 = minted.iter().map(|&part| lock(part).unwrap() as u128).sum();
+        let whole: u64 = minted.iter().sum();
+        let half = whole / 2;
+        let paid = value(half).unwrap() as u128 + value(whole - half).unwrap() as u128;
+        prop_assert!(locked >= paid);
+    }
+}
@@ -0,0 +1,118 @@
+//! sweep_dust and recover_unclaimed move what a series leaves in its vaults to
+//! the treasury and close the vaults.
+
+mod common;
+
+use common::{assert_anchor_error, Harness, DAY, SOL, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+
+/// Alice writes and redeems 2 calls; the 1 SOL Mallory donated is left
+/// behind and swept to the treasury, which must own the receiving accounts.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn sweeps_leftovers_to_treasury() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let mallory = h.actor().await;
+    let treasury = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let m = h.wallet(&series, &mallory.pubkey()).await;
+    let t = h.wallet(&series, &treasury.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.mint_to(&sol, &m.collateral, SOL).await;
+    h.set_treasury(&treasury.pubkey()).await;
+
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&mallory, &m.collateral, &series.collateral_vault, SOL)
+        .await;
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 2 * SOL);
+
+    // --- Only treasury-owned accounts can receive the sweep --------------------
+    let result = h
+        .treasury_sweep(ix::SweepDust {}, &series, &alice.pubkey(), &m)
+        .await;
+    assert_anchor_error(result, ErrorCode::InvalidTreasuryAccount);
+
+    h.treasury_sweep(ix::SweepDust {}, &series, &alice.pubkey(), &t)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&t.collateral).await, SOL);
+    for vault in [series.collateral_vault, series.consideration_vault] {
+        let account = h.ctx.banks_client.get_account(vault).await.unwrap();
+        assert!(account.is_none());
+    }
+}
+
+/// Bob never redeems his 2 calls. A year after expiry, anyone can recover the
+/// abandoned collateral to the treasury.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn recovers_abandoned_series_after_timeout() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let bob = h.actor().await;
+    let treasury = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&bob, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    let t = h.wallet(&series, &treasury.pubkey()).await;
+    h.mint_to(&sol, &b.collateral, 2 * SOL).await;
+    h.set_treasury(&treasury.pubkey()).await;
+
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+
+    // --- Disabled until the admin sets a timeout --------------------------------
+    let result = h
+        .treasury_sweep(ix::RecoverUnclaimed {}, &series, &bob.pubkey(), &t)
+        .await;
+    assert_anchor_error(result, ErrorCode::RecoveryDisabled);
+
+    h.set_unclaimed_timeout(365 * DAY).await;
+    h.next_blockhash().await;
+    let result = h
+        .treasury_sweep(ix::RecoverUnclaimed {}, &series, &bob.pubkey(), &t)
+        .await;
+    assert_anchor_error(result, ErrorCode::UnclaimedTimeoutNotElapsed);
+
+    // --- A year after expiry the funds go to the treasury ----------------------
+    h.warp_to(expiration + 365 * DAY).await;
+    h.next_blockhash().await;
+    h.treasury_sweep(ix::RecoverUnclaimed {}, &series, &bob.pubkey(), &t)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&t.collateral).await, 2 * SOL);
+    assert_eq!(h.supply(&series.redemption_mint).await, 2 * SOL);
+    let vault = h
+        .ctx
+        .banks_client
+        .get_account(series.collateral_vault)
+        .await
+        .unwrap();
+    assert!(vault.is_none());
+}
@@ -0,0 +1,96 @@
+//! Switchboard adapter: owner and discriminator checks on pull feeds, and
+//! rescaling of the 18-decimal result into strike units.
+
+use anchor_lang::prelude::*;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::oracle::switchboard::{
+    LAST_UPDATE_TIMESTAMP_OFFSET, MIN_ACCOUNT_LEN, PULL_FEED_DISCRIMINATOR, RESULT_STD_DEV_OFFSET,
+    RESULT_VALUE_OFFSET, SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+};
+use sol_option_protocol::oracle::{
+    read_guarded_price, OracleFeed, OracleGuard, OracleKind, PriceRequest,
+};
+
+const UPDATED_AT: i64 = 1_700_000_000;
+
+/// BONK/USD at $0.0000245 ± $0.0000001 (18-decimal fixed point)
+fn pull_feed(value: i128, std_dev: i128) -> Vec<u8> {
+    let mut data = vec![0u8; MIN_ACCOUNT_LEN];
+    data[..8].copy_from_slice(&PULL_FEED_DISCRIMINATOR);
+    data[LAST_UPDATE_TIMESTAMP_OFFSET..LAST_UPDATE_TIMESTAMP_OFFSET + 8]
+        .copy_from_slice(&UPDATED_AT.to_le_bytes());
+    data[RESULT_VALUE_OFFSET..RESULT_VALUE_OFFSET + 16].copy_from_slice(&value.to_le_bytes());
+    data[RESULT_STD_DEV_OFFSET..RESULT_STD_DEV_OFFSET + 16].copy_from_slice(&std_dev.to_le_bytes());
+    data
+}
+
+fn read(owner: Pubkey, mut data: Vec<u8>, reference_time: i64) -> Result<u64> {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    let feed = OracleFeed {
+        kind: OracleKind::Switchboard,
+        feed: key,
+        guard: OracleGuard::default(),
+    };
+    let request = PriceRequest {
+        reference_time,
+        late_window: 0,
+        previous_price: 0,
+        previous_update: 0,
+        strike_exponent: 6,
+    };
+    read_guarded_price(&feed, Some(&account), &request).map(|(value, _)| value)
+}
+
+fn assert_error(result: Result<u64>, code: ErrorCode) {
+    assert_eq!(result.unwrap_err(), error!(code));
+}
+
+#[test]
+fn reads_pull_feed_in_strike_units() {
+    let data = pull_feed(24_500_000_000_000, 100_000_000_000);
+    // 0.0000245 USDC (6 decimals) per whole BONK truncates to 24 raw units
+    assert_eq!(
+        read(SWITCHBOARD_ON_DEMAND_PROGRAM_ID, data, UPDATED_AT).unwrap(),
+        24
+    );
+
+    // $150.25 SOL -> 150_250_000 raw USDC
+    let data = pull_feed(150_250_000_000_000_000_000, 0);
+    let value = read(SWITCHBOARD_ON_DEMAND_PROGRAM_ID, data, UPDATED_AT).unwrap();
+    assert_eq!(value, 150_250_000);
+}
+
+#[test]
+fn rejects_foreign_owner_short_data_and_stale_result() {
+    let data = pull_feed(150_250_000_000_000_000_000, 0);
+    let result = read(Pubkey::new_unique(), data, UPDATED_AT);
+    assert_error(result, ErrorCode::InvalidOracleAccount);
+
+    let mut data = pull_feed(150_250_000_000_000_000_000, 0);
+    data.truncate(MIN_ACCOUNT_LEN - 1);
+    let result = read(SWITCHBOARD_ON_DEMAND_PROGRAM_ID, data, UPDATED_AT);
+    assert_error(result, ErrorCode::InvalidOracleAccount);
+
+    let data = pull_feed(150_250_000_000_000_000_000, 0);
+    let result = read(SWITCHBOARD_ON_DEMAND_PROGRAM_ID, data, UPDATED_AT + 61);
+    assert_error(result, ErrorCode::StaleOraclePrice);
+}
+
+#[test]
+fn rejects_non_positive_result() {
+    let data = pull_feed(-1_000_000_000_000_000_000, 0);
+    let result = read(SWITCHBOARD_ON_DEMAND_PROGRAM_ID, data, UPDATED_AT);
+    assert_error(result, ErrorCode::InvalidOraclePrice);
+}
@@ -0,0 +1,169 @@
+//! Timelocked parameter changes: once the admin sets a delay, fee, allowlist
+//! and series creation changes are scheduled, wait out the delay in public,
+//! and can be cancelled by the admin or guardian until they apply.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::Space;
+use common::{
+    approved_mint_address, assert_anchor_error, pending_change_address, protocol_config_address,
+    Harness, DAY,
+};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::state::{
+    ApprovedMint, FeeTier, ParameterChange, PendingChange, ProtocolConfig,
+};
+use sol_option_protocol::utils::validation::validate_timelock_delay;
+
+#[test]
+fn scheduled_changes_are_checked_up_front() {
+    assert!(ParameterChange::Fees {
+        mint_fee_bps: 10,
+        exercise_fee_bps: 25
+    }
+    .validate()
+    .is_ok());
+    assert!(ParameterChange::Fees {
+        mint_fee_bps: ProtocolConfig::MAX_FEE_BPS + 1,
+        exercise_fee_bps: 0
+    }
+    .validate()
+    .is_err());
+    assert!(ParameterChange::ReferralShare {
+        referral_share_bps: 10_001
+    }
+    .validate()
+    .is_err());
+    assert!(ParameterChange::ApprovedMint {
+        mint: Pubkey::default(),
+        approved: true
+    }
+    .validate()
+    .is_err());
+    assert!(ParameterChange::TimelockDelay { timelock_delay: -1 }
+        .validate()
+        .is_err());
+    assert!(ParameterChange::RateLimits {
+        max_series_per_epoch: 5,
+        creation_cooldown: -1
+    }
+    .validate()
+    .is_err());
+
+    assert!(validate_timelock_delay(0).is_ok());
+    assert!(validate_timelock_delay(ProtocolConfig::MAX_TIMELOCK_DELAY).is_ok());
+    assert!(validate_timelock_delay(ProtocolConfig::MAX_TIMELOCK_DELAY + 1).is_err());
+}
+
+#[test]
+fn the_largest_change_fits_its_account() {
+    let tier = FeeTier {
+        min_volume: u64::MAX,
+        discount_bps: 10_000,
+    };
+    let largest = [
+        ParameterChange::FeeTiers {
+            tiers: vec![tier; ProtocolConfig::MAX_FEE_TIERS],
+        },
+        ParameterChange::FeeExempt {
+            accounts: vec![Pubkey::new_unique(); ProtocolConfig::MAX_FEE_EXEMPT],
+        },
+    ];
+    for change in largest {
+        let pending = PendingChange {
+            id: u64::MAX,
+            change,
+            scheduled_at: 0,
+            executable_at: 0,
+            bump: 255,
+        };
+        assert!(pending.try_to_vec().unwrap().len() <= PendingChange::INIT_SPACE);
+    }
+}
+
+/// With a 2-day delay the admin can't change fees directly: the scheduled
+/// change only applies after the delay. A scheduled mint approval is cancelled
+/// by the guardian, and a rescheduled one creates the approval when applied.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn fee_and_allowlist_changes_wait_out_the_delay() {
+    let mut h = Harness::start().await;
+    let usdc = h.create_mint(6).await;
+    let guardian = h.actor().await;
+    h.set_guardian(&guardian.pubkey()).await;
+    h.set_timelock_delay(2 * DAY).await.unwrap();
+
+    let result = h.set_fees(10, 25).await;
+    assert_anchor_error(result, ErrorCode::TimelockRequired);
+    let result = h.set_timelock_delay(DAY).await;
+    assert_anchor_error(result, ErrorCode::TimelockRequired);
+
+    let fees = ParameterChange::Fees {
+        mint_fee_bps: 10,
+        exercise_fee_bps: 25,
+    };
+    h.schedule_change(1, fees).await.unwrap();
+    let result = h.apply_change(1, None).await;
+    assert_anchor_error(result, ErrorCode::TimelockNotElapsed);
+
+    let approval = ParameterChange::ApprovedMint {
+        mint: usdc,
+        approved: true,
+    };
+    h.schedule_change(2, approval.clone()).await.unwrap();
+    h.cancel_pending(&guardian, 2).await.unwrap();
+    h.schedule_change(3, approval).await.unwrap();
+
+    let now = h.now().await;
+    h.warp_to(now + 2 * DAY).await;
+    h.apply_change(1, None).await.unwrap();
+    h.apply_change(3, Some(&usdc)).await.unwrap();
+    assert!(h.apply_change(2, Some(&usdc)).await.is_err());
+
+    let state: ProtocolConfig = h.account(&protocol_config_address()).await;
+    assert_eq!((state.mint_fee_bps, state.exercise_fee_bps), (10, 25));
+    let approved: ApprovedMint = h.account(&approved_mint_address(&usdc)).await;
+    assert!(approved.approved);
+    let closed = h
+        .ctx
+        .banks_client
+        .get_account(pending_change_address(1))
+        .await
+        .unwrap();
+    assert!(closed.is_none());
+
+    // Lifting the timelock is itself timelocked
+    h.schedule_change(4, ParameterChange::TimelockDelay { timelock_delay: 0 })
+        .await
+        .unwrap();
+    let now = h.now().await;
+    h.warp_to(now + 2 * DAY).await;
+    h.apply_change(4, None).await.unwrap();
+    h.set_fees(0, 0).await.unwrap();
+}
+
+/// The creation bond is timelocked like the fees
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn creation_bond_changes_wait_out_the_delay() {
+    let mut h = Harness::start().await;
+    h.set_timelock_delay(DAY).await.unwrap();
+
+    let result = h.set_creation_bond(LAMPORTS_PER_SOL).await;
+    assert_anchor_error(result, ErrorCode::TimelockRequired);
+
+    let bond = ParameterChange::CreationBond {
+        creation_bond: LAMPORTS_PER_SOL,
+    };
+    h.schedule_change(1, bond).await.unwrap();
+    let now = h.now().await;
+    h.warp_to(now + DAY).await;
+    h.apply_change(1, None).await.unwrap();
+
+    let state: ProtocolConfig = h.account(&protocol_config_address()).await;
+    assert_eq!(state.creation_bond, LAMPORTS_PER_SOL);
+}
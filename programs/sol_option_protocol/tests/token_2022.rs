@@ -0,0 +1,100 @@
+//! Token-2022: series whose mints belong to Token-2022 work like SPL Token
+//! ones. Their option and redemption mints and vaults are created under
+//! Token-2022 too, and both mints of a series must share a token program.
+
+mod common;
+
+use anchor_spl::token_2022::spl_token_2022;
+use common::{create_series_ix, Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{OptionType, SeriesConfig};
+
+/// Alice writes 10 calls on Token-2022 SOL and USDC and sells 4 to Bob, who
+/// exercises 2. After expiry Alice redeems the rest of the vaults.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn token_2022_call_lifecycle() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint_2022(9).await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    assert_eq!(series.token_program, spl_token_2022::ID);
+    for address in [
+        series.option_mint,
+        series.redemption_mint,
+        series.collateral_vault,
+        series.consideration_vault,
+    ] {
+        assert_eq!(h.token_program_of(&address).await, spl_token_2022::ID);
+    }
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 300 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&series.collateral_vault).await, 10 * SOL);
+    assert_eq!(h.supply(&series.option_mint).await, 10 * SOL);
+
+    h.transfer(&alice, &a.option, &b.option, 4 * SOL).await;
+    h.option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 2 * SOL);
+    assert_eq!(h.balance(&b.consideration).await, 0);
+    assert_eq!(h.balance(&series.consideration_vault).await, 300 * USDC);
+
+    h.option_ix(ix::Burn { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, SOL);
+
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    h.option_ix(ix::Redeem { amount: 9 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 8 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 300 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 0);
+}
+
+/// A series can't pair an SPL Token mint with a Token-2022 one
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mints_must_share_a_token_program() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    for token_program in [anchor_spl::token::ID, spl_token_2022::ID] {
+        let (_, mut ix) = create_series_ix(
+            &alice.pubkey(),
+            sol,
+            usdc,
+            STRIKE,
+            expiration,
+            OptionType::Call,
+            SeriesConfig::default(),
+        );
+        for meta in ix.accounts.iter_mut() {
+            if meta.pubkey == anchor_spl::token::ID {
+                meta.pubkey = token_program;
+            }
+        }
+        let result = h.send(&[ix], &[&alice]).await;
+        assert!(result.is_err());
+    }
+}
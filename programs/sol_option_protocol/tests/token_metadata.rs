@@ -0,0 +1,214 @@
+//! Token metadata: series name their option and redemption mints after the
+//! canonical symbol through Metaplex Token Metadata, at creation when the
+//! metadata accounts are passed, or later through `update_series_metadata`.
+
+mod common;
+
+use anchor_lang::AnchorSerialize;
+use common::{assert_anchor_error, create_series_ix, Harness, DAY, SOL, STRIKE};
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::errors::ErrorCode;
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::metadata::{
+    create_metadata_ix, metadata_address, update_metadata_ix, TokenMetadata, MAX_NAME_LENGTH,
+    MAX_SYMBOL_LENGTH, TOKEN_METADATA_PROGRAM_ID,
+};
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+const FEB_28_2025: i64 = 1_740_700_800;
+
+fn bonk_call() -> OptionData {
+    OptionData {
+        option_type: OptionType::Call,
+        underlying_symbol: "BONK".to_string(),
+        strike_price: 40_000,
+        strike_exponent: 6,
+        expiration: FEB_28_2025,
+        metadata_uri: "https://example.com/bonk.json".to_string(),
+        ..OptionData::default()
+    }
+}
+
+#[test]
+fn names_follow_the_canonical_symbol() {
+    let series = bonk_call();
+    assert_eq!(
+        TokenMetadata::for_series(&series, false),
+        TokenMetadata {
+            name: "BONK-28FEB25-0.04-C".to_string(),
+            symbol: "BONK-C".to_string(),
+            uri: "https://example.com/bonk.json".to_string(),
+        }
+    );
+    let redemption = TokenMetadata::for_series(&series, true);
+    assert_eq!(redemption.name, "BONK-28FEB25-0.04-C-W");
+    assert_eq!(redemption.symbol, "BONK-CW");
+
+    let put = OptionData {
+        option_type: OptionType::BinaryPut,
+        ..bonk_call()
+    };
+    let put_metadata = TokenMetadata::for_series(&put, true);
+    assert_eq!(put_metadata.name, "BONK-28FEB25-0.04-BP-W");
+    assert_eq!(put_metadata.symbol, "BONK-BPW");
+}
+
+#[test]
+fn names_fit_the_metadata_limits() {
+    let series = OptionData {
+        underlying_symbol: "ABCDEFGHIJ".to_string(),
+        strike_price: 123_456_789_012_345,
+        strike_exponent: 9,
+        ..bonk_call()
+    };
+    let option = TokenMetadata::for_series(&series, false);
+    let redemption = TokenMetadata::for_series(&series, true);
+    assert_eq!(option.name.len(), MAX_NAME_LENGTH);
+    assert_eq!(redemption.name.len(), MAX_NAME_LENGTH);
+    assert!(redemption.name.ends_with("-W"));
+    assert_eq!(option.symbol, "ABCDEFGH-C");
+    assert_eq!(redemption.symbol, "ABCDEFG-CW");
+    assert!(redemption.symbol.len() <= MAX_SYMBOL_LENGTH);
+}
+
+/// Borsh `DataV2` with no creators, collection or uses
+fn data_v2(metadata: &TokenMetadata) -> Vec<u8> {
+    let mut data = Vec::new();
+    for field in [&metadata.name, &metadata.symbol, &metadata.uri] {
+        field.serialize(&mut data).unwrap();
+    }
+    data.extend_from_slice(&[0, 0, 0, 0, 0]);
+    data
+}
+
+#[test]
+fn instructions_match_the_token_metadata_layout() {
+    let mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let metadata = TokenMetadata::for_series(&bonk_call(), false);
+    let (expected_address, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    assert_eq!(metadata_address(&mint), expected_address);
+
+    // CreateMetadataAccountV3: mutable, no collection details
+    let create = create_metadata_ix(&mint, &authority, &payer, &metadata);
+    assert_eq!(create.program_id, TOKEN_METADATA_PROGRAM_ID);
+    assert_eq!(
+        create.accounts[0],
+        AccountMeta::new(expected_address, false)
+    );
+    assert_eq!(
+        create.accounts[2],
+        AccountMeta::new_readonly(authority, true)
+    );
+    assert_eq!(create.accounts[3], AccountMeta::new(payer, true));
+    let mut expected = vec![33];
+    expected.extend(data_v2(&metadata));
+    expected.extend_from_slice(&[1, 0]);
+    assert_eq!(create.data, expected);
+
+    // UpdateMetadataAccountV2: new data, everything else kept
+    let update = update_metadata_ix(&mint, &authority, &metadata);
+    assert_eq!(update.accounts.len(), 2);
+    let mut expected = vec![15, 1];
+    expected.extend(data_v2(&metadata));
+    expected.extend_from_slice(&[0, 0, 0]);
+    assert_eq!(update.data, expected);
+}
+
+/// Only the series authority can rewrite the metadata, and only into the
+/// mints' own metadata accounts
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn update_series_metadata_is_checked() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+
+    let result = h.update_series_metadata(&bob, &series, None).await;
+    assert_anchor_error(result, ErrorCode::Unauthorized);
+
+    let swapped = Some((
+        metadata_address(&series.redemption_mint),
+        metadata_address(&series.option_mint),
+    ));
+    let result = h.update_series_metadata(&alice, &series, swapped).await;
+    assert_anchor_error(result, ErrorCode::InvalidMetadataAccount);
+}
+
+/// Rewriting the metadata keeps the URI the series was created with, which
+/// holders bought against
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn update_series_metadata_keeps_the_uri() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        metadata_uri: "https://example.com/sol.json".to_string(),
+        ..SeriesConfig::default()
+    };
+    let series = h
+        .create_series_with(&alice, sol, usdc, STRIKE, expiration, false, config)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, SOL).await;
+    h.option_ix(ix::Mint { amount: SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    h.update_series_metadata(&alice, &series, None)
+        .await
+        .unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.metadata_uri, "https://example.com/sol.json");
+}
+
+/// `create_option` takes the metadata accounts all together or not at all
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn metadata_accounts_come_together() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, mut ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        SeriesConfig {
+            underlying_symbol: "SOL".to_string(),
+            ..SeriesConfig::default()
+        },
+    );
+    // Trailing optional accounts: program, option metadata, redemption metadata
+    let option_metadata = ix.accounts.len() - 2;
+    ix.accounts[option_metadata] = AccountMeta::new(metadata_address(&series.option_mint), false);
+    let result = h.send(&[ix], &[&alice]).await;
+    assert_anchor_error(result, ErrorCode::InvalidMetadataAccount);
+}
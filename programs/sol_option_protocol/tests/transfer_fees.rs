@@ -0,0 +1,166 @@
+//! Transfer fees: when a Token-2022 mint withholds a fee on transfers, mints
+//! and exercises are accounted against what actually reaches the vaults, so
+//! the fee never leaves a shortfall behind.
+
+mod common;
+
+use common::{Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::{OptionData, OptionType};
+
+const FEE_BPS: u16 = 100; // 1% withheld on every transfer
+
+fn call() -> OptionData {
+    OptionData {
+        option_type: OptionType::Call,
+        strike_price: STRIKE,
+        strike_scale: 9,
+        contract_multiplier: 1,
+        ..OptionData::default()
+    }
+}
+
+#[test]
+fn full_deposits_cover_the_requested_amount() {
+    let series = call();
+    assert_eq!(
+        series.covered_amount(10 * SOL, 10 * SOL, 0).unwrap(),
+        10 * SOL
+    );
+
+    let forward = OptionData {
+        option_type: OptionType::Forward,
+        ..call()
+    };
+    assert_eq!(
+        forward
+            .covered_amount(10 * SOL, 10 * SOL, 1_500 * USDC)
+            .unwrap(),
+        10 * SOL
+    );
+}
+
+#[test]
+fn short_deposits_cover_what_arrived() {
+    assert_eq!(
+        call().covered_amount(10 * SOL, 9_900_000_000, 0).unwrap(),
+        9_900_000_000
+    );
+    assert_eq!(call().covered_amount(10 * SOL, 0, 0).unwrap(), 0);
+
+    // Whole lots only
+    let lots = OptionData {
+        lot_size: SOL,
+        ..call()
+    };
+    assert_eq!(
+        lots.covered_amount(10 * SOL, 9_900_000_000, 0).unwrap(),
+        9 * SOL
+    );
+
+    // Puts escrow the strike value, rounded up per mint
+    let put = OptionData {
+        option_type: OptionType::Put,
+        ..call()
+    };
+    let covered = put.covered_amount(10 * SOL, 1_485 * USDC, 0).unwrap();
+    assert_eq!(covered, 9_900_000_000);
+    assert!(put.escrow_locked(covered).unwrap() <= 1_485 * USDC);
+    let odd = put.covered_amount(10 * SOL, 1_485 * USDC - 1, 0).unwrap();
+    assert!(odd < covered);
+    assert!(put.escrow_locked(odd).unwrap() < 1_485 * USDC);
+
+    // Forwards are bounded by the short leg too
+    let forward = OptionData {
+        option_type: OptionType::Forward,
+        ..call()
+    };
+    assert_eq!(
+        forward
+            .covered_amount(10 * SOL, 10 * SOL, 1_485 * USDC)
+            .unwrap(),
+        9_900_000_000
+    );
+}
+
+/// Alice writes 10 calls on a SOL mint that withholds 1% per transfer. The
+/// vault receives 9.9 SOL, so she gets 9.9 options and redemption tokens
+/// and the vault accounting matches its balance.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn mint_credits_what_reaches_the_vault() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint_with_transfer_fee(9, FEE_BPS, u64::MAX).await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    assert_eq!(h.balance(&a.collateral).await, 0);
+    assert_eq!(h.balance(&series.collateral_vault).await, 9_900_000_000);
+    assert_eq!(h.balance(&a.option).await, 9_900_000_000);
+    assert_eq!(h.balance(&a.redemption).await, 9_900_000_000);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.total_supply, 9_900_000_000);
+    assert_eq!(state.collateral_balance(), 9_900_000_000);
+    assert_eq!(
+        state.vault_shortfall(&series.collateral_vault, 9_900_000_000),
+        0
+    );
+}
+
+/// Bob exercises 2 calls with a USDC mint that withholds 1%: of his 300 USDC
+/// strike the vault receives 297, which is what Alice's redemption tokens
+/// can claim.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn exercise_credits_the_strike_that_arrived() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint_2022(9).await;
+    let usdc = h.create_mint_with_transfer_fee(6, FEE_BPS, u64::MAX).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 300 * USDC).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, 2 * SOL).await;
+
+    h.option_ix(ix::Exercise { amount: 2 * SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 2 * SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, 297 * USDC);
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.consideration_balance(), 297 * USDC);
+
+    h.option_ix(
+        ix::RedeemConsideration { amount: 2 * SOL },
+        &series,
+        &alice,
+        &a,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&series.consideration_vault).await, 0);
+    // The withdrawal is a transfer too
+    assert_eq!(h.balance(&a.consideration).await, 294_030_000);
+}
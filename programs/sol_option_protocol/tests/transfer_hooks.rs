@@ -0,0 +1,173 @@
+//! Transfer hooks: permissioned series give their option and redemption mints
+//! a Token-2022 transfer hook pointing at a compliance program. Every
+//! transfer of those tokens then calls the program; the protocol forwards the
+//! hook's accounts from its instructions' remaining accounts.
+
+mod common;
+
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{transfer_hook, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use common::{create_series_ix, create_series_ix_under, Harness, DAY, SOL, STRIKE};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::utils::validation::validate_transfer_hook;
+use sol_option_protocol::{OptionData, OptionType, SeriesConfig};
+
+fn hooked(transfer_hook_program: Pubkey) -> SeriesConfig {
+    SeriesConfig {
+        underlying_symbol: "SOL".to_string(),
+        transfer_hook_program,
+        ..SeriesConfig::default()
+    }
+}
+
+#[test]
+fn hooks_need_token_2022() {
+    let hook = Pubkey::new_unique();
+    assert!(validate_transfer_hook(&hook, &spl_token_2022::ID).is_ok());
+    assert!(validate_transfer_hook(&hook, &anchor_spl::token::ID).is_err());
+}
+
+#[test]
+fn series_without_a_hook_take_either_program() {
+    for token_program in [anchor_spl::token::ID, spl_token_2022::ID] {
+        assert!(validate_transfer_hook(&Pubkey::default(), &token_program).is_ok());
+    }
+}
+
+/// Hook program of `mint`, None when it has no transfer hook extension
+async fn hook_of(h: &mut Harness, mint: &Pubkey) -> Option<Pubkey> {
+    let account = h
+        .ctx
+        .banks_client
+        .get_account(*mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = StateWithExtensions::<MintState>::unpack(&account.data).unwrap();
+    transfer_hook::get_program_id(&state)
+}
+
+/// A Token-2022 series with a hook gets it on both of its mints, and writers
+/// still mint (minting never calls the hook)
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn hooked_series_mints_carry_the_hook() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint_2022(9).await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+    let compliance = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix_under(
+        &spl_token_2022::ID,
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        hooked(compliance),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+
+    assert_eq!(hook_of(&mut h, &series.option_mint).await, Some(compliance));
+    assert_eq!(
+        hook_of(&mut h, &series.redemption_mint).await,
+        Some(compliance)
+    );
+    let data: OptionData = h.account(&series.option_context).await;
+    assert_eq!(data.transfer_hook_program, compliance);
+
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 2 * SOL).await;
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.option).await, 2 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 2 * SOL);
+}
+
+/// Without a hook program a Token-2022 series' mints have no extension
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn unhooked_series_mints_are_plain() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint_2022(9).await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (series, ix) = create_series_ix_under(
+        &spl_token_2022::ID,
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        hooked(Pubkey::default()),
+    );
+    h.send(&[ix], &[&alice]).await.unwrap();
+
+    assert_eq!(hook_of(&mut h, &series.option_mint).await, None);
+    assert_eq!(hook_of(&mut h, &series.redemption_mint).await, None);
+}
+
+/// A batch ladder hooks every strike's mints
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn batch_series_carry_the_hook() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint_2022(9).await;
+    let usdc = h.create_mint_2022(6).await;
+    let alice = h.actor().await;
+    let compliance = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let ladder = h
+        .create_series_batch(
+            &alice,
+            sol,
+            usdc,
+            &[STRIKE, 2 * STRIKE],
+            expiration,
+            false,
+            hooked(compliance),
+        )
+        .await
+        .unwrap();
+    for series in &ladder {
+        assert_eq!(hook_of(&mut h, &series.option_mint).await, Some(compliance));
+        assert_eq!(
+            hook_of(&mut h, &series.redemption_mint).await,
+            Some(compliance)
+        );
+    }
+}
+
+/// SPL Token mints have no extensions, so a hook can't be asked for there
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn spl_token_series_cannot_be_hooked() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let (_, ix) = create_series_ix(
+        &alice.pubkey(),
+        sol,
+        usdc,
+        STRIKE,
+        expiration,
+        OptionType::Call,
+        hooked(Pubkey::new_unique()),
+    );
+    assert!(h.send(&[ix], &[&alice]).await.is_err());
+}
@@ -0,0 +1,81 @@
+//! Payouts come from the series' own vault accounting, not live balances:
+//! tokens sent straight to a vault can't inflate or skew anyone's share.
+
+mod common;
+
+use common::{Harness, DAY, SOL, STRIKE, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::OptionData;
+
+/// Alice writes 4 calls and Bob exercises 1. Mallory donates 10 SOL and
+/// 1,000 USDC to the vaults; the settlement snapshot and Alice's redemption
+/// ignore the donation, which stays in the vaults.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn donations_do_not_change_payouts() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let mallory = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    let b = h.wallet(&series, &bob.pubkey()).await;
+    let m = h.wallet(&series, &mallory.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 4 * SOL).await;
+    h.mint_to(&usdc, &b.consideration, 150 * USDC).await;
+    h.mint_to(&sol, &m.collateral, 10 * SOL).await;
+    h.mint_to(&usdc, &m.consideration, 1_000 * USDC).await;
+
+    h.option_ix(ix::Mint { amount: 4 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.transfer(&alice, &a.option, &b.option, SOL).await;
+    h.option_ix(ix::Exercise { amount: SOL }, &series, &bob, &b)
+        .await
+        .unwrap();
+
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.collateral_balance(), 3 * SOL);
+    assert_eq!(state.consideration_balance(), 150 * USDC);
+
+    // --- Donations land in the vaults but not in the accounting ----------------
+    h.transfer(&mallory, &m.collateral, &series.collateral_vault, 10 * SOL)
+        .await;
+    h.transfer(
+        &mallory,
+        &m.consideration,
+        &series.consideration_vault,
+        1_000 * USDC,
+    )
+    .await;
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.collateral_balance(), 3 * SOL);
+    assert_eq!(state.consideration_balance(), 150 * USDC);
+
+    // --- Settlement snapshots the accounted balances ---------------------------
+    h.warp_to(expiration + 1).await;
+    h.settle(&series).await.unwrap();
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.settled_collateral, 3 * SOL);
+    assert_eq!(state.settled_consideration, 150 * USDC);
+
+    h.option_ix(ix::Redeem { amount: 4 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.collateral).await, 3 * SOL);
+    assert_eq!(h.balance(&a.consideration).await, 150 * USDC);
+    assert_eq!(h.balance(&series.collateral_vault).await, 10 * SOL);
+    assert_eq!(h.balance(&series.consideration_vault).await, 1_000 * USDC);
+
+    let state: OptionData = h.account(&series.option_context).await;
+    assert_eq!(state.collateral_balance(), 0);
+    assert_eq!(state.consideration_balance(), 0);
+}
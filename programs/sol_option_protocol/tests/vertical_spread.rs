@@ -0,0 +1,236 @@
+//! Tokenized vertical spreads: writers lock further-OTM longs plus the strike
+//! difference and mint spread tokens paying the short series' intrinsic value.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use common::{vertical_spread_addresses, Harness, DAY, SOL, USDC};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::oracle::{OracleFeed, OracleKind};
+use sol_option_protocol::state::VerticalSpread;
+use sol_option_protocol::utils::margin::spread_max_loss;
+use sol_option_protocol::utils::netting::intrinsic_value;
+use sol_option_protocol::SeriesConfig;
+
+#[test]
+fn backing_plus_longs_cover_the_short_leg_at_any_price() {
+    for is_put in [false, true] {
+        let (short, long) = if is_put {
+            (150 * USDC, 100 * USDC)
+        } else {
+            (150 * USDC, 200 * USDC)
+        };
+        let backing = spread_max_loss(10 * SOL, short, long, 9, is_put).unwrap();
+        for price in (10..=400).step_by(5).map(|p| p * USDC) {
+            let owed = intrinsic_value(10 * SOL, short, price, 9, is_put).unwrap();
+            let longs = intrinsic_value(10 * SOL, long, price, 9, is_put).unwrap();
+            assert!(owed <= backing + longs, "price {price} is_put {is_put}");
+        }
+    }
+}
+
+fn oracle_config(oracle: Pubkey) -> SeriesConfig {
+    SeriesConfig {
+        primary_oracle: OracleFeed {
+            kind: OracleKind::Pyth,
+            feed: oracle,
+            ..OracleFeed::default()
+        },
+        ..SeriesConfig::default()
+    }
+}
+
+/// Alice holds 10 $200 calls and writes 10 $150/$200 spread tokens with only
+/// 2.5 SOL of backing, selling 4 to Bob. SOL settles at $250: the locked longs
+/// pay 2 SOL, holders get the $150 calls' 4 SOL and Alice the 0.5 SOL left.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn call_spread_tokens_settle_on_the_short_strike() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let bob = h.actor().await;
+    let oracle = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let short = h
+        .create_series_with(
+            &alice,
+            sol,
+            usdc,
+            150 * USDC,
+            expiration,
+            false,
+            oracle_config(oracle),
+        )
+        .await;
+    let long = h
+        .create_series_with(
+            &alice,
+            sol,
+            usdc,
+            200 * USDC,
+            expiration,
+            false,
+            oracle_config(oracle),
+        )
+        .await;
+    let a = h.wallet(&long, &alice.pubkey()).await;
+    let b = h.wallet(&long, &bob.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 13 * SOL).await;
+    h.option_ix(ix::Mint { amount: 10 * SOL }, &long, &alice, &a)
+        .await
+        .unwrap();
+
+    h.vertical_spread_ix(
+        ix::MintVerticalSpread { amount: 10 * SOL },
+        &short,
+        &long,
+        &alice,
+        true,
+    )
+    .await
+    .unwrap();
+    let (spread, spread_mint) = vertical_spread_addresses(&short, &long);
+    let state: VerticalSpread = h.account(&spread).await;
+    assert_eq!(state.written, 10 * SOL);
+    assert_eq!(state.backing, 2_500_000_000);
+    assert_eq!(h.balance(&a.collateral).await, 500_000_000);
+    assert_eq!(h.balance(&a.option).await, 0);
+
+    // Writers can burn spread tokens back before settlement
+    h.vertical_spread_ix(
+        ix::BurnVerticalSpread { amount: SOL },
+        &short,
+        &long,
+        &alice,
+        true,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&a.option).await, SOL);
+    assert_eq!(h.balance(&a.collateral).await, 750_000_000);
+    h.vertical_spread_ix(
+        ix::MintVerticalSpread { amount: SOL },
+        &short,
+        &long,
+        &alice,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let alice_spread = get_associated_token_address(&alice.pubkey(), &spread_mint);
+    let bob_spread = h.create_ata(&spread_mint, &bob.pubkey()).await;
+    h.transfer(&alice, &alice_spread, &bob_spread, 4 * SOL)
+        .await;
+
+    h.set_pyth_price(&oracle, 250 * USDC, expiration).await;
+    h.warp_to(expiration + 1).await;
+    h.settle_with_oracle(&long, &oracle).await.unwrap();
+    h.vertical_spread_ix(ix::SettleVerticalSpread {}, &short, &long, &bob, false)
+        .await
+        .unwrap();
+    let state: VerticalSpread = h.account(&spread).await;
+    assert_eq!(state.settlement_price, 250 * USDC);
+    assert_eq!(state.holder_payout, 4 * SOL);
+    assert_eq!(state.writer_payout, 500_000_000);
+
+    h.vertical_spread_ix(
+        ix::ClaimVerticalSpread { amount: 4 * SOL },
+        &short,
+        &long,
+        &bob,
+        false,
+    )
+    .await
+    .unwrap();
+    assert_eq!(h.balance(&b.collateral).await, 1_600_000_000);
+    h.vertical_spread_ix(
+        ix::ClaimVerticalSpread { amount: 6 * SOL },
+        &short,
+        &long,
+        &alice,
+        false,
+    )
+    .await
+    .unwrap();
+    h.vertical_spread_ix(ix::RedeemVerticalSpread {}, &short, &long, &alice, true)
+        .await
+        .unwrap();
+    assert_eq!(
+        h.balance(&a.collateral).await,
+        500_000_000 + 2_400_000_000 + 500_000_000
+    );
+    let state: VerticalSpread = h.account(&spread).await;
+    assert_eq!(state.written, 0);
+    assert_eq!(state.holder_payout, 0);
+    assert_eq!(state.writer_payout, 0);
+}
+
+/// Contracts of 100 shares: Alice writes 2 $150/$100 put spread contracts,
+/// backed by the strike difference on all 200 shares ($10,000). Shares settle
+/// at $120, so holders get $30 on each of the 200 shares and Alice the rest.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn spreads_scale_by_the_contract_multiplier() {
+    let mut h = Harness::start().await;
+    let shares = h.create_mint(0).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+    let oracle = Pubkey::new_unique();
+
+    let expiration = h.now().await + 30 * DAY;
+    let config = SeriesConfig {
+        contract_multiplier: 100,
+        ..oracle_config(oracle)
+    };
+    let short = h
+        .create_series_with(
+            &alice,
+            shares,
+            usdc,
+            150 * USDC,
+            expiration,
+            true,
+            config.clone(),
+        )
+        .await;
+    let long = h
+        .create_series_with(&alice, shares, usdc, 100 * USDC, expiration, true, config)
+        .await;
+    let a = h.wallet(&long, &alice.pubkey()).await;
+    h.mint_to(&usdc, &a.consideration, 30_000 * USDC).await;
+    h.option_ix(ix::Mint { amount: 2 }, &long, &alice, &a)
+        .await
+        .unwrap();
+    assert_eq!(h.balance(&a.consideration).await, 10_000 * USDC);
+
+    h.vertical_spread_ix(
+        ix::MintVerticalSpread { amount: 2 },
+        &short,
+        &long,
+        &alice,
+        true,
+    )
+    .await
+    .unwrap();
+    let (spread, _) = vertical_spread_addresses(&short, &long);
+    let state: VerticalSpread = h.account(&spread).await;
+    assert_eq!(state.backing, 10_000 * USDC);
+    assert_eq!(h.balance(&a.consideration).await, 0);
+
+    h.set_pyth_price(&oracle, 120 * USDC, expiration).await;
+    h.warp_to(expiration + 1).await;
+    h.settle_with_oracle(&long, &oracle).await.unwrap();
+    h.vertical_spread_ix(ix::SettleVerticalSpread {}, &short, &long, &alice, false)
+        .await
+        .unwrap();
+    let state: VerticalSpread = h.account(&spread).await;
+    assert_eq!(state.holder_payout, 6_000 * USDC);
+    assert_eq!(state.writer_payout, 4_000 * USDC);
+}
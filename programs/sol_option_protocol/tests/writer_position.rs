@@ -0,0 +1,46 @@
+//! WriterPosition records what a writer has outstanding when passed to
+//! mint/burn; fungible redemption tokens alone can't tell writers apart.
+
+mod common;
+
+use common::{Harness, DAY, SOL, STRIKE};
+use solana_sdk::signature::Signer;
+
+use sol_option_protocol::instruction as ix;
+use sol_option_protocol::state::WriterPosition;
+
+/// Alice writes 5 calls through her position and 2 without it, then burns 3
+/// pairs through it: the position shows 2 outstanding out of 5 written.
+#[tokio::test]
+#[ignore = "needs the SBF builds; run `anchor build` first"]
+async fn position_tracks_mints_and_burns() {
+    let mut h = Harness::start().await;
+    let sol = h.create_mint(9).await;
+    let usdc = h.create_mint(6).await;
+    let alice = h.actor().await;
+
+    let expiration = h.now().await + 30 * DAY;
+    let series = h
+        .create_series(&alice, sol, usdc, STRIKE, expiration, false)
+        .await;
+    let a = h.wallet(&series, &alice.pubkey()).await;
+    h.mint_to(&sol, &a.collateral, 7 * SOL).await;
+    let position = h.open_writer_position(&series, &alice).await;
+
+    h.tracked_option_ix(ix::Mint { amount: 5 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.option_ix(ix::Mint { amount: 2 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+    h.tracked_option_ix(ix::Burn { amount: 3 * SOL }, &series, &alice, &a)
+        .await
+        .unwrap();
+
+    let state: WriterPosition = h.account(&position).await;
+    assert_eq!(state.option_context, series.option_context);
+    assert_eq!(state.writer, alice.pubkey());
+    assert_eq!(state.outstanding, 2 * SOL);
+    assert_eq!(state.total_written, 5 * SOL);
+    assert_eq!(h.balance(&a.redemption).await, 4 * SOL);
+}
@@ -0,0 +1,191 @@
+//! Instruction builders for clients and for programs trading on the market.
+//!
+//! Depend on the crate with the `cpi` feature (it implies `no-entrypoint`).
+//! On-chain callers then invoke `cpi::place_order` and `cpi::cancel_order`
+//! with a `CpiContext` over `cpi::accounts::PlaceOrder` or
+//! `cpi::accounts::CancelOrder`. Anchor generates both from the program.
+//! Callers signing with their own PDA, or off-chain clients, can instead
+//! build the `Instruction` here and pass it to `invoke_signed`. Deposits come
+//! from, and refunds go to, the owner's associated token accounts.
+//!
+//! Indexers list accounts with getProgramAccounts and the [`Memcmp`] filters
+//! built here: the account discriminator plus a field at its `*_OFFSET`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, InstructionData};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+pub use crate::accounts;
+#[cfg(feature = "cpi")]
+pub use crate::cpi;
+pub use crate::instruction;
+
+use crate::state::market::Market;
+use crate::state::order::Order;
+
+/// Market PDA of a base/quote pair
+pub fn market_address(base_mint: &Pubkey, quote_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"market", base_mint.as_ref(), quote_mint.as_ref()],
+        &crate::ID,
+    )
+    .0
+}
+
+/// Order PDA of the `order_id`th order placed on `market`
+pub fn order_address(market: &Pubkey, order_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"order", market.as_ref(), order_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+    .0
+}
+
+/// Escrow token account of `order`
+pub fn escrow_address(order: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"escrow", order.as_ref()], &crate::ID).0
+}
+
+/// getProgramAccounts memcmp filter: `bytes` must sit at `offset` of the
+/// account data
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Memcmp {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+impl Memcmp {
+    fn new(offset: usize, bytes: &[u8]) -> Self {
+        Self {
+            offset,
+            bytes: bytes.to_vec(),
+        }
+    }
+}
+
+/// Filters matching every `Order` account
+pub fn orders_filter() -> Vec<Memcmp> {
+    vec![Memcmp::new(0, Order::DISCRIMINATOR)]
+}
+
+/// Filters matching the open orders of `market`
+pub fn orders_in_market_filter(market: &Pubkey) -> Vec<Memcmp> {
+    let mut filters = orders_filter();
+    filters.push(Memcmp::new(Order::MARKET_OFFSET, market.as_ref()));
+    filters
+}
+
+/// Filters matching the open orders of `owner` across markets
+pub fn orders_of_owner_filter(owner: &Pubkey) -> Vec<Memcmp> {
+    let mut filters = orders_filter();
+    filters.push(Memcmp::new(Order::OWNER_OFFSET, owner.as_ref()));
+    filters
+}
+
+/// Filters matching the bids (`is_buy`) or asks of `market`
+pub fn market_side_filter(market: &Pubkey, is_buy: bool) -> Vec<Memcmp> {
+    let mut filters = orders_in_market_filter(market);
+    filters.push(Memcmp::new(Order::IS_BUY_OFFSET, &[is_buy as u8]));
+    filters
+}
+
+/// Filters matching every `Market` account
+pub fn markets_filter() -> Vec<Memcmp> {
+    vec![Memcmp::new(0, Market::DISCRIMINATOR)]
+}
+
+/// Filters matching the markets trading `base_mint`
+pub fn markets_by_base_mint_filter(base_mint: &Pubkey) -> Vec<Memcmp> {
+    let mut filters = markets_filter();
+    filters.push(Memcmp::new(Market::BASE_MINT_OFFSET, base_mint.as_ref()));
+    filters
+}
+
+/// Filters matching the markets quoted in `quote_mint`
+pub fn markets_by_quote_mint_filter(quote_mint: &Pubkey) -> Vec<Memcmp> {
+    let mut filters = markets_filter();
+    filters.push(Memcmp::new(Market::QUOTE_MINT_OFFSET, quote_mint.as_ref()));
+    filters
+}
+
+/// Mint an order escrows: quote for buys, base for sells
+fn escrow_mint(market: &Market, is_buy: bool) -> Pubkey {
+    if is_buy {
+        market.quote_mint
+    } else {
+        market.base_mint
+    }
+}
+
+/// `place_order` of `user` on `market` (whose current state is `market_state`)
+/// and the address of the order it opens. `token_program` owns the escrowed
+/// mint. The series of a linked market is passed along.
+pub fn place_order_ix(
+    market: &Pubkey,
+    market_state: &Market,
+    user: &Pubkey,
+    token_program: &Pubkey,
+    price: u64,
+    size: u64,
+    is_buy: bool,
+) -> (Pubkey, Instruction) {
+    let order = order_address(market, market_state.next_order_id);
+    let deposit_mint = escrow_mint(market_state, is_buy);
+    let linked_series =
+        (market_state.linked_series != Pubkey::default()).then_some(market_state.linked_series);
+    let ix = Instruction {
+        program_id: crate::ID,
+        accounts: accounts::PlaceOrder {
+            user: *user,
+            market: *market,
+            order,
+            deposit_mint,
+            user_deposit_account: get_associated_token_address_with_program_id(
+                user,
+                &deposit_mint,
+                token_program,
+            ),
+            escrow: escrow_address(&order),
+            token_program: *token_program,
+            system_program: System::id(),
+            linked_series,
+        }
+        .to_account_metas(None),
+        data: instruction::PlaceOrder {
+            price,
+            size,
+            is_buy,
+        }
+        .data(),
+    };
+    (order, ix)
+}
+
+/// `cancel_order` of `order` (whose state is `order_state`, on a market in
+/// state `market_state`), refunding what is left to its owner
+pub fn cancel_order_ix(
+    order: &Pubkey,
+    order_state: &Order,
+    market_state: &Market,
+    token_program: &Pubkey,
+) -> Instruction {
+    let return_mint = escrow_mint(market_state, order_state.is_buy);
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts::CancelOrder {
+            user: order_state.owner,
+            order: *order,
+            return_mint,
+            user_return_account: get_associated_token_address_with_program_id(
+                &order_state.owner,
+                &return_mint,
+                token_program,
+            ),
+            escrow: escrow_address(order),
+            token_program: *token_program,
+        }
+        .to_account_metas(None),
+        data: instruction::CancelOrder {}.data(),
+    }
+}
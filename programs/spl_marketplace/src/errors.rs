@@ -25,4 +25,28 @@ pub enum ErrorCode {
 
     #[msg("Invalid market")]
     InvalidMarket,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Linked series account is missing or does not match the market")]
+    InvalidLinkedSeries,
+
+    #[msg("Trading is halted: linked series is paused, frozen or expired")]
+    TradingHalted,
+
+    #[msg("This program version has already been announced")]
+    VersionAlreadyAnnounced,
+
+    #[msg("Market already uses the current account layout")]
+    MarketUpToDate,
+
+    #[msg("Linked series uses an account layout this program can't read; migrate it")]
+    UnsupportedSeriesVersion,
+
+    #[msg("Migrating a legacy market takes the options protocol admin's signature")]
+    LegacyMarketAuthority,
+
+    #[msg("Account is not the options protocol's config")]
+    InvalidProtocolConfig,
 }
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the current market authority nominates a successor
+#[event]
+pub struct MarketAuthorityProposed {
+    pub market: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when the nominated successor accepts the market authority
+#[event]
+pub struct MarketAuthorityTransferred {
+    pub market: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when the market authority links the market to an option series
+#[event]
+pub struct MarketSeriesLinked {
+    pub market: Pubkey,
+    pub linked_series: Pubkey,
+}
+
+/// Emitted once per deployed version so indexers can detect supported capabilities
+#[event]
+pub struct ProgramVersionAnnounced {
+    pub program_id: Pubkey,
+    pub version: String,
+    pub previous_version: String,
+    pub capabilities: Vec<String>,
+    pub config_hash: [u8; 32],
+    pub slot: u64,
+}
+
+/// Emitted when migrate_market brings a market to the current account layout
+#[event]
+pub struct MarketMigrated {
+    pub market: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
@@ -0,0 +1,40 @@
+use crate::errors::ErrorCode;
+use crate::events::MarketAuthorityTransferred;
+use crate::state::market::Market;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptMarketAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = market.pending_authority != Pubkey::default() @ ErrorCode::NoPendingAuthority,
+        constraint = market.pending_authority == pending_authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub market: Account<'info, Market>,
+}
+
+/// Step 2 of 2: the nominated key signs to take over the market
+pub fn handler(ctx: Context<AcceptMarketAuthority>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let previous_authority = market.authority;
+
+    market.authority = ctx.accounts.pending_authority.key();
+    market.pending_authority = Pubkey::default();
+
+    emit!(MarketAuthorityTransferred {
+        market: market.key(),
+        previous_authority,
+        new_authority: market.authority,
+    });
+
+    msg!(
+        "Market {} authority transferred: {} -> {}",
+        market.key(),
+        previous_authority,
+        market.authority
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::errors::ErrorCode;
+use crate::events::ProgramVersionAnnounced;
+use crate::state::program_version::ProgramVersion;
+use crate::version::{CAPABILITIES, VERSION};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AnnounceVersion<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ProgramVersion::SIZE,
+        seeds = [b"program_version"],
+        bump
+    )]
+    pub program_version: Account<'info, ProgramVersion>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: emits the deployment's version once per upgrade. The
+/// marketplace has no global config, so the config hash is always zero.
+pub fn handler(ctx: Context<AnnounceVersion>) -> Result<()> {
+    let record = &mut ctx.accounts.program_version;
+    require!(
+        record.version != VERSION,
+        ErrorCode::VersionAlreadyAnnounced
+    );
+
+    let previous_version = std::mem::replace(&mut record.version, VERSION.to_string());
+    record.bump = ctx.bumps.program_version;
+    record.announced_slot = Clock::get()?.slot;
+
+    emit!(ProgramVersionAnnounced {
+        program_id: crate::ID,
+        version: VERSION.to_string(),
+        previous_version,
+        capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+        config_hash: [0u8; 32],
+        slot: record.announced_slot,
+    });
+
+    msg!("Program version announced: {}", VERSION);
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::errors::ErrorCode;
 use crate::state::order::Order;
+use crate::token_transfer::{transfer_checked_with_hook, HookedTransfer};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct CancelOrder<'info> {
@@ -30,7 +31,7 @@ pub struct CancelOrder<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handler(ctx: Context<CancelOrder>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>) -> Result<()> {
     let order = &ctx.accounts.order;
     let remaining = order.remaining();
 
@@ -40,19 +41,17 @@ pub fn handler(ctx: Context<CancelOrder>) -> Result<()> {
     let signer_seeds: &[&[&[u8]]] = &[&[b"escrow", order_key.as_ref(), &[ctx.bumps.escrow]]];
 
     // Return escrowed tokens
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.escrow.to_account_info(),
-                mint: ctx.accounts.return_mint.to_account_info(),
-                to: ctx.accounts.user_return_account.to_account_info(),
-                authority: ctx.accounts.order.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    transfer_checked_with_hook(
+        HookedTransfer {
+            token_program: ctx.accounts.token_program.to_account_info(),
+            from: ctx.accounts.escrow.to_account_info(),
+            mint: &ctx.accounts.return_mint,
+            to: ctx.accounts.user_return_account.to_account_info(),
+            authority: ctx.accounts.order.to_account_info(),
+        },
+        ctx.remaining_accounts,
         ctx.accounts.escrow.amount,
-        ctx.accounts.return_mint.decimals,
+        signer_seeds,
     )?;
 
     msg!("Order {} cancelled", ctx.accounts.order.order_id);
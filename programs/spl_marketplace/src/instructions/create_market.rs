@@ -1,6 +1,6 @@
+use crate::state::market::Market;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use crate::state::market::Market;
 
 #[derive(Accounts)]
 pub struct CreateMarket<'info> {
@@ -36,6 +36,11 @@ pub fn handler(ctx: Context<CreateMarket>) -> Result<()> {
     market.total_orders_filled = 0;
     market.total_base_volume = 0;
     market.total_quote_volume = 0;
+    market.authority = ctx.accounts.creator.key();
+    market.pending_authority = Pubkey::default();
+    market.linked_series = Pubkey::default();
+    market.version = Market::CURRENT_VERSION;
+    market.reserved = [0; 64];
 
     msg!(
         "Market created: {} / {}",
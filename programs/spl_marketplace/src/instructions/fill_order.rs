@@ -1,8 +1,10 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::errors::ErrorCode;
+use crate::series_policy::check_trading_allowed;
 use crate::state::market::Market;
 use crate::state::order::Order;
+use crate::token_transfer::{transfer_checked_with_hook, HookedTransfer};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct FillOrder<'info> {
@@ -35,17 +37,24 @@ pub struct FillOrder<'info> {
     pub maker_receive_account: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Required when the market is linked; validated by check_trading_allowed.
+    /// Last, after the accounts existing clients already pass
+    pub linked_series: Option<UncheckedAccount<'info>>,
 }
 
-pub fn handler(ctx: Context<FillOrder>, fill_size: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillOrder<'info>>,
+    fill_size: u64,
+) -> Result<()> {
     let order = &ctx.accounts.maker_order;
     let remaining = order.remaining();
 
     require!(fill_size > 0, ErrorCode::InvalidAmount);
     require!(fill_size <= remaining, ErrorCode::InvalidFillSize);
+    check_trading_allowed(&ctx.accounts.market, ctx.accounts.linked_series.as_deref())?;
 
     let base_decimals = ctx.accounts.base_mint.decimals;
-    let quote_decimals = ctx.accounts.quote_mint.decimals;
 
     // Calculate quote amount
     let quote_amount = order
@@ -66,63 +75,57 @@ pub fn handler(ctx: Context<FillOrder>, fill_size: u64) -> Result<()> {
 
     if order.is_buy {
         // Maker buying: Taker gives base, receives quote from escrow
-        token_interface::transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.taker_base_account.to_account_info(),
-                    mint: ctx.accounts.base_mint.to_account_info(),
-                    to: ctx.accounts.maker_receive_account.to_account_info(),
-                    authority: ctx.accounts.taker.to_account_info(),
-                },
-            ),
+        transfer_checked_with_hook(
+            HookedTransfer {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: ctx.accounts.taker_base_account.to_account_info(),
+                mint: &ctx.accounts.base_mint,
+                to: ctx.accounts.maker_receive_account.to_account_info(),
+                authority: ctx.accounts.taker.to_account_info(),
+            },
+            ctx.remaining_accounts,
             fill_size,
-            base_decimals,
+            &[],
         )?;
 
-        token_interface::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.maker_escrow.to_account_info(),
-                    mint: ctx.accounts.quote_mint.to_account_info(),
-                    to: ctx.accounts.taker_quote_account.to_account_info(),
-                    authority: ctx.accounts.maker_order.to_account_info(),
-                },
-                signer_seeds,
-            ),
+        transfer_checked_with_hook(
+            HookedTransfer {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: ctx.accounts.maker_escrow.to_account_info(),
+                mint: &ctx.accounts.quote_mint,
+                to: ctx.accounts.taker_quote_account.to_account_info(),
+                authority: ctx.accounts.maker_order.to_account_info(),
+            },
+            ctx.remaining_accounts,
             quote_amount,
-            quote_decimals,
+            signer_seeds,
         )?;
     } else {
         // Maker selling: Taker receives base from escrow, gives quote
-        token_interface::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.maker_escrow.to_account_info(),
-                    mint: ctx.accounts.base_mint.to_account_info(),
-                    to: ctx.accounts.taker_base_account.to_account_info(),
-                    authority: ctx.accounts.maker_order.to_account_info(),
-                },
-                signer_seeds,
-            ),
+        transfer_checked_with_hook(
+            HookedTransfer {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: ctx.accounts.maker_escrow.to_account_info(),
+                mint: &ctx.accounts.base_mint,
+                to: ctx.accounts.taker_base_account.to_account_info(),
+                authority: ctx.accounts.maker_order.to_account_info(),
+            },
+            ctx.remaining_accounts,
             fill_size,
-            base_decimals,
+            signer_seeds,
         )?;
 
-        token_interface::transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.taker_quote_account.to_account_info(),
-                    mint: ctx.accounts.quote_mint.to_account_info(),
-                    to: ctx.accounts.maker_receive_account.to_account_info(),
-                    authority: ctx.accounts.taker.to_account_info(),
-                },
-            ),
+        transfer_checked_with_hook(
+            HookedTransfer {
+                token_program: ctx.accounts.token_program.to_account_info(),
+                from: ctx.accounts.taker_quote_account.to_account_info(),
+                mint: &ctx.accounts.quote_mint,
+                to: ctx.accounts.maker_receive_account.to_account_info(),
+                authority: ctx.accounts.taker.to_account_info(),
+            },
+            ctx.remaining_accounts,
             quote_amount,
-            quote_decimals,
+            &[],
         )?;
     }
 
@@ -0,0 +1,47 @@
+use crate::errors::ErrorCode;
+use crate::events::MarketSeriesLinked;
+use crate::series_policy::SeriesStatus;
+use crate::state::market::Market;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LinkSeries<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedAccess,
+        constraint = market.linked_series == Pubkey::default() @ ErrorCode::InvalidLinkedSeries
+    )]
+    pub market: Account<'info, Market>,
+
+    /// CHECK: Owner, discriminator and option mint validated in SeriesStatus::load
+    pub series: UncheckedAccount<'info>,
+}
+
+/// Link the market to the series whose option token it trades. One-way, so a
+/// halted series cannot be bypassed by unlinking.
+pub fn handler(ctx: Context<LinkSeries>) -> Result<()> {
+    let status = SeriesStatus::load(&ctx.accounts.series)?;
+    let market = &mut ctx.accounts.market;
+    require_keys_eq!(
+        status.option_mint,
+        market.base_mint,
+        ErrorCode::InvalidLinkedSeries
+    );
+
+    market.linked_series = ctx.accounts.series.key();
+
+    emit!(MarketSeriesLinked {
+        market: market.key(),
+        linked_series: market.linked_series,
+    });
+
+    msg!(
+        "Market {} linked to series {}",
+        market.key(),
+        market.linked_series
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::errors::ErrorCode;
+use crate::events::MarketMigrated;
+use crate::series_policy::protocol_admin;
+use crate::state::market::Market;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Permissionless: grows a market account to the current `Market` layout
+/// and steps its version up (the payer covers the extra rent). Markets from
+/// before versioning also need the options protocol admin, who becomes
+/// their authority.
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: May predate the current layout, so it is loaded by hand; owner
+    /// checked here, discriminator in the handler
+    #[account(mut, owner = crate::ID)]
+    pub market: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Legacy markets only: the options protocol admin, backfilled as the
+    /// market authority
+    pub admin: Option<Signer<'info>>,
+
+    /// CHECK: Legacy markets only: the options program's `ProtocolConfig`;
+    /// address, owner and discriminator checked in `protocol_admin`
+    pub protocol_config: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> MigrateMarket<'info> {
+    /// The signing options protocol admin, if both accounts were passed
+    fn legacy_authority(&self) -> Result<Option<Pubkey>> {
+        let (Some(admin), Some(config)) = (&self.admin, &self.protocol_config) else {
+            return Ok(None);
+        };
+        require_keys_eq!(
+            admin.key(),
+            protocol_admin(config)?,
+            ErrorCode::UnauthorizedAccess
+        );
+        Ok(Some(admin.key()))
+    }
+}
+
+pub fn handler(ctx: Context<MigrateMarket>) -> Result<()> {
+    let info = ctx.accounts.market.to_account_info();
+    require!(
+        info.try_borrow_data()?.starts_with(Market::DISCRIMINATOR),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    // Markets created before versioning are LEGACY_SIZE; grow them (the new
+    // bytes are zeroed, so they read as version 0)
+    if info.data_len() < Market::SIZE {
+        require!(
+            info.data_len() == Market::LEGACY_SIZE,
+            anchor_lang::error::ErrorCode::AccountDidNotDeserialize
+        );
+        let shortfall = Rent::get()?
+            .minimum_balance(Market::SIZE)
+            .saturating_sub(info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(Market::SIZE)?;
+    }
+
+    let mut market = Market::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let from_version = market.version;
+    require!(
+        from_version < Market::CURRENT_VERSION,
+        ErrorCode::MarketUpToDate
+    );
+    market.migrate(ctx.accounts.legacy_authority()?)?;
+    market.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(MarketMigrated {
+        market: info.key(),
+        from_version,
+        to_version: market.version,
+    });
+
+    msg!(
+        "Market {} migrated from layout version {} to {}",
+        info.key(),
+        from_version,
+        market.version
+    );
+
+    Ok(())
+}
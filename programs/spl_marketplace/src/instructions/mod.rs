@@ -1,9 +1,30 @@
+pub mod accept_market_authority;
+pub mod announce_version;
 pub mod cancel_order;
 pub mod create_market;
 pub mod fill_order;
+pub mod link_series;
+pub mod migrate_market;
 pub mod place_order;
+pub mod propose_market_authority;
 
+// Note: Glob imports are required for Anchor's #[program] macro
+// The handler name collision is intentional - each module's handler is accessed via module path
+#[allow(ambiguous_glob_reexports)]
+pub use accept_market_authority::*;
+#[allow(ambiguous_glob_reexports)]
+pub use announce_version::*;
+#[allow(ambiguous_glob_reexports)]
 pub use cancel_order::*;
+#[allow(ambiguous_glob_reexports)]
 pub use create_market::*;
+#[allow(ambiguous_glob_reexports)]
 pub use fill_order::*;
+#[allow(ambiguous_glob_reexports)]
+pub use link_series::*;
+#[allow(ambiguous_glob_reexports)]
+pub use migrate_market::*;
+#[allow(ambiguous_glob_reexports)]
 pub use place_order::*;
+#[allow(ambiguous_glob_reexports)]
+pub use propose_market_authority::*;
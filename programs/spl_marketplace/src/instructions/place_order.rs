@@ -1,8 +1,10 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::errors::ErrorCode;
+use crate::series_policy::check_trading_allowed;
 use crate::state::market::Market;
 use crate::state::order::Order;
+use crate::token_transfer::{transfer_checked_with_hook, HookedTransfer};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct PlaceOrder<'info> {
@@ -44,13 +46,24 @@ pub struct PlaceOrder<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Required when the market is linked; validated by check_trading_allowed.
+    /// Last, after the accounts existing clients already pass
+    pub linked_series: Option<UncheckedAccount<'info>>,
 }
 
-pub fn handler(ctx: Context<PlaceOrder>, price: u64, size: u64, is_buy: bool) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceOrder<'info>>,
+    price: u64,
+    size: u64,
+    is_buy: bool,
+) -> Result<()> {
     require!(price > 0, ErrorCode::InvalidPrice);
     require!(size > 0, ErrorCode::InvalidAmount);
 
     let market = &ctx.accounts.market;
+    check_trading_allowed(market, ctx.accounts.linked_series.as_deref())?;
+
     let decimals = ctx.accounts.deposit_mint.decimals;
 
     // Validate mint matches order side
@@ -78,18 +91,17 @@ pub fn handler(ctx: Context<PlaceOrder>, price: u64, size: u64, is_buy: bool) ->
     };
 
     // Transfer to escrow
-    token_interface::transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.user_deposit_account.to_account_info(),
-                mint: ctx.accounts.deposit_mint.to_account_info(),
-                to: ctx.accounts.escrow.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
+    transfer_checked_with_hook(
+        HookedTransfer {
+            token_program: ctx.accounts.token_program.to_account_info(),
+            from: ctx.accounts.user_deposit_account.to_account_info(),
+            mint: &ctx.accounts.deposit_mint,
+            to: ctx.accounts.escrow.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+        ctx.remaining_accounts,
         escrow_amount,
-        decimals,
+        &[],
     )?;
 
     // Initialize order
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use crate::events::MarketAuthorityProposed;
+use crate::state::market::Market;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeMarketAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub market: Account<'info, Market>,
+}
+
+/// Step 1 of 2: record the nominated authority (Pubkey::default() cancels)
+pub fn handler(ctx: Context<ProposeMarketAuthority>, new_authority: Pubkey) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    market.pending_authority = new_authority;
+
+    emit!(MarketAuthorityProposed {
+        market: market.key(),
+        authority: market.authority,
+        pending_authority: new_authority,
+    });
+
+    msg!(
+        "Market {} authority proposed: {}",
+        market.key(),
+        new_authority
+    );
+
+    Ok(())
+}
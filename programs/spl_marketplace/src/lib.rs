@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 
+pub mod client;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod series_policy;
 pub mod state;
+pub mod token_transfer;
+pub mod version;
 
 use instructions::*;
 
@@ -16,8 +21,8 @@ pub mod spl_marketplace {
         instructions::create_market::handler(ctx)
     }
 
-    pub fn place_order(
-        ctx: Context<PlaceOrder>,
+    pub fn place_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceOrder<'info>>,
         price: u64,
         size: u64,
         is_buy: bool,
@@ -25,11 +30,37 @@ pub mod spl_marketplace {
         instructions::place_order::handler(ctx, price, size, is_buy)
     }
 
-    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+    pub fn cancel_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>) -> Result<()> {
         instructions::cancel_order::handler(ctx)
     }
 
-    pub fn fill_order(ctx: Context<FillOrder>, fill_size: u64) -> Result<()> {
+    pub fn fill_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillOrder<'info>>,
+        fill_size: u64,
+    ) -> Result<()> {
         instructions::fill_order::handler(ctx, fill_size)
     }
+
+    pub fn propose_market_authority(
+        ctx: Context<ProposeMarketAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_market_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_market_authority(ctx: Context<AcceptMarketAuthority>) -> Result<()> {
+        instructions::accept_market_authority::handler(ctx)
+    }
+
+    pub fn link_series(ctx: Context<LinkSeries>) -> Result<()> {
+        instructions::link_series::handler(ctx)
+    }
+
+    pub fn announce_version(ctx: Context<AnnounceVersion>) -> Result<()> {
+        instructions::announce_version::handler(ctx)
+    }
+
+    pub fn migrate_market(ctx: Context<MigrateMarket>) -> Result<()> {
+        instructions::migrate_market::handler(ctx)
+    }
 }
@@ -0,0 +1,122 @@
+//! Trading policy for markets linked to a `sol_option_protocol` series.
+//!
+//! The marketplace cannot depend on the options crate (the options program
+//! CPIs into this one), so the series account is read by raw offsets. The
+//! offsets mirror the `OptionData::*_OFFSET` constants, which the options
+//! program keeps stable by carving new fields from its `reserved` space. The
+//! layout version sits at a fixed offset too, so series in a layout these
+//! offsets don't describe are refused rather than misread. The protocol's
+//! `ProtocolConfig` is read the same way for its admin.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::market::Market;
+
+pub const OPTIONS_PROGRAM_ID: Pubkey = pubkey!("7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP");
+
+/// `sha256("account:OptionData")[..8]`
+pub const OPTION_DATA_DISCRIMINATOR: [u8; 8] = [241, 2, 215, 22, 100, 177, 168, 108];
+
+// OptionData offsets (include the 8-byte account discriminator)
+pub const EXPIRATION_OFFSET: usize = 80;
+pub const OPTION_MINT_OFFSET: usize = 90;
+pub const STATUS_OFFSET: usize = 405;
+pub const VERSION_OFFSET: usize = 927;
+
+/// `sha256("account:ProtocolConfig")[..8]`
+pub const PROTOCOL_CONFIG_DISCRIMINATOR: [u8; 8] = [207, 91, 250, 28, 152, 179, 215, 209];
+
+/// Seed of the options program's `ProtocolConfig` PDA
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
+
+// ProtocolConfig offsets (include the 8-byte account discriminator)
+pub const ADMIN_OFFSET: usize = 8;
+
+/// `OptionData` layout versions the offsets above are known to match
+pub const SUPPORTED_SERIES_VERSIONS: std::ops::RangeInclusive<u8> = 1..=1;
+
+/// Trading-relevant view of a linked series
+pub struct SeriesStatus {
+    pub option_mint: Pubkey,
+    pub expiration: i64,
+    pub status: u8,
+}
+
+impl SeriesStatus {
+    pub fn load(series: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *series.owner,
+            OPTIONS_PROGRAM_ID,
+            ErrorCode::InvalidLinkedSeries
+        );
+        let data = series.try_borrow_data()?;
+        require!(
+            data.len() > VERSION_OFFSET && data[..8] == OPTION_DATA_DISCRIMINATOR,
+            ErrorCode::InvalidLinkedSeries
+        );
+        require!(
+            SUPPORTED_SERIES_VERSIONS.contains(&data[VERSION_OFFSET]),
+            ErrorCode::UnsupportedSeriesVersion
+        );
+
+        let mut expiration = [0u8; 8];
+        expiration.copy_from_slice(&data[EXPIRATION_OFFSET..EXPIRATION_OFFSET + 8]);
+        let mut option_mint = [0u8; 32];
+        option_mint.copy_from_slice(&data[OPTION_MINT_OFFSET..OPTION_MINT_OFFSET + 32]);
+
+        Ok(Self {
+            option_mint: Pubkey::new_from_array(option_mint),
+            expiration: i64::from_le_bytes(expiration),
+            status: data[STATUS_OFFSET],
+        })
+    }
+
+    /// Paused, frozen (any status flag) or expired series halt trading
+    pub fn is_halted(&self, now: i64) -> bool {
+        self.status != 0 || now >= self.expiration
+    }
+}
+
+/// Blocks placement and fills on a market whose linked series is halted.
+/// Unlinked markets always trade; cancels never call this.
+pub fn check_trading_allowed(market: &Market, linked_series: Option<&AccountInfo>) -> Result<()> {
+    if market.linked_series == Pubkey::default() {
+        return Ok(());
+    }
+
+    let series = linked_series.ok_or(ErrorCode::InvalidLinkedSeries)?;
+    require_keys_eq!(
+        series.key(),
+        market.linked_series,
+        ErrorCode::InvalidLinkedSeries
+    );
+
+    let status = SeriesStatus::load(series)?;
+    require!(
+        !status.is_halted(Clock::get()?.unix_timestamp),
+        ErrorCode::TradingHalted
+    );
+
+    Ok(())
+}
+
+/// Admin of the options protocol, read from its `ProtocolConfig` PDA
+pub fn protocol_admin(config: &AccountInfo) -> Result<Pubkey> {
+    let (expected, _) = Pubkey::find_program_address(&[PROTOCOL_CONFIG_SEED], &OPTIONS_PROGRAM_ID);
+    require_keys_eq!(config.key(), expected, ErrorCode::InvalidProtocolConfig);
+    require_keys_eq!(
+        *config.owner,
+        OPTIONS_PROGRAM_ID,
+        ErrorCode::InvalidProtocolConfig
+    );
+    let data = config.try_borrow_data()?;
+    require!(
+        data.len() >= ADMIN_OFFSET + 32 && data[..8] == PROTOCOL_CONFIG_DISCRIMINATOR,
+        ErrorCode::InvalidProtocolConfig
+    );
+
+    let mut admin = [0u8; 32];
+    admin.copy_from_slice(&data[ADMIN_OFFSET..ADMIN_OFFSET + 32]);
+    Ok(Pubkey::new_from_array(admin))
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+
 /// Represents a trading market for a pair of SPL tokens
+///
+/// Layout: new fields are appended so the mint offsets stay stable for
+/// memcmp filters (see the `*_OFFSET` constants).
 #[account]
 pub struct Market {
     /// Base token mint (e.g., option token, NFT, any SPL token)
@@ -20,8 +25,54 @@ pub struct Market {
     pub total_orders_filled: u64,
     pub total_base_volume: u64,
     pub total_quote_volume: u64,
+
+    /// Market authority (creator by default; two-step transfer)
+    pub authority: Pubkey,
+
+    /// Proposed successor authority (Pubkey::default() when none)
+    pub pending_authority: Pubkey,
+
+    /// Option series this market trades (Pubkey::default() when unlinked);
+    /// placement and fills halt while the series is paused, frozen or expired
+    pub linked_series: Pubkey,
+
+    /// Layout version (0 for markets created before versioning)
+    pub version: u8,
+
+    /// Zeroed space new fields take their bytes from
+    pub reserved: [u8; 64],
 }
 
 impl Market {
-    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8;
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 1 + 64;
+
+    /// Size of markets created before versioning: mints, bump, order counter
+    /// and statistics, without the authority, linked series, version and
+    /// reserved fields
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 8 + 8;
+
+    /// Layout version new markets are written with and migrate_market upgrades to
+    pub const CURRENT_VERSION: u8 = 1;
+
+    // memcmp filter offsets (include the 8-byte account discriminator)
+    pub const BASE_MINT_OFFSET: usize = 8;
+    pub const QUOTE_MINT_OFFSET: usize = 40;
+
+    /// Steps a market written with an older layout up to `CURRENT_VERSION`;
+    /// fields added since read as zero until their step backfills them.
+    /// Markets from before versioning have no authority, so their step takes
+    /// the one to backfill (`LegacyMarketAuthority` without it).
+    pub fn migrate(&mut self, authority: Option<Pubkey>) -> Result<()> {
+        while self.version < Self::CURRENT_VERSION {
+            if self.version == 0 {
+                // 0 → 1: authority fields, `linked_series`, `version` and
+                // `reserved` appended; unlinked with no transfer pending
+                self.authority = authority.ok_or(ErrorCode::LegacyMarketAuthority)?;
+                self.pending_authority = Pubkey::default();
+                self.linked_series = Pubkey::default();
+            }
+            self.version += 1;
+        }
+        Ok(())
+    }
 }
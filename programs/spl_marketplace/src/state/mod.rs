@@ -1,5 +1,7 @@
 pub mod market;
 pub mod order;
+pub mod program_version;
 
 pub use market::*;
 pub use order::*;
+pub use program_version::*;
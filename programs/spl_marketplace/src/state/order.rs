@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
 /// Represents a single limit order in the market
+///
+/// Layout: every field is fixed-size, so "orders in market X", "orders of
+/// owner Y" and "bids/asks" are memcmp filters at stable offsets (see the
+/// `*_OFFSET` constants). Keep the field order and append new fields so
+/// existing orders still deserialize.
 #[account]
 pub struct Order {
     /// Market this order belongs to
@@ -34,6 +39,12 @@ pub struct Order {
 impl Order {
     pub const SIZE: usize = 8 + 32 + 8 + 32 + 1 + 8 + 8 + 8 + 1 + 8;
 
+    // memcmp filter offsets (include the 8-byte account discriminator)
+    pub const MARKET_OFFSET: usize = 8;
+    pub const ORDER_ID_OFFSET: usize = 40;
+    pub const OWNER_OFFSET: usize = 48;
+    pub const IS_BUY_OFFSET: usize = 80;
+
     pub fn remaining(&self) -> u64 {
         self.size.saturating_sub(self.filled)
     }
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_VERSION_LEN: usize = 16;
+
+/// Last version announced by this deployment (singleton PDA)
+#[account]
+pub struct ProgramVersion {
+    /// PDA bump
+    pub bump: u8,
+
+    /// Slot of the latest announcement
+    pub announced_slot: u64,
+
+    /// Semantic version of the latest announcement (empty before the first)
+    pub version: String,
+}
+
+impl ProgramVersion {
+    pub const SIZE: usize = 8 + 1 + 8 + 4 + MAX_VERSION_LEN;
+}
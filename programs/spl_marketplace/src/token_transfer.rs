@@ -0,0 +1,42 @@
+//! Token transfers that honour Token-2022 transfer hooks.
+//!
+//! Option tokens of permissioned series carry a transfer hook, and Token-2022
+//! rejects a `transfer_checked` that doesn't forward the hook's accounts. The
+//! caller passes them as the instruction's remaining accounts (the hook
+//! program, its extra-account-metas PDA and whatever that PDA lists); they
+//! are matched by address, so mints without a hook simply ignore them.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::Mint;
+
+/// Accounts of a hook-aware `transfer_checked`
+pub struct HookedTransfer<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+/// `transfer_checked` of `amount`, forwarding `hook_accounts` to the mint's
+/// transfer hook. PDAs sign with `signer_seeds`.
+pub fn transfer_checked_with_hook<'info>(
+    accounts: HookedTransfer<'_, 'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        accounts.token_program.key,
+        accounts.from,
+        accounts.mint.to_account_info(),
+        accounts.to,
+        accounts.authority,
+        hook_accounts,
+        amount,
+        accounts.mint.decimals,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,14 @@
+//! Deployment version and capability flags reported by `announce_version`.
+//!
+//! Append to `CAPABILITIES` whenever a release adds an integrator-visible
+//! feature, so indexers can gate on it instead of on program version.
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const CAPABILITIES: &[&str] = &[
+    "limit-orders",
+    "authority-transfer",
+    "series-linked-halts",
+    "cpi-client",
+    "versioned-markets",
+];
@@ -0,0 +1,473 @@
+/**
+ * Deterministic localnet fixture generator.
+ *
+ * Usage:
+ *   anchor build
+ *   npx ts-node scripts/localnet-fixtures.ts [spec.toml] [--skip-validator]
+ *
+ * Starts `solana-test-validator` with both programs deployed (upgradeable, the
+ * wallet as upgrade authority), initializes the protocol config, then creates
+ * the mints, series, markets and orders described in the TOML spec (default:
+ * fixtures/localnet.toml). Addresses are written to the spec's output path and
+ * the validator keeps running until Ctrl-C.
+ *
+ * --skip-validator seeds an already running validator instead.
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SolOptionProtocol } from "../target/types/sol_option_protocol";
+import { SplMarketplace } from "../target/types/spl_marketplace";
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  Connection,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { ChildProcess, spawn } from "child_process";
+import { createHash } from "crypto";
+import { mkdirSync, readFileSync, writeFileSync } from "fs";
+import { homedir } from "os";
+import { dirname } from "path";
+import { parse } from "smol-toml";
+
+const { BN } = anchor;
+
+const OPTIONS_PROGRAM_ID = new PublicKey(
+  "7a3MatFT2m6iHtZ3vYBoLRP4A1YBuophqGqoCz4p4JoP"
+);
+const MARKETPLACE_PROGRAM_ID = new PublicKey(
+  "DooTSqB4vH54evV1DhPC7XEbXNq75D3k7weYiPTGbxYz"
+);
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+interface Spec {
+  seed: string;
+  validator: { ledger: string; rpc_url: string };
+  output: { path: string };
+  mints: { name: string; decimals: number; supply: number }[];
+  series: {
+    name: string;
+    collateral: string;
+    consideration: string;
+    strike: number;
+    expiration: number;
+    is_put?: boolean;
+    underlying_symbol?: string;
+    metadata_uri?: string;
+    /** "american" (default) or "european" */
+    exercise_style?: "american" | "european";
+    /** European exercise window in seconds after expiration */
+    exercise_window?: number;
+    /** American exercise cutoff in seconds before expiration */
+    exercise_cutoff?: number;
+    /** Seconds after exercise closes before redeem opens */
+    settlement_delay?: number;
+    mint?: number;
+  }[];
+  markets: { name: string; base: string; quote: string; link_series?: boolean }[];
+  orders: { market: string; side: "buy" | "sell"; price: number; size: number }[];
+}
+
+interface MintFixture {
+  address: PublicKey;
+  decimals: number;
+  walletAccount: PublicKey;
+}
+
+interface SeriesFixture {
+  optionContext: PublicKey;
+  optionMint: PublicKey;
+  redemptionMint: PublicKey;
+  collateralVault: PublicKey;
+  considerationVault: PublicKey;
+}
+
+/** Whole-token amount -> base units (string math avoids float rounding) */
+function toUnits(amount: number, decimals: number): anchor.BN {
+  const [whole, frac = ""] = amount.toString().split(".");
+  if (frac.length > decimals) {
+    throw new Error(`${amount} has more than ${decimals} decimals`);
+  }
+  return new BN(whole + frac.padEnd(decimals, "0"));
+}
+
+/** Same spec seed + name always yields the same keypair */
+function deriveKeypair(seed: string, name: string): Keypair {
+  return Keypair.fromSeed(
+    createHash("sha256").update(`${seed}:${name}`).digest()
+  );
+}
+
+function loadWallet(): Keypair {
+  const path =
+    process.env.ANCHOR_WALLET ?? `${homedir()}/.config/solana/id.json`;
+  return Keypair.fromSecretKey(
+    Uint8Array.from(JSON.parse(readFileSync(path, "utf8")))
+  );
+}
+
+function startValidator(spec: Spec, wallet: Keypair): ChildProcess {
+  const args = [
+    "--reset",
+    "--quiet",
+    "--ledger",
+    spec.validator.ledger,
+    "--upgradeable-program",
+    OPTIONS_PROGRAM_ID.toString(),
+    "target/deploy/sol_option_protocol.so",
+    wallet.publicKey.toString(),
+    "--upgradeable-program",
+    MARKETPLACE_PROGRAM_ID.toString(),
+    "target/deploy/spl_marketplace.so",
+    wallet.publicKey.toString(),
+  ];
+  const validator = spawn("solana-test-validator", args, { stdio: "inherit" });
+  validator.on("exit", (code) => {
+    console.error(`solana-test-validator exited (${code})`);
+    process.exit(code ?? 1);
+  });
+  return validator;
+}
+
+async function waitForRpc(connection: Connection) {
+  for (let attempt = 0; attempt < 60; attempt++) {
+    try {
+      await connection.getLatestBlockhash();
+      return;
+    } catch {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+  }
+  throw new Error("Validator did not become ready within 60s");
+}
+
+async function main() {
+  const args = process.argv.slice(2);
+  const skipValidator = args.includes("--skip-validator");
+  const specPath = args.find((a) => !a.startsWith("--")) ?? "fixtures/localnet.toml";
+  const spec = parse(readFileSync(specPath, "utf8")) as unknown as Spec;
+  spec.mints ??= [];
+  spec.series ??= [];
+  spec.markets ??= [];
+  spec.orders ??= [];
+
+  const wallet = loadWallet();
+  const validator = skipValidator ? null : startValidator(spec, wallet);
+
+  const connection = new Connection(spec.validator.rpc_url, "confirmed");
+  await waitForRpc(connection);
+
+  const provider = new anchor.AnchorProvider(
+    connection,
+    new anchor.Wallet(wallet),
+    { commitment: "confirmed" }
+  );
+  anchor.setProvider(provider);
+  const options = anchor.workspace
+    .SolOptionProtocol as Program<SolOptionProtocol>;
+  const marketplace = anchor.workspace
+    .SplMarketplace as Program<SplMarketplace>;
+
+  await connection.confirmTransaction(
+    await connection.requestAirdrop(wallet.publicKey, 100 * LAMPORTS_PER_SOL)
+  );
+
+  // Protocol config (the wallet is the upgrade authority on this validator)
+  const [protocolConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config")],
+    OPTIONS_PROGRAM_ID
+  );
+  if (!(await connection.getAccountInfo(protocolConfig))) {
+    const [programData] = PublicKey.findProgramAddressSync(
+      [OPTIONS_PROGRAM_ID.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_ID
+    );
+    await options.methods
+      .initializeConfig()
+      .accountsPartial({
+        admin: wallet.publicKey,
+        protocolConfig,
+        program: OPTIONS_PROGRAM_ID,
+        programData,
+      })
+      .rpc();
+  }
+
+  // Mints
+  const mints = new Map<string, MintFixture>();
+  for (const m of spec.mints) {
+    const address = await createMint(
+      connection,
+      wallet,
+      wallet.publicKey,
+      null,
+      m.decimals,
+      deriveKeypair(spec.seed, `mint:${m.name}`)
+    );
+    const walletAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        wallet,
+        address,
+        wallet.publicKey
+      )
+    ).address;
+    await mintTo(
+      connection,
+      wallet,
+      address,
+      walletAccount,
+      wallet,
+      BigInt(toUnits(m.supply, m.decimals).toString())
+    );
+    mints.set(m.name, { address, decimals: m.decimals, walletAccount });
+    console.log(`Mint ${m.name}: ${address}`);
+  }
+
+  const mint = (name: string) => {
+    const found = mints.get(name);
+    if (!found) throw new Error(`Unknown mint "${name}"`);
+    return found;
+  };
+
+  // Series
+  const series = new Map<string, SeriesFixture>();
+  const noOracle = {
+    kind: { none: {} },
+    feed: PublicKey.default,
+    guard: { maxStaleness: 0, maxConfidenceBps: 0, maxDeviationBps: 0 },
+  };
+  for (const s of spec.series) {
+    const collateral = mint(s.collateral);
+    const consideration = mint(s.consideration);
+    const strike = toUnits(s.strike, consideration.decimals);
+    const expiration = new BN(s.expiration);
+    const isPut = s.is_put ?? false;
+
+    const [optionContext] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("option_context"),
+        collateral.address.toBuffer(),
+        consideration.address.toBuffer(),
+        strike.toArrayLike(Buffer, "le", 8),
+        expiration.toArrayLike(Buffer, "le", 8),
+        Buffer.from([isPut ? 1 : 0]),
+      ],
+      OPTIONS_PROGRAM_ID
+    );
+    const pda = (prefix: string) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from(prefix), optionContext.toBuffer()],
+        OPTIONS_PROGRAM_ID
+      )[0];
+    const fixture: SeriesFixture = {
+      optionContext,
+      optionMint: pda("option_mint"),
+      redemptionMint: pda("redemption_mint"),
+      collateralVault: pda("collateral_vault"),
+      considerationVault: pda("consideration_vault"),
+    };
+
+    await options.methods
+      .createOption(
+        collateral.address,
+        consideration.address,
+        strike,
+        expiration,
+        isPut ? { put: {} } : { call: {} },
+        {
+          primaryOracle: noOracle,
+          secondaryOracle: noOracle,
+          metadataUri: s.metadata_uri ?? "",
+          underlyingSymbol: s.underlying_symbol ?? "",
+          exerciseStyle:
+            s.exercise_style === "european" ? { european: {} } : { american: {} },
+          exerciseWindow: s.exercise_window ?? 0,
+          exerciseCutoff: s.exercise_cutoff ?? 0,
+          settlementDelay: s.settlement_delay ?? 0,
+          initialMarginBps: 0,
+          maintenanceMarginBps: 0,
+          lotSize: new BN(0),
+          minMintAmount: new BN(0),
+          contractMultiplier: new BN(0),
+          strikeExponent: null,
+          binaryPayout: new BN(0),
+          knockoutBarrier: new BN(0),
+          fundingRateBps: 0,
+          acceptsBasket: false,
+          transferHookProgram: PublicKey.default,
+          freezable: false,
+          complianceAuthority: PublicKey.default,
+          gateMint: PublicKey.default,
+        }
+      )
+      .accountsPartial({
+        user: wallet.publicKey,
+        optionContext,
+        collateralMint: collateral.address,
+        considerationMint: consideration.address,
+        tokenMetadataProgram: null,
+        optionMetadata: null,
+        redemptionMetadata: null,
+      })
+      .rpc();
+
+    const ata = async (m: PublicKey) =>
+      (await getOrCreateAssociatedTokenAccount(connection, wallet, m, wallet.publicKey))
+        .address;
+    const userOptionAccount = await ata(fixture.optionMint);
+    if (s.mint) {
+      await options.methods
+        .mint(toUnits(s.mint, collateral.decimals))
+        .accountsPartial({
+          user: wallet.publicKey,
+          optionContext,
+          collateralMint: collateral.address,
+          considerationMint: consideration.address,
+          optionMint: fixture.optionMint,
+          redemptionMint: fixture.redemptionMint,
+          collateralVault: fixture.collateralVault,
+          considerationVault: fixture.considerationVault,
+          userCollateralAccount: collateral.walletAccount,
+          userOptionAccount,
+          userRedemptionAccount: await ata(fixture.redemptionMint),
+          userConsiderationAccount: consideration.walletAccount,
+        })
+        .rpc();
+    }
+
+    // A series name can be used as a market base (its option token)
+    mints.set(s.name, {
+      address: fixture.optionMint,
+      decimals: collateral.decimals,
+      walletAccount: userOptionAccount,
+    });
+    series.set(s.name, fixture);
+    console.log(`Series ${s.name}: ${optionContext}`);
+  }
+
+  // Markets
+  const markets = new Map<string, { address: PublicKey; base: string; quote: string }>();
+  for (const m of spec.markets) {
+    const base = mint(m.base);
+    const quote = mint(m.quote);
+    const [market] = PublicKey.findProgramAddressSync(
+      [Buffer.from("market"), base.address.toBuffer(), quote.address.toBuffer()],
+      MARKETPLACE_PROGRAM_ID
+    );
+    await marketplace.methods
+      .createMarket()
+      .accountsPartial({
+        creator: wallet.publicKey,
+        baseMint: base.address,
+        quoteMint: quote.address,
+        market,
+      })
+      .rpc();
+
+    if (m.link_series) {
+      const linked = series.get(m.base);
+      if (!linked) throw new Error(`Market "${m.name}" base is not a series`);
+      await marketplace.methods
+        .linkSeries()
+        .accountsPartial({
+          authority: wallet.publicKey,
+          market,
+          series: linked.optionContext,
+        })
+        .rpc();
+    }
+    markets.set(m.name, { address: market, base: m.base, quote: m.quote });
+    console.log(`Market ${m.name}: ${market}`);
+  }
+
+  // Orders
+  const orders: { market: string; order: PublicKey; side: string }[] = [];
+  for (const o of spec.orders) {
+    const market = markets.get(o.market);
+    if (!market) throw new Error(`Unknown market "${o.market}"`);
+    const base = mint(market.base);
+    const quote = mint(market.quote);
+    const isBuy = o.side === "buy";
+    const deposit = isBuy ? quote : base;
+
+    const { nextOrderId } = await marketplace.account.market.fetch(
+      market.address
+    );
+    const [order] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("order"),
+        market.address.toBuffer(),
+        nextOrderId.toArrayLike(Buffer, "le", 8),
+      ],
+      MARKETPLACE_PROGRAM_ID
+    );
+    const [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow"), order.toBuffer()],
+      MARKETPLACE_PROGRAM_ID
+    );
+    const linked = series.get(market.base);
+
+    await marketplace.methods
+      .placeOrder(
+        toUnits(o.price, quote.decimals),
+        toUnits(o.size, base.decimals),
+        isBuy
+      )
+      .accountsPartial({
+        user: wallet.publicKey,
+        market: market.address,
+        order,
+        depositMint: deposit.address,
+        userDepositAccount: deposit.walletAccount,
+        escrow,
+        linkedSeries: linked?.optionContext ?? null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    orders.push({ market: o.market, order, side: o.side });
+    console.log(`Order ${o.side} ${o.size} @ ${o.price} on ${o.market}: ${order}`);
+  }
+
+  const output = {
+    rpcUrl: spec.validator.rpc_url,
+    wallet: wallet.publicKey,
+    protocolConfig,
+    mints: Object.fromEntries(
+      spec.mints.map((m) => [m.name, mint(m.name).address])
+    ),
+    series: Object.fromEntries(series),
+    markets: Object.fromEntries(
+      [...markets].map(([name, m]) => [name, m.address])
+    ),
+    orders,
+  };
+  mkdirSync(dirname(spec.output.path), { recursive: true });
+  writeFileSync(spec.output.path, JSON.stringify(output, null, 2) + "\n");
+  console.log(`\nFixtures written to ${spec.output.path}`);
+
+  if (validator) {
+    console.log("Validator running. Press Ctrl-C to stop.");
+    process.on("SIGINT", () => {
+      validator.removeAllListeners("exit");
+      validator.kill("SIGINT");
+      process.exit(0);
+    });
+  }
+}
+
+main().catch((err) => {
+  console.error(err);
+  process.exit(1);
+});
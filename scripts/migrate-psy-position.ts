@@ -0,0 +1,129 @@
+/**
+ * Migrate a paired PsyOptions American position into an equivalent series.
+ *
+ * Usage:
+ *   npx ts-node scripts/migrate-psy-position.ts <psyOptionMarket> <optionContext> <contracts>
+ *
+ * The wallet must hold `contracts` PsyOptions option tokens AND writer tokens.
+ * The program verifies the markets match, closes the PsyOptions pair into the
+ * series' collateral vault and mints the same size option + redemption pair.
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SolOptionProtocol } from "../target/types/sol_option_protocol";
+import {
+  getOrCreateAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
+
+const PSY_AMERICAN_PROGRAM_ID = new PublicKey(
+  "R2y9ip6mxmWUj4pt54jP2hz2dgvMozy9VTSwMWE7evs"
+);
+
+// OptionMarket layout (after the 8-byte discriminator)
+function decodePsyOptionMarket(data: Buffer) {
+  const key = (offset: number) =>
+    new PublicKey(data.subarray(offset, offset + 32));
+  const u64 = (offset: number) =>
+    new anchor.BN(data.subarray(offset, offset + 8), "le");
+  return {
+    optionMint: key(8),
+    writerTokenMint: key(40),
+    underlyingAssetMint: key(72),
+    quoteAssetMint: key(104),
+    underlyingAmountPerContract: u64(136),
+    quoteAmountPerContract: u64(144),
+    expirationUnixTimestamp: u64(152).fromTwos(64),
+    underlyingAssetPool: key(160),
+  };
+}
+
+async function main() {
+  const [marketArg, seriesArg, contractsArg] = process.argv.slice(2);
+  if (!marketArg || !seriesArg || !contractsArg) {
+    console.error(
+      "Usage: migrate-psy-position.ts <psyOptionMarket> <optionContext> <contracts>"
+    );
+    process.exit(1);
+  }
+
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace
+    .SolOptionProtocol as Program<SolOptionProtocol>;
+  const payer = provider.wallet as anchor.Wallet;
+
+  const psyMarketKey = new PublicKey(marketArg);
+  const optionContext = new PublicKey(seriesArg);
+  const contracts = new anchor.BN(contractsArg);
+
+  const psyInfo = await provider.connection.getAccountInfo(psyMarketKey);
+  if (!psyInfo || !psyInfo.owner.equals(PSY_AMERICAN_PROGRAM_ID)) {
+    throw new Error("Not a PsyOptions American OptionMarket account");
+  }
+  const psyMarket = decodePsyOptionMarket(psyInfo.data);
+  const series = await program.account.optionData.fetch(optionContext);
+
+  console.log("PsyOptions market:", psyMarketKey.toString());
+  console.log("  underlying:", psyMarket.underlyingAssetMint.toString());
+  console.log("  quote:     ", psyMarket.quoteAssetMint.toString());
+  console.log("  expiry:    ", psyMarket.expirationUnixTimestamp.toString());
+  console.log("Destination series:", optionContext.toString());
+
+  const userOptionAccount = await getOrCreateAssociatedTokenAccount(
+    provider.connection,
+    payer.payer,
+    series.optionMint,
+    payer.publicKey
+  );
+  const userRedemptionAccount = await getOrCreateAssociatedTokenAccount(
+    provider.connection,
+    payer.payer,
+    series.redemptionMint,
+    payer.publicKey
+  );
+
+  const [protocolConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config")],
+    program.programId
+  );
+
+  const tx = await program.methods
+    .migratePsyPosition(contracts)
+    .accounts({
+      user: payer.publicKey,
+      optionContext,
+      collateralMint: series.collateralMint,
+      optionMint: series.optionMint,
+      redemptionMint: series.redemptionMint,
+      collateralVault: series.collateralVault,
+      userOptionAccount: userOptionAccount.address,
+      userRedemptionAccount: userRedemptionAccount.address,
+      psyOptionMarket: psyMarketKey,
+      psyOptionMint: psyMarket.optionMint,
+      psyWriterMint: psyMarket.writerTokenMint,
+      psyUnderlyingPool: psyMarket.underlyingAssetPool,
+      userPsyOptionAccount: getAssociatedTokenAddressSync(
+        psyMarket.optionMint,
+        payer.publicKey
+      ),
+      userPsyWriterAccount: getAssociatedTokenAddressSync(
+        psyMarket.writerTokenMint,
+        payer.publicKey
+      ),
+      psyProgram: PSY_AMERICAN_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      protocolConfig,
+    } as any)
+    .rpc();
+
+  console.log("✓ Migrated", contracts.toString(), "contracts:", tx);
+}
+
+main().catch((err) => {
+  console.error(err);
+  process.exit(1);
+});
@@ -10,6 +10,11 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SolOptionProtocol } from "../target/types/sol_option_protocol";
+import {
+  defaultSeriesConfig,
+  NO_TOKEN_METADATA,
+  vanillaOptionType,
+} from "./series_config";
 const { BN } = anchor;
 import {
   createMint,
@@ -173,9 +178,11 @@ describe("Local Options Protocol Tests", () => {
         considerationMint,
         strikePrice,
         expiration,
-        false
+        vanillaOptionType(isPut),
+        defaultSeriesConfig()
       )
       .accounts({
+        ...NO_TOKEN_METADATA,
         // payer: payer.publicKey,
         optionContext: optionContextPDA,
         collateralMint: collateralMint,
@@ -12,6 +12,11 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SolOptionProtocol } from "../target/types/sol_option_protocol";
+import {
+  defaultSeriesConfig,
+  NO_TOKEN_METADATA,
+  vanillaOptionType,
+} from "./series_config";
 const { BN } = anchor;
 import {
   createMint,
@@ -233,9 +238,11 @@ describe("PDA Isolation & Multi-Series Tests", () => {
           considerationMint,
           strikePrice,
           expiration,
-          false
+          vanillaOptionType(isPut),
+          defaultSeriesConfig()
         )
         .accounts({
+          ...NO_TOKEN_METADATA,
           optionContext: optionContextPDA,
           collateralMint: collateralMint,
           considerationMint: considerationMint,
@@ -322,9 +329,11 @@ describe("PDA Isolation & Multi-Series Tests", () => {
           considerationMint,
           strikePrice,
           expiration,
-          false
+          vanillaOptionType(isPut),
+          defaultSeriesConfig()
         )
         .accounts({
+          ...NO_TOKEN_METADATA,
           optionContext: optionContextPDA,
           collateralMint: collateralMint,
           considerationMint: considerationMint,
@@ -447,9 +456,11 @@ describe("PDA Isolation & Multi-Series Tests", () => {
           considerationMint,
           strikePrice,
           expiration,
-          false
+          vanillaOptionType(isPut),
+          defaultSeriesConfig()
         )
         .accounts({
+          ...NO_TOKEN_METADATA,
           optionContext: optionContextPDA,
           collateralMint: collateralMint,
           considerationMint: considerationMint,
@@ -9,6 +9,11 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SplMarketplace } from "../target/types/spl_marketplace";
 import { SolOptionProtocol } from "../target/types/sol_option_protocol";
+import {
+  defaultSeriesConfig,
+  NO_TOKEN_METADATA,
+  vanillaOptionType,
+} from "./series_config";
 const { BN } = anchor;
 import {
   createMint,
@@ -309,7 +314,7 @@ describe("🚀 SPL Marketplace Demo", () => {
         quoteMint.toBuffer(),
         strikePrice.toArrayLike(Buffer, "le", 8),
         expiration.toArrayLike(Buffer, "le", 8),
-        Buffer.from([0]), // is_put = false
+        Buffer.from([0]), // option_type = call
       ],
       optionsProgram.programId
     );
@@ -318,8 +323,16 @@ describe("🚀 SPL Marketplace Demo", () => {
 
     try {
       await optionsProgram.methods
-        .createOption(baseMint, quoteMint, strikePrice, expiration, false)
+        .createOption(
+          baseMint,
+          quoteMint,
+          strikePrice,
+          expiration,
+          vanillaOptionType(false),
+          defaultSeriesConfig()
+        )
         .accounts({
+          ...NO_TOKEN_METADATA,
           optionContext: optionContextPDA,
           collateralMint: baseMint,
           considerationMint: quoteMint,